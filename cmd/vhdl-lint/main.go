@@ -26,12 +26,35 @@ package main
 import (
 	"fmt"
 	"os"
+	"slices"
+	"strconv"
+	"strings"
 
 	"github.com/robert-at-pretension-io/vhdl-lint/internal/config"
 	"github.com/robert-at-pretension-io/vhdl-lint/internal/indexer"
+	"github.com/robert-at-pretension-io/vhdl-lint/internal/policy"
+)
+
+// Rule module selection from --only/--skip (applies to every lint mode)
+var onlyModules, skipModules []string
+
+// Fast mode from --fast/--error-budget, sharding from --shards, policy
+// threads from --policy-jobs, the confidence floor from --min-confidence, the
+// lint profile from --profile and dead-code roots from --top/--testbench-roots
+// (applies to every lint mode)
+var (
+	fastMode      bool
+	errorBudget   int
+	shardWorkers  int
+	moduleWorkers int
+	minConfidence string
+	lintProfile   string
+	topEntities   []string
+	tbRoots       bool
 )
 
 func main() {
+	os.Args = extractModuleFlags(os.Args)
 	if len(os.Args) < 2 {
 		printUsage()
 		os.Exit(1)
@@ -42,6 +65,32 @@ func main() {
 	switch cmd {
 	case "init":
 		runInit()
+	case "constructs":
+		if len(os.Args) < 3 {
+			printUsage()
+			os.Exit(1)
+		}
+		runConstructs(os.Args[2])
+	case "diff":
+		args, format := extractFormatFlag(os.Args[2:])
+		if len(args) != 2 {
+			printUsage()
+			os.Exit(1)
+		}
+		runDiff(args[0], args[1], format)
+	case "explain":
+		if len(os.Args) < 3 {
+			printUsage()
+			os.Exit(1)
+		}
+		runExplain(os.Args[2])
+	case "graph":
+		args, dot := extractDotFlag(os.Args[2:])
+		if len(args) < 3 || args[0] != "signals" {
+			printUsage()
+			os.Exit(1)
+		}
+		runSignalGraph(args[1], args[2], dot)
 	case "-v", "--verbose":
 		if len(os.Args) < 3 {
 			printUsage()
@@ -110,6 +159,12 @@ func printUsage() {
 
 Commands:
   init              Create a vhdl_lint.json configuration file
+  constructs <path> Print detected FSMs, counters, FIFOs and ready/valid interfaces as JSON
+  diff <old.json> <new.json> [--format markdown]
+                    Print new, fixed and persisting violations between two JSON results
+  explain <rule>    Print a rule's rationale and bad/good VHDL examples
+  graph signals <arch> <path> [--dot]
+                    Print the signal dataflow graph of an architecture (or entity) as JSON or DOT
   <path>            Lint VHDL files in the given path
 
 Options:
@@ -122,6 +177,16 @@ Options:
   --timing          Emit timing.jsonl with pipeline timing events
   --clear-policy-cache  Remove cached policy results for the given path
   -c, --config      Specify config file: vhdl-lint -c config.json <path>
+  --only a,b        Run only these rule modules (e.g. --only cdc,rdc,signals)
+  --skip a,b        Skip these rule modules (e.g. --skip style,naming)
+  --fast            Run only cheap, high-signal rules for quick feedback
+  --error-budget N  With --fast, stop after the rule module that reaches N errors (default: no limit)
+  --shards N        Run intra-entity rule modules on N parallel per-entity shards
+  --policy-jobs N   Evaluate rule modules on N threads (default: one per core, 1 = sequential)
+  --min-confidence L  Report only violations at confidence L or above (low, medium, high)
+  --profile tb      Testbench profile: skip synthesis rules in testbench files, run testbench checks
+  --top a,b         Report entities, packages, functions and types unreachable from these top entities
+  --testbench-roots With --top (or alone), also treat testbench entities as reachable roots
   -h, --help        Show this help message
 
 Configuration:
@@ -160,6 +225,168 @@ func runInit() {
 	fmt.Println("  - Lint rule severities")
 }
 
+// extractModuleFlags removes --only/--skip pairs, --fast/--error-budget,
+// --shards, --policy-jobs, --min-confidence, --profile and --top/--testbench-roots
+// from args, recording the module lists, fast mode, worker settings,
+// confidence floor, profile and dead-code roots.
+func extractModuleFlags(args []string) []string {
+	out := make([]string, 0, len(args))
+	for i := 0; i < len(args); i++ {
+		arg := args[i]
+		if arg == "--fast" {
+			fastMode = true
+			continue
+		}
+		if arg == "--error-budget" && i+1 < len(args) {
+			budget, err := strconv.Atoi(args[i+1])
+			if err != nil || budget < 0 {
+				fmt.Fprintf(os.Stderr, "Invalid --error-budget %q\n", args[i+1])
+				os.Exit(1)
+			}
+			errorBudget = budget
+			i++
+			continue
+		}
+		if arg == "--shards" && i+1 < len(args) {
+			workers, err := strconv.Atoi(args[i+1])
+			if err != nil || workers < 0 {
+				fmt.Fprintf(os.Stderr, "Invalid --shards %q\n", args[i+1])
+				os.Exit(1)
+			}
+			shardWorkers = workers
+			i++
+			continue
+		}
+		if arg == "--policy-jobs" && i+1 < len(args) {
+			workers, err := strconv.Atoi(args[i+1])
+			if err != nil || workers < 0 {
+				fmt.Fprintf(os.Stderr, "Invalid --policy-jobs %q\n", args[i+1])
+				os.Exit(1)
+			}
+			moduleWorkers = workers
+			i++
+			continue
+		}
+		if arg == "--min-confidence" && i+1 < len(args) {
+			level := strings.ToLower(args[i+1])
+			if level != "low" && level != "medium" && level != "high" {
+				fmt.Fprintf(os.Stderr, "Invalid --min-confidence %q (want low, medium or high)\n", args[i+1])
+				os.Exit(1)
+			}
+			minConfidence = level
+			i++
+			continue
+		}
+		if arg == "--profile" && i+1 < len(args) {
+			profile := strings.ToLower(args[i+1])
+			if profile != "tb" && profile != "rtl" {
+				fmt.Fprintf(os.Stderr, "Invalid --profile %q (want tb or rtl)\n", args[i+1])
+				os.Exit(1)
+			}
+			if profile == "rtl" {
+				profile = ""
+			}
+			lintProfile = profile
+			i++
+			continue
+		}
+		if arg == "--top" && i+1 < len(args) {
+			for _, part := range strings.Split(args[i+1], ",") {
+				if part = strings.TrimSpace(part); part != "" {
+					topEntities = append(topEntities, part)
+				}
+			}
+			i++
+			continue
+		}
+		if arg == "--testbench-roots" {
+			tbRoots = true
+			continue
+		}
+		if arg == "--only" || arg == "--skip" {
+			if i+1 >= len(args) {
+				fmt.Fprintf(os.Stderr, "%s requires a module list\n", arg)
+				os.Exit(1)
+			}
+			modules := splitModuleList(args[i+1])
+			if arg == "--only" {
+				onlyModules = append(onlyModules, modules...)
+			} else {
+				skipModules = append(skipModules, modules...)
+			}
+			i++
+			continue
+		}
+		out = append(out, arg)
+	}
+	return out
+}
+
+func splitModuleList(value string) []string {
+	var modules []string
+	for _, part := range strings.Split(value, ",") {
+		part = strings.TrimSpace(part)
+		if part != "" {
+			modules = append(modules, strings.ToLower(part))
+		}
+	}
+	return modules
+}
+
+// validateModules exits when --only/--skip name a module the policy engine
+// doesn't know, instead of letting the selection silently match nothing
+func validateModules() {
+	if len(onlyModules) == 0 && len(skipModules) == 0 {
+		return
+	}
+	engine, err := policy.New(".")
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+		os.Exit(1)
+	}
+	known, err := engine.Modules()
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+		os.Exit(1)
+	}
+	for _, name := range append(append([]string{}, onlyModules...), skipModules...) {
+		if !slices.Contains(known, name) {
+			fmt.Fprintf(os.Stderr, "Unknown rule module %q (known: %s)\n", name, strings.Join(known, ", "))
+			os.Exit(1)
+		}
+	}
+}
+
+func applyModuleFlags(cfg *config.Config) {
+	validateModules()
+	cfg.Lint.OnlyModules = append(cfg.Lint.OnlyModules, onlyModules...)
+	cfg.Lint.SkipModules = append(cfg.Lint.SkipModules, skipModules...)
+	if fastMode {
+		cfg.Lint.Fast = true
+	}
+	if errorBudget > 0 {
+		cfg.Lint.ErrorBudget = errorBudget
+	}
+	if shardWorkers > 0 {
+		cfg.Lint.ShardWorkers = shardWorkers
+	}
+	if moduleWorkers > 0 {
+		cfg.Lint.ModuleWorkers = moduleWorkers
+	}
+	if minConfidence != "" {
+		cfg.Lint.MinConfidence = minConfidence
+	}
+	if lintProfile != "" {
+		cfg.Lint.Profile = lintProfile
+	}
+	if len(topEntities) > 0 {
+		cfg.Lint.Top = topEntities
+	}
+	if tbRoots {
+		cfg.Lint.TestbenchRoots = true
+	}
+}
+
 func runLintWithFlags(path string, verbose, jsonOutput, progress, trace, timing bool) {
 	// Load config from default locations
 	cfg, err := config.Load(path)
@@ -167,6 +394,7 @@ func runLintWithFlags(path string, verbose, jsonOutput, progress, trace, timing
 		fmt.Fprintf(os.Stderr, "Error loading config: %v\n", err)
 		os.Exit(1)
 	}
+	applyModuleFlags(cfg)
 
 	idx := indexer.NewWithConfig(cfg)
 	idx.Verbose = verbose
@@ -186,6 +414,7 @@ func runLintWithConfig(configPath, lintPath string, verbose, jsonOutput, progres
 		fmt.Fprintf(os.Stderr, "Error loading config %s: %v\n", configPath, err)
 		os.Exit(1)
 	}
+	applyModuleFlags(cfg)
 
 	idx := indexer.NewWithConfig(cfg)
 	idx.Verbose = verbose
@@ -199,6 +428,99 @@ func runLintWithConfig(configPath, lintPath string, verbose, jsonOutput, progres
 	}
 }
 
+func runConstructs(path string) {
+	cfg, err := config.Load(path)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Error loading config: %v\n", err)
+		os.Exit(1)
+	}
+
+	idx := indexer.NewWithConfig(cfg)
+	idx.JSONOutput = true // keep stdout to the inventory
+	idx.ConstructsOnly = true
+	if err := idx.Run(path); err != nil {
+		fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+		os.Exit(1)
+	}
+}
+
+func runExplain(rule string) {
+	engine, err := policy.New(".")
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+		os.Exit(1)
+	}
+
+	text, err := engine.Explain(rule)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+		os.Exit(1)
+	}
+	os.Stdout.Write(text)
+}
+
+func runDiff(oldPath, newPath, format string) {
+	engine, err := policy.New(".")
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+		os.Exit(1)
+	}
+
+	text, err := engine.DiffResults(oldPath, newPath, format)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+		os.Exit(1)
+	}
+	os.Stdout.Write(text)
+}
+
+// extractFormatFlag removes "--format <name>" from args, returning the name
+// ("text" when absent).
+func extractFormatFlag(args []string) ([]string, string) {
+	out := make([]string, 0, len(args))
+	format := "text"
+	for i := 0; i < len(args); i++ {
+		if args[i] == "--format" && i+1 < len(args) {
+			format = args[i+1]
+			i++
+			continue
+		}
+		out = append(out, args[i])
+	}
+	return out, format
+}
+
+// extractDotFlag removes --dot from args, reporting whether it was present.
+func extractDotFlag(args []string) ([]string, bool) {
+	out := make([]string, 0, len(args))
+	dot := false
+	for _, arg := range args {
+		if arg == "--dot" {
+			dot = true
+			continue
+		}
+		out = append(out, arg)
+	}
+	return out, dot
+}
+
+func runSignalGraph(arch, path string, dot bool) {
+	cfg, err := config.Load(path)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Error loading config: %v\n", err)
+		os.Exit(1)
+	}
+
+	idx := indexer.NewWithConfig(cfg)
+	idx.JSONOutput = true // keep stdout to the graph
+	idx.SignalGraphArch = arch
+	idx.SignalGraphDOT = dot
+	if err := idx.Run(path); err != nil {
+		fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+		os.Exit(1)
+	}
+}
+
 func runClearPolicyCache(path string) {
 	cfg, err := config.Load(path)
 	if err != nil {
@@ -5,6 +5,8 @@ import (
 	"fmt"
 	"os"
 	"path/filepath"
+	"regexp"
+	"strings"
 )
 
 // Config is the top-level configuration for vhdl-lint
@@ -55,6 +57,104 @@ type LintConfig struct {
 
 	// IgnoreRegions enables -- vhdl_lint off/on comment support
 	IgnoreRegions bool `json:"ignoreRegions,omitempty"`
+
+	// OnlyModules restricts evaluation to these rule modules (e.g. "cdc", "signals")
+	OnlyModules []string `json:"onlyModules,omitempty"`
+
+	// SkipModules excludes these rule modules from evaluation
+	SkipModules []string `json:"skipModules,omitempty"`
+
+	// GenericPrefix is the required generic name prefix for generic_naming_convention (default "G_")
+	GenericPrefix string `json:"genericPrefix,omitempty"`
+
+	// NamingPatterns maps identifier classes (signal, port_in, port_out, constant, generic, instance, entity, type) to regexes names must match in full (naming_convention_custom)
+	NamingPatterns map[string]string `json:"namingPatterns,omitempty"`
+
+	// PortOrder sets the port group order for port_ordering (clock, reset, in, out, inout)
+	PortOrder []string `json:"portOrder,omitempty"`
+
+	// BitOrder sets the preferred vector range direction for mixed_bit_order ("downto" or "to")
+	BitOrder string `json:"bitOrder,omitempty"`
+
+	// ResetStyle sets the preferred reset style for reset_style_inconsistent ("sync" or "async"; empty = project majority)
+	ResetStyle string `json:"resetStyle,omitempty"`
+
+	// Profile selects a lint profile: "tb" swaps synthesis rules for testbench checks in testbench files
+	Profile string `json:"profile,omitempty"`
+
+	// Top names the top-level entities dead_code reachability starts from (empty = dead_code off)
+	Top []string `json:"top,omitempty"`
+
+	// TestbenchRoots also starts dead_code reachability from testbench entities
+	TestbenchRoots bool `json:"testbenchRoots,omitempty"`
+
+	// AppliesTo scopes rules to design contexts: rule name -> ["rtl"], ["tb"] or both
+	AppliesTo map[string][]string `json:"appliesTo,omitempty"`
+
+	// Fast runs only the prioritized fast rules (undriven/multi-driven, orphan architectures, unresolved dependencies)
+	Fast bool `json:"fast,omitempty"`
+
+	// ErrorBudget stops fast mode after the rule module that reaches this many errors (0 = no limit)
+	ErrorBudget int `json:"errorBudget,omitempty"`
+
+	// ShardWorkers runs intra-entity rule modules (latch, processes, sensitivity, style) on this many per-entity shards in parallel (0/1 = off)
+	ShardWorkers int `json:"shardWorkers,omitempty"`
+
+	// ModuleWorkers is the number of threads evaluating rule modules (0 = one per core, 1 = sequential)
+	ModuleWorkers int `json:"moduleWorkers,omitempty"`
+
+	// StrictBinding skips hierarchy checks on instances inside architectures that no configuration or instantiation selects
+	StrictBinding bool `json:"strictBinding,omitempty"`
+
+	// GenerateLimit caps the elaborated copies of one instance inside for-generates when counting instances (0 = 1024)
+	GenerateLimit int `json:"generateLimit,omitempty"`
+
+	// MaxLogicDepth is the number of chained combinational assignments deep_combinational_path allows (0 = 8)
+	MaxLogicDepth int `json:"maxLogicDepth,omitempty"`
+
+	// Target is the implementation technology, "asic" or "fpga" (empty = fpga); signal_init_in_rtl flags every initial value and metavalue_comparison reports errors for ASICs
+	Target string `json:"target,omitempty"`
+
+	// Vendor is the FPGA vendor whose primitives and libraries resolve ("xilinx", "intel", "lattice"), or "none" to report vendor primitives as vendor_primitive
+	Vendor string `json:"vendor,omitempty"`
+
+	// FsmEncodingStates is the state count above which fsm_missing_encoding wants an explicit encoding attribute (0 = 8)
+	FsmEncodingStates int `json:"fsmEncodingStates,omitempty"`
+
+	// ClockMonitors names entities, architectures or processes that sample clocks on purpose; clock_used_as_data skips them
+	ClockMonitors []string `json:"clockMonitors,omitempty"`
+
+	// BindingPatterns maps ready/valid roles ("valid", "ready") to port name globs used when port directions can't tell them apart
+	BindingPatterns map[string][]string `json:"bindingPatterns,omitempty"`
+
+	// BindingConfidence is the minimum confidence (0..1) for a name-based ready/valid binding; below it ambiguous_construct is reported (0 = 0.75)
+	BindingConfidence float64 `json:"bindingConfidence,omitempty"`
+
+	// AssertionDensity is the minimum number of assertions, PSL directives and verification tags per 100 lines in an architecture with detected constructs; below it low_assertion_density is reported (0 = 1)
+	AssertionDensity float64 `json:"assertionDensity,omitempty"`
+
+	// MinConfidence drops violations reported below this confidence ("low", "medium", "high"; empty = report all)
+	MinConfidence string `json:"minConfidence,omitempty"`
+
+	// CustomRules are project-specific Rhai checks the policy engine runs (needs the "scripting" build feature)
+	CustomRules []CustomRule `json:"customRules,omitempty"`
+
+	// Clocks declares the primary clocks; when set, every clocked process must use one (unknown_clock_source) and CDC domains follow the declarations
+	Clocks []ClockDecl `json:"clocks,omitempty"`
+}
+
+// CustomRule is a Rhai script whose check(input) function returns violations
+type CustomRule struct {
+	ID       string `json:"id"`
+	Script   string `json:"script"`
+	Severity string `json:"severity,omitempty"`
+}
+
+// ClockDecl declares a primary clock. Clocks sharing a Domain are synchronous to each other
+type ClockDecl struct {
+	Name   string `json:"name"`
+	Period string `json:"period,omitempty"`
+	Domain string `json:"domain,omitempty"`
 }
 
 // AnalysisConfig contains analysis options
@@ -170,12 +270,40 @@ func LoadFile(path string) (*Config, error) {
 		return nil, fmt.Errorf("parsing config file: %w", err)
 	}
 
+	if err := cfg.validateNamingPatterns(); err != nil {
+		return nil, fmt.Errorf("parsing config file: %w", err)
+	}
+
 	// Apply defaults for missing fields
 	cfg.applyDefaults()
 
 	return &cfg, nil
 }
 
+// namingClasses are the identifier classes lint.namingPatterns accepts
+var namingClasses = []string{"signal", "port_in", "port_out", "constant", "generic", "instance", "entity", "type"}
+
+// validateNamingPatterns rejects unknown classes and patterns that don't
+// compile, so a typo fails the load instead of silently skipping the check
+func (c *Config) validateNamingPatterns() error {
+	for class, pattern := range c.Lint.NamingPatterns {
+		known := false
+		for _, name := range namingClasses {
+			if strings.EqualFold(class, name) {
+				known = true
+				break
+			}
+		}
+		if !known {
+			return fmt.Errorf("unknown naming class %q (expected one of: %s)", class, strings.Join(namingClasses, ", "))
+		}
+		if _, err := regexp.Compile("^(?:" + pattern + ")$"); err != nil {
+			return fmt.Errorf("invalid naming pattern for %q: %w", class, err)
+		}
+	}
+	return nil
+}
+
 // applyDefaults fills in missing configuration with defaults
 func (c *Config) applyDefaults() {
 	if c.Standard == "" {
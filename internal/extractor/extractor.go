@@ -44,6 +44,7 @@ import (
 	"fmt"
 	"os"
 	"regexp"
+	"sort"
 	"strconv"
 	"strings"
 
@@ -81,25 +82,36 @@ type FileFacts struct {
 	ConstantDecls  []ConstantDeclaration        // Constant declarations with full info
 	Configurations []ConfigurationDeclaration   // Configuration declarations
 	Disconnections []DisconnectionSpecification // Disconnection specifications
+	ModeViews      []ModeView                   // VHDL-2019 mode view declarations
+	AttributeSpecs []AttributeSpecification     // Attribute specifications (fsm_encoding, keep, ...)
 	// Type system information (for filtering false positives) - LEGACY, use Types/ConstantDecls instead
 	EnumLiterals    []string // Enum literals from type declarations (e.g., S_IDLE, S_RUN)
 	Constants       []string // Constants from constant declarations (names only)
 	SharedVariables []string // Shared variable names (for declared identifier filtering)
 	// Concurrent statements (outside processes)
 	ConcurrentAssignments []ConcurrentAssignment // Concurrent signal assignments
+	TristateAssignments   []TristateAssignment   // Assignments driving 'Z' (concurrent or in processes)
 	// Semantic analysis
 	ClockDomains []ClockDomain
 	SignalUsages []SignalUsage
 	ResetInfos   []ResetInfo
 	// Advanced analysis for security/power/correctness
 	Comparisons   []Comparison   // Comparisons for trojan detection
-	ArithmeticOps []ArithmeticOp // Expensive operations for power analysis
+	ArithmeticOps []ArithmeticOp // Arithmetic operations for power and width analysis
 	SignalDeps    []SignalDep    // Signal dependencies for loop detection
 	CDCCrossings  []CDCCrossing  // Clock domain crossing detection
 	// Verification contract
 	VerificationBlocks    []VerificationBlock
 	VerificationTags      []VerificationTag
 	VerificationTagErrors []VerificationTagError
+	PSLDirectives         []PSLDirective
+	Assertions            []Assertion
+	// Inline --@waive comments
+	Waivers []Waiver
+	// VHDL-2008 constructs the policy engine gates on the configured standard
+	LanguageFeatures []LanguageFeature
+	// Leading comment block is an FPGA vendor's copyright header
+	VendorHeader bool
 }
 
 // ClockDomain represents a clock and the signals it drives
@@ -138,6 +150,8 @@ type Process struct {
 	Label           string   // Optional label
 	SensitivityList []string // Signals in sensitivity list (or "all" for VHDL-2008)
 	Line            int
+	Column          int // 1-based columns of the label on Line (end exclusive), 0 if unknown
+	EndColumn       int
 	InArch          string // Which architecture this process belongs to
 	// Semantic info
 	IsSequential    bool     // Has clock edge (rising_edge/falling_edge)
@@ -155,6 +169,8 @@ type Process struct {
 	ProcedureCalls []ProcedureCall
 	FunctionCalls  []FunctionCall
 	WaitStatements []WaitStatement
+	Assignments    []SequentialAssignment // Signal assignments in source order
+	IfStatements   []IfStatement          // If statements, outer before nested
 }
 
 // ConcurrentAssignment represents a concurrent signal assignment (outside processes)
@@ -164,7 +180,9 @@ type Process struct {
 //   - Selected: with sel select sig <= a when "00", b when others;
 type ConcurrentAssignment struct {
 	Target        string   // Signal being assigned (LHS)
+	TargetFull    string   // Full target text including slices/fields (e.g. "bus(3 downto 0)")
 	ReadSignals   []string // Signals being read (RHS)
+	Value         string   // RHS text for plain "target <= value;" (simple kind, no delays)
 	Line          int
 	InArch        string // Which architecture contains this assignment
 	Kind          string // "simple", "conditional", "selected"
@@ -172,11 +190,21 @@ type ConcurrentAssignment struct {
 	GenerateLabel string // Label of the containing generate block
 }
 
+// TristateAssignment is a signal assignment whose value contains a 'Z'
+// literal ('Z', "ZZZZ", (others => 'Z')), i.e. a tri-state driver
+type TristateAssignment struct {
+	Target    string // Signal being assigned (base name)
+	Value     string // RHS text
+	Line      int
+	InArch    string // Which architecture contains this assignment
+	InProcess string // Label of the containing process ("" if concurrent or unlabeled)
+}
+
 // Comparison represents a comparison operation for trojan/trigger detection
 // Tracks comparisons against literals, especially large "magic" values
 type Comparison struct {
 	LeftOperand  string // Signal or expression on left
-	Operator     string // =, /=, <, >, <=, >=
+	Operator     string // =, /=, <, >, <=, >=, or std_match for std_match(a, b) calls
 	RightOperand string // Signal, literal, or expression on right
 	IsLiteral    bool   // True if right operand is a literal value
 	LiteralValue string // The literal value if IsLiteral
@@ -187,11 +215,11 @@ type Comparison struct {
 	InArch       string
 }
 
-// ArithmeticOp represents an expensive arithmetic operation for power analysis
+// ArithmeticOp represents an arithmetic operation for power and width analysis
 type ArithmeticOp struct {
-	Operator    string   // *, /, mod, rem, **
+	Operator    string   // +, -, *, /, mod, rem, **
 	Operands    []string // Input signals/expressions
-	Result      string   // Output signal
+	Result      string   // Signal assigned by the enclosing assignment, if any
 	IsGuarded   bool     // True if inputs are gated by enable
 	GuardSignal string   // The enable/valid signal if guarded
 	Line        int
@@ -254,10 +282,12 @@ type GenerateStatement struct {
 
 // Entity represents a VHDL entity declaration
 type Entity struct {
-	Name     string
-	Line     int
-	Ports    []Port
-	Generics []GenericDecl
+	Name      string
+	Line      int
+	Column    int // 1-based columns of the name on Line (end exclusive), 0 if unknown
+	EndColumn int
+	Ports     []Port
+	Generics  []GenericDecl
 }
 
 // Architecture represents a VHDL architecture body
@@ -265,12 +295,16 @@ type Architecture struct {
 	Name       string
 	EntityName string
 	Line       int
+	Column     int // 1-based columns of the name on Line (end exclusive), 0 if unknown
+	EndColumn  int
 }
 
 // Package represents a VHDL package declaration
 type Package struct {
-	Name string
-	Line int
+	Name      string
+	Line      int
+	Column    int // 1-based columns of the name on Line (end exclusive), 0 if unknown
+	EndColumn int
 }
 
 // Component represents a component declaration or instantiation
@@ -288,9 +322,12 @@ type Component struct {
 type Instance struct {
 	Name       string            // Instance label (e.g., "u_cpu")
 	Target     string            // Target entity/component (e.g., "work.cpu")
+	TargetArch string            // Explicit architecture (e.g., "rtl" in "entity work.cpu(rtl)")
 	PortMap    map[string]string // Formal port -> actual signal mapping
 	GenericMap map[string]string // Formal generic -> actual value mapping
 	Line       int
+	Column     int // 1-based columns of the label on Line (end exclusive), 0 if unknown
+	EndColumn  int
 	InArch     string // Which architecture contains this instance
 	// Structured association elements for map aspects
 	Associations []Association
@@ -306,6 +343,14 @@ type CaseStatement struct {
 	InProcess  string // Which process contains this case statement
 	InArch     string // Which architecture
 	IsComplete bool   // true if HasOthers or all possible values covered
+	Arms       []CaseArm
+}
+
+// CaseArm is one alternative of a case statement.
+type CaseArm struct {
+	Choices []string // Choices of the alternative ("others" for the catch-all)
+	Line    int
+	Branch  string // Path element ("case@<byte>") the alternative adds to SequentialAssignment.Branch
 }
 
 // Dependency represents a use/library clause or instantiation
@@ -318,10 +363,13 @@ type Dependency struct {
 
 // Signal represents a signal declaration
 type Signal struct {
-	Name     string
-	Type     string
-	Line     int
-	InEntity string // Which entity/arch it belongs to
+	Name      string
+	Type      string
+	Default   string // Initial value expression (":= ..."), empty if none
+	Line      int
+	Column    int // 1-based columns of the name on Line (end exclusive), 0 if unknown
+	EndColumn int
+	InEntity  string // Which entity/arch it belongs to
 }
 
 // Port represents an entity port
@@ -331,7 +379,11 @@ type Port struct {
 	Type      string
 	Default   string
 	Line      int
+	Column    int // 1-based columns of the name on Line (end exclusive), 0 if unknown
+	EndColumn int
 	InEntity  string // Which entity this port belongs to
+	Comment   string // Trailing "--" comment on the declaration line
+	View      string // Mode view name when Direction is "view" (VHDL-2019)
 }
 
 // GenericDecl represents a generic declaration (entity/component)
@@ -411,6 +463,35 @@ type WaitStatement struct {
 	Line      int
 }
 
+// SequentialAssignment is one signal assignment inside a process, in source order.
+// Branch identifies the enclosing statement list: "" is the process body and
+// each if/elsif/else branch, case alternative or loop body appends a path
+// element, so an assignment whose branch is a prefix of another's executes
+// whenever the other does.
+type SequentialAssignment struct {
+	Target  string // Full target text (e.g. "r(0)", "rec.field")
+	Signal  string // Base signal name
+	Value   string // RHS text for plain "target <= value;" (no delays or waveform list)
+	Line    int
+	Branch  string // Enclosing branch path, e.g. "if@120.1/case@310.0"
+	Segment int    // Number of wait statements executed before this assignment
+}
+
+// IfStatement is one if/elsif/else chain inside a process.
+type IfStatement struct {
+	Branch  string  // Branch path the statement sits in, as in SequentialAssignment.Branch
+	Line    int
+	Arms    []IfArm // Branches in source order; the last is the else when HasElse
+	HasElse bool
+}
+
+// IfArm is one branch of an if statement.
+type IfArm struct {
+	Line      int
+	Branch    string // Path element ("if@<byte>.<index>") the branch adds to SequentialAssignment.Branch
+	Condition string // Condition text; empty for the else branch
+}
+
 // VerificationBlock represents a verification anchor block inside an architecture
 type VerificationBlock struct {
 	Label     string
@@ -437,6 +518,40 @@ type VerificationTagError struct {
 	InArch  string
 }
 
+// Waiver represents an inline --@waive comment
+type Waiver struct {
+	Rules      []string // Lower-cased rule names from rule=/rules=
+	Expires    string   // expires= date (YYYY-MM-DD), empty if it never expires
+	Line       int      // Line of the comment
+	TargetLine int      // Line it waives: its own when it trails code, else the next code line
+	Raw        string
+}
+
+// LanguageFeature records one use of a version-dependent construct
+type LanguageFeature struct {
+	Feature string // "block_comment" or "matching_case"
+	Line    int
+}
+
+// PSLDirective represents a concurrent PSL assert/assume/cover/restrict directive
+type PSLDirective struct {
+	Kind    string   // "assert", "assume", "cover" or "restrict"
+	Label   string   // Statement label, empty if unlabeled
+	Signals []string // Signals the property reads, sorted
+	Line    int
+	InArch  string
+}
+
+// Assertion represents a plain (non-PSL) assert or report statement
+type Assertion struct {
+	Kind        string // "assert" or "report"
+	Severity    string // Lowercase severity clause, empty if absent
+	Line        int
+	InArch      string
+	InProcess   string // Enclosing process label
+	ProcessLine int    // Enclosing process line, 0 for concurrent assertions
+}
+
 // =============================================================================
 // TYPE SYSTEM TYPES
 // =============================================================================
@@ -458,6 +573,7 @@ type TypeDeclaration struct {
 	// Array-specific
 	ElementType   string   // For arrays: element type
 	IndexTypes    []string // For arrays: index type(s) or range(s)
+	IndexRange    string   // For arrays: first index constraint, e.g. "0 to DEPTH-1"
 	Unconstrained bool     // For arrays: true if "range <>"
 	// Physical-specific (time, etc.)
 	BaseUnit string // For physical: base unit name
@@ -496,6 +612,9 @@ type FunctionDeclaration struct {
 	Line       int
 	InPackage  string // Package containing this function
 	InArch     string // Architecture if local function
+
+	// Names read in the body that are neither parameters nor local declarations
+	ReadSignals []string
 }
 
 // ProcedureDeclaration represents a VHDL procedure declaration or body
@@ -506,6 +625,9 @@ type ProcedureDeclaration struct {
 	Line       int
 	InPackage  string // Package containing this procedure
 	InArch     string // Architecture if local procedure
+
+	// Signal assignment targets in the body (base names)
+	AssignedSignals []string
 }
 
 // SubprogramParameter represents a parameter in a function or procedure
@@ -529,12 +651,43 @@ type ConstantDeclaration struct {
 	InArch    string // Architecture if local constant
 }
 
+// ModeView represents a VHDL-2019 mode view declaration
+// Captures: view name of record_type is element : mode; ... end view;
+type ModeView struct {
+	Name      string
+	Type      string // Record type the view applies to
+	Elements  []ModeViewElement
+	Line      int
+	InPackage string // Package containing this view
+	InArch    string // Architecture if local view
+}
+
+// ModeViewElement is the mode of one record element in a mode view
+type ModeViewElement struct {
+	Name      string
+	Direction string // "in", "out", "inout", "buffer", or "view" for a nested view
+	View      string // Nested view name when Direction is "view"
+}
+
+// AttributeSpecification represents a VHDL attribute specification
+// Captures: attribute name of target, ... : class is value;
+type AttributeSpecification struct {
+	Name      string
+	Targets   []string // Named items, or "others"/"all"
+	Class     string   // Entity class ("signal", "type", "entity", ...)
+	Value     string   // Value expression text
+	Line      int
+	InPackage string // Package containing this specification
+	InArch    string // Architecture if local specification
+}
+
 // ConfigurationDeclaration represents a VHDL configuration declaration
 type ConfigurationDeclaration struct {
-	Name       string
-	EntityName string
-	Bindings   []ConfigurationBinding
-	Line       int
+	Name         string
+	EntityName   string
+	Architecture string // Architecture selected by the top-level block configuration
+	Bindings     []ConfigurationBinding
+	Line         int
 }
 
 // ConfigurationBinding represents a binding inside a configuration declaration
@@ -599,6 +752,10 @@ func (e *Extractor) Extract(filePath string) (FileFacts, error) {
 	// Detect clock domain crossings
 	facts.CDCCrossings = DetectCDCCrossings(&facts)
 	e.extractVerificationTags(content, &facts)
+	extractWaivers(content, &facts)
+	facts.VendorHeader = HasVendorHeader(content)
+	extractLanguageFeatures(tree.RootNode(), &facts)
+	e.extractTristateAssignments(tree.RootNode(), content, &facts, "", "")
 
 	return facts, nil
 }
@@ -708,13 +865,22 @@ func (e *Extractor) walkTreeWithPkg(node *sitter.Node, source []byte, facts *Fil
 		if dep.Target != "" {
 			facts.Dependencies = append(facts.Dependencies, dep)
 		}
-	case "psl_property_declaration", "psl_sequence_declaration", "psl_cover_statement", "psl_assume_statement", "psl_restrict_statement", "psl_default_clock":
+	case "psl_property_declaration", "psl_sequence_declaration", "psl_default_clock":
 		e.extractPSLSignalReads(node, source, facts, declaredSignals)
+	case "psl_cover_statement", "psl_assume_statement", "psl_restrict_statement":
+		signals := e.extractPSLSignalReads(node, source, facts, declaredSignals)
+		kind := strings.TrimSuffix(strings.TrimPrefix(node.Type(), "psl_"), "_statement")
+		facts.PSLDirectives = append(facts.PSLDirectives, pslDirective(node, source, kind, signals, archContext))
 	case "assert_statement":
 		// PSL assert statements are parsed as assert_statement with PSL expressions inside.
 		if hasPSLChild(node) {
-			e.extractPSLSignalReads(node, source, facts, declaredSignals)
+			signals := e.extractPSLSignalReads(node, source, facts, declaredSignals)
+			facts.PSLDirectives = append(facts.PSLDirectives, pslDirective(node, source, "assert", signals, archContext))
+		} else {
+			facts.Assertions = append(facts.Assertions, assertion(node, source, "assert", archContext))
 		}
+	case "report_statement":
+		facts.Assertions = append(facts.Assertions, assertion(node, source, "report", archContext))
 
 	case "component_instantiation":
 		comp := e.extractComponentInst(node, source)
@@ -798,6 +964,13 @@ func (e *Extractor) walkTreeWithPkg(node *sitter.Node, source []byte, facts *Fil
 		pd := e.extractProcedureDeclaration(node, source, pkgContext, archContext)
 		facts.Procedures = append(facts.Procedures, pd)
 
+	case "view_declaration":
+		// VHDL-2019 mode view with its element modes
+		facts.ModeViews = append(facts.ModeViews, e.extractModeView(node, source, pkgContext, archContext))
+
+	case "attribute_specification":
+		facts.AttributeSpecs = append(facts.AttributeSpecs, e.extractAttributeSpecification(node, source, pkgContext, archContext))
+
 	case "constant_declaration":
 		// Extract full constant declarations with context
 		constDecls := e.extractConstantDeclarations(node, source, pkgContext, archContext)
@@ -1031,6 +1204,7 @@ func (e *Extractor) extractEntity(node *sitter.Node, source []byte) Entity {
 	// Find the name field
 	if nameNode := node.ChildByFieldName("name"); nameNode != nil {
 		entity.Name = nameNode.Content(source)
+		entity.Column, entity.EndColumn = nodeColumns(nameNode, entity.Line)
 	}
 	if entity.Name != "" {
 		entity.Generics = e.extractGenericDeclsFromNode(node, source, entity.Name, "")
@@ -1046,6 +1220,7 @@ func (e *Extractor) extractArchitecture(node *sitter.Node, source []byte) Archit
 
 	if nameNode := node.ChildByFieldName("name"); nameNode != nil {
 		arch.Name = nameNode.Content(source)
+		arch.Column, arch.EndColumn = nodeColumns(nameNode, arch.Line)
 	}
 	if entityNode := node.ChildByFieldName("entity"); entityNode != nil {
 		arch.EntityName = entityNode.Content(source)
@@ -1061,11 +1236,37 @@ func (e *Extractor) extractPackage(node *sitter.Node, source []byte) Package {
 
 	if nameNode := node.ChildByFieldName("name"); nameNode != nil {
 		pkg.Name = nameNode.Content(source)
+		pkg.Column, pkg.EndColumn = nodeColumns(nameNode, pkg.Line)
 	}
 
 	return pkg
 }
 
+// nodeColumns returns the 1-based start and end (exclusive) columns of n when
+// it starts on line, else 0, 0.
+func nodeColumns(n *sitter.Node, line int) (int, int) {
+	if n == nil || int(n.StartPoint().Row)+1 != line {
+		return 0, 0
+	}
+	return int(n.StartPoint().Column) + 1, int(n.EndPoint().Column) + 1
+}
+
+// identifierColumns locates the identifier spelling name below n on line.
+func identifierColumns(n *sitter.Node, source []byte, name string, line int) (int, int) {
+	if n == nil {
+		return 0, 0
+	}
+	if n.Type() == "identifier" && strings.EqualFold(n.Content(source), name) {
+		return nodeColumns(n, line)
+	}
+	for i := 0; i < int(n.ChildCount()); i++ {
+		if column, end := identifierColumns(n.Child(i), source, name, line); column > 0 {
+			return column, end
+		}
+	}
+	return 0, 0
+}
+
 // extractConfigurationDeclaration extracts a configuration declaration (name + entity)
 func (e *Extractor) extractConfigurationDeclaration(node *sitter.Node, source []byte) ConfigurationDeclaration {
 	cfg := ConfigurationDeclaration{
@@ -1082,6 +1283,16 @@ func (e *Extractor) extractConfigurationDeclaration(node *sitter.Node, source []
 		cfg.EntityName = parts[len(parts)-1]
 	}
 
+	for i := 0; i < int(node.ChildCount()); i++ {
+		child := node.Child(i)
+		if child != nil && child.Type() == "block_configuration" {
+			if labelNode := child.ChildByFieldName("label"); labelNode != nil {
+				cfg.Architecture = strings.TrimSpace(labelNode.Content(source))
+			}
+			break
+		}
+	}
+
 	cfg.Bindings = e.extractConfigurationBindings(node, source)
 
 	return cfg
@@ -1219,7 +1430,9 @@ func (e *Extractor) extractDisconnectionSpecification(node *sitter.Node, source
 	return spec
 }
 
-func (e *Extractor) extractPSLSignalReads(node *sitter.Node, source []byte, facts *FileFacts, declaredSignals map[string]bool) {
+// extractPSLSignalReads records the signals a PSL construct reads as usages
+// and returns them sorted.
+func (e *Extractor) extractPSLSignalReads(node *sitter.Node, source []byte, facts *FileFacts, declaredSignals map[string]bool) []string {
 	readSet := make(map[string]bool)
 	for i := 0; i < int(node.ChildCount()); i++ {
 		child := node.Child(i)
@@ -1232,6 +1445,7 @@ func (e *Extractor) extractPSLSignalReads(node *sitter.Node, source []byte, fact
 		}
 		e.extractReadsFromNode(child, source, readSet, false, declaredSignals, nil)
 	}
+	signals := make([]string, 0, len(readSet))
 	for sig := range readSet {
 		facts.SignalUsages = append(facts.SignalUsages, SignalUsage{
 			Signal: sig,
@@ -1239,7 +1453,56 @@ func (e *Extractor) extractPSLSignalReads(node *sitter.Node, source []byte, fact
 			InPSL:  true,
 			Line:   int(node.StartPoint().Row) + 1,
 		})
+		signals = append(signals, sig)
+	}
+	sort.Strings(signals)
+	return signals
+}
+
+// pslDirective builds the directive for a PSL assert/assume/cover/restrict
+// statement. The label is the identifier before the leading ':'.
+func pslDirective(node *sitter.Node, source []byte, kind string, signals []string, archContext string) PSLDirective {
+	directive := PSLDirective{
+		Kind:    kind,
+		Signals: signals,
+		Line:    int(node.StartPoint().Row) + 1,
+		InArch:  archContext,
+	}
+	if node.ChildCount() > 1 && node.Child(0).Type() == "identifier" && node.Child(1).Type() == ":" {
+		directive.Label = node.Child(0).Content(source)
+	}
+	return directive
+}
+
+// assertion builds the fact for a plain assert or report statement, linking
+// it to the enclosing process (if any) by label and line.
+func assertion(node *sitter.Node, source []byte, kind, archContext string) Assertion {
+	a := Assertion{
+		Kind:     kind,
+		Severity: assertionSeverity(node.Content(source)),
+		Line:     int(node.StartPoint().Row) + 1,
+		InArch:   archContext,
+	}
+	for parent := node.Parent(); parent != nil; parent = parent.Parent() {
+		if parent.Type() == "process_statement" {
+			if labelNode := parent.ChildByFieldName("label"); labelNode != nil {
+				a.InProcess = labelNode.Content(source)
+			}
+			a.ProcessLine = int(parent.StartPoint().Row) + 1
+			break
+		}
+	}
+	return a
+}
+
+// assertionSeverity returns the lowercase severity level closing an
+// assert/report statement ("severity failure;"), or "" without one.
+func assertionSeverity(content string) string {
+	fields := strings.Fields(strings.TrimSuffix(strings.TrimSpace(content), ";"))
+	if len(fields) < 2 || !strings.EqualFold(fields[len(fields)-2], "severity") {
+		return ""
 	}
+	return strings.ToLower(fields[len(fields)-1])
 }
 
 func hasPSLChild(node *sitter.Node) bool {
@@ -1463,6 +1726,7 @@ func (e *Extractor) extractInstance(node *sitter.Node, source []byte, context st
 	// Instance label
 	if labelNode := node.ChildByFieldName("label"); labelNode != nil {
 		inst.Name = labelNode.Content(source)
+		inst.Column, inst.EndColumn = nodeColumns(labelNode, inst.Line)
 	}
 
 	// Target - either component name or entity reference
@@ -1480,6 +1744,9 @@ func (e *Extractor) extractInstance(node *sitter.Node, source []byte, context st
 			inst.Target = entityNode.Content(source)
 		}
 	}
+	if archNode := node.ChildByFieldName("architecture"); archNode != nil {
+		inst.TargetArch = strings.TrimSpace(archNode.Content(source))
+	}
 
 	// Extract generic_map_aspect and port_map_aspect
 	for i := 0; i < int(node.ChildCount()); i++ {
@@ -1736,6 +2003,12 @@ func (e *Extractor) extractConcurrentAssignment(node *sitter.Node, source []byte
 	if sig, ok := e.extractAssignmentTarget(node, source); ok {
 		ca.Target = sig
 	}
+	if targetNode := node.ChildByFieldName("target"); targetNode != nil {
+		ca.TargetFull = strings.TrimSpace(targetNode.Content(source))
+	}
+	if ca.Kind == "simple" {
+		ca.Value = extractAssignmentValue(node.Content(source))
+	}
 
 	// Extract reads from the RHS (everything after the assignment target)
 	readSet := make(map[string]bool)
@@ -1752,6 +2025,48 @@ func (e *Extractor) extractConcurrentAssignment(node *sitter.Node, source []byte
 	return ca
 }
 
+// extractAssignmentValue returns the RHS of a plain "target <= value;" assignment.
+// Returns "" for waveforms with delays, several elements or conditions, since
+// their value isn't a single expression.
+func extractAssignmentValue(content string) string {
+	var b strings.Builder
+	for _, line := range strings.Split(content, "\n") {
+		if idx := strings.Index(line, "--"); idx != -1 {
+			line = line[:idx]
+		}
+		b.WriteString(line)
+		b.WriteString(" ")
+	}
+	parts := strings.SplitN(b.String(), "<=", 2)
+	if len(parts) != 2 {
+		return ""
+	}
+	rhs := strings.TrimSpace(parts[1])
+	rhs = strings.TrimSpace(strings.TrimSuffix(rhs, ";"))
+	lower := " " + strings.ToLower(rhs) + " "
+	for _, kw := range []string{" after ", " when ", " transport ", " inertial ", " reject "} {
+		if strings.Contains(lower, kw) {
+			return ""
+		}
+	}
+	depth := 0
+	inString := false
+	for _, r := range rhs {
+		switch {
+		case r == '"':
+			inString = !inString
+		case inString:
+		case r == '(':
+			depth++
+		case r == ')':
+			depth--
+		case r == ',' && depth == 0:
+			return ""
+		}
+	}
+	return rhs
+}
+
 // extractBaseSignal extracts the base signal from selected_name or indexed_name
 // For "rec.field" returns "rec"
 // For "arr(i)" returns "arr"
@@ -2035,8 +2350,95 @@ func (e *Extractor) extractSharedVariableNames(node *sitter.Node, source []byte)
 	return names
 }
 
+// extractModeView extracts a VHDL-2019 mode view declaration with its element modes
+// Example: view master_v of bus_rec is addr, wdata : out; rdata : in; end view;
+func (e *Extractor) extractModeView(node *sitter.Node, source []byte, pkgContext, archContext string) ModeView {
+	view := ModeView{
+		Line:      int(node.StartPoint().Row) + 1,
+		InPackage: pkgContext,
+		InArch:    archContext,
+	}
+	if nameNode := node.ChildByFieldName("name"); nameNode != nil {
+		view.Name = nameNode.Content(source)
+	}
+	if typeNode := node.ChildByFieldName("type"); typeNode != nil {
+		view.Type = strings.TrimSpace(typeNode.Content(source))
+	}
+
+	for i := 0; i < int(node.NamedChildCount()); i++ {
+		child := node.NamedChild(i)
+		if child.Type() != "view_element" {
+			continue
+		}
+		direction := ""
+		nested := ""
+		if dirNode := child.ChildByFieldName("direction"); dirNode != nil {
+			direction = strings.ToLower(dirNode.Content(source))
+		} else if viewNode := child.ChildByFieldName("view"); viewNode != nil {
+			direction = "view"
+			nested = modeViewName(viewNode, source)
+		}
+		namesNode := child.ChildByFieldName("names")
+		if namesNode == nil {
+			continue
+		}
+		for _, name := range collectIdentifierList(namesNode, source) {
+			view.Elements = append(view.Elements, ModeViewElement{
+				Name:      name,
+				Direction: direction,
+				View:      nested,
+			})
+		}
+	}
+
+	return view
+}
+
+// modeViewName returns the view named by a mode_view_indication
+func modeViewName(node *sitter.Node, source []byte) string {
+	if nameNode := node.ChildByFieldName("name"); nameNode != nil {
+		return strings.TrimSpace(nameNode.Content(source))
+	}
+	return ""
+}
+
 // extractConstantDeclarations extracts full constant declarations with type and context
 // Example: constant WIDTH : integer := 8; -> returns [{Name: "WIDTH", Type: "integer", Value: "8"}]
+// extractAttributeSpecification extracts "attribute name of targets : class is value;".
+// The targets sit between "of" and ":", the entity class between ":" and "is".
+func (e *Extractor) extractAttributeSpecification(node *sitter.Node, source []byte, pkgContext, archContext string) AttributeSpecification {
+	spec := AttributeSpecification{
+		Line:      int(node.StartPoint().Row) + 1,
+		InPackage: pkgContext,
+		InArch:    archContext,
+	}
+	if nameNode := node.ChildByFieldName("name"); nameNode != nil {
+		spec.Name = nameNode.Content(source)
+	}
+	if valueNode := node.ChildByFieldName("value"); valueNode != nil {
+		spec.Value = strings.TrimSpace(valueNode.Content(source))
+	}
+	part := ""
+	for i := 0; i < int(node.ChildCount()); i++ {
+		child := node.Child(i)
+		text := strings.TrimSpace(child.Content(source))
+		lower := strings.ToLower(text)
+		switch {
+		case part == "" && lower == "of":
+			part = "targets"
+		case part == "targets" && lower == ":":
+			part = "class"
+		case part == "class" && lower == "is":
+			part = "value"
+		case part == "targets" && text != "," && !strings.HasPrefix(text, "["):
+			spec.Targets = append(spec.Targets, text)
+		case part == "class" && spec.Class == "":
+			spec.Class = lower
+		}
+	}
+	return spec
+}
+
 func (e *Extractor) extractConstantDeclarations(node *sitter.Node, source []byte, pkgContext, archContext string) []ConstantDeclaration {
 	var decls []ConstantDeclaration
 	line := int(node.StartPoint().Row) + 1
@@ -2215,13 +2617,21 @@ func (e *Extractor) extractSignals(node *sitter.Node, source []byte, context str
 	if sigType == "" {
 		sigType = typeIdent
 	}
+	initial := ""
+	if valueNode := node.ChildByFieldName("value"); valueNode != nil {
+		initial = strings.Join(strings.Fields(valueNode.Content(source)), " ")
+	}
 
 	for _, name := range names {
+		column, endColumn := identifierColumns(node, source, name, line)
 		signals = append(signals, Signal{
-			Name:     name,
-			Type:     sigType,
-			Line:     line,
-			InEntity: context,
+			Name:      name,
+			Type:      sigType,
+			Default:   initial,
+			Line:      line,
+			Column:    column,
+			EndColumn: endColumn,
+			InEntity:  context,
 		})
 	}
 
@@ -2599,13 +3009,25 @@ func (e *Extractor) extractPorts(node *sitter.Node, source []byte) []Port {
 		direction = strings.ToLower(dirNode.Content(source))
 	}
 
+	// VHDL-2019 mode view port (bus : view master_view of bus_rec): the view,
+	// not a port mode, gives each element its direction
+	view := ""
+	viewType := ""
+	if viewNode := node.ChildByFieldName("view"); viewNode != nil {
+		direction = "view"
+		view = modeViewName(viewNode, source)
+		if typeNode := viewNode.ChildByFieldName("type"); typeNode != nil {
+			viewType = strings.TrimSpace(typeNode.Content(source))
+		}
+	}
+
 	// Collect names and type from grammar fields first
 	var names []string
 	if namesNode := node.ChildByFieldName("names"); namesNode != nil {
 		names = append(names, collectIdentifierList(namesNode, source)...)
 	}
 
-	var portType string
+	portType := viewType
 	if typeNode := node.ChildByFieldName("type"); typeNode != nil {
 		portType = strings.TrimSpace(typeNode.Content(source))
 	}
@@ -2698,21 +3120,47 @@ func (e *Extractor) extractPorts(node *sitter.Node, source []byte) []Port {
 		names = names[:len(names)-1]
 	}
 
+	comment := trailingComment(node, source)
+
 	// Create a port for each name
 	var ports []Port
 	for _, name := range names {
+		column, endColumn := identifierColumns(node, source, name, line)
 		ports = append(ports, Port{
 			Name:      name,
 			Direction: direction,
 			Type:      portType,
 			Default:   defaultValue,
 			Line:      line,
+			Column:    column,
+			EndColumn: endColumn,
+			Comment:   comment,
+			View:      view,
 		})
 	}
 
 	return ports
 }
 
+// trailingComment returns the "--" comment closing the line node ends on,
+// when only ";" or ")" separate the two.
+func trailingComment(node *sitter.Node, source []byte) string {
+	end := int(node.EndByte())
+	if end > len(source) {
+		return ""
+	}
+	lineEnd := end
+	for lineEnd < len(source) && source[lineEnd] != '\n' {
+		lineEnd++
+	}
+	rest := string(source[end:lineEnd])
+	idx := strings.Index(rest, "--")
+	if idx < 0 || strings.Trim(rest[:idx], " \t\r;)") != "" {
+		return ""
+	}
+	return strings.TrimSpace(rest[idx+2:])
+}
+
 func (e *Extractor) extractProcess(node *sitter.Node, source []byte, context string, declaredSignals map[string]bool) Process {
 	proc := Process{
 		Line:   int(node.StartPoint().Row) + 1,
@@ -2722,6 +3170,7 @@ func (e *Extractor) extractProcess(node *sitter.Node, source []byte, context str
 	// Prefer grammar fields for label and sensitivity list
 	if labelNode := node.ChildByFieldName("label"); labelNode != nil {
 		proc.Label = labelNode.Content(source)
+		proc.Column, proc.EndColumn = nodeColumns(labelNode, proc.Line)
 	}
 	if sensNode := node.ChildByFieldName("sensitivity"); sensNode != nil {
 		proc.SensitivityList = e.extractSensitivityList(sensNode, source)
@@ -2782,6 +3231,7 @@ func (e *Extractor) analyzeProcessSemantics(node *sitter.Node, source []byte, pr
 	var vars []VariableDecl
 	e.collectProcessVariables(node, source, variableSet, &vars)
 	proc.Variables = vars
+	proc.Assignments, proc.IfStatements = e.extractOrderedAssignments(node, source, variableSet)
 
 	var walk func(n *sitter.Node, inCondition bool)
 	walk = func(n *sitter.Node, inCondition bool) {
@@ -2948,6 +3398,115 @@ func (e *Extractor) analyzeProcessSemantics(node *sitter.Node, source []byte, pr
 	}
 }
 
+// extractOrderedAssignments records signal assignments in source order with the
+// branch they execute in, plus the if statements whose branches those paths
+// name. if_statement branches are flat siblings in the tree (the elsif/else
+// keywords are hidden), so a branch starts at each condition node and at an
+// "else" keyword found in the source between statements.
+func (e *Extractor) extractOrderedAssignments(node *sitter.Node, source []byte, variableSet map[string]bool) ([]SequentialAssignment, []IfStatement) {
+	var assigns []SequentialAssignment
+	var ifs []IfStatement
+	segment := 0
+
+	var walk func(n *sitter.Node, branch string)
+	walk = func(n *sitter.Node, branch string) {
+		if n == nil {
+			return
+		}
+		switch n.Type() {
+		case "sequential_signal_assignment":
+			sig, ok := e.extractAssignmentTarget(n, source)
+			targetNode := n.ChildByFieldName("target")
+			if !ok || targetNode == nil || variableSet[strings.ToLower(sig)] {
+				return
+			}
+			target := strings.TrimSpace(targetNode.Content(source))
+			if strings.HasPrefix(target, "(") {
+				// Aggregate targets write several signals at once
+				return
+			}
+			assigns = append(assigns, SequentialAssignment{
+				Target:  target,
+				Signal:  sig,
+				Value:   extractAssignmentValue(n.Content(source)),
+				Line:    int(n.StartPoint().Row) + 1,
+				Branch:  branch,
+				Segment: segment,
+			})
+			return
+		case "wait_statement":
+			segment++
+			return
+		case "if_statement":
+			idx := -1
+			prevEnd := n.StartByte()
+			at := len(ifs)
+			ifs = append(ifs, IfStatement{Branch: branch, Line: int(n.StartPoint().Row) + 1})
+			for i := 0; i < int(n.ChildCount()); i++ {
+				child := n.Child(i)
+				newArm := false
+				if child.Type() == "condition" {
+					idx++
+					newArm = true
+				} else if child.IsNamed() && idx >= 0 && hasElseKeyword(source[prevEnd:child.StartByte()]) {
+					idx++
+					newArm = true
+					ifs[at].HasElse = true
+				}
+				if newArm {
+					arm := IfArm{
+						Line:   int(child.StartPoint().Row) + 1,
+						Branch: fmt.Sprintf("if@%d.%d", n.StartByte(), idx),
+					}
+					if child.Type() == "condition" {
+						arm.Condition = child.Content(source)
+					}
+					ifs[at].Arms = append(ifs[at].Arms, arm)
+				}
+				prevEnd = child.EndByte()
+				if child.Type() == "condition" || idx < 0 {
+					continue
+				}
+				walk(child, fmt.Sprintf("%s/if@%d.%d", branch, n.StartByte(), idx))
+			}
+			return
+		case "case_alternative", "loop_statement":
+			kind := "case"
+			if n.Type() == "loop_statement" {
+				kind = "loop"
+			}
+			path := fmt.Sprintf("%s/%s@%d", branch, kind, n.StartByte())
+			for i := 0; i < int(n.ChildCount()); i++ {
+				walk(n.Child(i), path)
+			}
+			return
+		}
+		for i := 0; i < int(n.ChildCount()); i++ {
+			walk(n.Child(i), branch)
+		}
+	}
+	walk(node, "")
+	return assigns, ifs
+}
+
+// hasElseKeyword reports whether the source between two if_statement children
+// contains a bare "else" keyword (ignoring comments).
+func hasElseKeyword(gap []byte) bool {
+	for _, line := range strings.Split(string(gap), "\n") {
+		if idx := strings.Index(line, "--"); idx != -1 {
+			line = line[:idx]
+		}
+		for _, word := range strings.FieldsFunc(line, func(r rune) bool {
+			return !(r == '_' || r >= 'a' && r <= 'z' || r >= 'A' && r <= 'Z' || r >= '0' && r <= '9')
+		}) {
+			if strings.EqualFold(word, "else") {
+				return true
+			}
+		}
+	}
+	return false
+}
+
 // collectProcessVariables collects all variable names declared in a process
 // These should be excluded from read_signals/assigned_signals since they
 // don't need to be in sensitivity lists
@@ -4232,6 +4791,159 @@ func (e *Extractor) extractVerificationTags(source []byte, facts *FileFacts) {
 	}
 }
 
+// vendorCopyrightHolders are the copyright holders of vendor-generated
+// primitives and IP cores.
+var vendorCopyrightHolders = []string{
+	"xilinx",
+	"advanced micro devices",
+	"altera",
+	"intel corporation",
+	"lattice semiconductor",
+	"microsemi",
+	"microchip technology",
+}
+
+// HasVendorHeader reports whether a line of the leading comment block is an
+// FPGA vendor's copyright notice. Designs that merely target a vendor's device
+// name it without claiming its copyright, so they aren't matched.
+func HasVendorHeader(source []byte) bool {
+	for _, line := range strings.Split(string(source), "\n") {
+		line = strings.ToLower(strings.TrimSpace(line))
+		if line == "" {
+			continue
+		}
+		if !strings.HasPrefix(line, "--") {
+			return false
+		}
+		if !strings.Contains(line, "copyright") && !strings.Contains(line, "(c)") {
+			continue
+		}
+		for _, vendor := range vendorCopyrightHolders {
+			if strings.Contains(line, vendor) {
+				return true
+			}
+		}
+	}
+	return false
+}
+
+// extractWaivers collects --@waive comments. Tokens are key=value like
+// --@check tags; rule=/rules= lists rules, expires= sets the expiry date and
+// anything else (a reason) is ignored. Comments naming no rule are skipped.
+func extractWaivers(source []byte, facts *FileFacts) {
+	lines := strings.Split(string(source), "\n")
+	for i, line := range lines {
+		idx := strings.Index(line, "--@waive")
+		if idx < 0 {
+			continue
+		}
+		waiver := Waiver{Line: i + 1, Raw: strings.TrimSpace(line)}
+		for _, part := range strings.Fields(line[idx+len("--@waive"):]) {
+			kv := strings.SplitN(part, "=", 2)
+			if len(kv) != 2 {
+				continue
+			}
+			switch strings.ToLower(kv[0]) {
+			case "rule", "rules":
+				for _, rule := range strings.Split(kv[1], ",") {
+					if rule = strings.ToLower(strings.TrimSpace(rule)); rule != "" {
+						waiver.Rules = append(waiver.Rules, rule)
+					}
+				}
+			case "expires":
+				waiver.Expires = kv[1]
+			}
+		}
+		if len(waiver.Rules) == 0 {
+			continue
+		}
+		before := strings.TrimSpace(line[:idx])
+		if before != "" && !strings.HasPrefix(before, "--") {
+			waiver.TargetLine = i + 1
+		} else {
+			for j := i + 1; j < len(lines); j++ {
+				next := strings.TrimSpace(lines[j])
+				if next != "" && !strings.HasPrefix(next, "--") {
+					waiver.TargetLine = j + 1
+					break
+				}
+			}
+		}
+		facts.Waivers = append(facts.Waivers, waiver)
+	}
+}
+
+// extractLanguageFeatures records the VHDL-2008 constructs the policy engine
+// gates on the configured standard: block comments and matching case
+// statements (case?), nested ones included.
+func extractLanguageFeatures(node *sitter.Node, facts *FileFacts) {
+	if node == nil {
+		return
+	}
+	feature := ""
+	switch node.Type() {
+	case "block_comment":
+		feature = "block_comment"
+	case "case_statement":
+		for i := 0; i < int(node.ChildCount()); i++ {
+			if node.Child(i).Type() == "?" {
+				feature = "matching_case"
+				break
+			}
+		}
+	}
+	if feature != "" {
+		facts.LanguageFeatures = append(facts.LanguageFeatures, LanguageFeature{
+			Feature: feature,
+			Line:    int(node.StartPoint().Row) + 1,
+		})
+	}
+	for i := 0; i < int(node.ChildCount()); i++ {
+		extractLanguageFeatures(node.Child(i), facts)
+	}
+}
+
+// tristateLiteral matches a 'Z' character literal or a bit string of Zs.
+var tristateLiteral = regexp.MustCompile(`'[zZ]'|"[zZ]+"`)
+
+// extractTristateAssignments records every signal assignment, concurrent or
+// sequential and including those in generate blocks, whose value drives 'Z'.
+func (e *Extractor) extractTristateAssignments(node *sitter.Node, source []byte, facts *FileFacts, archContext, procLabel string) {
+	if node == nil {
+		return
+	}
+	switch node.Type() {
+	case "architecture_body":
+		if nameNode := node.ChildByFieldName("name"); nameNode != nil {
+			archContext = nameNode.Content(source)
+		}
+	case "process_statement":
+		procLabel = ""
+		if labelNode := node.ChildByFieldName("label"); labelNode != nil {
+			procLabel = labelNode.Content(source)
+		}
+	case "signal_assignment", "sequential_signal_assignment":
+		content := node.Content(source)
+		if idx := strings.Index(content, "<="); idx != -1 {
+			value := strings.TrimSpace(strings.TrimSuffix(strings.TrimSpace(content[idx+2:]), ";"))
+			if tristateLiteral.MatchString(value) {
+				target, _ := e.extractAssignmentTarget(node, source)
+				facts.TristateAssignments = append(facts.TristateAssignments, TristateAssignment{
+					Target:    target,
+					Value:     value,
+					Line:      int(node.StartPoint().Row) + 1,
+					InArch:    archContext,
+					InProcess: procLabel,
+				})
+			}
+		}
+		return
+	}
+	for i := 0; i < int(node.ChildCount()); i++ {
+		e.extractTristateAssignments(node.Child(i), source, facts, archContext, procLabel)
+	}
+}
+
 func parseVerificationTagLine(line string, lineNo int) (VerificationTag, error) {
 	tag := VerificationTag{
 		Line:     lineNo,
@@ -4424,6 +5136,11 @@ func (e *Extractor) extractCaseStatement(node *sitter.Node, source []byte, archC
 	for i := 0; i < int(node.ChildCount()); i++ {
 		child := node.Child(i)
 		if child.Type() == "case_alternative" {
+			arm := CaseArm{
+				Choices: []string{},
+				Line:    int(child.StartPoint().Row) + 1,
+				Branch:  fmt.Sprintf("case@%d", child.StartByte()),
+			}
 			// Extract choices from this alternative
 			for j := 0; j < int(child.ChildCount()); j++ {
 				choiceChild := child.Child(j)
@@ -4434,6 +5151,7 @@ func (e *Extractor) extractCaseStatement(node *sitter.Node, source []byte, archC
 						if choiceChild.Child(k).Type() == "others_choice" {
 							caseStmt.HasOthers = true
 							caseStmt.Choices = append(caseStmt.Choices, "others")
+							arm.Choices = append(arm.Choices, "others")
 							isOthers = true
 							break
 						}
@@ -4442,9 +5160,11 @@ func (e *Extractor) extractCaseStatement(node *sitter.Node, source []byte, archC
 					if !isOthers {
 						choiceText := choiceChild.Content(source)
 						caseStmt.Choices = append(caseStmt.Choices, choiceText)
+						arm.Choices = append(arm.Choices, choiceText)
 					}
 				}
 			}
+			caseStmt.Arms = append(caseStmt.Arms, arm)
 		}
 	}
 
@@ -4477,6 +5197,8 @@ func (e *Extractor) extractCaseStatementsFromProcess(node *sitter.Node, source [
 // Note: When the grammar produces proper relational_expression nodes with fields,
 // this extraction becomes trivial. Until then, we work with what we have.
 func (e *Extractor) extractComparisonsFromProcess(node *sitter.Node, source []byte, archContext, processLabel string, facts *FileFacts) {
+	// std_match calls are recorded once, at the outermost node spanning them
+	stdMatchSeen := make(map[uint32]bool)
 	var walk func(n *sitter.Node, currentAssignment string, parent *sitter.Node)
 	walk = func(n *sitter.Node, currentAssignment string, parent *sitter.Node) {
 		if n == nil {
@@ -4515,6 +5237,14 @@ func (e *Extractor) extractComparisonsFromProcess(node *sitter.Node, source []by
 			return
 		}
 
+		// std_match(a, b) compares like "=" with '-' as don't care; record it with operator "std_match"
+		if !stdMatchSeen[n.StartByte()] {
+			if comp, ok := stdMatchComparison(n, source, archContext, processLabel, currentAssignment); ok {
+				stdMatchSeen[n.StartByte()] = true
+				facts.Comparisons = append(facts.Comparisons, comp)
+			}
+		}
+
 		// Fallback: Find relational_operator nodes and look at siblings
 		// Only fires when operator is NOT inside a relational_expression (flat structure)
 		if nodeType == "relational_operator" && parent != nil {
@@ -4535,6 +5265,52 @@ func (e *Extractor) extractComparisonsFromProcess(node *sitter.Node, source []by
 	walk(node, "", nil)
 }
 
+// stdMatchComparison turns a two-argument std_match call into a Comparison
+// (operator "std_match", the second argument as the literal side)
+func stdMatchComparison(node *sitter.Node, source []byte, archContext, processLabel, resultDrives string) (Comparison, bool) {
+	content := strings.TrimSpace(node.Content(source))
+	lower := strings.ToLower(content)
+	if !strings.HasPrefix(lower, "std_match") || !strings.HasSuffix(lower, ")") {
+		return Comparison{}, false
+	}
+	rest := strings.TrimSpace(content[len("std_match"):])
+	if !strings.HasPrefix(rest, "(") {
+		return Comparison{}, false
+	}
+	// The opening parenthesis must close at the very end ("std_match(a, b) and f(c)" is not one call)
+	depth := 0
+	for i := 0; i < len(rest); i++ {
+		switch rest[i] {
+		case '(':
+			depth++
+		case ')':
+			depth--
+			if depth == 0 && i != len(rest)-1 {
+				return Comparison{}, false
+			}
+		}
+	}
+	args := splitArgsRespectParens(rest[1 : len(rest)-1])
+	if len(args) != 2 {
+		return Comparison{}, false
+	}
+	comp := Comparison{
+		LeftOperand:  args[0],
+		Operator:     "std_match",
+		RightOperand: args[1],
+		ResultDrives: resultDrives,
+		Line:         int(node.StartPoint().Row) + 1,
+		InProcess:    processLabel,
+		InArch:       archContext,
+	}
+	if isLiteralContent(args[1]) {
+		comp.IsLiteral = true
+		comp.LiteralValue = args[1]
+		comp.LiteralBits = estimateBitWidth(args[1])
+	}
+	return comp, true
+}
+
 // extractComparisonStructured extracts from a structured relational_expression node
 func (e *Extractor) extractComparisonStructured(node *sitter.Node, source []byte, archContext, processLabel, resultDrives string) Comparison {
 	comp := Comparison{
@@ -4751,11 +5527,21 @@ func estimateBitWidth(literal string) int {
 	return 0
 }
 
-// extractArithmeticOpsFromProcess extracts expensive arithmetic operations for power analysis
-// Uses grammar's visible `multiplicative_expression` and `exponential_expression` nodes
+// extractArithmeticOpsFromProcess extracts arithmetic operations for power and width analysis
+// Uses grammar's visible `multiplicative_expression` and `exponential_expression` nodes,
+// plus the additive operators (+, -) of flattened additive expressions
 func (e *Extractor) extractArithmeticOpsFromProcess(node *sitter.Node, source []byte, archContext, processLabel string, facts *FileFacts) {
 	// Track enable signals from if conditions
 	var guardStack []string
+	// Signal assigned by the enclosing assignment, if any
+	target := ""
+
+	record := func(op ArithmeticOp) {
+		if op.Operator != "" {
+			op.Result = target
+			facts.ArithmeticOps = append(facts.ArithmeticOps, op)
+		}
+	}
 
 	var walk func(n *sitter.Node)
 	walk = func(n *sitter.Node) {
@@ -4765,6 +5551,14 @@ func (e *Extractor) extractArithmeticOpsFromProcess(node *sitter.Node, source []
 
 		nodeType := n.Type()
 
+		if nodeType == "sequential_signal_assignment" {
+			if sig, ok := e.extractAssignmentTarget(n, source); ok {
+				outer := target
+				target = sig
+				defer func() { target = outer }()
+			}
+		}
+
 		// Track if conditions as potential guards
 		if nodeType == "if_statement" {
 			if cond := n.ChildByFieldName("condition"); cond != nil {
@@ -4787,10 +5581,7 @@ func (e *Extractor) extractArithmeticOpsFromProcess(node *sitter.Node, source []
 		// - multiplicative_expression: field('left'), field('operator'), field('right')
 		// - exponential_expression: field('base'), '**', field('exponent')
 		if nodeType == "multiplicative_expression" {
-			op := e.extractMultiplicativeOp(n, source, archContext, processLabel, guardStack)
-			if op.Operator != "" {
-				facts.ArithmeticOps = append(facts.ArithmeticOps, op)
-			}
+			record(e.extractMultiplicativeOp(n, source, archContext, processLabel, guardStack))
 			// Recurse into operands for nested operations, skip the operator node
 			for i := 0; i < int(n.ChildCount()); i++ {
 				child := n.Child(i)
@@ -4802,10 +5593,7 @@ func (e *Extractor) extractArithmeticOpsFromProcess(node *sitter.Node, source []
 		}
 
 		if nodeType == "exponential_expression" {
-			op := e.extractExponentialOp(n, source, archContext, processLabel, guardStack)
-			if op.Operator != "" {
-				facts.ArithmeticOps = append(facts.ArithmeticOps, op)
-			}
+			record(e.extractExponentialOp(n, source, archContext, processLabel, guardStack))
 			// Recurse into operands for nested operations
 			for i := 0; i < int(n.ChildCount()); i++ {
 				child := n.Child(i)
@@ -4817,15 +5605,14 @@ func (e *Extractor) extractArithmeticOpsFromProcess(node *sitter.Node, source []
 		}
 
 		// Fallback: flat arithmetic operators (when expressions are not structured)
-		// Only fires when operator is NOT inside a structured expression
-		if nodeType == "arithmetic_operator" || nodeType == "multiplicative_operator" {
+		// Only fires when operator is NOT inside a structured expression.
+		// Additive expressions are always flat; '&' is concatenation, not arithmetic.
+		isAdditive := nodeType == "additive_operator" && n.Content(source) != "&"
+		if nodeType == "arithmetic_operator" || nodeType == "multiplicative_operator" || isAdditive {
 			if parent := n.Parent(); parent != nil {
 				// Skip if parent is a structured expression (already handled above)
 				if parent.Type() != "multiplicative_expression" && parent.Type() != "exponential_expression" {
-					op := e.extractArithmeticOpFromSiblings(n, parent, source, archContext, processLabel, guardStack)
-					if op.Operator != "" {
-						facts.ArithmeticOps = append(facts.ArithmeticOps, op)
-					}
+					record(e.extractArithmeticOpFromSiblings(n, parent, source, archContext, processLabel, guardStack))
 				}
 			}
 		}
@@ -5643,6 +6430,10 @@ func (e *Extractor) extractArrayTypeDetails(node *sitter.Node, source []byte, td
 		}
 	}
 
+	if !td.Unconstrained {
+		td.IndexRange = firstIndexConstraint(content)
+	}
+
 	// If we couldn't extract element type, try getting from content
 	if td.ElementType == "" {
 		parts := strings.Split(strings.ToLower(content), " of ")
@@ -5655,6 +6446,34 @@ func (e *Extractor) extractArrayTypeDetails(node *sitter.Node, source []byte, td
 	}
 }
 
+// firstIndexConstraint returns the first index constraint of an array
+// definition ("0 to DEPTH-1" for "array (0 to DEPTH-1) of word"), with
+// whitespace collapsed, or "" when there is none.
+func firstIndexConstraint(content string) string {
+	open := strings.Index(content, "(")
+	if open < 0 {
+		return ""
+	}
+	depth := 0
+	start := open + 1
+	for i := open; i < len(content); i++ {
+		switch content[i] {
+		case '(':
+			depth++
+		case ')':
+			depth--
+			if depth == 0 {
+				return strings.Join(strings.Fields(content[start:i]), " ")
+			}
+		case ',':
+			if depth == 1 {
+				return strings.Join(strings.Fields(content[start:i]), " ")
+			}
+		}
+	}
+	return ""
+}
+
 // extractPhysicalTypeDetails extracts physical type information (time, etc.)
 func (e *Extractor) extractPhysicalTypeDetails(node *sitter.Node, source []byte, td *TypeDeclaration) {
 	// Physical type: range X to Y units base_unit; secondary_units... end units;
@@ -5858,6 +6677,9 @@ func (e *Extractor) extractFunctionDeclaration(node *sitter.Node, source []byte,
 
 	// Extract parameters
 	fd.Parameters = e.extractSubprogramParameters(node, source)
+	if fd.HasBody {
+		_, fd.ReadSignals = e.extractSubprogramEffects(node, source, fd.Parameters)
+	}
 
 	return fd
 }
@@ -5884,10 +6706,91 @@ func (e *Extractor) extractProcedureDeclaration(node *sitter.Node, source []byte
 
 	// Extract parameters
 	pd.Parameters = e.extractSubprogramParameters(node, source)
+	if pd.HasBody {
+		pd.AssignedSignals, _ = e.extractSubprogramEffects(node, source, pd.Parameters)
+	}
 
 	return pd
 }
 
+// extractSubprogramEffects scans a subprogram body's statements for signal
+// assignment targets and read names. Parameters and local declarations
+// (variables, constants, nested subprograms) are excluded from the reads.
+func (e *Extractor) extractSubprogramEffects(node *sitter.Node, source []byte, params []SubprogramParameter) (assigned, reads []string) {
+	locals := make(map[string]bool)
+	for _, p := range params {
+		locals[strings.ToLower(p.Name)] = true
+	}
+	var statements []*sitter.Node
+	for i := 0; i < int(node.ChildCount()); i++ {
+		child := node.Child(i)
+		childType := child.Type()
+		switch {
+		case strings.HasSuffix(childType, "_declaration"):
+			for _, name := range declaredNamesBeforeColon(child, source) {
+				locals[strings.ToLower(name)] = true
+			}
+		case strings.HasSuffix(childType, "_statement") || strings.HasSuffix(childType, "_assignment"):
+			statements = append(statements, child)
+		}
+	}
+
+	assignedSet := make(map[string]bool)
+	readSet := make(map[string]bool)
+	var walk func(n *sitter.Node)
+	walk = func(n *sitter.Node) {
+		if n == nil {
+			return
+		}
+		if n.Type() == "sequential_signal_assignment" {
+			if sig, ok := e.extractAssignmentTarget(n, source); ok && !locals[strings.ToLower(sig)] {
+				if !assignedSet[strings.ToLower(sig)] {
+					assigned = append(assigned, sig)
+				}
+				assignedSet[strings.ToLower(sig)] = true
+			}
+		}
+		for i := 0; i < int(n.ChildCount()); i++ {
+			walk(n.Child(i))
+		}
+	}
+	for _, stmt := range statements {
+		walk(stmt)
+		e.extractReadsFromNode(stmt, source, readSet, false, nil, locals)
+	}
+	for name := range readSet {
+		lower := strings.ToLower(name)
+		if locals[lower] || assignedSet[lower] || e.isCommonVHDLFunction(lower) {
+			continue
+		}
+		reads = append(reads, name)
+	}
+	sort.Strings(reads)
+	return assigned, reads
+}
+
+// declaredNamesBeforeColon returns the identifiers a declaration introduces
+// (those before its ':'), or its name for nested subprograms.
+func declaredNamesBeforeColon(node *sitter.Node, source []byte) []string {
+	if node.Type() == "function_declaration" || node.Type() == "procedure_declaration" {
+		if nameNode := node.ChildByFieldName("name"); nameNode != nil {
+			return []string{nameNode.Content(source)}
+		}
+		return nil
+	}
+	var names []string
+	for i := 0; i < int(node.ChildCount()); i++ {
+		child := node.Child(i)
+		if child.Content(source) == ":" {
+			break
+		}
+		if child.Type() == "identifier" {
+			names = append(names, child.Content(source))
+		}
+	}
+	return names
+}
+
 // extractSubprogramParameters extracts parameters from a function/procedure
 func (e *Extractor) extractSubprogramParameters(node *sitter.Node, source []byte) []SubprogramParameter {
 	var params []SubprogramParameter
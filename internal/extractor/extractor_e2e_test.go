@@ -435,6 +435,20 @@ func TestExtractorE2EContextPSLWaitAll(t *testing.T) {
 	if len(pAll.SensitivityList) != 1 || strings.ToLower(pAll.SensitivityList[0]) != "all" {
 		t.Fatalf("expected p_all sensitivity list to be all, got %#v", pAll.SensitivityList)
 	}
+
+	var kinds []string
+	for _, d := range facts.PSLDirectives {
+		if d.InArch != "rtl" {
+			t.Fatalf("expected PSL directive in rtl, got %#v", d)
+		}
+		kinds = append(kinds, d.Kind)
+	}
+	if strings.Join(kinds, ",") != "assert,cover,assume,restrict" {
+		t.Fatalf("expected assert/cover/assume/restrict directives, got %#v", facts.PSLDirectives)
+	}
+	if cover := facts.PSLDirectives[1]; len(cover.Signals) != 2 || cover.Signals[0] != "a" || cover.Signals[1] != "b" {
+		t.Fatalf("expected cover to read a and b, got %#v", cover.Signals)
+	}
 }
 
 func TestExtractorE2EAnonTypesAndSubprogramInstantiation(t *testing.T) {
@@ -562,6 +576,30 @@ func TestExtractorE2EViewGroupAttributeMisc(t *testing.T) {
 	if _, ok := findPackageByName(facts.Packages, "misc_2019"); !ok {
 		t.Fatalf("expected package misc_2019, got %#v", facts.Packages)
 	}
+
+	if len(facts.ModeViews) != 1 {
+		t.Fatalf("expected one mode view, got %#v", facts.ModeViews)
+	}
+	view := facts.ModeViews[0]
+	if view.Name != "v1" || view.InPackage != "misc_2019" || len(view.Elements) != 2 {
+		t.Fatalf("expected view v1 with two elements in misc_2019, got %#v", view)
+	}
+	if view.Elements[0].Direction != "in" || view.Elements[1].Direction != "out" {
+		t.Fatalf("expected element modes in/out, got %#v", view.Elements)
+	}
+
+	if len(facts.AttributeSpecs) != 1 {
+		t.Fatalf("expected one attribute specification, got %#v", facts.AttributeSpecs)
+	}
+	spec := facts.AttributeSpecs[0]
+	if spec.Name != "foo" || len(spec.Targets) != 1 || spec.Targets[0] != "g1" || spec.Class != "group" || spec.Value != "1" {
+		t.Fatalf("expected attribute foo of g1 : group is 1, got %#v", spec)
+	}
+
+	link := mustFindPort(t, facts.Ports, "link")
+	if link.Direction != "view" || link.View != "v1" || link.Type != "rec_t" {
+		t.Fatalf("expected port link with view v1 of rec_t, got %#v", link)
+	}
 }
 
 func TestExtractorE2EBasedLiteralAggregate(t *testing.T) {
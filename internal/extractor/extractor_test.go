@@ -236,6 +236,40 @@ end architecture;`
 	}
 }
 
+func TestExtractorSubprogramEffects(t *testing.T) {
+	vhdl := `entity e is
+end entity;
+
+architecture rtl of e is
+  signal enable : bit;
+  signal busy   : bit;
+  function gated(x : bit) return bit is
+    variable tmp : bit;
+  begin
+    tmp := x and enable;
+    return tmp;
+  end function;
+  procedure mark(signal dst : out bit) is
+  begin
+    dst  <= '1';
+    busy <= '1';
+  end procedure;
+begin
+end architecture;`
+
+	facts := parseVHDL(t, vhdl)
+	for _, fn := range facts.Functions {
+		if fn.Name == "gated" && strings.Join(fn.ReadSignals, ",") != "enable" {
+			t.Fatalf("expected gated to read only enable, got %v", fn.ReadSignals)
+		}
+	}
+	for _, pr := range facts.Procedures {
+		if pr.Name == "mark" && strings.Join(pr.AssignedSignals, ",") != "dst,busy" {
+			t.Fatalf("expected mark to assign dst and busy, got %v", pr.AssignedSignals)
+		}
+	}
+}
+
 func TestExtractorPortDefaults(t *testing.T) {
 	vhdl := `entity top is
   port(
@@ -341,6 +375,9 @@ end;
 	if !hasCaseStatement(facts.CaseStatements, "state") {
 		t.Fatalf("expected case statement on state")
 	}
+	if arms := facts.CaseStatements[0].Arms; len(arms) != 3 || !hasString(arms[1].Choices, "RUN") || arms[2].Choices[0] != "others" || arms[0].Branch == "" {
+		t.Fatalf("expected IDLE, RUN and others arms, got %#v", arms)
+	}
 	if !hasComparisonLiteral(facts.Comparisons, "rst") {
 		t.Fatalf("expected comparison involving rst literal")
 	}
@@ -390,6 +427,61 @@ end architecture;
 	}
 }
 
+func TestExtractorOrderedAssignmentBranches(t *testing.T) {
+	vhdl := `library ieee;
+use ieee.std_logic_1164.all;
+
+entity e is
+  port (
+    clk : in std_logic;
+    en  : in std_logic;
+    q   : out std_logic
+  );
+end entity;
+
+architecture rtl of e is
+begin
+  p_seq: process
+  begin
+    q <= '0';
+    if en = '1' then
+      q <= '1';
+    else -- disabled
+      q <= '0';
+    end if;
+    wait for 1 ns;
+    q <= en;
+  end process;
+end architecture;
+`
+
+	facts := parseVHDL(t, vhdl)
+	proc := mustFindProcess(t, facts.Processes, "p_seq")
+	if len(proc.Assignments) != 4 {
+		t.Fatalf("expected 4 ordered assignments, got %+v", proc.Assignments)
+	}
+	first, thenBr, elseBr, last := proc.Assignments[0], proc.Assignments[1], proc.Assignments[2], proc.Assignments[3]
+	if first.Branch != "" || first.Line != 16 {
+		t.Fatalf("expected first assignment in process body, got %+v", first)
+	}
+	if thenBr.Branch == "" || elseBr.Branch == "" || thenBr.Branch == elseBr.Branch {
+		t.Fatalf("expected distinct if/else branches, got %q and %q", thenBr.Branch, elseBr.Branch)
+	}
+	if last.Segment != 1 || first.Segment != 0 {
+		t.Fatalf("expected wait to start a new segment, got %d and %d", first.Segment, last.Segment)
+	}
+	if len(proc.IfStatements) != 1 {
+		t.Fatalf("expected 1 if statement, got %+v", proc.IfStatements)
+	}
+	stmt := proc.IfStatements[0]
+	if !stmt.HasElse || len(stmt.Arms) != 2 || stmt.Line != 17 || stmt.Arms[1].Line != 20 {
+		t.Fatalf("expected if/else at line 17 with else branch at line 20, got %+v", stmt)
+	}
+	if thenBr.Branch != stmt.Branch+"/"+stmt.Arms[0].Branch || elseBr.Branch != stmt.Branch+"/"+stmt.Arms[1].Branch {
+		t.Fatalf("expected arm paths to match assignment branches, got %+v", stmt)
+	}
+}
+
 func TestExtractorReadsIndexedNameInExpression(t *testing.T) {
 	vhdl := `library ieee;
 use ieee.std_logic_1164.all;
@@ -618,6 +710,104 @@ end;
 	}
 }
 
+func TestExtractorWaivers(t *testing.T) {
+	vhdl := `entity w_top is
+end;
+
+architecture rtl of w_top is
+  --@waive rule=unused_signal expires=2026-06-30 reason=spare
+  -- kept for the next revision
+  signal spare : bit;
+  signal dbg : bit;  --@waive rules=unused_signal,wide_signal
+  --@waive expires=2026-06-30
+begin
+end;
+`
+
+	facts := parseVHDL(t, vhdl)
+	if len(facts.Waivers) != 2 {
+		t.Fatalf("expected 2 waivers, got %+v", facts.Waivers)
+	}
+	first := facts.Waivers[0]
+	if first.Line != 5 || first.TargetLine != 7 || first.Expires != "2026-06-30" || strings.Join(first.Rules, ",") != "unused_signal" {
+		t.Fatalf("unexpected standalone waiver: %+v", first)
+	}
+	second := facts.Waivers[1]
+	if second.TargetLine != 8 || strings.Join(second.Rules, ",") != "unused_signal,wide_signal" {
+		t.Fatalf("unexpected trailing waiver: %+v", second)
+	}
+}
+
+func TestExtractorLanguageFeatures(t *testing.T) {
+	vhdl := `entity lf_top is
+end;
+
+architecture rtl of lf_top is
+  /* spare
+     lanes */
+  signal sel : bit_vector(1 downto 0);
+  signal y : bit;
+begin
+  process (all)
+  begin
+    case? sel is
+      when "1-" => y <= '1';
+      when others => y <= '0';
+    end case?;
+  end process;
+end;
+`
+
+	facts := parseVHDL(t, vhdl)
+	want := []LanguageFeature{
+		{Feature: "block_comment", Line: 5},
+		{Feature: "matching_case", Line: 12},
+	}
+	if len(facts.LanguageFeatures) != len(want) {
+		t.Fatalf("expected %+v, got %+v", want, facts.LanguageFeatures)
+	}
+	for i, feature := range want {
+		if facts.LanguageFeatures[i] != feature {
+			t.Fatalf("expected %+v, got %+v", want, facts.LanguageFeatures)
+		}
+	}
+}
+
+func TestExtractorArithmeticOpResults(t *testing.T) {
+	vhdl := `library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity arith_top is
+  port (clk : in std_logic);
+end;
+
+architecture rtl of arith_top is
+  signal a, b, sum : unsigned(7 downto 0);
+  signal p : unsigned(15 downto 0);
+begin
+  process (clk)
+  begin
+    if rising_edge(clk) then
+      sum <= a + b;
+      p <= a * b;
+    end if;
+  end process;
+end;
+`
+
+	facts := parseVHDL(t, vhdl)
+	want := map[string]string{"+": "sum", "*": "p"}
+	for _, op := range facts.ArithmeticOps {
+		if result, ok := want[op.Operator]; ok && strings.EqualFold(op.Result, result) && len(op.Operands) == 2 {
+			delete(want, op.Operator)
+		}
+	}
+	if len(want) != 0 {
+		t.Fatalf("expected ops assigning %v, got %+v", want, facts.ArithmeticOps)
+	}
+}
+
 func parseVHDL(t *testing.T, src string) FileFacts {
 	t.Helper()
 
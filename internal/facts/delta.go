@@ -36,6 +36,8 @@ func diffTables(from, to Tables) Tables {
 	out.Procedures = diffProcedureRows(from.Procedures, to.Procedures)
 	out.Constants = diffConstantRows(from.Constants, to.Constants)
 	out.Symbols = diffSymbolRows(from.Symbols, to.Symbols)
+	out.SignalUses = diffSignalUseRows(from.SignalUses, to.SignalUses)
+	out.PortMaps = diffPortMapRows(from.PortMaps, to.PortMaps)
 
 	return out
 }
@@ -61,6 +63,8 @@ func emptyTables() Tables {
 		Procedures:     []ProcedureRow{},
 		Constants:      []ConstantRow{},
 		Symbols:        []SymbolRow{},
+		SignalUses:     []SignalUseRow{},
+		PortMaps:       []PortMapRow{},
 	}
 }
 
@@ -90,13 +94,13 @@ func diffPackageRows(from, to []PackageRow) []PackageRow {
 
 func diffPortRows(from, to []PortRow) []PortRow {
 	return diffRows(from, to, func(r PortRow) string {
-		return r.Entity + "|" + r.Name + "|" + r.Direction + "|" + r.Type + "|" + r.File + "|" + intKey(r.Line)
+		return r.Entity + "|" + r.Name + "|" + r.Direction + "|" + r.Type + "|" + r.Default + "|" + intKey(r.Width) + "|" + r.File + "|" + intKey(r.Line)
 	})
 }
 
 func diffSignalRows(from, to []SignalRow) []SignalRow {
 	return diffRows(from, to, func(r SignalRow) string {
-		return r.Name + "|" + r.Type + "|" + r.File + "|" + intKey(r.Line) + "|" + r.Scope
+		return r.Name + "|" + r.Type + "|" + intKey(r.Width) + "|" + r.File + "|" + intKey(r.Line) + "|" + r.Scope
 	})
 }
 
@@ -178,6 +182,18 @@ func diffSymbolRows(from, to []SymbolRow) []SymbolRow {
 	})
 }
 
+func diffSignalUseRows(from, to []SignalUseRow) []SignalUseRow {
+	return diffRows(from, to, func(r SignalUseRow) string {
+		return r.Name + "|" + r.Kind + "|" + r.File + "|" + intKey(r.Line)
+	})
+}
+
+func diffPortMapRows(from, to []PortMapRow) []PortMapRow {
+	return diffRows(from, to, func(r PortMapRow) string {
+		return r.Instance + "|" + r.Formal + "|" + r.Actual + "|" + r.File + "|" + intKey(r.Line)
+	})
+}
+
 func diffRows[T any](from, to []T, key func(T) string) []T {
 	fromSet := make(map[string]T, len(from))
 	for _, row := range from {
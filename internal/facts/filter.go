@@ -103,6 +103,16 @@ func FilterTablesByFiles(tables Tables, files map[string]bool) Tables {
 			out.Symbols = append(out.Symbols, row)
 		}
 	}
+	for _, row := range tables.SignalUses {
+		if files[row.File] {
+			out.SignalUses = append(out.SignalUses, row)
+		}
+	}
+	for _, row := range tables.PortMaps {
+		if files[row.File] {
+			out.PortMaps = append(out.PortMaps, row)
+		}
+	}
 
 	return out
 }
@@ -29,6 +29,8 @@ type Tables struct {
 	Procedures     []ProcedureRow     `json:"procedures"`
 	Constants      []ConstantRow      `json:"constants"`
 	Symbols        []SymbolRow        `json:"symbols"`
+	SignalUses     []SignalUseRow     `json:"signal_uses"`
+	PortMaps       []PortMapRow       `json:"port_maps"`
 }
 
 type FileRow struct {
@@ -61,6 +63,8 @@ type PortRow struct {
 	Name      string `json:"name"`
 	Direction string `json:"direction"`
 	Type      string `json:"type"`
+	Default   string `json:"default"`
+	Width     int    `json:"width"`
 	File      string `json:"file"`
 	Line      int    `json:"line"`
 }
@@ -68,6 +72,7 @@ type PortRow struct {
 type SignalRow struct {
 	Name  string `json:"name"`
 	Type  string `json:"type"`
+	Width int    `json:"width"`
 	File  string `json:"file"`
 	Line  int    `json:"line"`
 	Scope string `json:"scope"`
@@ -179,6 +184,26 @@ type SymbolRow struct {
 	Line int    `json:"line"`
 }
 
+// SignalUseRow is one read or write of a name by a process, a concurrent
+// assignment or a tracked signal usage. Port-map connections live in
+// PortMapRow.
+type SignalUseRow struct {
+	Name string `json:"name"`
+	Kind string `json:"kind"` // "read" or "write"
+	File string `json:"file"`
+	Line int    `json:"line"`
+}
+
+// PortMapRow is one formal => actual connection of an instance, keyed by the
+// instance's file and line.
+type PortMapRow struct {
+	Instance string `json:"instance"`
+	Formal   string `json:"formal"`
+	Actual   string `json:"actual"`
+	File     string `json:"file"`
+	Line     int    `json:"line"`
+}
+
 // BuildTables converts extractor FileFacts into a normalized relational model.
 func BuildTables(facts []extractor.FileFacts, fileLibs map[string]config.FileLibraryInfo, thirdParty map[string]bool, symbols []SymbolRow) Tables {
 	tables := Tables{
@@ -201,6 +226,8 @@ func BuildTables(facts []extractor.FileFacts, fileLibs map[string]config.FileLib
 		Procedures:     []ProcedureRow{},
 		Constants:      []ConstantRow{},
 		Symbols:        []SymbolRow{},
+		SignalUses:     []SignalUseRow{},
+		PortMaps:       []PortMapRow{},
 	}
 
 	seenFiles := make(map[string]bool)
@@ -249,6 +276,8 @@ func BuildTables(facts []extractor.FileFacts, fileLibs map[string]config.FileLib
 				Name:      p.Name,
 				Direction: p.Direction,
 				Type:      p.Type,
+				Default:   p.Default,
+				Width:     extractor.CalculateWidth(p.Type),
 				File:      f.File,
 				Line:      p.Line,
 			})
@@ -258,6 +287,7 @@ func BuildTables(facts []extractor.FileFacts, fileLibs map[string]config.FileLib
 			tables.Signals = append(tables.Signals, SignalRow{
 				Name:  s.Name,
 				Type:  s.Type,
+				Width: extractor.CalculateWidth(s.Type),
 				File:  f.File,
 				Line:  s.Line,
 				Scope: s.InEntity,
@@ -272,6 +302,15 @@ func BuildTables(facts []extractor.FileFacts, fileLibs map[string]config.FileLib
 				Line:   inst.Line,
 				InArch: inst.InArch,
 			})
+			for formal, actual := range inst.PortMap {
+				tables.PortMaps = append(tables.PortMaps, PortMapRow{
+					Instance: inst.Name,
+					Formal:   formal,
+					Actual:   actual,
+					File:     f.File,
+					Line:     inst.Line,
+				})
+			}
 		}
 
 		for _, dep := range f.Dependencies {
@@ -320,6 +359,22 @@ func BuildTables(facts []extractor.FileFacts, fileLibs map[string]config.FileLib
 				IsSequential: proc.IsSequential,
 				IsComb:       proc.IsCombinational,
 			})
+			tables.SignalUses = appendSignalUses(tables.SignalUses, f.File, proc.Line, "read", proc.ReadSignals)
+			tables.SignalUses = appendSignalUses(tables.SignalUses, f.File, proc.Line, "write", proc.AssignedSignals)
+		}
+
+		for _, ca := range f.ConcurrentAssignments {
+			tables.SignalUses = appendSignalUses(tables.SignalUses, f.File, ca.Line, "read", ca.ReadSignals)
+			tables.SignalUses = appendSignalUses(tables.SignalUses, f.File, ca.Line, "write", []string{ca.Target})
+		}
+
+		for _, usage := range f.SignalUsages {
+			if usage.IsRead {
+				tables.SignalUses = appendSignalUses(tables.SignalUses, f.File, usage.Line, "read", []string{usage.Signal})
+			}
+			if usage.IsWritten {
+				tables.SignalUses = appendSignalUses(tables.SignalUses, f.File, usage.Line, "write", []string{usage.Signal})
+			}
 		}
 
 		for _, gen := range f.Generates {
@@ -400,3 +455,13 @@ func BuildTables(facts []extractor.FileFacts, fileLibs map[string]config.FileLib
 
 	return tables
 }
+
+func appendSignalUses(rows []SignalUseRow, file string, line int, kind string, names []string) []SignalUseRow {
+	for _, name := range names {
+		if name == "" {
+			continue
+		}
+		rows = append(rows, SignalUseRow{Name: name, Kind: kind, File: file, Line: line})
+	}
+	return rows
+}
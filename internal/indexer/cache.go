@@ -1,6 +1,7 @@
 package indexer
 
 import (
+	"bytes"
 	"crypto/sha256"
 	"encoding/hex"
 	"encoding/json"
@@ -122,6 +123,32 @@ func (c *factsCache) Get(filePath, contentHash string) (extractor.FileFacts, boo
 	return facts, true, nil
 }
 
+// Previous returns the last cached facts for filePath regardless of its content
+// hash, so a re-extracted file can be compared against what it produced before.
+func (c *factsCache) Previous(filePath string) (extractor.FileFacts, bool, error) {
+	c.mu.Lock()
+	entry, ok := c.index.Entries[filePath]
+	c.mu.Unlock()
+	if !ok {
+		return extractor.FileFacts{}, false, nil
+	}
+	if entry.ParserVersion != c.parserVersion || entry.ExtractorVersion != c.extractorVersion {
+		return extractor.FileFacts{}, false, nil
+	}
+	data, err := os.ReadFile(entry.FactsPath)
+	if err != nil {
+		if os.IsNotExist(err) {
+			return extractor.FileFacts{}, false, nil
+		}
+		return extractor.FileFacts{}, false, fmt.Errorf("read cached facts: %w", err)
+	}
+	var facts extractor.FileFacts
+	if err := json.Unmarshal(data, &facts); err != nil {
+		return extractor.FileFacts{}, false, fmt.Errorf("parse cached facts: %w", err)
+	}
+	return facts, true, nil
+}
+
 func (c *factsCache) Put(filePath, contentHash string, facts extractor.FileFacts) error {
 	factsPath := c.factsPathForFile(filePath)
 	if err := os.MkdirAll(filepath.Dir(factsPath), 0o755); err != nil {
@@ -142,6 +169,20 @@ func (c *factsCache) Put(filePath, contentHash string, facts extractor.FileFacts
 	return nil
 }
 
+// factsEqual reports whether two extractions are identical. Edits that leave
+// the facts unchanged (e.g. inside a comment) cannot change policy results.
+func factsEqual(a, b extractor.FileFacts) bool {
+	da, err := json.Marshal(a)
+	if err != nil {
+		return false
+	}
+	db, err := json.Marshal(b)
+	if err != nil {
+		return false
+	}
+	return bytes.Equal(da, db)
+}
+
 func writeJSONAtomic(path string, v any) error {
 	data, err := json.MarshalIndent(v, "", "  ")
 	if err != nil {
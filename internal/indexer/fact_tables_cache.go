@@ -9,7 +9,7 @@ import (
 	"github.com/robert-at-pretension-io/vhdl-lint/internal/facts"
 )
 
-const factTablesCacheVersion = 1
+const factTablesCacheVersion = 2
 
 type factTablesCache struct {
 	Version int          `json:"version"`
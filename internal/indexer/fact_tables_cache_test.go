@@ -31,6 +31,8 @@ func TestFactTablesCacheRoundTrip(t *testing.T) {
 		Procedures:     []facts.ProcedureRow{},
 		Constants:      []facts.ConstantRow{},
 		Symbols:        []facts.SymbolRow{},
+		SignalUses:     []facts.SignalUseRow{},
+		PortMaps:       []facts.PortMapRow{},
 	}
 
 	if err := saveFactTablesCache(dir, tables); err != nil {
@@ -74,6 +74,14 @@ type Indexer struct {
 	// JSON output mode
 	JSONOutput bool
 
+	// Constructs mode: print the construct inventory as JSON instead of linting
+	ConstructsOnly bool
+
+	// Signal graph mode: print the signal dataflow graph of this architecture
+	// (or entity) instead of linting, as DOT when SignalGraphDOT is set
+	SignalGraphArch string
+	SignalGraphDOT  bool
+
 	// Timing output (JSONL)
 	Timing     bool
 	TimingPath string
@@ -112,10 +120,12 @@ type LintResult struct {
 
 // ResultSummary provides aggregate violation counts
 type ResultSummary struct {
-	TotalViolations int `json:"total_violations"`
-	Errors          int `json:"errors"`
-	Warnings        int `json:"warnings"`
-	Info            int `json:"info"`
+	TotalViolations int                              `json:"total_violations"`
+	Errors          int                              `json:"errors"`
+	Warnings        int                              `json:"warnings"`
+	Info            int                              `json:"info"`
+	ByDirectory     map[string]policy.SeverityCounts `json:"by_directory,omitempty"`
+	ByLibrary       map[string]policy.SeverityCounts `json:"by_library,omitempty"`
 }
 
 // ExtractionStats provides counts of extracted elements
@@ -432,12 +442,23 @@ func (idx *Indexer) Run(rootPath string) error {
 				errChan <- fmt.Errorf("%s: %w", f, err)
 				return
 			}
+			// A file whose content changed but whose facts did not (comment or
+			// whitespace edits) is not marked changed, so cached policy results
+			// and daemon state are reused.
+			changed := true
+			if cache != nil {
+				if prev, ok, err := cache.Previous(f); err != nil {
+					pipelineErrChan <- fmt.Errorf("cache read failed for %s: %w", f, err)
+				} else if ok && factsEqual(prev, facts) {
+					changed = false
+				}
+			}
 			if cache != nil && contentHash != "" {
 				if err := cache.Put(f, contentHash, facts); err != nil {
 					pipelineErrChan <- fmt.Errorf("cache write failed for %s: %w", f, err)
 				}
 			}
-			if cache != nil {
+			if cache != nil && changed {
 				changedMu.Lock()
 				changedFiles[f] = true
 				changedMu.Unlock()
@@ -471,6 +492,10 @@ func (idx *Indexer) Run(rootPath string) error {
 	for facts := range factsChan {
 		idx.Facts = append(idx.Facts, facts)
 		factsByFile[facts.File] = facts
+		// Vendor code is third-party without being listed in the config
+		if facts.VendorHeader || isVendorPath(facts.File) {
+			idx.ThirdPartyFiles[facts.File] = true
+		}
 	}
 	if cache != nil {
 		if err := cache.Save(); err != nil {
@@ -838,6 +863,13 @@ func (idx *Indexer) Run(rootPath string) error {
 	validateDuration := time.Since(stepStart)
 	timing.RecordStage("validate", stepStart, validateDuration, "")
 
+	if idx.ConstructsOnly {
+		return idx.printConstructs(policyInput)
+	}
+	if idx.SignalGraphArch != "" {
+		return idx.printSignalGraph(policyInput)
+	}
+
 	// 6. Run policy evaluation and build result
 	stepStart = time.Now()
 	lintResult := LintResult{
@@ -948,7 +980,11 @@ func (idx *Indexer) Run(rootPath string) error {
 				} else if v.Severity == "warning" {
 					icon = "⚠"
 				}
-				fmt.Printf("%s [%s] %s:%d - %s\n", icon, v.Rule, v.File, v.Line, v.Message)
+				message := v.Message
+				if v.Confidence != "" && v.Confidence != "high" {
+					message += fmt.Sprintf(" (%s confidence)", v.Confidence)
+				}
+				fmt.Printf("%s [%s] %s:%d - %s\n", icon, v.Rule, v.File, v.Line, message)
 			}
 		}
 
@@ -1017,6 +1053,39 @@ func (idx *Indexer) Run(rootPath string) error {
 	return nil
 }
 
+// printConstructs writes the construct inventory for input to stdout as JSON.
+func (idx *Indexer) printConstructs(input policy.Input) error {
+	policyEngine, err := policy.New(".")
+	if err != nil {
+		return fmt.Errorf("initialize policy engine: %w", err)
+	}
+	inventory, err := policyEngine.Constructs(input)
+	if err != nil {
+		return fmt.Errorf("construct detection failed: %w", err)
+	}
+	enc := json.NewEncoder(os.Stdout)
+	enc.SetIndent("", "  ")
+	if err := enc.Encode(inventory); err != nil {
+		return fmt.Errorf("failed to encode construct inventory: %w", err)
+	}
+	return nil
+}
+
+// printSignalGraph writes the signal dataflow graph of idx.SignalGraphArch to
+// stdout as JSON or DOT.
+func (idx *Indexer) printSignalGraph(input policy.Input) error {
+	policyEngine, err := policy.New(".")
+	if err != nil {
+		return fmt.Errorf("initialize policy engine: %w", err)
+	}
+	graph, err := policyEngine.SignalGraph(input, idx.SignalGraphArch, idx.SignalGraphDOT)
+	if err != nil {
+		return fmt.Errorf("signal graph export failed: %w", err)
+	}
+	_, err = os.Stdout.Write(graph)
+	return err
+}
+
 func formatPipelineErrors(errs []error) string {
 	var b strings.Builder
 	for i, err := range errs {
@@ -1041,6 +1110,8 @@ func applyPolicyResult(lintResult *LintResult, result *policy.Result) {
 		Errors:          result.Summary.Errors,
 		Warnings:        result.Summary.Warnings,
 		Info:            result.Summary.Info,
+		ByDirectory:     result.Summary.ByDirectory,
+		ByLibrary:       result.Summary.ByLibrary,
 	}
 
 	fileViolations := make(map[string]*FileResult)
@@ -1114,6 +1185,8 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 	// produces [] instead of null - the CUE contract requires arrays
 	input := policy.Input{
 		Standard:              idx.Config.Standard,
+		Profile:               idx.Config.Lint.Profile,
+		TopEntities:           idx.Config.Lint.Top,
 		FileCount:             len(idx.Facts),
 		Entities:              []policy.Entity{},
 		Architectures:         []policy.Architecture{},
@@ -1133,18 +1206,25 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 		VerificationBlocks:    []policy.VerificationBlock{},
 		VerificationTags:      []policy.VerificationTag{},
 		VerificationTagErrors: []policy.VerificationTagError{},
+		Waivers:               []policy.Waiver{},
+		LanguageFeatures:      []policy.LanguageFeature{},
+		PSLDirectives:         []policy.PSLDirective{},
+		Assertions:            []policy.Assertion{},
 		Instances:             []policy.Instance{},
 		CaseStatements:        []policy.CaseStatement{},
 		Processes:             []policy.Process{},
 		ConcurrentAssignments: []policy.ConcurrentAssignment{},
+		TristateAssignments:   []policy.TristateAssignment{},
 		Generates:             []policy.GenerateStatement{},
 		Configurations:        []policy.Configuration{},
 		// Type system
-		Types:         []policy.TypeDeclaration{},
-		Subtypes:      []policy.SubtypeDeclaration{},
-		Functions:     []policy.FunctionDeclaration{},
-		Procedures:    []policy.ProcedureDeclaration{},
-		ConstantDecls: []policy.ConstantDeclaration{},
+		Types:          []policy.TypeDeclaration{},
+		Subtypes:       []policy.SubtypeDeclaration{},
+		Functions:      []policy.FunctionDeclaration{},
+		Procedures:     []policy.ProcedureDeclaration{},
+		ConstantDecls:  []policy.ConstantDeclaration{},
+		ModeViews:      []policy.ModeView{},
+		AttributeSpecs: []policy.AttributeSpec{},
 		// Type system info for filtering (LEGACY)
 		EnumLiterals:    []string{},
 		Constants:       []string{},
@@ -1157,7 +1237,33 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 		SignalUsages:  []policy.SignalUsage{},
 		// Configuration
 		LintConfig: policy.LintRuleConfig{
-			Rules: idx.Config.Lint.Rules,
+			Rules:             idx.Config.Lint.Rules,
+			OnlyModules:       idx.Config.Lint.OnlyModules,
+			SkipModules:       idx.Config.Lint.SkipModules,
+			GenericPrefix:     idx.Config.Lint.GenericPrefix,
+			NamingPatterns:    idx.Config.Lint.NamingPatterns,
+			PortOrder:         idx.Config.Lint.PortOrder,
+			BitOrder:          idx.Config.Lint.BitOrder,
+			ResetStyle:        idx.Config.Lint.ResetStyle,
+			AppliesTo:         idx.Config.Lint.AppliesTo,
+			Fast:              idx.Config.Lint.Fast,
+			ErrorBudget:       idx.Config.Lint.ErrorBudget,
+			ShardWorkers:      idx.Config.Lint.ShardWorkers,
+			ModuleWorkers:     idx.Config.Lint.ModuleWorkers,
+			StrictBinding:     idx.Config.Lint.StrictBinding,
+			TestbenchRoots:    idx.Config.Lint.TestbenchRoots,
+			GenerateLimit:     idx.Config.Lint.GenerateLimit,
+			MaxLogicDepth:     idx.Config.Lint.MaxLogicDepth,
+			FsmEncodingStates: idx.Config.Lint.FsmEncodingStates,
+			Target:            idx.Config.Lint.Target,
+			Vendor:            idx.Config.Lint.Vendor,
+			ClockMonitors:     idx.Config.Lint.ClockMonitors,
+			BindingPatterns:   idx.Config.Lint.BindingPatterns,
+			BindingConfidence: idx.Config.Lint.BindingConfidence,
+			AssertionDensity:  idx.Config.Lint.AssertionDensity,
+			MinConfidence:     idx.Config.Lint.MinConfidence,
+			CustomRules:       customRules(idx.Config.Lint.CustomRules),
+			Clocks:            clockDecls(idx.Config.Lint.Clocks),
 		},
 		ThirdPartyFiles: []string{},
 	}
@@ -1199,8 +1305,12 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 						Type:      p.Type,
 						Default:   p.Default,
 						Line:      p.Line,
+						Column:    p.Column,
+						EndColumn: p.EndColumn,
 						InEntity:  p.InEntity,
 						Width:     extractor.CalculateWidth(p.Type),
+						Comment:   p.Comment,
+						View:      p.View,
 					})
 				}
 			}
@@ -1217,11 +1327,13 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 				})
 			}
 			input.Entities = append(input.Entities, policy.Entity{
-				Name:     e.Name,
-				File:     facts.File,
-				Line:     e.Line,
-				Ports:    ports,
-				Generics: generics,
+				Name:      e.Name,
+				File:      facts.File,
+				Line:      e.Line,
+				Column:    e.Column,
+				EndColumn: e.EndColumn,
+				Ports:     ports,
+				Generics:  generics,
 			})
 		}
 
@@ -1231,14 +1343,18 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 				EntityName: a.EntityName,
 				File:       facts.File,
 				Line:       a.Line,
+				Column:     a.Column,
+				EndColumn:  a.EndColumn,
 			})
 		}
 
 		for _, p := range facts.Packages {
 			input.Packages = append(input.Packages, policy.Package{
-				Name: p.Name,
-				File: facts.File,
-				Line: p.Line,
+				Name:      p.Name,
+				File:      facts.File,
+				Line:      p.Line,
+				Column:    p.Column,
+				EndColumn: p.EndColumn,
 			})
 		}
 
@@ -1279,6 +1395,8 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 					Line:      p.Line,
 					InEntity:  p.InEntity,
 					Width:     extractor.CalculateWidth(p.Type),
+					Comment:   p.Comment,
+					View:      p.View,
 				})
 			}
 			for _, g := range c.Generics {
@@ -1310,12 +1428,15 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 				continue
 			}
 			input.Signals = append(input.Signals, policy.Signal{
-				Name:     s.Name,
-				Type:     s.Type,
-				File:     facts.File,
-				Line:     s.Line,
-				InEntity: s.InEntity,
-				Width:    extractor.CalculateWidth(s.Type),
+				Name:      s.Name,
+				Type:      s.Type,
+				File:      facts.File,
+				Line:      s.Line,
+				Column:    s.Column,
+				EndColumn: s.EndColumn,
+				InEntity:  s.InEntity,
+				Width:     extractor.CalculateWidth(s.Type),
+				Default:   s.Default,
 			})
 		}
 
@@ -1325,8 +1446,12 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 				Direction: p.Direction,
 				Type:      p.Type,
 				Line:      p.Line,
+				Column:    p.Column,
+				EndColumn: p.EndColumn,
 				InEntity:  p.InEntity,
 				Width:     extractor.CalculateWidth(p.Type),
+				Comment:   p.Comment,
+				View:      p.View,
 			})
 		}
 
@@ -1392,11 +1517,14 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 			input.Instances = append(input.Instances, policy.Instance{
 				Name:         inst.Name,
 				Target:       inst.Target,
+				TargetArch:   inst.TargetArch,
 				PortMap:      portMap,
 				GenericMap:   genericMap,
 				Associations: associations,
 				File:         facts.File,
 				Line:         inst.Line,
+				Column:       inst.Column,
+				EndColumn:    inst.EndColumn,
 				InArch:       inst.InArch,
 			})
 		}
@@ -1407,6 +1535,14 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 			if choices == nil {
 				choices = []string{}
 			}
+			var arms []policy.CaseArm
+			for _, arm := range cs.Arms {
+				arms = append(arms, policy.CaseArm{
+					Choices: arm.Choices,
+					Line:    arm.Line,
+					Branch:  arm.Branch,
+				})
+			}
 			input.CaseStatements = append(input.CaseStatements, policy.CaseStatement{
 				Expression: cs.Expression,
 				Choices:    choices,
@@ -1416,6 +1552,7 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 				InProcess:  cs.InProcess,
 				InArch:     cs.InArch,
 				IsComplete: cs.IsComplete,
+				Arms:       arms,
 			})
 		}
 
@@ -1483,6 +1620,30 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 					Line:      w.Line,
 				})
 			}
+			var assigns []policy.SequentialAssignment
+			for _, a := range proc.Assignments {
+				assigns = append(assigns, policy.SequentialAssignment{
+					Target:  a.Target,
+					Signal:  a.Signal,
+					Value:   a.Value,
+					Line:    a.Line,
+					Branch:  a.Branch,
+					Segment: a.Segment,
+				})
+			}
+			var ifs []policy.IfStatement
+			for _, st := range proc.IfStatements {
+				var arms []policy.IfArm
+				for _, arm := range st.Arms {
+					arms = append(arms, policy.IfArm{Line: arm.Line, Branch: arm.Branch, Condition: arm.Condition})
+				}
+				ifs = append(ifs, policy.IfStatement{
+					Branch:  st.Branch,
+					Line:    st.Line,
+					Arms:    arms,
+					HasElse: st.HasElse,
+				})
+			}
 			input.Processes = append(input.Processes, policy.Process{
 				Label:           proc.Label,
 				SensitivityList: sensList,
@@ -1499,8 +1660,12 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 				ProcedureCalls:  procCalls,
 				FunctionCalls:   funcCalls,
 				WaitStatements:  waitStmts,
+				Assignments:     assigns,
+				IfStatements:    ifs,
 				File:            facts.File,
 				Line:            proc.Line,
+				Column:          proc.Column,
+				EndColumn:       proc.EndColumn,
 				InArch:          proc.InArch,
 			})
 		}
@@ -1517,7 +1682,9 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 			}
 			input.ConcurrentAssignments = append(input.ConcurrentAssignments, policy.ConcurrentAssignment{
 				Target:      ca.Target,
+				TargetFull:  ca.TargetFull,
 				ReadSignals: readSigs,
+				Value:       ca.Value,
 				File:        facts.File,
 				Line:        ca.Line,
 				InArch:      ca.InArch,
@@ -1525,6 +1692,18 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 			})
 		}
 
+		// Tri-state drivers ('Z' assignments)
+		for _, ta := range facts.TristateAssignments {
+			input.TristateAssignments = append(input.TristateAssignments, policy.TristateAssignment{
+				Target:    ta.Target,
+				Value:     ta.Value,
+				File:      facts.File,
+				Line:      ta.Line,
+				InArch:    ta.InArch,
+				InProcess: ta.InProcess,
+			})
+		}
+
 		// Advanced analysis: Comparisons for trojan/trigger detection
 		for _, comp := range facts.Comparisons {
 			input.Comparisons = append(input.Comparisons, policy.Comparison{
@@ -1629,11 +1808,24 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 
 		// Configuration declarations
 		for _, cfg := range facts.Configurations {
+			var bindings []policy.ConfigurationBinding
+			for _, b := range cfg.Bindings {
+				bindings = append(bindings, policy.ConfigurationBinding{
+					Scope:        b.ScopePath,
+					Instance:     b.InstanceLabel,
+					Component:    b.ComponentName,
+					Entity:       b.TargetEntity,
+					Architecture: b.TargetArch,
+					Line:         b.Line,
+				})
+			}
 			input.Configurations = append(input.Configurations, policy.Configuration{
-				Name:       cfg.Name,
-				EntityName: cfg.EntityName,
-				File:       facts.File,
-				Line:       cfg.Line,
+				Name:         cfg.Name,
+				EntityName:   cfg.EntityName,
+				Architecture: cfg.Architecture,
+				Bindings:     bindings,
+				File:         facts.File,
+				Line:         cfg.Line,
 			})
 		}
 
@@ -1673,6 +1865,52 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 			})
 		}
 
+		for _, w := range facts.Waivers {
+			input.Waivers = append(input.Waivers, policy.Waiver{
+				Rules:      w.Rules,
+				Expires:    w.Expires,
+				File:       facts.File,
+				Line:       w.Line,
+				TargetLine: w.TargetLine,
+				Raw:        w.Raw,
+			})
+		}
+
+		for _, f := range facts.LanguageFeatures {
+			input.LanguageFeatures = append(input.LanguageFeatures, policy.LanguageFeature{
+				Feature: f.Feature,
+				File:    facts.File,
+				Line:    f.Line,
+			})
+		}
+
+		for _, d := range facts.PSLDirectives {
+			signals := d.Signals
+			if signals == nil {
+				signals = []string{}
+			}
+			input.PSLDirectives = append(input.PSLDirectives, policy.PSLDirective{
+				Kind:    d.Kind,
+				Label:   d.Label,
+				Signals: signals,
+				File:    facts.File,
+				Line:    d.Line,
+				InArch:  d.InArch,
+			})
+		}
+
+		for _, a := range facts.Assertions {
+			input.Assertions = append(input.Assertions, policy.Assertion{
+				Kind:        a.Kind,
+				Severity:    a.Severity,
+				File:        facts.File,
+				Line:        a.Line,
+				InArch:      a.InArch,
+				InProcess:   a.InProcess,
+				ProcessLine: a.ProcessLine,
+			})
+		}
+
 		// Type system: Types
 		for _, t := range facts.Types {
 			// Convert enum literals (ensure not nil)
@@ -1708,6 +1946,7 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 				Fields:        fields,
 				ElementType:   t.ElementType,
 				IndexTypes:    indexTypes,
+				IndexRange:    t.IndexRange,
 				Unconstrained: t.Unconstrained,
 				BaseUnit:      t.BaseUnit,
 				RangeLow:      t.RangeLow,
@@ -1748,15 +1987,16 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 				params = []policy.SubprogramParameter{}
 			}
 			input.Functions = append(input.Functions, policy.FunctionDeclaration{
-				Name:       fn.Name,
-				ReturnType: fn.ReturnType,
-				Parameters: params,
-				IsPure:     fn.IsPure,
-				HasBody:    fn.HasBody,
-				File:       facts.File,
-				Line:       fn.Line,
-				InPackage:  fn.InPackage,
-				InArch:     fn.InArch,
+				Name:        fn.Name,
+				ReturnType:  fn.ReturnType,
+				Parameters:  params,
+				IsPure:      fn.IsPure,
+				HasBody:     fn.HasBody,
+				File:        facts.File,
+				Line:        fn.Line,
+				InPackage:   fn.InPackage,
+				InArch:      fn.InArch,
+				ReadSignals: fn.ReadSignals,
 			})
 		}
 
@@ -1778,13 +2018,14 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 				params = []policy.SubprogramParameter{}
 			}
 			input.Procedures = append(input.Procedures, policy.ProcedureDeclaration{
-				Name:       pr.Name,
-				Parameters: params,
-				HasBody:    pr.HasBody,
-				File:       facts.File,
-				Line:       pr.Line,
-				InPackage:  pr.InPackage,
-				InArch:     pr.InArch,
+				Name:            pr.Name,
+				Parameters:      params,
+				HasBody:         pr.HasBody,
+				File:            facts.File,
+				Line:            pr.Line,
+				InPackage:       pr.InPackage,
+				InArch:          pr.InArch,
+				AssignedSignals: pr.AssignedSignals,
 			})
 		}
 
@@ -1801,6 +2042,41 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 			})
 		}
 
+		// Type system: VHDL-2019 mode views
+		for _, v := range facts.ModeViews {
+			elements := []policy.ModeViewElement{}
+			for _, el := range v.Elements {
+				elements = append(elements, policy.ModeViewElement{
+					Name:      el.Name,
+					Direction: el.Direction,
+					View:      el.View,
+				})
+			}
+			input.ModeViews = append(input.ModeViews, policy.ModeView{
+				Name:      v.Name,
+				Type:      v.Type,
+				Elements:  elements,
+				File:      facts.File,
+				Line:      v.Line,
+				InPackage: v.InPackage,
+				InArch:    v.InArch,
+			})
+		}
+
+		// Attribute specifications (encodings, synthesis directives)
+		for _, a := range facts.AttributeSpecs {
+			input.AttributeSpecs = append(input.AttributeSpecs, policy.AttributeSpec{
+				Name:      a.Name,
+				Targets:   append([]string{}, a.Targets...),
+				Class:     a.Class,
+				Value:     a.Value,
+				File:      facts.File,
+				Line:      a.Line,
+				InPackage: a.InPackage,
+				InArch:    a.InArch,
+			})
+		}
+
 		// Type system info (LEGACY): collect enum literals and constants for filtering
 		input.EnumLiterals = append(input.EnumLiterals, facts.EnumLiterals...)
 		input.Constants = append(input.Constants, facts.Constants...)
@@ -1821,6 +2097,24 @@ func (idx *Indexer) buildPolicyInput() policy.Input {
 	return input
 }
 
+// customRules converts the configured Rhai checks to the policy engine's form
+func customRules(rules []config.CustomRule) []policy.CustomRule {
+	out := make([]policy.CustomRule, 0, len(rules))
+	for _, rule := range rules {
+		out = append(out, policy.CustomRule{ID: rule.ID, Script: rule.Script, Severity: rule.Severity})
+	}
+	return out
+}
+
+// clockDecls converts the declared primary clocks to the policy engine's form
+func clockDecls(clocks []config.ClockDecl) []policy.ClockDecl {
+	out := make([]policy.ClockDecl, 0, len(clocks))
+	for _, clock := range clocks {
+		out = append(out, policy.ClockDecl{Name: clock.Name, Period: clock.Period, Domain: clock.Domain})
+	}
+	return out
+}
+
 func validateVerificationTags(v *validator.Validator, input *policy.Input) error {
 	if len(input.VerificationTags) == 0 {
 		return nil
@@ -2309,6 +2603,17 @@ func (idx *Indexer) buildSymbolRows() []facts.SymbolRow {
 	return rows
 }
 
+// isVendorPath reports whether a file lies under a vendor/ or ip/ directory.
+func isVendorPath(file string) bool {
+	dirs := strings.Split(filepath.ToSlash(filepath.Dir(file)), "/")
+	for _, dir := range dirs {
+		if dir == "vendor" || dir == "ip" {
+			return true
+		}
+	}
+	return false
+}
+
 func (idx *Indexer) findVHDLFiles(root string) ([]string, error) {
 	var files []string
 
@@ -11,6 +11,7 @@ import (
 
 	"github.com/robert-at-pretension-io/vhdl-lint/internal/config"
 	"github.com/robert-at-pretension-io/vhdl-lint/internal/extractor"
+	"github.com/robert-at-pretension-io/vhdl-lint/internal/policy"
 )
 
 type countingExtractor struct {
@@ -197,3 +198,50 @@ func TestCachedRunMatchesFresh(t *testing.T) {
 		}
 	}
 }
+
+func TestCommentEditReusesPolicyCache(t *testing.T) {
+	dir := t.TempDir()
+	file := writeVHDL(t, dir, "a.vhd", "entity a is end entity; -- note\narchitecture rtl of a is begin end architecture;")
+	cacheDir := filepath.Join(dir, ".cache")
+	cfg := defaultTestConfig([]string{file}, cacheDir, true)
+	runIndexerForTest(t, NewWithConfig(cfg), dir)
+
+	// Tag the cached result so a reuse is observable.
+	entry, err := loadPolicyCache(cacheDir)
+	if err != nil || entry == nil {
+		t.Fatalf("load policy cache: %v", err)
+	}
+	marker := policy.Violation{Rule: "cache_marker", Severity: "info", File: file, Line: 1, Message: "cached"}
+	entry.Result.Violations = append(entry.Result.Violations, marker)
+	if err := savePolicyCache(cacheDir, *entry); err != nil {
+		t.Fatalf("save policy cache: %v", err)
+	}
+	hasMarker := func(result LintResult) bool {
+		for _, v := range result.Violations {
+			if v.Rule == marker.Rule {
+				return true
+			}
+		}
+		return false
+	}
+
+	// Comment-only edit: re-extracted, but facts are identical.
+	writeVHDL(t, dir, "a.vhd", "entity a is end entity; -- edited note\narchitecture rtl of a is begin end architecture;")
+	var count int32
+	idx := NewWithConfig(cfg)
+	idx.extractorFactory = func() FactsExtractor {
+		return &countingExtractor{inner: extractor.New(), count: &count}
+	}
+	if result := runIndexerForTest(t, idx, dir); !hasMarker(result) {
+		t.Fatalf("expected cached policy result after comment-only edit")
+	}
+	if got := atomic.LoadInt32(&count); got != 1 {
+		t.Fatalf("expected re-extract after edit, got %d", got)
+	}
+
+	// Semantic edit: facts change, so policy runs again.
+	writeVHDL(t, dir, "a.vhd", "entity a is end entity; -- edited note\narchitecture sim of a is begin end architecture;")
+	if result := runIndexerForTest(t, NewWithConfig(cfg), dir); hasMarker(result) {
+		t.Fatalf("expected fresh policy result after semantic edit")
+	}
+}
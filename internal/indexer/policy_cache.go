@@ -106,7 +106,13 @@ func policyRulesHash() (string, error) {
 	if repoRoot == "" {
 		return "", fmt.Errorf("policy rules hash: repo root not found")
 	}
-	root := filepath.Join(repoRoot, "src", "policy")
+	return hashPolicyTree(filepath.Join(repoRoot, "src", "policy"))
+}
+
+// hashPolicyTree hashes every file under root, not just the Rust sources:
+// the engine also compiles in data files (vendor_primitives/*.toml,
+// construct_detectors.json, rule_docs.toml) that change its results.
+func hashPolicyTree(root string) (string, error) {
 	info, err := os.Stat(root)
 	if err != nil {
 		return "", fmt.Errorf("policy rules hash: %w", err)
@@ -123,9 +129,6 @@ func policyRulesHash() (string, error) {
 		if d.IsDir() {
 			return nil
 		}
-		if filepath.Ext(path) != ".rs" {
-			return nil
-		}
 		files = append(files, path)
 		return nil
 	})
@@ -99,3 +99,31 @@ func TestClearPolicyCache(t *testing.T) {
 		t.Fatalf("expected cache file to be removed, got err: %v", err)
 	}
 }
+
+func TestPolicyRulesHashCoversDataFiles(t *testing.T) {
+	root := t.TempDir()
+	write := func(rel, text string) {
+		path := filepath.Join(root, rel)
+		if err := os.MkdirAll(filepath.Dir(path), 0o755); err != nil {
+			t.Fatalf("mkdir: %v", err)
+		}
+		if err := os.WriteFile(path, []byte(text), 0o644); err != nil {
+			t.Fatalf("write %s: %v", rel, err)
+		}
+	}
+	write("mod.rs", "pub mod vendor;\n")
+	write("vendor_primitives/xilinx.toml", "primitives = [\"BUFG\"]\n")
+	before, err := hashPolicyTree(root)
+	if err != nil {
+		t.Fatalf("hashPolicyTree error: %v", err)
+	}
+
+	write("vendor_primitives/xilinx.toml", "primitives = [\"BUFG\", \"IBUF\"]\n")
+	after, err := hashPolicyTree(root)
+	if err != nil {
+		t.Fatalf("hashPolicyTree error: %v", err)
+	}
+	if before == after {
+		t.Fatalf("expected hash to change when a primitive database changes")
+	}
+}
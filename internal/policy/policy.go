@@ -19,23 +19,45 @@ type Engine struct {
 
 // Violation represents a policy violation
 type Violation struct {
-	Rule     string `json:"rule"`
-	Severity string `json:"severity"`
-	File     string `json:"file"`
-	Line     int    `json:"line"`
-	Message  string `json:"message"`
+	Rule        string `json:"rule"`
+	Severity    string `json:"severity"`
+	File        string `json:"file"`
+	Line        int    `json:"line"`
+	Column      int    `json:"column,omitempty"` // 1-based column range (end exclusive) of the name the violation is about
+	EndLine     int    `json:"end_line,omitempty"`
+	EndColumn   int    `json:"end_column,omitempty"`
+	Message     string `json:"message"`
+	Scope       string `json:"scope,omitempty"`       // Hierarchical path, entity:arch[:generate...][:process]; empty outside architectures
+	Confidence  string `json:"confidence,omitempty"`  // "high", "medium" or "low"; heuristic rules report less than high
+	Snippet     string `json:"snippet,omitempty"`     // Source lines with carets, with --with-snippets
+	Fingerprint string `json:"fingerprint,omitempty"` // Stable id within the result, see baseline::occurrence_fingerprints
 }
 
 // Result contains the evaluation results
 type Result struct {
-	Violations          []Violation          `json:"violations"`
-	Summary             Summary              `json:"summary"`
-	MissingChecks       []MissingCheckTask   `json:"missing_checks,omitempty"`
-	AmbiguousConstructs []AmbiguousConstruct `json:"ambiguous_constructs,omitempty"`
+	Violations           []Violation           `json:"violations"`
+	Summary              Summary               `json:"summary"`
+	MissingChecks        []MissingCheckTask    `json:"missing_checks,omitempty"`
+	AmbiguousConstructs  []AmbiguousConstruct  `json:"ambiguous_constructs,omitempty"`
+	ClockDomains         *ClockDomainMap       `json:"clock_domains,omitempty"`         // Inferred clock domains and crossings
+	Metrics              *Metrics              `json:"metrics,omitempty"`               // Per-file and per-architecture design metrics
+	VerificationCoverage *VerificationCoverage `json:"verification_coverage,omitempty"` // Assertion density and per-construct check coverage
+	Suppressions         *Suppressions         `json:"suppressions,omitempty"`          // Violations dropped by waivers or the baseline
+	RuleDocs             map[string]RuleDoc    `json:"rule_docs,omitempty"`             // Documentation of each rule that fired
 }
 
 // Summary provides aggregate counts
 type Summary struct {
+	TotalViolations int                       `json:"total_violations"`
+	Errors          int                       `json:"errors"`
+	Warnings        int                       `json:"warnings"`
+	Info            int                       `json:"info"`
+	ByDirectory     map[string]SeverityCounts `json:"by_directory,omitempty"` // Rollup by directory of the violating file
+	ByLibrary       map[string]SeverityCounts `json:"by_library,omitempty"`   // Rollup by VHDL library of the violating file
+}
+
+// SeverityCounts provides violation counts for one directory or library
+type SeverityCounts struct {
 	TotalViolations int `json:"total_violations"`
 	Errors          int `json:"errors"`
 	Warnings        int `json:"warnings"`
@@ -58,6 +80,7 @@ type MissingCheckTask struct {
 	MissingIDs []string           `json:"missing_ids"`
 	Bindings   map[string]string  `json:"bindings,omitempty"`
 	Notes      []string           `json:"notes,omitempty"`
+	Confidence *float64           `json:"confidence,omitempty"` // Set when bindings were inferred from port names
 }
 
 // AmbiguousConstruct reports uncertain detection candidates.
@@ -67,11 +90,141 @@ type AmbiguousConstruct struct {
 	File       string              `json:"file"`
 	Line       int                 `json:"line"`
 	Candidates map[string][]string `json:"candidates,omitempty"`
+	Confidence float64             `json:"confidence"`           // Confidence of BestGuess, below the binding threshold
+	BestGuess  map[string]string   `json:"best_guess,omitempty"` // Highest-scoring binding from port names and comments
+}
+
+// DetectedConstruct is a construct verification checks bind to.
+type DetectedConstruct struct {
+	Kind       string            `json:"kind"`
+	Scope      string            `json:"scope"`
+	File       string            `json:"file"`
+	Line       int               `json:"line"`
+	Bindings   map[string]string `json:"bindings"`
+	Confidence *float64          `json:"confidence,omitempty"` // Set when bindings were inferred from port names
+}
+
+// ClockDomain groups the registers and clocked processes driven by one clock
+// in one architecture.
+type ClockDomain struct {
+	Clock     string   `json:"clock"`
+	InArch    string   `json:"in_arch"`
+	File      string   `json:"file"`
+	Line      int      `json:"line"` // Line of the first process in the domain
+	Processes []string `json:"processes"`
+	Registers []string `json:"registers"`
+}
+
+// ClockDomainMap is the policy engine's clock-domain report.
+type ClockDomainMap struct {
+	Domains   []ClockDomain `json:"domains"`
+	Crossings []CDCCrossing `json:"crossings"`
+}
+
+// ArchitectureMetrics is the size and complexity of one architecture.
+type ArchitectureMetrics struct {
+	Entity       string `json:"entity"`
+	Architecture string `json:"architecture"`
+	File         string `json:"file"`
+	Line         int    `json:"line"`
+	Processes    int    `json:"processes"`
+	Signals      int    `json:"signals"`
+	Instances    int    `json:"instances"`      // Instantiations: fan-out in the hierarchy
+	MaxCombDepth int    `json:"max_comb_depth"` // Longest combinational signal_deps chain
+	Complexity   int    `json:"complexity"`     // 1 + if/elsif conditions, extra case alternatives, conditional assignments
+}
+
+// FileMetrics rolls architecture metrics up per file.
+type FileMetrics struct {
+	File          string `json:"file"`
+	Entities      int    `json:"entities"`
+	Architectures int    `json:"architectures"`
+	Processes     int    `json:"processes"`
+	Signals       int    `json:"signals"`
+	Instances     int    `json:"instances"`
+	MaxCombDepth  int    `json:"max_comb_depth"`
+	Complexity    int    `json:"complexity"`
+}
+
+// Metrics is the policy engine's design-metrics report.
+type Metrics struct {
+	Files         []FileMetrics         `json:"files"`
+	Architectures []ArchitectureMetrics `json:"architectures"`
+}
+
+// AssertionDensity counts the assertions and verification checks written in
+// one architecture.
+type AssertionDensity struct {
+	Entity        string  `json:"entity"`
+	Architecture  string  `json:"architecture"`
+	File          string  `json:"file"`
+	Line          int     `json:"line"`
+	Lines         int     `json:"lines"`          // Up to the next design unit, or the last statement in the file's last one
+	Assertions    int     `json:"assertions"`     // Plain VHDL assert statements
+	PslDirectives int     `json:"psl_directives"` // PSL assert/assume/cover directives
+	Tags          int     `json:"tags"`           // Valid verification tags scoped to the architecture
+	Per100Lines   float64 `json:"per_100_lines"`
+}
+
+// KindCoverage rolls construct check coverage up per construct kind.
+type KindCoverage struct {
+	Kind       string `json:"kind"`
+	Constructs int    `json:"constructs"`
+	Required   int    `json:"required"`
+	Satisfied  int    `json:"satisfied"`
+}
+
+// ConstructCoverage splits the checks one construct requires into present
+// and missing.
+type ConstructCoverage struct {
+	Kind      string   `json:"kind"`
+	Scope     string   `json:"scope"`
+	File      string   `json:"file"`
+	Line      int      `json:"line"`
+	Satisfied []string `json:"satisfied"`
+	Missing   []string `json:"missing"`
+}
+
+// VerificationCoverage is the policy engine's assertion density and check
+// coverage report.
+type VerificationCoverage struct {
+	Architectures []AssertionDensity  `json:"architectures"`
+	Kinds         []KindCoverage      `json:"kinds"`
+	Constructs    []ConstructCoverage `json:"constructs"`
+}
+
+// Suppressions counts violations kept out of the result by waivers or the
+// baseline.
+type Suppressions struct {
+	Total     int            `json:"total"`
+	Waived    int            `json:"waived"`    // Dropped by an unexpired --@waive comment
+	Baselined int            `json:"baselined"` // Dropped by --baseline
+	ByRule    map[string]int `json:"by_rule"`
+	ByFile    map[string]int `json:"by_file"`
+}
+
+// RuleDoc is a rule's documentation from the policy engine's registry.
+type RuleDoc struct {
+	ID        string `json:"id"`
+	Category  string `json:"category"` // Rule module, as --only/--skip name it
+	Severity  string `json:"severity"` // Default severity before config overrides
+	Summary   string `json:"summary"`
+	Rationale string `json:"rationale"`
+	Bad       string `json:"bad"`  // VHDL example that triggers the rule
+	Good      string `json:"good"` // The same example, fixed
+}
+
+// ConstructInventory lists every construct detection found, bound or not.
+type ConstructInventory struct {
+	Constructs          []DetectedConstruct  `json:"constructs"`
+	AmbiguousConstructs []AmbiguousConstruct `json:"ambiguous_constructs"`
 }
 
 // Input is the data structure passed to the Rust policy engine
 type Input struct {
 	Standard              string                 `json:"standard"`
+	Profile               string                 `json:"profile,omitempty"`      // Lint profile ("tb" = testbench checks)
+	TopEntities           []string               `json:"top_entities,omitempty"` // Dead-code reachability roots (--top)
 	FileCount             int                    `json:"file_count"`
 	Entities              []Entity               `json:"entities"`
 	Architectures         []Architecture         `json:"architectures"`
@@ -91,25 +244,32 @@ type Input struct {
 	VerificationBlocks    []VerificationBlock    `json:"verification_blocks"`
 	VerificationTags      []VerificationTag      `json:"verification_tags"`
 	VerificationTagErrors []VerificationTagError `json:"verification_tag_errors"`
+	Waivers               []Waiver               `json:"waivers"`           // Inline --@waive comments
+	LanguageFeatures      []LanguageFeature      `json:"language_features"` // VHDL-2008 constructs gated on Standard
+	PSLDirectives         []PSLDirective         `json:"psl_directives"`
+	Assertions            []Assertion            `json:"assertions"`             // Plain assert/report statements
 	Instances             []Instance             `json:"instances"`              // Component/entity instantiations with port maps
 	CaseStatements        []CaseStatement        `json:"case_statements"`        // Case statements for latch detection
 	Processes             []Process              `json:"processes"`              // Process statements for sensitivity/clock analysis
 	ConcurrentAssignments []ConcurrentAssignment `json:"concurrent_assignments"` // Concurrent signal assignments (outside processes)
+	TristateAssignments   []TristateAssignment   `json:"tristate_assignments"`   // Assignments driving 'Z'
 	Generates             []GenerateStatement    `json:"generates"`              // Generate statements (for/if/case generate)
 	Configurations        []Configuration        `json:"configurations"`         // Configuration declarations
 	// Type system
-	Types         []TypeDeclaration      `json:"types"`          // Type declarations (enum, record, array, etc.)
-	Subtypes      []SubtypeDeclaration   `json:"subtypes"`       // Subtype declarations
-	Functions     []FunctionDeclaration  `json:"functions"`      // Function declarations/bodies
-	Procedures    []ProcedureDeclaration `json:"procedures"`     // Procedure declarations/bodies
-	ConstantDecls []ConstantDeclaration  `json:"constant_decls"` // Constant declarations with full info
+	Types          []TypeDeclaration      `json:"types"`           // Type declarations (enum, record, array, etc.)
+	Subtypes       []SubtypeDeclaration   `json:"subtypes"`        // Subtype declarations
+	Functions      []FunctionDeclaration  `json:"functions"`       // Function declarations/bodies
+	Procedures     []ProcedureDeclaration `json:"procedures"`      // Procedure declarations/bodies
+	ConstantDecls  []ConstantDeclaration  `json:"constant_decls"`  // Constant declarations with full info
+	ModeViews      []ModeView             `json:"mode_views"`      // VHDL-2019 mode view declarations
+	AttributeSpecs []AttributeSpec        `json:"attribute_specs"` // Attribute specifications (fsm_encoding, keep, ...)
 	// Type system info for filtering false positives (LEGACY - use Types/ConstantDecls instead)
 	EnumLiterals    []string `json:"enum_literals"`    // Enum literals from type declarations
 	Constants       []string `json:"constants"`        // Constants from constant declarations (names only)
 	SharedVariables []string `json:"shared_variables"` // Shared variable names (not signals)
 	// Advanced analysis for security/power/correctness
 	Comparisons   []Comparison   `json:"comparisons"`    // Comparisons for trojan/trigger detection
-	ArithmeticOps []ArithmeticOp `json:"arithmetic_ops"` // Expensive operations for power analysis
+	ArithmeticOps []ArithmeticOp `json:"arithmetic_ops"` // Arithmetic operations for power and width analysis
 	SignalDeps    []SignalDep    `json:"signal_deps"`    // Signal dependencies for loop detection
 	CDCCrossings  []CDCCrossing  `json:"cdc_crossings"`  // Clock domain crossings
 	SignalUsages  []SignalUsage  `json:"signal_usages"`  // Signal read/write/port-map tracking
@@ -121,38 +281,84 @@ type Input struct {
 
 // LintRuleConfig contains rule configuration passed to the Rust policy engine
 type LintRuleConfig struct {
-	Rules map[string]string `json:"rules"` // rule name -> "off", "warning", "error"
+	Rules             map[string]string   `json:"rules"`                         // rule name -> "off", "warning", "error"
+	OnlyModules       []string            `json:"only_modules,omitempty"`        // Run only these rule modules (e.g. "cdc")
+	SkipModules       []string            `json:"skip_modules,omitempty"`        // Never run these rule modules
+	GenericPrefix     string              `json:"generic_prefix,omitempty"`      // generic_naming_convention prefix
+	NamingPatterns    map[string]string   `json:"naming_patterns,omitempty"`     // Identifier class -> regex for naming_convention_custom
+	PortOrder         []string            `json:"port_order,omitempty"`          // port_ordering group order
+	BitOrder          string              `json:"bit_order,omitempty"`           // mixed_bit_order preferred direction
+	ResetStyle        string              `json:"reset_style,omitempty"`         // reset_style_inconsistent preferred style ("sync" or "async")
+	AppliesTo         map[string][]string `json:"applies_to,omitempty"`          // rule name -> contexts ("rtl", "tb")
+	Fast              bool                `json:"fast,omitempty"`                // Run only prioritized fast rules
+	ErrorBudget       int                 `json:"error_budget,omitempty"`        // Fast mode stops after this many errors
+	ShardWorkers      int                 `json:"shard_workers,omitempty"`       // Parallel per-entity shards for intra-entity modules
+	ModuleWorkers     int                 `json:"module_workers,omitempty"`      // Threads evaluating rule modules (0 = one per core)
+	StrictBinding     bool                `json:"strict_binding,omitempty"`      // Skip instance checks in architectures no configuration or instantiation selects
+	TestbenchRoots    bool                `json:"testbench_roots,omitempty"`     // Testbench entities are dead-code roots too
+	GenerateLimit     int                 `json:"generate_limit,omitempty"`      // Cap on elaborated copies of an instance inside for-generates (0 = 1024)
+	MaxLogicDepth     int                 `json:"max_logic_depth,omitempty"`     // Chained combinational assignments deep_combinational_path allows (0 = 8)
+	FsmEncodingStates int                 `json:"fsm_encoding_states,omitempty"` // States above which fsm_missing_encoding wants an encoding attribute (0 = 8)
+	Target            string              `json:"target,omitempty"`              // Implementation target ("asic" or "fpga"; empty = fpga)
+	Vendor            string              `json:"vendor,omitempty"`              // FPGA vendor whose primitives resolve, or "none" (vendor_primitive)
+	ClockMonitors     []string            `json:"clock_monitors,omitempty"`      // Entities/architectures/processes exempt from clock_used_as_data
+	BindingPatterns   map[string][]string `json:"binding_patterns,omitempty"`    // Port name globs per ready/valid role ("valid", "ready")
+	BindingConfidence float64             `json:"binding_confidence,omitempty"`  // Minimum confidence for name-based ready/valid bindings (0 = 0.75)
+	AssertionDensity  float64             `json:"assertion_density,omitempty"`   // Minimum assertions per 100 lines for low_assertion_density (0 = 1)
+	MinConfidence     string              `json:"min_confidence,omitempty"`      // Drop violations below this confidence ("low", "medium", "high")
+	CustomRules       []CustomRule        `json:"custom_rules,omitempty"`        // Rhai scripts run by policy::scripting
+	Clocks            []ClockDecl         `json:"clocks,omitempty"`              // Declared primary clocks (unknown_clock_source, CDC domains)
+}
+
+// CustomRule is a Rhai script whose check(input) function returns violations
+type CustomRule struct {
+	ID       string `json:"id"`
+	Script   string `json:"script"`
+	Severity string `json:"severity,omitempty"`
+}
+
+// ClockDecl is a declared primary clock; clocks sharing a domain are synchronous
+type ClockDecl struct {
+	Name   string `json:"name"`
+	Period string `json:"period,omitempty"`
+	Domain string `json:"domain,omitempty"`
 }
 
 // Process represents a VHDL process for policy analysis
 type Process struct {
-	Label           string          `json:"label"`
-	SensitivityList []string        `json:"sensitivity_list"`
-	IsSequential    bool            `json:"is_sequential"`
-	IsCombinational bool            `json:"is_combinational"`
-	ClockSignal     string          `json:"clock_signal"`
-	ClockEdge       string          `json:"clock_edge"`
-	HasReset        bool            `json:"has_reset"`
-	ResetSignal     string          `json:"reset_signal"`
-	ResetAsync      bool            `json:"reset_async"`
-	AssignedSignals []string        `json:"assigned_signals"`
-	ReadSignals     []string        `json:"read_signals"`
-	Variables       []VariableDecl  `json:"variables"`
-	ProcedureCalls  []ProcedureCall `json:"procedure_calls"`
-	FunctionCalls   []FunctionCall  `json:"function_calls"`
-	WaitStatements  []WaitStatement `json:"wait_statements"`
-	File            string          `json:"file"`
-	Line            int             `json:"line"`
-	InArch          string          `json:"in_arch"`
+	Label           string                 `json:"label"`
+	SensitivityList []string               `json:"sensitivity_list"`
+	IsSequential    bool                   `json:"is_sequential"`
+	IsCombinational bool                   `json:"is_combinational"`
+	ClockSignal     string                 `json:"clock_signal"`
+	ClockEdge       string                 `json:"clock_edge"`
+	HasReset        bool                   `json:"has_reset"`
+	ResetSignal     string                 `json:"reset_signal"`
+	ResetAsync      bool                   `json:"reset_async"`
+	AssignedSignals []string               `json:"assigned_signals"`
+	ReadSignals     []string               `json:"read_signals"`
+	Variables       []VariableDecl         `json:"variables"`
+	ProcedureCalls  []ProcedureCall        `json:"procedure_calls"`
+	FunctionCalls   []FunctionCall         `json:"function_calls"`
+	WaitStatements  []WaitStatement        `json:"wait_statements"`
+	Assignments     []SequentialAssignment `json:"assignments,omitempty"`
+	IfStatements    []IfStatement          `json:"if_statements,omitempty"`
+	File            string                 `json:"file"`
+	Line            int                    `json:"line"`
+	Column          int                    `json:"column,omitempty"`
+	EndColumn       int                    `json:"end_column,omitempty"`
+	InArch          string                 `json:"in_arch"`
 }
 
 // Simplified types for policy input (mirrors extractor types)
 type Entity struct {
-	Name     string        `json:"name"`
-	File     string        `json:"file"`
-	Line     int           `json:"line"`
-	Ports    []Port        `json:"ports"`
-	Generics []GenericDecl `json:"generics"`
+	Name      string        `json:"name"`
+	File      string        `json:"file"`
+	Line      int           `json:"line"`
+	Column    int           `json:"column,omitempty"` // 1-based columns of the name (end exclusive)
+	EndColumn int           `json:"end_column,omitempty"`
+	Ports     []Port        `json:"ports"`
+	Generics  []GenericDecl `json:"generics"`
 }
 
 type Architecture struct {
@@ -160,12 +366,16 @@ type Architecture struct {
 	EntityName string `json:"entity_name"`
 	File       string `json:"file"`
 	Line       int    `json:"line"`
+	Column     int    `json:"column,omitempty"`
+	EndColumn  int    `json:"end_column,omitempty"`
 }
 
 type Package struct {
-	Name string `json:"name"`
-	File string `json:"file"`
-	Line int    `json:"line"`
+	Name      string `json:"name"`
+	File      string `json:"file"`
+	Line      int    `json:"line"`
+	Column    int    `json:"column,omitempty"`
+	EndColumn int    `json:"end_column,omitempty"`
 }
 
 type Component struct {
@@ -179,12 +389,15 @@ type Component struct {
 }
 
 type Signal struct {
-	Name     string `json:"name"`
-	Type     string `json:"type"`
-	File     string `json:"file"`
-	Line     int    `json:"line"`
-	InEntity string `json:"in_entity"`
-	Width    int    `json:"width"` // Estimated bit width (0 if unknown)
+	Name      string `json:"name"`
+	Type      string `json:"type"`
+	File      string `json:"file"`
+	Line      int    `json:"line"`
+	Column    int    `json:"column,omitempty"`
+	EndColumn int    `json:"end_column,omitempty"`
+	InEntity  string `json:"in_entity"`
+	Width     int    `json:"width"`             // Estimated bit width (0 if unknown)
+	Default   string `json:"default,omitempty"` // Initial value expression, empty if none
 }
 
 type Port struct {
@@ -193,8 +406,12 @@ type Port struct {
 	Type      string `json:"type"`
 	Default   string `json:"default"`
 	Line      int    `json:"line"`
+	Column    int    `json:"column,omitempty"`
+	EndColumn int    `json:"end_column,omitempty"`
 	InEntity  string `json:"in_entity"`
-	Width     int    `json:"width"` // Estimated bit width (0 if unknown)
+	Width     int    `json:"width"`             // Estimated bit width (0 if unknown)
+	Comment   string `json:"comment,omitempty"` // Trailing "--" comment on the declaration line
+	View      string `json:"view,omitempty"`    // Mode view name when Direction is "view" (VHDL-2019)
 }
 
 type GenericDecl struct {
@@ -268,6 +485,31 @@ type WaitStatement struct {
 	Line      int      `json:"line"`
 }
 
+// SequentialAssignment is a signal assignment inside a process, in source order
+type SequentialAssignment struct {
+	Target  string `json:"target"`
+	Signal  string `json:"signal"`
+	Value   string `json:"value,omitempty"` // RHS text of plain assignments
+	Line    int    `json:"line"`
+	Branch  string `json:"branch"`  // Enclosing branch path ("" = process body)
+	Segment int    `json:"segment"` // Wait statements executed before this assignment
+}
+
+// IfStatement is an if/elsif/else chain inside a process
+type IfStatement struct {
+	Branch  string  `json:"branch"` // Branch path the statement sits in ("" = process body)
+	Line    int     `json:"line"`
+	Arms    []IfArm `json:"arms"` // Branches in source order; the last is the else when HasElse
+	HasElse bool    `json:"has_else"`
+}
+
+// IfArm is one branch of an if statement
+type IfArm struct {
+	Line      int    `json:"line"`
+	Branch    string `json:"branch"`              // Path element ("if@<byte>.<index>") added to the branch of assignments inside
+	Condition string `json:"condition,omitempty"` // Condition text; empty for the else branch
+}
+
 type Dependency struct {
 	Source   string `json:"source"`
 	Target   string `json:"target"`
@@ -348,37 +590,89 @@ type VerificationTagError struct {
 	InArch  string `json:"in_arch"`
 }
 
+// Waiver is an inline --@waive comment waiving rules on its target line.
+type Waiver struct {
+	Rules      []string `json:"rules"`
+	Expires    string   `json:"expires"` // YYYY-MM-DD; empty never expires
+	File       string   `json:"file"`
+	Line       int      `json:"line"`
+	TargetLine int      `json:"target_line"` // Own line when trailing code, else the next code line
+	Raw        string   `json:"raw"`
+}
+
+// LanguageFeature is one use of a version-dependent construct the policy
+// engine gates on the configured standard.
+type LanguageFeature struct {
+	Feature string `json:"feature"` // "block_comment" or "matching_case"
+	File    string `json:"file"`
+	Line    int    `json:"line"`
+}
+
+// PSLDirective represents a concurrent PSL assert/assume/cover/restrict directive.
+type PSLDirective struct {
+	Kind    string   `json:"kind"`
+	Label   string   `json:"label"`
+	Signals []string `json:"signals"`
+	File    string   `json:"file"`
+	Line    int      `json:"line"`
+	InArch  string   `json:"in_arch"`
+}
+
+// Assertion represents a plain (non-PSL) assert or report statement.
+type Assertion struct {
+	Kind        string `json:"kind"`         // "assert" or "report"
+	Severity    string `json:"severity"`     // Lowercase severity clause, empty if absent
+	File        string `json:"file"`         // Source file
+	Line        int    `json:"line"`         // Statement line
+	InArch      string `json:"in_arch"`      // Enclosing architecture
+	InProcess   string `json:"in_process"`   // Enclosing process label
+	ProcessLine int    `json:"process_line"` // Enclosing process line (0 = concurrent)
+}
+
 // Instance represents a component/entity instantiation with port/generic mappings
 // Enables system-level analysis (cross-module signal tracing, clock mismatch detection)
 type Instance struct {
-	Name         string            `json:"name"`        // Instance label (e.g., "u_cpu")
-	Target       string            `json:"target"`      // Target entity/component (e.g., "work.cpu")
-	PortMap      map[string]string `json:"port_map"`    // Formal port -> actual signal
-	GenericMap   map[string]string `json:"generic_map"` // Formal generic -> actual value
+	Name         string            `json:"name"`                  // Instance label (e.g., "u_cpu")
+	Target       string            `json:"target"`                // Target entity/component (e.g., "work.cpu")
+	TargetArch   string            `json:"target_arch,omitempty"` // Explicit architecture (e.g., "rtl")
+	PortMap      map[string]string `json:"port_map"`              // Formal port -> actual signal
+	GenericMap   map[string]string `json:"generic_map"`           // Formal generic -> actual value
 	Associations []Association     `json:"associations"`
 	File         string            `json:"file"`
 	Line         int               `json:"line"`
+	Column       int               `json:"column,omitempty"`
+	EndColumn    int               `json:"end_column,omitempty"`
 	InArch       string            `json:"in_arch"` // Which architecture contains this instance
 }
 
 // CaseStatement represents a VHDL case statement for latch detection
 // A case statement without "others" can infer a latch in combinational logic
 type CaseStatement struct {
-	Expression string   `json:"expression"` // The case expression (e.g., "state")
-	Choices    []string `json:"choices"`    // All explicit choices
-	HasOthers  bool     `json:"has_others"` // true if "when others =>" is present
-	File       string   `json:"file"`
-	Line       int      `json:"line"`
-	InProcess  string   `json:"in_process"`  // Which process contains this case statement
-	InArch     string   `json:"in_arch"`     // Which architecture
-	IsComplete bool     `json:"is_complete"` // true if HasOthers or all values covered
+	Expression string    `json:"expression"` // The case expression (e.g., "state")
+	Choices    []string  `json:"choices"`    // All explicit choices
+	HasOthers  bool      `json:"has_others"` // true if "when others =>" is present
+	File       string    `json:"file"`
+	Line       int       `json:"line"`
+	InProcess  string    `json:"in_process"`     // Which process contains this case statement
+	InArch     string    `json:"in_arch"`        // Which architecture
+	IsComplete bool      `json:"is_complete"`    // true if HasOthers or all values covered
+	Arms       []CaseArm `json:"arms,omitempty"` // Alternatives in source order
+}
+
+// CaseArm is one alternative of a case statement
+type CaseArm struct {
+	Choices []string `json:"choices"` // Choices of the alternative ("others" for the catch-all)
+	Line    int      `json:"line"`
+	Branch  string   `json:"branch"` // Path element ("case@<byte>") added to the branch of assignments inside
 }
 
 // ConcurrentAssignment represents a concurrent signal assignment (outside processes)
 // Enables detection of undriven/multi-driven signals that were previously missed
 type ConcurrentAssignment struct {
-	Target        string   `json:"target"`       // Signal being assigned (LHS)
-	ReadSignals   []string `json:"read_signals"` // Signals being read (RHS)
+	Target        string   `json:"target"`                // Signal being assigned (LHS)
+	TargetFull    string   `json:"target_full,omitempty"` // Full target text including slices
+	ReadSignals   []string `json:"read_signals"`          // Signals being read (RHS)
+	Value         string   `json:"value,omitempty"`       // RHS text of plain simple assignments
 	File          string   `json:"file"`
 	Line          int      `json:"line"`
 	InArch        string   `json:"in_arch"`        // Which architecture contains this assignment
@@ -387,11 +681,21 @@ type ConcurrentAssignment struct {
 	GenerateLabel string   `json:"generate_label"` // Label of containing generate block
 }
 
+// TristateAssignment is a signal assignment whose value contains a 'Z' literal
+type TristateAssignment struct {
+	Target    string `json:"target"`
+	Value     string `json:"value"` // RHS text
+	File      string `json:"file"`
+	Line      int    `json:"line"`
+	InArch    string `json:"in_arch"`
+	InProcess string `json:"in_process,omitempty"` // Label of the containing process
+}
+
 // Comparison represents a comparison operation for trojan/trigger detection
 // Tracks comparisons against literals, especially large "magic" values
 type Comparison struct {
 	LeftOperand  string `json:"left_operand"`  // Signal or expression on left
-	Operator     string `json:"operator"`      // =, /=, <, >, <=, >=
+	Operator     string `json:"operator"`      // =, /=, <, >, <=, >=, std_match
 	RightOperand string `json:"right_operand"` // Signal, literal, or expression on right
 	IsLiteral    bool   `json:"is_literal"`    // True if right operand is a literal
 	LiteralValue string `json:"literal_value"` // The literal value if IsLiteral
@@ -403,11 +707,11 @@ type Comparison struct {
 	InArch       string `json:"in_arch"`
 }
 
-// ArithmeticOp represents an expensive arithmetic operation for power analysis
+// ArithmeticOp represents an arithmetic operation for power and width analysis
 type ArithmeticOp struct {
-	Operator    string   `json:"operator"`     // *, /, mod, rem, **
+	Operator    string   `json:"operator"`     // +, -, *, /, mod, rem, **
 	Operands    []string `json:"operands"`     // Input signals/expressions
-	Result      string   `json:"result"`       // Output signal
+	Result      string   `json:"result"`       // Signal assigned by the enclosing assignment, if any
 	IsGuarded   bool     `json:"is_guarded"`   // True if inputs are gated by enable
 	GuardSignal string   `json:"guard_signal"` // The enable/valid signal if guarded
 	File        string   `json:"file"`
@@ -482,10 +786,22 @@ type GenerateStatement struct {
 
 // Configuration represents a VHDL configuration declaration
 type Configuration struct {
-	Name       string `json:"name"`
-	EntityName string `json:"entity_name"`
-	File       string `json:"file"`
-	Line       int    `json:"line"`
+	Name         string                 `json:"name"`
+	EntityName   string                 `json:"entity_name"`
+	Architecture string                 `json:"architecture,omitempty"` // Top-level block configuration
+	Bindings     []ConfigurationBinding `json:"bindings,omitempty"`
+	File         string                 `json:"file"`
+	Line         int                    `json:"line"`
+}
+
+// ConfigurationBinding is a component configuration binding instances to an entity/architecture
+type ConfigurationBinding struct {
+	Scope        []string `json:"scope,omitempty"` // Block configuration nesting, architecture first
+	Instance     string   `json:"instance"`
+	Component    string   `json:"component"`
+	Entity       string   `json:"entity"`
+	Architecture string   `json:"architecture,omitempty"`
+	Line         int      `json:"line"`
 }
 
 // =============================================================================
@@ -504,6 +820,7 @@ type TypeDeclaration struct {
 	Fields        []RecordField `json:"fields,omitempty"`        // For records
 	ElementType   string        `json:"element_type,omitempty"`  // For arrays
 	IndexTypes    []string      `json:"index_types,omitempty"`   // For arrays
+	IndexRange    string        `json:"index_range,omitempty"`   // For arrays: first index constraint
 	Unconstrained bool          `json:"unconstrained,omitempty"` // For arrays
 	BaseUnit      string        `json:"base_unit,omitempty"`     // For physical types
 	RangeLow      string        `json:"range_low,omitempty"`     // For range types
@@ -532,26 +849,28 @@ type SubtypeDeclaration struct {
 
 // FunctionDeclaration represents a VHDL function declaration or body
 type FunctionDeclaration struct {
-	Name       string                `json:"name"`
-	ReturnType string                `json:"return_type"`
-	Parameters []SubprogramParameter `json:"parameters,omitempty"`
-	IsPure     bool                  `json:"is_pure"`
-	HasBody    bool                  `json:"has_body"`
-	File       string                `json:"file"`
-	Line       int                   `json:"line"`
-	InPackage  string                `json:"in_package,omitempty"`
-	InArch     string                `json:"in_arch,omitempty"`
+	Name        string                `json:"name"`
+	ReturnType  string                `json:"return_type"`
+	Parameters  []SubprogramParameter `json:"parameters,omitempty"`
+	IsPure      bool                  `json:"is_pure"`
+	HasBody     bool                  `json:"has_body"`
+	File        string                `json:"file"`
+	Line        int                   `json:"line"`
+	InPackage   string                `json:"in_package,omitempty"`
+	InArch      string                `json:"in_arch,omitempty"`
+	ReadSignals []string              `json:"read_signals,omitempty"` // Body reads that are not parameters or locals
 }
 
 // ProcedureDeclaration represents a VHDL procedure declaration or body
 type ProcedureDeclaration struct {
-	Name       string                `json:"name"`
-	Parameters []SubprogramParameter `json:"parameters,omitempty"`
-	HasBody    bool                  `json:"has_body"`
-	File       string                `json:"file"`
-	Line       int                   `json:"line"`
-	InPackage  string                `json:"in_package,omitempty"`
-	InArch     string                `json:"in_arch,omitempty"`
+	Name            string                `json:"name"`
+	Parameters      []SubprogramParameter `json:"parameters,omitempty"`
+	HasBody         bool                  `json:"has_body"`
+	File            string                `json:"file"`
+	Line            int                   `json:"line"`
+	InPackage       string                `json:"in_package,omitempty"`
+	InArch          string                `json:"in_arch,omitempty"`
+	AssignedSignals []string              `json:"assigned_signals,omitempty"` // Signal assignment targets in the body
 }
 
 // SubprogramParameter represents a parameter in a function or procedure
@@ -575,6 +894,36 @@ type ConstantDeclaration struct {
 	InArch    string `json:"in_arch,omitempty"`    // Architecture if local constant
 }
 
+// ModeView represents a VHDL-2019 mode view declaration
+type ModeView struct {
+	Name      string            `json:"name"`
+	Type      string            `json:"type"` // Record type the view applies to
+	Elements  []ModeViewElement `json:"elements"`
+	File      string            `json:"file"`
+	Line      int               `json:"line"`
+	InPackage string            `json:"in_package,omitempty"`
+	InArch    string            `json:"in_arch,omitempty"`
+}
+
+// ModeViewElement is the mode of one record element in a mode view
+type ModeViewElement struct {
+	Name      string `json:"name"`
+	Direction string `json:"direction"`      // "in", "out", "inout", "buffer", or "view"
+	View      string `json:"view,omitempty"` // Nested view name when Direction is "view"
+}
+
+// AttributeSpec is an attribute specification: attribute name of targets : class is value
+type AttributeSpec struct {
+	Name      string   `json:"name"`
+	Targets   []string `json:"targets"` // Named items, or "others"/"all"
+	Class     string   `json:"class"`   // Entity class ("signal", "type", "entity", ...)
+	Value     string   `json:"value"`   // Value expression text
+	File      string   `json:"file"`
+	Line      int      `json:"line"`
+	InPackage string   `json:"in_package,omitempty"`
+	InArch    string   `json:"in_arch,omitempty"`
+}
+
 // New creates a new policy engine, loading policies from the given directory
 func New(policyDir string) (*Engine, error) {
 	binaryPath, err := ensurePolicyBinary(policyDir)
@@ -586,13 +935,75 @@ func New(policyDir string) (*Engine, error) {
 
 // Evaluate runs the policies against the input data
 func (e *Engine) Evaluate(input Input) (*Result, error) {
+	output, err := e.run(input)
+	if err != nil {
+		return nil, err
+	}
+
+	var result Result
+	if err := json.Unmarshal(output, &result); err != nil {
+		return nil, fmt.Errorf("parse policy output: %w", err)
+	}
+
+	return &result, nil
+}
+
+// Constructs runs only construct detection (vhdl_policy --constructs)
+func (e *Engine) Constructs(input Input) (*ConstructInventory, error) {
+	output, err := e.run(input, "--constructs")
+	if err != nil {
+		return nil, err
+	}
+
+	var inventory ConstructInventory
+	if err := json.Unmarshal(output, &inventory); err != nil {
+		return nil, fmt.Errorf("parse construct inventory: %w", err)
+	}
+
+	return &inventory, nil
+}
+
+// SignalGraph exports the signal dataflow graph of an architecture or entity
+// (vhdl_policy --signal-graph), as JSON or, with dot, Graphviz
+func (e *Engine) SignalGraph(input Input, arch string, dot bool) ([]byte, error) {
+	args := []string{"--signal-graph", arch}
+	if dot {
+		args = append(args, "--dot")
+	}
+	return e.run(input, args...)
+}
+
+// Explain returns the documentation of a rule as text (vhdl_policy --explain)
+func (e *Engine) Explain(rule string) ([]byte, error) {
+	return e.run(Input{}, "--explain", rule)
+}
+
+// Modules returns the rule module names --only/--skip accept
+// (vhdl_policy --list-modules)
+func (e *Engine) Modules() ([]string, error) {
+	output, err := e.run(Input{}, "--list-modules")
+	if err != nil {
+		return nil, err
+	}
+	return strings.Fields(string(output)), nil
+}
+
+// DiffResults compares two result files and returns the new, fixed and
+// persisting violations in format "text", "markdown" or "json"
+// (vhdl_policy diff)
+func (e *Engine) DiffResults(oldPath, newPath, format string) ([]byte, error) {
+	return e.run(Input{}, "diff", oldPath, newPath, "--format", format)
+}
+
+// run feeds input to the policy binary and returns its stdout
+func (e *Engine) run(input Input, args ...string) ([]byte, error) {
 	ctx := context.Background()
 	payload, err := json.Marshal(input)
 	if err != nil {
 		return nil, fmt.Errorf("marshal input: %w", err)
 	}
 
-	cmd := exec.CommandContext(ctx, e.binaryPath)
+	cmd := exec.CommandContext(ctx, e.binaryPath, args...)
 	cmd.Stdin = bytes.NewReader(payload)
 	var stdout bytes.Buffer
 	var stderr bytes.Buffer
@@ -607,12 +1018,7 @@ func (e *Engine) Evaluate(input Input) (*Result, error) {
 		return nil, fmt.Errorf("rust policy engine failed: %w (%s)", err, stderr.String())
 	}
 
-	var result Result
-	if err := json.Unmarshal(stdout.Bytes(), &result); err != nil {
-		return nil, fmt.Errorf("parse policy output: %w", err)
-	}
-
-	return &result, nil
+	return stdout.Bytes(), nil
 }
 
 func ensurePolicyBinary(policyDir string) (string, error) {
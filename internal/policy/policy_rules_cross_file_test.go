@@ -77,6 +77,42 @@ func TestCrossFileDuplicatePrimaryUnitsDifferentLibraries(t *testing.T) {
 	}
 }
 
+func TestCrossFileCircularDependency(t *testing.T) {
+	repoRoot := findRepoRoot(t)
+	fixturesDir := filepath.Join(repoRoot, "testdata", "policy_rules")
+	fileA := filepath.Join(fixturesDir, "circular_dep_a.vhd")
+	fileB := filepath.Join(fixturesDir, "circular_dep_b.vhd")
+
+	cfg := config.DefaultConfig()
+	cfg.Lint.Rules = map[string]string{
+		"circular_dependency": "error",
+	}
+	disabled := false
+	cfg.Analysis.Cache.Enabled = &disabled
+	cfg.Libraries = map[string]config.LibraryConfig{
+		"work": {
+			Files:        []string{fileA, fileB},
+			IsThirdParty: false,
+		},
+	}
+
+	result := lintWithConfig(t, repoRoot, cfg)
+	if !hasRule(result, "circular_dependency") {
+		t.Fatalf("expected circular_dependency, got rules: %v", collectRules(result))
+	}
+
+	cfg.Libraries = map[string]config.LibraryConfig{
+		"work": {
+			Files:        []string{fileA, filepath.Join(fixturesDir, "cross_file_dup_b.vhd")},
+			IsThirdParty: false,
+		},
+	}
+	result = lintWithConfig(t, repoRoot, cfg)
+	if hasRule(result, "circular_dependency") {
+		t.Fatalf("did not expect circular_dependency, got rules: %v", collectRules(result))
+	}
+}
+
 func lintWithConfig(t *testing.T, repoRoot string, cfg *config.Config) indexer.LintResult {
 	t.Helper()
 
@@ -5,6 +5,7 @@ import (
 	"io"
 	"os"
 	"path/filepath"
+	"regexp"
 	"testing"
 
 	"github.com/robert-at-pretension-io/vhdl-lint/internal/config"
@@ -85,8 +86,10 @@ func lintFile(t *testing.T, repoRoot, filePath string, enabledRules map[string]s
 	}
 
 	cfg := config.DefaultConfig()
-	cfg.Standard = "1993"
+	cfg.Standard = fixtureStandard(t, absFile)
 	cfg.Lint.Rules = enabledRules
+	cfg.Lint.Clocks = fixtureClocks(t, absFile)
+	cfg.Lint.Vendor = fixtureVendor(t, absFile)
 	cfg.Libraries = map[string]config.LibraryConfig{
 		"work": {
 			Files:        []string{absFile},
@@ -138,6 +141,56 @@ func lintFile(t *testing.T, repoRoot, filePath string, enabledRules map[string]s
 	return result
 }
 
+var fixtureStandardPattern = regexp.MustCompile(`--\s*fixture standard:\s*(\d{4})`)
+
+// fixtureStandard is the VHDL standard a fixture is linted with: 1993, so
+// version-gated rules fire, unless a "-- fixture standard: <year>" comment
+// asks for another.
+func fixtureStandard(t *testing.T, path string) string {
+	t.Helper()
+	data, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatalf("read fixture: %v", err)
+	}
+	if match := fixtureStandardPattern.FindSubmatch(data); match != nil {
+		return string(match[1])
+	}
+	return "1993"
+}
+
+var fixtureClockPattern = regexp.MustCompile(`--\s*fixture clock:\s*(\w+)(?:\s+domain\s+(\w+))?`)
+
+// fixtureClocks are the primary clocks declared for a fixture by
+// "-- fixture clock: <name> [domain <domain>]" comments; none by default.
+func fixtureClocks(t *testing.T, path string) []config.ClockDecl {
+	t.Helper()
+	data, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatalf("read fixture: %v", err)
+	}
+	var clocks []config.ClockDecl
+	for _, match := range fixtureClockPattern.FindAllSubmatch(data, -1) {
+		clocks = append(clocks, config.ClockDecl{Name: string(match[1]), Domain: string(match[2])})
+	}
+	return clocks
+}
+
+var fixtureVendorPattern = regexp.MustCompile(`--\s*fixture vendor:\s*(\w+)`)
+
+// fixtureVendor is the FPGA vendor a fixture is linted for, from a
+// "-- fixture vendor: <vendor>" comment; unset by default.
+func fixtureVendor(t *testing.T, path string) string {
+	t.Helper()
+	data, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatalf("read fixture: %v", err)
+	}
+	if match := fixtureVendorPattern.FindSubmatch(data); match != nil {
+		return string(match[1])
+	}
+	return ""
+}
+
 func buildEnabledRules(positive, negative ruleManifest) map[string]string {
 	enabled := make(map[string]string)
 	for rule := range positive {
@@ -67,6 +67,23 @@ func TestMissingChecksForFIFO(t *testing.T) {
 	})
 }
 
+func TestFIFOPointerRules(t *testing.T) {
+	repoRoot := findRepoRoot(t)
+	fixture := filepath.Join(repoRoot, "testdata", "verification", "fifo_pointer_rules.vhd")
+
+	result := lintFile(t, repoRoot, fixture, map[string]string{
+		"fifo_pointer_width_mismatch": "error",
+		"fifo_flag_incomplete":        "warning",
+		"fifo_pointer_not_gray":       "error",
+	})
+
+	for _, rule := range []string{"fifo_pointer_width_mismatch", "fifo_flag_incomplete", "fifo_pointer_not_gray"} {
+		if !hasRule(result, rule) {
+			t.Fatalf("expected %s violation, got rules: %v", rule, collectRules(result))
+		}
+	}
+}
+
 func TestMissingCoverCompanion(t *testing.T) {
 	repoRoot := findRepoRoot(t)
 	fixture := filepath.Join(repoRoot, "testdata", "verification", "missing_cover_companion.vhd")
@@ -80,6 +97,23 @@ func TestMissingCoverCompanion(t *testing.T) {
 	}
 }
 
+func TestPSLCoverSatisfiesCoverCompanion(t *testing.T) {
+	repoRoot := findRepoRoot(t)
+	fixture := filepath.Join(repoRoot, "testdata", "verification", "psl_cover_companion.vhd")
+
+	result := lintFile(t, repoRoot, fixture, map[string]string{
+		"missing_cover_companion": "warning",
+		"psl_check_kind_mismatch": "warning",
+	})
+
+	if hasRule(result, "missing_cover_companion") {
+		t.Fatalf("expected PSL cover to satisfy the cover companion, got rules: %v", collectRules(result))
+	}
+	if !hasRule(result, "psl_check_kind_mismatch") {
+		t.Fatalf("expected psl_check_kind_mismatch violation, got rules: %v", collectRules(result))
+	}
+}
+
 func TestMissingLivenessBound(t *testing.T) {
 	repoRoot := findRepoRoot(t)
 	fixture := filepath.Join(repoRoot, "testdata", "verification", "missing_liveness_bound.vhd")
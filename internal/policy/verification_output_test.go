@@ -59,6 +59,29 @@ func TestAmbiguousConstructWarning(t *testing.T) {
 	}
 }
 
+func TestNamedReadyValidResolved(t *testing.T) {
+	repoRoot := findRepoRoot(t)
+	fixture := filepath.Join(repoRoot, "testdata", "verification", "named_ready_valid.vhd")
+
+	result := lintFile(t, repoRoot, fixture, map[string]string{
+		"ambiguous_construct": "warning",
+	})
+
+	if len(result.AmbiguousConstructs) != 0 {
+		t.Fatalf("expected port names to resolve ready/valid, got %v", result.AmbiguousConstructs)
+	}
+	for _, task := range result.MissingChecks {
+		if task.Bindings["valid"] != "up_valid" || task.Bindings["ready"] != "up_rdy" {
+			continue
+		}
+		if task.Confidence == nil || *task.Confidence < 0.75 {
+			t.Fatalf("expected name-based confidence >= 0.75, got %v", task.Confidence)
+		}
+		return
+	}
+	t.Fatalf("expected missing-check task binding valid=up_valid ready=up_rdy, got %v", result.MissingChecks)
+}
+
 func stringSliceContains(items []string, target string) bool {
 	for _, item := range items {
 		if item == target {
@@ -28,6 +28,8 @@ func emptyFactTables() facts.Tables {
 		Procedures:     []facts.ProcedureRow{},
 		Constants:      []facts.ConstantRow{},
 		Symbols:        []facts.SymbolRow{},
+		SignalUses:     []facts.SignalUseRow{},
+		PortMaps:       []facts.PortMapRow{},
 	}
 }
 
@@ -26,6 +26,8 @@ package facts_schema
     procedures:      [...#ProcedureRow]
     constants:       [...#ConstantRow]
     symbols:         [...#SymbolRow]
+    signal_uses:     [...#SignalUseRow]
+    port_maps:       [...#PortMapRow]
 }
 
 #FileRow: {
@@ -58,6 +60,8 @@ package facts_schema
     name:      #Identifier
     direction: "in" | "out" | "inout" | "buffer" | "linkage" | ""
     type:      string & !=""
+    default:   string
+    width:     int & >=0
     file:      string & =~".+\\.(vhd|vhdl)$"
     line:      int & >=1
 }
@@ -65,6 +69,7 @@ package facts_schema
 #SignalRow: {
     name:  #Identifier
     type:  string & !=""
+    width: int & >=0
     file:  string & =~".+\\.(vhd|vhdl)$"
     line:  int & >=1
     scope: string
@@ -179,3 +184,18 @@ package facts_schema
     file: string & =~".+\\.(vhd|vhdl)$"
     line: int & >=1
 }
+
+#SignalUseRow: {
+    name: string & !=""
+    kind: "read" | "write"
+    file: string & =~".+\\.(vhd|vhdl)$"
+    line: int & >=1
+}
+
+#PortMapRow: {
+    instance: string
+    formal:   string & !=""
+    actual:   string
+    file:     string & =~".+\\.(vhd|vhdl)$"
+    line:     int & >=1
+}
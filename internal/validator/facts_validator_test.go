@@ -40,6 +40,8 @@ func TestFactsValidatorAcceptsValidTables(t *testing.T) {
 		Procedures:     []facts.ProcedureRow{},
 		Constants:      []facts.ConstantRow{},
 		Symbols:        []facts.SymbolRow{},
+		SignalUses:     []facts.SignalUseRow{},
+		PortMaps:       []facts.PortMapRow{},
 	}
 
 	if err := v.Validate(tables); err != nil {
@@ -27,6 +27,7 @@ package schema
     file:     string & =~".+\\.(vhd|vhdl)$" // Must be VHDL file
     line:     int & >=1                      // Line numbers start at 1
     message:  string & !=""                  // Human-readable description
+    confidence?: "high" | "medium" | "low"   // How sure a heuristic rule is
 }
 
 // Summary provides aggregate counts
@@ -72,6 +73,7 @@ package schema
     missing_ids: [...string]
     bindings:    {[string]: string} | *{}
     notes:       [...string] | *[]
+    confidence?: number & >=0 & <=1 // Set when bindings were inferred from port names
 }
 
 // VerificationAnchor identifies the insertion location for tags.
@@ -89,4 +91,6 @@ package schema
     file:       string & =~".+\\.(vhd|vhdl)$"
     line:       int & >=1
     candidates: {[string]: [...string]}
+    confidence: number & >=0 & <=1     // Confidence of best_guess, below the binding threshold
+    best_guess?: {[string]: string}    // Highest-scoring binding from port names and comments
 }
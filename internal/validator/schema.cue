@@ -13,6 +13,8 @@ package schema
 // This MUST match policy.Input in Go exactly
 #Input: {
     standard:              "1993" | "2002" | "2008" | "2019"
+    profile?:              "" | "tb"
+    top_entities?:         [...string]
     file_count:            int & >=1
     entities:               [...#Entity]
     architectures:          [...#Architecture]
@@ -31,11 +33,16 @@ package schema
     verification_blocks:    [...#VerificationBlock]
     verification_tags:      [...#VerificationTag]
     verification_tag_errors:[...#VerificationTagError]
+    waivers:                [...#Waiver]
+    language_features:      [...#LanguageFeature]
+    psl_directives:         [...#PSLDirective]
+    assertions:             [...#Assertion]
     files:                  [...#FileInfo]
     instances:              [...#Instance]
     case_statements:        [...#CaseStatement]
     processes:              [...#Process]
     concurrent_assignments: [...#ConcurrentAssignment]
+    tristate_assignments:   [...#TristateAssignment]
     generates:              [...#GenerateStatement]
     configurations:         [...#Configuration]
     signal_usages:          [...#SignalUsage]
@@ -45,6 +52,8 @@ package schema
     functions:              [...#FunctionDeclaration]
     procedures:             [...#ProcedureDeclaration]
     constant_decls:         [...#ConstantDeclaration]
+    mode_views:             [...#ModeView]
+    attribute_specs:        [...#AttributeSpec]
     // Type system info for filtering false positives (LEGACY - use types/constant_decls instead)
     enum_literals:          [...string]  // Enum literals from type declarations (e.g., S_IDLE, S_RUN)
     constants:              [...string]  // Constants from constant declarations (names only)
@@ -77,6 +86,8 @@ package schema
     name:  #Identifier  // Valid VHDL identifier
     file:  string & =~".+\\.(vhd|vhdl)$"          // Must be VHDL file
     line:  int & >=1                               // Line numbers start at 1
+    column?:     int & >=1                         // 1-based column of the name
+    end_column?: int & >=1                         // Exclusive end column of the name
     ports: [...#Port]
     generics: [...#GenericDecl]
 }
@@ -87,6 +98,8 @@ package schema
     entity_name: #Identifier
     file:        string & =~".+\\.(vhd|vhdl)$"
     line:        int & >=1
+    column?:     int & >=1
+    end_column?: int & >=1
 }
 
 // Package declaration
@@ -94,6 +107,8 @@ package schema
     name: #Identifier
     file: string & =~".+\\.(vhd|vhdl)$"
     line: int & >=1
+    column?:     int & >=1
+    end_column?: int & >=1
 }
 
 // Component declaration or instantiation
@@ -113,20 +128,30 @@ package schema
     type:      string & !=""  // Type must not be empty
     file:      string & =~".+\\.(vhd|vhdl)$"
     line:      int & >=1
+    column?:     int & >=1
+    end_column?: int & >=1
     in_entity: string  // Which entity/architecture this signal belongs to
     width:     int & >=0  // Estimated bit width (0 if unknown)
+    default?:  string     // Initial value expression
 }
 
 // Port declaration
 // Note: direction can be empty for generics (which are parsed similarly to ports)
+// and is "view" for VHDL-2019 mode view ports
 #Port: {
     name:      #Identifier
-    direction: "in" | "out" | "inout" | "buffer" | "linkage" | ""
-    type:      string & !=""  // Type must not be empty
+    direction: "in" | "out" | "inout" | "buffer" | "linkage" | "view" | ""
+    type:      string  // Must not be empty, except for a VHDL-2019 view port without "of type"
     default:   string
     line:      int & >=1
+    column?:     int & >=1
+    end_column?: int & >=1
     in_entity: string  // Which entity this port belongs to
     width:     int & >=0  // Estimated bit width (0 if unknown)
+    view?:     string  // Mode view name when direction is "view" (VHDL-2019)
+    if direction != "view" {
+        type: !=""
+    }
 }
 
 #GenericDecl: {
@@ -279,6 +304,44 @@ package schema
     in_arch: string
 }
 
+// Waiver is an inline --@waive comment; target_line is 0 when no code follows it
+#Waiver: {
+    rules:       [string, ...string]
+    expires:     string  // YYYY-MM-DD, or empty if it never expires
+    file:        string & =~".+\\.(vhd|vhdl)$"
+    line:        int & >=1
+    target_line: int & >=0
+    raw:         string
+}
+
+// LanguageFeature is one use of a VHDL-2008 construct gated on `standard`
+#LanguageFeature: {
+    feature: "block_comment" | "matching_case"
+    file:    string & =~".+\\.(vhd|vhdl)$"
+    line:    int & >=1
+}
+
+// PSLDirective is a concurrent PSL assert/assume/cover/restrict statement
+#PSLDirective: {
+    kind:    "assert" | "assume" | "cover" | "restrict"
+    label:   string
+    signals: [...string]
+    file:    string & =~".+\\.(vhd|vhdl)$"
+    line:    int & >=1
+    in_arch: string
+}
+
+// Assertion is a plain (non-PSL) assert or report statement
+#Assertion: {
+    kind:         "assert" | "report"
+    severity:     string
+    file:         string & =~".+\\.(vhd|vhdl)$"
+    line:         int & >=1
+    in_arch:      string
+    in_process:   string
+    process_line: int & >=0
+}
+
 // Instance represents a component/entity instantiation with port/generic mappings
 // Enables system-level analysis (cross-module signal tracing)
 #Instance: {
@@ -289,6 +352,8 @@ package schema
     associations: [...#Association]
     file:        string & =~".+\\.(vhd|vhdl)$"
     line:        int & >=1
+    column?:     int & >=1                              // 1-based column of the label
+    end_column?: int & >=1
     in_arch:     string                                 // Containing architecture
 }
 
@@ -303,6 +368,14 @@ package schema
     in_process:  string                                 // Which process contains this
     in_arch:     string                                 // Which architecture
     is_complete: bool                                   // true if complete coverage
+    arms?:       [...#CaseArm]                          // Alternatives in source order
+}
+
+// CaseArm is one alternative of a case statement
+#CaseArm: {
+    choices: [...string]        // Choices of the alternative ("others" for the catch-all)
+    line:    int & >=1
+    branch:  string             // Path element ("case@<byte>") added to assignment branches
 }
 
 // Process represents a VHDL process for sensitivity/clock/reset analysis
@@ -324,6 +397,8 @@ package schema
     wait_statements:  [...#WaitStatement]
     file:             string & =~".+\\.(vhd|vhdl)$"
     line:             int & >=1
+    column?:          int & >=1                         // 1-based column of the label
+    end_column?:      int & >=1
     in_arch:          string                            // Containing architecture
 }
 
@@ -340,11 +415,21 @@ package schema
     generate_label: string                                  // Label of containing generate
 }
 
+// TristateAssignment is a signal assignment whose value drives 'Z'
+#TristateAssignment: {
+    target:      string
+    value:       string                       // RHS text
+    file:        string & =~".+\\.(vhd|vhdl)$"
+    line:        int & >=1
+    in_arch:     string                       // Containing architecture
+    in_process?: string                       // Label of containing process
+}
+
 // Comparison represents a comparison operation for trojan/trigger detection
 // Tracks comparisons against literals, especially large "magic" values
 #Comparison: {
     left_operand:  string                               // Signal or expression on left
-    operator:      string                               // =, /=, <, >, <=, >=
+    operator:      string                               // =, /=, <, >, <=, >=, std_match
     right_operand: string                               // Signal, literal, or expression
     is_literal:    bool                                 // True if right operand is a literal
     literal_value: string                               // The literal value if is_literal
@@ -356,11 +441,11 @@ package schema
     in_arch:       string                               // Which architecture
 }
 
-// ArithmeticOp represents an expensive arithmetic operation for power analysis
+// ArithmeticOp represents an arithmetic operation for power and width analysis
 #ArithmeticOp: {
-    operator:     string                                // *, /, mod, rem, **
+    operator:     string                                // +, -, *, /, mod, rem, **
     operands:     [...string]                           // Input signals/expressions
-    result:       string                                // Output signal
+    result:       string                                // Signal assigned by the enclosing assignment, if any
     is_guarded:   bool                                  // True if gated by enable
     guard_signal: string                                // The enable/valid signal
     file:         string & =~".+\\.(vhd|vhdl)$"
@@ -453,6 +538,7 @@ package schema
     // Array-specific
     element_type?:  string                              // For arrays: element type
     index_types?:   [...string]                         // For arrays: index type(s)
+    index_range?:   string                              // For arrays: first index constraint
     unconstrained?: bool                                // For arrays: true if "range <>"
     // Physical-specific
     base_unit?:     string                              // For physical: base unit name
@@ -527,3 +613,32 @@ package schema
     in_package?: string                                 // Package containing this constant
     in_arch?:    string                                 // Architecture if local constant
 }
+
+// ModeView represents a VHDL-2019 mode view declaration
+#ModeView: {
+    name:        #Identifier
+    type:        string & !=""                          // Record type the view applies to
+    elements:    [...#ModeViewElement]
+    file:        string & =~".+\\.(vhd|vhdl)$"
+    line:        int & >=1
+    in_package?: string                                 // Package containing this view
+    in_arch?:    string                                 // Architecture if local view
+}
+
+// AttributeSpec represents an attribute specification
+#AttributeSpec: {
+    name:        #Identifier
+    targets:     [...string]                            // Named items, or "others"/"all"
+    class:       string                                 // Entity class ("signal", "type", ...)
+    value:       string                                 // Value expression text
+    file:        string & =~".+\\.(vhd|vhdl)$"
+    line:        int & >=1
+    in_package?: string
+    in_arch?:    string
+}
+
+#ModeViewElement: {
+    name:      #Identifier
+    direction: "in" | "out" | "inout" | "buffer" | "linkage" | "view"
+    view?:     string  // Nested view name when direction is "view"
+}
@@ -29,6 +29,8 @@ package schema
     verification_blocks:    [...#VerificationBlock]
     verification_tags:      [...#VerificationTag]
     verification_tag_errors:[...#VerificationTagError]
+    psl_directives:         [...#PSLDirective]
+    assertions:             [...#Assertion]
     files:           [...#FileInfo]
     instances:       [...#Instance]
     case_statements: [...#CaseStatement]
@@ -82,13 +84,19 @@ package schema
 
 // Port declaration
 // Note: direction can be empty for generics (which are parsed similarly to ports)
+// and is "view" for VHDL-2019 mode view ports
 #Port: {
     name:      #Identifier
-    direction: "in" | "out" | "inout" | "buffer" | "linkage" | ""
-    type:      string & !=""  // Type must not be empty
+    direction: "in" | "out" | "inout" | "buffer" | "linkage" | "view" | ""
+    type:      string  // Must not be empty, except for a VHDL-2019 view port without "of type"
     default:   string
     line:      int & >=1
     in_entity: string  // Which entity this port belongs to
+    comment?:  string  // Trailing "--" comment on the declaration line
+    view?:     string  // Mode view name when direction is "view" (VHDL-2019)
+    if direction != "view" {
+        type: !=""
+    }
 }
 
 #FileInfo: {
@@ -149,6 +157,27 @@ package schema
     in_arch: string
 }
 
+// PSLDirective is a concurrent PSL assert/assume/cover/restrict statement
+#PSLDirective: {
+    kind:    "assert" | "assume" | "cover" | "restrict"
+    label:   string
+    signals: [...string]
+    file:    string & =~".+\\.(vhd|vhdl)$"
+    line:    int & >=1
+    in_arch: string
+}
+
+// Assertion is a plain (non-PSL) assert or report statement
+#Assertion: {
+    kind:         "assert" | "report"
+    severity:     string
+    file:         string & =~".+\\.(vhd|vhdl)$"
+    line:         int & >=1
+    in_arch:      string
+    in_process:   string
+    process_line: int & >=0
+}
+
 #GenericDecl: {
     name:         #Identifier
     kind:         "constant" | "type" | "function" | "procedure" | "package" | string
@@ -220,6 +249,15 @@ package schema
     line:       int & >=1
 }
 
+#SequentialAssignment: {
+    target:  string & !=""      // Full target text (e.g. "r(0)")
+    signal:  string             // Base signal name
+    value?:  string             // RHS text of plain assignments
+    line:    int & >=1
+    branch:  string             // Enclosing branch path ("" = process body)
+    segment: int & >=0          // Wait statements executed before this assignment
+}
+
 // Dependency between files/entities
 #Dependency: {
     source:   string & !=""  // Source file or entity
@@ -242,6 +280,7 @@ package schema
 #Instance: {
     name:        #Identifier  // Instance label
     target:      string & !=""                          // Target entity/component
+    target_arch?: string                                // Explicit architecture (entity work.e(rtl))
     port_map:    {[string]: string}                     // Formal -> actual signal
     generic_map: {[string]: string}                     // Formal -> actual value
     associations: [...#Association]
@@ -261,6 +300,14 @@ package schema
     in_process:  string                                 // Which process contains this
     in_arch:     string                                 // Which architecture
     is_complete: bool                                   // true if complete coverage
+    arms?:       [...#CaseArm]                          // Alternatives in source order
+}
+
+// CaseArm is one alternative of a case statement
+#CaseArm: {
+    choices: [...string]        // Choices of the alternative ("others" for the catch-all)
+    line:    int & >=1
+    branch:  string             // Path element ("case@<byte>") added to assignment branches
 }
 
 // Process represents a VHDL process for sensitivity/clock/reset analysis
@@ -280,6 +327,7 @@ package schema
     procedure_calls:  [...#ProcedureCall]
     function_calls:   [...#FunctionCall]
     wait_statements:  [...#WaitStatement]
+    assignments?:     [...#SequentialAssignment]        // Signal assignments in source order
     file:             string & =~".+\\.(vhd|vhdl)$"
     line:             int & >=1
     in_arch:          string                            // Containing architecture
@@ -287,8 +335,20 @@ package schema
 
 // Configuration declaration
 #Configuration: {
-    name:        #Identifier
-    entity_name: #Identifier
-    file:        string & =~".+\\.(vhd|vhdl)$"
-    line:        int & >=1
+    name:          #Identifier
+    entity_name:   #Identifier
+    architecture?: string                      // Top-level block configuration
+    bindings?:     [...#ConfigurationBinding]
+    file:          string & =~".+\\.(vhd|vhdl)$"
+    line:          int & >=1
+}
+
+// Component configuration binding (for <instances> : <component> use entity ...)
+#ConfigurationBinding: {
+    scope?:        [...string]               // Block configuration nesting, architecture first
+    instance:      string
+    component:     string
+    entity:        string
+    architecture?: string
+    line:          int & >=1
 }
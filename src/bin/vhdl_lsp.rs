@@ -0,0 +1,769 @@
+//! Language server for editors: lints the workspace with the built-in
+//! extractor and policy engine, publishing violations as diagnostics, and
+//! answers document-symbol and go-to-definition requests from the extracted
+//! facts.
+//!
+//! Speaks LSP over stdio (`Content-Length` framed JSON-RPC). Documents sync
+//! in full, and the editor's unsaved text stands in for the file on disk.
+//! Parse trees and rule module outputs are kept between lints, so a re-lint
+//! re-parses only the documents that changed and re-runs only the modules
+//! whose input tables changed. Opening, closing or saving a document re-lints
+//! at once; edits re-lint once they pause for `CHANGE_DEBOUNCE`, and only if
+//! they changed what the edited documents extract to.
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+use vhdl_compiler::extract::{discover, Workspace};
+use vhdl_compiler::policy::config;
+use vhdl_compiler::policy::engine::{self, ModuleCache};
+use vhdl_compiler::policy::{Input, Violation};
+
+const METHOD_NOT_FOUND: i64 = -32601;
+/// `TextDocumentSyncKind.Full`.
+const SYNC_FULL: u64 = 1;
+/// Quiet period after a `didChange` before the workspace is re-linted.
+const CHANGE_DEBOUNCE: Duration = Duration::from_millis(300);
+
+// `SymbolKind` values.
+const SYMBOL_MODULE: u64 = 2;
+const SYMBOL_PACKAGE: u64 = 4;
+const SYMBOL_CLASS: u64 = 5;
+const SYMBOL_FIELD: u64 = 8;
+const SYMBOL_FUNCTION: u64 = 12;
+const SYMBOL_VARIABLE: u64 = 13;
+
+#[derive(Default)]
+struct Server {
+    root: Option<PathBuf>,
+    /// Unsaved editor text by path.
+    open: HashMap<String, String>,
+    /// Parse trees of every file in the last lint; created on first use.
+    workspace: Option<Workspace>,
+    input: Input,
+    /// Rule module outputs from earlier lints, reused while the tables a
+    /// module reads are unchanged.
+    modules: ModuleCache,
+    /// Files whose last published diagnostics were non-empty.
+    published: HashSet<String>,
+    /// An edit arrived since the last lint.
+    changed: bool,
+    shutdown: bool,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    // Messages are read on their own thread so pending edits can be
+    // re-linted once the editor goes quiet.
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let stdin = io::stdin();
+        let mut reader = stdin.lock();
+        loop {
+            match read_message(&mut reader) {
+                Ok(Some(message)) => {
+                    if tx.send(Ok(message)).is_err() {
+                        break;
+                    }
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    let _ = tx.send(Err(e));
+                    break;
+                }
+            }
+        }
+    });
+    let stdout = io::stdout();
+    let mut writer = stdout.lock();
+    let mut server = Server::default();
+    loop {
+        let next = if server.changed {
+            rx.recv_timeout(CHANGE_DEBOUNCE)
+        } else {
+            rx.recv().map_err(|_| RecvTimeoutError::Disconnected)
+        };
+        let replies = match next {
+            Ok(message) => {
+                let message = message?;
+                if message["method"] == "exit" {
+                    std::process::exit(if server.shutdown { 0 } else { 1 });
+                }
+                server.handle(&message)
+            }
+            Err(RecvTimeoutError::Timeout) => server.flush_edits(),
+            Err(RecvTimeoutError::Disconnected) => return Ok(()),
+        };
+        for reply in replies {
+            write_message(&mut writer, &reply)?;
+        }
+    }
+}
+
+/// Reads one framed message; `None` at end of input.
+fn read_message(reader: &mut impl BufRead) -> io::Result<Option<Value>> {
+    let mut length = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse::<usize>().ok();
+            }
+        }
+    }
+    let length = length
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing Content-Length"))?;
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+    serde_json::from_slice(&body)
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn write_message(writer: &mut impl Write, message: &Value) -> io::Result<()> {
+    let body = message.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    writer.flush()
+}
+
+impl Server {
+    /// Responses and notifications to send for one incoming message.
+    fn handle(&mut self, message: &Value) -> Vec<Value> {
+        let method = message["method"].as_str().unwrap_or("");
+        let params = &message["params"];
+        let id = message.get("id").cloned();
+        let result = match method {
+            "initialize" => {
+                self.root = params["rootUri"]
+                    .as_str()
+                    .and_then(uri_to_path)
+                    .or_else(|| params["rootPath"].as_str().map(PathBuf::from));
+                Some(json!({
+                    "capabilities": {
+                        "textDocumentSync": SYNC_FULL,
+                        "documentSymbolProvider": true,
+                        "definitionProvider": true,
+                    },
+                    "serverInfo": { "name": "vhdl_lsp" },
+                }))
+            }
+            "initialized" | "textDocument/didSave" => {
+                let mut out = self.sync_disk();
+                out.extend(self.relint());
+                return out;
+            }
+            "textDocument/didOpen" => {
+                if let Some(path) = document_path(params) {
+                    let text = params["textDocument"]["text"].as_str().unwrap_or("");
+                    self.open.insert(path, text.to_string());
+                }
+                return self.relint();
+            }
+            "textDocument/didChange" => {
+                let text = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str());
+                if let (Some(path), Some(text)) = (document_path(params), text) {
+                    self.open.insert(path, text.to_string());
+                    self.changed = true;
+                }
+                return Vec::new();
+            }
+            "textDocument/didClose" => {
+                let mut out = Vec::new();
+                if let Some(path) = document_path(params) {
+                    self.open.remove(&path);
+                    out.extend(self.reload(&path));
+                }
+                out.extend(self.relint());
+                return out;
+            }
+            // Answer from the latest text rather than the last lint.
+            "textDocument/documentSymbol" | "textDocument/definition" if self.changed => {
+                let mut out = self.flush_edits();
+                out.extend(self.handle(message));
+                return out;
+            }
+            "textDocument/documentSymbol" => {
+                Some(document_path(params).map_or(json!([]), |path| self.symbols(&path)))
+            }
+            "textDocument/definition" => Some(
+                document_path(params)
+                    .and_then(|path| self.definition(&path, &params["position"]))
+                    .unwrap_or(Value::Null),
+            ),
+            "shutdown" => {
+                self.shutdown = true;
+                Some(Value::Null)
+            }
+            _ => None,
+        };
+        let Some(id) = id else {
+            return Vec::new();
+        };
+        match result {
+            Some(result) => vec![json!({ "jsonrpc": "2.0", "id": id, "result": result })],
+            None => vec![json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": {
+                    "code": METHOD_NOT_FOUND,
+                    "message": format!("unsupported method '{}'", method),
+                },
+            })],
+        }
+    }
+
+    /// Re-lints after edits, unless the open documents still extract to the
+    /// same facts (e.g. the edits were inside comments), in which case the
+    /// published diagnostics stand.
+    fn flush_edits(&mut self) -> Vec<Value> {
+        self.lint(false)
+    }
+
+    fn relint(&mut self) -> Vec<Value> {
+        self.lint(true)
+    }
+
+    /// Re-parses the open documents that changed since the last lint, then
+    /// re-extracts and re-evaluates the workspace, returning
+    /// `publishDiagnostics` for every file with violations and for every file
+    /// whose violations are now gone. Without `force`, nothing is evaluated
+    /// unless some document's facts changed.
+    fn lint(&mut self, force: bool) -> Vec<Value> {
+        self.changed = false;
+        let Some(workspace) = self.workspace.as_mut() else {
+            return Vec::new();
+        };
+        let mut out = Vec::new();
+        let mut dirty = force;
+        for (path, text) in &self.open {
+            match workspace.update(path, text.clone()) {
+                Ok(changed) => dirty |= changed,
+                Err(e) => out.push(log_message(&format!("parsing '{}' failed: {}", path, e))),
+            }
+        }
+        if !dirty {
+            return out;
+        }
+        let mut input = workspace.input();
+        if let Some(root) = self.root.as_deref() {
+            if let Some(path) = config::discover(root) {
+                match config::load(&path) {
+                    Ok(project) => project.apply(&mut input),
+                    Err(e) => out.push(log_message(&e)),
+                }
+            }
+            if let Err(e) = config::apply_nested(root, &mut input) {
+                out.push(log_message(&e));
+            }
+        }
+        let result = engine::evaluate_cached(&input, &mut self.modules);
+        self.input = input;
+
+        let mut by_file: HashMap<&str, Vec<&Violation>> = HashMap::new();
+        for v in &result.violations {
+            by_file.entry(v.file.as_str()).or_default().push(v);
+        }
+        let stale: Vec<String> = self
+            .published
+            .iter()
+            .filter(|file| !by_file.contains_key(file.as_str()))
+            .cloned()
+            .collect();
+        for file in stale {
+            out.push(publish(&file, Vec::new()));
+        }
+        self.published = by_file.keys().map(|file| file.to_string()).collect();
+        for (file, violations) in by_file {
+            let text = self.text(file);
+            let diagnostics = violations.iter().map(|v| diagnostic(v, text)).collect();
+            out.push(publish(file, diagnostics));
+        }
+        out
+    }
+
+    /// Brings the workspace in line with the VHDL files on disk: new files
+    /// are added, vanished ones dropped and the rest re-read, though only
+    /// files whose text changed are re-parsed. Open documents keep their
+    /// editor text.
+    fn sync_disk(&mut self) -> Vec<Value> {
+        if self.workspace.is_none() {
+            match Workspace::new() {
+                Ok(workspace) => self.workspace = Some(workspace),
+                Err(e) => return vec![log_message(&format!("extraction failed: {}", e))],
+            }
+        }
+        let on_disk: Vec<String> = match &self.root {
+            Some(root) => discover::vhdl_files(root)
+                .unwrap_or_default()
+                .iter()
+                .map(|path| path.to_string_lossy().to_string())
+                .collect(),
+            None => Vec::new(),
+        };
+        let mut out = Vec::new();
+        if let Some(workspace) = self.workspace.as_mut() {
+            let keep: HashSet<&String> = on_disk.iter().chain(self.open.keys()).collect();
+            let gone: Vec<String> = workspace
+                .paths()
+                .filter(|path| !keep.contains(&path.to_string()))
+                .map(str::to_string)
+                .collect();
+            for path in gone {
+                workspace.remove(&path);
+            }
+        }
+        for path in on_disk {
+            if !self.open.contains_key(&path) {
+                out.extend(self.reload(&path));
+            }
+        }
+        out
+    }
+
+    /// Re-reads `path` from disk into the workspace, or drops it when it is
+    /// gone.
+    fn reload(&mut self, path: &str) -> Option<Value> {
+        let workspace = self.workspace.as_mut()?;
+        let Ok(text) = fs::read_to_string(path) else {
+            workspace.remove(path);
+            return None;
+        };
+        workspace
+            .update(path, text)
+            .err()
+            .map(|e| log_message(&format!("parsing '{}' failed: {}", path, e)))
+    }
+
+    /// Text of `file` as last linted.
+    fn text(&self, file: &str) -> &str {
+        self.workspace
+            .as_ref()
+            .and_then(|workspace| workspace.text(file))
+            .unwrap_or("")
+    }
+
+    /// Entities with their ports, architectures with their signals and
+    /// processes, and packages declared in `file`.
+    fn symbols(&self, file: &str) -> Value {
+        let text = self.text(file);
+        let input = &self.input;
+        let mut out = Vec::new();
+        for entity in input.entities.iter().filter(|e| e.file == file) {
+            let ports = entity
+                .ports
+                .iter()
+                .map(|p| {
+                    symbol(
+                        &p.name,
+                        &p.direction,
+                        SYMBOL_FIELD,
+                        text,
+                        p.line,
+                        Vec::new(),
+                    )
+                })
+                .collect();
+            out.push(symbol(
+                &entity.name,
+                "entity",
+                SYMBOL_CLASS,
+                text,
+                entity.line,
+                ports,
+            ));
+        }
+        for arch in input.architectures.iter().filter(|a| a.file == file) {
+            let in_arch = |scope: &str| scope.eq_ignore_ascii_case(&arch.name);
+            let mut children: Vec<Value> = input
+                .signals
+                .iter()
+                .filter(|s| s.file == file && in_arch(&s.in_entity))
+                .map(|s| {
+                    symbol(
+                        &s.name,
+                        &s.r#type,
+                        SYMBOL_VARIABLE,
+                        text,
+                        s.line,
+                        Vec::new(),
+                    )
+                })
+                .collect();
+            children.extend(
+                input
+                    .processes
+                    .iter()
+                    .filter(|p| p.file == file && in_arch(&p.in_arch) && !p.label.is_empty())
+                    .map(|p| {
+                        symbol(
+                            &p.label,
+                            "process",
+                            SYMBOL_FUNCTION,
+                            text,
+                            p.line,
+                            Vec::new(),
+                        )
+                    }),
+            );
+            let detail = format!("architecture of {}", arch.entity_name);
+            out.push(symbol(
+                &arch.name,
+                &detail,
+                SYMBOL_MODULE,
+                text,
+                arch.line,
+                children,
+            ));
+        }
+        for pkg in input.packages.iter().filter(|p| p.file == file) {
+            out.push(symbol(
+                &pkg.name,
+                "package",
+                SYMBOL_PACKAGE,
+                text,
+                pkg.line,
+                Vec::new(),
+            ));
+        }
+        Value::Array(out)
+    }
+
+    /// Declaration of the identifier under `position`.
+    fn definition(&self, file: &str, position: &Value) -> Option<Value> {
+        let text = self.workspace.as_ref()?.text(file)?;
+        let line = position["line"].as_u64()? as usize + 1;
+        let column = position["character"].as_u64()? as usize;
+        let word = word_at(text.lines().nth(line - 1)?, column)?;
+        let (def_file, def_line) = resolve_definition(&self.input, file, word)?;
+        let def_text = self.text(def_file);
+        Some(json!({
+            "uri": path_to_uri(def_file),
+            "range": line_range(def_text, def_line),
+        }))
+    }
+}
+
+/// Where `word`, used in `file`, is declared: the design unit, component,
+/// signal or port of that name, preferring one in the same file, then the
+/// first by file and line. Resolution is by name only; the built-in
+/// extractor records no symbol scopes, so a name declared in several
+/// architectures of one file resolves to the first.
+fn resolve_definition<'a>(input: &'a Input, file: &str, word: &str) -> Option<(&'a str, usize)> {
+    let named = |name: &str| name.eq_ignore_ascii_case(word);
+    let mut decls: Vec<(&'a str, usize)> = Vec::new();
+    decls.extend(
+        input
+            .entities
+            .iter()
+            .filter(|e| named(&e.name))
+            .map(|e| (e.file.as_str(), e.line)),
+    );
+    decls.extend(
+        input
+            .architectures
+            .iter()
+            .filter(|a| named(&a.name))
+            .map(|a| (a.file.as_str(), a.line)),
+    );
+    decls.extend(
+        input
+            .packages
+            .iter()
+            .filter(|p| named(&p.name))
+            .map(|p| (p.file.as_str(), p.line)),
+    );
+    decls.extend(
+        input
+            .components
+            .iter()
+            .filter(|c| named(&c.name))
+            .map(|c| (c.file.as_str(), c.line)),
+    );
+    decls.extend(
+        input
+            .signals
+            .iter()
+            .filter(|s| named(&s.name))
+            .map(|s| (s.file.as_str(), s.line)),
+    );
+    for entity in &input.entities {
+        decls.extend(
+            entity
+                .ports
+                .iter()
+                .filter(|p| named(&p.name))
+                .map(|p| (entity.file.as_str(), p.line)),
+        );
+    }
+    decls
+        .into_iter()
+        .max_by_key(|&(decl_file, line)| (decl_file == file, std::cmp::Reverse((decl_file, line))))
+}
+
+/// Identifier covering `column` (or ending right before it) on `line`.
+fn word_at(line: &str, column: usize) -> Option<&str> {
+    let is_word = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let chars: Vec<(usize, char)> = line.char_indices().collect();
+    let mut at = column.min(chars.len());
+    if at == chars.len() || !is_word(chars[at].1) {
+        at = at.checked_sub(1).filter(|&i| is_word(chars[i].1))?;
+    }
+    let start = (0..=at).rev().take_while(|&i| is_word(chars[i].1)).last()?;
+    let end = (at..chars.len())
+        .take_while(|&i| is_word(chars[i].1))
+        .last()?;
+    let end_byte = chars[end].0 + chars[end].1.len_utf8();
+    Some(&line[chars[start].0..end_byte])
+}
+
+fn symbol(
+    name: &str,
+    detail: &str,
+    kind: u64,
+    text: &str,
+    line: usize,
+    children: Vec<Value>,
+) -> Value {
+    let range = line_range(text, line);
+    json!({
+        "name": name,
+        "detail": detail,
+        "kind": kind,
+        "range": range,
+        "selectionRange": range,
+        "children": children,
+    })
+}
+
+fn diagnostic(v: &Violation, text: &str) -> Value {
+    let severity = match v.severity.as_str() {
+        "error" => 1,
+        "warning" => 2,
+        _ => 3,
+    };
+    json!({
+        "range": violation_range(v, text),
+        "severity": severity,
+        "code": v.rule,
+        "source": "vhdl_lint",
+        "message": v.message,
+        "data": { "confidence": v.confidence },
+    })
+}
+
+/// The identifier `v` is about when the engine located it, else its whole
+/// line. Violation columns are 1-based with an exclusive end, LSP's 0-based.
+fn violation_range(v: &Violation, text: &str) -> Value {
+    if v.column == 0 || v.line == 0 {
+        return line_range(text, v.line);
+    }
+    let end_line = v.end_line.max(v.line);
+    let end_column = if v.end_column > 0 {
+        v.end_column
+    } else {
+        v.column
+    };
+    json!({
+        "start": { "line": v.line - 1, "character": v.column - 1 },
+        "end": { "line": end_line - 1, "character": end_column - 1 },
+    })
+}
+
+/// The whole of 1-based `line`, minus leading indentation.
+fn line_range(text: &str, line: usize) -> Value {
+    let row = line.saturating_sub(1);
+    let content = text.lines().nth(row).unwrap_or("");
+    let start = content.chars().take_while(|c| c.is_whitespace()).count();
+    json!({
+        "start": { "line": row, "character": start },
+        "end": { "line": row, "character": content.chars().count() },
+    })
+}
+
+fn publish(file: &str, diagnostics: Vec<Value>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": { "uri": path_to_uri(file), "diagnostics": diagnostics },
+    })
+}
+
+fn log_message(message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "window/logMessage",
+        "params": { "type": 1, "message": message },
+    })
+}
+
+fn document_path(params: &Value) -> Option<String> {
+    let uri = params["textDocument"]["uri"].as_str()?;
+    uri_to_path(uri).map(|path| path.to_string_lossy().to_string())
+}
+
+fn uri_to_path(uri: &str) -> Option<PathBuf> {
+    let encoded = uri.strip_prefix("file://")?;
+    let bytes = encoded.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(decoded).ok().map(PathBuf::from)
+}
+
+fn path_to_uri(path: &str) -> String {
+    let absolute = fs::canonicalize(path).unwrap_or_else(|_| PathBuf::from(path));
+    let mut uri = String::from("file://");
+    for byte in absolute.to_string_lossy().bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-_.~".contains(&byte) {
+            uri.push(byte as char);
+        } else {
+            uri.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    uri
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vhdl_compiler::policy::input::{Architecture, Entity, Port, Signal};
+
+    #[test]
+    fn messages_round_trip_through_framing() {
+        let mut buf = Vec::new();
+        let message = json!({ "jsonrpc": "2.0", "id": 1, "method": "shutdown" });
+        write_message(&mut buf, &message).unwrap();
+        let mut reader = io::Cursor::new(buf);
+        assert_eq!(read_message(&mut reader).unwrap(), Some(message));
+        assert_eq!(read_message(&mut reader).unwrap(), None);
+    }
+
+    #[test]
+    fn uris_decode_and_words_extend_both_ways() {
+        assert_eq!(
+            uri_to_path("file:///work/my%20ip/top.vhd"),
+            Some(PathBuf::from("/work/my ip/top.vhd"))
+        );
+        assert_eq!(uri_to_path("untitled:1"), None);
+        let line = "  q <= data_in and en;";
+        assert_eq!(word_at(line, 9), Some("data_in"));
+        assert_eq!(word_at(line, 14), Some("data_in"));
+        assert_eq!(word_at(line, 3), Some("q"));
+        assert_eq!(word_at(line, 5), None);
+    }
+
+    #[test]
+    fn diagnostics_underline_located_names() {
+        let text = "architecture rtl of top is\n  signal unused_q : std_logic;\n";
+        let mut v = Violation {
+            line: 2,
+            ..Default::default()
+        };
+        assert_eq!(
+            diagnostic(&v, text)["range"],
+            json!({
+                "start": { "line": 1, "character": 2 },
+                "end": { "line": 1, "character": 30 },
+            })
+        );
+        v.column = 10;
+        v.end_line = 2;
+        v.end_column = 18;
+        assert_eq!(
+            diagnostic(&v, text)["range"],
+            json!({
+                "start": { "line": 1, "character": 9 },
+                "end": { "line": 1, "character": 17 },
+            })
+        );
+    }
+
+    #[test]
+    fn definition_resolves_by_name_preferring_the_same_file() {
+        let input = Input {
+            entities: vec![Entity {
+                name: "core".to_string(),
+                file: "core.vhd".to_string(),
+                line: 1,
+                ports: vec![Port {
+                    name: "en".to_string(),
+                    line: 3,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            architectures: vec![Architecture {
+                name: "rtl".to_string(),
+                entity_name: "core".to_string(),
+                file: "core.vhd".to_string(),
+                line: 8,
+                ..Default::default()
+            }],
+            signals: vec![
+                Signal {
+                    name: "count".to_string(),
+                    file: "core.vhd".to_string(),
+                    line: 9,
+                    in_entity: "rtl".to_string(),
+                    ..Default::default()
+                },
+                Signal {
+                    name: "count".to_string(),
+                    file: "other.vhd".to_string(),
+                    line: 4,
+                    in_entity: "beh".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve_definition(&input, "core.vhd", "COUNT"),
+            Some(("core.vhd", 9))
+        );
+        assert_eq!(
+            resolve_definition(&input, "top.vhd", "count"),
+            Some(("core.vhd", 9))
+        );
+        assert_eq!(
+            resolve_definition(&input, "other.vhd", "count"),
+            Some(("other.vhd", 4))
+        );
+        assert_eq!(
+            resolve_definition(&input, "core.vhd", "en"),
+            Some(("core.vhd", 3))
+        );
+        assert_eq!(resolve_definition(&input, "core.vhd", "missing"), None);
+    }
+}
@@ -1,20 +1,654 @@
 use std::error::Error;
 use std::fs::File;
 use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::Command;
 
+use vhdl_compiler::policy::baseline::Baseline;
+use vhdl_compiler::policy::checkstyle;
+use vhdl_compiler::policy::compile_order;
+use vhdl_compiler::policy::config;
+use vhdl_compiler::policy::dataflow;
+use vhdl_compiler::policy::diff;
+use vhdl_compiler::policy::dot;
+use vhdl_compiler::policy::duplicates;
 use vhdl_compiler::policy::engine;
+use vhdl_compiler::policy::fixes;
+use vhdl_compiler::policy::gitlab;
+use vhdl_compiler::policy::helpers;
 use vhdl_compiler::policy::input::Input;
+use vhdl_compiler::policy::metrics;
+use vhdl_compiler::policy::report;
+use vhdl_compiler::policy::result::{Confidence, Violation};
+use vhdl_compiler::policy::result_diff;
+use vhdl_compiler::policy::rule_docs;
+use vhdl_compiler::policy::sarif;
+use vhdl_compiler::policy::sequential;
+use vhdl_compiler::policy::source_map::SourceMap;
+use vhdl_compiler::policy::verification;
+
+const USAGE: &str = "Usage: vhdl_policy [--explain <rule>] [--list-modules] [--config <path>] [--baseline <path> [--write-baseline]] [--only <modules>] [--skip <modules>] \
+                     [--constructs] [--compile-order <ghdl|vunit|text>] [--signal-graph <arch> [--dot]] [--fix] [--generate-checks] [--fast] [--error-budget <n>] [--jobs <n>] \
+                     [--shards <n>] [--min-confidence <low|medium|high>] [--profile <tb|rtl>] \
+                     [--top <entities>] [--testbench-roots] [--diff <base_ref>] [--changed-lines-from <diff>] \
+                     [--emit-badge <path>] [--emit-clock-domains <path>] \
+                     [--emit-duplicates <path>] [--emit-hierarchy <path>] [--emit-html <path>] [--emit-metrics <path>] [--emit-patch <path>] [--emit-reset-styles <path>] \
+                     [--emit-sarif <path>] [--emit-tasks <path>] [--with-snippets] [--format <json|gitlab|checkstyle|markdown>] [--markdown-limit <n>] [--link-template <url>] [input.json]\n       \
+                     vhdl_policy diff <old.json> <new.json> [--format <text|markdown|json>]";
+
+#[derive(Default)]
+struct Options {
+    input_path: Option<String>,
+    explain: Option<String>,
+    /// Print the module names `--only`/`--skip` accept, one per line.
+    list_modules: bool,
+    config_path: Option<String>,
+    baseline_path: Option<String>,
+    write_baseline: bool,
+    only_modules: Vec<String>,
+    skip_modules: Vec<String>,
+    badge_path: Option<String>,
+    clock_domains_path: Option<String>,
+    duplicates_path: Option<String>,
+    hierarchy_path: Option<String>,
+    html_path: Option<String>,
+    metrics_path: Option<String>,
+    patch_path: Option<String>,
+    reset_styles_path: Option<String>,
+    sarif_path: Option<String>,
+    tasks_path: Option<String>,
+    constructs: bool,
+    compile_order: Option<String>,
+    signal_graph: Option<String>,
+    dot: bool,
+    fix: bool,
+    generate_checks: bool,
+    fast: bool,
+    error_budget: Option<usize>,
+    shard_workers: Option<usize>,
+    module_workers: Option<usize>,
+    min_confidence: Option<Confidence>,
+    profile: Option<String>,
+    top_entities: Vec<String>,
+    testbench_roots: bool,
+    /// Attach the source lines each violation points at (read from disk) to
+    /// the JSON output.
+    snippets: bool,
+    diff_base: Option<String>,
+    changed_lines_path: Option<String>,
+    format: Format,
+    markdown_limit: Option<usize>,
+    link_template: Option<String>,
+}
+
+/// Serialization of the result written to stdout.
+#[derive(Default, Clone, Copy)]
+enum Format {
+    #[default]
+    Json,
+    Gitlab,
+    Checkstyle,
+    Markdown,
+}
+
+/// Violations listed in the `--format markdown` table by default.
+const DEFAULT_MARKDOWN_LIMIT: usize = 20;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let args: Vec<String> = std::env::args().collect();
-    let input = if args.len() > 1 {
-        read_input_file(&args[1])?
-    } else {
-        read_input_stdin()?
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().is_some_and(|arg| arg == "diff") {
+        return run_diff(&args[1..]);
+    }
+    let opts = parse_args(&args)?;
+    if let Some(rule) = &opts.explain {
+        let doc = rule_docs::lookup(rule).ok_or_else(|| format!("unknown rule '{}'", rule))?;
+        print!("{}", rule_docs::explain(doc));
+        return Ok(());
+    }
+    if opts.list_modules {
+        for name in engine::module_names() {
+            println!("{}", name);
+        }
+        return Ok(());
+    }
+    let mut input = match &opts.input_path {
+        Some(path) => read_input_file(path)?,
+        None => read_input_stdin()?,
+    };
+    let config_path = match &opts.config_path {
+        Some(path) => Some(PathBuf::from(path)),
+        None => config::discover(Path::new(".")),
     };
+    let mut link_template = String::new();
+    if let Some(path) = config_path {
+        let project = config::load(&path)?;
+        project.apply(&mut input);
+        link_template = project.link_template;
+    }
+    config::apply_nested(Path::new("."), &mut input)?;
+    if let Some(template) = &opts.link_template {
+        link_template = template.clone();
+    }
+    input
+        .lint_config
+        .only_modules
+        .extend(opts.only_modules.iter().cloned());
+    input
+        .lint_config
+        .skip_modules
+        .extend(opts.skip_modules.iter().cloned());
+    input.lint_config.fast |= opts.fast;
+    if let Some(budget) = opts.error_budget {
+        input.lint_config.error_budget = budget;
+    }
+    if let Some(workers) = opts.shard_workers {
+        input.lint_config.shard_workers = workers;
+    }
+    if let Some(workers) = opts.module_workers {
+        input.lint_config.module_workers = workers;
+    }
+    if let Some(confidence) = opts.min_confidence {
+        input.lint_config.min_confidence = confidence.name().to_string();
+    }
+    if let Some(profile) = &opts.profile {
+        input.profile = profile.clone();
+    }
+    if !opts.top_entities.is_empty() {
+        input.top_entities = opts.top_entities.clone();
+    }
+    input.lint_config.testbench_roots |= opts.testbench_roots;
+    if let (Some(path), false) = (&opts.baseline_path, opts.write_baseline) {
+        input.lint_config.baseline = read_baseline(path)?.counts();
+    }
+    if opts.diff_base.is_some() || opts.changed_lines_path.is_some() {
+        let mut text = String::new();
+        if let Some(base) = &opts.diff_base {
+            text.push_str(&git_diff(base)?);
+        }
+        if let Some(path) = &opts.changed_lines_path {
+            text.push_str(
+                &std::fs::read_to_string(path)
+                    .map_err(|e| format!("reading diff '{}': {}", path, e))?,
+            );
+        }
+        input.lint_config.changed_lines = Some(diff::changed_lines(&text));
+    }
 
-    let result = engine::evaluate(&input);
-    serde_json::to_writer_pretty(std::io::stdout(), &result)?;
+    if opts.constructs {
+        let inventory = verification::constructs(&input);
+        serde_json::to_writer_pretty(std::io::stdout(), &inventory)?;
+        return Ok(());
+    }
+    if let Some(format) = &opts.compile_order {
+        let order = compile_order::compile_order(&input);
+        if !order.cycles.is_empty() {
+            for cycle in &order.cycles {
+                eprintln!("circular dependency: {}", compile_order::cycle_path(cycle));
+            }
+            return Err(format!(
+                "--compile-order: {} dependency cycle(s), no compile order exists",
+                order.cycles.len()
+            )
+            .into());
+        }
+        match format.as_str() {
+            "ghdl" => print!("{}", compile_order::ghdl(&order)),
+            "vunit" => print!("{}", compile_order::vunit(&order)),
+            _ => print!("{}", compile_order::text(&order)),
+        }
+        return Ok(());
+    }
+    if let Some(name) = &opts.signal_graph {
+        let graphs = dataflow::signal_graphs(&input, name);
+        if opts.dot {
+            print!("{}", dataflow::signal_graphs_dot(&graphs));
+        } else {
+            serde_json::to_writer_pretty(std::io::stdout(), &graphs)?;
+        }
+        return Ok(());
+    }
+
+    let mut result = engine::evaluate(&input);
+    if let (Some(path), true) = (&opts.baseline_path, opts.write_baseline) {
+        let baseline = Baseline::from_violations(&result.violations);
+        std::fs::write(path, serde_json::to_string_pretty(&baseline)?)?;
+        eprintln!(
+            "--write-baseline: recorded {} violation(s) in {}",
+            result.violations.len(),
+            path
+        );
+    }
+    if let Some(path) = &opts.badge_path {
+        write_badge(
+            path,
+            &report::badge(&result.summary, helpers::file_count(&input)),
+        )?;
+    }
+    if let Some(path) = &opts.clock_domains_path {
+        std::fs::write(path, serde_json::to_string_pretty(&result.clock_domains)?)?;
+    }
+    if let Some(path) = &opts.duplicates_path {
+        let clusters = duplicates::duplicate_clusters(&input);
+        std::fs::write(path, serde_json::to_string_pretty(&clusters)?)?;
+    }
+    if let Some(path) = &opts.hierarchy_path {
+        std::fs::write(path, dot::hierarchy_dot(&input))?;
+    }
+    if let Some(path) = &opts.html_path {
+        std::fs::write(path, report::summary_html(&result.summary))?;
+    }
+    if let Some(path) = &opts.metrics_path {
+        std::fs::write(path, metrics::csv(&result.metrics))?;
+    }
+    if let Some(path) = &opts.patch_path {
+        let fixes = fixes::file_fixes(&input, &result.violations, read_source("--emit-patch"));
+        std::fs::write(path, fixes::patch(&fixes))?;
+    }
+    if opts.fix {
+        let fixes = fixes::file_fixes(&input, &result.violations, read_source("--fix"));
+        for fix in &fixes {
+            std::fs::write(&fix.file, fix.fixed())?;
+        }
+        let edits: usize = fixes.iter().map(|fix| fix.edits.len()).sum();
+        eprintln!(
+            "--fix: applied {} edit(s) to {} file(s)",
+            edits,
+            fixes.len()
+        );
+    }
+    if opts.generate_checks {
+        let files = verification::generate_checks(&input, &result.missing_checks, |file| {
+            std::fs::read_to_string(file).ok()
+        });
+        for file in &files {
+            std::fs::write(&file.file, file.fixed())?;
+        }
+        let checks: usize = result
+            .missing_checks
+            .iter()
+            .map(|task| task.missing_ids.len())
+            .sum();
+        eprintln!(
+            "--generate-checks: wrote skeletons for {} missing check(s) to {} file(s)",
+            checks,
+            files.len()
+        );
+    }
+    if let Some(path) = &opts.reset_styles_path {
+        let counts = sequential::reset_style_counts(&input);
+        std::fs::write(path, serde_json::to_string_pretty(&counts)?)?;
+    }
+    if let Some(path) = &opts.sarif_path {
+        std::fs::write(path, sarif::sarif_json(&result))?;
+    }
+    if let Some(path) = &opts.tasks_path {
+        let tasks = verification::fix_tasks(&result.missing_checks);
+        std::fs::write(path, serde_json::to_string_pretty(&tasks)?)?;
+    }
+    if opts.snippets {
+        SourceMap::read(&result.violations).attach_snippets(&mut result.violations);
+    }
+    match opts.format {
+        Format::Json => serde_json::to_writer_pretty(std::io::stdout(), &result)?,
+        Format::Gitlab => print!("{}", gitlab::code_quality_json(&result)),
+        Format::Checkstyle => print!("{}", checkstyle::checkstyle_xml(&result)),
+        Format::Markdown => print!(
+            "{}",
+            report::summary_markdown(
+                &result,
+                opts.markdown_limit.unwrap_or(DEFAULT_MARKDOWN_LIMIT),
+                &link_template
+            )
+        ),
+    }
+    Ok(())
+}
+
+fn parse_args(args: &[String]) -> Result<Options, Box<dyn Error>> {
+    let mut opts = Options::default();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--only" | "--skip" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| format!("{} requires a module list\n{}", arg, USAGE))?;
+                let modules = parse_module_list(value)?;
+                if arg == "--only" {
+                    opts.only_modules.extend(modules);
+                } else {
+                    opts.skip_modules.extend(modules);
+                }
+            }
+            "--explain" => {
+                let rule = iter
+                    .next()
+                    .ok_or_else(|| format!("--explain requires a rule id\n{}", USAGE))?;
+                opts.explain = Some(rule.clone());
+            }
+            "--list-modules" => opts.list_modules = true,
+            "--config" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| format!("--config requires a path\n{}", USAGE))?;
+                opts.config_path = Some(path.clone());
+            }
+            "--baseline" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| format!("--baseline requires a path\n{}", USAGE))?;
+                opts.baseline_path = Some(path.clone());
+            }
+            "--write-baseline" => opts.write_baseline = true,
+            "--constructs" => opts.constructs = true,
+            "--compile-order" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| format!("--compile-order requires a format\n{}", USAGE))?;
+                let format = value.to_ascii_lowercase();
+                if !matches!(format.as_str(), "ghdl" | "vunit" | "text") {
+                    return Err(
+                        format!("invalid compile order format '{}'\n{}", value, USAGE).into(),
+                    );
+                }
+                opts.compile_order = Some(format);
+            }
+            "--signal-graph" => {
+                let name = iter
+                    .next()
+                    .ok_or_else(|| format!("--signal-graph requires an architecture\n{}", USAGE))?;
+                opts.signal_graph = Some(name.clone());
+            }
+            "--dot" => opts.dot = true,
+            "--fix" => opts.fix = true,
+            "--generate-checks" => opts.generate_checks = true,
+            "--fast" => opts.fast = true,
+            "--error-budget" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| format!("--error-budget requires a count\n{}", USAGE))?;
+                let budget = value
+                    .parse()
+                    .map_err(|_| format!("invalid error budget '{}'\n{}", value, USAGE))?;
+                opts.error_budget = Some(budget);
+            }
+            "--jobs" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| format!("--jobs requires a thread count\n{}", USAGE))?;
+                let workers = value
+                    .parse()
+                    .map_err(|_| format!("invalid job count '{}'\n{}", value, USAGE))?;
+                opts.module_workers = Some(workers);
+            }
+            "--shards" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| format!("--shards requires a worker count\n{}", USAGE))?;
+                let workers = value
+                    .parse()
+                    .map_err(|_| format!("invalid shard count '{}'\n{}", value, USAGE))?;
+                opts.shard_workers = Some(workers);
+            }
+            "--min-confidence" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| format!("--min-confidence requires a level\n{}", USAGE))?;
+                let confidence = Confidence::parse(value)
+                    .ok_or_else(|| format!("invalid confidence '{}'\n{}", value, USAGE))?;
+                opts.min_confidence = Some(confidence);
+            }
+            "--profile" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| format!("--profile requires a name\n{}", USAGE))?;
+                opts.profile = Some(match value.to_ascii_lowercase().as_str() {
+                    "tb" => "tb".to_string(),
+                    "rtl" => String::new(),
+                    _ => return Err(format!("invalid profile '{}'\n{}", value, USAGE).into()),
+                });
+            }
+            "--top" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| format!("--top requires an entity list\n{}", USAGE))?;
+                opts.top_entities.extend(
+                    value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|name| !name.is_empty())
+                        .map(str::to_string),
+                );
+            }
+            "--testbench-roots" => opts.testbench_roots = true,
+            "--with-snippets" => opts.snippets = true,
+            "--diff" => {
+                let base = iter
+                    .next()
+                    .ok_or_else(|| format!("--diff requires a base ref\n{}", USAGE))?;
+                opts.diff_base = Some(base.clone());
+            }
+            "--changed-lines-from" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| format!("--changed-lines-from requires a path\n{}", USAGE))?;
+                opts.changed_lines_path = Some(path.clone());
+            }
+            "--emit-badge" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| format!("--emit-badge requires a path\n{}", USAGE))?;
+                opts.badge_path = Some(path.clone());
+            }
+            "--emit-clock-domains" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| format!("--emit-clock-domains requires a path\n{}", USAGE))?;
+                opts.clock_domains_path = Some(path.clone());
+            }
+            "--emit-duplicates" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| format!("--emit-duplicates requires a path\n{}", USAGE))?;
+                opts.duplicates_path = Some(path.clone());
+            }
+            "--emit-hierarchy" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| format!("--emit-hierarchy requires a path\n{}", USAGE))?;
+                opts.hierarchy_path = Some(path.clone());
+            }
+            "--emit-html" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| format!("--emit-html requires a path\n{}", USAGE))?;
+                opts.html_path = Some(path.clone());
+            }
+            "--emit-metrics" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| format!("--emit-metrics requires a path\n{}", USAGE))?;
+                opts.metrics_path = Some(path.clone());
+            }
+            "--emit-patch" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| format!("--emit-patch requires a path\n{}", USAGE))?;
+                opts.patch_path = Some(path.clone());
+            }
+            "--emit-reset-styles" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| format!("--emit-reset-styles requires a path\n{}", USAGE))?;
+                opts.reset_styles_path = Some(path.clone());
+            }
+            "--emit-sarif" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| format!("--emit-sarif requires a path\n{}", USAGE))?;
+                opts.sarif_path = Some(path.clone());
+            }
+            "--emit-tasks" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| format!("--emit-tasks requires a path\n{}", USAGE))?;
+                opts.tasks_path = Some(path.clone());
+            }
+            "--format" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| format!("--format requires a name\n{}", USAGE))?;
+                opts.format = match value.to_ascii_lowercase().as_str() {
+                    "json" => Format::Json,
+                    "gitlab" => Format::Gitlab,
+                    "checkstyle" => Format::Checkstyle,
+                    "markdown" => Format::Markdown,
+                    _ => return Err(format!("invalid format '{}'\n{}", value, USAGE).into()),
+                };
+            }
+            "--markdown-limit" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| format!("--markdown-limit requires a count\n{}", USAGE))?;
+                let limit = value
+                    .parse()
+                    .map_err(|_| format!("invalid markdown limit '{}'\n{}", value, USAGE))?;
+                opts.markdown_limit = Some(limit);
+            }
+            "--link-template" => {
+                let template = iter
+                    .next()
+                    .ok_or_else(|| format!("--link-template requires a URL\n{}", USAGE))?;
+                opts.link_template = Some(template.clone());
+            }
+            "-h" | "--help" => {
+                eprintln!("{}", USAGE);
+                eprintln!("Modules: {}", engine::module_names().join(", "));
+                std::process::exit(0);
+            }
+            _ if arg.starts_with("--") => {
+                return Err(format!("unknown option '{}'\n{}", arg, USAGE).into());
+            }
+            _ => {
+                if opts.input_path.is_some() {
+                    return Err(format!("unexpected argument '{}'\n{}", arg, USAGE).into());
+                }
+                opts.input_path = Some(arg.clone());
+            }
+        }
+    }
+    if opts.write_baseline && opts.baseline_path.is_none() {
+        return Err(format!("--write-baseline requires --baseline <path>\n{}", USAGE).into());
+    }
+    Ok(opts)
+}
+
+/// `diff <old.json> <new.json>`: new, fixed and persisting violations
+/// between two result files (`policy::result_diff`).
+fn run_diff(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut paths = Vec::new();
+    let mut format = "text".to_string();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--format" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| format!("--format requires a name\n{}", USAGE))?;
+                format = value.to_ascii_lowercase();
+            }
+            _ if arg.starts_with("--") => {
+                return Err(format!("unknown option '{}'\n{}", arg, USAGE).into());
+            }
+            _ => paths.push(arg),
+        }
+    }
+    let [old, new] = paths.as_slice() else {
+        return Err(format!("diff requires two result files\n{}", USAGE).into());
+    };
+    let read = |path: &str| -> Result<Vec<Violation>, Box<dyn Error>> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("reading result '{}': {}", path, e))?;
+        Ok(result_diff::read_violations(&text)
+            .map_err(|e| format!("parsing result '{}': {}", path, e))?)
+    };
+    let diff = result_diff::compare(&read(old)?, &read(new)?);
+    match format.as_str() {
+        "text" => print!("{}", result_diff::text(&diff)),
+        "markdown" => print!("{}", result_diff::markdown(&diff)),
+        "json" => serde_json::to_writer_pretty(std::io::stdout(), &diff)?,
+        _ => return Err(format!("invalid format '{}'\n{}", format, USAGE).into()),
+    }
+    Ok(())
+}
+
+/// Zero-context diff of the working tree against `base`, with paths relative
+/// to the current directory like the indexer's.
+fn git_diff(base: &str) -> Result<String, Box<dyn Error>> {
+    let output = Command::new("git")
+        .args([
+            "diff",
+            "--no-color",
+            "--no-ext-diff",
+            "-U0",
+            "--relative",
+            // `base` comes from the user; never let it parse as an option.
+            "--end-of-options",
+            base,
+        ])
+        .output()
+        .map_err(|e| format!("--diff: running git: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "--diff: git diff {} failed: {}",
+            base,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )
+        .into());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn read_baseline(path: &str) -> Result<Baseline, Box<dyn Error>> {
+    let file = File::open(path).map_err(|e| format!("reading baseline '{}': {}", path, e))?;
+    let baseline =
+        serde_json::from_reader(file).map_err(|e| format!("parsing baseline '{}': {}", path, e))?;
+    Ok(baseline)
+}
+
+fn parse_module_list(value: &str) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut modules = Vec::new();
+    for name in value.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        if !engine::is_known_module(name) {
+            return Err(format!(
+                "unknown rule module '{}' (known: {})",
+                name,
+                engine::module_names().join(", ")
+            )
+            .into());
+        }
+        modules.push(name.to_ascii_lowercase());
+    }
+    Ok(modules)
+}
+
+/// Reads a source file for a fix, warning on stderr when it is skipped.
+fn read_source(flag: &'static str) -> impl Fn(&str) -> Option<String> {
+    move |file| {
+        std::fs::read_to_string(file)
+            .map_err(|e| eprintln!("{}: skipping '{}': {}", flag, file, e))
+            .ok()
+    }
+}
+
+/// Writes an SVG badge for `.svg` paths, shields.io endpoint JSON otherwise.
+fn write_badge(path: &str, badge: &report::Badge) -> Result<(), Box<dyn Error>> {
+    let body = if path.to_ascii_lowercase().ends_with(".svg") {
+        report::badge_svg(badge)
+    } else {
+        report::badge_json(badge)
+    };
+    std::fs::write(path, body)?;
     Ok(())
 }
 
@@ -5,8 +5,10 @@ use std::io::{self, BufRead, Write};
 use std::rc::Rc;
 
 use differential_dataflow::input::InputSession;
-use differential_dataflow::operators::{Consolidate, Join};
+use differential_dataflow::operators::{Consolidate, Join, Reduce, Threshold};
 use serde::{Deserialize, Serialize};
+use vhdl_compiler::policy::helpers::{is_clock_name, is_reset_name, is_testbench_name};
+use vhdl_compiler::policy::hierarchy::{actual_width, base_name, ActualWidth};
 
 #[derive(Debug, Deserialize, Default, Clone)]
 struct Tables {
@@ -17,9 +19,17 @@ struct Tables {
     #[serde(default)]
     ports: Vec<PortRow>,
     #[serde(default)]
+    signals: Vec<SignalRow>,
+    #[serde(default)]
+    instances: Vec<InstanceRow>,
+    #[serde(default)]
     dependencies: Vec<DependencyRow>,
     #[serde(default)]
     symbols: Vec<SymbolRow>,
+    #[serde(default)]
+    signal_uses: Vec<SignalUseRow>,
+    #[serde(default)]
+    port_maps: Vec<PortMapRow>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -53,8 +63,33 @@ struct PortRow {
     entity: String,
     name: String,
     direction: String,
+    #[serde(default)]
+    default: String,
+    #[serde(default)]
+    width: i64,
+    file: String,
+    line: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct SignalRow {
+    name: String,
+    #[serde(default)]
+    width: i64,
     file: String,
     line: i64,
+    #[serde(default)]
+    scope: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct InstanceRow {
+    name: String,
+    target: String,
+    file: String,
+    line: i64,
+    #[serde(default)]
+    in_arch: String,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -70,6 +105,21 @@ struct SymbolRow {
     name: String,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+struct SignalUseRow {
+    name: String,
+    kind: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct PortMapRow {
+    instance: String,
+    formal: String,
+    actual: String,
+    file: String,
+    line: i64,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 struct ViolationKey {
     rule: String,
@@ -125,11 +175,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     timely::execute_directly(move |worker| {
         let rx = rx.clone();
         let mut stdout = io::BufWriter::new(io::stdout());
-        let mut entities: Session<(String, String, i64)> = InputSession::new();
-        let mut architectures: Session<(String, String, i64, String)> = InputSession::new();
-        let mut ports: Session<(String, String)> = InputSession::new();
-        let mut dependencies: Session<(String, String, i64, String)> = InputSession::new();
-        let mut symbols: Session<String> = InputSession::new();
+        let mut inputs = Inputs::new();
 
         let violations_state: Rc<RefCell<HashMap<ViolationKey, isize>>> =
             Rc::new(RefCell::new(HashMap::new()));
@@ -138,23 +184,46 @@ fn main() -> Result<(), Box<dyn Error>> {
         let mut probe = timely::dataflow::operators::probe::Handle::new();
 
         worker.dataflow(|scope| {
-            let entity_rows = entities.to_collection(scope).map(|(name, file, line)| {
-                let name_clone = name.clone();
-                (name, (file, line, name_clone))
+            // Join keys are folded to lowercase (VHDL identifiers are case-insensitive)
+            // and the right-hand sides are distinct so duplicate rows don't skew counts.
+            let entity_rows = inputs.entities.to_collection(scope).map(|(name, file, line)| {
+                (name.to_ascii_lowercase(), (file, line, name))
             });
-            let arch_rows = architectures
-                .to_collection(scope)
-                .map(|(entity, file, line, name)| (entity, (file, line, name)));
-            let port_entities = ports
+            let arch_rows = inputs
+                .architectures
                 .to_collection(scope)
-                .map(|(entity, _name)| (entity, ()));
-            let dep_rows = dependencies
+                .map(|(entity, file, line, name)| {
+                    (entity.to_ascii_lowercase(), (file, line, name, entity))
+                });
+            let port_rows = inputs.ports.to_collection(scope);
+            let signal_rows = inputs.signals.to_collection(scope);
+            let instance_rows = inputs.instances.to_collection(scope);
+            let port_map_rows = inputs.port_maps.to_collection(scope);
+            let use_rows = inputs.signal_uses.to_collection(scope);
+            let port_entities = port_rows
+                .map(|(entity, ..)| (entity.to_ascii_lowercase(), ()))
+                .distinct();
+            let entity_keys = entity_rows.map(|(entity, _)| (entity, ())).distinct();
+            let arch_keys = arch_rows.map(|(entity, _)| (entity, ())).distinct();
+            let arch_entities = arch_rows.map(|(entity, _)| entity).distinct();
+            // Files declaring a testbench entity; RTL-only rules are dropped there,
+            // matching the batch engine's file context.
+            let tb_files = entity_rows
+                .filter(|(_entity, (_file, _line, name))| is_testbench_name(name))
+                .map(|(_entity, (file, _line, _name))| file)
+                .distinct();
+            let dep_rows = inputs
+                .dependencies
                 .to_collection(scope)
                 .map(|(target, file, line, kind)| {
                     let target_clone = target.clone();
                     (target, (file, line, kind, target_clone))
                 });
-            let sym_rows = symbols.to_collection(scope).map(|name| (name, ()));
+            let sym_rows = inputs
+                .symbols
+                .to_collection(scope)
+                .map(|name| (name, ()))
+                .distinct();
 
             let entity_ports = entity_rows.join_map(&port_entities, |entity, payload, _| {
                 (entity.clone(), payload.clone())
@@ -171,13 +240,13 @@ fn main() -> Result<(), Box<dyn Error>> {
                     message: format!("Entity '{}' has no ports defined", name),
                 });
 
-            let arch_with_entity = arch_rows.join_map(&entity_rows, |entity, payload, _| {
+            let arch_with_entity = arch_rows.join_map(&entity_keys, |entity, payload, _| {
                 (entity.clone(), payload.clone())
             });
             let orphan_arch = arch_rows
                 .concat(&arch_with_entity.negate())
                 .consolidate()
-                .map(|(entity, (file, line, name))| ViolationKey {
+                .map(|(_key, (file, line, name, entity))| ViolationKey {
                     rule: "architecture_has_entity".to_string(),
                     severity: "error".to_string(),
                     file,
@@ -188,7 +257,7 @@ fn main() -> Result<(), Box<dyn Error>> {
                     ),
                 });
 
-            let entity_with_arch = entity_rows.join_map(&arch_rows, |entity, payload, _| {
+            let entity_with_arch = entity_rows.join_map(&arch_keys, |entity, payload, _| {
                 (entity.clone(), payload.clone())
             });
             let entities_without_arch = entity_rows
@@ -217,10 +286,236 @@ fn main() -> Result<(), Box<dyn Error>> {
                     message: format!("Unresolved dependency: '{}'", dep_target),
                 });
 
+            // Signals: a declaration nothing reads, writes or maps onto a port.
+            let used_names = use_rows
+                .map(|(name, _kind)| name.to_ascii_lowercase())
+                .concat(&port_map_rows.map(|(_inst, _formal, actual, _file, _line)| {
+                    base_name(&actual).to_ascii_lowercase()
+                }))
+                .distinct();
+            let unused_signals = signal_rows
+                .map(|(name, _scope, _width, file, line)| {
+                    (name.to_ascii_lowercase(), (file, line, name))
+                })
+                .antijoin(&used_names)
+                .map(|(_key, (file, line, name))| (file, (line, name)))
+                .antijoin(&tb_files)
+                .map(|(file, (line, name))| ViolationKey {
+                    rule: "unused_signal".to_string(),
+                    severity: "warning".to_string(),
+                    file,
+                    line,
+                    message: format!("Signal '{}' is declared but never used", name),
+                });
+
+            // Ports: outputs of implemented entities that nothing drives.
+            let assigned_names = use_rows
+                .filter(|(_name, kind)| kind == "write")
+                .map(|(name, _kind)| name.to_ascii_lowercase())
+                .concat(&port_map_rows.map(|(_inst, _formal, actual, _file, _line)| {
+                    actual.to_ascii_lowercase()
+                }))
+                .distinct();
+            let undriven_outputs = port_rows
+                .filter(|(_entity, _name, direction, ..)| direction == "out")
+                .map(|(entity, name, _dir, _default, _width, file, line)| {
+                    (entity.to_ascii_lowercase(), (file, line, name))
+                })
+                .semijoin(&arch_entities)
+                .map(|(_entity, (file, line, name))| (name.to_ascii_lowercase(), (file, line, name)))
+                .antijoin(&assigned_names)
+                .map(|(_key, (file, line, name))| ViolationKey {
+                    rule: "undriven_output_port".to_string(),
+                    severity: "error".to_string(),
+                    file,
+                    line,
+                    message: format!("Output port '{}' is never assigned (floating output)", name),
+                });
+
+            // Hierarchy: each instance is bound to every entity declaration matching
+            // the last segment of its target (`work.core` binds `core`). Entity
+            // declarations are keyed by (name, file) so ports resolve to their entity.
+            let bound_instances = instance_rows
+                .map(|(name, target, file, line, in_arch)| {
+                    let entity = target.rsplit('.').next().unwrap_or("").to_ascii_lowercase();
+                    (entity, (file, line, name, in_arch))
+                })
+                .join_map(
+                    &entity_rows,
+                    |entity, (file, line, inst, in_arch), (entity_file, _line, entity_name)| {
+                        (
+                            (entity.clone(), entity_file.clone()),
+                            (
+                                file.clone(),
+                                *line,
+                                inst.clone(),
+                                in_arch.to_ascii_lowercase(),
+                                entity_name.clone(),
+                            ),
+                        )
+                    },
+                );
+            let required_inputs = port_rows
+                .filter(|(_entity, name, direction, default, ..)| {
+                    direction == "in"
+                        && default.trim().is_empty()
+                        && !is_clock_name(name)
+                        && !is_reset_name(name)
+                })
+                .map(|(entity, name, _dir, _default, _width, file, _line)| {
+                    ((entity.to_ascii_lowercase(), file), name)
+                });
+            let mapped_formals = port_map_rows
+                .map(|(inst, formal, _actual, file, line)| {
+                    (file, line, inst.to_ascii_lowercase(), formal.to_ascii_lowercase())
+                })
+                .distinct();
+            let floating_inputs = bound_instances
+                .join_map(
+                    &required_inputs,
+                    |_key, (file, line, inst, _in_arch, entity_name), port| {
+                        (
+                            (
+                                file.clone(),
+                                *line,
+                                inst.to_ascii_lowercase(),
+                                port.to_ascii_lowercase(),
+                            ),
+                            (inst.clone(), port.clone(), entity_name.clone()),
+                        )
+                    },
+                )
+                .antijoin(&mapped_formals)
+                .map(|((file, line, _inst, _port), payload)| (file, (line, payload)))
+                .antijoin(&tb_files)
+                .map(|(file, (line, (inst, port, entity)))| ViolationKey {
+                    rule: "floating_instance_input".to_string(),
+                    severity: "error".to_string(),
+                    file,
+                    line,
+                    message: format!(
+                        "Instance '{}' has unconnected input port '{}' from entity '{}'",
+                        inst, port, entity
+                    ),
+                });
+
+            // Width of each declared name as seen from an architecture, keyed by
+            // (architecture, name); the empty architecture is the project-wide view.
+            // Like the batch engine, the widest declaration wins.
+            let port_widths = port_rows.map(|(entity, name, _dir, _default, width, _file, _line)| {
+                (entity.to_ascii_lowercase(), (name.to_ascii_lowercase(), width))
+            });
+            let declared_widths = signal_rows
+                .flat_map(|(name, scope, width, _file, _line)| {
+                    let name = name.to_ascii_lowercase();
+                    vec![
+                        ((scope.to_ascii_lowercase(), name.clone()), width),
+                        ((String::new(), name), width),
+                    ]
+                })
+                .concat(&port_widths.join_map(&arch_rows, |_entity, (port, width), arch| {
+                    ((arch.2.to_ascii_lowercase(), port.clone()), *width)
+                }))
+                .concat(&port_widths.map(|(_entity, (port, width))| ((String::new(), port), width)))
+                .reduce(|_key, widths, output| {
+                    // Values arrive sorted, so the last one is the widest.
+                    output.push((*widths[widths.len() - 1].0, 1isize));
+                });
+
+            let connections = port_map_rows
+                .map(|(inst, formal, actual, file, line)| {
+                    ((file, line, inst.to_ascii_lowercase()), (formal, actual))
+                })
+                .join_map(
+                    &bound_instances.map(|((entity, entity_file), (file, line, inst, in_arch, _))| {
+                        ((file, line, inst.to_ascii_lowercase()), (entity, entity_file, inst, in_arch))
+                    }),
+                    |(file, line, _key), (formal, actual), (entity, entity_file, inst, in_arch)| {
+                        (
+                            (entity.clone(), entity_file.clone(), formal.to_ascii_lowercase()),
+                            (file.clone(), *line, inst.clone(), in_arch.clone(), actual.clone()),
+                        )
+                    },
+                )
+                .join_map(
+                    &port_rows
+                        .filter(|(_entity, _name, _dir, _default, width, _file, _line)| *width > 0)
+                        .map(|(entity, name, _dir, _default, width, file, _line)| {
+                            (
+                                (entity.to_ascii_lowercase(), file, name.to_ascii_lowercase()),
+                                (name, width),
+                            )
+                        }),
+                    |_key, (file, line, inst, in_arch, actual), (port, width)| {
+                        (
+                            file.clone(),
+                            *line,
+                            inst.clone(),
+                            in_arch.clone(),
+                            actual.clone(),
+                            port.clone(),
+                            *width,
+                        )
+                    },
+                );
+            let literal_widths =
+                connections.flat_map(|(file, line, inst, _in_arch, actual, port, width)| {
+                    match actual_width(&actual) {
+                        ActualWidth::Fixed(bits) => {
+                            Some((file, line, inst, actual, bits as i64, port, width))
+                        }
+                        _ => None,
+                    }
+                });
+            let named_widths = connections
+                .flat_map(|(file, line, inst, in_arch, actual, port, width)| {
+                    match actual_width(&actual) {
+                        ActualWidth::Declared { base, indexed } => Some((
+                            (in_arch, base.to_ascii_lowercase()),
+                            (file, line, inst, actual, indexed, port, width),
+                        )),
+                        _ => None,
+                    }
+                })
+                .join_map(
+                    &declared_widths,
+                    |_key, (file, line, inst, actual, indexed, port, width), declared| {
+                        let bits = if *indexed && *declared > 0 { 1 } else { *declared };
+                        (
+                            file.clone(),
+                            *line,
+                            inst.clone(),
+                            actual.clone(),
+                            bits,
+                            port.clone(),
+                            *width,
+                        )
+                    },
+                );
+            let width_mismatches = literal_widths
+                .concat(&named_widths)
+                .filter(|(_file, _line, _inst, _actual, bits, _port, width)| {
+                    *bits != 0 && bits != width
+                })
+                .map(|(file, line, inst, actual, bits, port, width)| ViolationKey {
+                    rule: "port_width_mismatch".to_string(),
+                    severity: "error".to_string(),
+                    file,
+                    line,
+                    message: format!(
+                        "Width mismatch: signal '{}' ({} bits) connected to port '{}' ({} bits) in instance '{}'",
+                        actual, bits, port, width, inst
+                    ),
+                });
+
             let all = entities_without_ports
                 .concat(&orphan_arch)
                 .concat(&entities_without_arch)
-                .concat(&unresolved);
+                .concat(&unresolved)
+                .concat(&unused_signals)
+                .concat(&undriven_outputs)
+                .concat(&floating_inputs)
+                .concat(&width_mismatches);
 
             all.inspect(move |(violation, _time, diff)| {
                 let mut map = violations_state_inner.borrow_mut();
@@ -260,36 +555,10 @@ fn main() -> Result<(), Box<dyn Error>> {
             };
 
             match cmd.kind.as_str() {
-                "init" => {
-                    apply_tables(
-                        &cmd.tables,
-                        &mut entities,
-                        &mut architectures,
-                        &mut ports,
-                        &mut dependencies,
-                        &mut symbols,
-                        1,
-                    );
-                }
+                "init" => inputs.apply(&cmd.tables, 1),
                 "delta" => {
-                    apply_tables(
-                        &cmd.added,
-                        &mut entities,
-                        &mut architectures,
-                        &mut ports,
-                        &mut dependencies,
-                        &mut symbols,
-                        1,
-                    );
-                    apply_tables(
-                        &cmd.removed,
-                        &mut entities,
-                        &mut architectures,
-                        &mut ports,
-                        &mut dependencies,
-                        &mut symbols,
-                        -1,
-                    );
+                    inputs.apply(&cmd.added, 1);
+                    inputs.apply(&cmd.removed, -1);
                 }
                 "snapshot" => {}
                 _ => {
@@ -303,18 +572,8 @@ fn main() -> Result<(), Box<dyn Error>> {
                 }
             }
 
-            entities.advance_to(epoch);
-            architectures.advance_to(epoch);
-            ports.advance_to(epoch);
-            dependencies.advance_to(epoch);
-            symbols.advance_to(epoch);
-            entities.flush();
-            architectures.flush();
-            ports.flush();
-            dependencies.flush();
-            symbols.flush();
-
-            while probe.less_than(entities.time()) {
+            inputs.advance_to(epoch);
+            while probe.less_than(inputs.entities.time()) {
                 worker.step();
             }
 
@@ -332,45 +591,139 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-fn apply_tables(
-    tables: &Tables,
-    entities: &mut Session<(String, String, i64)>,
-    architectures: &mut Session<(String, String, i64, String)>,
-    ports: &mut Session<(String, String)>,
-    dependencies: &mut Session<(String, String, i64, String)>,
-    symbols: &mut Session<String>,
-    weight: isize,
-) {
-    for ent in &tables.entities {
-        entities.update((ent.name.clone(), ent.file.clone(), ent.line), weight);
-    }
-    for arch in &tables.architectures {
-        architectures.update(
-            (
-                arch.entity_name.clone(),
-                arch.file.clone(),
-                arch.line,
-                arch.name.clone(),
-            ),
-            weight,
-        );
-    }
-    for port in &tables.ports {
-        ports.update((port.entity.clone(), port.name.clone()), weight);
+/// One input session per fact table the dataflow reads.
+struct Inputs {
+    entities: Session<(String, String, i64)>,
+    architectures: Session<(String, String, i64, String)>,
+    ports: Session<(String, String, String, String, i64, String, i64)>,
+    signals: Session<(String, String, i64, String, i64)>,
+    instances: Session<(String, String, String, i64, String)>,
+    dependencies: Session<(String, String, i64, String)>,
+    symbols: Session<String>,
+    signal_uses: Session<(String, String)>,
+    port_maps: Session<(String, String, String, String, i64)>,
+}
+
+impl Inputs {
+    fn new() -> Self {
+        Inputs {
+            entities: InputSession::new(),
+            architectures: InputSession::new(),
+            ports: InputSession::new(),
+            signals: InputSession::new(),
+            instances: InputSession::new(),
+            dependencies: InputSession::new(),
+            symbols: InputSession::new(),
+            signal_uses: InputSession::new(),
+            port_maps: InputSession::new(),
+        }
     }
-    for dep in &tables.dependencies {
-        dependencies.update(
-            (
-                dep.target.clone(),
-                dep.file.clone(),
-                dep.line,
-                dep.kind.clone(),
-            ),
-            weight,
-        );
+
+    fn apply(&mut self, tables: &Tables, weight: isize) {
+        for ent in &tables.entities {
+            self.entities
+                .update((ent.name.clone(), ent.file.clone(), ent.line), weight);
+        }
+        for arch in &tables.architectures {
+            self.architectures.update(
+                (
+                    arch.entity_name.clone(),
+                    arch.file.clone(),
+                    arch.line,
+                    arch.name.clone(),
+                ),
+                weight,
+            );
+        }
+        for port in &tables.ports {
+            self.ports.update(
+                (
+                    port.entity.clone(),
+                    port.name.clone(),
+                    port.direction.clone(),
+                    port.default.clone(),
+                    port.width,
+                    port.file.clone(),
+                    port.line,
+                ),
+                weight,
+            );
+        }
+        for sig in &tables.signals {
+            self.signals.update(
+                (
+                    sig.name.clone(),
+                    sig.scope.clone(),
+                    sig.width,
+                    sig.file.clone(),
+                    sig.line,
+                ),
+                weight,
+            );
+        }
+        for inst in &tables.instances {
+            self.instances.update(
+                (
+                    inst.name.clone(),
+                    inst.target.clone(),
+                    inst.file.clone(),
+                    inst.line,
+                    inst.in_arch.clone(),
+                ),
+                weight,
+            );
+        }
+        for dep in &tables.dependencies {
+            self.dependencies.update(
+                (
+                    dep.target.clone(),
+                    dep.file.clone(),
+                    dep.line,
+                    dep.kind.clone(),
+                ),
+                weight,
+            );
+        }
+        for sym in &tables.symbols {
+            self.symbols.update(sym.name.clone(), weight);
+        }
+        for usage in &tables.signal_uses {
+            self.signal_uses
+                .update((usage.name.clone(), usage.kind.clone()), weight);
+        }
+        for map in &tables.port_maps {
+            self.port_maps.update(
+                (
+                    map.instance.clone(),
+                    map.formal.clone(),
+                    map.actual.clone(),
+                    map.file.clone(),
+                    map.line,
+                ),
+                weight,
+            );
+        }
     }
-    for sym in &tables.symbols {
-        symbols.update(sym.name.clone(), weight);
+
+    fn advance_to(&mut self, epoch: u64) {
+        self.entities.advance_to(epoch);
+        self.architectures.advance_to(epoch);
+        self.ports.advance_to(epoch);
+        self.signals.advance_to(epoch);
+        self.instances.advance_to(epoch);
+        self.dependencies.advance_to(epoch);
+        self.symbols.advance_to(epoch);
+        self.signal_uses.advance_to(epoch);
+        self.port_maps.advance_to(epoch);
+        self.entities.flush();
+        self.architectures.flush();
+        self.ports.flush();
+        self.signals.flush();
+        self.instances.flush();
+        self.dependencies.flush();
+        self.symbols.flush();
+        self.signal_uses.flush();
+        self.port_maps.flush();
     }
 }
 
@@ -406,13 +759,4 @@ fn build_response(violations: &HashMap<ViolationKey, isize>) -> Response {
     }
 }
 
-fn is_testbench_name(name: &str) -> bool {
-    let lower = name.to_ascii_lowercase();
-    lower.contains("_tb")
-        || lower.contains("tb_")
-        || lower.ends_with("tb")
-        || lower.contains("test")
-        || lower.contains("bench")
-}
-
 type Session<D> = InputSession<u64, D, isize>;
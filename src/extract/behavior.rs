@@ -0,0 +1,555 @@
+use std::collections::HashSet;
+
+use tree_sitter::Node;
+
+use super::names::{
+    assignment_value, base_name, clock_edge, collect_reads, columns, compared_signal, line,
+    normalize, sensitivity_list, severity_clause, text, wait_clauses,
+};
+use super::units::descendants;
+use crate::policy::helpers::is_reset_name;
+use crate::policy::input::{
+    Assertion, CaseArm, CaseStatement, ConcurrentAssignment, IfArm, IfStatement, Input,
+    ProcedureCall, Process, SequentialAssignment, SignalDep, TristateAssignment, VariableDecl,
+    WaitStatement,
+};
+
+/// Behavior pass: processes (with their case statements) and concurrent
+/// assignments, plus the signal dependencies both imply.
+pub(super) struct Context<'a> {
+    file: &'a str,
+    source: &'a [u8],
+    /// Lower-cased signal and port names from every file.
+    declared: &'a HashSet<String>,
+}
+
+/// Per-process state threaded through the statement walk.
+#[derive(Default)]
+struct ProcessScan {
+    locals: HashSet<String>,
+    assigned: Vec<String>,
+    reads: Vec<String>,
+    /// Wait statements seen so far; the segment of later assignments.
+    waits: usize,
+    deps: Vec<(String, String, usize)>,
+    cases: Vec<CaseStatement>,
+    assertions: Vec<Assertion>,
+}
+
+impl<'a> Context<'a> {
+    pub(super) fn new(file: &'a str, source: &'a [u8], declared: &'a HashSet<String>) -> Self {
+        Self {
+            file,
+            source,
+            declared,
+        }
+    }
+
+    /// `arch` is the enclosing architecture name.
+    pub(super) fn walk(&mut self, node: Node, arch: &str, input: &mut Input) {
+        let mut arch = arch.to_string();
+        match node.kind() {
+            "architecture_body" => {
+                arch = node
+                    .child_by_field_name("name")
+                    .map(|n| text(n, self.source).to_string())
+                    .unwrap_or_default();
+            }
+            "process_statement" => {
+                self.process(node, &arch, input);
+                return;
+            }
+            "signal_assignment" => {
+                self.concurrent_assignment(node, &arch, input);
+                return;
+            }
+            "assert_statement" | "report_statement" => {
+                if let Some(mut assertion) = self.assertion(node) {
+                    assertion.in_arch = arch;
+                    input.assertions.push(assertion);
+                }
+                return;
+            }
+            "function_declaration" | "procedure_declaration" => return,
+            _ => {}
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.walk(child, &arch, input);
+        }
+    }
+
+    fn is_signal(&self, name: &str, locals: &HashSet<String>) -> bool {
+        let lower = name.to_ascii_lowercase();
+        self.declared.contains(&lower) && !locals.contains(&lower)
+    }
+
+    /// Declared signals read under `node`, excluding the `skip` subtree.
+    fn reads(&self, node: Node, skip: Option<Node>, locals: &HashSet<String>) -> Vec<String> {
+        let mut names = Vec::new();
+        collect_reads(node, self.source, skip, &mut names);
+        let mut seen = HashSet::new();
+        names
+            .into_iter()
+            .filter(|name| self.is_signal(name, locals))
+            .filter(|name| seen.insert(name.to_ascii_lowercase()))
+            .collect()
+    }
+
+    fn process(&self, node: Node, arch: &str, input: &mut Input) {
+        let label = node
+            .child_by_field_name("label")
+            .map(|n| text(n, self.source).to_string())
+            .unwrap_or_default();
+        let (column, end_column) = columns(node.child_by_field_name("label"), line(node));
+        let mut proc = Process {
+            label: label.clone(),
+            sensitivity_list: node
+                .child_by_field_name("sensitivity")
+                .map(|n| sensitivity_list(n, self.source))
+                .unwrap_or_default(),
+            file: self.file.to_string(),
+            line: line(node),
+            column,
+            end_column,
+            in_arch: arch.to_string(),
+            ..Default::default()
+        };
+
+        let mut scan = ProcessScan::default();
+        for decl in descendants(node, "variable_declaration") {
+            let ty = decl
+                .child_by_field_name("type")
+                .map(|n| normalize(text(n, self.source)))
+                .unwrap_or_default();
+            for name in declared_names(decl, self.source) {
+                scan.locals.insert(name.to_ascii_lowercase());
+                proc.variables.push(VariableDecl {
+                    name,
+                    r#type: ty.clone(),
+                    line: line(decl),
+                });
+            }
+        }
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.statement(child, "", false, &mut proc, &mut scan);
+        }
+        for assign in descendants(node, "sequential_signal_assignment") {
+            self.tristate(assign, arch, &label, input);
+        }
+
+        proc.is_sequential = !proc.clock_signal.is_empty();
+        proc.is_combinational = !proc.is_sequential && scan.waits == 0;
+        proc.assigned_signals = scan.assigned;
+        proc.read_signals = scan.reads;
+        for (source, target, dep_line) in scan.deps {
+            input.signal_deps.push(SignalDep {
+                source,
+                target,
+                file: self.file.to_string(),
+                line: dep_line,
+                is_sequential: proc.is_sequential || scan.waits > 0,
+                in_process: label.clone(),
+                in_arch: arch.to_string(),
+            });
+        }
+        for mut case in scan.cases {
+            case.in_process = label.clone();
+            case.in_arch = arch.to_string();
+            input.case_statements.push(case);
+        }
+        for mut assertion in scan.assertions {
+            assertion.in_process = label.clone();
+            assertion.process_line = proc.line;
+            assertion.in_arch = arch.to_string();
+            input.assertions.push(assertion);
+        }
+        input.processes.push(proc);
+    }
+
+    /// Walks sequential statements. `branch` is the enclosing branch path in
+    /// the Go extractor's format (`/if@<byte>.<index>`, `/case@<byte>`);
+    /// `clocked` is set inside the branch of a clock-edge `if`, which is what
+    /// makes a reset synchronous.
+    fn statement(
+        &self,
+        node: Node,
+        branch: &str,
+        clocked: bool,
+        proc: &mut Process,
+        scan: &mut ProcessScan,
+    ) {
+        match node.kind() {
+            "sequential_signal_assignment" => {
+                let target = node.child_by_field_name("target");
+                let target_name = target
+                    .map(|t| base_name(text(t, self.source)))
+                    .unwrap_or_default();
+                let reads = self.reads(node, target, &scan.locals);
+                if !target_name.is_empty()
+                    && !scan.locals.contains(&target_name.to_ascii_lowercase())
+                {
+                    for read in &reads {
+                        scan.deps
+                            .push((read.clone(), target_name.clone(), line(node)));
+                    }
+                    push_unique(&mut scan.assigned, &target_name);
+                    let target = target.map(|t| text(t, self.source).trim()).unwrap_or("");
+                    // Aggregate targets write several signals at once.
+                    if !target.starts_with('(') {
+                        proc.assignments.push(SequentialAssignment {
+                            target: target.to_string(),
+                            signal: target_name.clone(),
+                            value: assignment_value(text(node, self.source)),
+                            line: line(node),
+                            branch: branch.to_string(),
+                            segment: scan.waits,
+                        });
+                    }
+                }
+                for read in reads {
+                    push_unique(&mut scan.reads, &read);
+                }
+                return;
+            }
+            "if_statement" => {
+                let mut branch_clocked = clocked;
+                let mut idx: Option<usize> = None;
+                let mut prev_end = node.start_byte();
+                let at = proc.if_statements.len();
+                proc.if_statements.push(IfStatement {
+                    branch: branch.to_string(),
+                    line: line(node),
+                    ..Default::default()
+                });
+                let mut cursor = node.walk();
+                for child in node.children(&mut cursor) {
+                    let gap = &self.source[prev_end..child.start_byte()];
+                    let arm = if child.kind() == "condition" {
+                        idx = Some(idx.map_or(0, |i| i + 1));
+                        idx
+                    } else if child.is_named() && idx.is_some() && has_else_keyword(gap) {
+                        proc.if_statements[at].has_else = true;
+                        idx = idx.map(|i| i + 1);
+                        idx
+                    } else {
+                        None
+                    };
+                    if let Some(arm) = arm {
+                        let condition = if child.kind() == "condition" {
+                            text(child, self.source).to_string()
+                        } else {
+                            String::new()
+                        };
+                        proc.if_statements[at].arms.push(IfArm {
+                            line: line(child),
+                            branch: format!("if@{}.{}", node.start_byte(), arm),
+                            condition,
+                        });
+                    }
+                    prev_end = child.end_byte();
+                    if child.kind() == "condition" {
+                        let condition = text(child, self.source);
+                        if let Some((clock, edge)) = clock_edge(condition) {
+                            if proc.clock_signal.is_empty() {
+                                proc.clock_signal = clock;
+                                proc.clock_edge = edge.to_string();
+                            }
+                            branch_clocked = true;
+                            continue;
+                        }
+                        if let Some(reset) = compared_signal(condition) {
+                            if is_reset_name(&reset) && !proc.has_reset {
+                                proc.has_reset = true;
+                                proc.reset_signal = reset;
+                                proc.reset_async = !clocked;
+                            }
+                        }
+                        for read in self.reads(child, None, &scan.locals) {
+                            push_unique(&mut scan.reads, &read);
+                        }
+                        continue;
+                    }
+                    let Some(idx) = idx else {
+                        continue;
+                    };
+                    let path = format!("{}/if@{}.{}", branch, node.start_byte(), idx);
+                    self.statement(child, &path, branch_clocked, proc, scan);
+                }
+                return;
+            }
+            "case_statement" => {
+                let expression = node
+                    .child_by_field_name("expression")
+                    .map(|n| normalize(text(n, self.source)))
+                    .unwrap_or_default();
+                if let Some(expr) = node.child_by_field_name("expression") {
+                    for read in self.reads(expr, None, &scan.locals) {
+                        push_unique(&mut scan.reads, &read);
+                    }
+                }
+                let mut case = CaseStatement {
+                    expression,
+                    file: self.file.to_string(),
+                    line: line(node),
+                    ..Default::default()
+                };
+                for choice in descendants(node, "case_choice") {
+                    if descendants(choice, "others_choice").is_empty() {
+                        case.choices.push(normalize(text(choice, self.source)));
+                    } else {
+                        case.has_others = true;
+                    }
+                }
+                let mut cursor = node.walk();
+                for alternative in node.children(&mut cursor) {
+                    if alternative.kind() != "case_alternative" {
+                        continue;
+                    }
+                    let mut arm = CaseArm {
+                        line: line(alternative),
+                        branch: format!("case@{}", alternative.start_byte()),
+                        ..Default::default()
+                    };
+                    let mut choices = alternative.walk();
+                    for choice in alternative.children(&mut choices) {
+                        if choice.kind() != "case_choice" {
+                            continue;
+                        }
+                        if descendants(choice, "others_choice").is_empty() {
+                            arm.choices.push(normalize(text(choice, self.source)));
+                        } else {
+                            arm.choices.push("others".to_string());
+                        }
+                    }
+                    case.arms.push(arm);
+                }
+                case.is_complete = case.has_others;
+                scan.cases.push(case);
+            }
+            "case_alternative" | "loop_statement" => {
+                let kind = if node.kind() == "loop_statement" {
+                    "loop"
+                } else {
+                    "case"
+                };
+                let path = format!("{}/{}@{}", branch, kind, node.start_byte());
+                let mut cursor = node.walk();
+                for child in node.children(&mut cursor) {
+                    self.statement(child, &path, clocked, proc, scan);
+                }
+                return;
+            }
+            "procedure_call_statement" => {
+                let content = text(node, self.source);
+                let name = content.split('(').next().unwrap_or("").trim();
+                let name = name.trim_end_matches(';').trim();
+                if !name.is_empty() {
+                    proc.procedure_calls.push(ProcedureCall {
+                        name: name.to_string(),
+                        full_name: name.to_string(),
+                        line: line(node),
+                        in_process: proc.label.clone(),
+                    });
+                }
+                for read in self.reads(node, None, &scan.locals) {
+                    push_unique(&mut scan.reads, &read);
+                }
+                return;
+            }
+            "assignment_statement" => {
+                for read in self.reads(node, None, &scan.locals) {
+                    push_unique(&mut scan.reads, &read);
+                }
+                return;
+            }
+            "wait_statement" => {
+                scan.waits += 1;
+                let (on_signals, until_expr, for_expr) = wait_clauses(text(node, self.source));
+                proc.wait_statements.push(WaitStatement {
+                    on_signals,
+                    until_expr,
+                    for_expr,
+                    line: line(node),
+                    in_process: proc.label.clone(),
+                });
+                for read in self.reads(node, None, &scan.locals) {
+                    push_unique(&mut scan.reads, &read);
+                }
+                return;
+            }
+            "assert_statement" | "report_statement" => {
+                if let Some(assertion) = self.assertion(node) {
+                    scan.assertions.push(assertion);
+                }
+                return;
+            }
+            "variable_declaration" | "sensitivity_list" => return,
+            _ => {}
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.statement(child, branch, clocked, proc, scan);
+        }
+    }
+
+    /// A plain `assert` or `report`; PSL assertions yield `None`.
+    fn assertion(&self, node: Node) -> Option<Assertion> {
+        let mut cursor = node.walk();
+        if node
+            .children(&mut cursor)
+            .any(|child| child.kind().starts_with("psl_"))
+        {
+            return None;
+        }
+        Some(Assertion {
+            kind: node.kind().trim_end_matches("_statement").to_string(),
+            severity: severity_clause(text(node, self.source)),
+            file: self.file.to_string(),
+            line: line(node),
+            ..Default::default()
+        })
+    }
+
+    fn concurrent_assignment(&self, node: Node, arch: &str, input: &mut Input) {
+        let content = text(node, self.source);
+        let lower = content.to_ascii_lowercase();
+        let selected = lower.trim_start().starts_with("with ") && lower.contains(" select ");
+        let kind = if selected {
+            "selected"
+        } else if lower.contains(" when ") && lower.contains(" else ") {
+            "conditional"
+        } else {
+            "simple"
+        };
+        let target = node.child_by_field_name("target");
+        let target_full = target
+            .map(|t| normalize(text(t, self.source)))
+            .unwrap_or_default();
+        let target_name = base_name(&target_full);
+        let reads: Vec<String> = self
+            .reads(node, target, &HashSet::new())
+            .into_iter()
+            .filter(|read| !read.eq_ignore_ascii_case(&target_name))
+            .collect();
+        for read in &reads {
+            input.signal_deps.push(SignalDep {
+                source: read.clone(),
+                target: target_name.clone(),
+                file: self.file.to_string(),
+                line: line(node),
+                is_sequential: false,
+                in_process: String::new(),
+                in_arch: arch.to_string(),
+            });
+        }
+        input.concurrent_assignments.push(ConcurrentAssignment {
+            target: target_name,
+            target_full,
+            read_signals: reads,
+            value: if kind == "simple" {
+                assignment_value(content)
+            } else {
+                String::new()
+            },
+            file: self.file.to_string(),
+            line: line(node),
+            in_arch: arch.to_string(),
+            kind: kind.to_string(),
+            ..Default::default()
+        });
+        self.tristate(node, arch, "", input);
+    }
+
+    /// Records `node` in `tristate_assignments` when its value drives 'Z'.
+    fn tristate(&self, node: Node, arch: &str, process: &str, input: &mut Input) {
+        let content = text(node, self.source);
+        let Some((_, value)) = content.split_once("<=") else {
+            return;
+        };
+        let value = value.trim().trim_end_matches(';').trim();
+        if !is_tristate_value(value) {
+            return;
+        }
+        input.tristate_assignments.push(TristateAssignment {
+            target: node
+                .child_by_field_name("target")
+                .map(|t| base_name(text(t, self.source)))
+                .unwrap_or_default(),
+            value: value.to_string(),
+            file: self.file.to_string(),
+            line: line(node),
+            in_arch: arch.to_string(),
+            in_process: process.to_string(),
+        });
+    }
+}
+
+/// Whether `value` contains a 'Z' character literal or a string of Zs.
+fn is_tristate_value(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    bytes
+        .windows(3)
+        .any(|w| w[0] == b'\'' && w[1].eq_ignore_ascii_case(&b'z') && w[2] == b'\'')
+        || value
+            .split('"')
+            .skip(1)
+            .step_by(2)
+            .any(|s| !s.is_empty() && s.bytes().all(|b| b.eq_ignore_ascii_case(&b'z')))
+}
+
+/// Names a declaration introduces (the identifiers before its `:`).
+fn declared_names(node: Node, source: &[u8]) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if text(child, source) == ":" {
+            break;
+        }
+        if child.kind() == "identifier" {
+            names.push(text(child, source).to_string());
+        }
+    }
+    names
+}
+
+/// Whether the source between two `if` children holds an `else` keyword,
+/// which starts a branch without a condition node.
+fn has_else_keyword(gap: &[u8]) -> bool {
+    String::from_utf8_lossy(gap).lines().any(|line| {
+        line.split("--")
+            .next()
+            .unwrap_or("")
+            .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .any(|word| word.eq_ignore_ascii_case("else"))
+    })
+}
+
+fn push_unique(list: &mut Vec<String>, name: &str) {
+    if !list.iter().any(|n| n.eq_ignore_ascii_case(name)) {
+        list.push(name.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn else_keyword_ignores_comments_and_elsif() {
+        assert!(has_else_keyword(b"\n        else\n"));
+        assert!(!has_else_keyword(b" -- else branch\n"));
+        assert!(!has_else_keyword(b" elsif "));
+    }
+
+    #[test]
+    fn tristate_values_need_a_z_literal() {
+        assert!(is_tristate_value("'Z'"));
+        assert!(is_tristate_value("d when oe = '1' else (others => 'z')"));
+        assert!(is_tristate_value("\"ZZZZ\""));
+        assert!(!is_tristate_value("z_reg"));
+        assert!(!is_tristate_value("\"Z0\" & 'Y'"));
+    }
+}
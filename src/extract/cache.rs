@@ -0,0 +1,355 @@
+//! On-disk cache of per-file extraction results for `--lint`.
+//!
+//! Each source file gets one JSON entry under the cache directory holding the
+//! tables its two extraction passes produced. Declaration tables are reused
+//! while the file's content hash is unchanged; behavior tables also depend on
+//! the project-wide set of declared signal and port names, so they are reused
+//! only while that set hashes the same too.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::policy::input::{
+    Architecture, Assertion, CaseStatement, Component, ConcurrentAssignment, Dependency, Entity,
+    Input, Instance, LanguageFeature, LibraryClause, ModeView, Package, Port, Process, Signal,
+    SignalDep, TristateAssignment, UseClause, Waiver,
+};
+
+/// Bump whenever extraction output changes shape or content, so entries
+/// written by an older extractor are ignored.
+const CACHE_VERSION: u32 = 8;
+
+/// Default cache location, relative to the working directory.
+pub const DEFAULT_DIR: &str = ".vhdl_lint_cache/extract";
+
+/// Tables produced by the declaration pass (`units`) for one file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UnitTables {
+    #[serde(default)]
+    pub entities: Vec<Entity>,
+    #[serde(default)]
+    pub architectures: Vec<Architecture>,
+    #[serde(default)]
+    pub packages: Vec<Package>,
+    #[serde(default)]
+    pub components: Vec<Component>,
+    #[serde(default)]
+    pub use_clauses: Vec<UseClause>,
+    #[serde(default)]
+    pub library_clauses: Vec<LibraryClause>,
+    #[serde(default)]
+    pub signals: Vec<Signal>,
+    #[serde(default)]
+    pub ports: Vec<Port>,
+    #[serde(default)]
+    pub dependencies: Vec<Dependency>,
+    #[serde(default)]
+    pub instances: Vec<Instance>,
+    #[serde(default)]
+    pub mode_views: Vec<ModeView>,
+    #[serde(default)]
+    pub waivers: Vec<Waiver>,
+    #[serde(default)]
+    pub language_features: Vec<LanguageFeature>,
+}
+
+impl UnitTables {
+    pub(super) fn take(input: Input) -> Self {
+        UnitTables {
+            entities: input.entities,
+            architectures: input.architectures,
+            packages: input.packages,
+            components: input.components,
+            use_clauses: input.use_clauses,
+            library_clauses: input.library_clauses,
+            signals: input.signals,
+            ports: input.ports,
+            dependencies: input.dependencies,
+            instances: input.instances,
+            mode_views: input.mode_views,
+            waivers: input.waivers,
+            language_features: input.language_features,
+        }
+    }
+
+    pub(super) fn append_to(self, input: &mut Input) {
+        input.entities.extend(self.entities);
+        input.architectures.extend(self.architectures);
+        input.packages.extend(self.packages);
+        input.components.extend(self.components);
+        input.use_clauses.extend(self.use_clauses);
+        input.library_clauses.extend(self.library_clauses);
+        input.signals.extend(self.signals);
+        input.ports.extend(self.ports);
+        input.dependencies.extend(self.dependencies);
+        input.instances.extend(self.instances);
+        input.mode_views.extend(self.mode_views);
+        input.waivers.extend(self.waivers);
+        input.language_features.extend(self.language_features);
+    }
+}
+
+/// Tables produced by the behavior pass for one file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BehaviorTables {
+    #[serde(default)]
+    pub assertions: Vec<Assertion>,
+    #[serde(default)]
+    pub case_statements: Vec<CaseStatement>,
+    #[serde(default)]
+    pub concurrent_assignments: Vec<ConcurrentAssignment>,
+    #[serde(default)]
+    pub processes: Vec<Process>,
+    #[serde(default)]
+    pub signal_deps: Vec<SignalDep>,
+    #[serde(default)]
+    pub tristate_assignments: Vec<TristateAssignment>,
+}
+
+impl BehaviorTables {
+    pub(super) fn take(input: Input) -> Self {
+        BehaviorTables {
+            assertions: input.assertions,
+            case_statements: input.case_statements,
+            concurrent_assignments: input.concurrent_assignments,
+            processes: input.processes,
+            signal_deps: input.signal_deps,
+            tristate_assignments: input.tristate_assignments,
+        }
+    }
+
+    pub(super) fn append_to(self, input: &mut Input) {
+        input.assertions.extend(self.assertions);
+        input.case_statements.extend(self.case_statements);
+        input
+            .concurrent_assignments
+            .extend(self.concurrent_assignments);
+        input.processes.extend(self.processes);
+        input.signal_deps.extend(self.signal_deps);
+        input.tristate_assignments.extend(self.tristate_assignments);
+    }
+}
+
+/// Cached extraction of one source file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Entry {
+    pub version: u32,
+    pub extractor: String,
+    pub path: String,
+    pub content_hash: String,
+    pub declared_hash: String,
+    pub units: UnitTables,
+    pub behavior: BehaviorTables,
+}
+
+impl Entry {
+    pub fn new(
+        path: &str,
+        content_hash: String,
+        declared_hash: String,
+        units: UnitTables,
+        behavior: BehaviorTables,
+    ) -> Self {
+        Entry {
+            version: CACHE_VERSION,
+            extractor: env!("CARGO_PKG_VERSION").to_string(),
+            path: path.to_string(),
+            content_hash,
+            declared_hash,
+            units,
+            behavior,
+        }
+    }
+}
+
+/// How a cached build went: files served from the cache, files (re)parsed,
+/// and cache writes that failed (the build itself still succeeded).
+#[derive(Debug, Default)]
+pub struct CacheStats {
+    pub reused: usize,
+    pub extracted: usize,
+    pub errors: Vec<String>,
+}
+
+/// Directory of per-file cache entries.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    /// The entry for `path`, if one exists and was written by this extractor.
+    /// Unreadable or stale entries count as misses.
+    pub fn load(&self, path: &str) -> Option<Entry> {
+        let text = fs::read_to_string(self.entry_path(path)).ok()?;
+        let entry: Entry = serde_json::from_str(&text).ok()?;
+        (entry.version == CACHE_VERSION
+            && entry.extractor == env!("CARGO_PKG_VERSION")
+            && entry.path == path)
+            .then_some(entry)
+    }
+
+    /// Writes `entry` atomically (temp file, then rename).
+    pub fn store(&self, entry: &Entry) -> Result<(), String> {
+        let target = self.entry_path(&entry.path);
+        let fail = |e: std::io::Error| format!("writing '{}': {}", target.display(), e);
+        fs::create_dir_all(&self.dir).map_err(fail)?;
+        let json = serde_json::to_string(entry).map_err(|e| e.to_string())?;
+        let tmp = target.with_extension("json.tmp");
+        fs::write(&tmp, json).map_err(fail)?;
+        fs::rename(&tmp, &target).map_err(fail)
+    }
+
+    fn entry_path(&self, path: &str) -> PathBuf {
+        self.dir
+            .join(format!("{:016x}.json", fnv1a(path.as_bytes())))
+    }
+}
+
+/// Content key for a source file's text.
+pub fn content_hash(text: &str) -> String {
+    format!("{:016x}-{:x}", fnv1a(text.as_bytes()), text.len())
+}
+
+/// Order-independent key for the set of declared names the behavior pass
+/// consults.
+pub fn declared_hash(declared: &HashSet<String>) -> String {
+    let mut names: Vec<&str> = declared.iter().map(String::as_str).collect();
+    names.sort_unstable();
+    format!(
+        "{:016x}-{:x}",
+        fnv1a(names.join("\n").as_bytes()),
+        names.len()
+    )
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for &byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extract::{build_input_cached, declared_names, SourceFile};
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "vhdl_extract_cache_{}_{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn units(entity: &str, file: &str) -> UnitTables {
+        UnitTables {
+            entities: vec![Entity {
+                name: entity.to_string(),
+                file: file.to_string(),
+                line: 1,
+                ..Default::default()
+            }],
+            signals: vec![Signal {
+                name: format!("{}_q", entity),
+                file: file.to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn behavior(label: &str, file: &str) -> BehaviorTables {
+        BehaviorTables {
+            processes: vec![Process {
+                label: label.to_string(),
+                file: file.to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn entries_round_trip_and_key_on_path() {
+        let dir = scratch_dir("round_trip");
+        let cache = Cache::new(&dir);
+        let entry = Entry::new(
+            "rtl/a.vhd",
+            content_hash("entity a is end;"),
+            String::new(),
+            units("a", "rtl/a.vhd"),
+            behavior("p", "rtl/a.vhd"),
+        );
+        cache.store(&entry).unwrap();
+
+        let loaded = cache.load("rtl/a.vhd").unwrap();
+        assert_eq!(loaded.content_hash, entry.content_hash);
+        assert_eq!(loaded.units.entities[0].name, "a");
+        assert_eq!(loaded.behavior.processes[0].label, "p");
+        assert!(cache.load("rtl/b.vhd").is_none());
+
+        let mut stale = entry.clone();
+        stale.version = CACHE_VERSION + 1;
+        cache.store(&stale).unwrap();
+        assert!(cache.load("rtl/a.vhd").is_none());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn hashes_are_stable_and_order_independent() {
+        assert_eq!(content_hash("abc"), content_hash("abc"));
+        assert_ne!(content_hash("abc"), content_hash("abd"));
+        let a: HashSet<String> = ["x", "y"].iter().map(|s| s.to_string()).collect();
+        let b: HashSet<String> = ["y", "x"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(declared_hash(&a), declared_hash(&b));
+    }
+
+    #[test]
+    fn unchanged_files_are_served_from_the_cache() {
+        let dir = scratch_dir("hits");
+        let cache = Cache::new(&dir);
+        let files = vec![
+            SourceFile {
+                path: "a.vhd".to_string(),
+                text: "-- a".to_string(),
+            },
+            SourceFile {
+                path: "b.vhd".to_string(),
+                text: "-- b".to_string(),
+            },
+        ];
+        let tables = [units("a", "a.vhd"), units("b", "b.vhd")];
+        let declared = declared_names(&tables);
+        for (file, units) in files.iter().zip(tables) {
+            let entry = Entry::new(
+                &file.path,
+                content_hash(&file.text),
+                declared_hash(&declared),
+                units,
+                behavior(&format!("{}_proc", file.path), &file.path),
+            );
+            cache.store(&entry).unwrap();
+        }
+
+        let (input, stats) = build_input_cached(&files, &cache).unwrap();
+        assert_eq!((stats.reused, stats.extracted), (2, 0));
+        assert_eq!(input.file_count, 2);
+        let entities: Vec<&str> = input.entities.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(entities, ["a", "b"]);
+        let processes: Vec<&str> = input.processes.iter().map(|p| p.label.as_str()).collect();
+        assert_eq!(processes, ["a.vhd_proc", "b.vhd_proc"]);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
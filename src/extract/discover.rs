@@ -0,0 +1,71 @@
+//! Finding the VHDL sources of a project on disk, shared by the command-line
+//! linter, the language server and project manifests.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Every VHDL file below `dir`, in path order. Hidden directories (`.git`,
+/// the extraction cache, ...) are not entered; a `dir` that isn't a
+/// directory has no files.
+pub fn vhdl_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut found = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        if !dir.is_dir() {
+            continue;
+        }
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            let hidden = path
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+            if path.is_dir() {
+                if !hidden {
+                    pending.push(path);
+                }
+            } else if is_vhdl_file(&path) {
+                found.push(path);
+            }
+        }
+    }
+    found.sort();
+    Ok(found)
+}
+
+/// Whether `path` has a `.vhd` or `.vhdl` extension, in any case.
+pub fn is_vhdl_file(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| {
+        matches!(
+            ext.to_string_lossy().to_ascii_lowercase().as_str(),
+            "vhd" | "vhdl"
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_vhdl_files_outside_hidden_directories() {
+        let root = std::env::temp_dir().join(format!("vhdl_discover_{}", std::process::id()));
+        for file in [
+            "top.vhd",
+            "rtl/core.VHDL",
+            "rtl/notes.txt",
+            ".git/stale.vhd",
+            "rtl/.cache/old.vhd",
+        ] {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, "").unwrap();
+        }
+        let found = vhdl_files(&root).unwrap();
+        let missing = vhdl_files(&root.join("missing")).unwrap();
+        let _ = fs::remove_dir_all(&root);
+
+        assert_eq!(found, [root.join("rtl/core.VHDL"), root.join("top.vhd")]);
+        assert!(missing.is_empty());
+    }
+}
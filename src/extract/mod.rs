@@ -0,0 +1,565 @@
+//! Builds a policy `Input` straight from tree-sitter parse trees, so the crate
+//! can lint VHDL without the Go extractor.
+//!
+//! This covers the design-unit tables most rules read: entities, ports,
+//! architectures, packages, signals, components and instances, use/library
+//! clauses, processes, case statements, concurrent assignments, assertions and
+//! signal dependencies. Tables it leaves empty (types, functions, symbol tables,
+//! verification data, ...) simply keep the rules that need them quiet.
+
+mod behavior;
+pub mod cache;
+pub mod discover;
+mod names;
+mod units;
+
+use std::collections::{BTreeMap, HashSet};
+
+use tree_sitter::{Parser, Tree};
+
+use crate::parser::new_parser;
+use crate::policy::input::{FileInfo, Input};
+use crate::policy::third_party;
+use crate::policy::waivers;
+
+use cache::{BehaviorTables, Cache, CacheStats, Entry, UnitTables};
+
+/// One VHDL source file to extract.
+pub struct SourceFile {
+    pub path: String,
+    pub text: String,
+}
+
+/// Parses every file and returns the combined policy input. Files are
+/// extracted in two passes: declarations first, so behavior extraction can
+/// tell signal reads from calls, types and constants across files.
+pub fn build_input(files: &[SourceFile]) -> Result<Input, String> {
+    let mut parser = new_parser()?;
+    let mut trees = Vec::with_capacity(files.len());
+    for file in files {
+        trees.push(parse(&mut parser, file)?);
+    }
+    let parsed: Vec<(&SourceFile, &Tree)> = files.iter().zip(&trees).collect();
+    Ok(assemble(&parsed))
+}
+
+/// `build_input` backed by `cache`: files whose content (and, for behavior
+/// tables, the project's declared names) are unchanged are taken from their
+/// cache entries without parsing; the rest are parsed, extracted and written
+/// back. The merged `Input` is the same `build_input` would produce.
+pub fn build_input_cached(
+    files: &[SourceFile],
+    cache: &Cache,
+) -> Result<(Input, CacheStats), String> {
+    let mut parser: Option<Parser> = None;
+    let mut stats = CacheStats::default();
+    let mut hashes = Vec::with_capacity(files.len());
+    let mut cached = Vec::with_capacity(files.len());
+    let mut trees: Vec<Option<Tree>> = Vec::with_capacity(files.len());
+    let mut unit_tables = Vec::with_capacity(files.len());
+    for file in files {
+        let hash = cache::content_hash(&file.text);
+        match cache.load(&file.path).filter(|e| e.content_hash == hash) {
+            Some(entry) => {
+                unit_tables.push(entry.units);
+                cached.push(Some((entry.declared_hash, entry.behavior)));
+                trees.push(None);
+            }
+            None => {
+                let tree = parse(cached_parser(&mut parser)?, file)?;
+                unit_tables.push(extract_units(file, &tree));
+                cached.push(None);
+                trees.push(Some(tree));
+            }
+        }
+        hashes.push(hash);
+    }
+
+    let declared = declared_names(&unit_tables);
+    let declared_hash = cache::declared_hash(&declared);
+    let mut behavior_tables = Vec::with_capacity(files.len());
+    for (i, file) in files.iter().enumerate() {
+        match cached[i].take() {
+            Some((hash, behavior)) if hash == declared_hash => {
+                stats.reused += 1;
+                behavior_tables.push(behavior);
+                continue;
+            }
+            _ => {}
+        }
+        let tree = match trees[i].take() {
+            Some(tree) => tree,
+            None => parse(cached_parser(&mut parser)?, file)?,
+        };
+        let behavior = extract_behavior(file, &tree, &declared);
+        let entry = Entry::new(
+            &file.path,
+            hashes[i].clone(),
+            declared_hash.clone(),
+            unit_tables[i].clone(),
+            behavior.clone(),
+        );
+        if let Err(e) = cache.store(&entry) {
+            stats.errors.push(e);
+        }
+        stats.extracted += 1;
+        behavior_tables.push(behavior);
+    }
+    Ok((combine(files, unit_tables, behavior_tables), stats))
+}
+
+/// Parsed sources kept between builds for long-lived callers (watch mode,
+/// the language server): only files whose text changed are re-parsed and
+/// re-extracted, and the rest only when the project's declared names change.
+/// `update` diffs each re-extracted file against its previous tables, so
+/// callers can skip re-linting after edits that leave them as they were
+/// (e.g. inside a comment).
+pub struct Workspace {
+    parser: Parser,
+    files: BTreeMap<String, Parsed>,
+}
+
+/// A workspace file with its parse tree and the tables extracted from it.
+struct Parsed {
+    file: SourceFile,
+    tree: Tree,
+    units: UnitTables,
+    /// Behavior tables, with the `cache::declared_hash` of the names they
+    /// were extracted against.
+    behavior: (String, BehaviorTables),
+}
+
+impl Parsed {
+    /// Whether both extract to the same tables and third-party flag.
+    fn same_facts(&self, other: &Parsed) -> bool {
+        let facts = |parsed: &Parsed| {
+            let vendor = third_party::is_vendor_file(&parsed.file.path, &parsed.file.text);
+            serde_json::to_value((&parsed.units, &parsed.behavior, vendor))
+        };
+        matches!((facts(self), facts(other)), (Ok(a), Ok(b)) if a == b)
+    }
+}
+
+impl Workspace {
+    pub fn new() -> Result<Self, String> {
+        Ok(Workspace {
+            parser: new_parser()?,
+            files: BTreeMap::new(),
+        })
+    }
+
+    /// Adds or replaces a file. Returns whether its extracted facts changed:
+    /// false when the text is unchanged (nothing is re-parsed) or extracts
+    /// to the same tables as before.
+    pub fn update(&mut self, path: &str, text: String) -> Result<bool, String> {
+        if self
+            .files
+            .get(path)
+            .is_some_and(|parsed| parsed.file.text == text)
+        {
+            return Ok(false);
+        }
+        let file = SourceFile {
+            path: path.to_string(),
+            text,
+        };
+        let tree = parse(&mut self.parser, &file)?;
+        let units = extract_units(&file, &tree);
+        let previous = self.files.remove(path);
+        let others = self.files.values().map(|parsed| &parsed.units);
+        let declared = declared_names(others.chain([&units]));
+        let behavior = extract_behavior(&file, &tree, &declared);
+        let parsed = Parsed {
+            file,
+            tree,
+            units,
+            behavior: (cache::declared_hash(&declared), behavior),
+        };
+        let changed = previous.is_none_or(|previous| !previous.same_facts(&parsed));
+        self.files.insert(path.to_string(), parsed);
+        Ok(changed)
+    }
+
+    /// Drops a file; returns whether it was present.
+    pub fn remove(&mut self, path: &str) -> bool {
+        self.files.remove(path).is_some()
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.files.keys().map(String::as_str)
+    }
+
+    /// The text a file was last parsed from.
+    pub fn text(&self, path: &str) -> Option<&str> {
+        self.files.get(path).map(|parsed| parsed.file.text.as_str())
+    }
+
+    /// The combined policy input. Behavior tables extracted against other
+    /// declared names than the current ones are re-extracted first.
+    pub fn input(&mut self) -> Input {
+        let declared = declared_names(self.files.values().map(|parsed| &parsed.units));
+        let declared_hash = cache::declared_hash(&declared);
+        for parsed in self.files.values_mut() {
+            if parsed.behavior.0 != declared_hash {
+                let behavior = extract_behavior(&parsed.file, &parsed.tree, &declared);
+                parsed.behavior = (declared_hash.clone(), behavior);
+            }
+        }
+        let files: Vec<&SourceFile> = self.files.values().map(|parsed| &parsed.file).collect();
+        let units = self.files.values().map(|parsed| parsed.units.clone());
+        let behavior = self.files.values().map(|parsed| parsed.behavior.1.clone());
+        combine(&files, units.collect(), behavior.collect())
+    }
+}
+
+fn cached_parser(parser: &mut Option<Parser>) -> Result<&mut Parser, String> {
+    if parser.is_none() {
+        *parser = Some(new_parser()?);
+    }
+    Ok(parser.as_mut().expect("parser initialized above"))
+}
+
+fn parse(parser: &mut Parser, file: &SourceFile) -> Result<Tree, String> {
+    parser
+        .parse(&file.text, None)
+        .ok_or_else(|| format!("failed to parse '{}'", file.path))
+}
+
+fn assemble(files: &[(&SourceFile, &Tree)]) -> Input {
+    let units: Vec<UnitTables> = files
+        .iter()
+        .map(|(file, tree)| extract_units(file, tree))
+        .collect();
+    let declared = declared_names(&units);
+    let behavior = files
+        .iter()
+        .map(|(file, tree)| extract_behavior(file, tree, &declared))
+        .collect();
+    let sources: Vec<&SourceFile> = files.iter().map(|(file, _)| *file).collect();
+    combine(&sources, units, behavior)
+}
+
+/// Merges per-file tables in file order: every file's declaration tables,
+/// then every file's behavior tables.
+fn combine<F: std::borrow::Borrow<SourceFile>>(
+    files: &[F],
+    units: Vec<UnitTables>,
+    behavior: Vec<BehaviorTables>,
+) -> Input {
+    let mut input = Input {
+        standard: "2008".to_string(),
+        file_count: files.len(),
+        ..Default::default()
+    };
+    for file in files {
+        let file = file.borrow();
+        input.files.push(FileInfo {
+            path: file.path.clone(),
+            library: "work".to_string(),
+            is_third_party: third_party::is_vendor_file(&file.path, &file.text),
+            ..Default::default()
+        });
+    }
+    for tables in units {
+        tables.append_to(&mut input);
+    }
+    for tables in behavior {
+        tables.append_to(&mut input);
+    }
+    input
+}
+
+fn extract_units(file: &SourceFile, tree: &Tree) -> UnitTables {
+    let mut input = Input::default();
+    let mut ctx = units::Context::new(&file.path, file.text.as_bytes());
+    ctx.walk(tree.root_node(), &mut input);
+    input.waivers = waivers::scan(&file.text, &file.path);
+    units::language_features(tree.root_node(), &file.path, &mut input.language_features);
+    UnitTables::take(input)
+}
+
+fn extract_behavior(file: &SourceFile, tree: &Tree, declared: &HashSet<String>) -> BehaviorTables {
+    let mut input = Input::default();
+    let mut ctx = behavior::Context::new(&file.path, file.text.as_bytes(), declared);
+    ctx.walk(tree.root_node(), "", &mut input);
+    BehaviorTables::take(input)
+}
+
+/// Lower-cased signal and port names across all files; the behavior pass
+/// uses them to tell signal reads from calls, types and constants.
+fn declared_names<'a>(units: impl IntoIterator<Item = &'a UnitTables>) -> HashSet<String> {
+    units
+        .into_iter()
+        .flat_map(|tables| {
+            tables
+                .signals
+                .iter()
+                .map(|s| s.name.to_ascii_lowercase())
+                .chain(tables.ports.iter().map(|p| p.name.to_ascii_lowercase()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(files: &[(&str, &str)]) -> Input {
+        let files: Vec<SourceFile> = files
+            .iter()
+            .map(|(path, text)| SourceFile {
+                path: path.to_string(),
+                text: text.to_string(),
+            })
+            .collect();
+        build_input(&files).unwrap()
+    }
+
+    #[test]
+    fn kitchen_sink_builds_design_unit_tables() {
+        let input = build(&[("test.vhdl", include_str!("../../testdata/vhdl/test.vhdl"))]);
+
+        let entities: Vec<&str> = input.entities.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(entities, ["kitchen_sink", "kitchen_sink_tb"]);
+        let archs: Vec<(&str, &str)> = input
+            .architectures
+            .iter()
+            .map(|a| (a.name.as_str(), a.entity_name.as_str()))
+            .collect();
+        assert_eq!(archs, [("rtl", "kitchen_sink"), ("sim", "kitchen_sink_tb")]);
+
+        let ports: Vec<(&str, &str)> = input.entities[0]
+            .ports
+            .iter()
+            .map(|p| (p.name.as_str(), p.direction.as_str()))
+            .collect();
+        assert_eq!(
+            ports,
+            [
+                ("clk", "in"),
+                ("rst_n", "in"),
+                ("data_in", "in"),
+                ("valid_in", "in"),
+                ("ready_in", "out"),
+                ("data_out", "out"),
+                ("valid_out", "out"),
+                ("ready_out", "in"),
+                ("bidir", "inout"),
+                ("status", "buffer"),
+            ]
+        );
+        let clk = &input.entities[0].ports[0];
+        assert_eq!(
+            (clk.r#type.as_str(), clk.width, clk.line),
+            ("std_logic", 1, 197)
+        );
+        assert_eq!(clk.in_entity, "kitchen_sink");
+        assert!(input.entities[1].ports.is_empty());
+        assert_eq!(input.ports.len(), 10);
+
+        let signals = |arch: &str| -> Vec<&str> {
+            input
+                .signals
+                .iter()
+                .filter(|s| s.in_entity == arch)
+                .map(|s| s.name.as_str())
+                .collect()
+        };
+        assert_eq!(
+            signals("rtl"),
+            [
+                "state",
+                "next_state",
+                "counter",
+                "data_reg",
+                "fifo_mem",
+                "wr_ptr",
+                "rd_ptr",
+                "enable",
+                "stage_data",
+                "local_sig",
+            ]
+        );
+        assert_eq!(
+            signals("sim"),
+            [
+                "clk",
+                "rst_n",
+                "data_in",
+                "data_out",
+                "valid_in",
+                "valid_out",
+                "ready_in",
+                "ready_out",
+            ]
+        );
+        let counter = input.signals.iter().find(|s| s.name == "counter").unwrap();
+        assert_eq!((counter.width, counter.line), (8, 238));
+        let enable = input.signals.iter().find(|s| s.name == "enable").unwrap();
+        assert_eq!(enable.default, "'0'");
+
+        let instances: Vec<(&str, &str, &str, &str)> = input
+            .instances
+            .iter()
+            .map(|i| {
+                (
+                    i.name.as_str(),
+                    i.target.as_str(),
+                    i.target_arch.as_str(),
+                    i.in_arch.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            instances,
+            [
+                ("adder_inst1", "adder", "", "rtl"),
+                ("adder_inst2", "adder", "", "rtl"),
+                ("counter_inst", "work.counter", "rtl", "rtl"),
+                ("dut", "work.kitchen_sink", "rtl", "sim"),
+            ]
+        );
+        let positional = &input.instances[0];
+        assert!(positional.port_map.is_empty());
+        let actuals: Vec<&str> = positional
+            .associations
+            .iter()
+            .filter(|a| a.is_positional)
+            .map(|a| a.actual.as_str())
+            .collect();
+        assert_eq!(actuals, ["a", "b", "sum"]);
+        let dut = &input.instances[3];
+        assert_eq!(dut.line, 867);
+        assert_eq!(dut.port_map.len(), 10);
+        assert_eq!(dut.port_map["rst_n"], "rst_n");
+        assert_eq!(dut.generic_map["DEPTH"], "16");
+        let bidir = dut
+            .associations
+            .iter()
+            .find(|a| a.formal == "bidir")
+            .unwrap();
+        assert_eq!(bidir.actual_kind, "open");
+        assert!(input
+            .dependencies
+            .iter()
+            .any(|d| d.kind == "instantiation" && d.target == "work.kitchen_sink"));
+    }
+
+    #[test]
+    fn kitchen_sink_builds_process_tables() {
+        let input = build(&[("test.vhdl", include_str!("../../testdata/vhdl/test.vhdl"))]);
+        let process = |label: &str| {
+            input
+                .processes
+                .iter()
+                .find(|p| p.label == label)
+                .unwrap_or_else(|| panic!("no process '{}'", label))
+        };
+
+        let sim: Vec<&str> = input
+            .processes
+            .iter()
+            .filter(|p| p.in_arch == "sim")
+            .map(|p| p.label.as_str())
+            .collect();
+        assert_eq!(sim, ["stim_proc", "monitor_proc"]);
+
+        let seq = process("seq_proc");
+        assert_eq!((seq.in_arch.as_str(), seq.line), ("rtl", 451));
+        assert_eq!(seq.sensitivity_list, ["clk", "rst_n"]);
+        assert!(seq.is_sequential && !seq.is_combinational);
+        assert_eq!(
+            (seq.clock_signal.as_str(), seq.clock_edge.as_str()),
+            ("clk", "rising")
+        );
+        assert!(seq.has_reset && seq.reset_async);
+        assert_eq!(seq.reset_signal, "rst_n");
+        for signal in ["state", "counter"] {
+            assert!(seq.assigned_signals.iter().any(|s| s == signal));
+        }
+        assert!(seq.read_signals.iter().any(|s| s == "enable"));
+
+        let comb = process("comb_proc2");
+        assert_eq!(comb.sensitivity_list, ["all"]);
+        assert!(comb.is_combinational);
+        assert!(process("monitor_proc").is_sequential);
+        // The stimulus process waits instead of having a sensitivity list.
+        let stim = process("stim_proc");
+        assert!(stim.sensitivity_list.is_empty() && !stim.is_combinational);
+    }
+
+    #[test]
+    fn small_sources_extract_components_and_process_variables() {
+        let input = build(&[
+            (
+                "arch_test.vhdl",
+                include_str!("../../testdata/vhdl/arch_test.vhdl"),
+            ),
+            (
+                "process_all_test.vhdl",
+                include_str!("../../testdata/vhdl/process_all_test.vhdl"),
+            ),
+        ]);
+        assert_eq!(input.file_count, 2);
+        assert!(input.entities.is_empty());
+
+        let adder = input
+            .components
+            .iter()
+            .find(|c| c.name == "adder" && !c.is_instance)
+            .unwrap();
+        let ports: Vec<(&str, &str, usize)> = adder
+            .ports
+            .iter()
+            .map(|p| (p.name.as_str(), p.direction.as_str(), p.width))
+            .collect();
+        assert_eq!(ports, [("a", "in", 8), ("b", "in", 8), ("sum", "out", 9)]);
+
+        let signals: Vec<&str> = input.signals.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(
+            signals,
+            [
+                "state",
+                "next_state",
+                "counter",
+                "data_reg",
+                "wr_ptr",
+                "rd_ptr",
+                "enable"
+            ]
+        );
+        assert!(input.signals.iter().all(|s| s.file == "arch_test.vhdl"));
+
+        let agg = &input.processes[0];
+        assert_eq!(input.processes.len(), 1);
+        assert_eq!(
+            (agg.label.as_str(), agg.in_arch.as_str()),
+            ("agg_proc", "rtl")
+        );
+        assert_eq!(agg.file, "process_all_test.vhdl");
+        assert_eq!(agg.sensitivity_list, ["all"]);
+        let variables: Vec<(&str, &str)> = agg
+            .variables
+            .iter()
+            .map(|v| (v.name.as_str(), v.r#type.as_str()))
+            .collect();
+        assert_eq!(variables, [("p", "point")]);
+    }
+
+    #[test]
+    fn workspace_updates_report_only_fact_changes() {
+        let text = "entity e is\n  port (a : in bit);\nend entity;\n";
+        let mut workspace = Workspace::new().unwrap();
+        assert!(workspace.update("e.vhd", text.to_string()).unwrap());
+        assert!(!workspace.update("e.vhd", text.to_string()).unwrap());
+
+        let commented = text.replace("bit);", "bit); -- input");
+        assert!(!workspace.update("e.vhd", commented.clone()).unwrap());
+        assert_eq!(workspace.text("e.vhd"), Some(commented.as_str()));
+
+        let renamed = text.replace("a :", "b :");
+        assert!(workspace.update("e.vhd", renamed).unwrap());
+        let ports = &workspace.input().entities[0].ports;
+        assert_eq!(ports[0].name, "b");
+    }
+}
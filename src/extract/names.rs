@@ -0,0 +1,330 @@
+use regex::Regex;
+use tree_sitter::Node;
+
+use crate::policy::elaboration::{self, Generics};
+
+/// Attributes whose prefix is not read at run time (`sig'length`).
+const STATIC_ATTRIBUTES: &[&str] = &[
+    "ascending",
+    "high",
+    "left",
+    "length",
+    "low",
+    "range",
+    "reverse_range",
+    "right",
+];
+
+/// Node kinds whose identifiers are never signal reads.
+const OPAQUE_KINDS: &[&str] = &["external_name", "physical_literal", "comment"];
+
+pub(super) fn text<'a>(node: Node, source: &'a [u8]) -> &'a str {
+    node.utf8_text(source).unwrap_or("")
+}
+
+pub(super) fn line(node: Node) -> usize {
+    node.start_position().row + 1
+}
+
+/// 1-based start and end (exclusive) columns of `name` when it starts on
+/// `line`, else `(0, 0)`.
+pub(super) fn columns(name: Option<Node>, line: usize) -> (usize, usize) {
+    match name {
+        Some(name) if name.start_position().row + 1 == line => (
+            name.start_position().column + 1,
+            name.end_position().column + 1,
+        ),
+        _ => (0, 0),
+    }
+}
+
+/// `--` comment closing the line `node` ends on, when only `;` or `)`
+/// separate the two: `ready : in std_logic; -- from sink` -> `from sink`.
+pub(super) fn trailing_comment(node: Node, source: &[u8]) -> String {
+    let rest = &source[node.end_byte().min(source.len())..];
+    let end = rest.iter().position(|&b| b == b'\n').unwrap_or(rest.len());
+    let rest = String::from_utf8_lossy(&rest[..end]);
+    let Some(start) = rest.find("--") else {
+        return String::new();
+    };
+    if rest[..start]
+        .chars()
+        .any(|c| !c.is_whitespace() && c != ';' && c != ')')
+    {
+        return String::new();
+    }
+    rest[start + 2..].trim().to_string()
+}
+
+/// Collapses runs of whitespace so multi-line types compare equal.
+pub(super) fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Identifiers of an `identifier_list` (or a lone identifier).
+pub(super) fn identifier_list(node: Node, source: &[u8]) -> Vec<String> {
+    identifier_nodes(node)
+        .into_iter()
+        .map(|name| text(name, source).to_string())
+        .collect()
+}
+
+/// The identifier nodes of `identifier_list`.
+pub(super) fn identifier_nodes(node: Node) -> Vec<Node> {
+    if node.kind() == "identifier" {
+        return vec![node];
+    }
+    let mut names = Vec::new();
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "identifier" => names.push(child),
+            "identifier_list" => names.extend(identifier_nodes(child)),
+            _ => {}
+        }
+    }
+    names
+}
+
+/// Root identifier of a name: `rec.field(3)` -> `rec`.
+pub(super) fn base_name(name: &str) -> String {
+    name.trim()
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .collect()
+}
+
+/// Bit width of `std_logic`-like scalars and vectors whose bounds are literal
+/// arithmetic (`7 downto 0`, `4*8-1 downto 0`); 0 when unknown. Bounds naming
+/// generics or constants are folded later, in `policy::elaboration`.
+pub(super) fn type_width(ty: &str) -> usize {
+    if let Some(width) = elaboration::range_width(ty, &Generics::new()) {
+        return width;
+    }
+    match ty.to_ascii_lowercase().trim() {
+        "std_logic" | "std_ulogic" | "bit" | "boolean" => 1,
+        _ => 0,
+    }
+}
+
+/// Identifiers under `node` that can be reads, in source order. Suffixes of
+/// selected names, attribute names, association formals, labels and
+/// static-attribute prefixes are left out, as is the `skip` subtree
+/// (typically an assignment target). Callers filter the result against
+/// declared signals, which also drops calls, types and literals.
+pub(super) fn collect_reads(node: Node, source: &[u8], skip: Option<Node>, out: &mut Vec<String>) {
+    let mut cursor = node.walk();
+    if !cursor.goto_first_child() {
+        return;
+    }
+    loop {
+        let child = cursor.node();
+        let field = cursor.field_name();
+        let skipped = Some(child) == skip
+            || OPAQUE_KINDS.contains(&child.kind())
+            || matches!(
+                field,
+                Some("suffix" | "attribute" | "formal" | "label" | "end_label")
+            );
+        if !skipped {
+            if child.kind() == "identifier" {
+                if !followed_by_static_attribute(child, source) {
+                    out.push(text(child, source).to_string());
+                }
+            } else {
+                collect_reads(child, source, skip, out);
+            }
+        }
+        if !cursor.goto_next_sibling() {
+            break;
+        }
+    }
+}
+
+fn followed_by_static_attribute(node: Node, source: &[u8]) -> bool {
+    let rest = match source.get(node.end_byte()..) {
+        Some(rest) => String::from_utf8_lossy(&rest[..rest.len().min(32)]).to_string(),
+        None => return false,
+    };
+    let Some(attr) = rest.strip_prefix('\'') else {
+        return false;
+    };
+    let attr: String = attr
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .collect();
+    STATIC_ATTRIBUTES.contains(&attr.to_ascii_lowercase().as_str())
+}
+
+/// Sensitivity list entries, or `["all"]` for VHDL-2008 `process (all)`.
+pub(super) fn sensitivity_list(node: Node, source: &[u8]) -> Vec<String> {
+    let content = text(node, source).trim();
+    if content.eq_ignore_ascii_case("all") {
+        return vec!["all".to_string()];
+    }
+    content
+        .split(',')
+        .map(normalize)
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Clock signal and edge of a condition such as `rising_edge(clk)` or
+/// `clk'event and clk = '1'`.
+pub(super) fn clock_edge(condition: &str) -> Option<(String, &'static str)> {
+    let call = Regex::new(r"(?i)\b(rising|falling)_edge\s*\(\s*([A-Za-z_][A-Za-z0-9_]*)").unwrap();
+    if let Some(caps) = call.captures(condition) {
+        let edge = if caps[1].eq_ignore_ascii_case("rising") {
+            "rising"
+        } else {
+            "falling"
+        };
+        return Some((caps[2].to_string(), edge));
+    }
+    let event = Regex::new(
+        r"(?i)\b([A-Za-z_][A-Za-z0-9_]*)'event\s+and\s+([A-Za-z_][A-Za-z0-9_]*)\s*=\s*'([01])'",
+    )
+    .unwrap();
+    let caps = event.captures(condition)?;
+    if !caps[1].eq_ignore_ascii_case(&caps[2]) {
+        return None;
+    }
+    let edge = if &caps[3] == "1" { "rising" } else { "falling" };
+    Some((caps[1].to_string(), edge))
+}
+
+/// Signal compared against a bit literal: `rst = '1'` -> `rst`.
+pub(super) fn compared_signal(condition: &str) -> Option<String> {
+    let re =
+        Regex::new(r"(?i)^\s*\(?\s*([A-Za-z_][A-Za-z0-9_]*)\s*(=|/=)\s*'[01]'\s*\)?\s*$").unwrap();
+    re.captures(condition).map(|caps| caps[1].to_string())
+}
+
+/// The right-hand side of a plain `target <= value;` assignment, or "" for
+/// waveforms with delays, several elements or conditions.
+pub(super) fn assignment_value(content: &str) -> String {
+    let joined: String = content
+        .lines()
+        .map(|line| line.split("--").next().unwrap_or(""))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let Some((_, rhs)) = joined.split_once("<=") else {
+        return String::new();
+    };
+    let rhs = rhs.trim().trim_end_matches(';').trim();
+    let lower = format!(" {} ", rhs.to_ascii_lowercase());
+    if [" after ", " when ", " transport ", " inertial ", " reject "]
+        .iter()
+        .any(|kw| lower.contains(kw))
+    {
+        return String::new();
+    }
+    let mut depth = 0i32;
+    let mut in_string = false;
+    for c in rhs.chars() {
+        match c {
+            '"' => in_string = !in_string,
+            _ if in_string => {}
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => return String::new(),
+            _ => {}
+        }
+    }
+    rhs.to_string()
+}
+
+/// Clauses of a `wait` statement: the `on` signals, the `until` condition
+/// and the `for` timeout, each empty when absent (all three for `wait;`).
+pub(super) fn wait_clauses(content: &str) -> (Vec<String>, String, String) {
+    let content = content.split("--").next().unwrap_or("");
+    let re = Regex::new(r"(?i)\b(wait|on|until|for)\b").unwrap();
+    let mut keywords = Vec::new();
+    for found in re.find_iter(content) {
+        let before = &content[..found.start()];
+        let depth = before.matches('(').count() as i32 - before.matches(')').count() as i32;
+        if depth == 0 {
+            keywords.push(found);
+        }
+    }
+    let (mut on, mut until, mut timeout) = (Vec::new(), String::new(), String::new());
+    for (idx, keyword) in keywords.iter().enumerate() {
+        let end = keywords
+            .get(idx + 1)
+            .map_or(content.len(), |next| next.start());
+        let clause = normalize(content[keyword.end()..end].trim().trim_end_matches(';'));
+        match keyword.as_str().to_ascii_lowercase().as_str() {
+            "on" => on = clause.split(',').map(|s| s.trim().to_string()).collect(),
+            "until" => until = clause,
+            "for" => timeout = clause,
+            _ => {}
+        }
+    }
+    (on, until, timeout)
+}
+
+/// Lowercase severity of an `assert`/`report` statement, "" without a
+/// `severity` clause.
+pub(super) fn severity_clause(content: &str) -> String {
+    let re = Regex::new(r#"(?i)\bseverity\s+([A-Za-z_][A-Za-z0-9_.]*)\s*;?\s*$"#).unwrap();
+    re.captures(content.trim())
+        .map(|caps| caps[1].to_ascii_lowercase())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widths_and_clock_conditions() {
+        assert_eq!(type_width("std_logic_vector(7 downto 0)"), 8);
+        assert_eq!(type_width("unsigned (0 to 3)"), 4);
+        assert_eq!(type_width("std_logic_vector(4*8-1 downto 0)"), 32);
+        assert_eq!(type_width("std_logic_vector(W-1 downto 0)"), 0);
+        assert_eq!(type_width("std_logic"), 1);
+        assert_eq!(type_width("state_t"), 0);
+
+        assert_eq!(
+            clock_edge("rising_edge(clk_i)"),
+            Some(("clk_i".to_string(), "rising"))
+        );
+        assert_eq!(
+            clock_edge("clk'event and clk = '0'"),
+            Some(("clk".to_string(), "falling"))
+        );
+        assert_eq!(clock_edge("en = '1'"), None);
+        assert_eq!(compared_signal("rst_n = '0'"), Some("rst_n".to_string()));
+        assert_eq!(compared_signal("a = b"), None);
+    }
+
+    #[test]
+    fn wait_and_severity_clauses() {
+        assert_eq!(
+            wait_clauses("wait;"),
+            (Vec::new(), String::new(), String::new())
+        );
+        assert_eq!(
+            wait_clauses("wait on a, b until f(x, y) = '1' for 10 ns;"),
+            (
+                vec!["a".to_string(), "b".to_string()],
+                "f(x, y) = '1'".to_string(),
+                "10 ns".to_string()
+            )
+        );
+        assert_eq!(wait_clauses("wait for CLK_PERIOD / 2;").2, "CLK_PERIOD / 2");
+        assert_eq!(
+            severity_clause("assert done report \"timeout\" severity FAILURE;"),
+            "failure"
+        );
+        assert_eq!(severity_clause("report \"severity of this\";"), "");
+    }
+
+    #[test]
+    fn assignment_value_only_for_plain_waveforms() {
+        assert_eq!(assignment_value("q <= d;"), "d");
+        assert_eq!(assignment_value("q <= d after 1 ns;"), "");
+        assert_eq!(assignment_value("q <= '1', '0' after 5 ns;"), "");
+        assert_eq!(assignment_value("q <= f(a, b); -- comment"), "f(a, b)");
+    }
+}
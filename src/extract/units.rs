@@ -0,0 +1,486 @@
+use regex::Regex;
+use tree_sitter::Node;
+
+use super::names::{
+    base_name, columns, identifier_list, identifier_nodes, line, normalize, text, trailing_comment,
+    type_width,
+};
+use crate::policy::input::{
+    Architecture, Association, Component, Dependency, Entity, GenericDecl, Input, Instance,
+    LanguageFeature, LibraryClause, ModeView, ModeViewElement, Package, Port, Signal, UseClause,
+};
+use crate::policy::standards;
+
+/// Declaration pass: design units, clauses, signals, ports, components and
+/// instances of one file.
+pub(super) struct Context<'a> {
+    file: &'a str,
+    source: &'a [u8],
+}
+
+impl<'a> Context<'a> {
+    pub(super) fn new(file: &'a str, source: &'a [u8]) -> Self {
+        Self { file, source }
+    }
+
+    pub(super) fn walk(&mut self, root: Node, input: &mut Input) {
+        self.walk_scoped(root, "", input);
+    }
+
+    /// `scope` is the enclosing entity, architecture or package name; signals
+    /// record it as `in_entity` and instances as `in_arch`.
+    fn walk_scoped(&mut self, node: Node, scope: &str, input: &mut Input) {
+        let mut scope = scope.to_string();
+        match node.kind() {
+            "entity_declaration" => {
+                let entity = self.entity(node);
+                input.ports.extend(entity.ports.iter().cloned());
+                scope = entity.name.clone();
+                input.entities.push(entity);
+            }
+            "architecture_body" => {
+                let (column, end_column) = columns(node.child_by_field_name("name"), line(node));
+                let arch = Architecture {
+                    name: self.field(node, "name"),
+                    entity_name: self.field(node, "entity"),
+                    file: self.file.to_string(),
+                    line: line(node),
+                    column,
+                    end_column,
+                };
+                scope = arch.name.clone();
+                input.architectures.push(arch);
+            }
+            "package_declaration" => {
+                let (column, end_column) = columns(node.child_by_field_name("name"), line(node));
+                let pkg = Package {
+                    name: self.field(node, "name"),
+                    file: self.file.to_string(),
+                    line: line(node),
+                    column,
+                    end_column,
+                };
+                scope = pkg.name.clone();
+                input.packages.push(pkg);
+            }
+            "package_body" => scope = self.field(node, "name"),
+            "use_clause" => self.use_clause(node, input),
+            "library_clause" => self.library_clause(node, input),
+            "signal_declaration" => self.signals(node, &scope, input),
+            "view_declaration" => {
+                self.mode_view(node, input);
+                return;
+            }
+            "component_declaration" => {
+                let name = self.field(node, "name");
+                input.components.push(Component {
+                    ports: self.ports(node, &name),
+                    generics: self.generics(node, "", &name),
+                    name,
+                    file: self.file.to_string(),
+                    line: line(node),
+                    ..Default::default()
+                });
+                return;
+            }
+            "component_instantiation" => {
+                self.instance(node, &scope, input);
+                return;
+            }
+            // Behavior is extracted in the second pass.
+            "process_statement" | "function_declaration" | "procedure_declaration" => return,
+            _ => {}
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.walk_scoped(child, &scope, input);
+        }
+    }
+
+    fn field(&self, node: Node, name: &str) -> String {
+        node.child_by_field_name(name)
+            .map(|n| text(n, self.source).trim().to_string())
+            .unwrap_or_default()
+    }
+
+    fn entity(&self, node: Node) -> Entity {
+        let name = self.field(node, "name");
+        let (column, end_column) = columns(node.child_by_field_name("name"), line(node));
+        Entity {
+            ports: self.ports(node, &name),
+            generics: self.generics(node, &name, ""),
+            name,
+            file: self.file.to_string(),
+            line: line(node),
+            column,
+            end_column,
+        }
+    }
+
+    /// Ports of the `port_clause` directly under an entity or component.
+    fn ports(&self, node: Node, owner: &str) -> Vec<Port> {
+        let mut ports = Vec::new();
+        let mut cursor = node.walk();
+        for clause in node
+            .children(&mut cursor)
+            .filter(|c| c.kind() == "port_clause")
+        {
+            for param in descendants(clause, "parameter") {
+                let mut direction = self.field(param, "direction").to_ascii_lowercase();
+                let mut ty = normalize(&self.field(param, "type"));
+                let mut view = String::new();
+                if let Some(indication) = param.child_by_field_name("view") {
+                    direction = "view".to_string();
+                    view = self.field(indication, "name");
+                    ty = normalize(&self.field(indication, "type"));
+                }
+                let default = self.field(param, "default");
+                let comment = trailing_comment(param, self.source);
+                let Some(names) = param.child_by_field_name("names") else {
+                    continue;
+                };
+                for name in identifier_nodes(names) {
+                    let (column, end_column) = columns(Some(name), line(param));
+                    ports.push(Port {
+                        name: text(name, self.source).to_string(),
+                        direction: direction.clone(),
+                        width: type_width(&ty),
+                        r#type: ty.clone(),
+                        default: default.clone(),
+                        line: line(param),
+                        column,
+                        end_column,
+                        in_entity: owner.to_string(),
+                        comment: comment.clone(),
+                        view: view.clone(),
+                    });
+                }
+            }
+        }
+        ports
+    }
+
+    /// Constant generics (`generic_item` parameters) of an entity or component.
+    fn generics(&self, node: Node, in_entity: &str, in_component: &str) -> Vec<GenericDecl> {
+        let mut generics = Vec::new();
+        let mut cursor = node.walk();
+        for item in node
+            .children(&mut cursor)
+            .filter(|c| c.kind() == "generic_item")
+        {
+            let mut item_cursor = item.walk();
+            let Some(param) = item
+                .children(&mut item_cursor)
+                .find(|c| c.kind() == "parameter")
+            else {
+                continue;
+            };
+            let Some(names) = param.child_by_field_name("names") else {
+                continue;
+            };
+            let class = match self.field(param, "class").to_ascii_lowercase() {
+                class if class.is_empty() => "constant".to_string(),
+                class => class,
+            };
+            for name in identifier_list(names, self.source) {
+                generics.push(GenericDecl {
+                    name,
+                    kind: "constant".to_string(),
+                    r#type: normalize(&self.field(param, "type")),
+                    class: class.clone(),
+                    default: self.field(param, "default"),
+                    line: line(param),
+                    in_entity: in_entity.to_string(),
+                    in_component: in_component.to_string(),
+                });
+            }
+        }
+        generics
+    }
+
+    fn use_clause(&self, node: Node, input: &mut Input) {
+        let mut items: Vec<String> = Vec::new();
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            match child.kind() {
+                "identifier" => items.push(text(child, self.source).to_string()),
+                "selector_clause" => {
+                    if let Some(last) = items.last_mut() {
+                        last.push_str(text(child, self.source));
+                    }
+                }
+                _ => {}
+            }
+        }
+        for item in &items {
+            input.dependencies.push(Dependency {
+                source: self.file.to_string(),
+                target: item.clone(),
+                kind: "use".to_string(),
+                line: line(node),
+                resolved: false,
+            });
+        }
+        if !items.is_empty() {
+            input.use_clauses.push(UseClause {
+                items,
+                file: self.file.to_string(),
+                line: line(node),
+            });
+        }
+    }
+
+    fn library_clause(&self, node: Node, input: &mut Input) {
+        let libraries = identifier_list(node, self.source);
+        for library in &libraries {
+            input.dependencies.push(Dependency {
+                source: self.file.to_string(),
+                target: library.clone(),
+                kind: "library".to_string(),
+                line: line(node),
+                resolved: false,
+            });
+        }
+        input.library_clauses.push(LibraryClause {
+            libraries,
+            file: self.file.to_string(),
+            line: line(node),
+        });
+    }
+
+    /// VHDL-2019 `view ... end view`, scoped like constant declarations: to
+    /// the enclosing package, or the enclosing architecture.
+    fn mode_view(&self, node: Node, input: &mut Input) {
+        let mut elements = Vec::new();
+        for element in descendants(node, "view_element") {
+            let (direction, view) = match element.child_by_field_name("view") {
+                Some(indication) => ("view".to_string(), self.field(indication, "name")),
+                None => (
+                    self.field(element, "direction").to_ascii_lowercase(),
+                    String::new(),
+                ),
+            };
+            let Some(names) = element.child_by_field_name("names") else {
+                continue;
+            };
+            for name in identifier_list(names, self.source) {
+                elements.push(ModeViewElement {
+                    name,
+                    direction: direction.clone(),
+                    view: view.clone(),
+                });
+            }
+        }
+        input.mode_views.push(ModeView {
+            name: self.field(node, "name"),
+            r#type: self.field(node, "type"),
+            elements,
+            file: self.file.to_string(),
+            line: line(node),
+            in_package: self.enclosing(node, "package_declaration"),
+            in_arch: self.enclosing(node, "architecture_body"),
+        });
+    }
+
+    /// Name of the closest ancestor of `kind`, or "".
+    fn enclosing(&self, node: Node, kind: &str) -> String {
+        let mut parent = node.parent();
+        while let Some(current) = parent {
+            if current.kind() == kind {
+                return self.field(current, "name");
+            }
+            parent = current.parent();
+        }
+        String::new()
+    }
+
+    fn signals(&self, node: Node, scope: &str, input: &mut Input) {
+        let ty = normalize(&self.field(node, "type"));
+        let Some(names) = node.child_by_field_name("names") else {
+            return;
+        };
+        for name in identifier_nodes(names) {
+            let (column, end_column) = columns(Some(name), line(node));
+            input.signals.push(Signal {
+                name: text(name, self.source).to_string(),
+                width: type_width(&ty),
+                r#type: ty.clone(),
+                file: self.file.to_string(),
+                line: line(node),
+                column,
+                end_column,
+                in_entity: scope.to_string(),
+                default: normalize(&self.field(node, "value")),
+            });
+        }
+    }
+
+    fn instance(&self, node: Node, scope: &str, input: &mut Input) {
+        let target = if node.child_by_field_name("component").is_some() {
+            self.field(node, "component")
+        } else if node.child_by_field_name("configuration").is_some() {
+            self.field(node, "configuration")
+        } else {
+            let entity = self.field(node, "entity");
+            match self.field(node, "library") {
+                library if library.is_empty() => entity,
+                library => format!("{}.{}", library, entity),
+            }
+        };
+        let (column, end_column) = columns(node.child_by_field_name("label"), line(node));
+        let mut inst = Instance {
+            name: self.field(node, "label"),
+            target: target.clone(),
+            target_arch: self.field(node, "architecture"),
+            file: self.file.to_string(),
+            line: line(node),
+            column,
+            end_column,
+            in_arch: scope.to_string(),
+            ..Default::default()
+        };
+        let mut cursor = node.walk();
+        for aspect in node.children(&mut cursor) {
+            let (kind, map) = match aspect.kind() {
+                "generic_map_aspect" => ("generic", &mut inst.generic_map),
+                "port_map_aspect" => ("port", &mut inst.port_map),
+                _ => continue,
+            };
+            for (idx, element) in descendants(aspect, "association_element")
+                .into_iter()
+                .enumerate()
+            {
+                let assoc = association(text(element, self.source), kind, idx, line(element));
+                if !assoc.is_positional {
+                    map.insert(assoc.formal.clone(), assoc.actual.clone());
+                }
+                inst.associations.push(assoc);
+            }
+        }
+        input.components.push(Component {
+            name: inst.name.clone(),
+            entity_ref: target.clone(),
+            file: self.file.to_string(),
+            line: inst.line,
+            is_instance: true,
+            ..Default::default()
+        });
+        input.dependencies.push(Dependency {
+            source: self.file.to_string(),
+            target,
+            kind: "instantiation".to_string(),
+            line: inst.line,
+            resolved: false,
+        });
+        input.instances.push(inst);
+    }
+}
+
+/// Splits `formal => actual` (or a positional actual) and classifies the
+/// actual the way the Go extractor does.
+fn association(content: &str, kind: &str, position: usize, line: usize) -> Association {
+    let content = normalize(content);
+    let (formal, actual) = match content.split_once("=>") {
+        Some((formal, actual)) => (formal.trim().to_string(), actual.trim().to_string()),
+        None => (String::new(), content.trim().to_string()),
+    };
+    let first = actual.chars().next().unwrap_or(' ');
+    let is_name =
+        Regex::new(r"^[A-Za-z_][A-Za-z0-9_]*(\.[A-Za-z_][A-Za-z0-9_]*)*(\s*\([^()]*\))?$")
+            .unwrap()
+            .is_match(&actual);
+    let actual_kind = if actual.eq_ignore_ascii_case("open") {
+        "open"
+    } else if first == '(' {
+        "aggregate"
+    } else if first == '\'' || first.is_ascii_digit() || is_string_literal(&actual) {
+        "literal"
+    } else if is_name {
+        "name"
+    } else {
+        "expression"
+    };
+    let (actual_base, actual_full) = if actual_kind == "name" {
+        (base_name(&actual), actual.replace(' ', ""))
+    } else {
+        (String::new(), String::new())
+    };
+    Association {
+        kind: kind.to_string(),
+        is_positional: formal.is_empty(),
+        formal,
+        actual,
+        actual_kind: actual_kind.to_string(),
+        actual_base,
+        actual_full,
+        line,
+        position_index: position,
+    }
+}
+
+/// String and bit-string literals: `"0101"`, `x"00"`, `8ux"ff"`.
+fn is_string_literal(actual: &str) -> bool {
+    Regex::new(r#"^[0-9]*[A-Za-z]{0,2}""#)
+        .unwrap()
+        .is_match(actual)
+}
+
+/// Every node of `kind` under `node`, outermost first, without descending
+/// into matches.
+/// VHDL-2008 constructs `policy::standards` gates on the configured standard:
+/// block comments and matching `case?` statements, nested ones included.
+pub(super) fn language_features(node: Node, file: &str, out: &mut Vec<LanguageFeature>) {
+    let mut cursor = node.walk();
+    let feature = match node.kind() {
+        "block_comment" => Some(standards::BLOCK_COMMENT),
+        "case_statement" if node.children(&mut cursor).any(|c| c.kind() == "?") => {
+            Some(standards::MATCHING_CASE)
+        }
+        _ => None,
+    };
+    if let Some(feature) = feature {
+        out.push(LanguageFeature {
+            feature: feature.to_string(),
+            file: file.to_string(),
+            line: line(node),
+        });
+    }
+    for child in node.children(&mut cursor) {
+        language_features(child, file, out);
+    }
+}
+
+pub(super) fn descendants<'t>(node: Node<'t>, kind: &str) -> Vec<Node<'t>> {
+    let mut found = Vec::new();
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == kind {
+            found.push(child);
+        } else {
+            found.extend(descendants(child, kind));
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn association_kinds() {
+        let named = association("clk => sys_clk", "port", 0, 3);
+        assert!(!named.is_positional);
+        assert_eq!(named.formal, "clk");
+        assert_eq!(named.actual_kind, "name");
+        assert_eq!(named.actual_base, "sys_clk");
+
+        assert_eq!(association("q => open", "port", 1, 3).actual_kind, "open");
+        assert_eq!(association("x\"00\"", "port", 2, 3).actual_kind, "literal");
+        assert_eq!(
+            association("a and b", "port", 3, 3).actual_kind,
+            "expression"
+        );
+        assert!(association("data(3)", "port", 4, 3).is_positional);
+    }
+}
@@ -1 +1,30 @@
+//! VHDL linting as a library. The stable entry points are:
+//!
+//! - `policy::{Input, evaluate, Result, Violation}`: the policy engine over
+//!   extracted facts (the JSON the Go extractor emits deserializes into
+//!   `Input`);
+//! - `extract::build_input` and `lint_source`: facts and lint results straight
+//!   from VHDL text;
+//! - `parser::parse_errors`: syntax errors of one source, with fix hints.
+//!
+//! The binaries are thin front ends over these.
+
+pub mod extract;
+pub mod parser;
 pub mod policy;
+
+use extract::SourceFile;
+use policy::input::LintConfig;
+
+/// Lints one in-memory VHDL source as if it were the only file in the
+/// project, so editor plugins and tests can check a snippet without touching
+/// the filesystem. `name` is the path violations are reported against and
+/// the one `config`'s file patterns match.
+pub fn lint_source(name: &str, text: &str, config: &LintConfig) -> Result<policy::Result, String> {
+    let mut input = extract::build_input(&[SourceFile {
+        path: name.to_string(),
+        text: text.to_string(),
+    }])?;
+    input.lint_config = config.clone();
+    Ok(policy::evaluate(&input))
+}
@@ -1,98 +1,727 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::env;
 use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use notify::{RecursiveMode, Watcher};
+use serde::Serialize;
+use vhdl_compiler::extract::cache::{self, Cache};
+use vhdl_compiler::extract::{self, discover, SourceFile, Workspace};
+use vhdl_compiler::parser::{self, ParseError};
+use vhdl_compiler::policy::config;
+use vhdl_compiler::policy::daemon::{self, Daemon};
+use vhdl_compiler::policy::engine::{self, ModuleCache};
+use vhdl_compiler::policy::fixes;
+use vhdl_compiler::policy::helpers::glob_match;
+use vhdl_compiler::policy::project::{self, Project};
+use vhdl_compiler::policy::result::Summary;
+use vhdl_compiler::policy::source_map::SourceMap;
+use vhdl_compiler::policy::{self, Input, Violation};
 
 const MAX_ERRORS: usize = 10;
+/// Quiet period after a filesystem event before re-linting, so an editor's
+/// burst of writes is handled as one change.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(100);
+const USAGE: &str =
+    "Usage: vhdl-compiler [--lint [--fix] [--no-cache] [--with-snippets] [--project vhdl_project.toml] | --watch] [--format text|json] [file.vhd | dir | 'rtl/**/*.vhd' | -]...";
+/// The argument that reads a source from stdin instead of a file.
+const STDIN_ARG: &str = "-";
+/// The file name a source read from stdin is reported under.
+const STDIN_NAME: &str = "<stdin>";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+struct Options {
+    format: Format,
+    /// Run the policy engine on extracted facts instead of reporting parse
+    /// errors.
+    lint: bool,
+    /// With `--lint`, apply the fixes attached to violations to the sources.
+    fix: bool,
+    /// With `--lint`, extract every file instead of reusing the per-file
+    /// tables cached in `.vhdl_lint_cache/extract/`.
+    no_cache: bool,
+    /// With `--lint`, attach the offending source lines to each violation.
+    snippets: bool,
+    /// Keep running, re-linting through `vhdl_policyd` on every change.
+    watch: bool,
+    /// With `--lint`, the project manifest listing the files to lint.
+    project: Option<PathBuf>,
+    patterns: Vec<String>,
+}
+
+/// Parse errors found in one scanned file.
+#[derive(Serialize)]
+struct FileReport {
+    file: String,
+    errors: usize,
+}
+
+#[derive(Serialize)]
+struct Report {
+    summary: Summary,
+    files: Vec<FileReport>,
+    violations: Vec<ParseError>,
+}
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let args: Vec<String> = env::args().skip(1).collect();
+    let opts = match parse_args(&args) {
+        Ok(parsed) => parsed,
+        Err(msg) => {
+            eprintln!("{}\n{}", msg, USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    if opts.watch {
+        watch(&opts.patterns, opts.format);
+        return;
+    }
 
-    let filename = args.get(1).map(|s| s.as_str()).unwrap_or("test.vhdl");
+    if let Some(path) = &opts.project {
+        let project = match project::open(path) {
+            Ok(project) => project,
+            Err(e) => {
+                eprintln!("Error loading project: {}", e);
+                std::process::exit(1);
+            }
+        };
+        lint(&project.paths(), &opts, Some(&project));
+        return;
+    }
+
+    let files = match discover_all(&opts.patterns) {
+        Ok(files) => files,
+        Err(msg) => {
+            eprintln!("{}", msg);
+            std::process::exit(1);
+        }
+    };
 
-    let source_code = match fs::read_to_string(filename) {
-        Ok(content) => content,
+    if opts.lint {
+        lint(&files, &opts, None);
+        return;
+    }
+
+    let mut parser = match parser::new_parser() {
+        Ok(parser) => parser,
         Err(e) => {
-            eprintln!("Error reading '{}': {}", filename, e);
+            eprintln!("Error {}", e);
             std::process::exit(1);
         }
     };
 
-    let mut parser = tree_sitter::Parser::new();
-    parser
-        .set_language(&tree_sitter_vhdl::language())
-        .expect("Error loading VHDL grammar");
+    let mut reports = Vec::new();
+    let mut unreadable = false;
+    for path in &files {
+        let Some(source) = read_or_report(path) else {
+            unreadable = true;
+            continue;
+        };
+        let tree = parser.parse(&source.text, None).expect("Failed to parse");
+        let errors = parser::tree_errors(&tree, &source.text, &source.name);
+        reports.push((source.name, errors));
+    }
 
-    let tree = parser.parse(&source_code, None).expect("Failed to parse");
-    let root = tree.root_node();
+    match opts.format {
+        Format::Json => print_json(reports),
+        Format::Text => print_text(&reports),
+    }
+    if unreadable {
+        std::process::exit(1);
+    }
+}
 
-    // Walk and report any errors (up to MAX_ERRORS)
-    let mut error_count: usize = 0;
-    let mut cursor = root.walk();
-    walk_errors(&mut cursor, &source_code, &mut error_count);
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut format = Format::Text;
+    let mut lint = false;
+    let mut fix = false;
+    let mut no_cache = false;
+    let mut snippets = false;
+    let mut watch = false;
+    let mut project = None;
+    let mut patterns = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--format" => {
+                format = match iter.next().map(String::as_str) {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    Some(other) => return Err(format!("unknown format '{}'", other)),
+                    None => return Err("--format requires 'text' or 'json'".to_string()),
+                };
+            }
+            "--lint" => lint = true,
+            "--fix" => fix = true,
+            "--no-cache" => no_cache = true,
+            "--with-snippets" => snippets = true,
+            "--watch" => watch = true,
+            "--project" => match iter.next() {
+                Some(path) => project = Some(PathBuf::from(path)),
+                None => return Err("--project requires a manifest path".to_string()),
+            },
+            _ if arg.starts_with("--") => return Err(format!("unknown option '{}'", arg)),
+            _ => patterns.push(arg.clone()),
+        }
+    }
+    if project.is_some() {
+        if !lint {
+            return Err("--project requires --lint".to_string());
+        }
+        if !patterns.is_empty() {
+            return Err("--project lists the files; drop the file arguments".to_string());
+        }
+    } else if patterns.is_empty() {
+        patterns.push("test.vhdl".to_string());
+    }
+    if fix && !lint {
+        return Err("--fix requires --lint".to_string());
+    }
+    if no_cache && !lint {
+        return Err("--no-cache requires --lint".to_string());
+    }
+    if snippets && !lint {
+        return Err("--with-snippets requires --lint".to_string());
+    }
+    if watch && (lint || fix) {
+        return Err("--watch cannot be combined with --lint or --fix".to_string());
+    }
+    if (watch || fix) && patterns.iter().any(|pattern| pattern == STDIN_ARG) {
+        return Err("reading stdin ('-') cannot be combined with --watch or --fix".to_string());
+    }
+    Ok(Options {
+        format,
+        lint,
+        fix,
+        no_cache,
+        snippets,
+        watch,
+        project,
+        patterns,
+    })
+}
 
-    if error_count > 0 {
-        if error_count > MAX_ERRORS {
-            println!("... and {} more errors", error_count - MAX_ERRORS);
+/// Expands every argument and returns the sorted, de-duplicated file list.
+fn discover_all(patterns: &[String]) -> Result<Vec<PathBuf>, String> {
+    let mut files = Vec::new();
+    for pattern in patterns {
+        match discover_files(pattern) {
+            Ok(found) if found.is_empty() => {
+                return Err(format!("No VHDL files match '{}'", pattern));
+            }
+            Ok(found) => files.extend(found),
+            Err(e) => return Err(format!("Error reading '{}': {}", pattern, e)),
         }
-        println!("\n✗ {} parse error(s) found", error_count);
-        std::process::exit(1);
+    }
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+/// Expands one command-line argument: a glob pattern (`*`, `?`, `**`) or a
+/// directory yields the VHDL files beneath it; anything else, including `-`
+/// for stdin, is taken as a file path as-is.
+fn discover_files(pattern: &str) -> std::io::Result<Vec<PathBuf>> {
+    if pattern.contains(['*', '?']) {
+        let (base, rest) = split_glob(pattern);
+        let mut found = discover::vhdl_files(&base)?;
+        found.retain(|path| {
+            let relative = path.strip_prefix(&base).unwrap_or(path);
+            glob_match(&rest, &relative.to_string_lossy().replace('\\', "/"))
+        });
+        return Ok(found);
+    }
+    let path = PathBuf::from(pattern);
+    if path.is_dir() {
+        return discover::vhdl_files(&path);
+    }
+    Ok(vec![path])
+}
+
+/// Splits a glob into its literal leading directory and the pattern below it.
+fn split_glob(pattern: &str) -> (PathBuf, String) {
+    let parts: Vec<&str> = pattern.split('/').collect();
+    let literal = parts
+        .iter()
+        .position(|part| part.contains(['*', '?']))
+        .unwrap_or(parts.len());
+    let base = if literal == 0 {
+        PathBuf::from(".")
+    } else if parts[..literal].join("/").is_empty() {
+        PathBuf::from("/")
     } else {
-        println!("✓ No parse errors!");
+        PathBuf::from(parts[..literal].join("/"))
+    };
+    (base, parts[literal..].join("/"))
+}
+
+/// A discovered file's reported name and text.
+struct Source {
+    name: String,
+    text: String,
+    /// The file isn't valid UTF-8 (e.g. a Latin-1 comment in a vendor
+    /// file); `text` has U+FFFD in place of the offending bytes, so the
+    /// file is linted but never rewritten.
+    lossy: bool,
+}
+
+/// Reads a discovered file, or stdin for `-`.
+fn read_source(path: &Path) -> io::Result<Source> {
+    let (name, bytes) = if path == Path::new(STDIN_ARG) {
+        let mut bytes = Vec::new();
+        io::stdin().read_to_end(&mut bytes)?;
+        (STDIN_NAME.to_string(), bytes)
+    } else {
+        (path.to_string_lossy().to_string(), fs::read(path)?)
+    };
+    let (text, lossy) = match String::from_utf8(bytes) {
+        Ok(text) => (text, false),
+        Err(e) => (String::from_utf8_lossy(e.as_bytes()).into_owned(), true),
+    };
+    Ok(Source { name, text, lossy })
+}
+
+/// `read_source`, reporting a file that can't be read and moving on so one
+/// bad file doesn't stop a directory or glob scan.
+fn read_or_report(path: &Path) -> Option<Source> {
+    match read_source(path) {
+        Ok(source) => Some(source),
+        Err(e) => {
+            eprintln!("Error reading '{}': {}", path.display(), e);
+            None
+        }
     }
 }
 
-fn walk_errors(cursor: &mut tree_sitter::TreeCursor, source: &str, error_count: &mut usize) {
-    loop {
-        let node = cursor.node();
+fn print_text(reports: &[(String, Vec<ParseError>)]) {
+    let multi = reports.len() > 1;
+    let mut total = 0;
+    let mut failed_files = 0;
+    for (file, errors) in reports {
+        if multi && !errors.is_empty() {
+            println!("{}:", file);
+        }
+        for error in errors.iter().take(MAX_ERRORS) {
+            println!(
+                "{}{} at {}:{}-{}:{}: {}",
+                if multi { "  " } else { "" },
+                if error.missing { "MISSING" } else { "ERROR" },
+                error.line,
+                error.column,
+                error.end_line,
+                error.end_column,
+                error.message
+            );
+            if !error.hint.is_empty() {
+                println!("{}  hint: {}", if multi { "  " } else { "" }, error.hint);
+            }
+        }
+        if errors.len() > MAX_ERRORS {
+            println!(
+                "{}... and {} more errors",
+                if multi { "  " } else { "" },
+                errors.len() - MAX_ERRORS
+            );
+        }
+        total += errors.len();
+        if !errors.is_empty() {
+            failed_files += 1;
+        }
+    }
+    if total == 0 {
+        if multi {
+            println!("✓ No parse errors in {} files!", reports.len());
+        } else {
+            println!("✓ No parse errors!");
+        }
+        return;
+    }
+    if multi {
+        println!(
+            "\n✗ {} parse error(s) in {} of {} files",
+            total,
+            failed_files,
+            reports.len()
+        );
+    } else {
+        println!("\n✗ {} parse error(s) found", total);
+    }
+    std::process::exit(1);
+}
 
-        if node.is_error() || node.is_missing() || node.kind() == "invalid_bit_string_literal" {
-            *error_count += 1;
+/// Reports every error (no `MAX_ERRORS` cap) as `{summary, files, violations}`.
+fn print_json(reports: Vec<(String, Vec<ParseError>)>) {
+    let files: Vec<FileReport> = reports
+        .iter()
+        .map(|(file, errors)| FileReport {
+            file: file.clone(),
+            errors: errors.len(),
+        })
+        .collect();
+    let errors: Vec<ParseError> = reports.into_iter().flat_map(|(_, errors)| errors).collect();
+    let failed = !errors.is_empty();
+    let report = Report {
+        summary: Summary {
+            total_violations: errors.len(),
+            errors: errors.len(),
+            ..Default::default()
+        },
+        files,
+        violations: errors,
+    };
+    match serde_json::to_string_pretty(&report) {
+        Ok(json) => println!("{}", json),
+        Err(e) => {
+            eprintln!("Error serializing report: {}", e);
+            std::process::exit(1);
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
 
-            if *error_count <= MAX_ERRORS {
-                let start = node.start_position();
-                let end = node.end_position();
-                let text = node
-                    .utf8_text(source.as_bytes())
-                    .unwrap_or("<invalid utf8>");
+/// Extracts policy facts from `files` (reusing cached tables for unchanged
+/// files unless `no_cache`; stdin is never cached), evaluates every rule and
+/// prints the violations, with their source lines for `--with-snippets`;
+/// exits 1 when any of them is an error or a file couldn't be read. A
+/// `project` sets the files' libraries, standards and third-party flags
+/// first.
+fn lint(files: &[PathBuf], opts: &Options, project: Option<&Project>) {
+    let mut sources = Vec::with_capacity(files.len());
+    let mut lossy = HashSet::new();
+    let mut unreadable = false;
+    for path in files {
+        let Some(source) = read_or_report(path) else {
+            unreadable = true;
+            continue;
+        };
+        if source.lossy {
+            lossy.insert(source.name.clone());
+        }
+        sources.push(SourceFile {
+            path: source.name,
+            text: source.text,
+        });
+    }
+    let stdin = files.iter().any(|path| path == Path::new(STDIN_ARG));
+    let built = if opts.no_cache || stdin {
+        extract::build_input(&sources)
+    } else {
+        extract::build_input_cached(&sources, &Cache::new(cache::DEFAULT_DIR)).map(
+            |(input, stats)| {
+                for e in &stats.errors {
+                    eprintln!("Warning: extraction cache: {}", e);
+                }
+                input
+            },
+        )
+    };
+    let mut input = match built {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Error extracting facts: {}", e);
+            std::process::exit(1);
+        }
+    };
+    if let Some(project) = project {
+        project.apply(&mut input);
+    }
+    if let Err(e) = apply_config(&mut input) {
+        eprintln!("Error loading config: {}", e);
+        std::process::exit(1);
+    }
+    let mut result = policy::evaluate(&input);
+    if opts.fix {
+        apply_fixes(&input, &result.violations, &sources, &lossy);
+    }
+    if opts.snippets {
+        let sources = SourceMap::new(sources.iter().map(|s| (s.path.as_str(), s.text.as_str())));
+        sources.attach_snippets(&mut result.violations);
+    }
 
-                if node.kind() == "invalid_bit_string_literal" {
-                    println!(
-                        "ERROR at {}:{}-{}:{}: invalid bit string literal \"{}\"",
-                        start.row + 1,
-                        start.column + 1,
-                        end.row + 1,
-                        end.column + 1,
-                        text.chars().take(40).collect::<String>()
-                    );
-                } else if node.is_missing() {
-                    println!(
-                        "MISSING at {}:{}-{}:{}: expected {}",
-                        start.row + 1,
-                        start.column + 1,
-                        end.row + 1,
-                        end.column + 1,
-                        node.kind()
-                    );
-                } else {
-                    println!(
-                        "ERROR at {}:{}-{}:{}: \"{}\"",
-                        start.row + 1,
-                        start.column + 1,
-                        end.row + 1,
-                        end.column + 1,
-                        text.chars().take(40).collect::<String>()
-                    );
+    match opts.format {
+        Format::Json => match serde_json::to_string_pretty(&result) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("Error serializing result: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Format::Text => {
+            for v in &result.violations {
+                println!(
+                    "{}:{}: {} [{}] {}",
+                    v.file, v.line, v.severity, v.rule, v.message
+                );
+                for line in v.snippet.lines() {
+                    println!("    {}", line);
                 }
             }
+            let summary = &result.summary;
+            println!(
+                "\n{} violation(s): {} error(s), {} warning(s), {} info in {} files",
+                summary.total_violations,
+                summary.errors,
+                summary.warnings,
+                summary.info,
+                files.len()
+            );
         }
+    }
+    if result.summary.errors > 0 || unreadable {
+        std::process::exit(1);
+    }
+}
 
-        // Recurse into children
-        if cursor.goto_first_child() {
-            walk_errors(cursor, source, error_count);
-            cursor.goto_parent();
+/// Rewrites each source file with the non-conflicting fixes attached to its
+/// violations. The report still lists what was found before fixing. Files
+/// that weren't valid UTF-8 are left alone: writing back their decoded text
+/// would replace the bytes that didn't decode.
+fn apply_fixes(
+    input: &Input,
+    violations: &[Violation],
+    sources: &[SourceFile],
+    lossy: &HashSet<String>,
+) {
+    for file in lossy {
+        eprintln!("Not fixing '{}': it isn't valid UTF-8", file);
+    }
+    let fixes = fixes::file_fixes(input, violations, |file| {
+        sources
+            .iter()
+            .filter(|source| !lossy.contains(&source.path))
+            .find(|source| source.path == file)
+            .map(|source| source.text.clone())
+    });
+    let mut edits = 0;
+    for fix in &fixes {
+        if let Err(e) = fs::write(&fix.file, fix.fixed()) {
+            eprintln!("Error writing '{}': {}", fix.file, e);
+            std::process::exit(1);
         }
+        edits += fix.edits.len();
+    }
+    eprintln!("Applied {} fix(es) to {} file(s)", edits, fixes.len());
+}
+
+/// Applies the project config in the working directory, if any, and the
+/// config files nested below it to `input`.
+fn apply_config(input: &mut Input) -> Result<(), String> {
+    if let Some(path) = config::discover(Path::new(".")) {
+        config::load(&path)?.apply(input);
+    }
+    config::apply_nested(Path::new("."), input)
+}
 
-        if !cursor.goto_next_sibling() {
-            break;
+/// Lints `patterns`, then keeps watching them: after each filesystem change
+/// the changed files are re-parsed, the policy input rebuilt from the cached
+/// parse trees, and the difference sent to `vhdl_policyd` as a delta. The
+/// rules the daemon doesn't maintain come from the batch engine, which
+/// re-runs only the modules whose input tables changed. Only violations that
+/// appeared (`+`) or were resolved (`-`) are printed; the first pass reports
+/// everything as new.
+fn watch(patterns: &[String], format: Format) {
+    let fail = |msg: String| -> ! {
+        eprintln!("{}", msg);
+        std::process::exit(1);
+    };
+    let mut workspace = Workspace::new().unwrap_or_else(|e| fail(e));
+    if let Err(e) = refresh_workspace(&mut workspace, patterns, None) {
+        fail(e);
+    }
+    let bin = daemon::locate_binary();
+    let mut policyd = Daemon::spawn(&bin)
+        .unwrap_or_else(|e| fail(format!("Error starting '{}': {}", bin.display(), e)));
+    let mut modules = ModuleCache::new();
+    let input = workspace.input();
+    let mut shown = policyd
+        .init(daemon::tables(&input))
+        .unwrap_or_else(|e| fail(e));
+    shown.extend(batch_violations(input, &mut modules).unwrap_or_else(|e| fail(e)));
+    print_watch_delta(&[], &shown, format);
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)
+        .unwrap_or_else(|e| fail(format!("Error starting file watcher: {}", e)));
+    for (root, mode) in watch_roots(patterns) {
+        if let Err(e) = watcher.watch(&root, mode) {
+            fail(format!("Error watching '{}': {}", root.display(), e));
+        }
+    }
+
+    while let Ok(event) = rx.recv() {
+        let mut changed = HashSet::new();
+        let mut collect = |event: notify::Result<notify::Event>| match event {
+            Ok(event) => changed.extend(event.paths),
+            Err(e) => eprintln!("File watcher error: {}", e),
+        };
+        collect(event);
+        while let Ok(event) = rx.recv_timeout(WATCH_DEBOUNCE) {
+            collect(event);
+        }
+        match refresh_workspace(&mut workspace, patterns, Some(&changed)) {
+            Ok(false) => continue,
+            Ok(true) => {}
+            Err(e) => {
+                eprintln!("{}", e);
+                continue;
+            }
+        }
+        let input = workspace.input();
+        let mut current = policyd
+            .update(daemon::tables(&input))
+            .unwrap_or_else(|e| fail(e));
+        match batch_violations(input, &mut modules) {
+            Ok(batch) => current.extend(batch),
+            Err(e) => {
+                eprintln!("Error loading config: {}", e);
+                continue;
+            }
+        }
+        print_watch_delta(&shown, &current, format);
+        shown = current;
+    }
+}
+
+/// Violations of the rules `vhdl_policyd` doesn't maintain, from the batch
+/// engine with the project config applied; `modules` keeps each module's
+/// output between calls.
+fn batch_violations(mut input: Input, modules: &mut ModuleCache) -> Result<Vec<Violation>, String> {
+    apply_config(&mut input)?;
+    Ok(engine::evaluate_cached(&input, modules)
+        .violations
+        .into_iter()
+        .filter(|v| !daemon::RULES.contains(&v.rule.as_str()))
+        .collect())
+}
+
+/// Brings `workspace` in line with the files `patterns` match now: new files
+/// are added, vanished ones dropped, and files named in `changed` (absolute
+/// paths from the watcher; `None` means all) re-read. A file that can't be
+/// read is reported and left as it was. Returns whether any file's text
+/// actually changed.
+fn refresh_workspace(
+    workspace: &mut Workspace,
+    patterns: &[String],
+    changed: Option<&HashSet<PathBuf>>,
+) -> Result<bool, String> {
+    let files = discover_all(patterns)?;
+    let current: BTreeSet<String> = files
+        .iter()
+        .map(|path| path.to_string_lossy().to_string())
+        .collect();
+    let stale: Vec<String> = workspace
+        .paths()
+        .filter(|path| !current.contains(*path))
+        .map(str::to_string)
+        .collect();
+    let mut dirty = false;
+    for path in stale {
+        dirty |= workspace.remove(&path);
+    }
+    let known: HashSet<String> = workspace.paths().map(str::to_string).collect();
+    for path in &current {
+        let touched = changed
+            .is_none_or(|changed| fs::canonicalize(path).is_ok_and(|abs| changed.contains(&abs)));
+        if known.contains(path) && !touched {
+            continue;
+        }
+        let Some(source) = read_or_report(Path::new(path)) else {
+            continue;
+        };
+        dirty |= workspace.update(path, source.text)?;
+    }
+    Ok(dirty)
+}
+
+/// Directories (recursively) and plain files to watch for `patterns`.
+fn watch_roots(patterns: &[String]) -> Vec<(PathBuf, RecursiveMode)> {
+    let mut roots: BTreeMap<PathBuf, RecursiveMode> = BTreeMap::new();
+    for pattern in patterns {
+        let (path, mode) = if pattern.contains(['*', '?']) {
+            (split_glob(pattern).0, RecursiveMode::Recursive)
+        } else if Path::new(pattern).is_dir() {
+            (PathBuf::from(pattern), RecursiveMode::Recursive)
+        } else {
+            (PathBuf::from(pattern), RecursiveMode::NonRecursive)
+        };
+        let path = fs::canonicalize(&path).unwrap_or(path);
+        roots.entry(path).or_insert(mode);
+    }
+    roots.into_iter().collect()
+}
+
+/// Prints the violations in `current` but not `previous` as new and the
+/// reverse as resolved, then the current totals. JSON emits one
+/// `{added, resolved, summary}` object per line.
+fn print_watch_delta(previous: &[Violation], current: &[Violation], format: Format) {
+    let key = |v: &Violation| {
+        (
+            v.file.clone(),
+            v.line,
+            v.rule.clone(),
+            v.severity.clone(),
+            v.message.clone(),
+        )
+    };
+    let before: BTreeSet<_> = previous.iter().map(key).collect();
+    let after: BTreeSet<_> = current.iter().map(key).collect();
+    let mut added: Vec<&Violation> = current
+        .iter()
+        .filter(|v| !before.contains(&key(v)))
+        .collect();
+    let mut resolved: Vec<&Violation> = previous
+        .iter()
+        .filter(|v| !after.contains(&key(v)))
+        .collect();
+    added.sort_by_key(|v| key(v));
+    resolved.sort_by_key(|v| key(v));
+
+    let mut summary = Summary {
+        total_violations: current.len(),
+        ..Default::default()
+    };
+    for v in current {
+        match v.severity.as_str() {
+            "error" => summary.errors += 1,
+            "warning" => summary.warnings += 1,
+            "info" => summary.info += 1,
+            _ => {}
+        }
+    }
+
+    match format {
+        Format::Json => {
+            let delta = serde_json::json!({
+                "added": added,
+                "resolved": resolved,
+                "summary": summary,
+            });
+            println!("{}", delta);
+        }
+        Format::Text => {
+            for (sign, list) in [("+", &added), ("-", &resolved)] {
+                for v in list.iter() {
+                    println!(
+                        "{} {}:{}: {} [{}] {}",
+                        sign, v.file, v.line, v.severity, v.rule, v.message
+                    );
+                }
+            }
+            println!(
+                "{} violation(s): {} error(s), {} warning(s), {} info",
+                summary.total_violations, summary.errors, summary.warnings, summary.info
+            );
         }
     }
 }
@@ -0,0 +1,417 @@
+//! Tree-sitter parsing and syntax-error reporting, shared by the binaries and
+//! the extractor.
+
+use serde::Serialize;
+use tree_sitter::{Parser, Tree, TreeCursor};
+
+/// Longest offending text quoted in a `ParseError`.
+pub const SNIPPET_CHARS: usize = 40;
+
+/// A parse error in the policy engine's `Violation` shape, plus the column
+/// range and offending text.
+#[derive(Debug, Clone, Serialize)]
+pub struct ParseError {
+    pub rule: &'static str,
+    pub severity: &'static str,
+    pub file: String,
+    pub line: usize,
+    pub message: String,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    pub snippet: String,
+    /// What probably went wrong, when `suggest` recognizes the mistake.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub hint: String,
+    /// A token the grammar expected but did not find, rather than
+    /// unparseable text.
+    #[serde(skip)]
+    pub missing: bool,
+}
+
+/// A parser loaded with the VHDL grammar.
+pub fn new_parser() -> Result<Parser, String> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_vhdl::language())
+        .map_err(|e| format!("loading VHDL grammar: {}", e))?;
+    Ok(parser)
+}
+
+/// Parses `text` and returns its syntax errors, reported against `file`.
+pub fn parse_errors(file: &str, text: &str) -> Result<Vec<ParseError>, String> {
+    let tree = new_parser()?
+        .parse(text, None)
+        .ok_or_else(|| format!("failed to parse '{}'", file))?;
+    Ok(tree_errors(&tree, text, file))
+}
+
+/// The `ERROR`, `MISSING` and invalid bit-string nodes of an already parsed
+/// tree, in source order.
+pub fn tree_errors(tree: &Tree, source: &str, file: &str) -> Vec<ParseError> {
+    let mut errors = Vec::new();
+    walk_errors(&mut tree.root_node().walk(), source, file, &mut errors);
+    errors
+}
+
+fn walk_errors(cursor: &mut TreeCursor, source: &str, file: &str, errors: &mut Vec<ParseError>) {
+    loop {
+        let node = cursor.node();
+
+        if node.is_error() || node.is_missing() || node.kind() == "invalid_bit_string_literal" {
+            let start = node.start_position();
+            let end = node.end_position();
+            let snippet: String = node
+                .utf8_text(source.as_bytes())
+                .unwrap_or("<invalid utf8>")
+                .chars()
+                .take(SNIPPET_CHARS)
+                .collect();
+
+            let message = if node.kind() == "invalid_bit_string_literal" {
+                format!("invalid bit string literal \"{}\"", snippet)
+            } else if node.is_missing() {
+                format!("expected {}", node.kind())
+            } else {
+                format!("\"{}\"", snippet)
+            };
+            errors.push(ParseError {
+                rule: "parse_error",
+                severity: "error",
+                file: file.to_string(),
+                line: start.row + 1,
+                message,
+                column: start.column + 1,
+                end_line: end.row + 1,
+                end_column: end.column + 1,
+                snippet,
+                hint: suggest(
+                    source,
+                    node.start_byte(),
+                    node.end_byte(),
+                    node.is_missing().then(|| node.kind()),
+                )
+                .unwrap_or_default(),
+                missing: node.is_missing(),
+            });
+        }
+
+        // Recurse into children
+        if cursor.goto_first_child() {
+            walk_errors(cursor, source, file, errors);
+            cursor.goto_parent();
+        }
+
+        if !cursor.goto_next_sibling() {
+            break;
+        }
+    }
+}
+
+/// Block keywords an `end <keyword>` closes.
+const BLOCKS: &[&str] = &["block", "case", "generate", "if", "loop", "process"];
+
+/// Keywords after which a line legitimately continues without a `;`.
+const OPENERS: &[&str] = &[
+    "begin", "else", "generate", "is", "loop", "port", "generic", "then", "record", "select",
+    "units", "when", "others",
+];
+
+/// A hint for the syntax error spanning `start..end` of `source`, or `None`
+/// when nothing better than the raw text can be said. `missing` is the kind
+/// of a MISSING node. Looks for common VHDL slips: assignment/comparison
+/// operators from other languages, `=` for `:=` in declarations, misspelt
+/// `elsif` / `end if`, mismatched `end` keywords and missing semicolons.
+pub fn suggest(source: &str, start: usize, end: usize, missing: Option<&str>) -> Option<String> {
+    if let Some(kind) = missing {
+        let kind = kind.trim_start_matches("_kw_");
+        return Some(match kind {
+            ";" => "end the statement with ';'".to_string(),
+            ")" => "unbalanced parentheses - add the closing ')'".to_string(),
+            "then" => "'if' and 'elsif' conditions are followed by 'then'".to_string(),
+            "is" => "the declaration header is followed by 'is'".to_string(),
+            "identifier" => "a name is expected here".to_string(),
+            _ => format!("insert '{}' here", kind),
+        });
+    }
+
+    let code = blank_comments_and_strings(source);
+    let start = start.min(code.len());
+    let end = end.clamp(start, code.len());
+    let line_start = code[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = code[end..].find('\n').map_or(code.len(), |i| end + i);
+    let line = code[line_start..line_end].trim().to_ascii_lowercase();
+    let line_no = code[..start].matches('\n').count() + 1;
+
+    for (typo, hint) in [
+        ("elseif", "VHDL spells it 'elsif'"),
+        (
+            "else if",
+            "use 'elsif', or close the nested 'if' with its own 'end if'",
+        ),
+        ("endif", "write 'end if;' as two words"),
+        ("==", "VHDL compares with a single '='"),
+        ("!=", "VHDL's inequality operator is '/='"),
+        ("&&", "use 'and' instead of '&&'"),
+        ("||", "use 'or' instead of '||'"),
+    ] {
+        if contains_word(&line, typo) {
+            return Some(hint.to_string());
+        }
+    }
+
+    if let Some(hint) = end_mismatch(
+        &code,
+        code[..start].matches('\n').count().saturating_sub(2),
+        end,
+    ) {
+        return Some(hint);
+    }
+
+    let first = line
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .next()
+        .unwrap_or_default();
+    if matches!(first, "signal" | "variable" | "constant" | "shared") {
+        if let Some((_, rest)) = line.split_once(':') {
+            if !rest.starts_with('=') && rest.replace(":=", "").replace("=>", "").contains('=') {
+                return Some(format!(
+                    "initial values are given with ':=' (e.g. '{} x : std_logic := '0';')",
+                    first
+                ));
+            }
+        }
+    }
+    if is_plain_assignment(&line) {
+        return Some("'=' compares; assign signals with '<=' and variables with ':='".to_string());
+    }
+
+    let previous = code[..line_start]
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|text| !text.is_empty())?;
+    let last_word = previous
+        .rsplit(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let continues = previous.ends_with([';', '(', ',', '&', '>'])
+        || previous.ends_with("<=")
+        || previous.ends_with(":=")
+        || OPENERS.contains(&last_word.as_str());
+    let previous_no = code[..line_start].trim_end().matches('\n').count() + 1;
+    (!continues && previous_no < line_no)
+        .then(|| format!("line {} probably lacks a ';' at its end", previous_no))
+}
+
+/// `source` with comments, string and character literals replaced by spaces,
+/// so byte offsets and line numbers stay valid.
+fn blank_comments_and_strings(source: &str) -> String {
+    let bytes = source.as_bytes();
+    let mut out = bytes.to_vec();
+    let mut i = 0;
+    while i < bytes.len() {
+        let blank_until = match bytes[i] {
+            b'-' if bytes.get(i + 1) == Some(&b'-') => bytes[i..]
+                .iter()
+                .position(|&b| b == b'\n')
+                .map_or(bytes.len(), |n| i + n),
+            b'"' => bytes[i + 1..]
+                .iter()
+                .position(|&b| b == b'"' || b == b'\n')
+                .map_or(bytes.len(), |n| i + n + 2),
+            // A character literal, not an attribute tick (`clk'event`).
+            b'\''
+                if bytes.get(i + 2) == Some(&b'\'')
+                    && !(i > 0
+                        && (bytes[i - 1].is_ascii_alphanumeric() || bytes[i - 1] == b')')) =>
+            {
+                i + 3
+            }
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+        let blank_until = blank_until.min(bytes.len());
+        for b in &mut out[i..blank_until] {
+            if *b != b'\n' && b.is_ascii() {
+                *b = b' ';
+            }
+        }
+        i = blank_until;
+    }
+    String::from_utf8(out).unwrap_or_else(|_| source.to_string())
+}
+
+/// Whether `needle` occurs in `line` as a whole word (or, for operators, at all).
+fn contains_word(line: &str, needle: &str) -> bool {
+    if !needle.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return line.contains(needle);
+    }
+    line.match_indices(needle).any(|(at, _)| {
+        let before = line[..at].chars().next_back();
+        let after = line[at + needle.len()..].chars().next();
+        !before.is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+            && !after.is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+/// `name = value;` or `name(i) = value;` as a statement of its own.
+fn is_plain_assignment(line: &str) -> bool {
+    let Some((target, value)) = line.split_once('=') else {
+        return false;
+    };
+    let target = target.trim();
+    let keyword = target
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .next()
+        .unwrap_or_default();
+    !target.is_empty()
+        && !target.ends_with(['<', ':', '/', '>', '?'])
+        && !value.starts_with(['=', '>'])
+        && !matches!(
+            keyword,
+            "if" | "elsif" | "when" | "while" | "until" | "assert" | "return" | "report"
+        )
+        && target
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_.() ".contains(c))
+        && line.trim_end().ends_with(';')
+}
+
+/// An `end <keyword>` closing a different block than the innermost open one,
+/// or a unit's `end` with a block still open, found between line `from_line`
+/// (0-based) and byte `until`.
+fn end_mismatch(code: &str, from_line: usize, until: usize) -> Option<String> {
+    let mut words: Vec<(String, usize)> = Vec::new();
+    let mut line = 1;
+    let mut word_start = None;
+    for (i, c) in code[..until].char_indices().chain([(until, ' ')]) {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            word_start.get_or_insert(i);
+            continue;
+        }
+        if let Some(at) = word_start.take() {
+            words.push((code[at..i].to_ascii_lowercase(), line));
+        }
+        if c == ';' {
+            words.push((";".to_string(), line));
+        } else if c == '\n' {
+            line += 1;
+        }
+    }
+
+    // (block, line it opened on, opened). Pending entries (`if` before
+    // `then`, `for` before `loop`, `case` before `is`) wait for the word that
+    // makes them a block and are dropped at `;`.
+    let mut stack: Vec<(&str, usize, bool)> = Vec::new();
+    let mut words = words.iter().map(|(word, line)| (word.as_str(), *line));
+    while let Some((word, line)) = words.next() {
+        let pending = stack.last_mut().filter(|top| !top.2);
+        match word {
+            ";" => stack.retain(|entry| entry.2),
+            "if" => stack.push(("if", line, false)),
+            "case" => stack.push(("case", line, false)),
+            "for" | "while" => stack.push(("loop", line, false)),
+            "then" | "is" | "loop" => match pending {
+                Some(top)
+                    if matches!(
+                        (top.0, word),
+                        ("if", "then") | ("case", "is") | ("loop", "loop")
+                    ) =>
+                {
+                    top.2 = true;
+                }
+                _ if word == "loop" => stack.push(("loop", line, true)),
+                _ => {}
+            },
+            // `elsif ... generate` and `else generate` continue an open one.
+            "generate" => {
+                if let Some(top) = pending {
+                    *top = ("generate", top.1, true);
+                }
+            }
+            "process" => stack.push(("process", line, true)),
+            "block" => stack.push(("block", line, true)),
+            "end" => {
+                stack.retain(|entry| entry.2);
+                let closes = words.next().map_or(";", |(next, _)| next);
+                let Some(&(open, open_line, _)) = stack.last() else {
+                    continue;
+                };
+                if !BLOCKS.contains(&closes) {
+                    // `end architecture`, `end function`, `end;`: nothing may be left open.
+                    if line > from_line {
+                        return Some(format!(
+                            "the '{}' opened on line {} is never closed - add 'end {};' before this 'end'",
+                            open, open_line, open
+                        ));
+                    }
+                    stack.clear();
+                } else if open != closes {
+                    if line > from_line {
+                        return Some(format!(
+                            "'end {}' does not match the '{}' opened on line {} - add 'end {};' first",
+                            closes, open, open_line, open
+                        ));
+                    }
+                    let pos = stack.iter().rposition(|entry| entry.0 == closes);
+                    stack.truncate(pos.unwrap_or(stack.len() - 1));
+                } else {
+                    stack.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hint_at(source: &str, needle: &str) -> Option<String> {
+        let start = source.find(needle).unwrap();
+        suggest(source, start, start + needle.len(), None)
+    }
+
+    #[test]
+    fn suggests_fixes_for_common_slips() {
+        assert_eq!(
+            suggest("", 0, 0, Some(";")).as_deref(),
+            Some("end the statement with ';'")
+        );
+        assert_eq!(
+            hint_at("  signal s : bit = '0';\n", "= '0'").as_deref(),
+            Some("initial values are given with ':=' (e.g. 'signal x : std_logic := '0';')")
+        );
+        assert_eq!(
+            hint_at("  variable v : integer <= 0;\n", "<= 0").as_deref(),
+            Some("initial values are given with ':=' (e.g. 'variable x : std_logic := '0';')")
+        );
+        assert_eq!(hint_at("  constant c : t := (a => b) x;\n", "x;"), None);
+        assert_eq!(
+            hint_at("begin\n  y = a and b;\n", "= a").as_deref(),
+            Some("'=' compares; assign signals with '<=' and variables with ':='")
+        );
+        assert_eq!(
+            hint_at("if a = '1' then\n  y <= a;\nelseif b = '1' then", "elseif").as_deref(),
+            Some("VHDL spells it 'elsif'")
+        );
+        assert_eq!(
+            hint_at("  x <= a -- no semicolon; here\n  y <= b;\n", "y <= b").as_deref(),
+            Some("line 1 probably lacks a ';' at its end")
+        );
+
+        let unclosed = "p : process(clk)\nbegin\n  if rising_edge(clk) then\n    q <= d;\n  for i in 0 to 3 loop\n  end loop;\nend process;\n";
+        assert_eq!(
+            hint_at(unclosed, "end process").as_deref(),
+            Some("'end process' does not match the 'if' opened on line 3 - add 'end if;' first")
+        );
+        let wait = "p : process\nbegin\n  wait for 10 ns;\n  if a = '1' then\n    b <= '1';\n  end if;\nend process;\n";
+        assert_eq!(hint_at(wait, "end process"), None);
+    }
+}
@@ -0,0 +1,238 @@
+//! Baselines of accepted violations, for adopting the linter on an existing
+//! codebase: `--write-baseline` records every current violation, later runs
+//! with `--baseline` report only the ones that aren't in it.
+//!
+//! A violation is identified by a fingerprint of its rule, file and message,
+//! so moving code up or down a file doesn't bring it back. Line numbers
+//! quoted in a message (`at line 40`) are left out of the fingerprint for the
+//! same reason. Entries carry a count: a baseline with two identical findings
+//! still reports a third.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::policy::result::Violation;
+
+const BASELINE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct Baseline {
+    #[serde(default)]
+    pub version: u32,
+    #[serde(default)]
+    pub entries: Vec<BaselineEntry>,
+}
+
+/// Rule, file and message are informational, for reviewing the baseline;
+/// only the fingerprint and count are matched.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct BaselineEntry {
+    pub fingerprint: String,
+    #[serde(default)]
+    pub rule: String,
+    #[serde(default)]
+    pub file: String,
+    #[serde(default)]
+    pub message: String,
+    #[serde(default = "one")]
+    pub count: usize,
+}
+
+fn one() -> usize {
+    1
+}
+
+impl Baseline {
+    /// Baseline accepting every violation in `violations`, sorted by file,
+    /// rule and message so the file diffs cleanly between runs.
+    pub fn from_violations(violations: &[Violation]) -> Baseline {
+        let mut entries: BTreeMap<(String, String, String), BaselineEntry> = BTreeMap::new();
+        for v in violations {
+            entries
+                .entry((v.file.clone(), v.rule.clone(), v.message.clone()))
+                .or_insert_with(|| BaselineEntry {
+                    fingerprint: fingerprint(v),
+                    rule: v.rule.clone(),
+                    file: v.file.clone(),
+                    message: v.message.clone(),
+                    count: 0,
+                })
+                .count += 1;
+        }
+        Baseline {
+            version: BASELINE_VERSION,
+            entries: entries.into_values().collect(),
+        }
+    }
+
+    /// Accepted count per fingerprint, the form `lint_config.baseline` takes.
+    pub fn counts(&self) -> HashMap<String, usize> {
+        let mut counts = HashMap::new();
+        for entry in &self.entries {
+            *counts.entry(entry.fingerprint.clone()).or_insert(0) += entry.count;
+        }
+        counts
+    }
+}
+
+/// 16 hex digits of FNV-1a over the rule, file and line-free message. FNV is
+/// used over `DefaultHasher` because baselines are committed and must hash
+/// the same on every toolchain.
+pub fn fingerprint(v: &Violation) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in [
+        v.rule.as_str(),
+        v.file.as_str(),
+        &without_line_numbers(&v.message),
+    ] {
+        for byte in part.bytes().chain([0]) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+/// `fingerprint` of each of `violations`, with an occurrence suffix (`-2`,
+/// `-3`, ...) on repeats of the same finding, so every violation of a result
+/// has its own id and the ids stay put when unrelated code moves.
+pub fn occurrence_fingerprints(violations: &[Violation]) -> Vec<String> {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    violations
+        .iter()
+        .map(|v| {
+            let base = fingerprint(v);
+            let count = seen.entry(base.clone()).or_insert(0);
+            *count += 1;
+            match *count {
+                1 => base,
+                n => format!("{}-{}", base, n),
+            }
+        })
+        .collect()
+}
+
+/// `message` with the numbers after "line"/"lines" replaced by `#`
+/// (`at line 40` -> `at line #`, `lines 3-9` -> `lines #-#`).
+fn without_line_numbers(message: &str) -> String {
+    let mut out = String::with_capacity(message.len());
+    let mut rest = message;
+    while let Some(pos) = find_line_word(rest) {
+        let (head, tail) = rest.split_at(pos);
+        out.push_str(head);
+        let word_len = if tail
+            .get(..6)
+            .is_some_and(|word| word.eq_ignore_ascii_case("lines "))
+        {
+            6
+        } else {
+            5
+        };
+        out.push_str(&tail[..word_len]);
+        let mut chars = tail[word_len..].char_indices().peekable();
+        let mut end = 0;
+        let mut in_number = false;
+        while let Some(&(idx, ch)) = chars.peek() {
+            if ch.is_ascii_digit() {
+                if !in_number {
+                    out.push('#');
+                    in_number = true;
+                }
+            } else if ch == '-' && in_number {
+                out.push('-');
+                in_number = false;
+            } else {
+                break;
+            }
+            end = idx + ch.len_utf8();
+            chars.next();
+        }
+        rest = &tail[word_len + end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Start of the next "line " or "lines " that begins a word.
+fn find_line_word(text: &str) -> Option<usize> {
+    let lower = text.to_ascii_lowercase();
+    let mut from = 0;
+    while let Some(found) = lower[from..].find("line") {
+        let pos = from + found;
+        let after = &lower[pos + 4..];
+        let word_start = pos == 0 || !lower.as_bytes()[pos - 1].is_ascii_alphanumeric();
+        if word_start && (after.starts_with(' ') || after.starts_with("s ")) {
+            return Some(pos);
+        }
+        from = pos + 4;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn violation(rule: &str, file: &str, line: usize, message: &str) -> Violation {
+        Violation {
+            rule: rule.to_string(),
+            severity: "warning".to_string(),
+            file: file.to_string(),
+            line,
+            message: message.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn fingerprint_ignores_line_drift() {
+        let old = violation(
+            "unused_signal",
+            "a.vhd",
+            10,
+            "Signal 'x' is unused (declared at line 10)",
+        );
+        let moved = violation(
+            "unused_signal",
+            "a.vhd",
+            42,
+            "Signal 'x' is unused (declared at line 42)",
+        );
+        assert_eq!(fingerprint(&old), fingerprint(&moved));
+        assert_eq!(fingerprint(&old).len(), 16);
+
+        let other_file = violation("unused_signal", "b.vhd", 10, &old.message);
+        let other_signal = violation(
+            "unused_signal",
+            "a.vhd",
+            10,
+            "Signal 'y' is unused (declared at line 10)",
+        );
+        assert_ne!(fingerprint(&old), fingerprint(&other_file));
+        assert_ne!(fingerprint(&old), fingerprint(&other_signal));
+
+        assert_eq!(
+            without_line_numbers("Baseline lines 3-9, pipeline 2, Line 7."),
+            "Baseline lines #-#, pipeline 2, Line #."
+        );
+    }
+
+    #[test]
+    fn baseline_counts_duplicates() {
+        let violations = vec![
+            violation("latch", "b.vhd", 5, "Latch on 'q'"),
+            violation("latch", "b.vhd", 9, "Latch on 'q'"),
+            violation("unused_signal", "a.vhd", 1, "Signal 'x' is unused"),
+        ];
+        let baseline = Baseline::from_violations(&violations);
+        assert_eq!(baseline.version, BASELINE_VERSION);
+        assert_eq!(baseline.entries.len(), 2);
+        assert_eq!(baseline.entries[0].file, "a.vhd");
+        assert_eq!(baseline.entries[1].count, 2);
+
+        let json = serde_json::to_string(&baseline).unwrap();
+        let parsed: Baseline = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, baseline);
+        assert_eq!(parsed.counts()[&fingerprint(&violations[0])], 2);
+    }
+}
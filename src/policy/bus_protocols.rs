@@ -0,0 +1,235 @@
+//! Standard bus interfaces on entity ports: AXI4-Lite, AXI-Stream and APB,
+//! recognized from groups of ports sharing a prefix (`s_axi_awvalid`,
+//! `s_axi_awready`, ...) whose modes agree with one side of the protocol.
+//! Verification binds the channel signals like a ready/valid construct and
+//! requires the protocol's check family.
+
+use crate::policy::input::{Input, Port};
+use std::collections::BTreeMap;
+
+/// A signal of a protocol and whether the initiator (manager) drives it.
+struct Role {
+    suffix: &'static str,
+    from_initiator: bool,
+}
+
+const fn role(suffix: &'static str, from_initiator: bool) -> Role {
+    Role {
+        suffix,
+        from_initiator,
+    }
+}
+
+pub struct Protocol {
+    /// Construct kind and check family prefix.
+    pub name: &'static str,
+    roles: &'static [Role],
+    /// Registry check ids an interface requires.
+    pub checks: &'static [&'static str],
+}
+
+pub const PROTOCOLS: &[Protocol] = &[
+    Protocol {
+        name: "axi_lite",
+        roles: &[
+            role("awvalid", true),
+            role("awready", false),
+            role("awaddr", true),
+            role("wvalid", true),
+            role("wready", false),
+            role("wdata", true),
+            role("bvalid", false),
+            role("bready", true),
+            role("bresp", false),
+            role("arvalid", true),
+            role("arready", false),
+            role("araddr", true),
+            role("rvalid", false),
+            role("rready", true),
+            role("rdata", false),
+            role("rresp", false),
+        ],
+        checks: &[
+            "axil.stable_while_stalled",
+            "axil.valid_known",
+            "axil.response_order",
+            "cover.axil.transaction",
+        ],
+    },
+    Protocol {
+        name: "axi_stream",
+        roles: &[
+            role("tvalid", true),
+            role("tready", false),
+            role("tdata", true),
+        ],
+        checks: &[
+            "axis.stable_while_stalled",
+            "axis.valid_known",
+            "cover.axis.transfer",
+        ],
+    },
+    Protocol {
+        name: "apb",
+        roles: &[
+            role("psel", true),
+            role("penable", true),
+            role("pwrite", true),
+            role("paddr", true),
+            role("pready", false),
+        ],
+        checks: &[
+            "apb.stable_while_stalled",
+            "apb.access_follows_setup",
+            "apb.valid_known",
+            "cover.apb.transfer",
+        ],
+    },
+];
+
+/// One interface on an entity. `bindings` maps each role (the protocol's
+/// signal name, e.g. `awvalid`) to the port implementing it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BusInterface {
+    pub protocol: &'static str,
+    pub entity: String,
+    /// Port name prefix shared by the group, e.g. `s_axi_`.
+    pub prefix: String,
+    /// Whether the entity is the manager side (drives valid/psel).
+    pub initiator: bool,
+    pub line: usize,
+    pub bindings: BTreeMap<String, String>,
+}
+
+pub fn protocol(name: &str) -> Option<&'static Protocol> {
+    PROTOCOLS.iter().find(|p| p.name == name)
+}
+
+/// Every complete interface on every entity, in entity and prefix order.
+/// A group missing a role or with a port of the wrong mode isn't one.
+pub fn interfaces(input: &Input) -> Vec<BusInterface> {
+    let mut by_entity: BTreeMap<String, Vec<&Port>> = BTreeMap::new();
+    for port in &input.ports {
+        by_entity
+            .entry(port.in_entity.to_ascii_lowercase())
+            .or_default()
+            .push(port);
+    }
+    let mut out = Vec::new();
+    for ports in by_entity.values() {
+        for protocol in PROTOCOLS {
+            out.extend(protocol_interfaces(protocol, ports));
+        }
+    }
+    out
+}
+
+fn protocol_interfaces(protocol: &Protocol, ports: &[&Port]) -> Vec<BusInterface> {
+    let mut groups: BTreeMap<String, BTreeMap<&str, &Port>> = BTreeMap::new();
+    for port in ports {
+        let name = port.name.to_ascii_lowercase();
+        for role in protocol.roles {
+            if let Some(prefix) = name.strip_suffix(role.suffix) {
+                groups
+                    .entry(prefix.to_string())
+                    .or_default()
+                    .insert(role.suffix, port);
+            }
+        }
+    }
+    groups
+        .into_iter()
+        .filter_map(|(prefix, found)| {
+            if found.len() != protocol.roles.len() {
+                return None;
+            }
+            let drives = |port: &Port| {
+                let mode = port.direction.to_ascii_lowercase();
+                mode == "out" || mode == "buffer"
+            };
+            let first = protocol.roles.first()?;
+            let initiator = drives(found[first.suffix]) == first.from_initiator;
+            let consistent = protocol
+                .roles
+                .iter()
+                .all(|role| drives(found[role.suffix]) == (role.from_initiator == initiator));
+            if !consistent {
+                return None;
+            }
+            let port = found[first.suffix];
+            Some(BusInterface {
+                protocol: protocol.name,
+                entity: port.in_entity.clone(),
+                prefix,
+                initiator,
+                line: found.values().map(|p| p.line).min().unwrap_or(port.line),
+                bindings: found
+                    .iter()
+                    .map(|(role, port)| (role.to_string(), port.name.clone()))
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn port(name: &str, direction: &str) -> Port {
+        Port {
+            name: name.to_string(),
+            direction: direction.to_string(),
+            r#type: "std_logic".to_string(),
+            in_entity: "dma".to_string(),
+            line: 4,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn groups_ports_by_prefix_and_side() {
+        let mut input = Input::default();
+        for (name, dir) in [
+            ("s_axis_tvalid", "in"),
+            ("s_axis_tready", "out"),
+            ("s_axis_tdata", "in"),
+            ("m_axis_tvalid", "out"),
+            ("m_axis_tready", "in"),
+            ("m_axis_tdata", "out"),
+            // tdata points the wrong way: not an interface.
+            ("x_axis_tvalid", "out"),
+            ("x_axis_tready", "in"),
+            ("x_axis_tdata", "in"),
+            ("PSEL", "in"),
+            ("PENABLE", "in"),
+            ("PWRITE", "in"),
+            ("PADDR", "in"),
+        ] {
+            input.ports.push(port(name, dir));
+        }
+
+        let found = interfaces(&input);
+        let summary: Vec<_> = found
+            .iter()
+            .map(|i| (i.protocol, i.prefix.as_str(), i.initiator))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("axi_stream", "m_axis_", true),
+                ("axi_stream", "s_axis_", false)
+            ]
+        );
+        assert_eq!(found[1].bindings["tready"], "s_axis_tready");
+
+        input.ports.push(port("PREADY", "out"));
+        let apb = interfaces(&input)
+            .into_iter()
+            .find(|i| i.protocol == "apb")
+            .unwrap();
+        assert_eq!(apb.prefix, "");
+        assert!(!apb.initiator);
+        assert_eq!(apb.bindings["psel"], "PSEL");
+    }
+}
@@ -1,17 +1,59 @@
-use crate::policy::input::Input;
+use std::borrow::Cow;
+
+use crate::policy::clock_domains;
+use crate::policy::helpers;
+use crate::policy::input::{CDCCrossing, Input};
 use crate::policy::result::Violation;
 
 pub fn violations(input: &Input) -> Vec<Violation> {
+    let crossings = crossings(input);
     let mut out = Vec::new();
-    out.extend(cdc_unsync_single_bit(input));
-    out.extend(cdc_unsync_multi_bit(input));
-    out.extend(cdc_insufficient_sync(input));
+    out.extend(cdc_unsync_single_bit(&crossings));
+    out.extend(cdc_unsync_multi_bit(&crossings));
+    out.extend(cdc_insufficient_sync(&crossings));
     out
 }
 
-fn cdc_unsync_single_bit(input: &Input) -> Vec<Violation> {
-    input
-        .cdc_crossings
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "architectures",
+        "cdc_crossings",
+        "entities",
+        "processes",
+        "signals",
+    ]
+}
+
+/// The extractor's crossings, or ones inferred from the clock domains when it
+/// reported none (e.g. input built by the Rust extractor). Crossings between
+/// clocks declared in the same domain are dropped.
+pub fn crossings(input: &Input) -> Cow<'_, [CDCCrossing]> {
+    let crossings = if input.cdc_crossings.is_empty() {
+        Cow::Owned(clock_domains::infer(input).crossings)
+    } else {
+        Cow::Borrowed(&input.cdc_crossings[..])
+    };
+    if crossings.iter().all(|cdc| !same_domain(input, cdc)) {
+        return crossings;
+    }
+    Cow::Owned(
+        crossings
+            .iter()
+            .filter(|cdc| !same_domain(input, cdc))
+            .cloned()
+            .collect(),
+    )
+}
+
+fn same_domain(input: &Input, cdc: &CDCCrossing) -> bool {
+    helpers::declared_clock(input, &cdc.source_clock).is_some()
+        && helpers::declared_clock(input, &cdc.dest_clock).is_some()
+        && helpers::clock_domain(input, &cdc.source_clock)
+            == helpers::clock_domain(input, &cdc.dest_clock)
+}
+
+fn cdc_unsync_single_bit(crossings: &[CDCCrossing]) -> Vec<Violation> {
+    crossings
         .iter()
         .filter(|cdc| !cdc.is_synchronized && !cdc.is_multi_bit)
         .map(|cdc| Violation {
@@ -23,13 +65,13 @@ fn cdc_unsync_single_bit(input: &Input) -> Vec<Violation> {
                 "Signal '{}' crosses from {} to {} clock domain without synchronizer",
                 cdc.signal, cdc.source_clock, cdc.dest_clock
             ),
+            ..Default::default()
         })
         .collect()
 }
 
-fn cdc_unsync_multi_bit(input: &Input) -> Vec<Violation> {
-    input
-        .cdc_crossings
+fn cdc_unsync_multi_bit(crossings: &[CDCCrossing]) -> Vec<Violation> {
+    crossings
         .iter()
         .filter(|cdc| !cdc.is_synchronized && cdc.is_multi_bit)
         .map(|cdc| Violation {
@@ -41,13 +83,13 @@ fn cdc_unsync_multi_bit(input: &Input) -> Vec<Violation> {
                 "Multi-bit signal '{}' crosses from {} to {} clock domain - requires handshaking or Gray code",
                 cdc.signal, cdc.source_clock, cdc.dest_clock
             ),
+            ..Default::default()
         })
         .collect()
 }
 
-fn cdc_insufficient_sync(input: &Input) -> Vec<Violation> {
-    input
-        .cdc_crossings
+fn cdc_insufficient_sync(crossings: &[CDCCrossing]) -> Vec<Violation> {
+    crossings
         .iter()
         .filter(|cdc| cdc.is_synchronized && cdc.sync_stages < 2)
         .map(|cdc| Violation {
@@ -59,6 +101,7 @@ fn cdc_insufficient_sync(input: &Input) -> Vec<Violation> {
                 "Signal '{}' has only {} synchronizer stage(s), recommend 2+",
                 cdc.signal, cdc.sync_stages
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -66,7 +109,7 @@ fn cdc_insufficient_sync(input: &Input) -> Vec<Violation> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::policy::input::{CDCCrossing, Input};
+    use crate::policy::input::{CDCCrossing, Input, Process};
 
     #[test]
     fn cdc_unsync_single_bit_flags() {
@@ -81,8 +124,31 @@ mod tests {
             line: 1,
             ..Default::default()
         });
-        let v = cdc_unsync_single_bit(&input);
+        let v = cdc_unsync_single_bit(&input.cdc_crossings);
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[0].rule, "cdc_unsync_single_bit");
+    }
+
+    #[test]
+    fn infers_crossings_when_extractor_reports_none() {
+        let mut input = Input::default();
+        for (label, clock, assigned, read) in [
+            ("p_a", "clk_a", "pulse", "start"),
+            ("p_b", "clk_b", "seen", "pulse"),
+        ] {
+            input.processes.push(Process {
+                label: label.to_string(),
+                is_sequential: true,
+                clock_signal: clock.to_string(),
+                assigned_signals: vec![assigned.to_string()],
+                read_signals: vec![read.to_string(), "en".to_string()],
+                in_arch: "rtl".to_string(),
+                ..Default::default()
+            });
+        }
+        let v = violations(&input);
         assert_eq!(v.len(), 1);
         assert_eq!(v[0].rule, "cdc_unsync_single_bit");
+        assert!(v[0].message.contains("'pulse' crosses from clk_a to clk_b"));
     }
 }
@@ -0,0 +1,122 @@
+use crate::policy::result::Result;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Checkstyle XML report, the format Jenkins' warnings-ng plugin (and most
+/// CI dashboards) ingest. Files are listed in path order; each keeps its
+/// violations in result order. The rule is carried in `source` as
+/// `vhdl-lint.<rule>`.
+pub fn checkstyle_xml(result: &Result) -> String {
+    let mut files: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    for (i, v) in result.violations.iter().enumerate() {
+        let path = v.file.trim_start_matches("./").replace('\\', "/");
+        files.entry(path).or_default().push(i);
+    }
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<checkstyle version=\"4.3\">\n");
+    for (path, indices) in &files {
+        let _ = writeln!(out, "  <file name=\"{}\">", escape(path));
+        for &i in indices {
+            let v = &result.violations[i];
+            let _ = writeln!(
+                out,
+                "    <error line=\"{}\" severity=\"{}\" message=\"{}\" source=\"vhdl-lint.{}\"/>",
+                v.line,
+                severity(&v.severity),
+                escape(&v.message),
+                escape(&v.rule)
+            );
+        }
+        out.push_str("  </file>\n");
+    }
+    out.push_str("</checkstyle>\n");
+    out
+}
+
+fn severity(severity: &str) -> &'static str {
+    match severity {
+        "error" => "error",
+        "warning" => "warning",
+        _ => "info",
+    }
+}
+
+/// Escapes text for a double-quoted XML attribute.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\n' => out.push_str("&#10;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::result::Violation;
+
+    fn violation(rule: &str, severity: &str, file: &str, line: usize, message: &str) -> Violation {
+        Violation {
+            rule: rule.to_string(),
+            severity: severity.to_string(),
+            file: file.to_string(),
+            line,
+            message: message.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn groups_violations_by_file() {
+        let result = Result {
+            violations: vec![
+                violation("unused_signal", "info", "./rtl/b.vhd", 4, "unused"),
+                violation("missing_reset", "warning", "rtl/a.vhd", 9, "no reset"),
+                violation("latch_inferred", "error", "rtl/b.vhd", 2, "latch"),
+            ],
+            ..Default::default()
+        };
+        let xml = checkstyle_xml(&result);
+        let expected = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+<checkstyle version=\"4.3\">\n  \
+<file name=\"rtl/a.vhd\">\n    \
+<error line=\"9\" severity=\"warning\" message=\"no reset\" source=\"vhdl-lint.missing_reset\"/>\n  \
+</file>\n  \
+<file name=\"rtl/b.vhd\">\n    \
+<error line=\"4\" severity=\"info\" message=\"unused\" source=\"vhdl-lint.unused_signal\"/>\n    \
+<error line=\"2\" severity=\"error\" message=\"latch\" source=\"vhdl-lint.latch_inferred\"/>\n  \
+</file>\n\
+</checkstyle>\n";
+        assert_eq!(xml, expected);
+    }
+
+    #[test]
+    fn escapes_attribute_text() {
+        let result = Result {
+            violations: vec![violation(
+                "magic_number",
+                "info",
+                "top.vhd",
+                1,
+                "value \"x\" <= 'a' & b",
+            )],
+            ..Default::default()
+        };
+        let xml = checkstyle_xml(&result);
+        assert!(xml.contains("message=\"value &quot;x&quot; &lt;= &apos;a&apos; &amp; b\""));
+    }
+
+    #[test]
+    fn empty_result_is_an_empty_report() {
+        let xml = checkstyle_xml(&Result::default());
+        assert!(xml.ends_with("<checkstyle version=\"4.3\">\n</checkstyle>\n"));
+    }
+}
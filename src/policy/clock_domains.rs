@@ -0,0 +1,276 @@
+//! Clock-domain inference. Each clocked process belongs to the domain of its
+//! `clock_signal` (per architecture), or to the declared domain of that clock
+//! (`lint_config.clocks`), and so does every signal it assigns. A
+//! register read by a clocked process of another domain is a crossing; a
+//! crossing is synchronized when the reading process copies it straight into
+//! a register, and each further plain copy in that domain adds a stage.
+
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+use crate::policy::elaboration;
+use crate::policy::helpers;
+use crate::policy::input::{CDCCrossing, Input, Process};
+use crate::policy::result::{ClockDomain, ClockDomainMap};
+
+/// Plain copies followed when counting synchronizer stages.
+const MAX_SYNC_STAGES: usize = 8;
+
+/// Domains in order of their first process, and crossings in process order.
+pub fn infer(input: &Input) -> ClockDomainMap {
+    let clocked: Vec<&Process> = input
+        .processes
+        .iter()
+        .filter(|proc| proc.is_sequential && !proc.clock_signal.is_empty())
+        .filter(|proc| !helpers::process_in_testbench(input, proc))
+        .collect();
+
+    let mut domains: Vec<ClockDomain> = Vec::new();
+    let mut domain_ids: HashMap<(String, String, String), usize> = HashMap::new();
+    let mut proc_domains = Vec::with_capacity(clocked.len());
+    let mut owners: HashMap<(String, String, String), (usize, &Process)> = HashMap::new();
+    for proc in &clocked {
+        let key = (
+            proc.file.clone(),
+            proc.in_arch.to_ascii_lowercase(),
+            helpers::clock_domain(input, &proc.clock_signal),
+        );
+        let id = *domain_ids.entry(key).or_insert_with(|| {
+            domains.push(ClockDomain {
+                clock: proc.clock_signal.clone(),
+                domain: helpers::declared_clock(input, &proc.clock_signal)
+                    .map(|clock| clock.domain.clone())
+                    .unwrap_or_default(),
+                in_arch: proc.in_arch.clone(),
+                file: proc.file.clone(),
+                line: proc.line,
+                processes: Vec::new(),
+                registers: Vec::new(),
+            });
+            domains.len() - 1
+        });
+        proc_domains.push(id);
+        domains[id].processes.push(helpers::process_name(proc));
+        for sig in &proc.assigned_signals {
+            if !helpers::is_actual_signal(input, sig) {
+                continue;
+            }
+            if let Entry::Vacant(entry) = owners.entry(signal_key(proc, sig)) {
+                entry.insert((id, proc));
+                domains[id].registers.push(sig.clone());
+            }
+        }
+    }
+
+    let mut crossings = Vec::new();
+    let mut seen = HashSet::new();
+    for (idx, (proc, &dest)) in clocked.iter().zip(&proc_domains).enumerate() {
+        for read in &proc.read_signals {
+            let Some(&(source, source_proc)) = owners.get(&signal_key(proc, read)) else {
+                continue;
+            };
+            if source == dest || !seen.insert((idx, read.to_ascii_lowercase())) {
+                continue;
+            }
+            let domain_procs: Vec<&Process> = clocked
+                .iter()
+                .zip(&proc_domains)
+                .filter(|(_, &id)| id == dest)
+                .map(|(proc, _)| *proc)
+                .collect();
+            let stages = sync_stages(proc, &domain_procs, read);
+            crossings.push(CDCCrossing {
+                signal: read.clone(),
+                source_clock: domains[source].clock.clone(),
+                dest_clock: domains[dest].clock.clone(),
+                is_synchronized: stages > 0,
+                sync_stages: stages,
+                is_multi_bit: signal_width(input, &proc.in_arch, read) > 1,
+                source_proc: source_proc.label.clone(),
+                dest_proc: proc.label.clone(),
+                file: proc.file.clone(),
+                line: read_line(proc, read),
+                in_arch: proc.in_arch.clone(),
+            });
+        }
+    }
+
+    ClockDomainMap { domains, crossings }
+}
+
+fn signal_key(proc: &Process, sig: &str) -> (String, String, String) {
+    (
+        proc.file.clone(),
+        proc.in_arch.to_ascii_lowercase(),
+        sig.to_ascii_lowercase(),
+    )
+}
+
+/// Registers in a chain of plain copies starting at `signal` in `dest`, then
+/// through any process of the destination domain.
+fn sync_stages(dest: &Process, domain: &[&Process], signal: &str) -> usize {
+    let Some(mut current) = copy_target(&[dest], signal) else {
+        return 0;
+    };
+    let mut visited = HashSet::from([signal.to_ascii_lowercase()]);
+    let mut stages = 1;
+    while stages < MAX_SYNC_STAGES && visited.insert(current.to_ascii_lowercase()) {
+        let Some(next) = copy_target(domain, current) else {
+            break;
+        };
+        current = next;
+        stages += 1;
+    }
+    stages
+}
+
+/// Target of the first `target <= signal` assignment in `procs`.
+fn copy_target<'a>(procs: &[&'a Process], signal: &str) -> Option<&'a str> {
+    procs
+        .iter()
+        .flat_map(|proc| &proc.assignments)
+        .find(|assign| assign.value.trim().eq_ignore_ascii_case(signal))
+        .map(|assign| assign.target.as_str())
+}
+
+/// Line of the first assignment in `proc` reading `signal`, else the process.
+fn read_line(proc: &Process, signal: &str) -> usize {
+    proc.assignments
+        .iter()
+        .find(|assign| {
+            assign
+                .value
+                .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .any(|word| word.eq_ignore_ascii_case(signal))
+        })
+        .map_or(proc.line, |assign| assign.line)
+}
+
+fn signal_width(input: &Input, in_arch: &str, name: &str) -> usize {
+    let Some(sig) = input.signals.iter().find(|sig| {
+        sig.in_entity.eq_ignore_ascii_case(in_arch) && sig.name.eq_ignore_ascii_case(name)
+    }) else {
+        return 0;
+    };
+    let generics = elaboration::architecture_scope(input, in_arch);
+    elaboration::declared_width(&sig.r#type, sig.width, &generics)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{ClockDecl, SequentialAssignment, Signal};
+
+    fn clocked(label: &str, clock: &str, line: usize, copies: &[(&str, &str)]) -> Process {
+        Process {
+            label: label.to_string(),
+            is_sequential: true,
+            clock_signal: clock.to_string(),
+            assigned_signals: copies.iter().map(|(t, _)| t.to_string()).collect(),
+            read_signals: copies.iter().map(|(_, v)| v.to_string()).collect(),
+            assignments: copies
+                .iter()
+                .enumerate()
+                .map(|(i, (target, value))| SequentialAssignment {
+                    target: target.to_string(),
+                    value: value.to_string(),
+                    line: line + i + 1,
+                    ..Default::default()
+                })
+                .collect(),
+            file: "a.vhd".to_string(),
+            line,
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn infers_domains_and_synchronized_crossings() {
+        let mut input = Input::default();
+        input
+            .processes
+            .push(clocked("p_src", "clk_a", 10, &[("flag", "req")]));
+        input.processes.push(clocked(
+            "p_sync",
+            "clk_b",
+            20,
+            &[("flag_meta", "flag"), ("flag_sync", "flag_meta")],
+        ));
+        let mut raw = clocked("p_raw", "clk_b", 30, &[("seen", "flag and en")]);
+        raw.read_signals = vec!["flag".to_string(), "en".to_string()];
+        input.processes.push(raw);
+
+        let map = infer(&input);
+        assert_eq!(map.domains.len(), 2);
+        assert_eq!(map.domains[0].clock, "clk_a");
+        assert_eq!(map.domains[0].registers, ["flag"]);
+        assert_eq!(map.domains[1].processes, ["p_sync", "p_raw"]);
+        assert_eq!(map.domains[1].registers, ["flag_meta", "flag_sync", "seen"]);
+
+        assert_eq!(map.crossings.len(), 2);
+        let crossing = &map.crossings[0];
+        assert_eq!(crossing.signal, "flag");
+        assert_eq!(crossing.source_clock, "clk_a");
+        assert_eq!(crossing.dest_clock, "clk_b");
+        assert_eq!(crossing.dest_proc, "p_sync");
+        assert_eq!(crossing.line, 21);
+        assert!(crossing.is_synchronized);
+        assert_eq!(crossing.sync_stages, 2);
+        assert!(!crossing.is_multi_bit);
+
+        let raw = &map.crossings[1];
+        assert_eq!(raw.dest_proc, "p_raw");
+        assert!(!raw.is_synchronized);
+        assert_eq!(raw.sync_stages, 0);
+    }
+
+    #[test]
+    fn flags_unsynchronized_multi_bit_crossing() {
+        let mut input = Input::default();
+        input.signals.push(Signal {
+            name: "count".to_string(),
+            r#type: "unsigned(7 downto 0)".to_string(),
+            in_entity: "rtl".to_string(),
+            ..Default::default()
+        });
+        input
+            .processes
+            .push(clocked("p_cnt", "clk_a", 10, &[("count", "count + 1")]));
+        let mut reader = clocked("p_use", "clk_b", 20, &[("total", "total + count")]);
+        reader.read_signals = vec!["total".to_string(), "count".to_string()];
+        input.processes.push(reader);
+
+        let map = infer(&input);
+        assert_eq!(map.crossings.len(), 1);
+        assert!(!map.crossings[0].is_synchronized);
+        assert!(map.crossings[0].is_multi_bit);
+        assert_eq!(map.crossings[0].line, 21);
+    }
+
+    #[test]
+    fn clocks_of_one_declared_domain_share_it() {
+        let mut input = Input::default();
+        input
+            .processes
+            .push(clocked("p_fast", "clk_a", 10, &[("flag", "req")]));
+        input
+            .processes
+            .push(clocked("p_slow", "clk_b", 20, &[("seen", "flag")]));
+        input.processes[1].read_signals = vec!["flag".to_string()];
+        assert_eq!(infer(&input).crossings.len(), 1);
+
+        input.lint_config.clocks = ["clk_a", "clk_b"]
+            .map(|name| ClockDecl {
+                name: name.to_string(),
+                domain: "Sys".to_string(),
+                ..Default::default()
+            })
+            .to_vec();
+        let map = infer(&input);
+        assert_eq!(map.domains.len(), 1);
+        assert_eq!(map.domains[0].domain, "Sys");
+        assert_eq!(map.domains[0].processes, ["p_fast", "p_slow"]);
+        assert!(map.crossings.is_empty());
+    }
+}
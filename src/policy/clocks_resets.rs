@@ -1,12 +1,15 @@
-use crate::policy::helpers::{is_clock_name, is_reset_name, is_single_bit_type};
-use crate::policy::input::{Input, Port};
-use crate::policy::result::Violation;
+use crate::policy::helpers::{self, base_arch_name, is_clock, is_reset_name, is_single_bit_type};
+use crate::policy::input::{Input, Port, Process};
+use crate::policy::result::{Confidence, Violation};
+use std::collections::HashSet;
 
 pub fn violations(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     out.extend(clock_not_std_logic(input));
     out.extend(reset_not_std_logic(input));
     out.extend(multiple_clocks_in_process(input));
+    out.extend(clock_used_as_data(input));
+    out.extend(unknown_clock_source(input));
     out
 }
 
@@ -17,11 +20,23 @@ pub fn optional_violations(input: &Input) -> Vec<Violation> {
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "architectures",
+        "arithmetic_ops",
+        "comparisons",
+        "concurrent_assignments",
+        "entities",
+        "ports",
+        "processes",
+    ]
+}
+
 fn clock_not_std_logic(input: &Input) -> Vec<Violation> {
     input
         .ports
         .iter()
-        .filter(|port| is_clock_name(&port.name))
+        .filter(|port| is_clock(input, &port.name))
         .filter(|port| port.direction == "in")
         .filter(|port| !is_single_bit_type(&port.r#type))
         .map(|port| Violation {
@@ -33,6 +48,8 @@ fn clock_not_std_logic(input: &Input) -> Vec<Violation> {
                 "Clock signal '{}' should be std_logic, not '{}'",
                 port.name, port.r#type
             ),
+            confidence: port_role_confidence(input, port, |proc| &proc.clock_signal),
+            ..Default::default()
         })
         .collect()
 }
@@ -53,6 +70,8 @@ fn reset_not_std_logic(input: &Input) -> Vec<Violation> {
                 "Reset signal '{}' should be std_logic, not '{}'",
                 port.name, port.r#type
             ),
+            confidence: port_role_confidence(input, port, |proc| &proc.reset_signal),
+            ..Default::default()
         })
         .collect()
 }
@@ -66,7 +85,7 @@ fn multiple_clocks_in_process(input: &Input) -> Vec<Violation> {
             let clocks: Vec<String> = proc
                 .sensitivity_list
                 .iter()
-                .filter(|sig| is_clock_name(sig))
+                .filter(|sig| is_clock(input, sig))
                 .cloned()
                 .collect();
             if clocks.len() > 1 {
@@ -79,6 +98,8 @@ fn multiple_clocks_in_process(input: &Input) -> Vec<Violation> {
                         "Process '{}' appears to use multiple clocks {:?} - potential CDC issue",
                         proc.label, clocks
                     ),
+                    confidence: Confidence::Medium,
+                    ..Default::default()
                 })
             } else {
                 None
@@ -87,6 +108,36 @@ fn multiple_clocks_in_process(input: &Input) -> Vec<Violation> {
         .collect()
 }
 
+/// With `lint_config.clocks` declared, a clocked process outside the
+/// testbenches must be clocked by one of them.
+fn unknown_clock_source(input: &Input) -> Vec<Violation> {
+    let declared = &input.lint_config.clocks;
+    if declared.is_empty() {
+        return Vec::new();
+    }
+    let names: Vec<&str> = declared.iter().map(|clock| clock.name.as_str()).collect();
+    input
+        .processes
+        .iter()
+        .filter(|proc| proc.is_sequential && !proc.clock_signal.is_empty())
+        .filter(|proc| helpers::declared_clock(input, &proc.clock_signal).is_none())
+        .filter(|proc| !helpers::process_in_testbench(input, proc))
+        .map(|proc| Violation {
+            rule: "unknown_clock_source".to_string(),
+            severity: "warning".to_string(),
+            file: proc.file.clone(),
+            line: proc.line,
+            message: format!(
+                "Process '{}' is clocked by '{}', which is not a declared clock ({})",
+                proc.label,
+                proc.clock_signal,
+                names.join(", ")
+            ),
+            ..Default::default()
+        })
+        .collect()
+}
+
 fn missing_reset(input: &Input) -> Vec<Violation> {
     input
         .processes
@@ -103,6 +154,7 @@ fn missing_reset(input: &Input) -> Vec<Violation> {
                 "Sequential process '{}' has no reset - power-on state will be unknown",
                 proc.label
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -127,10 +179,199 @@ fn async_reset_active_high(input: &Input) -> Vec<Violation> {
                 "Reset '{}' in process '{}' may be active-high - consider using active-low reset (rstn, rst_n)",
                 proc.reset_signal, proc.label
             ),
+            ..Default::default()
         })
         .collect()
 }
 
+/// Inferred clocks (signals some process in the same architecture is
+/// edge-triggered on) read by arithmetic, comparisons or data assignments.
+/// Edge idioms such as `clk'event and clk = '1'` in a process clocked by that
+/// signal are not data uses; `lint_config.clock_monitors` waives entities,
+/// architectures or processes that sample a clock on purpose.
+fn clock_used_as_data(input: &Input) -> Vec<Violation> {
+    let clocks = ClockDomains::new(input);
+    let mut out = Vec::new();
+    let mut seen = HashSet::new();
+    let mut flag = |file: &str, line: usize, clock: &str, usage: &str| {
+        if !seen.insert((file.to_string(), line)) {
+            return;
+        }
+        out.push(Violation {
+            rule: "clock_used_as_data".to_string(),
+            severity: "warning".to_string(),
+            file: file.to_string(),
+            line,
+            message: format!(
+                "Clock '{}' used in {} - clock-as-data needs a dedicated clock monitor or sampling structure",
+                clock, usage
+            ),
+            ..Default::default()
+        });
+    };
+
+    for op in &input.arithmetic_ops {
+        if clock_monitor(input, &op.file, &op.in_arch, &op.in_process) {
+            continue;
+        }
+        if let Some(clock) = op
+            .operands
+            .iter()
+            .find(|operand| clocks.contains(&op.file, &op.in_arch, operand))
+        {
+            flag(
+                &op.file,
+                op.line,
+                clock,
+                &format!("'{}' arithmetic", op.operator),
+            );
+        }
+    }
+
+    for cmp in &input.comparisons {
+        if clock_monitor(input, &cmp.file, &cmp.in_arch, &cmp.in_process) {
+            continue;
+        }
+        let Some(clock) = [&cmp.left_operand, &cmp.right_operand]
+            .into_iter()
+            .find(|operand| clocks.contains(&cmp.file, &cmp.in_arch, operand))
+        else {
+            continue;
+        };
+        let edge_idiom = cmp.is_literal
+            && find_process(input, &cmp.file, &cmp.in_process)
+                .is_some_and(|proc| proc.clock_signal.eq_ignore_ascii_case(clock));
+        if !edge_idiom {
+            flag(&cmp.file, cmp.line, clock, "a comparison");
+        }
+    }
+
+    for ca in &input.concurrent_assignments {
+        // Clock aliases and derived clocks stay on the clock network;
+        // gated_clock_detection covers the latter.
+        if is_clock(input, &ca.target)
+            || clocks.contains(&ca.file, &ca.in_arch, &ca.target)
+            || clock_monitor(input, &ca.file, &ca.in_arch, "")
+        {
+            continue;
+        }
+        if let Some(clock) = ca
+            .read_signals
+            .iter()
+            .find(|sig| clocks.contains(&ca.file, &ca.in_arch, sig))
+        {
+            flag(&ca.file, ca.line, clock, "a data assignment");
+        }
+    }
+
+    for proc in &input.processes {
+        if clock_monitor(input, &proc.file, &proc.in_arch, &proc.label) {
+            continue;
+        }
+        for assign in &proc.assignments {
+            // `clk <= not clk` generates the clock rather than sampling it.
+            if clocks.contains(&proc.file, &proc.in_arch, &assign.signal) {
+                continue;
+            }
+            let clock = identifiers(&assign.value)
+                .find(|ident| clocks.contains(&proc.file, &proc.in_arch, ident));
+            if let Some(clock) = clock {
+                flag(&proc.file, assign.line, clock, "a data assignment");
+            }
+        }
+    }
+    out
+}
+
+/// Clock signals per architecture, keyed by file so same-named architectures
+/// of different entities stay apart.
+struct ClockDomains {
+    clocks: HashSet<(String, String, String)>,
+}
+
+impl ClockDomains {
+    fn new(input: &Input) -> Self {
+        let clocks = input
+            .processes
+            .iter()
+            .filter(|proc| proc.is_sequential && !proc.clock_signal.is_empty())
+            .map(|proc| Self::key(&proc.file, &proc.in_arch, &proc.clock_signal))
+            .collect();
+        ClockDomains { clocks }
+    }
+
+    fn contains(&self, file: &str, in_arch: &str, signal: &str) -> bool {
+        self.clocks.contains(&Self::key(file, in_arch, signal))
+    }
+
+    fn key(file: &str, in_arch: &str, signal: &str) -> (String, String, String) {
+        (
+            file.to_string(),
+            base_arch_name(in_arch).to_ascii_lowercase(),
+            signal.trim().to_ascii_lowercase(),
+        )
+    }
+}
+
+fn clock_monitor(input: &Input, file: &str, in_arch: &str, process: &str) -> bool {
+    let monitors = &input.lint_config.clock_monitors;
+    if monitors.is_empty() {
+        return false;
+    }
+    let arch = base_arch_name(in_arch);
+    let entity = input
+        .architectures
+        .iter()
+        .find(|a| a.file == file && a.name.eq_ignore_ascii_case(&arch))
+        .map(|a| a.entity_name.as_str())
+        .unwrap_or_default();
+    monitors.iter().any(|name| {
+        [entity, arch.as_str(), process]
+            .iter()
+            .any(|scope| !scope.is_empty() && scope.eq_ignore_ascii_case(name))
+    })
+}
+
+fn find_process<'a>(input: &'a Input, file: &str, label: &str) -> Option<&'a Process> {
+    if label.is_empty() {
+        return None;
+    }
+    input
+        .processes
+        .iter()
+        .find(|proc| proc.file == file && proc.label.eq_ignore_ascii_case(label))
+}
+
+fn identifiers(expr: &str) -> impl Iterator<Item = &str> {
+    expr.split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .filter(|word| word.starts_with(|c: char| c.is_ascii_alphabetic()))
+}
+
+/// Port roles are guessed from names; a process of the port's entity that
+/// clocks or resets on it confirms the guess.
+fn port_role_confidence(
+    input: &Input,
+    port: &Port,
+    role: impl Fn(&Process) -> &String,
+) -> Confidence {
+    let confirmed = input
+        .architectures
+        .iter()
+        .filter(|arch| arch.entity_name.eq_ignore_ascii_case(&port.in_entity))
+        .any(|arch| {
+            input.processes.iter().any(|proc| {
+                proc.file == arch.file
+                    && base_arch_name(&proc.in_arch).eq_ignore_ascii_case(&arch.name)
+                    && role(proc).eq_ignore_ascii_case(&port.name)
+            })
+        });
+    if confirmed {
+        Confidence::High
+    } else {
+        Confidence::Medium
+    }
+}
+
 fn entity_file(input: &Input, port: &Port) -> Option<String> {
     input
         .entities
@@ -142,7 +383,10 @@ fn entity_file(input: &Input, port: &Port) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::policy::input::{Architecture, Entity, Input, Process};
+    use crate::policy::input::{
+        Architecture, ArithmeticOp, ClockDecl, Comparison, ConcurrentAssignment, Entity, Input,
+        Process, SequentialAssignment,
+    };
 
     fn add_entity_arch(input: &mut Input, name: &str) {
         input.entities.push(Entity {
@@ -156,6 +400,7 @@ mod tests {
             entity_name: name.to_string(),
             file: "a.vhd".to_string(),
             line: 2,
+            ..Default::default()
         });
     }
 
@@ -176,6 +421,35 @@ mod tests {
         assert_eq!(violations[0].rule, "clock_not_std_logic");
     }
 
+    #[test]
+    fn clock_not_std_logic_confidence_follows_usage() {
+        let mut input = Input::default();
+        add_entity_arch(&mut input, "core");
+        input.ports.push(Port {
+            name: "clk".to_string(),
+            direction: "in".to_string(),
+            r#type: "std_logic_vector".to_string(),
+            in_entity: "core".to_string(),
+            line: 3,
+            ..Default::default()
+        });
+        assert_eq!(
+            clock_not_std_logic(&input)[0].confidence,
+            Confidence::Medium
+        );
+
+        input.processes.push(Process {
+            label: "p1".to_string(),
+            is_sequential: true,
+            clock_signal: "CLK".to_string(),
+            file: "a.vhd".to_string(),
+            in_arch: "rtl".to_string(),
+            line: 6,
+            ..Default::default()
+        });
+        assert_eq!(clock_not_std_logic(&input)[0].confidence, Confidence::High);
+    }
+
     #[test]
     fn multiple_clocks_in_process_flags() {
         let mut input = Input::default();
@@ -192,6 +466,44 @@ mod tests {
         assert_eq!(violations[0].rule, "multiple_clocks_in_process");
     }
 
+    #[test]
+    fn declared_clocks_replace_the_naming_heuristic() {
+        let mut input = Input::default();
+        input.processes.push(Process {
+            label: "p_sys".to_string(),
+            is_sequential: true,
+            clock_signal: "sys_clk".to_string(),
+            sensitivity_list: vec!["sys_clk".to_string(), "sample".to_string()],
+            file: "a.vhd".to_string(),
+            line: 4,
+            ..Default::default()
+        });
+        input.processes.push(Process {
+            label: "p_div".to_string(),
+            is_sequential: true,
+            clock_signal: "div_clk".to_string(),
+            file: "a.vhd".to_string(),
+            line: 12,
+            ..Default::default()
+        });
+        assert!(unknown_clock_source(&input).is_empty());
+
+        input.lint_config.clocks = ["sys_clk", "sample"]
+            .map(|name| ClockDecl {
+                name: name.to_string(),
+                ..Default::default()
+            })
+            .to_vec();
+        let v = unknown_clock_source(&input);
+        assert_eq!(v.len(), 1);
+        assert_eq!(
+            v[0].message,
+            "Process 'p_div' is clocked by 'div_clk', which is not a declared clock (sys_clk, sample)"
+        );
+        // `sample` doesn't look like a clock but is declared as one.
+        assert_eq!(multiple_clocks_in_process(&input).len(), 1);
+    }
+
     #[test]
     fn missing_reset_flags() {
         let mut input = Input::default();
@@ -208,4 +520,78 @@ mod tests {
         assert_eq!(violations.len(), 1);
         assert_eq!(violations[0].rule, "missing_reset");
     }
+
+    fn clocked_input() -> Input {
+        let mut input = Input::default();
+        add_entity_arch(&mut input, "core");
+        input.processes.push(Process {
+            label: "p_reg".to_string(),
+            is_sequential: true,
+            clock_signal: "clk".to_string(),
+            in_arch: "rtl".to_string(),
+            file: "a.vhd".to_string(),
+            line: 10,
+            assignments: vec![SequentialAssignment {
+                target: "q".to_string(),
+                signal: "q".to_string(),
+                value: "d and clk".to_string(),
+                line: 12,
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        input.comparisons.push(Comparison {
+            left_operand: "clk".to_string(),
+            operator: "=".to_string(),
+            right_operand: "'1'".to_string(),
+            is_literal: true,
+            in_process: "p_reg".to_string(),
+            in_arch: "rtl".to_string(),
+            file: "a.vhd".to_string(),
+            line: 11,
+            ..Default::default()
+        });
+        input.arithmetic_ops.push(ArithmeticOp {
+            operator: "+".to_string(),
+            operands: vec!["count".to_string(), "CLK".to_string()],
+            in_arch: "rtl".to_string(),
+            file: "a.vhd".to_string(),
+            line: 20,
+            ..Default::default()
+        });
+        input.concurrent_assignments.push(ConcurrentAssignment {
+            target: "led".to_string(),
+            read_signals: vec!["clk".to_string()],
+            in_arch: "rtl".to_string(),
+            file: "a.vhd".to_string(),
+            line: 21,
+            ..Default::default()
+        });
+        input.concurrent_assignments.push(ConcurrentAssignment {
+            target: "clk_int".to_string(),
+            read_signals: vec!["clk".to_string()],
+            in_arch: "rtl".to_string(),
+            file: "a.vhd".to_string(),
+            line: 22,
+            ..Default::default()
+        });
+        input
+    }
+
+    #[test]
+    fn clock_used_as_data_flags_data_uses() {
+        let input = clocked_input();
+        let lines: Vec<usize> = clock_used_as_data(&input).iter().map(|v| v.line).collect();
+        // The edge comparison (11) and the clock alias (22) are clock uses.
+        assert_eq!(lines, [20, 21, 12]);
+    }
+
+    #[test]
+    fn clock_used_as_data_honors_clock_monitor_waivers() {
+        let mut input = clocked_input();
+        input.lint_config.clock_monitors = vec!["P_REG".to_string()];
+        assert_eq!(clock_used_as_data(&input).len(), 2);
+        input.lint_config.clock_monitors = vec!["core".to_string()];
+        assert!(clock_used_as_data(&input).is_empty());
+    }
 }
@@ -1,7 +1,7 @@
 use crate::policy::helpers;
 use crate::policy::input::{Input, SignalDep};
 use crate::policy::result::Violation;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 
 pub fn violations(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
@@ -10,6 +10,7 @@ pub fn violations(input: &Input) -> Vec<Violation> {
     out.extend(direct_combinational_loop(input));
     out.extend(two_stage_loop(input));
     out.extend(three_stage_loop(input));
+    out.extend(combinational_loop(input));
     out.extend(cross_process_loop(input));
     out
 }
@@ -20,9 +21,14 @@ pub fn optional_violations(input: &Input) -> Vec<Violation> {
     out.extend(vhdl2008_sensitivity_all(input));
     out.extend(long_sensitivity_list(input));
     out.extend(potential_comb_loop(input));
+    out.extend(delta_oscillation_risk(input));
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &["ports", "processes", "signal_deps", "signals"]
+}
+
 fn combinational_feedback(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     for proc in &input.processes {
@@ -52,6 +58,7 @@ fn combinational_feedback(input: &Input) -> Vec<Violation> {
                         "Combinational process '{}' reads signal '{}' that it assigns - potential combinational loop",
                         proc.label, assigned
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -76,6 +83,7 @@ fn large_combinational_process(input: &Input) -> Vec<Violation> {
                         "Large combinational process '{}' ({} signals) - may cause timing issues",
                         proc.label, total
                     ),
+                    ..Default::default()
                 })
             } else {
                 None
@@ -101,6 +109,7 @@ fn empty_sensitivity_combinational(input: &Input) -> Vec<Violation> {
                 "Combinational process '{}' has empty sensitivity list - will only execute once!",
                 proc.label
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -119,6 +128,7 @@ fn vhdl2008_sensitivity_all(input: &Input) -> Vec<Violation> {
                 "Process '{}' uses VHDL-2008 'all' sensitivity - good practice but requires VHDL-2008 support",
                 proc.label
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -140,6 +150,7 @@ fn long_sensitivity_list(input: &Input) -> Vec<Violation> {
                 proc.label,
                 proc.sensitivity_list.len()
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -156,7 +167,6 @@ fn direct_combinational_loop(input: &Input) -> Vec<Violation> {
         .iter()
         .filter(|dep| !dep.is_sequential && dep.source == dep.target)
         .filter(|dep| !sequential_targets.contains(&dep.target))
-        .filter(|dep| !helpers::file_in_testbench(input, &dep.file))
         .filter(|dep| !helpers::is_resolved_signal(input, &dep.target))
         .map(|dep| Violation {
             rule: "direct_combinational_loop".to_string(),
@@ -167,6 +177,7 @@ fn direct_combinational_loop(input: &Input) -> Vec<Violation> {
                 "Direct combinational loop: signal '{}' depends on itself",
                 dep.source
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -203,6 +214,7 @@ fn two_stage_loop(input: &Input) -> Vec<Violation> {
                         "Combinational loop detected: '{}' -> '{}' -> '{}'",
                         dep.source, dep.target, dep.source
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -256,12 +268,196 @@ fn three_stage_loop(input: &Input) -> Vec<Violation> {
                     "Combinational loop detected: '{}' -> '{}' -> '{}' -> '{}'",
                     dep.source, b_name, c_name, dep.source
                 ),
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+/// Zero-delay loops of any length: every strongly connected component of an
+/// architecture's combinational dependency graph with more than one signal,
+/// reported with its shortest cycle through the component's first signal.
+fn combinational_loop(input: &Input) -> Vec<Violation> {
+    let mut graphs: BTreeMap<(String, String), DepGraph> = BTreeMap::new();
+    for dep in filtered_combinational_deps(input) {
+        graphs
+            .entry((dep.file.clone(), dep.in_arch.to_ascii_lowercase()))
+            .or_default()
+            .add(dep);
+    }
+
+    let mut out = Vec::new();
+    for graph in graphs.values() {
+        for component in graph.strongly_connected() {
+            if component.len() < 2 {
+                continue;
+            }
+            let cycle = graph.shortest_cycle(&component);
+            let Some(first) = cycle.first() else { continue };
+            let mut names: Vec<&str> = cycle.iter().map(|dep| dep.source.as_str()).collect();
+            names.push(&first.source);
+            let mut message = format!("Combinational loop detected: '{}'", names.join("' -> '"));
+            if component.len() > cycle.len() {
+                message.push_str(&format!(
+                    " ({} signals are mutually dependent in this loop)",
+                    component.len()
+                ));
+            }
+            out.push(Violation {
+                rule: "combinational_loop".to_string(),
+                severity: "error".to_string(),
+                file: first.file.clone(),
+                line: first.line,
+                message,
+                ..Default::default()
             });
         }
     }
     out
 }
 
+/// The signal sets `combinational_loop` reports for architecture `arch` of
+/// `file`, each sorted by lowercase name.
+pub fn loops(input: &Input, file: &str, arch: &str) -> Vec<Vec<String>> {
+    let mut graph = DepGraph::default();
+    for dep in filtered_combinational_deps(input) {
+        if dep.file == file && dep.in_arch.eq_ignore_ascii_case(arch) {
+            graph.add(dep);
+        }
+    }
+    let mut loops: Vec<Vec<String>> = graph
+        .strongly_connected()
+        .into_iter()
+        .filter(|component| component.len() > 1)
+        .map(|component| {
+            let mut names: Vec<String> =
+                component.iter().map(|&id| graph.keys[id].clone()).collect();
+            names.sort();
+            names
+        })
+        .collect();
+    loops.sort();
+    loops
+}
+
+/// Signal dependency graph of one architecture, with nodes keyed by
+/// lowercase name and edges carrying the dependency that created them.
+#[derive(Default)]
+struct DepGraph<'a> {
+    ids: HashMap<String, usize>,
+    keys: Vec<String>,
+    edges: Vec<Vec<(usize, &'a SignalDep)>>,
+}
+
+impl<'a> DepGraph<'a> {
+    fn add(&mut self, dep: &'a SignalDep) {
+        let source = self.node(&dep.source);
+        let target = self.node(&dep.target);
+        self.edges[source].push((target, dep));
+    }
+
+    fn node(&mut self, name: &str) -> usize {
+        let key = name.to_ascii_lowercase();
+        if let Some(&id) = self.ids.get(&key) {
+            return id;
+        }
+        let id = self.keys.len();
+        self.ids.insert(key.clone(), id);
+        self.keys.push(key);
+        self.edges.push(Vec::new());
+        id
+    }
+
+    /// Tarjan's algorithm, with an explicit work stack so long dependency
+    /// chains can't overflow the call stack.
+    fn strongly_connected(&self) -> Vec<Vec<usize>> {
+        let count = self.keys.len();
+        let mut index: Vec<Option<usize>> = vec![None; count];
+        let mut low = vec![0; count];
+        let mut on_stack = vec![false; count];
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+        let mut next_index = 0;
+
+        for root in 0..count {
+            if index[root].is_some() {
+                continue;
+            }
+            let mut work = vec![(root, 0)];
+            while let Some((node, edge)) = work.pop() {
+                if edge == 0 {
+                    index[node] = Some(next_index);
+                    low[node] = next_index;
+                    next_index += 1;
+                    stack.push(node);
+                    on_stack[node] = true;
+                }
+                if let Some(&(succ, _)) = self.edges[node].get(edge) {
+                    work.push((node, edge + 1));
+                    match index[succ] {
+                        None => work.push((succ, 0)),
+                        Some(succ_index) if on_stack[succ] => {
+                            low[node] = low[node].min(succ_index);
+                        }
+                        Some(_) => {}
+                    }
+                    continue;
+                }
+                if Some(low[node]) == index[node] {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+                if let Some(&(parent, _)) = work.last() {
+                    low[parent] = low[parent].min(low[node]);
+                }
+            }
+        }
+        components
+    }
+
+    /// Shortest cycle (breadth-first, staying inside `component`) from the
+    /// component's alphabetically first signal back to itself.
+    fn shortest_cycle(&self, component: &[usize]) -> Vec<&'a SignalDep> {
+        let members: HashSet<usize> = component.iter().copied().collect();
+        let Some(&start) = component.iter().min_by_key(|&&id| &self.keys[id]) else {
+            return Vec::new();
+        };
+        let mut parent: HashMap<usize, (usize, &'a SignalDep)> = HashMap::new();
+        let mut queue = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            for &(succ, dep) in &self.edges[node] {
+                if succ == node {
+                    continue;
+                }
+                if succ == start {
+                    let mut cycle = vec![dep];
+                    let mut at = node;
+                    while at != start {
+                        let (prev, dep) = parent[&at];
+                        cycle.push(dep);
+                        at = prev;
+                    }
+                    cycle.reverse();
+                    return cycle;
+                }
+                if members.contains(&succ) && !parent.contains_key(&succ) {
+                    parent.insert(succ, (node, dep));
+                    queue.push_back(succ);
+                }
+            }
+        }
+        Vec::new()
+    }
+}
+
 fn potential_comb_loop(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     for proc in &input.processes {
@@ -288,6 +484,7 @@ fn potential_comb_loop(input: &Input) -> Vec<Violation> {
                         "Potential combinational loop in process '{}': signal '{}' is both read and written",
                         proc.label, assigned
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -382,6 +579,7 @@ fn cross_process_loop(input: &Input) -> Vec<Violation> {
                         "Cross-process combinational loop between '{}' and '{}' via signals '{}' and '{}'",
                         proc1.label, proc2.label, a, b
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -398,6 +596,122 @@ struct ProcessInfo {
     read: Vec<String>,
 }
 
+/// Longest zero-delay cycle `delta_oscillation_risk` searches for.
+const MAX_DELTA_CYCLE: usize = 4;
+
+/// Zero-delay cycles that the loop rules above skip because a resolved signal
+/// sits on them, where the edges come from more than one process (or
+/// concurrent statement). Such handshake couplings ping-pong between drivers
+/// for delta cycles instead of settling.
+fn delta_oscillation_risk(input: &Input) -> Vec<Violation> {
+    let mut graphs: HashMap<(String, String), HashMap<String, Vec<DeltaEdge>>> = HashMap::new();
+    for dep in &input.signal_deps {
+        if dep.is_sequential || dep.source.eq_ignore_ascii_case(&dep.target) {
+            continue;
+        }
+        let driver = if dep.in_process.is_empty() {
+            format!("concurrent assignment at line {}", dep.line)
+        } else {
+            format!("process '{}'", dep.in_process)
+        };
+        graphs
+            .entry((dep.file.clone(), dep.in_arch.to_ascii_lowercase()))
+            .or_default()
+            .entry(dep.source.to_ascii_lowercase())
+            .or_default()
+            .push(DeltaEdge {
+                target: dep.target.to_ascii_lowercase(),
+                driver,
+                dep,
+            });
+    }
+
+    let mut keys: Vec<&(String, String)> = graphs.keys().collect();
+    keys.sort();
+    let mut out = Vec::new();
+    for key in keys {
+        let graph = &graphs[key];
+        let mut starts: Vec<&String> = graph.keys().collect();
+        starts.sort();
+        let mut seen: HashSet<Vec<String>> = HashSet::new();
+        for start in starts {
+            let mut path: Vec<&DeltaEdge> = Vec::new();
+            let mut cycles = Vec::new();
+            find_delta_cycles(graph, start, start, &mut path, &mut cycles);
+            for cycle in cycles {
+                let mut nodes: Vec<String> = cycle.iter().map(|edge| edge.target.clone()).collect();
+                nodes.sort();
+                if !seen.insert(nodes) {
+                    continue;
+                }
+                let mut drivers: Vec<&str> = cycle.iter().map(|e| e.driver.as_str()).collect();
+                drivers.sort_unstable();
+                drivers.dedup();
+                if drivers.len() < 2 {
+                    continue;
+                }
+                let Some(resolved) = cycle
+                    .iter()
+                    .find(|edge| helpers::is_resolved_signal(input, &edge.dep.target))
+                else {
+                    continue;
+                };
+                let mut names: Vec<&str> = cycle.iter().map(|e| e.dep.source.as_str()).collect();
+                names.push(&cycle[0].dep.source);
+                out.push(Violation {
+                    rule: "delta_oscillation_risk".to_string(),
+                    severity: "warning".to_string(),
+                    file: cycle[0].dep.file.clone(),
+                    line: cycle[0].dep.line,
+                    message: format!(
+                        "Zero-delay cycle '{}' between {} is only broken by resolution of '{}'; it may oscillate in delta cycles",
+                        names.join("' -> '"),
+                        drivers.join(" and "),
+                        resolved.dep.target
+                    ),
+                    ..Default::default()
+                });
+            }
+        }
+    }
+    out
+}
+
+struct DeltaEdge<'a> {
+    target: String,
+    driver: String,
+    dep: &'a SignalDep,
+}
+
+/// Depth-first search for cycles back to `start`, visiting only nodes that
+/// sort after it so each cycle is found from its smallest node.
+fn find_delta_cycles<'a>(
+    graph: &'a HashMap<String, Vec<DeltaEdge<'a>>>,
+    start: &str,
+    node: &str,
+    path: &mut Vec<&'a DeltaEdge<'a>>,
+    cycles: &mut Vec<Vec<&'a DeltaEdge<'a>>>,
+) {
+    if path.len() >= MAX_DELTA_CYCLE {
+        return;
+    }
+    let Some(edges) = graph.get(node) else { return };
+    for edge in edges {
+        if edge.target == start {
+            path.push(edge);
+            cycles.push(path.clone());
+            path.pop();
+            continue;
+        }
+        if edge.target.as_str() < start || path.iter().any(|e| e.target == edge.target) {
+            continue;
+        }
+        path.push(edge);
+        find_delta_cycles(graph, start, &edge.target, path, cycles);
+        path.pop();
+    }
+}
+
 fn filtered_combinational_deps(input: &Input) -> Vec<&SignalDep> {
     input
         .signal_deps
@@ -478,8 +792,16 @@ mod tests {
             line: 10,
             ..Default::default()
         });
-        let v = direct_combinational_loop(&input);
-        assert!(v.is_empty());
+        input
+            .lint_config
+            .rules
+            .insert("direct_combinational_loop".to_string(), "error".to_string());
+        // Testbench scoping is applied by the engine from the rule registry.
+        let result = crate::policy::engine::evaluate(&input);
+        assert!(result
+            .violations
+            .iter()
+            .all(|v| v.rule != "direct_combinational_loop"));
     }
 
     #[test]
@@ -503,4 +825,96 @@ mod tests {
         let v = direct_combinational_loop(&input);
         assert!(v.is_empty());
     }
+
+    fn handshake_dep(source: &str, target: &str, process: &str, line: usize) -> SignalDep {
+        SignalDep {
+            source: source.to_string(),
+            target: target.to_string(),
+            file: "a.vhd".to_string(),
+            line,
+            in_process: process.to_string(),
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn delta_oscillation_risk_flags_resolved_handshake() {
+        let mut input = Input::default();
+        for name in ["req", "ack"] {
+            input.signals.push(Signal {
+                name: name.to_string(),
+                r#type: "std_logic".to_string(),
+                file: "a.vhd".to_string(),
+                in_entity: "rtl".to_string(),
+                ..Default::default()
+            });
+        }
+        input
+            .signal_deps
+            .push(handshake_dep("ack", "req", "p_req", 10));
+        input
+            .signal_deps
+            .push(handshake_dep("req", "ack", "p_ack", 20));
+        assert!(two_stage_loop(&input).is_empty());
+        let v = delta_oscillation_risk(&input);
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[0].rule, "delta_oscillation_risk");
+        assert!(v[0].message.contains("'ack' -> 'req' -> 'ack'"));
+
+        // A cycle inside one process is left to the loop rules.
+        input.signal_deps[1].in_process = "p_req".to_string();
+        assert!(delta_oscillation_risk(&input).is_empty());
+    }
+
+    #[test]
+    fn combinational_loop_reports_full_cycle() {
+        let mut input = Input::default();
+        for (i, (source, target)) in [("a", "b"), ("b", "c"), ("c", "d"), ("d", "e"), ("e", "a")]
+            .into_iter()
+            .enumerate()
+        {
+            input
+                .signal_deps
+                .push(handshake_dep(source, target, "", i + 1));
+        }
+        assert!(two_stage_loop(&input).is_empty());
+        assert!(three_stage_loop(&input).is_empty());
+        let v = combinational_loop(&input);
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[0].rule, "combinational_loop");
+        assert_eq!(v[0].line, 1);
+        assert!(v[0]
+            .message
+            .ends_with("'a' -> 'b' -> 'c' -> 'd' -> 'e' -> 'a'"));
+
+        // A chord joins a second, shorter loop into the same component.
+        input.signal_deps.push(handshake_dep("c", "a", "", 6));
+        let v = combinational_loop(&input);
+        assert_eq!(v.len(), 1);
+        assert!(v[0].message.contains("'a' -> 'b' -> 'c' -> 'a' (5 signals"));
+
+        input.signal_deps[4].is_sequential = true;
+        input.signal_deps.pop();
+        assert!(combinational_loop(&input).is_empty());
+    }
+
+    #[test]
+    fn combinational_loop_separates_components() {
+        let mut input = Input::default();
+        for (source, target) in [("x", "y"), ("y", "x"), ("y", "z"), ("z", "z"), ("p", "q")] {
+            input.signal_deps.push(handshake_dep(source, target, "", 1));
+        }
+        let graph = {
+            let mut graph = DepGraph::default();
+            input.signal_deps.iter().for_each(|dep| graph.add(dep));
+            graph
+        };
+        let mut sizes: Vec<usize> = graph.strongly_connected().iter().map(Vec::len).collect();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![1, 1, 1, 2]);
+        let v = combinational_loop(&input);
+        assert_eq!(v.len(), 1);
+        assert!(v[0].message.ends_with("'x' -> 'y' -> 'x'"));
+    }
 }
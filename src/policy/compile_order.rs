@@ -0,0 +1,538 @@
+//! Compile order from `Input.dependencies`: files sorted so every file comes
+//! after the files defining the entities, packages and configurations it
+//! uses, instantiates or implements. `vhdl_policy --compile-order
+//! <ghdl|vunit|text>` prints the order as GHDL analysis commands, a VUnit
+//! `run.py` or one path per line.
+//!
+//! Tools analyze whole files, so a cycle between files has no valid order
+//! even when the units inside them don't depend on each other circularly;
+//! each one is reported as `circular_dependency` with its path. Component
+//! instantiations count as dependencies too: analysis doesn't need the
+//! entity, but simulators elaborating straight after analysis do.
+
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use crate::policy::input::Input;
+use crate::policy::result::Violation;
+use crate::policy::standards;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompileFile {
+    pub path: String,
+    pub library: String,
+    /// The file's standard, `Input.standard` unless a source set overrides
+    /// it; empty when neither is set.
+    pub standard: String,
+}
+
+/// Why one file has to be analyzed after another.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileEdge {
+    /// Index of the file needing the unit.
+    pub from: usize,
+    /// Index of the file defining it.
+    pub to: usize,
+    /// Unit name as written at the dependency, lowercase.
+    pub unit: String,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompileOrder {
+    /// Dependencies first. Files on a cycle, or needing one that is, follow
+    /// the others in input order.
+    pub files: Vec<CompileFile>,
+    /// Each cycle as its edges, starting at its file listed first in the
+    /// input.
+    pub cycles: Vec<Vec<FileCycleStep>>,
+}
+
+/// One edge of a cycle, with file paths rather than indices.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileCycleStep {
+    pub file: String,
+    pub unit: String,
+    pub line: usize,
+}
+
+pub fn violations(input: &Input) -> Vec<Violation> {
+    compile_order(input)
+        .cycles
+        .iter()
+        .filter_map(|cycle| {
+            let first = cycle.first()?;
+            Some(Violation {
+                rule: "circular_dependency".to_string(),
+                severity: "error".to_string(),
+                file: first.file.clone(),
+                line: first.line,
+                message: format!(
+                    "Circular dependency between files: {} - no compile order exists",
+                    cycle_path(cycle)
+                ),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "files",
+        "dependencies",
+        "entities",
+        "architectures",
+        "packages",
+        "configurations",
+    ]
+}
+
+/// `a.vhd (pkg_b, line 3) -> b.vhd (pkg_a, line 2) -> a.vhd`.
+pub fn cycle_path(cycle: &[FileCycleStep]) -> String {
+    let mut parts: Vec<String> = cycle
+        .iter()
+        .map(|step| format!("{} ({}, line {})", step.file, step.unit, step.line))
+        .collect();
+    if let Some(first) = cycle.first() {
+        parts.push(first.file.clone());
+    }
+    parts.join(" -> ")
+}
+
+pub fn compile_order(input: &Input) -> CompileOrder {
+    let files = design_files(input);
+    let edges = file_edges(input, &files);
+    let mut needs: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); files.len()];
+    let mut needed_by: Vec<Vec<usize>> = vec![Vec::new(); files.len()];
+    for edge in &edges {
+        if needs[edge.from].insert(edge.to) {
+            needed_by[edge.to].push(edge.from);
+        }
+    }
+
+    // Kahn's algorithm, always taking the earliest ready file so unrelated
+    // files keep their input order.
+    let mut ready: BTreeSet<usize> = (0..files.len())
+        .filter(|idx| needs[*idx].is_empty())
+        .collect();
+    let mut remaining: Vec<usize> = needs.iter().map(BTreeSet::len).collect();
+    let mut ordered = Vec::new();
+    while let Some(idx) = ready.pop_first() {
+        ordered.push(idx);
+        for &user in &needed_by[idx] {
+            remaining[user] -= 1;
+            if remaining[user] == 0 {
+                ready.insert(user);
+            }
+        }
+    }
+    let placed: HashSet<usize> = ordered.iter().copied().collect();
+    let stuck: Vec<usize> = (0..files.len())
+        .filter(|idx| !placed.contains(idx))
+        .collect();
+    let cycles = cycles(&stuck, &edges)
+        .into_iter()
+        .map(|cycle| {
+            cycle
+                .into_iter()
+                .map(|edge| FileCycleStep {
+                    file: files[edge.from].path.clone(),
+                    unit: edge.unit.clone(),
+                    line: edge.line,
+                })
+                .collect()
+        })
+        .collect();
+    ordered.extend(stuck);
+    CompileOrder {
+        files: ordered.into_iter().map(|idx| files[idx].clone()).collect(),
+        cycles,
+    }
+}
+
+/// `Input.files` in order, then files that only appear on design units.
+fn design_files(input: &Input) -> Vec<CompileFile> {
+    let mut seen = HashSet::new();
+    let mut files = Vec::new();
+    let mut add = |path: &str, library: &str| {
+        if path.is_empty() || !seen.insert(path.to_string()) {
+            return;
+        }
+        files.push(CompileFile {
+            path: path.to_string(),
+            library: if library.is_empty() {
+                "work".to_string()
+            } else {
+                library.to_ascii_lowercase()
+            },
+            standard: standards::standard_of(input, path).to_string(),
+        });
+    };
+    for info in &input.files {
+        add(&info.path, &info.library);
+    }
+    let unit_files = input
+        .entities
+        .iter()
+        .map(|e| &e.file)
+        .chain(input.architectures.iter().map(|a| &a.file))
+        .chain(input.packages.iter().map(|p| &p.file))
+        .chain(input.configurations.iter().map(|c| &c.file));
+    for file in unit_files {
+        add(file, "");
+    }
+    files
+}
+
+/// Edges from each file to the other files defining the units it names.
+/// A dependency naming a library other than `work` only matches files of
+/// that library when any exist.
+fn file_edges(input: &Input, files: &[CompileFile]) -> Vec<FileEdge> {
+    let index: HashMap<&str, usize> = files
+        .iter()
+        .enumerate()
+        .map(|(idx, file)| (file.path.as_str(), idx))
+        .collect();
+    let mut units: HashMap<String, Vec<usize>> = HashMap::new();
+    let defined = input
+        .entities
+        .iter()
+        .map(|e| (&e.name, &e.file))
+        .chain(input.packages.iter().map(|p| (&p.name, &p.file)))
+        .chain(input.configurations.iter().map(|c| (&c.name, &c.file)));
+    for (name, file) in defined {
+        if let Some(&idx) = index.get(file.as_str()) {
+            let providers = units.entry(name.to_ascii_lowercase()).or_default();
+            if !providers.contains(&idx) {
+                providers.push(idx);
+            }
+        }
+    }
+
+    let mut edges = Vec::new();
+    let mut push = |from: usize, library: Option<&str>, unit: &str, line: usize| {
+        let Some(providers) = units.get(unit) else {
+            return;
+        };
+        let in_library: Vec<usize> = providers
+            .iter()
+            .copied()
+            .filter(|&idx| library.is_some_and(|lib| files[idx].library == lib))
+            .collect();
+        let targets = if in_library.is_empty() {
+            providers.clone()
+        } else {
+            in_library
+        };
+        for to in targets.into_iter().filter(|&to| to != from) {
+            edges.push(FileEdge {
+                from,
+                to,
+                unit: unit.to_string(),
+                line,
+            });
+        }
+    };
+    for dep in &input.dependencies {
+        if dep.kind == "library" {
+            continue;
+        }
+        let Some(&from) = index.get(dep.source.as_str()) else {
+            continue;
+        };
+        let (library, unit) = split_target(&dep.target);
+        push(from, library.as_deref(), &unit, dep.line);
+    }
+    for arch in &input.architectures {
+        if let Some(&from) = index.get(arch.file.as_str()) {
+            push(
+                from,
+                None,
+                &arch.entity_name.to_ascii_lowercase(),
+                arch.line,
+            );
+        }
+    }
+    edges
+}
+
+/// Library (when one other than `work` is named) and unit of a dependency
+/// target: `lib.pkg.all` is `(Some("lib"), "pkg")`, `work.alu` and `alu` are
+/// `(None, "alu")`.
+fn split_target(target: &str) -> (Option<String>, String) {
+    let lower = target.trim().to_ascii_lowercase();
+    let parts: Vec<&str> = lower
+        .split('.')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect();
+    match parts.as_slice() {
+        [unit] => (None, unit.to_string()),
+        [library, unit, ..] if *library == "work" => (None, unit.to_string()),
+        [library, unit, ..] => (Some(library.to_string()), unit.to_string()),
+        [] => (None, String::new()),
+    }
+}
+
+/// One shortest cycle per strongly connected group of `stuck` files, found
+/// breadth-first from the group's earliest file.
+fn cycles<'a>(stuck: &[usize], edges: &'a [FileEdge]) -> Vec<Vec<&'a FileEdge>> {
+    let stuck_set: HashSet<usize> = stuck.iter().copied().collect();
+    let mut covered = HashSet::new();
+    let mut out = Vec::new();
+    for &start in stuck {
+        if covered.contains(&start) {
+            continue;
+        }
+        let mut came_from: HashMap<usize, &FileEdge> = HashMap::new();
+        let mut queue = VecDeque::from([start]);
+        let mut closing = None;
+        while let Some(node) = queue.pop_front() {
+            for edge in edges
+                .iter()
+                .filter(|edge| edge.from == node && stuck_set.contains(&edge.to))
+            {
+                if edge.to == start {
+                    closing = Some(edge);
+                    break;
+                }
+                if let Entry::Vacant(slot) = came_from.entry(edge.to) {
+                    slot.insert(edge);
+                    queue.push_back(edge.to);
+                }
+            }
+            if closing.is_some() {
+                break;
+            }
+        }
+        // Files only stuck behind a cycle, not on one, have no way back.
+        let Some(last) = closing else { continue };
+        let mut cycle = vec![last];
+        let mut node = last.from;
+        while node != start {
+            let edge = came_from[&node];
+            cycle.push(edge);
+            node = edge.from;
+        }
+        cycle.reverse();
+        covered.extend(cycle.iter().map(|edge| edge.from));
+        out.push(cycle);
+    }
+    out
+}
+
+/// `ghdl -a` commands, one per file, with the file's library and standard.
+pub fn ghdl(order: &CompileOrder) -> String {
+    let mut out = String::new();
+    for file in &order.files {
+        out.push_str("ghdl -a");
+        if let Some(std) = ghdl_std(&file.standard) {
+            out.push_str(&format!(" --std={}", std));
+        }
+        out.push_str(&format!(
+            " --work={} {}\n",
+            file.library,
+            shell_quote(&file.path)
+        ));
+    }
+    out
+}
+
+/// A VUnit `run.py` adding the files in order, each to its library and with
+/// its standard where that differs from the first file's.
+pub fn vunit(order: &CompileOrder) -> String {
+    let mut out = String::from(
+        "# Generated by vhdl_policy --compile-order vunit.\nfrom vunit import VUnit\n\n",
+    );
+    let default = order
+        .files
+        .first()
+        .map(|file| file.standard.as_str())
+        .unwrap_or("");
+    let mut libraries: Vec<&str> = Vec::new();
+    for file in &order.files {
+        if !libraries.contains(&file.library.as_str()) {
+            libraries.push(&file.library);
+        }
+    }
+    match vunit_standard(default) {
+        Some(std) => out.push_str(&format!(
+            "vu = VUnit.from_argv(vhdl_standard=\"{}\")\n",
+            std
+        )),
+        None => out.push_str("vu = VUnit.from_argv()\n"),
+    }
+    out.push_str("vu.add_vhdl_builtins()\n\n");
+    for library in &libraries {
+        out.push_str(&format!("vu.add_library(\"{}\")\n", library));
+    }
+    out.push('\n');
+    for file in &order.files {
+        let standard = match vunit_standard(&file.standard) {
+            Some(std) if file.standard != default => format!(", vhdl_standard=\"{}\"", std),
+            _ => String::new(),
+        };
+        out.push_str(&format!(
+            "vu.library(\"{}\").add_source_file({}{})\n",
+            file.library,
+            python_string(&file.path),
+            standard
+        ));
+    }
+    out.push_str("\nvu.main()\n");
+    out
+}
+
+/// One path per line.
+pub fn text(order: &CompileOrder) -> String {
+    order
+        .files
+        .iter()
+        .map(|file| format!("{}\n", file.path))
+        .collect()
+}
+
+fn ghdl_std(standard: &str) -> Option<&'static str> {
+    match standard {
+        "1987" => Some("87"),
+        "1993" => Some("93"),
+        "2000" => Some("00"),
+        "2002" => Some("02"),
+        "2008" => Some("08"),
+        "2019" => Some("19"),
+        _ => None,
+    }
+}
+
+/// VUnit only knows 93, 2002, 2008 and 2019.
+fn vunit_standard(standard: &str) -> Option<&'static str> {
+    match standard {
+        "1987" | "1993" => Some("93"),
+        "2000" | "2002" => Some("2002"),
+        "2008" => Some("2008"),
+        "2019" => Some("2019"),
+        _ => None,
+    }
+}
+
+fn shell_quote(path: &str) -> String {
+    if path
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "/._-+".contains(c))
+    {
+        path.to_string()
+    } else {
+        format!("'{}'", path.replace('\'', "'\\''"))
+    }
+}
+
+fn python_string(path: &str) -> String {
+    format!("\"{}\"", path.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{Architecture, Dependency, Entity, FileInfo, Package};
+
+    fn file(path: &str, library: &str) -> FileInfo {
+        FileInfo {
+            path: path.to_string(),
+            library: library.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn uses(source: &str, target: &str, kind: &str, line: usize) -> Dependency {
+        Dependency {
+            source: source.to_string(),
+            target: target.to_string(),
+            kind: kind.to_string(),
+            line,
+            resolved: true,
+        }
+    }
+
+    #[test]
+    fn orders_files_after_their_dependencies_and_reports_cycles() {
+        let mut input = Input {
+            standard: "2008".to_string(),
+            ..Default::default()
+        };
+        input.files = vec![
+            file("top.vhd", "work"),
+            file("alu_rtl.vhd", "work"),
+            file("alu.vhd", "work"),
+            file("my dir/util_pkg.vhd", "Util"),
+        ];
+        input.files[3].standard = "1993".to_string();
+        input.entities = vec![
+            Entity {
+                name: "top".to_string(),
+                file: "top.vhd".to_string(),
+                ..Default::default()
+            },
+            Entity {
+                name: "ALU".to_string(),
+                file: "alu.vhd".to_string(),
+                ..Default::default()
+            },
+        ];
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "alu".to_string(),
+            file: "alu_rtl.vhd".to_string(),
+            line: 4,
+            ..Default::default()
+        });
+        input.packages.push(Package {
+            name: "util_pkg".to_string(),
+            file: "my dir/util_pkg.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        input.dependencies = vec![
+            uses("top.vhd", "work.alu", "instantiation", 12),
+            uses("top.vhd", "util", "library", 1),
+            uses("alu.vhd", "util.util_pkg.all", "use", 2),
+            uses("top.vhd", "ieee.std_logic_1164.all", "use", 3),
+        ];
+
+        let order = compile_order(&input);
+        assert!(order.cycles.is_empty());
+        let paths: Vec<&str> = order.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            ["my dir/util_pkg.vhd", "alu.vhd", "top.vhd", "alu_rtl.vhd"]
+        );
+        assert_eq!(
+            ghdl(&order).lines().next(),
+            Some("ghdl -a --std=93 --work=util 'my dir/util_pkg.vhd'")
+        );
+        assert_eq!(text(&order).lines().nth(1), Some("alu.vhd"));
+        let run = vunit(&order);
+        assert!(run.contains("vu = VUnit.from_argv(vhdl_standard=\"93\")\n"));
+        assert!(run.contains("vu.add_library(\"util\")\nvu.add_library(\"work\")\n"));
+        assert!(run
+            .contains("vu.library(\"work\").add_source_file(\"alu.vhd\", vhdl_standard=\"2008\")"));
+        assert!(violations(&input).is_empty());
+
+        // The package now needs the ALU back: util_pkg -> alu -> util_pkg.
+        input
+            .dependencies
+            .push(uses("my dir/util_pkg.vhd", "work.alu", "instantiation", 9));
+        let order = compile_order(&input);
+        assert_eq!(order.files.len(), 4);
+        assert_eq!(order.files[0].path, "top.vhd");
+        let out = violations(&input);
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0].rule, "circular_dependency");
+        assert_eq!(out[0].file, "alu.vhd");
+        assert_eq!(out[0].line, 2);
+        assert_eq!(
+            out[0].message,
+            "Circular dependency between files: alu.vhd (util_pkg, line 2) -> my dir/util_pkg.vhd (alu, line 9) -> alu.vhd - no compile order exists"
+        );
+    }
+}
@@ -0,0 +1,547 @@
+use crate::policy::helpers;
+use crate::policy::input::{ClockDecl, CustomRule, DirectoryRules, Input};
+use crate::policy::naming;
+use crate::policy::vendor;
+use serde::Deserialize;
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+/// Project configuration files, in lookup order.
+pub const CONFIG_FILES: &[&str] = &["vhdl_lint.toml", ".vhdllint.yml", ".vhdllint.yaml"];
+
+/// Severity value `enable` records for optional rules. It switches the rule
+/// on without overriding its default severity.
+const ENABLED: &str = "on";
+
+/// Rule settings read from a `vhdl_lint.toml` or `.vhdllint.yml` at the
+/// project root. Config files in subdirectories (see `apply_nested`) may set
+/// only `[rules]` and `enable`, for the files below them.
+///
+/// ```toml
+/// exclude = ["build/**"]
+/// third_party = ["ip/**/*.vhd"]
+/// enable = ["naming_convention"]
+/// min_confidence = "medium"
+/// profile = "tb"
+/// target = "asic"
+/// vendor = "xilinx"
+/// top = ["soc_top"]
+/// testbench_roots = true
+/// link_template = "https://github.com/org/repo/blob/main/{file}#L{line}"
+///
+/// [rules]
+/// unused_signal = "off"
+/// missing_reset = "error"
+///
+/// [naming]
+/// signal = "[a-z][a-z0-9_]*"
+/// constant = "C_[A-Z0-9_]+"
+///
+/// [[custom_rules]]
+/// id = "no_latch_names"
+/// script = "lint/no_latch_names.rhai"
+///
+/// [[clocks]]
+/// name = "sys_clk"
+/// period = "10 ns"
+/// domain = "sys"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectConfig {
+    /// Rule name -> severity ("error", "warning", "info") or "off".
+    pub rules: HashMap<String, String>,
+    /// Optional rules to turn on at their default severity.
+    pub enable: Vec<String>,
+    /// File globs whose violations are dropped.
+    pub exclude: Vec<String>,
+    /// File globs treated as third-party code, besides the vendor code
+    /// `third_party` recognizes on its own.
+    pub third_party: Vec<String>,
+    /// Lowest violation confidence to report ("low", "medium", "high").
+    pub min_confidence: String,
+    /// Lint profile ("tb" for testbench checks); empty keeps the default.
+    pub profile: String,
+    /// Implementation target, "asic" or "fpga"; empty keeps the default.
+    pub target: String,
+    /// FPGA vendor whose primitives resolve ("xilinx", "intel", "lattice"),
+    /// or "none" for a vendor-neutral design; empty keeps the default.
+    pub vendor: String,
+    /// Top-level entities the `dead_code` rules measure reachability from.
+    pub top: Vec<String>,
+    /// Treat testbench entities as `dead_code` roots too.
+    pub testbench_roots: bool,
+    /// Identifier class -> regex for `naming_convention_custom`.
+    pub naming: HashMap<String, String>,
+    /// Rhai checks run by `policy::scripting`; `load` resolves their
+    /// scripts against the config file's directory.
+    pub custom_rules: Vec<CustomRule>,
+    /// Primary clocks; processes clocked by anything else are
+    /// `unknown_clock_source`.
+    pub clocks: Vec<ClockDecl>,
+    /// Repository browser URL of a violation in `--format markdown`, with
+    /// `{file}` and `{line}` placeholders.
+    pub link_template: String,
+}
+
+/// First config file present in `root`, if any.
+pub fn discover(root: &Path) -> Option<PathBuf> {
+    CONFIG_FILES
+        .iter()
+        .map(|name| root.join(name))
+        .find(|path| path.is_file())
+}
+
+/// Config files nested below `root`: the first of `CONFIG_FILES` in each
+/// directory between `root` (excluded) and one of `files`, with the directory
+/// as those paths spell it, shallowest first.
+pub fn discover_nested<'a>(
+    root: &Path,
+    files: impl IntoIterator<Item = &'a str>,
+) -> Vec<(String, PathBuf)> {
+    let mut dirs: BTreeSet<String> = BTreeSet::new();
+    for file in files {
+        let file = file.trim_start_matches("./").replace('\\', "/");
+        let components: Vec<&str> = file.split('/').collect();
+        for depth in 1..components.len() {
+            let dir = components[..depth].join("/");
+            if !dir.is_empty() {
+                dirs.insert(dir);
+            }
+        }
+    }
+    let mut dirs: Vec<String> = dirs.into_iter().collect();
+    dirs.sort_by_key(|dir| dir.matches('/').count());
+    dirs.into_iter()
+        .filter_map(|dir| {
+            let path = root.join(&dir);
+            if path == root || !path.starts_with(root) {
+                return None;
+            }
+            Some((dir, discover(&path)?))
+        })
+        .collect()
+}
+
+/// Loads the config files nested below `root` next to the files of `input`
+/// into `lint_config.directory_rules`; the engine resolves each violation's
+/// rule setting from the deepest one holding its file.
+pub fn apply_nested(root: &Path, input: &mut Input) -> Result<(), String> {
+    let nested = discover_nested(root, input.files.iter().map(|f| f.path.as_str()));
+    for (dir, path) in nested {
+        let rules = load(&path)?
+            .directory_rules(&dir)
+            .map_err(|e| format!("'{}': {}", path.display(), e))?;
+        input.lint_config.directory_rules.push(rules);
+    }
+    Ok(())
+}
+
+/// Reads a config file, choosing the format from its extension. Relative
+/// custom rule scripts are resolved against the file's directory.
+pub fn load(path: &Path) -> Result<ProjectConfig, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("reading '{}': {}", path.display(), e))?;
+    let parsed = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("yml") | Some("yaml") => parse_yaml(&text),
+        _ => parse_toml(&text),
+    };
+    let mut config = parsed.map_err(|e| format!("parsing '{}': {}", path.display(), e))?;
+    let dir = path.parent().unwrap_or(Path::new(""));
+    for rule in &mut config.custom_rules {
+        rule.script = dir.join(&rule.script).to_string_lossy().into_owned();
+    }
+    Ok(config)
+}
+
+pub fn parse_toml(text: &str) -> Result<ProjectConfig, String> {
+    let config: ProjectConfig = toml::from_str(text).map_err(|e| e.to_string())?;
+    config.validate()
+}
+
+pub fn parse_yaml(text: &str) -> Result<ProjectConfig, String> {
+    if text.trim().is_empty() {
+        return Ok(ProjectConfig::default());
+    }
+    let config: ProjectConfig = serde_yaml::from_str(text).map_err(|e| e.to_string())?;
+    config.validate()
+}
+
+impl ProjectConfig {
+    /// Rejects naming patterns for unknown classes or that don't compile,
+    /// custom rules without an id or script, and clocks without a name, so a
+    /// typo fails the load instead of silently disabling the check.
+    fn validate(self) -> Result<Self, String> {
+        naming::compile_naming_patterns(&self.naming)?;
+        if let Some(rule) = self
+            .custom_rules
+            .iter()
+            .find(|rule| rule.id.is_empty() || rule.script.is_empty())
+        {
+            return Err(format!(
+                "custom rule '{}' needs both an id and a script",
+                rule.id
+            ));
+        }
+        if self.clocks.iter().any(|clock| clock.name.is_empty()) {
+            return Err("every [[clocks]] entry needs a name".to_string());
+        }
+        if !matches!(
+            self.target.to_ascii_lowercase().as_str(),
+            "" | "asic" | "fpga"
+        ) {
+            return Err(format!(
+                "unknown target '{}' (expected asic or fpga)",
+                self.target
+            ));
+        }
+        if !self.vendor.is_empty() && !vendor::is_valid_setting(&self.vendor) {
+            return Err(format!(
+                "unknown vendor '{}' (expected xilinx, intel, lattice or none)",
+                self.vendor
+            ));
+        }
+        Ok(self)
+    }
+
+    /// The settings of a config file nested in `dir`, which must not set
+    /// anything but `rules` and `enable`: the rest is project-wide.
+    fn directory_rules(self, dir: &str) -> Result<DirectoryRules, String> {
+        let project_wide = ProjectConfig {
+            rules: HashMap::new(),
+            enable: Vec::new(),
+            ..self.clone()
+        };
+        if project_wide != ProjectConfig::default() {
+            return Err("a nested config may only set [rules] and enable".to_string());
+        }
+        let mut rules: HashMap<String, String> = self
+            .enable
+            .into_iter()
+            .map(|rule| (rule, ENABLED.to_string()))
+            .collect();
+        for (rule, severity) in self.rules {
+            rules.insert(rule, severity.to_ascii_lowercase());
+        }
+        Ok(DirectoryRules {
+            dir: dir.to_string(),
+            rules,
+        })
+    }
+
+    /// Merges the file into `input.lint_config`. Severities from the file win
+    /// over ones already in the input; `enable` only switches on rules the
+    /// input leaves unset, and so do `min_confidence`, `profile`, `target`,
+    /// `vendor`, `top` and each `naming` class. Custom rules and clocks are added unless the
+    /// input already has one with the same id or name.
+    /// Third-party globs are resolved against `input.files` so every check
+    /// that skips third-party code sees them.
+    pub fn apply(&self, input: &mut Input) {
+        let rules = &mut input.lint_config.rules;
+        for rule in &self.enable {
+            rules
+                .entry(rule.clone())
+                .or_insert_with(|| ENABLED.to_string());
+        }
+        for (rule, severity) in &self.rules {
+            rules.insert(rule.clone(), severity.to_ascii_lowercase());
+        }
+        for pattern in &self.exclude {
+            if !input.lint_config.exclude.contains(pattern) {
+                input.lint_config.exclude.push(pattern.clone());
+            }
+        }
+        if input.lint_config.min_confidence.is_empty() {
+            input.lint_config.min_confidence = self.min_confidence.to_ascii_lowercase();
+        }
+        if input.profile.is_empty() {
+            input.profile = self.profile.to_ascii_lowercase();
+        }
+        if input.lint_config.target.is_empty() {
+            input.lint_config.target = self.target.to_ascii_lowercase();
+        }
+        if input.lint_config.vendor.is_empty() {
+            input.lint_config.vendor = self.vendor.to_ascii_lowercase();
+        }
+        if input.top_entities.is_empty() {
+            input.top_entities = self.top.clone();
+        }
+        input.lint_config.testbench_roots |= self.testbench_roots;
+        for (class, pattern) in &self.naming {
+            input
+                .lint_config
+                .naming_patterns
+                .entry(class.to_ascii_lowercase())
+                .or_insert_with(|| pattern.clone());
+        }
+        for rule in &self.custom_rules {
+            let custom_rules = &mut input.lint_config.custom_rules;
+            if !custom_rules.iter().any(|known| known.id == rule.id) {
+                custom_rules.push(rule.clone());
+            }
+        }
+        for clock in &self.clocks {
+            let clocks = &mut input.lint_config.clocks;
+            if !clocks
+                .iter()
+                .any(|known| known.name.eq_ignore_ascii_case(&clock.name))
+            {
+                clocks.push(clock.clone());
+            }
+        }
+        if self.third_party.is_empty() {
+            return;
+        }
+        for file in &mut input.files {
+            if !helpers::path_matches_any(&self.third_party, &file.path) {
+                continue;
+            }
+            file.is_third_party = true;
+            if !input.third_party_files.contains(&file.path) {
+                input.third_party_files.push(file.path.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::engine;
+    use crate::policy::input::{Entity, FileInfo};
+
+    const TOML: &str = r#"
+exclude = ["build/**"]
+third_party = ["ip/**/*.vhd"]
+enable = ["naming_convention"]
+min_confidence = "Medium"
+profile = "TB"
+target = "ASIC"
+vendor = "Altera"
+top = ["soc_top"]
+testbench_roots = true
+
+[rules]
+unused_signal = "Error"
+
+[naming]
+signal = "[a-z][a-z0-9_]*"
+
+[[custom_rules]]
+id = "no_latch_names"
+script = "lint/no_latch_names.rhai"
+
+[[clocks]]
+name = "sys_clk"
+period = "10 ns"
+domain = "sys"
+"#;
+
+    const YAML: &str = "
+exclude: [build/**]
+third_party:
+  - ip/**/*.vhd
+enable: [naming_convention]
+min_confidence: Medium
+profile: TB
+target: ASIC
+vendor: Altera
+top: [soc_top]
+testbench_roots: true
+rules:
+  unused_signal: Error
+naming:
+  signal: '[a-z][a-z0-9_]*'
+custom_rules:
+  - id: no_latch_names
+    script: lint/no_latch_names.rhai
+clocks:
+  - name: sys_clk
+    period: 10 ns
+    domain: sys
+";
+
+    #[test]
+    fn toml_and_yaml_parse_to_the_same_config() {
+        let toml = parse_toml(TOML).unwrap();
+        assert_eq!(toml, parse_yaml(YAML).unwrap());
+        assert_eq!(toml.rules["unused_signal"], "Error");
+        assert_eq!(parse_yaml("").unwrap(), ProjectConfig::default());
+        assert!(parse_toml("severity = 1").is_err());
+        let err = parse_toml("[naming]\nport_in = \"(_i\"").unwrap_err();
+        assert!(err.contains("invalid naming pattern for 'port_in'"));
+        let err = parse_toml("[[custom_rules]]\nid = \"x\"").unwrap_err();
+        assert!(err.contains("custom rule 'x' needs both an id and a script"));
+        let err = parse_toml("[[clocks]]\nperiod = \"10 ns\"").unwrap_err();
+        assert!(err.contains("needs a name"));
+        let err = parse_toml("target = \"cpld\"").unwrap_err();
+        assert!(err.contains("unknown target 'cpld'"));
+        let err = parse_toml("vendor = \"actel\"").unwrap_err();
+        assert!(err.contains("unknown vendor 'actel'"));
+    }
+
+    #[test]
+    fn apply_merges_into_lint_config() {
+        let mut input = Input {
+            files: vec![
+                FileInfo {
+                    path: "./ip/uart/uart.vhd".to_string(),
+                    library: "work".to_string(),
+                    ..Default::default()
+                },
+                FileInfo {
+                    path: "rtl/top.vhd".to_string(),
+                    library: "work".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        input
+            .lint_config
+            .rules
+            .insert("naming_convention".to_string(), "warning".to_string());
+        input
+            .lint_config
+            .rules
+            .insert("unused_signal".to_string(), "info".to_string());
+        parse_toml(TOML).unwrap().apply(&mut input);
+
+        let rules = &input.lint_config.rules;
+        assert_eq!(rules["naming_convention"], "warning");
+        assert_eq!(rules["unused_signal"], "error");
+        assert_eq!(input.lint_config.exclude, ["build/**"]);
+        assert_eq!(input.lint_config.min_confidence, "medium");
+        assert_eq!(input.profile, "tb");
+        assert_eq!(input.lint_config.target, "asic");
+        assert_eq!(input.lint_config.vendor, "altera");
+        assert_eq!(input.top_entities, ["soc_top"]);
+        assert!(input.lint_config.testbench_roots);
+        assert_eq!(
+            input.lint_config.naming_patterns["signal"],
+            "[a-z][a-z0-9_]*"
+        );
+        assert_eq!(input.lint_config.custom_rules[0].id, "no_latch_names");
+        assert_eq!(input.lint_config.clocks[0].domain, "sys");
+        assert_eq!(input.third_party_files, ["./ip/uart/uart.vhd"]);
+        assert!(input.files[0].is_third_party);
+        assert!(!input.files[1].is_third_party);
+    }
+
+    #[test]
+    fn excluded_files_report_nothing() {
+        let entity = |file: &str| Entity {
+            name: "core".to_string(),
+            file: file.to_string(),
+            line: 1,
+            ..Default::default()
+        };
+        let mut input = Input {
+            entities: vec![entity("build/gen/core.vhd"), entity("rtl/core.vhd")],
+            ..Default::default()
+        };
+        parse_toml("enable = [\"entity_has_ports\"]\nexclude = [\"build/**\"]")
+            .unwrap()
+            .apply(&mut input);
+        let result = engine::evaluate(&input);
+        assert!(result
+            .violations
+            .iter()
+            .all(|v| !v.file.starts_with("build/")));
+        assert!(result
+            .violations
+            .iter()
+            .any(|v| v.rule == "entity_has_ports" && v.file == "rtl/core.vhd"));
+    }
+
+    #[test]
+    fn custom_rule_scripts_resolve_against_the_config_directory() {
+        let root = std::env::temp_dir().join(format!("vhdl_config_scripts_{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        let path = root.join("vhdl_lint.toml");
+        std::fs::write(
+            &path,
+            "[[custom_rules]]\nid = \"a\"\nscript = \"lint/a.rhai\"\n\n\
+             [[custom_rules]]\nid = \"b\"\nscript = \"/opt/lint/b.rhai\"\n",
+        )
+        .unwrap();
+        let config = load(&path).unwrap();
+        let _ = std::fs::remove_dir_all(&root);
+
+        let scripts: Vec<&str> = config
+            .custom_rules
+            .iter()
+            .map(|rule| rule.script.as_str())
+            .collect();
+        let relative = root.join("lint/a.rhai");
+        assert_eq!(scripts, [relative.to_str().unwrap(), "/opt/lint/b.rhai"]);
+    }
+
+    #[test]
+    fn nested_configs_override_rules_below_their_directory() {
+        let root = std::env::temp_dir().join(format!("vhdl_nested_config_{}", std::process::id()));
+        for (file, text) in [
+            (
+                "rtl/legacy/.vhdllint.yml",
+                "rules:\n  entity_has_ports: off\n",
+            ),
+            (
+                "rtl/legacy/new/vhdl_lint.toml",
+                "[rules]\nentity_has_ports = \"error\"\n",
+            ),
+            ("ip/.vhdllint.yml", "exclude: [\"**\"]\n"),
+        ] {
+            let path = root.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, text).unwrap();
+        }
+        let files = [
+            "rtl/core.vhd",
+            "./rtl/legacy/uart.vhd",
+            "rtl/legacy/new/spi.vhd",
+        ];
+        let mut input = Input::default();
+        for file in files {
+            input.files.push(FileInfo {
+                path: file.to_string(),
+                ..Default::default()
+            });
+            input.entities.push(Entity {
+                name: "core".to_string(),
+                file: file.to_string(),
+                line: 1,
+                ..Default::default()
+            });
+        }
+        parse_toml("enable = [\"entity_has_ports\"]")
+            .unwrap()
+            .apply(&mut input);
+        apply_nested(&root, &mut input).unwrap();
+        let dirs: Vec<&str> = input
+            .lint_config
+            .directory_rules
+            .iter()
+            .map(|rules| rules.dir.as_str())
+            .collect();
+        assert_eq!(dirs, ["rtl/legacy", "rtl/legacy/new"]);
+
+        let result = engine::evaluate(&input);
+        let reported: Vec<(&str, &str)> = result
+            .violations
+            .iter()
+            .filter(|v| v.rule == "entity_has_ports")
+            .map(|v| (v.file.as_str(), v.severity.as_str()))
+            .collect();
+        assert_eq!(reported.len(), 2);
+        assert_eq!(reported[0].0, "rtl/core.vhd");
+        assert_eq!(reported[1], ("rtl/legacy/new/spi.vhd", "error"));
+
+        input.files.push(FileInfo {
+            path: "ip/fifo.vhd".to_string(),
+            ..Default::default()
+        });
+        let err = apply_nested(&root, &mut input).unwrap_err();
+        assert!(err.contains("a nested config may only set [rules] and enable"));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -1,8 +1,17 @@
-use crate::policy::input::Input;
+use crate::policy::hierarchy::target_matches_entity;
+use crate::policy::input::{Architecture, Entity, Input};
 use crate::policy::result::Violation;
+use std::collections::HashSet;
 
 pub fn violations(input: &Input) -> Vec<Violation> {
-    configuration_missing_entity(input)
+    let mut out = Vec::new();
+    out.extend(configuration_missing_entity(input));
+    out.extend(unused_architecture(input));
+    out
+}
+
+pub fn reads() -> &'static [&'static str] {
+    &["architectures", "configurations", "entities", "instances"]
 }
 
 fn configuration_missing_entity(input: &Input) -> Vec<Violation> {
@@ -19,10 +28,91 @@ fn configuration_missing_entity(input: &Input) -> Vec<Violation> {
                 "Configuration '{}' references missing entity '{}'",
                 cfg.name, cfg.entity_name
             ),
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn unused_architecture(input: &Input) -> Vec<Violation> {
+    unselected_architectures(input)
+        .into_iter()
+        .map(|(entity, arch)| Violation {
+            rule: "unused_architecture".to_string(),
+            severity: "info".to_string(),
+            file: arch.file.clone(),
+            line: arch.line,
+            message: format!(
+                "Architecture '{}' of entity '{}' is never selected by a configuration or instantiation",
+                arch.name, entity.name
+            ),
+            ..Default::default()
         })
         .collect()
 }
 
+/// Architectures of a multi-architecture entity that no configuration or
+/// instantiation selects. Unqualified bindings pick the most recently analyzed
+/// architecture, which is only predictable when all of them share a file, so
+/// entities whose default binding is ambiguous are skipped.
+pub fn unselected_architectures(input: &Input) -> Vec<(&Entity, &Architecture)> {
+    let mut out = Vec::new();
+    for entity in &input.entities {
+        let archs: Vec<&Architecture> = input
+            .architectures
+            .iter()
+            .filter(|arch| arch.entity_name.eq_ignore_ascii_case(&entity.name))
+            .collect();
+        if archs.len() < 2 {
+            continue;
+        }
+
+        let mut selected: HashSet<String> = HashSet::new();
+        let mut referenced = false;
+        let mut default_binding = false;
+        let mut select = |arch: &str| {
+            referenced = true;
+            if arch.is_empty() {
+                default_binding = true;
+            } else {
+                selected.insert(arch.to_ascii_lowercase());
+            }
+        };
+        for inst in &input.instances {
+            if target_matches_entity(&inst.target, &entity.name) {
+                select(&inst.target_arch);
+            }
+        }
+        for cfg in &input.configurations {
+            if cfg.entity_name.eq_ignore_ascii_case(&entity.name) && !cfg.architecture.is_empty() {
+                select(&cfg.architecture);
+            }
+            for binding in &cfg.bindings {
+                if target_matches_entity(&binding.entity, &entity.name) {
+                    select(&binding.architecture);
+                }
+            }
+        }
+        if !referenced {
+            continue;
+        }
+        if default_binding {
+            if archs.iter().any(|arch| arch.file != archs[0].file) {
+                continue;
+            }
+            if let Some(last) = archs.iter().max_by_key(|arch| arch.line) {
+                selected.insert(last.name.to_ascii_lowercase());
+            }
+        }
+
+        for arch in archs {
+            if !selected.contains(&arch.name.to_ascii_lowercase()) {
+                out.push((entity, arch));
+            }
+        }
+    }
+    out
+}
+
 fn entity_exists(input: &Input, name: &str) -> bool {
     input
         .entities
@@ -33,7 +123,7 @@ fn entity_exists(input: &Input, name: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::policy::input::{Configuration, Entity, Input};
+    use crate::policy::input::{Configuration, ConfigurationBinding, Entity, Input, Instance};
 
     #[test]
     fn configuration_missing_entity_flags() {
@@ -43,6 +133,7 @@ mod tests {
             entity_name: "missing".to_string(),
             file: "a.vhd".to_string(),
             line: 10,
+            ..Default::default()
         });
         let violations = configuration_missing_entity(&input);
         assert_eq!(violations.len(), 1);
@@ -63,8 +154,91 @@ mod tests {
             entity_name: "core".to_string(),
             file: "a.vhd".to_string(),
             line: 10,
+            ..Default::default()
         });
         let violations = configuration_missing_entity(&input);
         assert!(violations.is_empty());
     }
+
+    fn input_with_archs(archs: &[(&str, &str, usize)]) -> Input {
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "core".to_string(),
+            file: "core.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        for (name, file, line) in archs {
+            input.architectures.push(Architecture {
+                name: name.to_string(),
+                entity_name: "core".to_string(),
+                file: file.to_string(),
+                line: *line,
+                ..Default::default()
+            });
+        }
+        input
+    }
+
+    fn instance(target: &str, arch: &str) -> Instance {
+        Instance {
+            name: "u_core".to_string(),
+            target: target.to_string(),
+            target_arch: arch.to_string(),
+            file: "top.vhd".to_string(),
+            line: 20,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn unused_architecture_flags_unselected_arch() {
+        let mut input = input_with_archs(&[("rtl", "core.vhd", 10), ("sim", "core.vhd", 30)]);
+        input.instances.push(instance("work.core", "RTL"));
+        let violations = unused_architecture(&input);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 30);
+        assert!(violations[0].message.contains("'sim'"));
+    }
+
+    #[test]
+    fn unused_architecture_honors_configuration_bindings() {
+        let mut input = input_with_archs(&[("rtl", "core.vhd", 10), ("sim", "core.vhd", 30)]);
+        input.instances.push(instance("work.core", "rtl"));
+        input.configurations.push(Configuration {
+            name: "cfg_top".to_string(),
+            entity_name: "top".to_string(),
+            architecture: "rtl".to_string(),
+            bindings: vec![ConfigurationBinding {
+                instance: "u_core".to_string(),
+                component: "core".to_string(),
+                entity: "work.core".to_string(),
+                architecture: "sim".to_string(),
+                line: 5,
+                ..Default::default()
+            }],
+            file: "cfg.vhd".to_string(),
+            line: 1,
+        });
+        assert!(unused_architecture(&input).is_empty());
+    }
+
+    #[test]
+    fn unused_architecture_default_binding_selects_last_arch() {
+        let mut input = input_with_archs(&[("rtl", "core.vhd", 10), ("sim", "core.vhd", 30)]);
+        input.instances.push(instance("core", ""));
+        let violations = unused_architecture(&input);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("'rtl'"));
+
+        let mut input = input_with_archs(&[("rtl", "core.vhd", 10), ("sim", "core_sim.vhd", 3)]);
+        input.instances.push(instance("core", ""));
+        assert!(unused_architecture(&input).is_empty());
+    }
+
+    #[test]
+    fn unused_architecture_skips_unreferenced_entities() {
+        let input = input_with_archs(&[("rtl", "core.vhd", 10), ("sim", "core.vhd", 30)]);
+        assert!(unused_architecture(&input).is_empty());
+    }
 }
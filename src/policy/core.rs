@@ -1,6 +1,8 @@
 use crate::policy::helpers::{self, is_testbench_name};
-use crate::policy::input::{Component, Input};
+use crate::policy::input::{Component, Input, Process};
+use crate::policy::libraries::LibraryMap;
 use crate::policy::result::Violation;
+use crate::policy::vendor;
 use std::collections::HashMap;
 
 pub fn violations(input: &Input) -> Vec<Violation> {
@@ -13,9 +15,25 @@ pub fn violations(input: &Input) -> Vec<Violation> {
     out.extend(entity_without_arch(input));
     out.extend(duplicate_entity_in_library(input));
     out.extend(duplicate_package_in_library(input));
+    out.extend(unknown_library(input));
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "architectures",
+        "case_statements",
+        "components",
+        "dependencies",
+        "entities",
+        "files",
+        "library_clauses",
+        "lint_config",
+        "packages",
+        "processes",
+    ]
+}
+
 fn missing_ports(input: &Input) -> Vec<Violation> {
     input
         .entities
@@ -27,6 +45,7 @@ fn missing_ports(input: &Input) -> Vec<Violation> {
             file: entity.file.clone(),
             line: entity.line,
             message: format!("Entity '{}' has no ports defined", entity.name),
+            ..Default::default()
         })
         .collect()
 }
@@ -45,16 +64,18 @@ fn orphan_architecture(input: &Input) -> Vec<Violation> {
                 "Architecture '{}' references undefined entity '{}'",
                 arch.name, arch.entity_name
             ),
+            ..Default::default()
         })
         .collect()
 }
 
 fn unresolved_component(input: &Input) -> Vec<Violation> {
+    let libraries = LibraryMap::new(input);
     input
         .components
         .iter()
         .filter(|comp| comp.is_instance && !comp.entity_ref.is_empty())
-        .filter(|comp| !component_or_entity_exists(input, comp))
+        .filter(|comp| !component_or_entity_exists(input, &libraries, comp))
         .map(|comp| Violation {
             rule: "component_resolved".to_string(),
             severity: "warning".to_string(),
@@ -64,6 +85,7 @@ fn unresolved_component(input: &Input) -> Vec<Violation> {
                 "Component instance '{}' references undefined '{}'",
                 comp.name, comp.entity_ref
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -73,23 +95,24 @@ fn unresolved_dependency(input: &Input) -> Vec<Violation> {
         .dependencies
         .iter()
         .filter(|dep| !dep.resolved && dep.kind == "instantiation")
+        .filter(|dep| !vendor::resolves(input, &dep.target))
         .map(|dep| Violation {
             rule: "unresolved_dependency".to_string(),
             severity: "error".to_string(),
             file: dep.source.clone(),
             line: dep.line,
             message: format!("Unresolved dependency: '{}'", dep.target),
+            ..Default::default()
         })
         .collect()
 }
 
 fn potential_latch(input: &Input) -> Vec<Violation> {
-    input
+    let mut out: Vec<Violation> = input
         .case_statements
         .iter()
         .filter(|cs| !cs.has_others)
         .filter(|cs| case_in_combinational_process(input, cs))
-        .filter(|cs| !helpers::file_in_testbench(input, &cs.file))
         .map(|cs| Violation {
             rule: "potential_latch".to_string(),
             severity: "warning".to_string(),
@@ -99,8 +122,130 @@ fn potential_latch(input: &Input) -> Vec<Violation> {
                 "Case statement on '{}' missing 'when others =>' (potential latch in process '{}')",
                 cs.expression, cs.in_process
             ),
+            ..Default::default()
         })
-        .collect()
+        .collect();
+    for proc in input.processes.iter().filter(|proc| proc.is_combinational) {
+        out.extend(incomplete_if_assignments(proc));
+    }
+    out
+}
+
+/// Signals a combinational process assigns in some branches of an if/elsif
+/// chain but not on every path through it, with no default assignment in an
+/// enclosing statement list. Reported at each branch that misses the
+/// assignment, or at the `if` when the chain has no `else`.
+fn incomplete_if_assignments(proc: &Process) -> Vec<Violation> {
+    let mut signals: Vec<String> = Vec::new();
+    for assign in &proc.assignments {
+        let signal = assign.signal.to_ascii_lowercase();
+        if !signal.is_empty() && !signals.contains(&signal) {
+            signals.push(signal);
+        }
+    }
+    let mut out = Vec::new();
+    for signal in &signals {
+        let mut missing = Vec::new();
+        missing_branches(proc, signal, "", &mut missing);
+        let name = proc
+            .assignments
+            .iter()
+            .find(|assign| assign.signal.eq_ignore_ascii_case(signal))
+            .map_or(signal.as_str(), |assign| assign.signal.as_str());
+        for (line, place) in missing {
+            out.push(Violation {
+                rule: "potential_latch".to_string(),
+                severity: "warning".to_string(),
+                file: proc.file.clone(),
+                line,
+                message: format!(
+                    "Signal '{}' in combinational process '{}' is not assigned {} and has no default assignment (potential latch)",
+                    name, proc.label, place
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+/// Collects (line, description) for every branch under `path` that leaves
+/// `signal` unassigned although a sibling branch assigns it.
+fn missing_branches(proc: &Process, signal: &str, path: &str, out: &mut Vec<(usize, String)>) {
+    if always_assigned(proc, signal, path) {
+        return;
+    }
+    for stmt in proc.if_statements.iter().filter(|stmt| stmt.branch == path) {
+        let arm_paths: Vec<String> = stmt
+            .arms
+            .iter()
+            .map(|arm| format!("{}/{}", path, arm.branch))
+            .collect();
+        if !arm_paths
+            .iter()
+            .any(|arm_path| assigned_under(proc, signal, arm_path))
+        {
+            continue;
+        }
+        for (arm, arm_path) in stmt.arms.iter().zip(&arm_paths) {
+            if always_assigned(proc, signal, arm_path) {
+                continue;
+            }
+            if assigned_under(proc, signal, arm_path) {
+                missing_branches(proc, signal, arm_path, out);
+            } else {
+                out.push((arm.line, format!("in the branch at line {}", arm.line)));
+            }
+        }
+        if !stmt.has_else {
+            out.push((
+                stmt.line,
+                format!(
+                    "when no branch of the if at line {} is taken (no else)",
+                    stmt.line
+                ),
+            ));
+        }
+    }
+}
+
+/// Whether every pass through the statement list at `path` assigns
+/// `signal`: directly, through an if chain with an `else` whose branches all
+/// assign it, or inside a case or loop (left to the case checks).
+fn always_assigned(proc: &Process, signal: &str, path: &str) -> bool {
+    let direct = proc.assignments.iter().any(|assign| {
+        if !assign.signal.eq_ignore_ascii_case(signal) {
+            return false;
+        }
+        if assign.branch == path {
+            return true;
+        }
+        assign
+            .branch
+            .strip_prefix(path)
+            .and_then(|rest| rest.strip_prefix('/'))
+            .is_some_and(|rest| !rest.starts_with("if@"))
+    });
+    direct
+        || proc.if_statements.iter().any(|stmt| {
+            stmt.branch == path
+                && stmt.has_else
+                && stmt
+                    .arms
+                    .iter()
+                    .all(|arm| always_assigned(proc, signal, &format!("{}/{}", path, arm.branch)))
+        })
+}
+
+fn assigned_under(proc: &Process, signal: &str, path: &str) -> bool {
+    proc.assignments.iter().any(|assign| {
+        assign.signal.eq_ignore_ascii_case(signal)
+            && (assign.branch == path
+                || assign
+                    .branch
+                    .strip_prefix(path)
+                    .is_some_and(|rest| rest.starts_with('/')))
+    })
 }
 
 fn case_in_combinational_process(input: &Input, cs: &crate::policy::input::CaseStatement) -> bool {
@@ -123,20 +268,21 @@ fn entity_without_arch(input: &Input) -> Vec<Violation> {
             file: entity.file.clone(),
             line: entity.line,
             message: format!("Entity '{}' has no architecture defined", entity.name),
+            ..Default::default()
         })
         .collect()
 }
 
 fn duplicate_entity_in_library(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
-    let lib_map = file_library_map(input);
+    let libraries = LibraryMap::new(input);
     let mut seen: HashMap<(String, String), (String, usize)> = HashMap::new();
 
     for entity in &input.entities {
         if helpers::is_third_party_file(input, &entity.file) {
             continue;
         }
-        let lib = library_for_file(&lib_map, &entity.file);
+        let lib = libraries.library_of(&entity.file);
         let key = (lib.clone(), entity.name.to_ascii_lowercase());
         if let Some((first_file, first_line)) = seen.get(&key) {
             if &entity.file == first_file {
@@ -151,6 +297,7 @@ fn duplicate_entity_in_library(input: &Input) -> Vec<Violation> {
                     "Entity '{}' is defined multiple times in library '{}' (first seen at {}:{})",
                     entity.name, lib, first_file, first_line
                 ),
+                ..Default::default()
             });
         } else {
             seen.insert(key, (entity.file.clone(), entity.line));
@@ -161,14 +308,14 @@ fn duplicate_entity_in_library(input: &Input) -> Vec<Violation> {
 
 fn duplicate_package_in_library(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
-    let lib_map = file_library_map(input);
+    let libraries = LibraryMap::new(input);
     let mut seen: HashMap<(String, String), (String, usize)> = HashMap::new();
 
     for pkg in &input.packages {
         if helpers::is_third_party_file(input, &pkg.file) {
             continue;
         }
-        let lib = library_for_file(&lib_map, &pkg.file);
+        let lib = libraries.library_of(&pkg.file);
         let key = (lib.clone(), pkg.name.to_ascii_lowercase());
         if let Some((first_file, first_line)) = seen.get(&key) {
             if &pkg.file == first_file {
@@ -183,6 +330,7 @@ fn duplicate_package_in_library(input: &Input) -> Vec<Violation> {
                     "Package '{}' is defined multiple times in library '{}' (first seen at {}:{})",
                     pkg.name, lib, first_file, first_line
                 ),
+                ..Default::default()
             });
         } else {
             seen.insert(key, (pkg.file.clone(), pkg.line));
@@ -191,21 +339,36 @@ fn duplicate_package_in_library(input: &Input) -> Vec<Violation> {
     out
 }
 
-fn file_library_map(input: &Input) -> HashMap<String, String> {
-    let mut map = HashMap::new();
-    for file in &input.files {
-        let lib = if file.library.is_empty() {
-            "work".to_string()
-        } else {
-            file.library.to_ascii_lowercase()
-        };
-        map.insert(file.path.clone(), lib);
+/// Library clauses naming a library no project file is mapped to. Skipped
+/// when the input carries no file list to build the library map from.
+fn unknown_library(input: &Input) -> Vec<Violation> {
+    if input.files.is_empty() {
+        return Vec::new();
     }
-    map
-}
-
-fn library_for_file(map: &HashMap<String, String>, file: &str) -> String {
-    map.get(file).cloned().unwrap_or_else(|| "work".to_string())
+    let libraries = LibraryMap::new(input);
+    let mut out = Vec::new();
+    for clause in &input.library_clauses {
+        if helpers::is_third_party_file(input, &clause.file) {
+            continue;
+        }
+        for name in &clause.libraries {
+            if libraries.is_known(&libraries.resolve(&clause.file, name)) {
+                continue;
+            }
+            out.push(Violation {
+                rule: "unknown_library".to_string(),
+                severity: "error".to_string(),
+                file: clause.file.clone(),
+                line: clause.line,
+                message: format!(
+                    "Library '{}' has no files mapped to it - add it to the library configuration",
+                    name
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    out
 }
 
 fn entity_exists(input: &Input, name: &str) -> bool {
@@ -215,8 +378,22 @@ fn entity_exists(input: &Input, name: &str) -> bool {
         .any(|entity| entity.name.eq_ignore_ascii_case(name))
 }
 
-fn component_or_entity_exists(input: &Input, comp: &Component) -> bool {
+/// `lib.entity` references resolve in that library (`work` being the
+/// instantiating file's); references to libraries without files, and plain
+/// names, match any entity or component of that name.
+fn component_or_entity_exists(input: &Input, libraries: &LibraryMap, comp: &Component) -> bool {
     let target = base_entity_name(&comp.entity_ref);
+    let library = comp
+        .entity_ref
+        .rsplit_once('.')
+        .map(|(prefix, _)| libraries.resolve(&comp.file, prefix))
+        .filter(|lib| libraries.has_files(lib));
+    if let Some(library) = library {
+        return input.entities.iter().any(|entity| {
+            entity.name.eq_ignore_ascii_case(&target)
+                && libraries.library_of(&entity.file) == library
+        });
+    }
     input
         .entities
         .iter()
@@ -226,6 +403,7 @@ fn component_or_entity_exists(input: &Input, comp: &Component) -> bool {
             .iter()
             .filter(|c| !c.is_instance)
             .any(|c| c.name.eq_ignore_ascii_case(&target))
+        || vendor::resolves(input, &comp.entity_ref)
 }
 
 fn base_entity_name(name: &str) -> String {
@@ -247,8 +425,8 @@ fn has_architecture(input: &Input, entity_name: &str) -> bool {
 mod tests {
     use super::*;
     use crate::policy::input::{
-        Architecture, CaseStatement, Component, Dependency, Entity, FileInfo, Input, Package, Port,
-        Process,
+        Architecture, CaseStatement, Component, Dependency, Entity, FileInfo, IfArm, IfStatement,
+        Input, LibraryClause, Package, Port, Process, SequentialAssignment,
     };
 
     fn base_input() -> Input {
@@ -264,6 +442,7 @@ mod tests {
             line: 1,
             ports: vec![],
             generics: vec![],
+            ..Default::default()
         });
         let violations = missing_ports(&input);
         assert_eq!(violations.len(), 1);
@@ -279,6 +458,7 @@ mod tests {
             line: 1,
             ports: vec![],
             generics: vec![],
+            ..Default::default()
         });
         let violations = missing_ports(&input);
         assert!(violations.is_empty());
@@ -292,6 +472,7 @@ mod tests {
             entity_name: "missing".to_string(),
             file: "a.vhd".to_string(),
             line: 2,
+            ..Default::default()
         });
         let violations = orphan_architecture(&input);
         assert_eq!(violations.len(), 1);
@@ -330,6 +511,26 @@ mod tests {
         assert_eq!(violations[0].rule, "unresolved_dependency");
     }
 
+    #[test]
+    fn configured_vendor_resolves_its_primitives() {
+        let mut input = base_input();
+        for target in ["BUFG", "unisim.IBUFDS", "altsyncram"] {
+            input.dependencies.push(Dependency {
+                source: "a.vhd".to_string(),
+                target: target.to_string(),
+                kind: "instantiation".to_string(),
+                line: 4,
+                resolved: false,
+            });
+        }
+        assert_eq!(unresolved_dependency(&input).len(), 3);
+
+        input.lint_config.vendor = "xilinx".to_string();
+        let violations = unresolved_dependency(&input);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("'altsyncram'"));
+    }
+
     #[test]
     fn potential_latch_flags_missing_others() {
         let mut input = base_input();
@@ -354,6 +555,55 @@ mod tests {
         assert_eq!(violations[0].rule, "potential_latch");
     }
 
+    #[test]
+    fn potential_latch_flags_if_branches_missing_assignment() {
+        let assign = |signal: &str, line: usize, branch: &str| SequentialAssignment {
+            target: signal.to_string(),
+            signal: signal.to_string(),
+            line,
+            branch: branch.to_string(),
+            ..Default::default()
+        };
+        let arm = |line: usize, idx: usize| IfArm {
+            line,
+            branch: format!("if@100.{}", idx),
+            ..Default::default()
+        };
+        let mut input = base_input();
+        input.processes.push(Process {
+            label: "comb".to_string(),
+            is_combinational: true,
+            file: "a.vhd".to_string(),
+            line: 8,
+            // y <= '0';
+            // if a then x <= ..; y <= ..; elsif b then y <= ..; else null; end if;
+            assignments: vec![
+                assign("y", 10, ""),
+                assign("x", 12, "/if@100.0"),
+                assign("y", 13, "/if@100.0"),
+                assign("y", 15, "/if@100.1"),
+            ],
+            if_statements: vec![IfStatement {
+                branch: String::new(),
+                line: 11,
+                arms: vec![arm(11, 0), arm(14, 1), arm(16, 2)],
+                has_else: true,
+            }],
+            ..Default::default()
+        });
+        let violations = potential_latch(&input);
+        let lines: Vec<usize> = violations.iter().map(|v| v.line).collect();
+        assert_eq!(lines, [14, 16]);
+        assert!(violations.iter().all(|v| v.message.contains("'x'")));
+
+        input.processes[0].if_statements[0].has_else = false;
+        input.processes[0].if_statements[0].arms.pop();
+        let violations = potential_latch(&input);
+        let lines: Vec<usize> = violations.iter().map(|v| v.line).collect();
+        assert_eq!(lines, [14, 11]);
+        assert!(violations[1].message.contains("no else"));
+    }
+
     #[test]
     fn entity_without_arch_flags_missing_arch() {
         let mut input = base_input();
@@ -363,6 +613,7 @@ mod tests {
             line: 6,
             ports: vec![Port::default()],
             generics: vec![],
+            ..Default::default()
         });
         let violations = entity_without_arch(&input);
         assert_eq!(violations.len(), 1);
@@ -390,6 +641,7 @@ mod tests {
             line: 1,
             ports: vec![],
             generics: vec![],
+            ..Default::default()
         });
         input.entities.push(Entity {
             name: "dup_ent".to_string(),
@@ -397,6 +649,7 @@ mod tests {
             line: 2,
             ports: vec![],
             generics: vec![],
+            ..Default::default()
         });
         let violations = duplicate_entity_in_library(&input);
         assert_eq!(violations.len(), 1);
@@ -424,6 +677,7 @@ mod tests {
             line: 1,
             ports: vec![],
             generics: vec![],
+            ..Default::default()
         });
         input.entities.push(Entity {
             name: "dup_ent".to_string(),
@@ -431,6 +685,7 @@ mod tests {
             line: 2,
             ports: vec![],
             generics: vec![],
+            ..Default::default()
         });
         let violations = duplicate_entity_in_library(&input);
         assert!(violations.is_empty());
@@ -455,14 +710,80 @@ mod tests {
             name: "dup_pkg".to_string(),
             file: "a.vhd".to_string(),
             line: 1,
+            ..Default::default()
         });
         input.packages.push(Package {
             name: "dup_pkg".to_string(),
             file: "b.vhd".to_string(),
             line: 2,
+            ..Default::default()
         });
         let violations = duplicate_package_in_library(&input);
         assert_eq!(violations.len(), 1);
         assert_eq!(violations[0].rule, "duplicate_package_in_library");
     }
+
+    #[test]
+    fn library_references_resolve_per_library() {
+        let mut input = base_input();
+        for (path, library) in [("dsp/fir.vhd", "dsp"), ("top.vhd", "work")] {
+            input.files.push(FileInfo {
+                path: path.to_string(),
+                library: library.to_string(),
+                ..Default::default()
+            });
+        }
+        input.entities.push(Entity {
+            name: "fir".to_string(),
+            file: "dsp/fir.vhd".to_string(),
+            line: 1,
+            ports: vec![Port::default()],
+            generics: vec![],
+            ..Default::default()
+        });
+        let instance = |name: &str, entity_ref: &str, file: &str| Component {
+            name: name.to_string(),
+            entity_ref: entity_ref.to_string(),
+            file: file.to_string(),
+            line: 3,
+            is_instance: true,
+            ports: vec![],
+            generics: vec![],
+        };
+        input
+            .components
+            .push(instance("u_dsp", "dsp.fir", "top.vhd"));
+        input
+            .components
+            .push(instance("u_self", "work.fir", "dsp/fir.vhd"));
+        input
+            .components
+            .push(instance("u_vendor", "unisim.fir", "top.vhd"));
+        input
+            .components
+            .push(instance("u_work", "work.fir", "top.vhd"));
+        let unresolved = unresolved_component(&input);
+        assert_eq!(unresolved.len(), 1);
+        assert!(unresolved[0].message.contains("'u_work'"));
+
+        input.library_clauses.push(LibraryClause {
+            libraries: vec![
+                "ieee".to_string(),
+                "work".to_string(),
+                "DSP".to_string(),
+                "unisim".to_string(),
+            ],
+            file: "top.vhd".to_string(),
+            line: 1,
+        });
+        let unknown = unknown_library(&input);
+        assert_eq!(unknown.len(), 1);
+        assert_eq!(unknown[0].rule, "unknown_library");
+        assert!(unknown[0]
+            .message
+            .starts_with("Library 'unisim' has no files"));
+
+        input.files.clear();
+        assert!(unknown_library(&input).is_empty());
+    }
 }
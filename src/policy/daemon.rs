@@ -0,0 +1,498 @@
+//! Client for the `vhdl_policyd` protocol (`init`/`delta`/`snapshot`) for
+//! callers that hold a policy `Input` rather than the Go extractor's fact
+//! tables, such as watch mode. The input is lowered to the tables the daemon
+//! reads, and each later state is sent as a `delta` of the rows that changed.
+
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::policy::input::Input;
+use crate::policy::result::Violation;
+use crate::policy::vendor;
+
+/// Rules `vhdl_policyd` maintains; callers take every other rule from the
+/// batch engine.
+pub const RULES: &[&str] = &[
+    "entity_has_ports",
+    "architecture_has_entity",
+    "entity_without_arch",
+    "unresolved_dependency",
+    "unused_signal",
+    "undriven_output_port",
+    "floating_instance_input",
+    "port_width_mismatch",
+];
+
+/// The fact tables `vhdl_policyd` reads, in the Go `facts.Tables` JSON shape.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct Tables {
+    pub entities: Vec<EntityRow>,
+    pub architectures: Vec<ArchitectureRow>,
+    pub ports: Vec<PortRow>,
+    pub signals: Vec<SignalRow>,
+    pub instances: Vec<InstanceRow>,
+    pub dependencies: Vec<DependencyRow>,
+    pub symbols: Vec<SymbolRow>,
+    pub signal_uses: Vec<SignalUseRow>,
+    pub port_maps: Vec<PortMapRow>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EntityRow {
+    pub name: String,
+    pub file: String,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ArchitectureRow {
+    pub name: String,
+    pub entity_name: String,
+    pub file: String,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PortRow {
+    pub entity: String,
+    pub name: String,
+    pub direction: String,
+    pub default: String,
+    pub width: usize,
+    pub file: String,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SignalRow {
+    pub name: String,
+    pub width: usize,
+    pub file: String,
+    pub line: usize,
+    pub scope: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct InstanceRow {
+    pub name: String,
+    pub target: String,
+    pub file: String,
+    pub line: usize,
+    pub in_arch: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DependencyRow {
+    pub file: String,
+    pub target: String,
+    pub kind: String,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SymbolRow {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SignalUseRow {
+    pub name: String,
+    pub kind: String,
+    pub file: String,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PortMapRow {
+    pub instance: String,
+    pub formal: String,
+    pub actual: String,
+    pub file: String,
+    pub line: usize,
+}
+
+impl Tables {
+    /// Rows of `self` not matched by a row of `other`, counting duplicates,
+    /// so `next.minus(prev)` / `prev.minus(next)` are a delta's added and
+    /// removed sides.
+    pub fn minus(&self, other: &Tables) -> Tables {
+        Tables {
+            entities: multiset_minus(&self.entities, &other.entities),
+            architectures: multiset_minus(&self.architectures, &other.architectures),
+            ports: multiset_minus(&self.ports, &other.ports),
+            signals: multiset_minus(&self.signals, &other.signals),
+            instances: multiset_minus(&self.instances, &other.instances),
+            dependencies: multiset_minus(&self.dependencies, &other.dependencies),
+            symbols: multiset_minus(&self.symbols, &other.symbols),
+            signal_uses: multiset_minus(&self.signal_uses, &other.signal_uses),
+            port_maps: multiset_minus(&self.port_maps, &other.port_maps),
+        }
+    }
+}
+
+fn multiset_minus<T: Ord + Clone>(rows: &[T], other: &[T]) -> Vec<T> {
+    let mut counts: BTreeMap<&T, usize> = BTreeMap::new();
+    for row in other {
+        *counts.entry(row).or_default() += 1;
+    }
+    rows.iter()
+        .filter(|row| match counts.get_mut(row) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                false
+            }
+            _ => true,
+        })
+        .cloned()
+        .collect()
+}
+
+/// Lowers `input` to daemon tables. Signal uses come from process and
+/// concurrent-assignment reads/writes plus signal usages, as the Go fact
+/// tables do; an instantiation resolves when its target names an entity,
+/// standing in for the Go symbol table.
+pub fn tables(input: &Input) -> Tables {
+    let mut tables = Tables::default();
+    for entity in &input.entities {
+        tables.entities.push(EntityRow {
+            name: entity.name.clone(),
+            file: entity.file.clone(),
+            line: entity.line,
+        });
+        for port in &entity.ports {
+            tables.ports.push(PortRow {
+                entity: entity.name.clone(),
+                name: port.name.clone(),
+                direction: port.direction.clone(),
+                default: port.default.clone(),
+                width: port.width,
+                file: entity.file.clone(),
+                line: port.line,
+            });
+        }
+    }
+    for arch in &input.architectures {
+        tables.architectures.push(ArchitectureRow {
+            name: arch.name.clone(),
+            entity_name: arch.entity_name.clone(),
+            file: arch.file.clone(),
+            line: arch.line,
+        });
+    }
+    for sig in &input.signals {
+        tables.signals.push(SignalRow {
+            name: sig.name.clone(),
+            width: sig.width,
+            file: sig.file.clone(),
+            line: sig.line,
+            scope: sig.in_entity.clone(),
+        });
+    }
+    for inst in &input.instances {
+        tables.instances.push(InstanceRow {
+            name: inst.name.clone(),
+            target: inst.target.clone(),
+            file: inst.file.clone(),
+            line: inst.line,
+            in_arch: inst.in_arch.clone(),
+        });
+        for (formal, actual) in &inst.port_map {
+            tables.port_maps.push(PortMapRow {
+                instance: inst.name.clone(),
+                formal: formal.clone(),
+                actual: actual.clone(),
+                file: inst.file.clone(),
+                line: inst.line,
+            });
+        }
+    }
+    for dep in &input.dependencies {
+        tables.dependencies.push(DependencyRow {
+            file: dep.source.clone(),
+            target: dep.target.clone(),
+            kind: dep.kind.clone(),
+            line: dep.line,
+        });
+        if dep.kind == "instantiation"
+            && (dep.resolved
+                || names_entity(input, &dep.target)
+                || vendor::resolves(input, &dep.target))
+        {
+            tables.symbols.push(SymbolRow {
+                name: dep.target.clone(),
+            });
+        }
+    }
+
+    let mut uses = |names: &[String], kind: &str, file: &str, line: usize| {
+        for name in names.iter().filter(|name| !name.is_empty()) {
+            tables.signal_uses.push(SignalUseRow {
+                name: name.clone(),
+                kind: kind.to_string(),
+                file: file.to_string(),
+                line,
+            });
+        }
+    };
+    for proc in &input.processes {
+        uses(&proc.read_signals, "read", &proc.file, proc.line);
+        uses(&proc.assigned_signals, "write", &proc.file, proc.line);
+    }
+    for ca in &input.concurrent_assignments {
+        uses(&ca.read_signals, "read", &ca.file, ca.line);
+        uses(std::slice::from_ref(&ca.target), "write", &ca.file, ca.line);
+    }
+    for usage in &input.signal_usages {
+        let name = std::slice::from_ref(&usage.signal);
+        if usage.is_read {
+            uses(name, "read", "", usage.line);
+        }
+        if usage.is_written {
+            uses(name, "write", "", usage.line);
+        }
+    }
+
+    // Row order is irrelevant to the daemon; sorting keeps `port_map`'s hash
+    // order out of the tables so equal inputs lower to equal tables.
+    tables.port_maps.sort();
+    tables.symbols.sort();
+    tables.symbols.dedup();
+    tables
+}
+
+fn names_entity(input: &Input, target: &str) -> bool {
+    let name = target.rsplit('.').next().unwrap_or(target);
+    input
+        .entities
+        .iter()
+        .any(|entity| entity.name.eq_ignore_ascii_case(name))
+}
+
+/// The daemon binary: `VHDL_POLICYD_BIN`, else `vhdl_policyd` next to the
+/// running executable, else whatever `vhdl_policyd` is on `PATH`.
+pub fn locate_binary() -> PathBuf {
+    if let Some(bin) = std::env::var_os("VHDL_POLICYD_BIN") {
+        return PathBuf::from(bin);
+    }
+    let name = format!("vhdl_policyd{}", std::env::consts::EXE_SUFFIX);
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(|dir| dir.join(&name)))
+        .filter(|path| path.is_file())
+        .unwrap_or_else(|| PathBuf::from(name))
+}
+
+#[derive(Deserialize)]
+struct Response {
+    kind: String,
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    violations: Vec<DaemonViolation>,
+}
+
+#[derive(Deserialize)]
+struct DaemonViolation {
+    rule: String,
+    severity: String,
+    file: String,
+    line: usize,
+    message: String,
+}
+
+/// A running `vhdl_policyd` and the tables it currently holds.
+pub struct Daemon {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    tables: Tables,
+}
+
+impl Daemon {
+    pub fn spawn(bin: &std::path::Path) -> io::Result<Daemon> {
+        let mut child = Command::new(bin)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()?;
+        let stdin = child.stdin.take().expect("daemon stdin is piped");
+        let stdout = BufReader::new(child.stdout.take().expect("daemon stdout is piped"));
+        Ok(Daemon {
+            child,
+            stdin,
+            stdout,
+            tables: Tables::default(),
+        })
+    }
+
+    /// Loads a full state and returns its violations.
+    pub fn init(&mut self, tables: Tables) -> Result<Vec<Violation>, String> {
+        let violations = self.send(json!({"kind": "init", "tables": tables}))?;
+        self.tables = tables;
+        Ok(violations)
+    }
+
+    /// Moves the daemon to `tables`, sending only the rows that changed since
+    /// the last `init`/`update`.
+    pub fn update(&mut self, tables: Tables) -> Result<Vec<Violation>, String> {
+        let violations = self.send(json!({
+            "kind": "delta",
+            "added": tables.minus(&self.tables),
+            "removed": self.tables.minus(&tables),
+        }))?;
+        self.tables = tables;
+        Ok(violations)
+    }
+
+    /// Violations for the current state, without changing it.
+    pub fn snapshot(&mut self) -> Result<Vec<Violation>, String> {
+        self.send(json!({"kind": "snapshot"}))
+    }
+
+    fn send(&mut self, cmd: serde_json::Value) -> Result<Vec<Violation>, String> {
+        writeln!(self.stdin, "{}", cmd)
+            .and_then(|_| self.stdin.flush())
+            .map_err(|e| format!("writing to vhdl_policyd: {}", e))?;
+        let mut line = String::new();
+        match self.stdout.read_line(&mut line) {
+            Ok(0) => return Err("vhdl_policyd exited".to_string()),
+            Ok(_) => {}
+            Err(e) => return Err(format!("reading from vhdl_policyd: {}", e)),
+        }
+        let response: Response = serde_json::from_str(&line)
+            .map_err(|e| format!("invalid vhdl_policyd response: {}", e))?;
+        if response.kind != "snapshot" {
+            return Err(format!("vhdl_policyd: {}", response.message));
+        }
+        Ok(response
+            .violations
+            .into_iter()
+            .map(|v| Violation {
+                rule: v.rule,
+                severity: v.severity,
+                file: v.file,
+                line: v.line,
+                message: v.message,
+                ..Default::default()
+            })
+            .collect())
+    }
+}
+
+impl Drop for Daemon {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{
+        ConcurrentAssignment, Dependency, Entity, Instance, Port, Process, Signal,
+    };
+
+    fn input() -> Input {
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "core".to_string(),
+            file: "core.vhd".to_string(),
+            line: 1,
+            ports: vec![Port {
+                name: "din".to_string(),
+                direction: "in".to_string(),
+                width: 8,
+                in_entity: "core".to_string(),
+                line: 3,
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        input.signals.push(Signal {
+            name: "bus".to_string(),
+            in_entity: "rtl".to_string(),
+            file: "top.vhd".to_string(),
+            line: 12,
+            width: 8,
+            ..Default::default()
+        });
+        input.instances.push(Instance {
+            name: "u_core".to_string(),
+            target: "work.core".to_string(),
+            port_map: [("din".to_string(), "bus".to_string())].into(),
+            file: "top.vhd".to_string(),
+            line: 20,
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        });
+        for target in ["work.core", "work.missing"] {
+            input.dependencies.push(Dependency {
+                source: "top.vhd".to_string(),
+                target: target.to_string(),
+                kind: "instantiation".to_string(),
+                line: 20,
+                resolved: false,
+            });
+        }
+        input.processes.push(Process {
+            read_signals: vec!["bus".to_string()],
+            assigned_signals: vec!["q".to_string()],
+            file: "top.vhd".to_string(),
+            line: 30,
+            ..Default::default()
+        });
+        input.concurrent_assignments.push(ConcurrentAssignment {
+            target: "dout".to_string(),
+            file: "top.vhd".to_string(),
+            line: 40,
+            ..Default::default()
+        });
+        input
+    }
+
+    #[test]
+    fn tables_lower_input() {
+        let tables = tables(&input());
+        assert_eq!(tables.ports.len(), 1);
+        assert_eq!(tables.ports[0].file, "core.vhd");
+        assert_eq!(tables.ports[0].width, 8);
+        assert_eq!(tables.signals[0].scope, "rtl");
+        assert_eq!(tables.port_maps[0].actual, "bus");
+        assert_eq!(
+            tables.symbols,
+            vec![SymbolRow {
+                name: "work.core".to_string()
+            }]
+        );
+        let uses: Vec<(&str, &str)> = tables
+            .signal_uses
+            .iter()
+            .map(|u| (u.name.as_str(), u.kind.as_str()))
+            .collect();
+        assert_eq!(uses, [("bus", "read"), ("q", "write"), ("dout", "write")]);
+    }
+
+    #[test]
+    fn minus_counts_duplicate_rows() {
+        let row = SymbolRow {
+            name: "work.core".to_string(),
+        };
+        let prev = Tables {
+            symbols: vec![row.clone()],
+            ..Default::default()
+        };
+        let next = Tables {
+            symbols: vec![row.clone(), row.clone()],
+            ..Default::default()
+        };
+        assert_eq!(next.minus(&prev).symbols, vec![row]);
+        assert!(prev.minus(&next).symbols.is_empty());
+        assert_eq!(next.minus(&next), Tables::default());
+    }
+}
@@ -0,0 +1,204 @@
+//! Signal dataflow graphs from `signal_deps`, one per architecture, for
+//! inspecting what the combinational loop rules saw. Sequential edges go
+//! through a register; combinational edges are zero-delay, and the ones
+//! inside a `combinational_loop` are marked.
+
+use serde::Serialize;
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::Write;
+
+use crate::policy::combinational;
+use crate::policy::dot::quote;
+use crate::policy::input::Input;
+
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct SignalGraph {
+    pub entity: String,
+    pub arch: String,
+    pub file: String,
+    /// Every signal on an edge, lowercase and sorted.
+    pub signals: Vec<String>,
+    pub edges: Vec<SignalEdge>,
+    /// Signal sets of the combinational loops, as `combinational_loop`
+    /// reports them.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub loops: Vec<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct SignalEdge {
+    pub source: String,
+    pub target: String,
+    /// "sequential" or "combinational".
+    pub kind: String,
+    /// Driving process label; empty for concurrent statements.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub process: String,
+    pub line: usize,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub in_loop: bool,
+}
+
+/// Graphs of the architectures named `name`, or of every architecture of the
+/// entity named `name`, in declaration order.
+pub fn signal_graphs(input: &Input, name: &str) -> Vec<SignalGraph> {
+    input
+        .architectures
+        .iter()
+        .filter(|arch| {
+            arch.name.eq_ignore_ascii_case(name) || arch.entity_name.eq_ignore_ascii_case(name)
+        })
+        .map(|arch| {
+            let loops = combinational::loops(input, &arch.file, &arch.name);
+            let in_loop = |source: &str, target: &str| {
+                loops.iter().any(|names| {
+                    names.iter().any(|name| name == source)
+                        && names.iter().any(|name| name == target)
+                })
+            };
+            let mut signals = BTreeSet::new();
+            let mut seen = HashSet::new();
+            let mut edges = Vec::new();
+            for dep in input
+                .signal_deps
+                .iter()
+                .filter(|dep| dep.file == arch.file && dep.in_arch.eq_ignore_ascii_case(&arch.name))
+            {
+                let source = dep.source.to_ascii_lowercase();
+                let target = dep.target.to_ascii_lowercase();
+                if !seen.insert((
+                    source.clone(),
+                    target.clone(),
+                    dep.is_sequential,
+                    dep.in_process.clone(),
+                )) {
+                    continue;
+                }
+                signals.insert(source.clone());
+                signals.insert(target.clone());
+                edges.push(SignalEdge {
+                    in_loop: !dep.is_sequential && in_loop(&source, &target),
+                    kind: if dep.is_sequential {
+                        "sequential"
+                    } else {
+                        "combinational"
+                    }
+                    .to_string(),
+                    process: dep.in_process.clone(),
+                    line: dep.line,
+                    source,
+                    target,
+                });
+            }
+            SignalGraph {
+                entity: arch.entity_name.clone(),
+                arch: arch.name.clone(),
+                file: arch.file.clone(),
+                signals: signals.into_iter().collect(),
+                edges,
+                loops,
+            }
+        })
+        .collect()
+}
+
+/// One digraph with a cluster per architecture. Sequential edges are dashed;
+/// combinational loop edges are red.
+pub fn signal_graphs_dot(graphs: &[SignalGraph]) -> String {
+    let mut dot = String::from("digraph signals {\n  rankdir=LR;\n  node [shape=ellipse];\n");
+    for (idx, graph) in graphs.iter().enumerate() {
+        let _ = writeln!(dot, "  subgraph \"cluster_{}\" {{", idx);
+        let _ = writeln!(
+            dot,
+            "    label={};",
+            quote(&format!("{}({}) {}", graph.entity, graph.arch, graph.file))
+        );
+        for signal in &graph.signals {
+            let _ = writeln!(
+                dot,
+                "    {} [label={}];",
+                quote(&format!("{}:{}", idx, signal)),
+                quote(signal)
+            );
+        }
+        for edge in &graph.edges {
+            let mut attrs = vec![format!("label={}", quote(&edge_label(edge)))];
+            if edge.kind == "sequential" {
+                attrs.push("style=dashed".to_string());
+            }
+            if edge.in_loop {
+                attrs.push("color=red, penwidth=2".to_string());
+            }
+            let _ = writeln!(
+                dot,
+                "    {} -> {} [{}];",
+                quote(&format!("{}:{}", idx, edge.source)),
+                quote(&format!("{}:{}", idx, edge.target)),
+                attrs.join(", ")
+            );
+        }
+        dot.push_str("  }\n");
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+fn edge_label(edge: &SignalEdge) -> String {
+    if edge.process.is_empty() {
+        format!("line {}", edge.line)
+    } else {
+        format!("{} @ {}", edge.process, edge.line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{Architecture, SignalDep};
+
+    fn dep(source: &str, target: &str, sequential: bool, process: &str, line: usize) -> SignalDep {
+        SignalDep {
+            source: source.to_string(),
+            target: target.to_string(),
+            is_sequential: sequential,
+            in_process: process.to_string(),
+            file: "top.vhd".to_string(),
+            line,
+            in_arch: "rtl".to_string(),
+        }
+    }
+
+    #[test]
+    fn graph_separates_sequential_edges_and_marks_loops() {
+        let mut input = Input::default();
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "top".to_string(),
+            file: "top.vhd".to_string(),
+            ..Default::default()
+        });
+        input.signal_deps.push(dep("a", "b", false, "p_comb", 10));
+        input.signal_deps.push(dep("B", "a", false, "", 14));
+        input.signal_deps.push(dep("b", "q", true, "p_reg", 20));
+        input.signal_deps.push(dep("b", "q", true, "p_reg", 21));
+        let mut other = dep("x", "y", false, "", 3);
+        other.file = "other.vhd".to_string();
+        input.signal_deps.push(other);
+
+        assert!(signal_graphs(&input, "core").is_empty());
+        let graphs = signal_graphs(&input, "TOP");
+        assert_eq!(graphs.len(), 1);
+        let graph = &graphs[0];
+        assert_eq!(graph.signals, ["a", "b", "q"]);
+        assert_eq!(graph.loops, [vec!["a".to_string(), "b".to_string()]]);
+        assert_eq!(graph.edges.len(), 3);
+        assert!(graph.edges[0].in_loop && graph.edges[1].in_loop);
+        assert_eq!(graph.edges[2].kind, "sequential");
+        assert!(!graph.edges[2].in_loop);
+
+        let dot = signal_graphs_dot(&graphs);
+        assert!(dot.contains("label=\"top(rtl) top.vhd\";"));
+        assert!(dot.contains("\"0:a\" -> \"0:b\" [label=\"p_comb @ 10\", color=red, penwidth=2];"));
+        assert!(dot.contains("\"0:b\" -> \"0:q\" [label=\"p_reg @ 20\", style=dashed];"));
+    }
+}
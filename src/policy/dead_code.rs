@@ -0,0 +1,656 @@
+//! Dead code: design units nothing reachable from the top uses. Reachability
+//! starts at `input.top_entities` (plus testbench entities with
+//! `lint_config.testbench_roots`) and follows architectures, instantiations
+//! (through configuration bindings), use clauses and qualified calls.
+//! Entities and packages outside that set are dead; functions and types are
+//! dead when their unit is, or when no declaration in a reachable file
+//! refers to them by name.
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::policy::helpers::{base_type_name, is_testbench_name};
+use crate::policy::hierarchy;
+use crate::policy::input::Input;
+use crate::policy::libraries::LibraryMap;
+use crate::policy::result::{Confidence, Violation};
+
+pub fn violations(input: &Input) -> Vec<Violation> {
+    let Some(reach) = Reachability::new(input) else {
+        return Vec::new();
+    };
+    let mut out = Vec::new();
+    out.extend(dead_entity(input, &reach));
+    out.extend(dead_package(input, &reach));
+    out.extend(dead_function(input, &reach));
+    out.extend(dead_type(input, &reach));
+    out
+}
+
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "architectures",
+        "concurrent_assignments",
+        "configurations",
+        "constant_decls",
+        "entities",
+        "files",
+        "functions",
+        "instances",
+        "name_uses",
+        "packages",
+        "procedures",
+        "processes",
+        "subtypes",
+        "top_entities",
+        "types",
+        "use_clauses",
+    ]
+}
+
+fn dead_entity(input: &Input, reach: &Reachability) -> Vec<Violation> {
+    input
+        .entities
+        .iter()
+        .filter(|entity| !reach.entity_live(&entity.name))
+        .map(|entity| Violation {
+            rule: "dead_entity".to_string(),
+            severity: "warning".to_string(),
+            file: entity.file.clone(),
+            line: entity.line,
+            message: format!(
+                "Entity '{}' is not instantiated anywhere below the top-level design",
+                entity.name
+            ),
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn dead_package(input: &Input, reach: &Reachability) -> Vec<Violation> {
+    input
+        .packages
+        .iter()
+        .filter(|pkg| !reach.package_live(&pkg.file, &pkg.name))
+        .map(|pkg| Violation {
+            rule: "dead_package".to_string(),
+            severity: "warning".to_string(),
+            file: pkg.file.clone(),
+            line: pkg.line,
+            message: format!(
+                "Package '{}' is not used by any unit reachable from the top-level design",
+                pkg.name
+            ),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Functions in dead units, or in live ones but never called. A package
+/// declaration and its body (and overloads) are one name, reported once.
+fn dead_function(input: &Input, reach: &Reachability) -> Vec<Violation> {
+    let mut seen = HashSet::new();
+    let mut out = Vec::new();
+    for func in &input.functions {
+        if func.name.starts_with('"') {
+            continue; // Operator overloads are used through their symbol.
+        }
+        let Some(owner) = reach.owner(&func.file, &func.in_package, &func.in_arch) else {
+            continue;
+        };
+        let key = (owner.key.clone(), func.name.to_ascii_lowercase());
+        if owner.live && reach.referenced.contains(&key.1) {
+            continue;
+        }
+        if !seen.insert(key) {
+            continue;
+        }
+        out.push(dead_declaration(
+            "dead_function",
+            "Function",
+            &func.name,
+            &func.file,
+            func.line,
+            &owner,
+        ));
+    }
+    out
+}
+
+fn dead_type(input: &Input, reach: &Reachability) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for decl in &input.types {
+        let Some(owner) = reach.owner(&decl.file, &decl.in_package, &decl.in_arch) else {
+            continue;
+        };
+        if owner.live && reach.referenced.contains(&decl.name.to_ascii_lowercase()) {
+            continue;
+        }
+        out.push(dead_declaration(
+            "dead_type",
+            "Type",
+            &decl.name,
+            &decl.file,
+            decl.line,
+            &owner,
+        ));
+    }
+    out
+}
+
+/// A dead unit takes everything in it along (certain); an unreferenced name
+/// in a live unit is only as good as the name facts.
+fn dead_declaration(
+    rule: &str,
+    what: &str,
+    name: &str,
+    file: &str,
+    line: usize,
+    owner: &Owner,
+) -> Violation {
+    let (message, confidence) = if owner.live {
+        (
+            format!(
+                "{} '{}' in {} is never referenced from the reachable design",
+                what, name, owner.label
+            ),
+            Confidence::Medium,
+        )
+    } else {
+        (
+            format!(
+                "{} '{}' is unreachable: {} is not used by the top-level design",
+                what, name, owner.label
+            ),
+            Confidence::High,
+        )
+    };
+    Violation {
+        rule: rule.to_string(),
+        severity: "info".to_string(),
+        file: file.to_string(),
+        line,
+        message,
+        confidence,
+        ..Default::default()
+    }
+}
+
+/// Unit a function or type is declared in.
+struct Owner {
+    key: String,
+    label: String,
+    live: bool,
+}
+
+struct Reachability {
+    libraries: LibraryMap,
+    /// Lowercase entity names.
+    entities: HashSet<String>,
+    /// (library, lowercase package name).
+    packages: HashSet<(String, String)>,
+    files: HashSet<String>,
+    /// Lowercase names referenced from reachable files.
+    referenced: HashSet<String>,
+    /// (file, lowercase architecture name) -> lowercase entity name.
+    arch_entities: Vec<(String, String, String)>,
+}
+
+enum Unit {
+    Entity(String),
+    Package(String, String),
+}
+
+impl Reachability {
+    /// `None` when no root resolves to an entity, so there is no top to
+    /// measure against.
+    fn new(input: &Input) -> Option<Self> {
+        let mut queue: VecDeque<Unit> = VecDeque::new();
+        for entity in &input.entities {
+            let top = input.top_entities.iter().any(|top| {
+                let name = top.rsplit('.').next().unwrap_or(top).trim();
+                name.eq_ignore_ascii_case(&entity.name)
+            });
+            if top || (input.lint_config.testbench_roots && is_testbench_name(&entity.name)) {
+                queue.push_back(Unit::Entity(entity.name.to_ascii_lowercase()));
+            }
+        }
+        if queue.is_empty() {
+            return None;
+        }
+
+        let mut reach = Reachability {
+            libraries: LibraryMap::new(input),
+            entities: HashSet::new(),
+            packages: HashSet::new(),
+            files: HashSet::new(),
+            referenced: HashSet::new(),
+            arch_entities: input
+                .architectures
+                .iter()
+                .map(|arch| {
+                    (
+                        arch.file.clone(),
+                        arch.name.to_ascii_lowercase(),
+                        arch.entity_name.to_ascii_lowercase(),
+                    )
+                })
+                .collect(),
+        };
+        let mut new_files = Vec::new();
+        while let Some(unit) = queue.pop_front() {
+            match unit {
+                Unit::Entity(name) => {
+                    if !reach.entities.insert(name.clone()) {
+                        continue;
+                    }
+                    reach.visit_entity(input, &name, &mut queue, &mut new_files);
+                }
+                Unit::Package(library, name) => {
+                    if !reach.packages.insert((library.clone(), name.clone())) {
+                        continue;
+                    }
+                    reach.visit_package(input, &library, &name, &mut new_files);
+                }
+            }
+            for file in new_files.drain(..) {
+                if reach.files.insert(file.clone()) {
+                    reach.visit_file(input, &file, &mut queue);
+                }
+            }
+        }
+        reach.collect_references(input);
+        Some(reach)
+    }
+
+    fn visit_entity(
+        &self,
+        input: &Input,
+        name: &str,
+        queue: &mut VecDeque<Unit>,
+        files: &mut Vec<String>,
+    ) {
+        for entity in &input.entities {
+            if entity.name.eq_ignore_ascii_case(name) {
+                files.push(entity.file.clone());
+            }
+        }
+        for arch in &input.architectures {
+            if !arch.entity_name.eq_ignore_ascii_case(name) {
+                continue;
+            }
+            files.push(arch.file.clone());
+            let instances = input.instances.iter().filter(|inst| {
+                inst.file == arch.file && inst.in_arch.eq_ignore_ascii_case(&arch.name)
+            });
+            for inst in instances {
+                for bound in hierarchy::bound_entities(input, inst) {
+                    queue.push_back(Unit::Entity(bound.name.to_ascii_lowercase()));
+                }
+            }
+        }
+        for cfg in &input.configurations {
+            if cfg.entity_name.eq_ignore_ascii_case(name) {
+                files.push(cfg.file.clone());
+            }
+        }
+    }
+
+    /// Files holding the package or anything declared in it, so a body in a
+    /// file of its own counts too.
+    fn visit_package(&self, input: &Input, library: &str, name: &str, files: &mut Vec<String>) {
+        let mut add = |file: &str, pkg: &str| {
+            if pkg.eq_ignore_ascii_case(name) && self.libraries.library_of(file) == library {
+                files.push(file.to_string());
+            }
+        };
+        for pkg in &input.packages {
+            add(&pkg.file, &pkg.name);
+        }
+        for func in &input.functions {
+            add(&func.file, &func.in_package);
+        }
+        for proc_decl in &input.procedures {
+            add(&proc_decl.file, &proc_decl.in_package);
+        }
+        for decl in &input.types {
+            add(&decl.file, &decl.in_package);
+        }
+        for decl in &input.constant_decls {
+            add(&decl.file, &decl.in_package);
+        }
+    }
+
+    /// Packages a reachable file pulls in through use clauses and
+    /// package-qualified calls.
+    fn visit_file(&self, input: &Input, file: &str, queue: &mut VecDeque<Unit>) {
+        for clause in input
+            .use_clauses
+            .iter()
+            .filter(|clause| clause.file == file)
+        {
+            for item in &clause.items {
+                let parts: Vec<&str> = item.split('.').map(str::trim).collect();
+                if parts.len() < 2 {
+                    continue;
+                }
+                let library = self.libraries.resolve(file, parts[0]);
+                queue.push_back(Unit::Package(library, parts[1].to_ascii_lowercase()));
+            }
+        }
+        for use_ in input.name_uses.iter().filter(|use_| use_.file == file) {
+            let parts: Vec<&str> = use_.name.split('.').map(str::trim).collect();
+            match parts.len() {
+                0 | 1 => {}
+                2 => {
+                    for pkg in &input.packages {
+                        if pkg.name.eq_ignore_ascii_case(parts[0]) {
+                            queue.push_back(Unit::Package(
+                                self.libraries.library_of(&pkg.file),
+                                parts[0].to_ascii_lowercase(),
+                            ));
+                        }
+                    }
+                }
+                n => {
+                    let library = self.libraries.resolve(file, parts[n - 3]);
+                    queue.push_back(Unit::Package(library, parts[n - 2].to_ascii_lowercase()));
+                }
+            }
+        }
+    }
+
+    /// Names declarations in reachable files refer to: calls, reads, types
+    /// of objects and parameters, identifiers in initial values and maps.
+    fn collect_references(&mut self, input: &Input) {
+        let files = &self.files;
+        let live = |file: &str| files.contains(file);
+        let mut names: Vec<String> = Vec::new();
+        let mut words = |text: &str| {
+            names.extend(
+                text.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+                    .filter(|word| !word.is_empty())
+                    .map(base_type_name),
+            );
+        };
+        for use_ in input.name_uses.iter().filter(|use_| live(&use_.file)) {
+            words(&use_.name);
+        }
+        for entity in input
+            .entities
+            .iter()
+            .filter(|e| self.entities.contains(&e.name.to_ascii_lowercase()))
+        {
+            for port in &entity.ports {
+                words(&port.r#type);
+                words(&port.default);
+            }
+            for generic in &entity.generics {
+                words(&generic.r#type);
+                words(&generic.default);
+            }
+        }
+        for sig in input.signals.iter().filter(|sig| live(&sig.file)) {
+            words(&sig.r#type);
+        }
+        for decl in input.constant_decls.iter().filter(|decl| live(&decl.file)) {
+            words(&decl.r#type);
+            words(&decl.value);
+        }
+        for proc in input.processes.iter().filter(|proc| live(&proc.file)) {
+            proc.read_signals.iter().for_each(|name| words(name));
+            proc.function_calls
+                .iter()
+                .for_each(|call| words(&call.name));
+            proc.variables.iter().for_each(|var| words(&var.r#type));
+            proc.assignments
+                .iter()
+                .for_each(|assign| words(&assign.value));
+        }
+        for assign in input
+            .concurrent_assignments
+            .iter()
+            .filter(|a| live(&a.file))
+        {
+            assign.read_signals.iter().for_each(|name| words(name));
+            words(&assign.value);
+        }
+        for inst in input.instances.iter().filter(|inst| live(&inst.file)) {
+            inst.port_map.values().for_each(|actual| words(actual));
+            inst.generic_map.values().for_each(|actual| words(actual));
+        }
+        for func in input.functions.iter().filter(|func| live(&func.file)) {
+            words(&func.return_type);
+            func.parameters
+                .iter()
+                .for_each(|param| words(&param.r#type));
+            func.read_signals
+                .iter()
+                .filter(|name| !name.eq_ignore_ascii_case(&func.name))
+                .for_each(|name| words(name));
+        }
+        for proc_decl in input.procedures.iter().filter(|p| live(&p.file)) {
+            proc_decl
+                .parameters
+                .iter()
+                .for_each(|param| words(&param.r#type));
+        }
+        for decl in input.types.iter().filter(|decl| live(&decl.file)) {
+            words(&decl.element_type);
+            decl.fields.iter().for_each(|field| words(&field.r#type));
+        }
+        for decl in input.subtypes.iter().filter(|decl| live(&decl.file)) {
+            words(&decl.base_type);
+            words(&decl.constraint);
+        }
+        self.referenced.extend(names);
+    }
+
+    fn entity_live(&self, name: &str) -> bool {
+        self.entities.contains(&name.to_ascii_lowercase())
+    }
+
+    fn package_live(&self, file: &str, name: &str) -> bool {
+        self.packages
+            .contains(&(self.libraries.library_of(file), name.to_ascii_lowercase()))
+    }
+
+    /// Package or architecture a declaration sits in; `None` when the facts
+    /// name neither.
+    fn owner(&self, file: &str, in_package: &str, in_arch: &str) -> Option<Owner> {
+        if !in_package.is_empty() {
+            return Some(Owner {
+                key: format!("package:{}", in_package.to_ascii_lowercase()),
+                label: format!("package '{}'", in_package),
+                live: self.package_live(file, in_package),
+            });
+        }
+        if in_arch.is_empty() {
+            return None;
+        }
+        let (_, _, entity) = self
+            .arch_entities
+            .iter()
+            .find(|(arch_file, arch, _)| arch_file == file && arch.eq_ignore_ascii_case(in_arch))?;
+        Some(Owner {
+            key: format!("arch:{}:{}", file, in_arch.to_ascii_lowercase()),
+            label: format!("architecture '{}' of '{}'", in_arch, entity),
+            live: self.entity_live(entity),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{
+        Architecture, Entity, FunctionCall, FunctionDeclaration, Instance, Package, Process,
+        Signal, TypeDeclaration, UseClause,
+    };
+
+    fn entity(name: &str, file: &str) -> Entity {
+        Entity {
+            name: name.to_string(),
+            file: file.to_string(),
+            line: 1,
+            ..Default::default()
+        }
+    }
+
+    fn arch(entity: &str, file: &str) -> Architecture {
+        Architecture {
+            name: "rtl".to_string(),
+            entity_name: entity.to_string(),
+            file: file.to_string(),
+            line: 5,
+            ..Default::default()
+        }
+    }
+
+    fn design() -> Input {
+        let mut input = Input {
+            entities: vec![
+                entity("top", "top.vhd"),
+                entity("core", "core.vhd"),
+                entity("old_core", "old_core.vhd"),
+                entity("top_tb", "top_tb.vhd"),
+            ],
+            architectures: vec![
+                arch("top", "top.vhd"),
+                arch("core", "core.vhd"),
+                arch("old_core", "old_core.vhd"),
+                arch("top_tb", "top_tb.vhd"),
+            ],
+            packages: vec![
+                Package {
+                    name: "util_pkg".to_string(),
+                    file: "util_pkg.vhd".to_string(),
+                    line: 1,
+                    ..Default::default()
+                },
+                Package {
+                    name: "legacy_pkg".to_string(),
+                    file: "legacy_pkg.vhd".to_string(),
+                    line: 1,
+                    ..Default::default()
+                },
+            ],
+            instances: vec![
+                Instance {
+                    name: "u_core".to_string(),
+                    target: "work.core".to_string(),
+                    file: "top.vhd".to_string(),
+                    line: 8,
+                    in_arch: "rtl".to_string(),
+                    ..Default::default()
+                },
+                Instance {
+                    name: "dut".to_string(),
+                    target: "work.top".to_string(),
+                    file: "top_tb.vhd".to_string(),
+                    line: 8,
+                    in_arch: "rtl".to_string(),
+                    ..Default::default()
+                },
+            ],
+            use_clauses: vec![UseClause {
+                items: vec!["work.util_pkg.all".to_string()],
+                file: "core.vhd".to_string(),
+                line: 1,
+            }],
+            ..Default::default()
+        };
+        for name in ["parity", "unused_f"] {
+            input.functions.push(FunctionDeclaration {
+                name: name.to_string(),
+                has_body: true,
+                file: "util_pkg.vhd".to_string(),
+                line: 10,
+                in_package: "util_pkg".to_string(),
+                ..Default::default()
+            });
+        }
+        for name in ["word_t", "spare_t"] {
+            input.types.push(TypeDeclaration {
+                name: name.to_string(),
+                file: "util_pkg.vhd".to_string(),
+                line: 3,
+                in_package: "util_pkg".to_string(),
+                ..Default::default()
+            });
+        }
+        input.signals.push(Signal {
+            name: "w".to_string(),
+            r#type: "work.util_pkg.word_t".to_string(),
+            file: "core.vhd".to_string(),
+            line: 6,
+            in_entity: "rtl".to_string(),
+            ..Default::default()
+        });
+        input.processes.push(Process {
+            function_calls: vec![FunctionCall {
+                name: "parity".to_string(),
+                line: 9,
+                in_process: "p".to_string(),
+            }],
+            file: "core.vhd".to_string(),
+            line: 8,
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        });
+        input
+    }
+
+    fn flagged(input: &Input) -> Vec<(String, String)> {
+        let mut out: Vec<(String, String)> = violations(input)
+            .into_iter()
+            .map(|v| {
+                let name = v.message.split('\'').nth(1).unwrap_or("").to_string();
+                (v.rule, name)
+            })
+            .collect();
+        out.sort();
+        out
+    }
+
+    #[test]
+    fn no_top_reports_nothing() {
+        assert!(violations(&design()).is_empty());
+    }
+
+    #[test]
+    fn flags_units_unreachable_from_top() {
+        let mut input = design();
+        input.top_entities = vec!["work.TOP".to_string()];
+        assert_eq!(
+            flagged(&input),
+            [
+                ("dead_entity".to_string(), "old_core".to_string()),
+                ("dead_entity".to_string(), "top_tb".to_string()),
+                ("dead_function".to_string(), "unused_f".to_string()),
+                ("dead_package".to_string(), "legacy_pkg".to_string()),
+                ("dead_type".to_string(), "spare_t".to_string()),
+            ]
+        );
+        let unused = violations(&input)
+            .into_iter()
+            .find(|v| v.rule == "dead_function")
+            .unwrap();
+        assert_eq!(unused.confidence, Confidence::Medium);
+    }
+
+    #[test]
+    fn testbench_roots_and_dead_packages_take_their_contents() {
+        let mut input = design();
+        input.lint_config.testbench_roots = true;
+        input.use_clauses.clear();
+        let result = flagged(&input);
+        assert!(!result.contains(&("dead_entity".to_string(), "top".to_string())));
+        assert!(result.contains(&("dead_entity".to_string(), "old_core".to_string())));
+        assert!(result.contains(&("dead_package".to_string(), "util_pkg".to_string())));
+        let parity = violations(&input)
+            .into_iter()
+            .find(|v| v.rule == "dead_function" && v.message.contains("'parity'"))
+            .unwrap();
+        assert_eq!(parity.confidence, Confidence::High);
+    }
+}
@@ -0,0 +1,174 @@
+//! Changed lines from a unified diff, for diff-aware linting: with
+//! `lint_config.changed_lines` set, `engine::filter_violations` keeps only the
+//! violations on lines the diff added or modified, so a PR gate reports what
+//! the change introduced rather than the file's legacy findings.
+//!
+//! Only the new side of the diff matters. Context lines are skipped, so the
+//! result is the same whatever `-U` the diff was produced with; a pure
+//! deletion marks the line that now follows it.
+
+use std::collections::HashMap;
+
+/// Inclusive 1-based line ranges per file, as stored in
+/// `lint_config.changed_lines`.
+pub type ChangedLines = HashMap<String, Vec<(usize, usize)>>;
+
+/// Changed lines per new-side path in `diff`. Deleted files are left out;
+/// `a/`/`b/` prefixes are stripped.
+pub fn changed_lines(diff: &str) -> ChangedLines {
+    let mut changed = ChangedLines::new();
+    let mut file: Option<String> = None;
+    let mut old_left = 0usize;
+    let mut new_left = 0usize;
+    let mut line = 0usize;
+    for text in diff.lines() {
+        if old_left > 0 || new_left > 0 {
+            match text.as_bytes().first() {
+                Some(b'+') => {
+                    mark(&mut changed, &file, line);
+                    line += 1;
+                    new_left = new_left.saturating_sub(1);
+                }
+                Some(b'-') => {
+                    mark(&mut changed, &file, line.max(1));
+                    old_left = old_left.saturating_sub(1);
+                }
+                Some(b'\\') => {}
+                _ => {
+                    line += 1;
+                    old_left = old_left.saturating_sub(1);
+                    new_left = new_left.saturating_sub(1);
+                }
+            }
+            continue;
+        }
+        if let Some(path) = text.strip_prefix("+++ ") {
+            let path = path.split('\t').next().unwrap_or(path).trim_end();
+            file =
+                (path != "/dev/null").then(|| normalize(path.strip_prefix("b/").unwrap_or(path)));
+        } else if let Some(header) = text.strip_prefix("@@ ") {
+            if let Some((old, new)) = hunk_header(header) {
+                (old_left, new_left) = (old.1, new.1);
+                // An empty new side names the line before the deletion.
+                line = if new.1 == 0 { new.0 + 1 } else { new.0 };
+            }
+        }
+    }
+    changed
+}
+
+/// Whether a violation at `file`:`line` falls on a changed line. Paths match
+/// when equal or when `file` ends with the diff path (absolute or
+/// prefixed violation paths); line 0 (file-level findings) matches any
+/// change in the file.
+pub fn touches(changed: &ChangedLines, file: &str, line: usize) -> bool {
+    let file = normalize(file);
+    changed.iter().any(|(path, ranges)| {
+        let same_file = file == *path
+            || file
+                .strip_suffix(path.as_str())
+                .is_some_and(|prefix| prefix.ends_with('/'));
+        same_file && (line == 0 || ranges.iter().any(|&(lo, hi)| lo <= line && line <= hi))
+    })
+}
+
+fn normalize(path: &str) -> String {
+    path.trim_start_matches("./").replace('\\', "/")
+}
+
+/// Adds `line` to the file's ranges, extending the last range when the
+/// line continues it.
+fn mark(changed: &mut ChangedLines, file: &Option<String>, line: usize) {
+    let Some(file) = file else {
+        return;
+    };
+    let ranges = changed.entry(file.clone()).or_default();
+    match ranges.last_mut() {
+        Some((lo, hi)) if *lo <= line && line <= *hi + 1 => *hi = (*hi).max(line),
+        _ => ranges.push((line, line)),
+    }
+}
+
+/// `-12,3 +14,5 @@ ...` -> ((12, 3), (14, 5)); a missing count is 1.
+fn hunk_header(header: &str) -> Option<((usize, usize), (usize, usize))> {
+    let mut parts = header.split_whitespace();
+    let old = span(parts.next()?.strip_prefix('-')?)?;
+    let new = span(parts.next()?.strip_prefix('+')?)?;
+    Some((old, new))
+}
+
+fn span(text: &str) -> Option<(usize, usize)> {
+    match text.split_once(',') {
+        Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+        None => Some((text.parse().ok()?, 1)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIFF: &str = "\
+diff --git a/rtl/core.vhd b/rtl/core.vhd
+index 1111111..2222222 100644
+--- a/rtl/core.vhd
++++ b/rtl/core.vhd
+@@ -10,3 +10,4 @@ architecture rtl of core is
+   signal a : std_logic;
+-  signal b : std_logic;
++  signal b : std_logic_vector(1 downto 0);
++  signal c : std_logic;
+ begin
+@@ -40,2 +41,0 @@ begin
+-  a <= '0';
+-  b <= \"00\";
+diff --git a/rtl/old.vhd b/rtl/old.vhd
+deleted file mode 100644
+--- a/rtl/old.vhd
++++ /dev/null
+@@ -1,2 +0,0 @@
+-entity old is
+-end entity;
+diff --git a/rtl/new.vhd b/rtl/new.vhd
+new file mode 100644
+--- /dev/null
++++ b/rtl/new.vhd
+@@ -0,0 +1,2 @@
++entity new is
++end entity;
+";
+
+    #[test]
+    fn collects_new_side_lines_per_file() {
+        let changed = changed_lines(DIFF);
+        assert_eq!(changed.len(), 2);
+        assert_eq!(changed["rtl/core.vhd"], vec![(11, 12), (42, 42)]);
+        assert_eq!(changed["rtl/new.vhd"], vec![(1, 2)]);
+    }
+
+    #[test]
+    fn context_width_does_not_matter() {
+        let zero_context = "\
+--- a/top.vhd
++++ b/top.vhd
+@@ -11 +11,2 @@
+-  signal b : std_logic;
++  signal b : std_logic_vector(1 downto 0);
++  signal c : std_logic;
+";
+        let changed = changed_lines(zero_context);
+        assert_eq!(changed["top.vhd"], vec![(11, 12)]);
+    }
+
+    #[test]
+    fn touches_matches_paths_and_lines() {
+        let changed = changed_lines(DIFF);
+        assert!(touches(&changed, "rtl/core.vhd", 12));
+        assert!(touches(&changed, "./rtl/core.vhd", 11));
+        assert!(touches(&changed, "/work/proj/rtl/core.vhd", 42));
+        assert!(touches(&changed, "rtl/core.vhd", 0));
+        assert!(!touches(&changed, "rtl/core.vhd", 10));
+        assert!(!touches(&changed, "xrtl/core.vhd", 12));
+        assert!(!touches(&changed, "rtl/other.vhd", 0));
+    }
+}
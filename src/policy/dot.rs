@@ -0,0 +1,302 @@
+//! Graphviz rendering of the design hierarchy. Entities and their
+//! architectures are grouped in one cluster per library; each instance is an
+//! edge from the instantiating architecture to its target. Targets that name
+//! no entity are drawn outside the clusters: grey when the indexer resolved
+//! them elsewhere (vendor libraries), dashed red when nothing did.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write;
+
+use crate::policy::input::{Architecture, Input};
+
+/// `digraph hierarchy { ... }` for `dot -Tsvg`.
+pub fn hierarchy_dot(input: &Input) -> String {
+    let libraries: HashMap<&str, String> = input
+        .files
+        .iter()
+        .map(|file| (file.path.as_str(), library_name(&file.library)))
+        .collect();
+    let library_of = |file: &str| {
+        libraries
+            .get(file)
+            .cloned()
+            .unwrap_or_else(|| "work".to_string())
+    };
+    let entities: HashSet<String> = input
+        .entities
+        .iter()
+        .map(|entity| entity.name.to_ascii_lowercase())
+        .collect();
+
+    let mut clusters: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut seen = HashSet::new();
+    for entity in &input.entities {
+        let id = entity_id(&entity.name);
+        if seen.insert(id.clone()) {
+            clusters
+                .entry(library_of(&entity.file))
+                .or_default()
+                .push(format!(
+                    "{} [label={}, shape=box, style=bold];",
+                    quote(&id),
+                    quote(&entity.name)
+                ));
+        }
+    }
+    let mut edges = Vec::new();
+    for arch in &input.architectures {
+        let id = arch_id(arch);
+        if !seen.insert(id.clone()) {
+            continue;
+        }
+        clusters
+            .entry(library_of(&arch.file))
+            .or_default()
+            .push(format!(
+                "{} [label={}, shape=ellipse];",
+                quote(&id),
+                quote(&format!("{}({})", arch.entity_name, arch.name))
+            ));
+        if entities.contains(&arch.entity_name.to_ascii_lowercase()) {
+            edges.push(format!(
+                "{} -> {} [style=dashed, arrowhead=empty];",
+                quote(&id),
+                quote(&entity_id(&arch.entity_name))
+            ));
+        }
+    }
+
+    let resolved_elsewhere: HashSet<String> = input
+        .dependencies
+        .iter()
+        .filter(|dep| dep.kind == "instantiation" && dep.resolved)
+        .map(|dep| base_name(&dep.target))
+        .collect();
+    let mut targets = BTreeMap::new();
+    let mut target_id = |target: &str, target_arch: &str| {
+        let base = base_name(target);
+        if entities.contains(&base) {
+            let arch = input.architectures.iter().find(|arch| {
+                arch.entity_name.eq_ignore_ascii_case(&base)
+                    && arch.name.eq_ignore_ascii_case(target_arch)
+            });
+            return match arch {
+                Some(arch) => arch_id(arch),
+                None => entity_id(&base),
+            };
+        }
+        let id = format!("target:{}", target.to_ascii_lowercase());
+        targets
+            .entry(id.clone())
+            .or_insert_with(|| (target.to_string(), resolved_elsewhere.contains(&base)));
+        id
+    };
+
+    let mut instantiated = HashSet::new();
+    for inst in &input.instances {
+        let Some(arch) = containing_arch(input, &inst.in_arch, &inst.file) else {
+            continue;
+        };
+        instantiated.insert((inst.file.as_str(), inst.target.to_ascii_lowercase()));
+        edges.push(format!(
+            "{} -> {} [label={}];",
+            quote(&arch_id(arch)),
+            quote(&target_id(&inst.target, &inst.target_arch)),
+            quote(&inst.name)
+        ));
+    }
+    // Instantiations the extractor recorded only as dependencies hang off
+    // every architecture of their file.
+    for dep in &input.dependencies {
+        if dep.kind != "instantiation"
+            || instantiated.contains(&(dep.source.as_str(), dep.target.to_ascii_lowercase()))
+        {
+            continue;
+        }
+        let target = target_id(&dep.target, "");
+        for arch in input
+            .architectures
+            .iter()
+            .filter(|arch| arch.file == dep.source)
+        {
+            edges.push(format!("{} -> {};", quote(&arch_id(arch)), quote(&target)));
+        }
+    }
+
+    let mut dot =
+        String::from("digraph hierarchy {\n  rankdir=LR;\n  node [fontname=\"Helvetica\"];\n");
+    for (library, nodes) in &clusters {
+        let _ = writeln!(
+            dot,
+            "  subgraph {} {{",
+            quote(&format!("cluster_{}", library))
+        );
+        let _ = writeln!(dot, "    label={};", quote(library));
+        for node in nodes {
+            let _ = writeln!(dot, "    {}", node);
+        }
+        dot.push_str("  }\n");
+    }
+    for (id, (label, resolved)) in &targets {
+        let style = if *resolved {
+            "shape=box, style=dashed, color=grey50"
+        } else {
+            "shape=box, style=\"dashed,bold\", color=red, fontcolor=red"
+        };
+        let _ = writeln!(dot, "  {} [label={}, {}];", quote(id), quote(label), style);
+    }
+    for edge in &edges {
+        let _ = writeln!(dot, "  {}", edge);
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+fn library_name(library: &str) -> String {
+    if library.is_empty() {
+        "work".to_string()
+    } else {
+        library.to_ascii_lowercase()
+    }
+}
+
+/// Entity name without its library prefix (`work.fifo` -> `fifo`).
+fn base_name(target: &str) -> String {
+    target
+        .rsplit('.')
+        .next()
+        .unwrap_or(target)
+        .trim()
+        .to_ascii_lowercase()
+}
+
+/// The architecture named `name`, preferring the one declared in `file`.
+fn containing_arch<'a>(input: &'a Input, name: &str, file: &str) -> Option<&'a Architecture> {
+    let mut named = input
+        .architectures
+        .iter()
+        .filter(|arch| arch.name.eq_ignore_ascii_case(name));
+    let first = named.clone().next();
+    named.find(|arch| arch.file == file).or(first)
+}
+
+fn entity_id(name: &str) -> String {
+    format!("entity:{}", name.to_ascii_lowercase())
+}
+
+fn arch_id(arch: &Architecture) -> String {
+    format!(
+        "arch:{}({})",
+        arch.entity_name.to_ascii_lowercase(),
+        arch.name.to_ascii_lowercase()
+    )
+}
+
+/// A DOT quoted string.
+pub(crate) fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{Dependency, Entity, FileInfo, Instance};
+
+    fn entity(name: &str, file: &str) -> Entity {
+        Entity {
+            name: name.to_string(),
+            file: file.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn arch(name: &str, entity: &str, file: &str) -> Architecture {
+        Architecture {
+            name: name.to_string(),
+            entity_name: entity.to_string(),
+            file: file.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn instance(name: &str, target: &str, in_arch: &str, file: &str) -> Instance {
+        Instance {
+            name: name.to_string(),
+            target: target.to_string(),
+            in_arch: in_arch.to_string(),
+            file: file.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn renders_libraries_instances_and_unresolved_targets() {
+        let mut input = Input::default();
+        input.files.push(FileInfo {
+            path: "uart.vhd".to_string(),
+            library: "UART_LIB".to_string(),
+            ..Default::default()
+        });
+        input.entities.push(entity("top", "top.vhd"));
+        input.entities.push(entity("uart", "uart.vhd"));
+        input.architectures.push(arch("rtl", "top", "top.vhd"));
+        input.architectures.push(arch("rtl", "uart", "uart.vhd"));
+        input
+            .instances
+            .push(instance("u_uart", "uart_lib.uart", "rtl", "top.vhd"));
+        input
+            .instances
+            .push(instance("u_pll", "work.pll", "rtl", "top.vhd"));
+        input
+            .instances
+            .push(instance("u_buf", "unisim.ibufg", "rtl", "top.vhd"));
+        input.dependencies.push(Dependency {
+            source: "top.vhd".to_string(),
+            target: "unisim.ibufg".to_string(),
+            kind: "instantiation".to_string(),
+            resolved: true,
+            ..Default::default()
+        });
+
+        let dot = hierarchy_dot(&input);
+        assert!(dot.starts_with("digraph hierarchy {"));
+        assert!(dot.contains(
+            "subgraph \"cluster_uart_lib\" {\n    label=\"uart_lib\";\n    \"entity:uart\""
+        ));
+        assert!(dot.contains("subgraph \"cluster_work\""));
+        assert!(
+            dot.contains("\"arch:uart(rtl)\" -> \"entity:uart\" [style=dashed, arrowhead=empty];")
+        );
+        assert!(dot.contains("\"arch:top(rtl)\" -> \"entity:uart\" [label=\"u_uart\"];"));
+        assert!(dot.contains(
+            "\"target:work.pll\" [label=\"work.pll\", shape=box, style=\"dashed,bold\", color=red"
+        ));
+        assert!(dot.contains("\"target:unisim.ibufg\" [label=\"unisim.ibufg\", shape=box, style=dashed, color=grey50];"));
+        assert_eq!(dot.matches("\"arch:top(rtl)\" -> ").count(), 4);
+    }
+
+    #[test]
+    fn explicit_architecture_and_dependency_only_instantiations() {
+        let mut input = Input::default();
+        input.entities.push(entity("fifo", "fifo.vhd"));
+        input.architectures.push(arch("rtl", "fifo", "fifo.vhd"));
+        input.architectures.push(arch("sim", "fifo", "fifo.vhd"));
+        input.architectures.push(arch("rtl", "top", "top.vhd"));
+        let mut inst = instance("u_fifo", "work.fifo", "RTL", "top.vhd");
+        inst.target_arch = "sim".to_string();
+        input.instances.push(inst);
+        input.dependencies.push(Dependency {
+            source: "top.vhd".to_string(),
+            target: "ram_block".to_string(),
+            kind: "instantiation".to_string(),
+            ..Default::default()
+        });
+
+        let dot = hierarchy_dot(&input);
+        assert!(dot.contains("\"arch:top(rtl)\" -> \"arch:fifo(sim)\" [label=\"u_fifo\"];"));
+        assert!(dot.contains("\"arch:top(rtl)\" -> \"target:ram_block\";"));
+        assert!(dot.contains(
+            "\"target:ram_block\" [label=\"ram_block\", shape=box, style=\"dashed,bold\""
+        ));
+    }
+}
@@ -0,0 +1,266 @@
+use crate::policy::helpers::is_testbench_name;
+use crate::policy::input::{Entity, Input};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// Minimum body similarity (multiset Jaccard) for two entities with the same
+/// port signature to be reported as copies of each other.
+const SIMILARITY_THRESHOLD: f64 = 0.8;
+
+/// Entities that look like copy-pasted variants of one block: identical port
+/// signatures and near-identical architecture bodies.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DuplicateCluster {
+    /// `direction type` per port, in declaration order.
+    pub port_signature: Vec<String>,
+    /// Lowest pairwise body similarity within the cluster (0..=1).
+    pub similarity: f64,
+    pub entities: Vec<ClusterMember>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ClusterMember {
+    pub name: String,
+    pub file: String,
+    pub line: usize,
+}
+
+/// Consolidation candidates, largest clusters first. Testbenches and entities
+/// without ports are skipped; port names are ignored so renamed copies match.
+pub fn duplicate_clusters(input: &Input) -> Vec<DuplicateCluster> {
+    let mut by_signature: BTreeMap<Vec<String>, Vec<&Entity>> = BTreeMap::new();
+    for entity in &input.entities {
+        if entity.ports.is_empty() || is_testbench_name(&entity.name) {
+            continue;
+        }
+        by_signature
+            .entry(port_signature(entity))
+            .or_default()
+            .push(entity);
+    }
+
+    let mut clusters = Vec::new();
+    for (signature, entities) in by_signature {
+        if entities.len() < 2 {
+            continue;
+        }
+        let bodies: Vec<HashMap<String, usize>> =
+            entities.iter().map(|e| body_features(input, e)).collect();
+        for group in link_similar(&bodies) {
+            let mut similarity: f64 = 1.0;
+            for (i, &a) in group.iter().enumerate() {
+                for &b in &group[i + 1..] {
+                    similarity = similarity.min(jaccard(&bodies[a], &bodies[b]));
+                }
+            }
+            let mut members: Vec<ClusterMember> = group
+                .iter()
+                .map(|&idx| ClusterMember {
+                    name: entities[idx].name.clone(),
+                    file: entities[idx].file.clone(),
+                    line: entities[idx].line,
+                })
+                .collect();
+            members.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.file.cmp(&b.file)));
+            clusters.push(DuplicateCluster {
+                port_signature: signature.clone(),
+                similarity: (similarity * 100.0).round() / 100.0,
+                entities: members,
+            });
+        }
+    }
+    clusters.sort_by(|a, b| {
+        b.entities
+            .len()
+            .cmp(&a.entities.len())
+            .then_with(|| a.entities[0].name.cmp(&b.entities[0].name))
+    });
+    clusters
+}
+
+fn port_signature(entity: &Entity) -> Vec<String> {
+    entity
+        .ports
+        .iter()
+        .map(|port| {
+            let ty: String = port.r#type.chars().filter(|c| !c.is_whitespace()).collect();
+            format!(
+                "{} {}",
+                port.direction.to_ascii_lowercase(),
+                ty.to_ascii_lowercase()
+            )
+        })
+        .collect()
+}
+
+/// Name-independent shape of an entity's architectures: signal types, process
+/// kinds, concurrent assignment kinds and instantiated targets.
+fn body_features(input: &Input, entity: &Entity) -> HashMap<String, usize> {
+    let mut features: HashMap<String, usize> = HashMap::new();
+    let mut add = |feature: String| *features.entry(feature).or_insert(0) += 1;
+    for arch in input
+        .architectures
+        .iter()
+        .filter(|arch| arch.entity_name.eq_ignore_ascii_case(&entity.name))
+    {
+        let in_arch =
+            |file: &str, name: &str| file == arch.file && name.eq_ignore_ascii_case(&arch.name);
+        for sig in input
+            .signals
+            .iter()
+            .filter(|s| in_arch(&s.file, &s.in_entity))
+        {
+            add(format!(
+                "signal:{}",
+                sig.r#type.to_ascii_lowercase().replace(' ', "")
+            ));
+        }
+        for proc in input
+            .processes
+            .iter()
+            .filter(|p| in_arch(&p.file, &p.in_arch))
+        {
+            add(format!(
+                "process:{}:{}:{}:{}:{}",
+                proc.is_sequential,
+                proc.clock_edge,
+                proc.has_reset,
+                proc.assigned_signals.len(),
+                proc.read_signals.len()
+            ));
+        }
+        for ca in input
+            .concurrent_assignments
+            .iter()
+            .filter(|c| in_arch(&c.file, &c.in_arch))
+        {
+            add(format!("assign:{}:{}", ca.kind, ca.read_signals.len()));
+        }
+        for inst in input
+            .instances
+            .iter()
+            .filter(|i| in_arch(&i.file, &i.in_arch))
+        {
+            let target = inst.target.rsplit('.').next().unwrap_or(&inst.target);
+            add(format!("instance:{}", target.to_ascii_lowercase()));
+        }
+    }
+    features
+}
+
+fn jaccard(a: &HashMap<String, usize>, b: &HashMap<String, usize>) -> f64 {
+    let mut shared = 0;
+    let mut total = 0;
+    for (feature, &count) in a {
+        let other = b.get(feature).copied().unwrap_or(0);
+        shared += count.min(other);
+        total += count.max(other);
+    }
+    for (feature, &count) in b {
+        if !a.contains_key(feature) {
+            total += count;
+        }
+    }
+    if total == 0 {
+        1.0
+    } else {
+        shared as f64 / total as f64
+    }
+}
+
+/// Single-linkage grouping of bodies at `SIMILARITY_THRESHOLD`; singletons are
+/// dropped.
+fn link_similar(bodies: &[HashMap<String, usize>]) -> Vec<Vec<usize>> {
+    let mut group_of: Vec<usize> = (0..bodies.len()).collect();
+    for i in 0..bodies.len() {
+        for j in i + 1..bodies.len() {
+            if jaccard(&bodies[i], &bodies[j]) < SIMILARITY_THRESHOLD {
+                continue;
+            }
+            let (keep, merge) = (group_of[i].min(group_of[j]), group_of[i].max(group_of[j]));
+            for group in group_of.iter_mut() {
+                if *group == merge {
+                    *group = keep;
+                }
+            }
+        }
+    }
+    let mut groups: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for (idx, group) in group_of.into_iter().enumerate() {
+        groups.entry(group).or_default().push(idx);
+    }
+    groups.into_values().filter(|g| g.len() > 1).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{Architecture, Port, Process, Signal};
+
+    fn add_block(input: &mut Input, name: &str, data_type: &str, processes: usize) {
+        let file = format!("{}.vhd", name);
+        let port = |port: &str, direction: &str, ty: &str| Port {
+            name: format!("{}_{}", name, port),
+            direction: direction.to_string(),
+            r#type: ty.to_string(),
+            in_entity: name.to_string(),
+            ..Default::default()
+        };
+        input.entities.push(Entity {
+            name: name.to_string(),
+            file: file.clone(),
+            line: 1,
+            ports: vec![
+                port("clk", "in", "std_logic"),
+                port("d", "in", data_type),
+                port("q", "out", data_type),
+            ],
+            ..Default::default()
+        });
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: name.to_string(),
+            file: file.clone(),
+            line: 10,
+            ..Default::default()
+        });
+        input.signals.push(Signal {
+            name: format!("{}_r", name),
+            r#type: data_type.to_string(),
+            file: file.clone(),
+            in_entity: "rtl".to_string(),
+            ..Default::default()
+        });
+        for _ in 0..processes {
+            input.processes.push(Process {
+                is_sequential: true,
+                clock_edge: "rising".to_string(),
+                assigned_signals: vec!["r".to_string()],
+                read_signals: vec!["d".to_string()],
+                file: file.clone(),
+                in_arch: "rtl".to_string(),
+                ..Default::default()
+            });
+        }
+    }
+
+    #[test]
+    fn clusters_entities_with_same_ports_and_body() {
+        let mut input = Input::default();
+        add_block(&mut input, "reg_a", "std_logic_vector(7 downto 0)", 1);
+        add_block(&mut input, "reg_b", "std_logic_vector(7 downto 0)", 1);
+        add_block(&mut input, "reg_wide", "std_logic_vector(15 downto 0)", 1);
+        add_block(&mut input, "reg_busy", "std_logic_vector(7 downto 0)", 4);
+
+        let clusters = duplicate_clusters(&input);
+        assert_eq!(clusters.len(), 1);
+        let names: Vec<&str> = clusters[0]
+            .entities
+            .iter()
+            .map(|m| m.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["reg_a", "reg_b"]);
+        assert_eq!(clusters[0].similarity, 1.0);
+        assert_eq!(clusters[0].port_signature[0], "in std_logic");
+    }
+}
@@ -0,0 +1,590 @@
+//! Just enough elaboration for width checks and instance counts: integer
+//! generic and constant values, the constant folding needed to size
+//! `WIDTH-1 downto 0` style ranges, and the copies for-generates make of the
+//! instances in them.
+//!
+//! An architecture sees package constants, its entity's generic defaults and
+//! its own constants; an instance's entity sees package constants and its
+//! `generic_map` actuals, evaluated in the instantiating architecture, with
+//! defaults for the rest. Propagation is one level deep: an entity's own scope
+//! always uses its defaults, not the values a parent passes it. Package
+//! constants are visible everywhere, whatever the `use` clauses say.
+
+use std::collections::HashMap;
+
+use crate::policy::input::{ConstantDeclaration, Entity, GenerateStatement, Input, Instance};
+
+/// Integer generic and constant values by lowercase name.
+pub type Generics = HashMap<String, i64>;
+
+/// Cap on the elaborated copies of one instance when
+/// `lint_config.generate_limit` is 0.
+const DEFAULT_GENERATE_LIMIT: usize = 1024;
+
+/// Values visible in `arch_name` (the first architecture of that name, as
+/// elsewhere in the hierarchy checks): package constants, the generic
+/// defaults of its entity, then the constants it declares. Only the package
+/// constants for an unknown architecture.
+pub fn architecture_scope(input: &Input, arch_name: &str) -> Generics {
+    let mut scope = package_constants(input);
+    let Some(arch) = input
+        .architectures
+        .iter()
+        .find(|arch| !arch_name.is_empty() && arch.name.eq_ignore_ascii_case(arch_name))
+    else {
+        return scope;
+    };
+    if let Some(entity) = input
+        .entities
+        .iter()
+        .find(|entity| entity.name.eq_ignore_ascii_case(&arch.entity_name))
+    {
+        scope = bind_generics(entity, None, &Generics::new(), scope);
+    }
+    fold_constants(
+        input.constant_decls.iter().filter(|decl| {
+            decl.in_package.is_empty()
+                && decl.file == arch.file
+                && decl.in_arch.eq_ignore_ascii_case(&arch.name)
+        }),
+        &mut scope,
+    );
+    scope
+}
+
+/// Integer constants declared in packages. Constants may refer to ones
+/// declared later or in other files.
+pub fn package_constants(input: &Input) -> Generics {
+    let mut scope = Generics::new();
+    fold_constants(
+        input
+            .constant_decls
+            .iter()
+            .filter(|decl| !decl.in_package.is_empty()),
+        &mut scope,
+    );
+    scope
+}
+
+/// Adds the constants among `decls` whose values fold, repeating until no
+/// more do so a constant may use one declared after it.
+fn fold_constants<'a>(decls: impl Iterator<Item = &'a ConstantDeclaration>, scope: &mut Generics) {
+    let mut pending: Vec<&ConstantDeclaration> = decls.collect();
+    loop {
+        let before = pending.len();
+        pending.retain(|decl| match eval(&decl.value, scope) {
+            Some(value) => {
+                scope.insert(decl.name.to_ascii_lowercase(), value);
+                false
+            }
+            None => true,
+        });
+        if pending.len() == before {
+            return;
+        }
+    }
+}
+
+/// Folded range width of each of `input.signals` (same order), using the
+/// scope of the architecture declaring it; 0 when the type has no range
+/// or a bound doesn't fold.
+pub fn signal_range_widths(input: &Input) -> Vec<usize> {
+    let mut scopes: HashMap<String, Generics> = HashMap::new();
+    input
+        .signals
+        .iter()
+        .map(|sig| {
+            let generics = scopes
+                .entry(sig.in_entity.to_ascii_lowercase())
+                .or_insert_with(|| architecture_scope(input, &sig.in_entity));
+            range_width(&sig.r#type, generics).unwrap_or(0)
+        })
+        .collect()
+}
+
+/// Generic values of `entity`, in declaration order so a default may refer to
+/// an earlier generic. With `inst`, mapped actuals (evaluated in `outer`, the
+/// instantiating scope) replace the defaults. Generics that don't fold to an
+/// integer are left out.
+pub fn entity_generics(entity: &Entity, inst: Option<&Instance>, outer: &Generics) -> Generics {
+    bind_generics(entity, inst, outer, Generics::new())
+}
+
+/// `entity_generics` on top of the package constants, which generic defaults
+/// and port ranges may use.
+pub fn entity_scope(
+    input: &Input,
+    entity: &Entity,
+    inst: Option<&Instance>,
+    outer: &Generics,
+) -> Generics {
+    bind_generics(entity, inst, outer, package_constants(input))
+}
+
+fn bind_generics(
+    entity: &Entity,
+    inst: Option<&Instance>,
+    outer: &Generics,
+    mut values: Generics,
+) -> Generics {
+    for generic in &entity.generics {
+        let actual = inst.and_then(|inst| {
+            inst.generic_map
+                .iter()
+                .find(|(formal, _)| formal.eq_ignore_ascii_case(&generic.name))
+                .map(|(_, actual)| actual)
+        });
+        let value = match actual {
+            Some(actual) => eval(actual, outer),
+            None => eval(&generic.default, &values),
+        };
+        if let Some(value) = value {
+            values.insert(generic.name.to_ascii_lowercase(), value);
+        }
+    }
+    values
+}
+
+/// Copies of `inst` after elaboration: the product of the iteration counts of
+/// the for-generates enclosing it (its `in_arch` is `arch.gen.nested`), 0
+/// inside a null range. Loops the extractor couldn't count are folded in the
+/// architecture's scope; ones that still don't fold count
+/// once. The product is capped at `lint_config.generate_limit`.
+pub fn instance_copies(input: &Input, inst: &Instance) -> usize {
+    let limit = match input.lint_config.generate_limit {
+        0 => DEFAULT_GENERATE_LIMIT,
+        limit => limit,
+    };
+    let mut scopes = inst.in_arch.split('.');
+    let Some(arch) = scopes.next() else {
+        return 1;
+    };
+    let generics = architecture_scope(input, arch);
+    let mut scope = arch.to_string();
+    let mut copies = 1usize;
+    for label in scopes {
+        let generate = input.generates.iter().find(|gen| {
+            gen.file == inst.file
+                && gen.in_arch.eq_ignore_ascii_case(&scope)
+                && generate_label(gen).eq_ignore_ascii_case(label)
+        });
+        if let Some(generate) = generate {
+            copies = copies
+                .saturating_mul(iterations(generate, &generics).unwrap_or(1))
+                .min(limit);
+        }
+        scope = format!("{}.{}", scope, label);
+    }
+    copies
+}
+
+/// The label the extractor scopes a generate's contents under.
+fn generate_label(gen: &GenerateStatement) -> String {
+    if !gen.label.is_empty() {
+        gen.label.clone()
+    } else if gen.line > 0 {
+        format!("gen@{}", gen.line)
+    } else {
+        "gen".to_string()
+    }
+}
+
+/// Iterations of a for-generate (1 for if/case generates), or `None` when
+/// its range doesn't fold.
+fn iterations(gen: &GenerateStatement, generics: &Generics) -> Option<usize> {
+    if !gen.kind.eq_ignore_ascii_case("for") {
+        return Some(1);
+    }
+    if gen.can_elaborate && gen.iteration_count >= 0 {
+        return Some(gen.iteration_count as usize);
+    }
+    let low = eval(&gen.range_low, generics)?;
+    let high = eval(&gen.range_high, generics)?;
+    let count = match gen.range_dir.to_ascii_lowercase().as_str() {
+        "to" => high - low + 1,
+        "downto" => low - high + 1,
+        _ => return None,
+    };
+    Some(count.max(0) as usize)
+}
+
+/// Bit count of the first parenthesized range in a type or slice
+/// (`std_logic_vector(WIDTH-1 downto 0)`, `bus(7 downto 4)`), or `None` when
+/// there is no range or a bound doesn't fold.
+pub fn range_width(text: &str, generics: &Generics) -> Option<usize> {
+    let (low, high) = range_bounds(text, generics)?;
+    Some(low.abs_diff(high) as usize + 1)
+}
+
+/// Width of a declaration of type `type_str`: the folded range when there is
+/// one, else the width the extractor recorded.
+pub fn declared_width(type_str: &str, recorded: usize, generics: &Generics) -> usize {
+    range_width(type_str, generics).unwrap_or(recorded)
+}
+
+fn range_bounds(text: &str, generics: &Generics) -> Option<(i64, i64)> {
+    let start = text.find('(')?;
+    let inside = &text[start + 1..matching_paren(text, start)?];
+    let lower = inside.to_ascii_lowercase();
+    let (left, right) = split_top_level(inside, &lower, " downto ")
+        .or_else(|| split_top_level(inside, &lower, " to "))?;
+    Some((eval(left, generics)?, eval(right, generics)?))
+}
+
+fn matching_paren(text: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (idx, ch) in text.char_indices().skip_while(|(idx, _)| *idx < open) {
+        match ch {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(idx);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Splits `text` at the first `keyword` outside parentheses; `lower` is
+/// `text` lowercased, for the keyword search.
+fn split_top_level<'a>(text: &'a str, lower: &str, keyword: &str) -> Option<(&'a str, &'a str)> {
+    let mut depth = 0i32;
+    for (idx, ch) in lower.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ if depth == 0 && lower[idx..].starts_with(keyword) => {
+                return Some((&text[..idx], &text[idx + keyword.len()..]));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Folds an integer expression of literals, known generics, `+ - * / **`,
+/// `mod`, `rem` and parentheses. Anything else (attributes, function calls,
+/// unknown names) is `None`.
+pub fn eval(expr: &str, generics: &Generics) -> Option<i64> {
+    let tokens = tokenize(expr)?;
+    let mut parser = Parser {
+        tokens: &tokens,
+        pos: 0,
+        generics,
+    };
+    let value = parser.sum()?;
+    (parser.pos == tokens.len()).then_some(value)
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Num(i64),
+    Name(String),
+    Op(&'static str),
+}
+
+fn tokenize(expr: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = expr.trim().chars().peekable();
+    while let Some(&ch) = chars.peek() {
+        if ch.is_whitespace() {
+            chars.next();
+        } else if ch.is_ascii_digit() {
+            let mut digits = String::new();
+            while let Some(&c) = chars.peek().filter(|c| c.is_ascii_digit() || **c == '_') {
+                digits.push(c);
+                chars.next();
+            }
+            tokens.push(Token::Num(digits.replace('_', "").parse().ok()?));
+        } else if ch.is_ascii_alphabetic() {
+            let mut name = String::new();
+            while let Some(&c) = chars
+                .peek()
+                .filter(|c| c.is_ascii_alphanumeric() || **c == '_')
+            {
+                name.push(c);
+                chars.next();
+            }
+            match name.to_ascii_lowercase().as_str() {
+                "mod" => tokens.push(Token::Op("mod")),
+                "rem" => tokens.push(Token::Op("rem")),
+                _ => tokens.push(Token::Name(name.to_ascii_lowercase())),
+            }
+        } else {
+            chars.next();
+            let op = match ch {
+                '*' if chars.peek() == Some(&'*') => {
+                    chars.next();
+                    "**"
+                }
+                '+' => "+",
+                '-' => "-",
+                '*' => "*",
+                '/' => "/",
+                '(' => "(",
+                ')' => ")",
+                _ => return None,
+            };
+            tokens.push(Token::Op(op));
+        }
+    }
+    Some(tokens)
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    generics: &'a Generics,
+}
+
+impl Parser<'_> {
+    fn eat(&mut self, op: &str) -> bool {
+        let found = matches!(self.tokens.get(self.pos), Some(Token::Op(next)) if *next == op);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn sum(&mut self) -> Option<i64> {
+        let mut value = if self.eat("-") {
+            self.product()?.checked_neg()?
+        } else {
+            self.eat("+");
+            self.product()?
+        };
+        loop {
+            if self.eat("+") {
+                value = value.checked_add(self.product()?)?;
+            } else if self.eat("-") {
+                value = value.checked_sub(self.product()?)?;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    fn product(&mut self) -> Option<i64> {
+        let mut value = self.power()?;
+        loop {
+            if self.eat("*") {
+                value = value.checked_mul(self.power()?)?;
+            } else if self.eat("/") {
+                value = value.checked_div(self.power()?)?;
+            } else if self.eat("mod") {
+                value = value.checked_rem_euclid(self.power()?)?;
+            } else if self.eat("rem") {
+                value = value.checked_rem(self.power()?)?;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    fn power(&mut self) -> Option<i64> {
+        let base = self.primary()?;
+        if self.eat("**") {
+            let exp = u32::try_from(self.primary()?).ok()?;
+            return base.checked_pow(exp);
+        }
+        Some(base)
+    }
+
+    fn primary(&mut self) -> Option<i64> {
+        if self.eat("(") {
+            let value = self.sum()?;
+            return self.eat(")").then_some(value);
+        }
+        let value = match self.tokens.get(self.pos)? {
+            Token::Num(n) => *n,
+            Token::Name(name) => *self.generics.get(name)?,
+            Token::Op(_) => return None,
+        };
+        self.pos += 1;
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{Architecture, GenericDecl};
+
+    fn constant(name: &str, value: &str, in_package: &str, in_arch: &str) -> ConstantDeclaration {
+        ConstantDeclaration {
+            name: name.to_string(),
+            value: value.to_string(),
+            file: "top.vhd".to_string(),
+            in_package: in_package.to_string(),
+            in_arch: in_arch.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn generics(pairs: &[(&str, i64)]) -> Generics {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), *value))
+            .collect()
+    }
+
+    fn generic(name: &str, default: &str) -> GenericDecl {
+        GenericDecl {
+            name: name.to_string(),
+            default: default.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn eval_folds_integer_expressions() {
+        let env = generics(&[("width", 8), ("depth", 4)]);
+        assert_eq!(eval("WIDTH-1", &env), Some(7));
+        assert_eq!(eval("2**DEPTH - 1", &env), Some(15));
+        assert_eq!(eval("(width*2)/4 + 1_0", &env), Some(14));
+        assert_eq!(eval("-width mod 3", &env), Some(-2));
+        assert_eq!(eval("unknown - 1", &env), None);
+        assert_eq!(eval("data'length", &env), None);
+        assert_eq!(eval("width -", &env), None);
+        assert_eq!(eval("1/0", &env), None);
+    }
+
+    #[test]
+    fn range_width_folds_bounds() {
+        let env = generics(&[("width", 8)]);
+        assert_eq!(
+            range_width("std_logic_vector(WIDTH-1 downto 0)", &env),
+            Some(8)
+        );
+        assert_eq!(range_width("unsigned(0 to (WIDTH*2)-1)", &env), Some(16));
+        assert_eq!(range_width("bus(7 downto 4)", &env), Some(4));
+        assert_eq!(range_width("std_logic", &env), None);
+        assert_eq!(range_width("std_logic_vector(N-1 downto 0)", &env), None);
+        assert_eq!(declared_width("std_logic", 1, &env), 1);
+    }
+
+    #[test]
+    fn instance_generics_override_defaults() {
+        let entity = Entity {
+            name: "fifo".to_string(),
+            generics: vec![generic("WIDTH", "4"), generic("BYTES", "WIDTH / 8")],
+            ..Default::default()
+        };
+        let defaults = entity_generics(&entity, None, &Generics::new());
+        assert_eq!(defaults, generics(&[("width", 4), ("bytes", 0)]));
+
+        let inst = Instance {
+            generic_map: [("width".to_string(), "BUS_W * 2".to_string())].into(),
+            ..Default::default()
+        };
+        let outer = generics(&[("bus_w", 16)]);
+        let mapped = entity_generics(&entity, Some(&inst), &outer);
+        assert_eq!(mapped, generics(&[("width", 32), ("bytes", 4)]));
+    }
+
+    #[test]
+    fn architecture_sees_entity_defaults() {
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "top".to_string(),
+            generics: vec![generic("N", "12")],
+            ..Default::default()
+        });
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "top".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(architecture_scope(&input, "RTL"), generics(&[("n", 12)]));
+        assert!(architecture_scope(&input, "other").is_empty());
+    }
+
+    #[test]
+    fn architecture_scope_folds_constants() {
+        let mut input = Input::default();
+        input.constant_decls = vec![
+            constant("C_ADDR_W", "C_DEPTH_LOG2 + 2", "cfg_pkg", ""),
+            constant("C_DEPTH_LOG2", "4", "cfg_pkg", ""),
+            constant("C_RESET", "x\"00\"", "cfg_pkg", ""),
+            constant("C_WORDS", "2**C_ADDR_W / N", "", "rtl"),
+            constant("C_OTHER", "1", "", "other_rtl"),
+        ];
+        input.entities.push(Entity {
+            name: "top".to_string(),
+            generics: vec![generic("N", "C_DEPTH_LOG2 * 2")],
+            ..Default::default()
+        });
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "top".to_string(),
+            file: "top.vhd".to_string(),
+            ..Default::default()
+        });
+
+        let scope = architecture_scope(&input, "rtl");
+        assert_eq!(scope.get("c_addr_w"), Some(&6));
+        assert_eq!(scope.get("n"), Some(&8));
+        assert_eq!(scope.get("c_words"), Some(&8));
+        assert!(!scope.contains_key("c_reset"));
+        assert!(!scope.contains_key("c_other"));
+        assert_eq!(
+            architecture_scope(&input, "missing"),
+            generics(&[("c_addr_w", 6), ("c_depth_log2", 4)])
+        );
+        assert_eq!(
+            crate::policy::helpers::eval_const_expr(&input, "rtl", "C_WORDS-1"),
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn instance_copies_multiply_enclosing_loops() {
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "top".to_string(),
+            generics: vec![generic("LANES", "4")],
+            ..Default::default()
+        });
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "top".to_string(),
+            ..Default::default()
+        });
+        for (label, in_arch, high, count) in [
+            ("g_lane", "rtl", "LANES-1", -1),
+            ("g_bit", "rtl.g_lane", "7", 8),
+            ("g_none", "rtl", "-1", 0),
+            ("g_dyn", "rtl", "width'high", -1),
+        ] {
+            input.generates.push(GenerateStatement {
+                label: label.to_string(),
+                kind: "for".to_string(),
+                file: "top.vhd".to_string(),
+                in_arch: in_arch.to_string(),
+                range_low: "0".to_string(),
+                range_high: high.to_string(),
+                range_dir: "to".to_string(),
+                can_elaborate: count >= 0,
+                iteration_count: count,
+                ..Default::default()
+            });
+        }
+        let inst = |in_arch: &str| Instance {
+            file: "top.vhd".to_string(),
+            in_arch: in_arch.to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(instance_copies(&input, &inst("rtl")), 1);
+        assert_eq!(instance_copies(&input, &inst("rtl.g_lane")), 4);
+        assert_eq!(instance_copies(&input, &inst("RTL.G_LANE.g_bit")), 32);
+        assert_eq!(instance_copies(&input, &inst("rtl.g_none")), 0);
+        assert_eq!(instance_copies(&input, &inst("rtl.g_dyn")), 1);
+
+        input.lint_config.generate_limit = 10;
+        assert_eq!(instance_copies(&input, &inst("rtl.g_lane.g_bit")), 10);
+    }
+}
@@ -1,378 +1,800 @@
+use crate::policy::baseline;
 use crate::policy::cdc;
+use crate::policy::clock_domains;
 use crate::policy::clocks_resets;
 use crate::policy::combinational;
+use crate::policy::compile_order;
 use crate::policy::configurations;
 use crate::policy::core;
+use crate::policy::dead_code;
+use crate::policy::diff;
 use crate::policy::fsm;
+use crate::policy::generics;
 use crate::policy::helpers;
 use crate::policy::hierarchy;
+use crate::policy::initial_values;
 use crate::policy::input::Input;
 use crate::policy::instances;
 use crate::policy::latch;
+use crate::policy::metavalues;
+use crate::policy::metrics;
 use crate::policy::naming;
 use crate::policy::ports;
 use crate::policy::power;
 use crate::policy::processes;
 use crate::policy::quality;
+use crate::policy::ranges::Ranges;
 use crate::policy::rdc;
-use crate::policy::result::{AmbiguousConstruct, MissingCheckTask, Result, Summary, Violation};
+use crate::policy::rule_docs;
+use crate::policy::registry::{self, FileContexts};
+use crate::policy::reset_domains;
+use crate::policy::result::{
+    AmbiguousConstruct, ClockDomainMap, Confidence, FsmGraph, MissingCheckTask, ResetDomainMap,
+    Result, Summary, Suppressions, VerificationCoverage, Violation,
+};
+use crate::policy::rule::{self, Rule};
+use crate::policy::scope::Scopes;
+use crate::policy::scripting;
 use crate::policy::security;
 use crate::policy::sensitivity;
 use crate::policy::sequential;
+use crate::policy::shard;
 use crate::policy::signals;
+use crate::policy::standards;
 use crate::policy::style;
 use crate::policy::subprograms;
 use crate::policy::synthesis;
 use crate::policy::testbench;
+use crate::policy::timing;
 use crate::policy::types;
+use crate::policy::use_clauses;
+use crate::policy::vendor;
 use crate::policy::verification;
+use crate::policy::waivers;
+use crate::policy::width_arith;
+use serde::ser::{self, Serialize};
+use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
 
-pub fn evaluate(input: &Input) -> Result {
-    let timing_enabled = is_timing_enabled();
-    let total_start = Instant::now();
-    let mut timings: Vec<TimingEntry> = Vec::new();
-    let mut raw = Vec::new();
-    if timing_enabled {
-        eprintln!("=== Policy Timing (live) ===");
+type ModuleFn = fn(&Input) -> Vec<Violation>;
+type ReadsFn = fn() -> &'static [&'static str];
+
+/// A built-in rule module: one family's `violations` or
+/// `optional_violations`.
+struct RuleModule {
+    name: &'static str,
+    run: ModuleFn,
+    /// Input tables the module reads (on top of `helpers::HELPER_READS`).
+    reads: ReadsFn,
+}
+
+const fn module(name: &'static str, run: ModuleFn, reads: ReadsFn) -> RuleModule {
+    RuleModule { name, run, reads }
+}
+
+impl Rule for RuleModule {
+    fn id(&self) -> &'static str {
+        self.name
     }
-    raw.extend(collect_timed(
-        "core",
-        input,
-        timing_enabled,
-        &mut timings,
-        core::violations,
-    ));
-    let verification_analysis = if timing_enabled {
-        let start = Instant::now();
-        let analysis = verification::analyze(input);
-        let elapsed = start.elapsed();
-        timings.push(TimingEntry {
-            name: "verification",
-            duration: elapsed,
-            count: analysis.violations.len(),
-        });
-        analysis
-    } else {
-        verification::analyze(input)
-    };
-    raw.extend(verification_analysis.violations);
-    let missing_checks = verification_analysis.missing_checks;
-    let ambiguous_constructs = verification_analysis.ambiguous_constructs;
-    raw.extend(collect_timed(
-        "cdc",
-        input,
-        timing_enabled,
-        &mut timings,
-        cdc::violations,
-    ));
-    raw.extend(collect_timed(
+
+    fn category(&self) -> &'static str {
+        module_family(self.name)
+    }
+
+    fn check(&self, input: &Input) -> Vec<Violation> {
+        (self.run)(input)
+    }
+
+    fn reads(&self) -> &'static [&'static str] {
+        (self.reads)()
+    }
+}
+
+/// Rule modules in execution order. `--only`/`--skip` select by module name;
+/// selecting a family (e.g. `signals`) also covers its `_optional` companion.
+const MODULES: &[RuleModule] = &[
+    module("core", core::violations, core::reads),
+    module("cdc", cdc::violations, cdc::reads),
+    module(
         "combinational",
-        input,
-        timing_enabled,
-        &mut timings,
         combinational::violations,
-    ));
-    raw.extend(collect_timed(
+        combinational::reads,
+    ),
+    module(
+        "compile_order",
+        compile_order::violations,
+        compile_order::reads,
+    ),
+    module(
         "clocks_resets",
-        input,
-        timing_enabled,
-        &mut timings,
         clocks_resets::violations,
-    ));
-    raw.extend(collect_timed(
+        clocks_resets::reads,
+    ),
+    module(
         "clocks_resets_optional",
-        input,
-        timing_enabled,
-        &mut timings,
         clocks_resets::optional_violations,
-    ));
-    raw.extend(collect_timed(
-        "fsm",
-        input,
-        timing_enabled,
-        &mut timings,
-        fsm::violations,
-    ));
-    raw.extend(collect_timed(
-        "fsm_optional",
-        input,
-        timing_enabled,
-        &mut timings,
-        fsm::optional_violations,
-    ));
-    raw.extend(collect_timed(
+        clocks_resets::reads,
+    ),
+    module("fsm", fsm::violations, fsm::reads),
+    module("fsm_optional", fsm::optional_violations, fsm::reads),
+    module(
         "configurations",
-        input,
-        timing_enabled,
-        &mut timings,
         configurations::violations,
-    ));
-    raw.extend(collect_timed(
-        "hierarchy",
-        input,
-        timing_enabled,
-        &mut timings,
-        hierarchy::violations,
-    ));
-    raw.extend(collect_timed(
-        "instances",
-        input,
-        timing_enabled,
-        &mut timings,
-        instances::violations,
-    ));
-    raw.extend(collect_timed(
-        "latch",
-        input,
-        timing_enabled,
-        &mut timings,
-        latch::violations,
-    ));
-    raw.extend(collect_timed(
-        "naming",
-        input,
-        timing_enabled,
-        &mut timings,
-        naming::violations,
-    ));
-    raw.extend(collect_timed(
+        configurations::reads,
+    ),
+    module("dead_code", dead_code::violations, dead_code::reads),
+    module("hierarchy", hierarchy::violations, hierarchy::reads),
+    module("instances", instances::violations, instances::reads),
+    module("latch", latch::violations, latch::reads),
+    module("naming", naming::violations, naming::reads),
+    module(
         "naming_optional",
-        input,
-        timing_enabled,
-        &mut timings,
         naming::optional_violations,
-    ));
-    raw.extend(collect_timed(
-        "ports",
-        input,
-        timing_enabled,
-        &mut timings,
-        ports::violations,
-    ));
-    raw.extend(collect_timed(
-        "ports_optional",
-        input,
-        timing_enabled,
-        &mut timings,
-        ports::optional_violations,
-    ));
-    raw.extend(collect_timed(
-        "processes",
-        input,
-        timing_enabled,
-        &mut timings,
-        processes::violations,
-    ));
-    raw.extend(collect_timed(
-        "power",
-        input,
-        timing_enabled,
-        &mut timings,
-        power::violations,
-    ));
-    raw.extend(collect_timed(
-        "quality",
-        input,
-        timing_enabled,
-        &mut timings,
-        quality::violations,
-    ));
-    raw.extend(collect_timed(
+        naming::reads,
+    ),
+    module("ports", ports::violations, ports::reads),
+    module("ports_optional", ports::optional_violations, ports::reads),
+    module("processes", processes::violations, processes::reads),
+    module("power", power::violations, power::reads),
+    module("quality", quality::violations, quality::reads),
+    module(
         "quality_optional",
-        input,
-        timing_enabled,
-        &mut timings,
         quality::optional_violations,
-    ));
-    raw.extend(collect_timed(
-        "rdc",
-        input,
-        timing_enabled,
-        &mut timings,
-        rdc::violations,
-    ));
-    raw.extend(collect_timed(
-        "security",
-        input,
-        timing_enabled,
-        &mut timings,
-        security::violations,
-    ));
-    raw.extend(collect_timed(
-        "sensitivity",
-        input,
-        timing_enabled,
-        &mut timings,
-        sensitivity::violations,
-    ));
-    raw.extend(collect_timed(
-        "sequential",
-        input,
-        timing_enabled,
-        &mut timings,
-        sequential::violations,
-    ));
-    raw.extend(collect_timed(
-        "signals",
-        input,
-        timing_enabled,
-        &mut timings,
-        signals::violations,
-    ));
-    raw.extend(collect_timed(
-        "style",
-        input,
-        timing_enabled,
-        &mut timings,
-        style::violations,
-    ));
-    raw.extend(collect_timed(
-        "style_optional",
-        input,
-        timing_enabled,
-        &mut timings,
-        style::optional_violations,
-    ));
-    raw.extend(collect_timed(
-        "subprograms",
-        input,
-        timing_enabled,
-        &mut timings,
-        subprograms::violations,
-    ));
-    raw.extend(collect_timed(
-        "synthesis",
-        input,
-        timing_enabled,
-        &mut timings,
-        synthesis::violations,
-    ));
-    raw.extend(collect_timed(
-        "testbench",
-        input,
-        timing_enabled,
-        &mut timings,
-        testbench::violations,
-    ));
-    raw.extend(collect_timed(
+        quality::reads,
+    ),
+    module("rdc", rdc::violations, rdc::reads),
+    module("security", security::violations, security::reads),
+    module("sensitivity", sensitivity::violations, sensitivity::reads),
+    module("sequential", sequential::violations, sequential::reads),
+    module("signals", signals::violations, signals::reads),
+    module("standards", standards::violations, standards::reads),
+    module("style", style::violations, style::reads),
+    module("style_optional", style::optional_violations, style::reads),
+    module("subprograms", subprograms::violations, subprograms::reads),
+    module("synthesis", synthesis::violations, synthesis::reads),
+    module("testbench", testbench::violations, testbench::reads),
+    module("timing", timing::violations, timing::reads),
+    module(
         "testbench_optional",
-        input,
-        timing_enabled,
-        &mut timings,
         testbench::optional_violations,
-    ));
-    raw.extend(collect_timed(
-        "types",
-        input,
-        timing_enabled,
-        &mut timings,
-        types::violations,
-    ));
-    raw.extend(collect_timed(
-        "types_optional",
-        input,
-        timing_enabled,
-        &mut timings,
-        types::optional_violations,
-    ));
-
-    raw.extend(collect_timed(
+        testbench::reads,
+    ),
+    module("types", types::violations, types::reads),
+    module("types_optional", types::optional_violations, types::reads),
+    module("use_clauses", use_clauses::violations, use_clauses::reads),
+    module(
+        "use_clauses_optional",
+        use_clauses::optional_violations,
+        use_clauses::reads,
+    ),
+    module("waivers", waivers::violations, waivers::reads),
+    module("width_arith", width_arith::violations, width_arith::reads),
+    module("scripting", scripting::violations, scripting::reads),
+    module(
         "combinational_optional",
-        input,
-        timing_enabled,
-        &mut timings,
         combinational::optional_violations,
-    ));
-    raw.extend(collect_timed(
+        combinational::reads,
+    ),
+    module(
         "hierarchy_optional",
-        input,
-        timing_enabled,
-        &mut timings,
         hierarchy::optional_violations,
-    ));
-    raw.extend(collect_timed(
-        "latch_optional",
-        input,
-        timing_enabled,
-        &mut timings,
-        latch::optional_violations,
-    ));
-    raw.extend(collect_timed(
-        "power_optional",
-        input,
-        timing_enabled,
-        &mut timings,
-        power::optional_violations,
-    ));
-    raw.extend(collect_timed(
-        "rdc_optional",
-        input,
-        timing_enabled,
-        &mut timings,
-        rdc::optional_violations,
-    ));
-    raw.extend(collect_timed(
+        hierarchy::reads,
+    ),
+    module("latch_optional", latch::optional_violations, latch::reads),
+    module("power_optional", power::optional_violations, power::reads),
+    module("rdc_optional", rdc::optional_violations, rdc::reads),
+    module(
         "security_optional",
-        input,
-        timing_enabled,
-        &mut timings,
         security::optional_violations,
-    ));
-    raw.extend(collect_timed(
+        security::reads,
+    ),
+    module(
         "sensitivity_optional",
-        input,
-        timing_enabled,
-        &mut timings,
         sensitivity::optional_violations,
-    ));
-    raw.extend(collect_timed(
+        sensitivity::reads,
+    ),
+    module(
         "sequential_optional",
-        input,
-        timing_enabled,
-        &mut timings,
         sequential::optional_violations,
-    ));
-    raw.extend(collect_timed(
+        sequential::reads,
+    ),
+    module(
         "signals_optional",
-        input,
-        timing_enabled,
-        &mut timings,
         signals::optional_violations,
-    ));
-    raw.extend(collect_timed(
+        signals::reads,
+    ),
+    module(
         "synthesis_optional",
-        input,
-        timing_enabled,
-        &mut timings,
         synthesis::optional_violations,
-    ));
+        synthesis::reads,
+    ),
+    module("generics", generics::violations, generics::reads),
+    module(
+        "generics_optional",
+        generics::optional_violations,
+        generics::reads,
+    ),
+    module(
+        "initial_values",
+        initial_values::violations,
+        initial_values::reads,
+    ),
+    module("metavalues", metavalues::violations, metavalues::reads),
+    module("vendor", vendor::violations, vendor::reads),
+];
+
+const VERIFICATION_MODULE: &str = "verification";
+
+/// Module families whose rules only look inside one entity/architecture, so
+/// they can run on per-entity shards (`lint_config.shard_workers`).
+const SHARDABLE_MODULES: &[&str] = &["latch", "processes", "sensitivity", "style"];
+
+/// The rules `evaluate` runs: the built-in modules in execution order, then
+/// those registered with `register_rule!`.
+pub fn rules() -> Vec<&'static dyn Rule> {
+    let mut rules: Vec<&'static dyn Rule> = MODULES.iter().map(|module| module as _).collect();
+    rules.extend(rule::plugins());
+    rules
+}
+
+/// Names accepted by `--only`/`--skip`: every registered rule plus the
+/// categories they belong to.
+pub fn module_names() -> Vec<&'static str> {
+    let mut names = vec![VERIFICATION_MODULE];
+    for rule in rules() {
+        names.push(rule.id());
+        names.push(rule.category());
+    }
+    names.sort_unstable();
+    names.dedup();
+    names
+}
+
+pub fn is_known_module(name: &str) -> bool {
+    module_names()
+        .iter()
+        .any(|known| known.eq_ignore_ascii_case(name))
+}
+
+fn module_family(name: &str) -> &str {
+    name.strip_suffix("_optional").unwrap_or(name)
+}
+
+fn module_selected(input: &Input, name: &str) -> bool {
+    selected(input, name, module_family(name))
+}
+
+fn rule_selected(input: &Input, rule: &dyn Rule) -> bool {
+    selected(input, rule.id(), rule.category())
+}
+
+fn selected(input: &Input, name: &str, category: &str) -> bool {
+    let config = &input.lint_config;
+    let matches =
+        |sel: &String| sel.eq_ignore_ascii_case(name) || sel.eq_ignore_ascii_case(category);
+    if config.skip_modules.iter().any(matches) {
+        return false;
+    }
+    config.only_modules.is_empty() || config.only_modules.iter().any(matches)
+}
+
+/// Per-module violation cache for long-lived callers (language server/watch).
+/// Each entry is keyed by a hash of only the Input tables that module reads,
+/// so a module is skipped when its tables are unchanged even if others
+/// changed. Modules that read every table are not cached: hashing the whole
+/// input would cost about as much as running them.
+#[derive(Debug, Default)]
+pub struct ModuleCache {
+    entries: HashMap<&'static str, CachedModule>,
+    hits: usize,
+    misses: usize,
+}
+
+#[derive(Debug)]
+struct CachedModule {
+    key: u64,
+    violations: Vec<Violation>,
+}
+
+impl ModuleCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
 
-    let filtered = filter_violations(input, raw);
+pub fn evaluate(input: &Input) -> Result {
+    evaluate_with_cache(input, None)
+}
+
+pub fn evaluate_cached(input: &Input, cache: &mut ModuleCache) -> Result {
+    evaluate_with_cache(input, Some(cache))
+}
+
+fn evaluate_with_cache(input: &Input, mut cache: Option<&mut ModuleCache>) -> Result {
+    if input.lint_config.fast {
+        return evaluate_fast(input);
+    }
+    let timing_enabled = is_timing_enabled();
+    let total_start = Instant::now();
+    let mut timings: Vec<TimingEntry> = Vec::new();
+    let mut raw = Vec::new();
+    if timing_enabled {
+        eprintln!("=== Policy Timing (live) ===");
+    }
+    let mut missing_checks = Vec::new();
+    let mut ambiguous_constructs = Vec::new();
+    let mut verification_coverage = VerificationCoverage::default();
+    if module_selected(input, VERIFICATION_MODULE) {
+        let start = Instant::now();
+        let analysis = verification::analyze(input);
+        if timing_enabled {
+            timings.push(TimingEntry {
+                name: VERIFICATION_MODULE,
+                duration: start.elapsed(),
+                count: analysis.violations.len(),
+            });
+        }
+        raw.extend(analysis.violations);
+        missing_checks = analysis.missing_checks;
+        ambiguous_constructs = analysis.ambiguous_constructs;
+        verification_coverage = analysis.coverage;
+    }
+    let sharded = cache.is_none() && input.lint_config.shard_workers > 1;
+    let modules: Vec<&dyn Rule> = rules()
+        .into_iter()
+        .filter(|rule| rule_selected(input, *rule))
+        .filter(|rule| !(sharded && is_shardable(*rule)))
+        .collect();
+    let mut outputs: Vec<Option<Vec<Violation>>> = vec![None; modules.len()];
+    let mut keys = Vec::new();
+    if let Some(cache) = cache.as_deref_mut() {
+        let wanted: HashSet<&str> = modules
+            .iter()
+            .filter_map(|module| key_tables(*module))
+            .flatten()
+            .collect();
+        let table_hashes = table_hashes(input, &wanted);
+        for (slot, module) in outputs.iter_mut().zip(&modules) {
+            let key = module_key(*module, &table_hashes);
+            match cache.entries.get(module.id()) {
+                Some(entry) if Some(entry.key) == key => {
+                    cache.hits += 1;
+                    *slot = Some(entry.violations.clone());
+                }
+                _ => cache.misses += 1,
+            }
+            keys.push(key);
+        }
+    }
+    let pending: Vec<usize> = (0..modules.len())
+        .filter(|&idx| outputs[idx].is_none())
+        .collect();
+    let jobs: Vec<&dyn Rule> = pending.iter().map(|&idx| modules[idx]).collect();
+    let runs = run_modules(input, &jobs, module_workers(input), timing_enabled);
+    for (idx, (violations, timing)) in pending.into_iter().zip(runs) {
+        timings.extend(timing);
+        if let (Some(cache), Some(&Some(key))) = (cache.as_deref_mut(), keys.get(idx)) {
+            cache.entries.insert(
+                modules[idx].id(),
+                CachedModule {
+                    key,
+                    violations: violations.clone(),
+                },
+            );
+        }
+        outputs[idx] = Some(violations);
+    }
+    raw.extend(outputs.into_iter().flatten().flatten());
+    if sharded {
+        raw.extend(collect_timed(
+            "sharded",
+            input,
+            timing_enabled,
+            &mut timings,
+            |input| evaluate_shards(input, input.lint_config.shard_workers),
+        ));
+    }
+
+    let mut suppressions = Suppressions::default();
+    let mut filtered = filter_violations(input, raw, &mut suppressions);
+    canonicalize(&mut filtered);
     let filtered_missing_checks = filter_missing_checks(input, missing_checks);
     let filtered_ambiguous = filter_ambiguous_constructs(input, ambiguous_constructs);
     if timing_enabled {
         emit_timings(&timings, total_start.elapsed(), filtered.len());
     }
     Result {
-        summary: summarize(&filtered),
+        summary: summarize(input, &filtered),
+        rule_docs: rule_docs::for_violations(&filtered),
         violations: filtered,
         missing_checks: filtered_missing_checks,
         ambiguous_constructs: filtered_ambiguous,
+        clock_domains: clock_domain_map(input),
+        reset_domains: reset_domain_map(input),
+        fsm_graphs: fsm_graphs(input),
+        metrics: metrics::compute(input),
+        verification_coverage,
+        suppressions,
+    }
+}
+
+/// Sorts violations by file, line, rule and message, whatever order modules,
+/// workers and shards produced them in, and gives each its fingerprint. The
+/// sort is stable, so findings equal on all four keep module order.
+fn canonicalize(violations: &mut [Violation]) {
+    violations.sort_by(|a, b| {
+        (&a.file, a.line, &a.rule, &a.message).cmp(&(&b.file, b.line, &b.rule, &b.message))
+    });
+    let fingerprints = baseline::occurrence_fingerprints(violations);
+    for (v, fingerprint) in violations.iter_mut().zip(fingerprints) {
+        v.fingerprint = fingerprint;
+    }
+}
+
+/// Worker threads for module evaluation: `lint_config.module_workers`, or one
+/// per available core when unset.
+fn module_workers(input: &Input) -> usize {
+    match input.lint_config.module_workers {
+        0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n,
+    }
+}
+
+type ModuleRun = (Vec<Violation>, Option<TimingEntry>);
+
+/// Runs `modules` on up to `workers` threads. Workers pull the next module off
+/// a shared counter so one slow module does not hold up a fixed partition;
+/// results come back in `modules` order whichever thread ran them.
+fn run_modules(
+    input: &Input,
+    modules: &[&dyn Rule],
+    workers: usize,
+    timing_enabled: bool,
+) -> Vec<ModuleRun> {
+    let run = |module: &dyn Rule| {
+        run_timed(module.id(), input, timing_enabled, |input| {
+            module.check(input)
+        })
+    };
+    let workers = workers.min(modules.len());
+    if workers <= 1 {
+        return modules.iter().map(|module| run(*module)).collect();
+    }
+    let next = AtomicUsize::new(0);
+    let mut done: Vec<(usize, ModuleRun)> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let idx = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(module) = modules.get(idx) else {
+                            break;
+                        };
+                        done.push((idx, run(*module)));
+                    }
+                    done
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("policy worker panicked"))
+            .collect()
+    });
+    done.sort_by_key(|(idx, _)| *idx);
+    done.into_iter().map(|(_, run)| run).collect()
+}
+
+fn is_shardable(rule: &dyn Rule) -> bool {
+    SHARDABLE_MODULES.contains(&rule.category())
+}
+
+/// Runs the selected shardable modules on per-entity shards in parallel,
+/// keeping module order. Shared tables are copied into every shard, so a rule
+/// reading them can fire in more than one shard; duplicates are dropped.
+fn evaluate_shards(input: &Input, workers: usize) -> Vec<Violation> {
+    let modules: Vec<&dyn Rule> = rules()
+        .into_iter()
+        .filter(|rule| is_shardable(*rule) && rule_selected(input, *rule))
+        .collect();
+    if modules.is_empty() {
+        return Vec::new();
+    }
+    let shards = shard::split(input, workers);
+    let per_shard: Vec<Vec<Vec<Violation>>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = shards
+            .iter()
+            .map(|shard| {
+                let modules = &modules;
+                scope.spawn(move || modules.iter().map(|module| module.check(shard)).collect())
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("shard worker panicked"))
+            .collect()
+    });
+
+    let mut seen = HashSet::new();
+    let mut out = Vec::new();
+    for idx in 0..modules.len() {
+        for shard in &per_shard {
+            for v in &shard[idx] {
+                if seen.insert((&v.rule, &v.file, v.line, &v.message)) {
+                    out.push(v.clone());
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Fast mode: only the registry's fast rules, module by module in priority
+/// order, stopping once `error_budget` errors have been reported. The budget
+/// is checked between modules, not inside them: the module that reaches it
+/// still reports all of its violations, so the result can exceed the budget.
+fn evaluate_fast(input: &Input) -> Result {
+    let budget = input.lint_config.error_budget;
+    let mut violations = Vec::new();
+    let mut suppressions = Suppressions::default();
+    let rules = rules();
+    for name in registry::fast_modules() {
+        let Some(module) = rules.iter().find(|rule| rule.id() == name) else {
+            continue;
+        };
+        if !rule_selected(input, *module) {
+            continue;
+        }
+        let raw = module
+            .check(input)
+            .into_iter()
+            .filter(|v| registry::is_fast_rule(&v.rule))
+            .collect();
+        violations.extend(filter_violations(input, raw, &mut suppressions));
+        if budget > 0 && summarize(input, &violations).errors >= budget {
+            break;
+        }
+    }
+    canonicalize(&mut violations);
+    Result {
+        summary: summarize(input, &violations),
+        rule_docs: rule_docs::for_violations(&violations),
+        violations,
+        suppressions,
+        ..Default::default()
+    }
+}
+
+/// The Input tables a module's cache key covers: what it reads plus what the
+/// shared helpers read. `None` for a module that reads every table.
+fn key_tables(module: &dyn Rule) -> Option<Vec<&'static str>> {
+    let reads = module.reads();
+    if reads.contains(&rule::ALL_TABLES) {
+        return None;
+    }
+    let mut tables: Vec<&str> = reads.to_vec();
+    tables.extend_from_slice(helpers::HELPER_READS);
+    tables.sort_unstable();
+    tables.dedup();
+    Some(tables)
+}
+
+/// Hashes the top-level Input tables in `wanted`, each serialized on its own
+/// so module keys can be combined cheaply without serializing the rest.
+fn table_hashes(input: &Input, wanted: &HashSet<&str>) -> HashMap<&'static str, u64> {
+    let mut hasher = TableHasher {
+        wanted,
+        hashes: HashMap::new(),
+    };
+    input
+        .serialize(&mut hasher)
+        .expect("Input serializes as a struct");
+    hasher.hashes
+}
+
+fn module_key(module: &dyn Rule, table_hashes: &HashMap<&'static str, u64>) -> Option<u64> {
+    let mut hasher = DefaultHasher::new();
+    for table in key_tables(module)? {
+        table.hash(&mut hasher);
+        table_hashes.get(table).hash(&mut hasher);
+    }
+    Some(hasher.finish())
+}
+
+/// Serializer for `Input` that hashes the struct fields `wanted` names and
+/// skips the others. Each table goes through `serde_json::Value`, so map
+/// fields hash in key order rather than `HashMap` iteration order.
+struct TableHasher<'a> {
+    wanted: &'a HashSet<&'a str>,
+    hashes: HashMap<&'static str, u64>,
+}
+
+impl ser::SerializeStruct for &mut TableHasher<'_> {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> std::result::Result<(), Self::Error> {
+        if self.wanted.contains(key) {
+            let mut hasher = DefaultHasher::new();
+            serde_json::to_value(value)?.to_string().hash(&mut hasher);
+            self.hashes.insert(key, hasher.finish());
+        }
+        Ok(())
+    }
+
+    fn end(self) -> std::result::Result<(), Self::Error> {
+        Ok(())
     }
 }
 
-fn filter_violations(input: &Input, violations: Vec<Violation>) -> Vec<Violation> {
+/// `Serializer` methods for everything but a struct, which `TableHasher`
+/// rejects.
+macro_rules! not_a_struct {
+    ($($method:ident($($arg:ty),*) -> $ok:ty;)*) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> std::result::Result<$ok, Self::Error> {
+                Err(ser::Error::custom("expected a struct"))
+            }
+        )*
+    };
+}
+
+/// The compound serializers `TableHasher` never hands out.
+type Rejected = ser::Impossible<(), serde_json::Error>;
+
+impl ser::Serializer for &mut TableHasher<'_> {
+    type Ok = ();
+    type Error = serde_json::Error;
+    type SerializeSeq = Rejected;
+    type SerializeTuple = Rejected;
+    type SerializeTupleStruct = Rejected;
+    type SerializeTupleVariant = Rejected;
+    type SerializeMap = Rejected;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Rejected;
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> std::result::Result<Self, Self::Error> {
+        Ok(self)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(
+        self,
+        _value: &T,
+    ) -> std::result::Result<(), Self::Error> {
+        Err(ser::Error::custom("expected a struct"))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _value: &T,
+    ) -> std::result::Result<(), Self::Error> {
+        Err(ser::Error::custom("expected a struct"))
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> std::result::Result<(), Self::Error> {
+        Err(ser::Error::custom("expected a struct"))
+    }
+
+    not_a_struct! {
+        serialize_bool(bool) -> ();
+        serialize_i8(i8) -> ();
+        serialize_i16(i16) -> ();
+        serialize_i32(i32) -> ();
+        serialize_i64(i64) -> ();
+        serialize_u8(u8) -> ();
+        serialize_u16(u16) -> ();
+        serialize_u32(u32) -> ();
+        serialize_u64(u64) -> ();
+        serialize_f32(f32) -> ();
+        serialize_f64(f64) -> ();
+        serialize_char(char) -> ();
+        serialize_str(&str) -> ();
+        serialize_bytes(&[u8]) -> ();
+        serialize_none() -> ();
+        serialize_unit() -> ();
+        serialize_unit_struct(&'static str) -> ();
+        serialize_unit_variant(&'static str, u32, &'static str) -> ();
+        serialize_seq(Option<usize>) -> Rejected;
+        serialize_tuple(usize) -> Rejected;
+        serialize_tuple_struct(&'static str, usize) -> Rejected;
+        serialize_tuple_variant(&'static str, u32, &'static str, usize) -> Rejected;
+        serialize_map(Option<usize>) -> Rejected;
+        serialize_struct_variant(&'static str, u32, &'static str, usize) -> Rejected;
+    }
+}
+
+/// Applies config, scoping, standard, confidence, diff, waivers and baseline
+/// to raw violations, recording what waivers and the baseline drop in
+/// `suppressions`, and fills in the hierarchical scope and column range of
+/// those kept.
+fn filter_violations(
+    input: &Input,
+    violations: Vec<Violation>,
+    suppressions: &mut Suppressions,
+) -> Vec<Violation> {
     let mut out = Vec::new();
+    let waived = waivers::Active::new(input);
+    let contexts = FileContexts::new(input);
+    let min_confidence =
+        Confidence::parse(&input.lint_config.min_confidence).unwrap_or(Confidence::Low);
+    let mut baselined = input.lint_config.baseline.clone();
+    let scopes = Scopes::new(input);
+    let ranges = Ranges::new(input);
     for v in violations {
-        if helpers::rule_is_disabled(input, &v.rule) {
+        if helpers::rule_is_disabled_in(input, &v.rule, &v.file) {
+            continue;
+        }
+        if !registry::rule_applies(input, &contexts, &v.rule, &v.file) {
+            continue;
+        }
+        if !standards::recommendation_applies(input, &v.rule) {
             continue;
         }
         if helpers::is_third_party_file(input, &v.file) {
             continue;
         }
+        if helpers::path_matches_any(&input.lint_config.exclude, &v.file) {
+            continue;
+        }
+        if v.confidence < min_confidence {
+            continue;
+        }
+        if let Some(changed) = &input.lint_config.changed_lines {
+            if !diff::touches(changed, &v.file, v.line) {
+                continue;
+            }
+        }
+        if !waived.is_empty() && waived.waives(&v) {
+            suppressions.add(&v, true);
+            continue;
+        }
+        if !baselined.is_empty() {
+            if let Some(count) = baselined.get_mut(&baseline::fingerprint(&v)) {
+                if *count > 0 {
+                    *count -= 1;
+                    suppressions.add(&v, false);
+                    continue;
+                }
+            }
+        }
         let mut final_violation = v;
-        if let Some(sev) = helpers::get_rule_severity(input, &final_violation.rule) {
+        scopes.annotate(&mut final_violation);
+        ranges.annotate(&mut final_violation);
+        if let Some(sev) =
+            helpers::get_rule_severity_in(input, &final_violation.rule, &final_violation.file)
+        {
             if is_valid_severity(&sev) {
                 final_violation.severity = sev;
             }
@@ -382,9 +804,14 @@ fn filter_violations(input: &Input, violations: Vec<Violation>) -> Vec<Violation
     out
 }
 
-fn summarize(violations: &[Violation]) -> Summary {
+fn summarize(input: &Input, violations: &[Violation]) -> Summary {
     let mut summary = Summary::default();
     summary.total_violations = violations.len();
+    let libraries: HashMap<&str, &str> = input
+        .files
+        .iter()
+        .map(|f| (f.path.as_str(), f.library.as_str()))
+        .collect();
     for v in violations {
         match v.severity.as_str() {
             "error" => summary.errors += 1,
@@ -392,23 +819,90 @@ fn summarize(violations: &[Violation]) -> Summary {
             "info" => summary.info += 1,
             _ => {}
         }
+        summary
+            .by_directory
+            .entry(violation_directory(&v.file))
+            .or_default()
+            .add(&v.severity);
+        let library = match libraries.get(v.file.as_str()) {
+            Some(lib) if !lib.is_empty() => lib.to_ascii_lowercase(),
+            _ => "work".to_string(),
+        };
+        summary
+            .by_library
+            .entry(library)
+            .or_default()
+            .add(&v.severity);
     }
     summary
 }
 
+/// Directory part of a violation's file path; "." when it has none.
+fn violation_directory(file: &str) -> String {
+    match std::path::Path::new(file).parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.to_string_lossy().replace('\\', "/"),
+        _ => ".".to_string(),
+    }
+}
+
 fn is_valid_severity(sev: &str) -> bool {
     matches!(sev, "error" | "warning" | "info")
 }
 
+/// The inferred clock domains with the crossings the `cdc` rules check,
+/// reported with that module and without third-party files.
+fn clock_domain_map(input: &Input) -> ClockDomainMap {
+    if !module_selected(input, "cdc") {
+        return ClockDomainMap::default();
+    }
+    let mut map = clock_domains::infer(input);
+    if let Cow::Borrowed(crossings) = cdc::crossings(input) {
+        map.crossings = crossings.to_vec();
+    }
+    map.domains
+        .retain(|domain| !helpers::is_third_party_file(input, &domain.file));
+    map.crossings
+        .retain(|crossing| !helpers::is_third_party_file(input, &crossing.file));
+    map
+}
+
+/// The inferred reset tree, reported with the `rdc` module and without
+/// third-party files.
+fn reset_domain_map(input: &Input) -> ResetDomainMap {
+    if !module_selected(input, "rdc") {
+        return ResetDomainMap::default();
+    }
+    let mut map = reset_domains::infer(input);
+    map.domains
+        .retain(|domain| !helpers::is_third_party_file(input, &domain.file));
+    map.sources
+        .retain(|source| !helpers::is_third_party_file(input, &source.file));
+    map.synchronizers
+        .retain(|sync| !helpers::is_third_party_file(input, &sync.file));
+    map
+}
+
+/// State machine graphs, reported with the `fsm` module and without
+/// third-party files.
+fn fsm_graphs(input: &Input) -> Vec<FsmGraph> {
+    if !module_selected(input, "fsm") {
+        return Vec::new();
+    }
+    fsm::graphs(input)
+        .into_iter()
+        .filter(|graph| !helpers::is_third_party_file(input, &graph.file))
+        .collect()
+}
+
 fn filter_missing_checks(
     input: &Input,
     tasks: Vec<MissingCheckTask>,
 ) -> Vec<MissingCheckTask> {
-    if helpers::rule_is_disabled(input, "missing_verification_check") {
-        return Vec::new();
-    }
     tasks
         .into_iter()
+        .filter(|task| {
+            !helpers::rule_is_disabled_in(input, "missing_verification_check", &task.file)
+        })
         .filter(|task| !helpers::is_third_party_file(input, &task.file))
         .collect()
 }
@@ -417,11 +911,9 @@ fn filter_ambiguous_constructs(
     input: &Input,
     items: Vec<AmbiguousConstruct>,
 ) -> Vec<AmbiguousConstruct> {
-    if helpers::rule_is_disabled(input, "ambiguous_construct") {
-        return Vec::new();
-    }
     items
         .into_iter()
+        .filter(|item| !helpers::rule_is_disabled_in(input, "ambiguous_construct", &item.file))
         .filter(|item| !helpers::is_third_party_file(input, &item.file))
         .collect()
 }
@@ -439,11 +931,23 @@ fn collect_timed<F>(
     timings: &mut Vec<TimingEntry>,
     f: F,
 ) -> Vec<Violation>
+where
+    F: FnOnce(&Input) -> Vec<Violation>,
+{
+    let (out, timing) = run_timed(name, input, enabled, f);
+    timings.extend(timing);
+    out
+}
+
+/// Runs one module, timing it when instrumentation is on. Live lines go to
+/// stderr as modules finish, so with several workers they interleave; the
+/// final table is in module order.
+fn run_timed<F>(name: &'static str, input: &Input, enabled: bool, f: F) -> ModuleRun
 where
     F: FnOnce(&Input) -> Vec<Violation>,
 {
     if !enabled {
-        return f(input);
+        return (f(input), None);
     }
     eprintln!("  [start] {}", name);
     let start = Instant::now();
@@ -459,8 +963,7 @@ where
         entry.count,
         format_duration(entry.duration)
     );
-    timings.push(entry);
-    out
+    (out, Some(entry))
 }
 
 fn emit_timings(timings: &[TimingEntry], total: Duration, total_count: usize) {
@@ -541,6 +1044,7 @@ mod tests {
                 entity_name: "core".to_string(),
                 file: "a.vhd".to_string(),
                 line: 2,
+                ..Default::default()
             });
         input.signals.push(Signal {
             name: "sig".to_string(),
@@ -587,6 +1091,7 @@ mod tests {
                 entity_name: "core".to_string(),
                 file: "a.vhd".to_string(),
                 line: 2,
+                ..Default::default()
             });
         input.signals.push(Signal {
             name: "sig".to_string(),
@@ -597,4 +1102,504 @@ mod tests {
         assert_eq!(result.violations.len(), 1);
         assert_eq!(result.violations[0].severity, "error");
     }
+
+    #[test]
+    fn filter_drops_violations_below_min_confidence() {
+        let violation = |rule: &str, confidence| Violation {
+            rule: rule.to_string(),
+            severity: "info".to_string(),
+            file: "a.vhd".to_string(),
+            line: 1,
+            confidence,
+            ..Default::default()
+        };
+        let raw = vec![
+            violation("potential_memory_inference", Confidence::Low),
+            violation("combinational_reset", Confidence::Medium),
+            violation("entity_has_ports", Confidence::High),
+        ];
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "core".to_string(),
+            file: "a.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        for rule in [
+            "potential_memory_inference",
+            "combinational_reset",
+            "entity_has_ports",
+        ] {
+            input
+                .lint_config
+                .rules
+                .insert(rule.to_string(), "info".to_string());
+        }
+        assert_eq!(
+            filter_violations(&input, raw.clone(), &mut Suppressions::default()).len(),
+            3
+        );
+
+        input.lint_config.min_confidence = "Medium".to_string();
+        let kept: Vec<String> = filter_violations(&input, raw, &mut Suppressions::default())
+            .into_iter()
+            .map(|v| v.rule)
+            .collect();
+        assert_eq!(kept, vec!["combinational_reset", "entity_has_ports"]);
+    }
+
+    #[test]
+    fn filter_drops_baselined_violations() {
+        let violation = |line: usize, message: &str| Violation {
+            rule: "entity_has_ports".to_string(),
+            severity: "info".to_string(),
+            file: "a.vhd".to_string(),
+            line,
+            message: message.to_string(),
+            ..Default::default()
+        };
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "core".to_string(),
+            file: "a.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        input
+            .lint_config
+            .rules
+            .insert("entity_has_ports".to_string(), "info".to_string());
+        let accepted = baseline::Baseline::from_violations(&[violation(3, "old")]);
+        input.lint_config.baseline = accepted.counts();
+
+        // The accepted finding moved down the file and a second copy appeared.
+        let raw = vec![
+            violation(9, "old"),
+            violation(12, "old"),
+            violation(20, "new"),
+        ];
+        let kept: Vec<usize> = filter_violations(&input, raw, &mut Suppressions::default())
+            .into_iter()
+            .map(|v| v.line)
+            .collect();
+        assert_eq!(kept, vec![12, 20]);
+    }
+
+    #[test]
+    fn filter_counts_waived_and_baselined_violations() {
+        let violation = |rule: &str, line: usize| Violation {
+            rule: rule.to_string(),
+            severity: "warning".to_string(),
+            file: "a.vhd".to_string(),
+            line,
+            message: format!("{} at {}", rule, line),
+            ..Default::default()
+        };
+        let mut input = Input::default();
+        input.waivers = waivers::scan(
+            "signal a : bit;  --@waive rule=unused_signal\n\
+             signal b : bit;  --@waive rule=unused_signal expires=2020-01-01\n",
+            "a.vhd",
+        );
+        input.lint_config.today = "2026-01-01".to_string();
+        for rule in ["unused_signal", "missing_reset"] {
+            input
+                .lint_config
+                .rules
+                .insert(rule.to_string(), "warning".to_string());
+        }
+        let accepted = baseline::Baseline::from_violations(&[violation("missing_reset", 7)]);
+        input.lint_config.baseline = accepted.counts();
+
+        let raw = vec![
+            violation("unused_signal", 1),
+            violation("unused_signal", 2),
+            violation("missing_reset", 7),
+        ];
+        let mut suppressions = Suppressions::default();
+        let kept: Vec<usize> = filter_violations(&input, raw, &mut suppressions)
+            .into_iter()
+            .map(|v| v.line)
+            .collect();
+        assert_eq!(kept, vec![2]);
+        assert_eq!(
+            (
+                suppressions.total,
+                suppressions.waived,
+                suppressions.baselined
+            ),
+            (2, 1, 1)
+        );
+        assert_eq!(suppressions.by_rule["unused_signal"], 1);
+        assert_eq!(suppressions.by_file["a.vhd"], 2);
+    }
+
+    #[test]
+    fn filter_keeps_only_changed_lines() {
+        let violation = |file: &str, line: usize| Violation {
+            rule: "entity_has_ports".to_string(),
+            severity: "info".to_string(),
+            file: file.to_string(),
+            line,
+            message: "finding".to_string(),
+            ..Default::default()
+        };
+        let mut input = Input::default();
+        input
+            .lint_config
+            .rules
+            .insert("entity_has_ports".to_string(), "info".to_string());
+        let raw = vec![
+            violation("a.vhd", 3),
+            violation("a.vhd", 8),
+            violation("a.vhd", 0),
+            violation("b.vhd", 3),
+        ];
+        assert_eq!(
+            filter_violations(&input, raw.clone(), &mut Suppressions::default()).len(),
+            4
+        );
+
+        input.lint_config.changed_lines = Some(diff::changed_lines(
+            "--- a/a.vhd\n+++ b/a.vhd\n@@ -8 +8 @@\n-old\n+new\n",
+        ));
+        let kept: Vec<(String, usize)> =
+            filter_violations(&input, raw, &mut Suppressions::default())
+                .into_iter()
+                .map(|v| (v.file, v.line))
+                .collect();
+        assert_eq!(
+            kept,
+            vec![("a.vhd".to_string(), 8), ("a.vhd".to_string(), 0)]
+        );
+    }
+
+    #[test]
+    fn only_modules_restricts_evaluation() {
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "core".to_string(),
+            file: "a.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        input
+            .lint_config
+            .rules
+            .insert("entity_has_ports".to_string(), "warning".to_string());
+        input.lint_config.only_modules.push("cdc".to_string());
+        let result = evaluate(&input);
+        assert!(result.violations.is_empty());
+
+        input.lint_config.only_modules = vec!["core".to_string()];
+        let result = evaluate(&input);
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].rule, "entity_has_ports");
+    }
+
+    #[test]
+    fn fast_mode_runs_prioritized_rules_within_budget() {
+        let mut input = Input::default();
+        for (name, entity) in [("rtl", "missing_a"), ("sim", "missing_b")] {
+            input
+                .architectures
+                .push(crate::policy::input::Architecture {
+                    name: name.to_string(),
+                    entity_name: entity.to_string(),
+                    file: "a.vhd".to_string(),
+                    line: 2,
+                    ..Default::default()
+                });
+        }
+        input.entities.push(Entity {
+            name: "core".to_string(),
+            file: "b.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        input
+            .lint_config
+            .rules
+            .insert("entity_has_ports".to_string(), "warning".to_string());
+        for rule in ["architecture_has_entity", "undriven_signal"] {
+            input
+                .lint_config
+                .rules
+                .insert(rule.to_string(), "error".to_string());
+        }
+        input.lint_config.fast = true;
+        let result = evaluate(&input);
+        assert_eq!(result.violations.len(), 2);
+        assert!(result
+            .violations
+            .iter()
+            .all(|v| v.rule == "architecture_has_entity"));
+
+        input.lint_config.error_budget = 1;
+        input.signals.push(Signal {
+            name: "sig".to_string(),
+            in_entity: "rtl".to_string(),
+            ..Default::default()
+        });
+        input
+            .concurrent_assignments
+            .push(crate::policy::input::ConcurrentAssignment {
+                target: "out_s".to_string(),
+                read_signals: vec!["sig".to_string()],
+                in_arch: "rtl".to_string(),
+                ..Default::default()
+            });
+        // The budget is per module: the module that reaches it reports both
+        // of its errors, and the next module (with `undriven_signal`) is
+        // skipped.
+        let result = evaluate(&input);
+        assert_eq!(result.summary.errors, 2);
+        assert!(result
+            .violations
+            .iter()
+            .all(|v| v.rule == "architecture_has_entity"));
+
+        input.lint_config.error_budget = 0;
+        let result = evaluate(&input);
+        assert!(result
+            .violations
+            .iter()
+            .any(|v| v.rule == "undriven_signal"));
+    }
+
+    fn three_entity_input() -> Input {
+        let mut input = Input::default();
+        for name in ["alu", "fifo", "uart"] {
+            let file = format!("{}.vhd", name);
+            input.entities.push(Entity {
+                name: name.to_string(),
+                file: file.clone(),
+                line: 1,
+                ..Default::default()
+            });
+            input
+                .architectures
+                .push(crate::policy::input::Architecture {
+                    name: "rtl".to_string(),
+                    entity_name: name.to_string(),
+                    file: file.clone(),
+                    line: 5,
+                    ..Default::default()
+                });
+            for sig in ["a", "b", "y"] {
+                input.signals.push(Signal {
+                    name: sig.to_string(),
+                    file: file.clone(),
+                    in_entity: "rtl".to_string(),
+                    ..Default::default()
+                });
+            }
+            input.processes.push(crate::policy::input::Process {
+                is_combinational: true,
+                sensitivity_list: vec!["a".to_string()],
+                read_signals: vec!["a".to_string(), "b".to_string()],
+                assigned_signals: vec!["y".to_string()],
+                file,
+                line: 8,
+                in_arch: "rtl".to_string(),
+                ..Default::default()
+            });
+        }
+        input.lint_config.rules.insert(
+            "sensitivity_list_incomplete".to_string(),
+            "error".to_string(),
+        );
+        input
+    }
+
+    #[test]
+    fn sharded_evaluation_matches_unsharded() {
+        let mut input = three_entity_input();
+        let key = |v: &Violation| (v.rule.clone(), v.file.clone(), v.line, v.message.clone());
+        let mut expected: Vec<_> = evaluate(&input).violations.iter().map(key).collect();
+        expected.sort();
+        assert_eq!(
+            expected
+                .iter()
+                .filter(|(rule, ..)| rule == "sensitivity_list_incomplete")
+                .count(),
+            3
+        );
+
+        input.lint_config.shard_workers = 2;
+        let mut actual: Vec<_> = evaluate(&input).violations.iter().map(key).collect();
+        actual.sort();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn violations_are_sorted_and_fingerprinted_however_evaluated() {
+        let mut input = three_entity_input();
+        let unsharded = evaluate(&input).violations;
+        let key = |v: &Violation| (v.file.clone(), v.line, v.rule.clone(), v.message.clone());
+        assert!(unsharded.windows(2).all(|w| key(&w[0]) <= key(&w[1])));
+        let ids: HashSet<&str> = unsharded.iter().map(|v| v.fingerprint.as_str()).collect();
+        assert_eq!(ids.len(), unsharded.len());
+        assert!(!ids.contains(""));
+
+        input.lint_config.shard_workers = 2;
+        assert_eq!(evaluate(&input).violations, unsharded);
+
+        let mut shuffled = unsharded.clone();
+        shuffled.reverse();
+        canonicalize(&mut shuffled);
+        assert_eq!(shuffled, unsharded);
+    }
+
+    #[test]
+    fn parallel_modules_keep_sequential_order() {
+        let mut input = three_entity_input();
+        input.lint_config.module_workers = 1;
+        let sequential = evaluate(&input);
+        assert!(!sequential.violations.is_empty());
+        input.lint_config.module_workers = 4;
+        for _ in 0..4 {
+            let parallel = evaluate(&input);
+            assert_eq!(
+                serde_json::to_string(&parallel.violations).unwrap(),
+                serde_json::to_string(&sequential.violations).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn module_reads_name_input_tables() {
+        let wanted: HashSet<&str> = MODULES
+            .iter()
+            .filter_map(|module| key_tables(module))
+            .flatten()
+            .collect();
+        let tables = table_hashes(&Input::default(), &wanted);
+        for module in MODULES {
+            for table in key_tables(module).into_iter().flatten() {
+                assert!(
+                    tables.contains_key(table),
+                    "module '{}' reads unknown table '{}'",
+                    module.name,
+                    table
+                );
+            }
+        }
+    }
+
+    struct EveryTable;
+
+    impl Rule for EveryTable {
+        fn id(&self) -> &'static str {
+            "every_table"
+        }
+
+        fn category(&self) -> &'static str {
+            "test"
+        }
+
+        fn check(&self, _input: &Input) -> Vec<Violation> {
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn module_keys_hash_only_the_tables_read() {
+        let naming = MODULES
+            .iter()
+            .find(|module| module.name == "naming")
+            .unwrap();
+        let wanted: HashSet<&str> = key_tables(naming).unwrap().into_iter().collect();
+        let mut input = Input::default();
+        let before = table_hashes(&input, &wanted);
+        assert_eq!(before.len(), wanted.len());
+        assert!(!before.contains_key("cdc_crossings"));
+
+        input
+            .cdc_crossings
+            .push(crate::policy::input::CDCCrossing::default());
+        let after = table_hashes(&input, &wanted);
+        assert_eq!(module_key(naming, &before), module_key(naming, &after));
+        input.entities.push(Entity::default());
+        let after = table_hashes(&input, &wanted);
+        assert_ne!(module_key(naming, &before), module_key(naming, &after));
+
+        assert_eq!(key_tables(&EveryTable), None);
+        assert_eq!(module_key(&EveryTable, &after), None);
+    }
+
+    #[test]
+    fn module_cache_reuses_unchanged_tables() {
+        let mut input = Input::default();
+        input
+            .lint_config
+            .rules
+            .insert("cdc_unsync_single_bit".to_string(), "warning".to_string());
+        input.cdc_crossings.push(crate::policy::input::CDCCrossing {
+            signal: "sig".to_string(),
+            file: "a.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        let mut cache = ModuleCache::new();
+        let first = evaluate_cached(&input, &mut cache);
+        assert_eq!(cache.hits(), 0);
+        let misses = cache.misses();
+
+        input.cdc_crossings[0].line = 2;
+        let second = evaluate_cached(&input, &mut cache);
+        let reads_everything = rules()
+            .iter()
+            .filter(|rule| rule.reads().contains(&rule::ALL_TABLES))
+            .count();
+        assert_eq!(cache.misses(), misses + 1 + reads_everything);
+        assert_eq!(cache.hits(), rules().len() - 1 - reads_everything);
+        assert_eq!(first.violations.len(), second.violations.len());
+        assert!(second.violations.iter().any(|v| v.line == 2));
+        assert_eq!(second.violations.len(), evaluate(&input).violations.len());
+    }
+
+    #[test]
+    fn skip_modules_covers_optional_companion() {
+        let mut input = Input::default();
+        input.lint_config.skip_modules.push("signals".to_string());
+        assert!(!module_selected(&input, "signals"));
+        assert!(!module_selected(&input, "signals_optional"));
+        assert!(module_selected(&input, "core"));
+        assert!(is_known_module("signals_optional"));
+        assert!(!is_known_module("nonexistent"));
+    }
+    #[test]
+    fn summary_rolls_up_by_directory_and_library() {
+        let mut input = Input::default();
+        input.files.push(crate::policy::input::FileInfo {
+            path: "ip/uart/tx.vhd".to_string(),
+            library: "UART_LIB".to_string(),
+            ..Default::default()
+        });
+        let violation = |file: &str, severity: &str| Violation {
+            rule: "entity_has_ports".to_string(),
+            severity: severity.to_string(),
+            file: file.to_string(),
+            line: 1,
+            message: String::new(),
+            ..Default::default()
+        };
+        let summary = summarize(
+            &input,
+            &[
+                violation("ip/uart/tx.vhd", "error"),
+                violation("ip/uart/tx.vhd", "warning"),
+                violation("top.vhd", "info"),
+            ],
+        );
+        let uart = &summary.by_directory["ip/uart"];
+        assert_eq!(
+            (uart.total_violations, uart.errors, uart.warnings),
+            (2, 1, 1)
+        );
+        assert_eq!(summary.by_directory["."].info, 1);
+        assert_eq!(summary.by_library["uart_lib"].total_violations, 2);
+        assert_eq!(summary.by_library["work"].total_violations, 1);
+    }
 }
@@ -0,0 +1,705 @@
+use crate::policy::input::Input;
+use crate::policy::result::{Fix, FixEdit, Violation};
+use regex::Regex;
+use std::collections::BTreeMap;
+
+/// Unchanged lines shown around each change in a patch hunk.
+const CONTEXT_LINES: usize = 3;
+
+/// Rules with a mechanical fix: each adds a signal to, or drops one from, the
+/// sensitivity list of the process the violation points at.
+pub const FIXABLE_RULES: &[&str] = &[
+    "missing_clock_sensitivity",
+    "missing_reset_sensitivity",
+    "sensitivity_list_incomplete",
+    "sensitivity_list_superfluous",
+];
+
+pub fn is_fixable(rule: &str) -> bool {
+    FIXABLE_RULES.contains(&rule)
+}
+
+/// Signals to add to and drop from one process's sensitivity list.
+#[derive(Default)]
+struct ListChange {
+    add: Vec<String>,
+    drop: Vec<String>,
+}
+
+/// Replaces `original[start..end]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub start: usize,
+    pub end: usize,
+    pub replacement: String,
+}
+
+/// Non-overlapping edits to one file, in source order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileFix {
+    pub file: String,
+    pub original: String,
+    pub edits: Vec<TextEdit>,
+}
+
+impl FileFix {
+    pub fn fixed(&self) -> String {
+        apply(&self.original, 0, &self.edits)
+    }
+}
+
+/// Fixes for every fixable violation and every fix attached to a violation,
+/// grouped per file in path order. Overlapping edits are dropped after the
+/// first, as are attached fixes whose anchor text is gone. `source` returns a
+/// file's text; files it can't read are skipped.
+pub fn file_fixes(
+    input: &Input,
+    violations: &[Violation],
+    source: impl Fn(&str) -> Option<String>,
+) -> Vec<FileFix> {
+    let mut wanted: BTreeMap<(&str, usize), ListChange> = BTreeMap::new();
+    for v in violations.iter().filter(|v| is_fixable(&v.rule)) {
+        let Some(proc) = input
+            .processes
+            .iter()
+            .find(|p| p.file == v.file && p.line == v.line)
+        else {
+            continue;
+        };
+        let change = wanted.entry((&v.file, v.line)).or_default();
+        let (list, name) = match v.rule.as_str() {
+            "missing_clock_sensitivity" => (&mut change.add, Some(proc.clock_signal.clone())),
+            "missing_reset_sensitivity" => (&mut change.add, Some(proc.reset_signal.clone())),
+            "sensitivity_list_incomplete" => (&mut change.add, quoted_name(&v.message)),
+            _ => (&mut change.drop, quoted_name(&v.message)),
+        };
+        if let Some(name) = name.filter(|n| !n.is_empty()) {
+            if !list.iter().any(|n| n.eq_ignore_ascii_case(&name)) {
+                list.push(name);
+            }
+        }
+    }
+
+    // Sensitivity changes by process line, plus fixes attached to violations.
+    type FileChanges<'a> = (Vec<(usize, ListChange)>, Vec<&'a Fix>);
+    let mut by_file: BTreeMap<&str, FileChanges> = BTreeMap::new();
+    for ((file, line), change) in wanted {
+        by_file.entry(file).or_default().0.push((line, change));
+    }
+    for fix in violations.iter().flat_map(|v| &v.fixes) {
+        by_file.entry(&fix.file).or_default().1.push(fix);
+    }
+    let mut out = Vec::new();
+    for (file, (procs, attached)) in by_file {
+        let Some(original) = source(file) else {
+            continue;
+        };
+        let mut edits: Vec<TextEdit> = procs
+            .iter()
+            .filter_map(|(line, change)| sensitivity_edit(&original, *line, change))
+            .chain(attached.iter().filter_map(|fix| resolve(&original, fix)))
+            .collect();
+        edits.sort_by_key(|e| (e.start, e.end));
+        edits.dedup_by(|b, a| b.start < a.end || b.start == a.start);
+        if !edits.is_empty() {
+            out.push(FileFix {
+                file: file.to_string(),
+                original,
+                edits,
+            });
+        }
+    }
+    out
+}
+
+/// Unified diff (`git apply` / `patch -p1` compatible) of every fix.
+pub fn patch(fixes: &[FileFix]) -> String {
+    fixes.iter().map(unified_diff).collect()
+}
+
+/// First `'name'` in a violation message.
+fn quoted_name(message: &str) -> Option<String> {
+    let rest = message.split_once('\'')?.1;
+    let name = rest.split_once('\'')?.0;
+    Some(name.to_string())
+}
+
+/// Rewrites the sensitivity list of the process whose header starts on
+/// `line`, or adds one after the `process` keyword. Returns `None` when
+/// nothing changes or the list would end up empty.
+fn sensitivity_edit(text: &str, line: usize, change: &ListChange) -> Option<TextEdit> {
+    let (start, end, existing) = sensitivity_span(text, line)?;
+    let mut entries: Vec<&str> = existing
+        .iter()
+        .copied()
+        .filter(|e| !change.drop.iter().any(|d| d.eq_ignore_ascii_case(e)))
+        .collect();
+    for name in &change.add {
+        if !entries.iter().any(|e| e.eq_ignore_ascii_case(name)) {
+            entries.push(name);
+        }
+    }
+    if entries.is_empty() || entries == existing {
+        return None;
+    }
+    Some(list_edit(start, end, format!("({})", entries.join(", "))))
+}
+
+/// Byte range of the sensitivity list of the process whose header starts on
+/// `line`, with its entries; an empty range right after `process` when it has
+/// none.
+fn sensitivity_span(text: &str, line: usize) -> Option<(usize, usize, Vec<&str>)> {
+    let line_start = line_offset(text, line);
+    let keyword = Regex::new(r"(?i)\bprocess\b")
+        .unwrap()
+        .find(text.get(line_start..)?)?;
+    let after = line_start + keyword.end();
+    let rest = &text[after..];
+    let open = after + rest.len() - rest.trim_start().len();
+    if !text[open..].starts_with('(') {
+        return Some((after, after, Vec::new()));
+    }
+    let close = closing_paren(text, open)?;
+    let existing = text[open + 1..close]
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect();
+    Some((open, close + 1, existing))
+}
+
+/// Offset of the `)` matching the `(` at `open`.
+fn closing_paren(text: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    text[open..].char_indices().find_map(|(i, c)| {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => {}
+        }
+        (depth == 0).then_some(open + i)
+    })
+}
+
+/// Puts `list` over `start..end`, spaced off the `process` keyword when it is
+/// an insertion.
+fn list_edit(start: usize, end: usize, list: String) -> TextEdit {
+    TextEdit {
+        start,
+        end,
+        replacement: if start == end {
+            format!(" {}", list)
+        } else {
+            list
+        },
+    }
+}
+
+/// Byte offset where 1-based `line` starts.
+fn line_offset(text: &str, line: usize) -> usize {
+    text.split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum()
+}
+
+/// Turns an attached fix into a byte range of `text`, or `None` when its
+/// anchor is not where the fix expects it.
+fn resolve(text: &str, fix: &Fix) -> Option<TextEdit> {
+    let start = line_offset(text, fix.line);
+    let line = text.get(start..)?.split('\n').next()?;
+    match &fix.edit {
+        FixEdit::Replace { find, replacement } => {
+            let (from, to) = find_word(line, find)?;
+            Some(TextEdit {
+                start: start + from,
+                end: start + to,
+                replacement: replacement.clone(),
+            })
+        }
+        FixEdit::InsertBefore { anchor, text } => {
+            let (from, _) = find_word(line, anchor)?;
+            Some(TextEdit {
+                start: start + from,
+                end: start + from,
+                replacement: text.clone(),
+            })
+        }
+        FixEdit::SensitivityList { text: list } => {
+            let (start, end, _) = sensitivity_span(text, fix.line)?;
+            Some(list_edit(start, end, list.clone()))
+        }
+        FixEdit::PortOrder { ports } => port_order_edit(text, start, ports),
+        FixEdit::CaseAlternative { text: alternative } => {
+            let (end_line, indent) = case_end(text, start)?;
+            Some(TextEdit {
+                start: end_line,
+                end: end_line,
+                replacement: format!("{}  {}\n", indent, alternative),
+            })
+        }
+    }
+}
+
+/// Moves the declarations of the first port clause at or after `from` into
+/// `ports` order; each declaration slot keeps its surrounding whitespace.
+/// `None` when the clause holds comments, declares other ports than `ports`,
+/// or groups names (`a, b : in bit`) that `ports` splits up.
+fn port_order_edit(text: &str, from: usize, ports: &[String]) -> Option<TextEdit> {
+    let keyword = Regex::new(r"(?i)\bport\s*\(")
+        .unwrap()
+        .find(text.get(from..)?)?;
+    let open = from + keyword.end() - 1;
+    let close = closing_paren(text, open)?;
+    let clause = &text[open + 1..close];
+    if clause.contains("--") {
+        return None;
+    }
+    let slots: Vec<&str> = clause.split(';').collect();
+    let mut declarations = slots
+        .iter()
+        .map(|slot| {
+            let body = slot.trim();
+            let names: Vec<&str> = body
+                .split_once(':')?
+                .0
+                .split(',')
+                .map(|name| name.split_whitespace().last())
+                .collect::<Option<_>>()?;
+            Some((body, names))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let position = |name: &str| ports.iter().position(|p| p.eq_ignore_ascii_case(name));
+    declarations.sort_by_key(|(_, names)| position(names[0]).unwrap_or(usize::MAX));
+    let ordered: Vec<&str> = declarations
+        .iter()
+        .flat_map(|(_, names)| names.iter().copied())
+        .collect();
+    if ordered.len() != ports.len()
+        || !ordered
+            .iter()
+            .zip(ports)
+            .all(|(name, port)| name.eq_ignore_ascii_case(port))
+    {
+        return None;
+    }
+    let replacement = slots
+        .iter()
+        .zip(&declarations)
+        .map(|(slot, (body, _))| {
+            let lead = &slot[..slot.len() - slot.trim_start().len()];
+            let trail = &slot[slot.trim_end().len()..];
+            format!("{}{}{}", lead, body, trail)
+        })
+        .collect::<Vec<_>>()
+        .join(";");
+    (replacement != clause).then_some(TextEdit {
+        start: open + 1,
+        end: close,
+        replacement,
+    })
+}
+
+/// Byte range of the first case-insensitive whole-word `word` in the code part
+/// of `line`.
+fn find_word(line: &str, word: &str) -> Option<(usize, usize)> {
+    let code = line.split("--").next().unwrap_or(line);
+    let pattern = Regex::new(&format!(r"(?i)\b{}\b", regex::escape(word))).ok()?;
+    pattern.find(code).map(|m| (m.start(), m.end()))
+}
+
+/// Start and indentation of the line holding the `end case` that closes the
+/// case statement opening at `from`, skipping nested case statements.
+fn case_end(text: &str, from: usize) -> Option<(usize, &str)> {
+    let token = Regex::new(r"(?i)\bend\s+case\b|\bcase\b").unwrap();
+    let mut depth = 0;
+    let mut offset = from;
+    for line in text.get(from..)?.split_inclusive('\n') {
+        let code = line.split("--").next().unwrap_or(line);
+        for m in token.find_iter(code) {
+            if !m.as_str().eq_ignore_ascii_case("case") {
+                depth -= 1;
+                if depth == 0 {
+                    let indent = &line[..line.len() - line.trim_start().len()];
+                    return Some((offset, indent));
+                }
+                if depth < 0 {
+                    return None;
+                }
+            } else {
+                depth += 1;
+            }
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Applies `edits` (offsets into the whole file) to `text`, which starts at
+/// file offset `base`.
+fn apply(text: &str, base: usize, edits: &[TextEdit]) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut pos = 0;
+    for edit in edits {
+        out.push_str(&text[pos..edit.start - base]);
+        out.push_str(&edit.replacement);
+        pos = edit.end - base;
+    }
+    out.push_str(&text[pos..]);
+    out
+}
+
+/// Old line range `[first, last)` replaced by `new` lines.
+struct Change {
+    first: usize,
+    last: usize,
+    new: Vec<String>,
+}
+
+fn unified_diff(fix: &FileFix) -> String {
+    let text = &fix.original;
+    let mut starts = vec![0];
+    starts.extend(text.match_indices('\n').map(|(i, _)| i + 1));
+    if starts.last() == Some(&text.len()) {
+        starts.pop();
+    }
+    let lines: Vec<&str> = text.lines().collect();
+    let line_of = |offset: usize| starts.partition_point(|&s| s <= offset).saturating_sub(1);
+    // Old line range an edit touches; insertions touch the line they're on.
+    let span = |edit: &TextEdit| {
+        (
+            line_of(edit.start),
+            line_of(edit.end.max(edit.start + 1) - 1) + 1,
+        )
+    };
+
+    // Edits touching the same lines become one change.
+    let mut groups: Vec<(usize, usize, Vec<TextEdit>)> = Vec::new();
+    for edit in &fix.edits {
+        let (first, last) = span(edit);
+        match groups.last_mut() {
+            Some(group) if first < group.1 => {
+                group.1 = group.1.max(last);
+                group.2.push(edit.clone());
+            }
+            _ => groups.push((first, last, vec![edit.clone()])),
+        }
+    }
+    let changes: Vec<Change> = groups
+        .into_iter()
+        .map(|(first, last, edits)| {
+            let end = starts.get(last).copied().unwrap_or(text.len());
+            let region = apply(&text[starts[first]..end], starts[first], &edits);
+            Change {
+                first,
+                last,
+                new: region.lines().map(str::to_string).collect(),
+            }
+        })
+        .collect();
+    if changes.is_empty() {
+        return String::new();
+    }
+
+    let path = fix.file.trim_start_matches("./").trim_start_matches('/');
+    let mut out = format!("--- a/{}\n+++ b/{}\n", path, path);
+    let no_newline = !text.ends_with('\n');
+    let push = |out: &mut String, prefix: char, line: &str, at_eof: bool| {
+        out.push(prefix);
+        out.push_str(line);
+        out.push('\n');
+        if no_newline && at_eof {
+            out.push_str("\\ No newline at end of file\n");
+        }
+    };
+
+    let mut offset: isize = 0;
+    let mut i = 0;
+    while i < changes.len() {
+        let mut j = i;
+        while j + 1 < changes.len() && changes[j + 1].first - changes[j].last <= 2 * CONTEXT_LINES {
+            j += 1;
+        }
+        let old_start = changes[i].first.saturating_sub(CONTEXT_LINES);
+        let old_end = (changes[j].last + CONTEXT_LINES).min(lines.len());
+        let old_len = old_end - old_start;
+        let new_len = changes[i..=j]
+            .iter()
+            .fold(old_len, |len, c| len + c.new.len() - (c.last - c.first));
+        out.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            old_start + 1,
+            old_len,
+            old_start as isize + offset + 1,
+            new_len
+        ));
+        let mut line = old_start;
+        for change in &changes[i..=j] {
+            for (idx, text) in lines.iter().enumerate().take(change.first).skip(line) {
+                push(&mut out, ' ', text, idx + 1 == lines.len());
+            }
+            for (idx, text) in lines
+                .iter()
+                .enumerate()
+                .take(change.last)
+                .skip(change.first)
+            {
+                push(&mut out, '-', text, idx + 1 == lines.len());
+            }
+            let at_eof = change.last == lines.len();
+            for (k, text) in change.new.iter().enumerate() {
+                push(&mut out, '+', text, at_eof && k + 1 == change.new.len());
+            }
+            line = change.last;
+        }
+        for (idx, text) in lines.iter().enumerate().take(old_end).skip(line) {
+            push(&mut out, ' ', text, idx + 1 == lines.len());
+        }
+        offset += new_len as isize - old_len as isize;
+        i = j + 1;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::Process;
+
+    const SOURCE: &str = "architecture rtl of top is
+begin
+  comb : process (a, unused)
+  begin
+    y <= a and b;
+  end process;
+
+  seq : process
+  begin
+    if rising_edge(clk) then
+      q <= d;
+    end if;
+  end process;
+end architecture;
+";
+
+    fn violation(rule: &str, line: usize, message: &str) -> Violation {
+        Violation {
+            rule: rule.to_string(),
+            severity: "error".to_string(),
+            file: "rtl/top.vhd".to_string(),
+            line,
+            message: message.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn input() -> Input {
+        let mut input = Input::default();
+        for (label, line, clock) in [("comb", 3, ""), ("seq", 8, "clk")] {
+            input.processes.push(Process {
+                label: label.to_string(),
+                file: "rtl/top.vhd".to_string(),
+                line,
+                clock_signal: clock.to_string(),
+                ..Default::default()
+            });
+        }
+        input
+    }
+
+    #[test]
+    fn sensitivity_fixes_rewrite_lists() {
+        let violations = vec![
+            violation(
+                "sensitivity_list_incomplete",
+                3,
+                "Signal 'b' read in combinational process 'comb' but missing from sensitivity list",
+            ),
+            violation(
+                "sensitivity_list_superfluous",
+                3,
+                "Signal 'unused' in sensitivity list but never read in process 'comb'",
+            ),
+            violation("missing_clock_sensitivity", 8, "clock 'clk'"),
+            violation("unused_signal", 5, "Signal 'y' is never read"),
+        ];
+        let fixes = file_fixes(&input(), &violations, |_| Some(SOURCE.to_string()));
+        assert_eq!(fixes.len(), 1);
+        let fixed = fixes[0].fixed();
+        assert!(fixed.contains("comb : process (a, b)\n"));
+        assert!(fixed.contains("seq : process (clk)\n"));
+    }
+
+    #[test]
+    fn patch_has_context_and_merged_hunks() {
+        let violations = vec![
+            violation(
+                "sensitivity_list_incomplete",
+                3,
+                "Signal 'b' read in combinational process 'comb' but missing from sensitivity list",
+            ),
+            violation("missing_clock_sensitivity", 8, "clock 'clk'"),
+        ];
+        let fixes = file_fixes(&input(), &violations, |_| Some(SOURCE.to_string()));
+        let patch = patch(&fixes);
+        assert!(patch.starts_with("--- a/rtl/top.vhd\n+++ b/rtl/top.vhd\n@@ -1,11 +1,11 @@\n"));
+        assert!(patch.contains("-  comb : process (a, unused)\n+  comb : process (a, unused, b)\n"));
+        assert!(patch.contains("-  seq : process\n+  seq : process (clk)\n"));
+        assert_eq!(patch.matches("@@ ").count(), 1);
+        assert!(!patch.contains("end architecture"));
+    }
+
+    #[test]
+    fn unreadable_files_and_emptied_lists_are_skipped() {
+        let violations = vec![violation(
+            "sensitivity_list_superfluous",
+            3,
+            "Signal 'a' in sensitivity list but never read in process 'comb'",
+        )];
+        assert!(file_fixes(&input(), &violations, |_| None).is_empty());
+        let source = "comb : process (a)\n";
+        let mut input = input();
+        input.processes[0].line = 1;
+        let violations = vec![violation(
+            "sensitivity_list_superfluous",
+            1,
+            "Signal 'a' in sensitivity list but never read in process 'comb'",
+        )];
+        assert!(file_fixes(&input, &violations, |_| Some(source.to_string())).is_empty());
+    }
+
+    const ATTACHED_SOURCE: &str = "entity top is
+  port (q : BUFFER std_logic); -- buffer port
+end entity;
+architecture rtl of top is
+begin
+  for i in 0 to 3 generate
+  end generate;
+  comb : process (sel, mode)
+  begin
+    case sel is
+      when \"00\" =>
+        case mode is
+          when others => y <= '0';
+        end case;
+    end case;
+  end process;
+end architecture;
+";
+
+    fn fix(line: usize, edit: FixEdit) -> Fix {
+        Fix {
+            file: "rtl/top.vhd".to_string(),
+            line,
+            edit,
+        }
+    }
+
+    #[test]
+    fn attached_fixes_resolve_against_source() {
+        let mut v = violation(
+            "buffer_port",
+            2,
+            "Port 'q' uses deprecated 'buffer' direction",
+        );
+        v.fixes = vec![
+            fix(
+                2,
+                FixEdit::Replace {
+                    find: "buffer".to_string(),
+                    replacement: "out".to_string(),
+                },
+            ),
+            fix(
+                6,
+                FixEdit::InsertBefore {
+                    anchor: "for".to_string(),
+                    text: "gen_6: ".to_string(),
+                },
+            ),
+            fix(
+                10,
+                FixEdit::CaseAlternative {
+                    text: "when others => null;".to_string(),
+                },
+            ),
+            // Same insertion point as the label: conflicts, so it is dropped.
+            fix(
+                6,
+                FixEdit::InsertBefore {
+                    anchor: "for".to_string(),
+                    text: "gen_x: ".to_string(),
+                },
+            ),
+            // Anchor no longer on the line.
+            fix(
+                7,
+                FixEdit::Replace {
+                    find: "buffer".to_string(),
+                    replacement: "out".to_string(),
+                },
+            ),
+        ];
+        let fixes = file_fixes(&Input::default(), &[v], |_| {
+            Some(ATTACHED_SOURCE.to_string())
+        });
+        assert_eq!(fixes[0].edits.len(), 3);
+        let fixed = fixes[0].fixed();
+        assert!(fixed.contains("  port (q : out std_logic); -- buffer port\n"));
+        assert!(fixed.contains("  gen_6: for i in 0 to 3 generate\n"));
+        assert!(fixed.contains("        end case;\n      when others => null;\n    end case;\n"));
+    }
+
+    #[test]
+    fn port_order_fixes_move_whole_declarations() {
+        let source = "entity top is
+  generic (W : natural := 8);
+  port (
+    d_i, e_i : in  std_logic_vector(W - 1 downto 0);
+    clk      : in  std_logic;
+    q_o      : out std_logic
+  );
+end entity;
+";
+        let order = |ports: &[&str]| {
+            let mut v = violation("port_ordering", 5, "Port 'clk' (clock)");
+            v.fixes = vec![fix(
+                1,
+                FixEdit::PortOrder {
+                    ports: ports.iter().map(|p| p.to_string()).collect(),
+                },
+            )];
+            file_fixes(&Input::default(), &[v], |_| Some(source.to_string()))
+        };
+        let fixes = order(&["clk", "d_i", "e_i", "q_o"]);
+        assert!(fixes[0].fixed().contains(
+            "  port (
+    clk      : in  std_logic;
+    d_i, e_i : in  std_logic_vector(W - 1 downto 0);
+    q_o      : out std_logic
+  );
+"
+        ));
+        // Splitting `d_i, e_i` or naming a port the clause lacks is refused.
+        assert!(order(&["d_i", "clk", "e_i", "q_o"]).is_empty());
+        assert!(order(&["clk", "d_i", "e_i"]).is_empty());
+    }
+
+    #[test]
+    fn sensitivity_list_fixes_replace_or_add_the_list() {
+        let list = |line| {
+            fix(
+                line,
+                FixEdit::SensitivityList {
+                    text: "(a, b)".to_string(),
+                },
+            )
+        };
+        let mut v = violation("sensitivity_list_incomplete", 3, "");
+        v.fixes = vec![list(3), list(8)];
+        let fixes = file_fixes(&Input::default(), &[v], |_| Some(SOURCE.to_string()));
+        let fixed = fixes[0].fixed();
+        assert!(fixed.contains("  comb : process (a, b)\n"));
+        assert!(fixed.contains("  seq : process (a, b)\n"));
+    }
+}
@@ -1,5 +1,14 @@
-use crate::policy::input::Input;
-use crate::policy::result::Violation;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use crate::policy::helpers;
+use crate::policy::input::{AttributeSpec, CaseStatement, Input, Process, TypeDeclaration};
+use crate::policy::result::{Confidence, FsmGraph, FsmTransition, Violation};
+
+const DEFAULT_FSM_ENCODING_STATES: usize = 8;
+
+/// Attributes that pick a state encoding: IEEE 1076.6, Xilinx and
+/// Synplify/Intel.
+const ENCODING_ATTRIBUTES: &[&str] = &["enum_encoding", "fsm_encoding", "syn_encoding"];
 
 pub fn violations(_input: &Input) -> Vec<Violation> {
     Vec::new()
@@ -12,9 +21,256 @@ pub fn optional_violations(input: &Input) -> Vec<Violation> {
     out.extend(fsm_unreachable_state(input));
     out.extend(fsm_missing_default_state(input));
     out.extend(fsm_unhandled_state(input));
+    out.extend(fsm_dead_end_state(input));
+    out.extend(fsm_missing_transition(input));
+    out.extend(fsm_missing_encoding(input));
+    out.extend(fsm_state_count_boundary(input));
+    out.extend(hand_rolled_one_hot(input));
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "attribute_specs",
+        "case_statements",
+        "concurrent_assignments",
+        "constant_decls",
+        "lint_config",
+        "processes",
+        "signals",
+        "types",
+    ]
+}
+
+/// State machines with their transition graphs, for the result JSON.
+pub fn graphs(input: &Input) -> Vec<FsmGraph> {
+    models(input).into_iter().map(|model| model.graph).collect()
+}
+
+/// A state machine: an enumerated type, the signals of that type in one
+/// architecture and the case statements over them. Transitions are the
+/// literal assignments to those signals inside each case alternative;
+/// literal assignments outside them (reset, aborts) are entry states, as is
+/// the type's leftmost literal, every signal's default initial value.
+struct FsmModel<'a> {
+    graph: FsmGraph,
+    type_decl: &'a TypeDeclaration,
+    /// Line of the first alternative covering each state, by lowercase name.
+    arm_lines: HashMap<String, usize>,
+    /// States covered by an alternative of a case that makes transitions.
+    next_state_handled: HashSet<String>,
+    has_transition_case: bool,
+}
+
+fn models(input: &Input) -> Vec<FsmModel<'_>> {
+    let mut enum_types: HashMap<String, &TypeDeclaration> = HashMap::new();
+    for type_decl in &input.types {
+        if type_decl.kind == "enum" && type_decl.enum_literals.len() > 1 {
+            enum_types
+                .entry(type_decl.name.to_ascii_lowercase())
+                .or_insert(type_decl);
+        }
+    }
+
+    let mut groups: Vec<((String, String, String), Vec<String>)> = Vec::new();
+    for sig in &input.signals {
+        let type_name = helpers::base_type_name(&sig.r#type);
+        if !enum_types.contains_key(&type_name) {
+            continue;
+        }
+        let key = (
+            sig.file.clone(),
+            sig.in_entity.to_ascii_lowercase(),
+            type_name,
+        );
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, signals)) => signals.push(sig.name.clone()),
+            None => groups.push((key, vec![sig.name.clone()])),
+        }
+    }
+
+    groups
+        .into_iter()
+        .filter_map(|((file, arch, type_name), signals)| {
+            build_model(input, enum_types[&type_name], &file, &arch, signals)
+        })
+        .collect()
+}
+
+fn build_model<'a>(
+    input: &'a Input,
+    type_decl: &'a TypeDeclaration,
+    file: &str,
+    arch: &str,
+    signals: Vec<String>,
+) -> Option<FsmModel<'a>> {
+    let is_fsm_signal = |name: &str| signals.iter().any(|sig| sig.eq_ignore_ascii_case(name));
+    let literal = |value: &str| {
+        type_decl
+            .enum_literals
+            .iter()
+            .find(|lit| lit.eq_ignore_ascii_case(value.trim()))
+    };
+    let cases: Vec<&CaseStatement> = input
+        .case_statements
+        .iter()
+        .filter(|cs| cs.file == file && cs.in_arch.eq_ignore_ascii_case(arch))
+        .filter(|cs| is_fsm_signal(cs.expression.trim()))
+        .collect();
+    let first_case = cases.first()?;
+    let procs: Vec<&Process> = input
+        .processes
+        .iter()
+        .filter(|proc| proc.file == file && proc.in_arch.eq_ignore_ascii_case(arch))
+        .collect();
+
+    let mut model = FsmModel {
+        graph: FsmGraph {
+            type_name: type_decl.name.clone(),
+            signals: signals.clone(),
+            file: file.to_string(),
+            line: first_case.line,
+            in_arch: first_case.in_arch.clone(),
+            states: type_decl.enum_literals.clone(),
+            initial_states: vec![type_decl.enum_literals[0].clone()],
+            ..Default::default()
+        },
+        type_decl,
+        arm_lines: HashMap::new(),
+        next_state_handled: HashSet::new(),
+        has_transition_case: false,
+    };
+
+    let mut arm_branches = HashSet::new();
+    for cs in &cases {
+        let explicit: HashSet<String> = cs
+            .arms
+            .iter()
+            .flat_map(|arm| &arm.choices)
+            .map(|choice| choice.to_ascii_lowercase())
+            .collect();
+        let proc = case_process(&procs, cs);
+        let mut covered = Vec::new();
+        let mut makes_transitions = false;
+        for arm in &cs.arms {
+            arm_branches.insert(arm.branch.as_str());
+            let is_others = arm.choices.iter().any(|c| c.eq_ignore_ascii_case("others"));
+            let from: Vec<&String> = if is_others {
+                type_decl
+                    .enum_literals
+                    .iter()
+                    .filter(|lit| !explicit.contains(&lit.to_ascii_lowercase()))
+                    .collect()
+            } else {
+                arm.choices
+                    .iter()
+                    .filter_map(|choice| literal(choice))
+                    .collect()
+            };
+            for state in &from {
+                model
+                    .arm_lines
+                    .entry(state.to_ascii_lowercase())
+                    .or_insert(arm.line);
+            }
+            covered.extend(from.iter().map(|state| state.to_ascii_lowercase()));
+            let Some(proc) = proc else { continue };
+            for assign in &proc.assignments {
+                if !in_branch(&assign.branch, &arm.branch) || !is_fsm_signal(&assign.signal) {
+                    continue;
+                }
+                let Some(to) = literal(&assign.value) else {
+                    continue;
+                };
+                makes_transitions = true;
+                for state in &from {
+                    model.graph.transitions.push(FsmTransition {
+                        from: (*state).clone(),
+                        to: to.clone(),
+                        line: assign.line,
+                    });
+                }
+            }
+        }
+        if makes_transitions {
+            model.has_transition_case = true;
+            model.next_state_handled.extend(covered);
+        }
+    }
+
+    for proc in &procs {
+        for assign in &proc.assignments {
+            if !is_fsm_signal(&assign.signal) {
+                continue;
+            }
+            let inside_arm = assign
+                .branch
+                .split('/')
+                .any(|element| arm_branches.contains(element));
+            if inside_arm {
+                continue;
+            }
+            if let Some(state) = literal(&assign.value) {
+                if !model.graph.initial_states.contains(state) {
+                    model.graph.initial_states.push(state.clone());
+                }
+            }
+        }
+    }
+
+    let reachable = reachable_states(&model.graph);
+    for state in &model.graph.states {
+        let key = state.to_ascii_lowercase();
+        if !reachable.contains(&key) {
+            model.graph.unreachable_states.push(state.clone());
+            continue;
+        }
+        let leaves = model
+            .graph
+            .transitions
+            .iter()
+            .any(|t| t.from.eq_ignore_ascii_case(state) && !t.to.eq_ignore_ascii_case(state));
+        if model.arm_lines.contains_key(&key) && !leaves {
+            model.graph.dead_end_states.push(state.clone());
+        }
+    }
+    Some(model)
+}
+
+/// The process a case statement sits in: the last one of its label that
+/// starts before it.
+fn case_process<'a>(procs: &[&'a Process], cs: &CaseStatement) -> Option<&'a Process> {
+    procs
+        .iter()
+        .filter(|proc| proc.label.eq_ignore_ascii_case(&cs.in_process) && proc.line <= cs.line)
+        .max_by_key(|proc| proc.line)
+        .copied()
+}
+
+/// Whether an assignment `branch` path runs inside the alternative adding
+/// the `arm` path element.
+fn in_branch(branch: &str, arm: &str) -> bool {
+    !arm.is_empty() && branch.split('/').any(|element| element == arm)
+}
+
+fn reachable_states(graph: &FsmGraph) -> BTreeSet<String> {
+    let mut seen: BTreeSet<String> = graph
+        .initial_states
+        .iter()
+        .map(|state| state.to_ascii_lowercase())
+        .collect();
+    let mut queue: VecDeque<String> = seen.iter().cloned().collect();
+    while let Some(state) = queue.pop_front() {
+        for t in &graph.transitions {
+            let to = t.to.to_ascii_lowercase();
+            if t.from.eq_ignore_ascii_case(&state) && seen.insert(to.clone()) {
+                queue.push_back(to);
+            }
+        }
+    }
+    seen
+}
+
 fn state_signal_not_enum(input: &Input) -> Vec<Violation> {
     input
         .signals
@@ -30,6 +286,7 @@ fn state_signal_not_enum(input: &Input) -> Vec<Violation> {
                 "State signal '{}' uses vector type '{}' - consider using enumerated type for clarity",
                 sig.name, sig.r#type
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -47,6 +304,7 @@ fn single_state_signal(input: &Input) -> Vec<Violation> {
             line: sig.line,
             message: "Signal 'state' found without 'next_state' - consider two-process FSM style"
                 .to_string(),
+            ..Default::default()
         })
         .collect()
 }
@@ -66,6 +324,7 @@ fn fsm_missing_default_state(input: &Input) -> Vec<Violation> {
                 "FSM case statement on '{}' missing 'when others' - undefined behavior for invalid states",
                 cs.expression
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -100,6 +359,7 @@ fn fsm_unhandled_state(input: &Input) -> Vec<Violation> {
                             "FSM state '{}' from type '{}' not explicitly handled in case statement",
                             literal, type_decl.name
                         ),
+                        ..Default::default()
                     });
                 }
             }
@@ -108,12 +368,40 @@ fn fsm_unhandled_state(input: &Input) -> Vec<Violation> {
     out
 }
 
+/// States no transition path reaches from an entry state. Types without a
+/// modelled state machine fall back to checking that each literal is ever
+/// assigned to a state-named signal.
 fn fsm_unreachable_state(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
+    let models = models(input);
+    for model in &models {
+        for state in &model.graph.unreachable_states {
+            let (file, line) = model.state_location(state);
+            out.push(Violation {
+                rule: "fsm_unreachable_state".to_string(),
+                severity: "warning".to_string(),
+                file,
+                line,
+                message: format!(
+                    "FSM state '{}' of '{}' is unreachable from initial state(s) {}",
+                    state,
+                    model.graph.signals.join("/"),
+                    quoted(&model.graph.initial_states)
+                ),
+                ..Default::default()
+            });
+        }
+    }
     for type_decl in &input.types {
         if type_decl.kind != "enum" {
             continue;
         }
+        if models
+            .iter()
+            .any(|model| model.type_decl.name.eq_ignore_ascii_case(&type_decl.name))
+        {
+            continue;
+        }
         if !is_state_type_name(&type_decl.name) {
             continue;
         }
@@ -138,6 +426,7 @@ fn fsm_unreachable_state(input: &Input) -> Vec<Violation> {
                             "FSM state '{}' is never assigned to '{}' - potentially unreachable",
                             sig.name, literal
                         ),
+                        ..Default::default()
                     });
                 }
             }
@@ -146,6 +435,307 @@ fn fsm_unreachable_state(input: &Input) -> Vec<Violation> {
     out
 }
 
+/// Reachable states with a case alternative that never moves to another
+/// state: once entered, the machine stays there.
+fn fsm_dead_end_state(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for model in models(input) {
+        for state in &model.graph.dead_end_states {
+            let (file, line) = model.state_location(state);
+            out.push(Violation {
+                rule: "fsm_dead_end_state".to_string(),
+                severity: "warning".to_string(),
+                file,
+                line,
+                message: format!(
+                    "FSM state '{}' of '{}' has no transition to another state - the machine stays there once entered",
+                    state,
+                    model.graph.signals.join("/")
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+/// Transitions into a state no alternative of the transition case statement
+/// covers, so the next-state logic is undefined once it is entered.
+fn fsm_missing_transition(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for model in models(input) {
+        if !model.has_transition_case {
+            continue;
+        }
+        let mut reported = HashSet::new();
+        for t in &model.graph.transitions {
+            if model.graph.unreachable_states.contains(&t.from) {
+                continue;
+            }
+            let key = t.to.to_ascii_lowercase();
+            if model.next_state_handled.contains(&key) || !reported.insert(key) {
+                continue;
+            }
+            out.push(Violation {
+                rule: "fsm_missing_transition".to_string(),
+                severity: "warning".to_string(),
+                file: model.graph.file.clone(),
+                line: t.line,
+                message: format!(
+                    "Transition {} -> {} enters a state with no case alternative on '{}' - its next state is undefined",
+                    t.from,
+                    t.to,
+                    model.graph.signals.join("/")
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+/// Machines with more states than `lint_config.fsm_encoding_states` whose
+/// type and signals carry no encoding attribute: the synthesis tool's
+/// default differs between vendors and releases.
+fn fsm_missing_encoding(input: &Input) -> Vec<Violation> {
+    let limit = match input.lint_config.fsm_encoding_states {
+        0 => DEFAULT_FSM_ENCODING_STATES,
+        states => states,
+    };
+    let mut out = Vec::new();
+    for model in models(input) {
+        let states = model.graph.states.len();
+        if states <= limit || encoding(input, &model).is_some() {
+            continue;
+        }
+        out.push(Violation {
+            rule: "fsm_missing_encoding".to_string(),
+            severity: "warning".to_string(),
+            file: model.graph.file.clone(),
+            line: model.graph.line,
+            message: format!(
+                "FSM on '{}' has {} states (limit {}) but type '{}' has no encoding attribute - the encoding is left to the synthesis tool; add e.g. attribute fsm_encoding of {} : signal is \"one_hot\"",
+                model.graph.signals.join("/"),
+                states,
+                limit,
+                model.type_decl.name,
+                model.graph.signals[0]
+            ),
+            ..Default::default()
+        });
+    }
+    out
+}
+
+/// State types with one state past a power of two (5, 9, 17, ...): a binary
+/// or gray encoding spends a whole extra register bit on that last state.
+/// Machines encoded one-hot don't care.
+fn fsm_state_count_boundary(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    let mut seen = HashSet::new();
+    for model in models(input) {
+        let states = model.graph.states.len();
+        if states < 5 || !(states - 1).is_power_of_two() {
+            continue;
+        }
+        let one_hot = encoding(input, &model).is_some_and(|spec| {
+            let value = spec.value.to_ascii_lowercase();
+            value.contains("one") && value.contains("hot")
+        });
+        if one_hot || !seen.insert(model.type_decl.name.to_ascii_lowercase()) {
+            continue;
+        }
+        let (file, line) = if model.type_decl.file.is_empty() {
+            (model.graph.file.clone(), model.graph.line)
+        } else {
+            (model.type_decl.file.clone(), model.type_decl.line)
+        };
+        let bits = usize::BITS - (states - 1).leading_zeros();
+        out.push(Violation {
+            rule: "fsm_state_count_boundary".to_string(),
+            severity: "info".to_string(),
+            file,
+            line,
+            message: format!(
+                "FSM type '{}' has {} states, one past {} - a binary encoding needs {} state bits where {} states would fit in {}",
+                model.type_decl.name,
+                states,
+                states - 1,
+                bits,
+                states - 1,
+                bits - 1
+            ),
+            ..Default::default()
+        });
+    }
+    out
+}
+
+/// The encoding attribute naming a machine's type or one of its signals.
+fn encoding<'a>(input: &'a Input, model: &FsmModel) -> Option<&'a AttributeSpec> {
+    input.attribute_specs.iter().find(|spec| {
+        if !ENCODING_ATTRIBUTES
+            .iter()
+            .any(|name| spec.name.eq_ignore_ascii_case(name))
+        {
+            return false;
+        }
+        let names: Vec<&String> = match spec.class.as_str() {
+            "type" | "subtype" => vec![&model.type_decl.name],
+            "signal" => model.graph.signals.iter().collect(),
+            _ => return false,
+        };
+        spec.targets.iter().any(|target| {
+            target.eq_ignore_ascii_case("all")
+                || names.iter().any(|name| name.eq_ignore_ascii_case(target))
+        })
+    })
+}
+
+/// Vector signals only ever assigned one-hot literals (or all zeros), with
+/// at least three distinct codes among the assignments and the case choices
+/// over the signal: an enumerated type encoded one-hot says the same and
+/// keeps the state names.
+fn hand_rolled_one_hot(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for sig in &input.signals {
+        if !is_vector_type(&sig.r#type) {
+            continue;
+        }
+        let values: Vec<&str> = input
+            .processes
+            .iter()
+            .filter(|proc| proc.file == sig.file)
+            .flat_map(|proc| &proc.assignments)
+            .filter(|assign| assign.target.trim().eq_ignore_ascii_case(&sig.name))
+            .map(|assign| assign.value.as_str())
+            .chain(
+                input
+                    .concurrent_assignments
+                    .iter()
+                    .filter(|assign| {
+                        assign.file == sig.file
+                            && assign.target_full.trim().eq_ignore_ascii_case(&sig.name)
+                    })
+                    .map(|assign| assign.value.as_str()),
+            )
+            .collect();
+        if values.is_empty() {
+            continue;
+        }
+        let mut codes = BTreeSet::new();
+        let mut other = false;
+        for value in values {
+            match vector_literal(input, value) {
+                Some(bits) if is_one_hot(&bits) => {
+                    codes.insert(bits);
+                }
+                Some(bits) if bits.chars().all(|c| c == '0') => {}
+                _ => other = true,
+            }
+        }
+        if other {
+            continue;
+        }
+        let choices = input
+            .case_statements
+            .iter()
+            .filter(|cs| {
+                cs.file == sig.file && cs.expression.trim().eq_ignore_ascii_case(&sig.name)
+            })
+            .flat_map(|cs| &cs.choices)
+            .filter_map(|choice| vector_literal(input, choice))
+            .filter(|bits| is_one_hot(bits));
+        codes.extend(choices);
+        let width = codes.first().map_or(0, String::len);
+        if codes.len() < 3 || codes.iter().any(|code| code.len() != width) {
+            continue;
+        }
+        out.push(Violation {
+            rule: "hand_rolled_one_hot".to_string(),
+            severity: "info".to_string(),
+            file: sig.file.clone(),
+            line: sig.line,
+            message: format!(
+                "Signal '{}' ({}) only holds one-hot codes {} - an enumerated state type with attribute fsm_encoding \"one_hot\" keeps the encoding and names the states",
+                sig.name,
+                sig.r#type,
+                codes
+                    .iter()
+                    .rev()
+                    .map(|code| format!("\"{}\"", code))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            confidence: Confidence::Medium,
+            ..Default::default()
+        });
+    }
+    out
+}
+
+/// Bits of a vector literal: `"0100"`, `b"0100"`, `x"4"`, `(others => '0')`
+/// or a constant with such a value.
+fn vector_literal(input: &Input, value: &str) -> Option<String> {
+    let value = value.trim();
+    let lower = value.to_ascii_lowercase();
+    let compact: String = lower.split_whitespace().collect();
+    if compact == "(others=>'0')" {
+        return Some("0".to_string());
+    }
+    let (base, digits) = match lower.find('"') {
+        Some(0) => ("b", &lower[..]),
+        Some(1) => (&lower[..1], &lower[1..]),
+        _ => {
+            let constant = input
+                .constant_decls
+                .iter()
+                .find(|decl| decl.name.eq_ignore_ascii_case(value))?;
+            let resolved = constant.value.trim();
+            return (!resolved.eq_ignore_ascii_case(value))
+                .then(|| vector_literal(&Input::default(), resolved))
+                .flatten();
+        }
+    };
+    let digits = digits
+        .strip_prefix('"')?
+        .strip_suffix('"')?
+        .replace('_', "");
+    match base {
+        "b" if digits.chars().all(|c| c == '0' || c == '1') => Some(digits),
+        "x" => digits
+            .chars()
+            .map(|c| c.to_digit(16).map(|d| format!("{:04b}", d)))
+            .collect(),
+        _ => None,
+    }
+}
+
+fn is_one_hot(bits: &str) -> bool {
+    bits.len() >= 3 && bits.matches('1').count() == 1
+}
+
+impl FsmModel<'_> {
+    /// The first alternative covering `state`, else its type declaration.
+    fn state_location(&self, state: &str) -> (String, usize) {
+        match self.arm_lines.get(&state.to_ascii_lowercase()) {
+            Some(&line) => (self.graph.file.clone(), line),
+            None if !self.type_decl.file.is_empty() => {
+                (self.type_decl.file.clone(), self.type_decl.line)
+            }
+            None => (self.graph.file.clone(), self.graph.line),
+        }
+    }
+}
+
+fn quoted(names: &[String]) -> String {
+    names
+        .iter()
+        .map(|name| format!("'{}'", name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 fn is_state_signal_name(name: &str) -> bool {
     let lower = name.to_ascii_lowercase();
     lower == "state"
@@ -211,7 +801,10 @@ fn state_ever_assigned(input: &Input, sig_name: &str, state_literal: &str) -> bo
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::policy::input::{CaseStatement, Input, Process, Signal, TypeDeclaration};
+    use crate::policy::input::{
+        AttributeSpec, CaseArm, CaseStatement, ConstantDeclaration, Input, Process,
+        SequentialAssignment, Signal, TypeDeclaration,
+    };
 
     #[test]
     fn state_signal_not_enum_flags_vector() {
@@ -323,4 +916,246 @@ mod tests {
         let violations = fsm_unreachable_state(&input);
         assert!(violations.is_empty());
     }
+
+    fn arm(choice: &str, line: usize) -> CaseArm {
+        CaseArm {
+            choices: vec![choice.to_string()],
+            line,
+            branch: format!("case@{}", line * 100),
+        }
+    }
+
+    fn assign(value: &str, branch: &str, line: usize) -> SequentialAssignment {
+        SequentialAssignment {
+            target: "state".to_string(),
+            signal: "state".to_string(),
+            value: value.to_string(),
+            line,
+            branch: branch.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// IDLE -> RUN -> DONE (dead end) and RUN -> FLUSH (no alternative);
+    /// ORPHAN is only left, never entered. Reset enters IDLE.
+    fn graph_input() -> Input {
+        let mut input = Input::default();
+        input.types.push(TypeDeclaration {
+            name: "ctrl_t".to_string(),
+            kind: "enum".to_string(),
+            enum_literals: ["IDLE", "RUN", "DONE", "FLUSH", "ORPHAN"]
+                .map(String::from)
+                .to_vec(),
+            file: "a.vhd".to_string(),
+            line: 2,
+            ..Default::default()
+        });
+        input.signals.push(Signal {
+            name: "state".to_string(),
+            r#type: "ctrl_t".to_string(),
+            file: "a.vhd".to_string(),
+            line: 3,
+            in_entity: "rtl".to_string(),
+            ..Default::default()
+        });
+        input.case_statements.push(CaseStatement {
+            expression: "state".to_string(),
+            choices: ["IDLE", "RUN", "DONE", "ORPHAN"].map(String::from).to_vec(),
+            file: "a.vhd".to_string(),
+            line: 10,
+            in_process: "p_fsm".to_string(),
+            in_arch: "rtl".to_string(),
+            arms: vec![
+                arm("IDLE", 11),
+                arm("RUN", 13),
+                arm("DONE", 16),
+                arm("ORPHAN", 17),
+            ],
+            ..Default::default()
+        });
+        input.processes.push(Process {
+            label: "p_fsm".to_string(),
+            file: "a.vhd".to_string(),
+            line: 5,
+            in_arch: "rtl".to_string(),
+            assignments: vec![
+                assign("IDLE", "/if@50.0", 7),
+                assign("RUN", "/if@50.1/case@1100/if@1150.0", 12),
+                assign("DONE", "/if@50.1/case@1300", 14),
+                assign("FLUSH", "/if@50.1/case@1300/if@1400.0", 15),
+                assign("IDLE", "/if@50.1/case@1700", 18),
+            ],
+            ..Default::default()
+        });
+        input
+    }
+
+    #[test]
+    fn graphs_extract_transitions_and_analysis() {
+        let graphs = graphs(&graph_input());
+        assert_eq!(graphs.len(), 1);
+        let graph = &graphs[0];
+        assert_eq!(graph.type_name, "ctrl_t");
+        assert_eq!(graph.initial_states, ["IDLE"]);
+        let edges: Vec<(&str, &str, usize)> = graph
+            .transitions
+            .iter()
+            .map(|t| (t.from.as_str(), t.to.as_str(), t.line))
+            .collect();
+        assert_eq!(
+            edges,
+            [
+                ("IDLE", "RUN", 12),
+                ("RUN", "DONE", 14),
+                ("RUN", "FLUSH", 15),
+                ("ORPHAN", "IDLE", 18)
+            ]
+        );
+        assert_eq!(graph.unreachable_states, ["ORPHAN"]);
+        assert_eq!(graph.dead_end_states, ["DONE"]);
+    }
+
+    #[test]
+    fn fsm_graph_rules_report_precise_lines() {
+        let input = graph_input();
+        let unreachable = fsm_unreachable_state(&input);
+        assert_eq!(unreachable.len(), 1);
+        assert_eq!(unreachable[0].line, 17);
+        assert!(unreachable[0].message.contains("'ORPHAN'"));
+
+        let dead_ends = fsm_dead_end_state(&input);
+        assert_eq!(dead_ends.len(), 1);
+        assert_eq!(dead_ends[0].line, 16);
+
+        let missing = fsm_missing_transition(&input);
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].line, 15);
+        assert!(missing[0].message.contains("RUN -> FLUSH"));
+    }
+
+    #[test]
+    fn others_alternative_covers_remaining_states() {
+        let mut input = graph_input();
+        input.case_statements[0].arms.push(CaseArm {
+            choices: vec!["others".to_string()],
+            line: 19,
+            branch: "case@1900".to_string(),
+        });
+        input.processes[0]
+            .assignments
+            .push(assign("IDLE", "/if@50.1/case@1900", 20));
+        let graph = &graphs(&input)[0];
+        assert!(graph
+            .transitions
+            .iter()
+            .any(|t| t.from == "FLUSH" && t.to == "IDLE" && t.line == 20));
+        assert!(fsm_missing_transition(&input).is_empty());
+    }
+
+    #[test]
+    fn encoding_rules_follow_state_count_and_attributes() {
+        let mut input = Input::default();
+        input.types.push(TypeDeclaration {
+            name: "t_state".to_string(),
+            kind: "enum".to_string(),
+            enum_literals: (0..9).map(|idx| format!("S{}", idx)).collect(),
+            file: "ctrl.vhd".to_string(),
+            line: 3,
+            ..Default::default()
+        });
+        input.signals.push(Signal {
+            name: "state".to_string(),
+            r#type: "t_state".to_string(),
+            file: "ctrl.vhd".to_string(),
+            in_entity: "rtl".to_string(),
+            ..Default::default()
+        });
+        input.case_statements.push(CaseStatement {
+            expression: "state".to_string(),
+            file: "ctrl.vhd".to_string(),
+            line: 20,
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        });
+        let rules = |input: &Input| {
+            fsm_missing_encoding(input)
+                .into_iter()
+                .chain(fsm_state_count_boundary(input))
+                .map(|v| (v.rule, v.line, v.message))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            rules(&input),
+            [
+                (
+                    "fsm_missing_encoding".to_string(),
+                    20,
+                    "FSM on 'state' has 9 states (limit 8) but type 't_state' has no encoding attribute - the encoding is left to the synthesis tool; add e.g. attribute fsm_encoding of state : signal is \"one_hot\"".to_string()
+                ),
+                (
+                    "fsm_state_count_boundary".to_string(),
+                    3,
+                    "FSM type 't_state' has 9 states, one past 8 - a binary encoding needs 4 state bits where 8 states would fit in 3".to_string()
+                ),
+            ]
+        );
+
+        input.attribute_specs.push(AttributeSpec {
+            name: "FSM_ENCODING".to_string(),
+            targets: vec!["State".to_string()],
+            class: "signal".to_string(),
+            value: "\"one_hot\"".to_string(),
+            ..Default::default()
+        });
+        assert!(rules(&input).is_empty());
+        input.attribute_specs[0].value = "\"sequential\"".to_string();
+        assert_eq!(rules(&input).len(), 1);
+        input.attribute_specs.clear();
+        input.lint_config.fsm_encoding_states = 9;
+        assert_eq!(rules(&input)[0].0, "fsm_state_count_boundary");
+    }
+
+    #[test]
+    fn hand_rolled_one_hot_needs_only_one_hot_values() {
+        let mut input = Input::default();
+        input.signals.push(Signal {
+            name: "st".to_string(),
+            r#type: "std_logic_vector(3 downto 0)".to_string(),
+            file: "a.vhd".to_string(),
+            line: 7,
+            ..Default::default()
+        });
+        input.constant_decls.push(ConstantDeclaration {
+            name: "ST_DONE".to_string(),
+            value: "\"1000\"".to_string(),
+            ..Default::default()
+        });
+        let assign = |value: &str| SequentialAssignment {
+            target: "st".to_string(),
+            signal: "st".to_string(),
+            value: value.to_string(),
+            ..Default::default()
+        };
+        input.processes.push(Process {
+            file: "a.vhd".to_string(),
+            assignments: vec![
+                assign("(others => '0')"),
+                assign("\"0001\""),
+                assign("x\"2\""),
+                assign("ST_DONE"),
+            ],
+            ..Default::default()
+        });
+        let found = hand_rolled_one_hot(&input);
+        assert_eq!(found.len(), 1);
+        assert_eq!(
+            found[0].message,
+            "Signal 'st' (std_logic_vector(3 downto 0)) only holds one-hot codes \"1000\", \"0010\", \"0001\" - an enumerated state type with attribute fsm_encoding \"one_hot\" keeps the encoding and names the states"
+        );
+
+        input.processes[0]
+            .assignments
+            .push(assign("st(2 downto 0) & '0'"));
+        assert!(hand_rolled_one_hot(&input).is_empty());
+    }
 }
@@ -0,0 +1,945 @@
+use crate::policy::hierarchy::target_matches_entity;
+use crate::policy::input::{Architecture, Entity, GenericDecl, Input, Instance};
+use crate::policy::result::{Confidence, Violation};
+use regex::Regex;
+use std::collections::HashSet;
+
+const DEFAULT_GENERIC_PREFIX: &str = "G_";
+
+pub fn violations(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for inst in &input.instances {
+        for entity in input
+            .entities
+            .iter()
+            .filter(|entity| target_matches_entity(&inst.target, &entity.name))
+        {
+            let bindings = generic_bindings(inst);
+            out.extend(unknown_generic(inst, entity, &bindings));
+            out.extend(missing_generic_binding(input, inst, entity, &bindings));
+            out.extend(generic_type_mismatch(inst, entity, &bindings));
+        }
+    }
+    out.extend(unused_generic(input));
+    out.extend(component_generic_mismatch(input));
+    out
+}
+
+pub fn optional_violations(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    out.extend(generic_naming_convention(input));
+    out.extend(generic_vector_type(input));
+    out.extend(generic_never_overridden(input));
+    out
+}
+
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "architectures",
+        "arithmetic_ops",
+        "case_statements",
+        "comparisons",
+        "components",
+        "concurrent_assignments",
+        "constant_decls",
+        "entities",
+        "functions",
+        "generates",
+        "instances",
+        "name_uses",
+        "processes",
+        "signals",
+        "subtypes",
+        "types",
+    ]
+}
+
+/// Constant generics only; VHDL-2008 type/subprogram/package generics are skipped.
+fn constant_generics(entity: &Entity) -> impl Iterator<Item = &GenericDecl> {
+    entity
+        .generics
+        .iter()
+        .filter(|g| g.kind.is_empty() || g.kind.eq_ignore_ascii_case("constant"))
+}
+
+fn generic_prefix(input: &Input) -> &str {
+    if input.lint_config.generic_prefix.is_empty() {
+        DEFAULT_GENERIC_PREFIX
+    } else {
+        &input.lint_config.generic_prefix
+    }
+}
+
+fn generic_naming_convention(input: &Input) -> Vec<Violation> {
+    let prefix = generic_prefix(input);
+    let mut out = Vec::new();
+    for entity in &input.entities {
+        for generic in constant_generics(entity) {
+            if has_prefix_ignore_case(&generic.name, prefix) {
+                continue;
+            }
+            out.push(Violation {
+                rule: "generic_naming_convention".to_string(),
+                severity: "info".to_string(),
+                file: entity.file.clone(),
+                line: generic.line,
+                message: format!(
+                    "Generic '{}' of entity '{}' should use the '{}' prefix",
+                    generic.name, entity.name, prefix
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+fn generic_vector_type(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for entity in &input.entities {
+        for generic in constant_generics(entity) {
+            if !is_logic_vector_type(&generic.r#type) {
+                continue;
+            }
+            out.push(Violation {
+                rule: "generic_vector_type".to_string(),
+                severity: "info".to_string(),
+                file: entity.file.clone(),
+                line: generic.line,
+                message: format!(
+                    "Generic '{}' of entity '{}' has type '{}' - prefer integer/natural or boolean generics",
+                    generic.name, entity.name, generic.r#type
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+/// Boolean generics selecting if-generate branches are dead configurability
+/// when every instance of the entity leaves them at their default.
+fn generic_never_overridden(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for entity in &input.entities {
+        let instances: Vec<&Instance> = input
+            .instances
+            .iter()
+            .filter(|inst| target_matches_entity(&inst.target, &entity.name))
+            .collect();
+        if instances.is_empty() {
+            continue;
+        }
+        for generic in constant_generics(entity) {
+            if !generic.r#type.trim().eq_ignore_ascii_case("boolean") {
+                continue;
+            }
+            let Some(generate) = input.generates.iter().find(|gen| {
+                arch_of_entity(input, &gen.in_arch, &entity.name)
+                    && condition_mentions(&gen.condition, &generic.name)
+            }) else {
+                continue;
+            };
+            let position = entity.generics.iter().position(|g| g.name == generic.name);
+            if instances
+                .iter()
+                .any(|inst| instance_overrides(inst, &generic.name, position))
+            {
+                continue;
+            }
+            let default = if generic.default.is_empty() {
+                "its default"
+            } else {
+                generic.default.as_str()
+            };
+            out.push(Violation {
+                rule: "generic_never_overridden".to_string(),
+                severity: "info".to_string(),
+                file: entity.file.clone(),
+                line: generic.line,
+                message: format!(
+                    "Boolean generic '{}' of entity '{}' controls generate '{}' but none of its {} instance(s) override it (always {})",
+                    generic.name,
+                    entity.name,
+                    generate.label,
+                    instances.len(),
+                    default
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+/// Constant generics no architecture of their entity refers to: not in an
+/// expression, a declaration's type or width, a generate condition or range,
+/// nor passed on through a generic map. The port list and the other
+/// generics' defaults count as uses too. Entities without an architecture in
+/// the input are skipped, since their uses can't be seen.
+fn unused_generic(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for entity in &input.entities {
+        let archs: Vec<&Architecture> = input
+            .architectures
+            .iter()
+            .filter(|arch| arch.entity_name.eq_ignore_ascii_case(&entity.name))
+            .collect();
+        if archs.is_empty() {
+            continue;
+        }
+        let mut used = HashSet::new();
+        for port in &entity.ports {
+            add_words(&mut used, &port.r#type);
+            add_words(&mut used, &port.default);
+        }
+        for generic in &entity.generics {
+            add_words(&mut used, &generic.r#type);
+            add_words(&mut used, &generic.default);
+        }
+        for arch in &archs {
+            architecture_words(input, arch, &mut used);
+        }
+        for generic in constant_generics(entity) {
+            if used.contains(&generic.name.to_ascii_lowercase()) {
+                continue;
+            }
+            out.push(Violation {
+                rule: "unused_generic".to_string(),
+                severity: "warning".to_string(),
+                file: entity.file.clone(),
+                line: generic.line,
+                message: format!(
+                    "Generic '{}' of entity '{}' is never referenced in {}",
+                    generic.name,
+                    entity.name,
+                    archs
+                        .iter()
+                        .map(|arch| format!("architecture '{}'", arch.name))
+                        .collect::<Vec<_>>()
+                        .join(" or ")
+                ),
+                // Names inside statements the extractor doesn't record
+                // (report strings, attribute specifications) go unseen.
+                confidence: Confidence::Medium,
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+/// Lowercase identifiers in the facts of one architecture. Declarations
+/// without an architecture field (signals, types, subtypes) count for every
+/// architecture of their file.
+fn architecture_words(input: &Input, arch: &Architecture, words: &mut HashSet<String>) {
+    let same_file = |file: &str| file == arch.file;
+    let inside =
+        |file: &str, in_arch: &str| same_file(file) && in_arch.eq_ignore_ascii_case(&arch.name);
+    let scope = format!("arch:{}", arch.name.to_ascii_lowercase());
+    for use_ in input.name_uses.iter().filter(|use_| {
+        let use_scope = use_.scope.to_ascii_lowercase();
+        same_file(&use_.file) && (!use_scope.contains("arch:") || use_scope.contains(&scope))
+    }) {
+        add_words(words, &use_.name);
+    }
+    for sig in input.signals.iter().filter(|sig| same_file(&sig.file)) {
+        add_words(words, &sig.r#type);
+    }
+    for decl in input.types.iter().filter(|decl| same_file(&decl.file)) {
+        add_words(words, &decl.element_type);
+        add_words(words, &decl.index_range);
+        decl.fields
+            .iter()
+            .for_each(|field| add_words(words, &field.r#type));
+    }
+    for decl in input.subtypes.iter().filter(|decl| same_file(&decl.file)) {
+        add_words(words, &decl.base_type);
+        add_words(words, &decl.constraint);
+    }
+    for decl in input
+        .constant_decls
+        .iter()
+        .filter(|d| inside(&d.file, &d.in_arch))
+    {
+        add_words(words, &decl.r#type);
+        add_words(words, &decl.value);
+    }
+    for func in input
+        .functions
+        .iter()
+        .filter(|f| inside(&f.file, &f.in_arch))
+    {
+        add_words(words, &func.return_type);
+        func.parameters
+            .iter()
+            .for_each(|param| add_words(words, &param.r#type));
+        func.read_signals
+            .iter()
+            .for_each(|name| add_words(words, name));
+    }
+    for proc in input
+        .processes
+        .iter()
+        .filter(|p| inside(&p.file, &p.in_arch))
+    {
+        proc.read_signals
+            .iter()
+            .for_each(|name| add_words(words, name));
+        proc.function_calls
+            .iter()
+            .for_each(|call| add_words(words, &call.name));
+        proc.variables
+            .iter()
+            .for_each(|var| add_words(words, &var.r#type));
+        for assign in &proc.assignments {
+            add_words(words, &assign.target);
+            add_words(words, &assign.value);
+        }
+        for wait in &proc.wait_statements {
+            add_words(words, &wait.until_expr);
+            add_words(words, &wait.for_expr);
+        }
+    }
+    for assign in input
+        .concurrent_assignments
+        .iter()
+        .filter(|a| inside(&a.file, &a.in_arch))
+    {
+        assign
+            .read_signals
+            .iter()
+            .for_each(|name| add_words(words, name));
+        add_words(words, &assign.target_full);
+        add_words(words, &assign.value);
+    }
+    for gen in input
+        .generates
+        .iter()
+        .filter(|g| inside(&g.file, &g.in_arch))
+    {
+        add_words(words, &gen.condition);
+        add_words(words, &gen.range_low);
+        add_words(words, &gen.range_high);
+    }
+    for case in input
+        .case_statements
+        .iter()
+        .filter(|c| inside(&c.file, &c.in_arch))
+    {
+        add_words(words, &case.expression);
+        case.choices
+            .iter()
+            .for_each(|choice| add_words(words, choice));
+    }
+    for cmp in input
+        .comparisons
+        .iter()
+        .filter(|c| inside(&c.file, &c.in_arch))
+    {
+        add_words(words, &cmp.left_operand);
+        add_words(words, &cmp.right_operand);
+    }
+    for op in input
+        .arithmetic_ops
+        .iter()
+        .filter(|o| inside(&o.file, &o.in_arch))
+    {
+        op.operands
+            .iter()
+            .for_each(|operand| add_words(words, operand));
+    }
+    for inst in input
+        .instances
+        .iter()
+        .filter(|i| inside(&i.file, &i.in_arch))
+    {
+        inst.generic_map
+            .values()
+            .for_each(|actual| add_words(words, actual));
+        inst.port_map
+            .values()
+            .for_each(|actual| add_words(words, actual));
+        for assoc in &inst.associations {
+            add_words(words, &assoc.actual);
+            add_words(words, &assoc.actual_full);
+        }
+    }
+}
+
+fn add_words(words: &mut HashSet<String>, text: &str) {
+    words.extend(
+        text.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .filter(|word| !word.is_empty())
+            .map(str::to_ascii_lowercase),
+    );
+}
+
+/// Component declarations whose generics diverge from the entity of the
+/// same name: a generic the entity lacks, an entity generic without a
+/// default the component leaves out (default binding can't give it a
+/// value), or a different type.
+fn component_generic_mismatch(input: &Input) -> Vec<Violation> {
+    let normalize = |ty: &str| {
+        ty.split_whitespace()
+            .collect::<String>()
+            .to_ascii_lowercase()
+    };
+    let mut out = Vec::new();
+    for comp in input.components.iter().filter(|comp| !comp.is_instance) {
+        let Some(entity) = input
+            .entities
+            .iter()
+            .find(|entity| entity.name.eq_ignore_ascii_case(&comp.name))
+        else {
+            continue;
+        };
+        let mut push = |line: usize, detail: String| {
+            out.push(Violation {
+                rule: "component_generic_mismatch".to_string(),
+                severity: "warning".to_string(),
+                file: comp.file.clone(),
+                line,
+                message: format!(
+                    "Component '{}' does not match entity '{}': {}",
+                    comp.name, entity.name, detail
+                ),
+                ..Default::default()
+            });
+        };
+        for generic in &comp.generics {
+            let line = if generic.line > 0 {
+                generic.line
+            } else {
+                comp.line
+            };
+            match entity
+                .generics
+                .iter()
+                .find(|g| g.name.eq_ignore_ascii_case(&generic.name))
+            {
+                None => push(
+                    line,
+                    format!("generic '{}' is not declared by the entity", generic.name),
+                ),
+                Some(declared)
+                    if !generic.r#type.is_empty()
+                        && !declared.r#type.is_empty()
+                        && normalize(&generic.r#type) != normalize(&declared.r#type) =>
+                {
+                    push(
+                        line,
+                        format!(
+                            "generic '{}' has type '{}' but the entity declares '{}'",
+                            generic.name, generic.r#type, declared.r#type
+                        ),
+                    )
+                }
+                Some(_) => {}
+            }
+        }
+        for generic in constant_generics(entity).filter(|g| g.default.trim().is_empty()) {
+            if !comp
+                .generics
+                .iter()
+                .any(|g| g.name.eq_ignore_ascii_case(&generic.name))
+            {
+                push(
+                    comp.line,
+                    format!(
+                        "entity generic '{}' has no default and is missing from the component",
+                        generic.name
+                    ),
+                );
+            }
+        }
+    }
+    out
+}
+
+/// One element of an instance's generic map: the named formal (`None` when
+/// positional), its position and the actual text.
+struct GenericBinding {
+    formal: Option<String>,
+    position: usize,
+    actual: String,
+}
+
+/// Generic associations of `inst`, falling back to the formal/actual map
+/// when the extractor recorded no association elements.
+fn generic_bindings(inst: &Instance) -> Vec<GenericBinding> {
+    let assocs: Vec<GenericBinding> = inst
+        .associations
+        .iter()
+        .filter(|assoc| assoc.kind == "generic")
+        .map(|assoc| GenericBinding {
+            formal: (!assoc.is_positional).then(|| assoc.formal.clone()),
+            position: assoc.position_index,
+            actual: if assoc.actual_full.is_empty() {
+                assoc.actual.clone()
+            } else {
+                assoc.actual_full.clone()
+            },
+        })
+        .collect();
+    if !assocs.is_empty() {
+        return assocs;
+    }
+    inst.generic_map
+        .iter()
+        .map(|(formal, actual)| GenericBinding {
+            formal: Some(formal.clone()),
+            position: 0,
+            actual: actual.clone(),
+        })
+        .collect()
+}
+
+/// Generic of `entity` a binding associates with.
+fn bound_generic<'a>(entity: &'a Entity, binding: &GenericBinding) -> Option<&'a GenericDecl> {
+    match &binding.formal {
+        Some(formal) => entity
+            .generics
+            .iter()
+            .find(|g| g.name.eq_ignore_ascii_case(formal)),
+        None => entity.generics.get(binding.position),
+    }
+}
+
+fn unknown_generic(
+    inst: &Instance,
+    entity: &Entity,
+    bindings: &[GenericBinding],
+) -> Vec<Violation> {
+    bindings
+        .iter()
+        .filter_map(|binding| {
+            let formal = binding.formal.as_ref()?;
+            if bound_generic(entity, binding).is_some() {
+                return None;
+            }
+            Some(Violation {
+                rule: "unknown_generic".to_string(),
+                severity: "error".to_string(),
+                file: inst.file.clone(),
+                line: inst.line,
+                message: format!(
+                    "Instance '{}' maps generic '{}' but entity '{}' declares no such generic",
+                    inst.name, formal, entity.name
+                ),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+/// Constant generics without a default that the generic map leaves out. A
+/// default on the matching component declaration also counts, since the
+/// default binding takes it from there.
+fn missing_generic_binding(
+    input: &Input,
+    inst: &Instance,
+    entity: &Entity,
+    bindings: &[GenericBinding],
+) -> Vec<Violation> {
+    let component = inst.target.rsplit('.').next().unwrap_or(&inst.target);
+    let mut out = Vec::new();
+    for generic in constant_generics(entity) {
+        if !generic.default.trim().is_empty()
+            || bindings
+                .iter()
+                .any(|b| bound_generic(entity, b).is_some_and(|g| std::ptr::eq(g, generic)))
+        {
+            continue;
+        }
+        let component_default = input.components.iter().any(|comp| {
+            !comp.is_instance
+                && comp.name.eq_ignore_ascii_case(component)
+                && comp.generics.iter().any(|g| {
+                    g.name.eq_ignore_ascii_case(&generic.name) && !g.default.trim().is_empty()
+                })
+        });
+        if component_default {
+            continue;
+        }
+        out.push(Violation {
+            rule: "missing_generic_binding".to_string(),
+            severity: "error".to_string(),
+            file: inst.file.clone(),
+            line: inst.line,
+            message: format!(
+                "Instance '{}' does not map generic '{}' of entity '{}', which has no default",
+                inst.name, generic.name, entity.name
+            ),
+            ..Default::default()
+        });
+    }
+    out
+}
+
+/// Literal actuals whose kind can't match the generic's declared type, such
+/// as a string passed to an integer generic. Names and expressions aren't
+/// typed here and are skipped.
+fn generic_type_mismatch(
+    inst: &Instance,
+    entity: &Entity,
+    bindings: &[GenericBinding],
+) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for binding in bindings {
+        let Some(generic) = bound_generic(entity, binding) else {
+            continue;
+        };
+        let (Some(expected), Some(actual)) =
+            (type_class(&generic.r#type), literal_class(&binding.actual))
+        else {
+            continue;
+        };
+        if expected == actual {
+            continue;
+        }
+        out.push(Violation {
+            rule: "generic_type_mismatch".to_string(),
+            severity: "error".to_string(),
+            file: inst.file.clone(),
+            line: inst.line,
+            message: format!(
+                "Instance '{}' passes {} literal {} to {} generic '{}' of entity '{}'",
+                inst.name,
+                actual,
+                binding.actual.trim(),
+                expected,
+                generic.name,
+                entity.name
+            ),
+            ..Default::default()
+        });
+    }
+    out
+}
+
+/// Literal kinds the generic type check knows about.
+fn type_class(ty: &str) -> Option<&'static str> {
+    let lower = ty.trim().to_ascii_lowercase();
+    let base = lower
+        .split(|c: char| c == '(' || c.is_whitespace())
+        .next()
+        .unwrap_or("");
+    match base {
+        "integer" | "natural" | "positive" => Some("integer"),
+        "boolean" => Some("boolean"),
+        "string" => Some("string"),
+        "time" | "delay_length" => Some("time"),
+        "real" => Some("real"),
+        _ => None,
+    }
+}
+
+fn literal_class(actual: &str) -> Option<&'static str> {
+    let lower = actual.trim().to_ascii_lowercase();
+    if lower.starts_with('"') || Regex::new(r#"^[0-9]*[xbo]""#).unwrap().is_match(&lower) {
+        return Some("string");
+    }
+    if Regex::new(r"^'.'$").unwrap().is_match(&lower) {
+        return Some("character");
+    }
+    if lower == "true" || lower == "false" {
+        return Some("boolean");
+    }
+    if Regex::new(r"^[+-]?[0-9][0-9_]*$").unwrap().is_match(&lower) {
+        return Some("integer");
+    }
+    if Regex::new(r"^[+-]?[0-9][0-9_]*\.[0-9][0-9_]*(e[+-]?[0-9]+)?$")
+        .unwrap()
+        .is_match(&lower)
+    {
+        return Some("real");
+    }
+    if Regex::new(r"^[0-9][0-9_.]*\s*(fs|ps|ns|us|ms|sec|min|hr)$")
+        .unwrap()
+        .is_match(&lower)
+    {
+        return Some("time");
+    }
+    None
+}
+
+fn has_prefix_ignore_case(name: &str, prefix: &str) -> bool {
+    name.len() >= prefix.len()
+        && name.is_char_boundary(prefix.len())
+        && name[..prefix.len()].eq_ignore_ascii_case(prefix)
+}
+
+fn is_logic_vector_type(t: &str) -> bool {
+    let lower = t.trim().to_ascii_lowercase();
+    let base = lower.split('(').next().unwrap_or("").trim();
+    matches!(
+        base,
+        "std_logic_vector" | "std_ulogic_vector" | "bit_vector" | "unsigned" | "signed"
+    )
+}
+
+fn arch_of_entity(input: &Input, arch_name: &str, entity_name: &str) -> bool {
+    input.architectures.iter().any(|arch| {
+        arch.name.eq_ignore_ascii_case(arch_name)
+            && arch.entity_name.eq_ignore_ascii_case(entity_name)
+    })
+}
+
+fn condition_mentions(condition: &str, name: &str) -> bool {
+    condition
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .any(|token| token.eq_ignore_ascii_case(name))
+}
+
+fn instance_overrides(inst: &Instance, generic: &str, position: Option<usize>) -> bool {
+    inst.generic_map
+        .keys()
+        .any(|formal| formal.eq_ignore_ascii_case(generic))
+        || inst.associations.iter().any(|assoc| {
+            assoc.kind == "generic"
+                && if assoc.is_positional {
+                    position.is_some_and(|pos| assoc.position_index == pos)
+                } else {
+                    assoc.formal.eq_ignore_ascii_case(generic)
+                }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{Component, ConcurrentAssignment, GenerateStatement, Port, Signal};
+    use std::collections::HashMap;
+
+    fn generic(name: &str, ty: &str, default: &str) -> GenericDecl {
+        GenericDecl {
+            name: name.to_string(),
+            kind: "constant".to_string(),
+            r#type: ty.to_string(),
+            default: default.to_string(),
+            line: 3,
+            ..Default::default()
+        }
+    }
+
+    fn input_with_generics(generics: Vec<GenericDecl>) -> Input {
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "core".to_string(),
+            file: "core.vhd".to_string(),
+            line: 1,
+            generics,
+            ..Default::default()
+        });
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "core".to_string(),
+            file: "core.vhd".to_string(),
+            line: 10,
+            ..Default::default()
+        });
+        input.generates.push(GenerateStatement {
+            label: "gen_fifo".to_string(),
+            kind: "if".to_string(),
+            condition: "G_USE_FIFO = true".to_string(),
+            file: "core.vhd".to_string(),
+            in_arch: "rtl".to_string(),
+            line: 12,
+            ..Default::default()
+        });
+        input
+    }
+
+    fn instance(generic_map: &[(&str, &str)]) -> Instance {
+        Instance {
+            name: "u_core".to_string(),
+            target: "work.core".to_string(),
+            generic_map: generic_map
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<HashMap<_, _>>(),
+            file: "top.vhd".to_string(),
+            line: 20,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn generic_maps_are_checked_against_entity_generics() {
+        let mut input = input_with_generics(vec![
+            generic("G_WIDTH", "integer", ""),
+            generic("G_NAME", "string", "\"core\""),
+            generic("G_DELAY", "time", "1 ns"),
+        ]);
+        input
+            .instances
+            .push(instance(&[("G_WIDTH", "\"8\""), ("G_DEPTH", "4")]));
+        input.instances.push(instance(&[("g_delay", "2 ns")]));
+        input.instances.push(instance(&[("G_WIDTH", "WIDTH + 1")]));
+
+        let mut v: Vec<_> = violations(&input)
+            .into_iter()
+            .map(|v| (v.rule, v.message))
+            .collect();
+        v.sort();
+        assert_eq!(
+            v,
+            [
+                (
+                    "generic_type_mismatch".to_string(),
+                    "Instance 'u_core' passes string literal \"8\" to integer generic 'G_WIDTH' of entity 'core'".to_string()
+                ),
+                (
+                    "missing_generic_binding".to_string(),
+                    "Instance 'u_core' does not map generic 'G_WIDTH' of entity 'core', which has no default".to_string()
+                ),
+                (
+                    "unknown_generic".to_string(),
+                    "Instance 'u_core' maps generic 'G_DEPTH' but entity 'core' declares no such generic".to_string()
+                ),
+                (
+                    "unused_generic".to_string(),
+                    "Generic 'G_DELAY' of entity 'core' is never referenced in architecture 'rtl'".to_string()
+                ),
+                (
+                    "unused_generic".to_string(),
+                    "Generic 'G_NAME' of entity 'core' is never referenced in architecture 'rtl'".to_string()
+                ),
+                (
+                    "unused_generic".to_string(),
+                    "Generic 'G_WIDTH' of entity 'core' is never referenced in architecture 'rtl'".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn generic_naming_convention_uses_configured_prefix() {
+        let mut input = input_with_generics(vec![
+            generic("WIDTH", "integer", "8"),
+            generic("g_depth", "natural", "4"),
+        ]);
+        let v = generic_naming_convention(&input);
+        assert_eq!(v.len(), 1);
+        assert!(v[0].message.contains("'WIDTH'"));
+
+        input.lint_config.generic_prefix = "C_".to_string();
+        assert_eq!(generic_naming_convention(&input).len(), 2);
+    }
+
+    #[test]
+    fn generic_vector_type_flags_logic_vectors() {
+        let input = input_with_generics(vec![
+            generic("G_MASK", "std_logic_vector(7 downto 0)", "x\"FF\""),
+            generic("G_WIDTH", "integer", "8"),
+        ]);
+        let v = generic_vector_type(&input);
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[0].rule, "generic_vector_type");
+        assert!(v[0].message.contains("G_MASK"));
+    }
+
+    #[test]
+    fn generic_never_overridden_flags_dead_generate_switch() {
+        let mut input = input_with_generics(vec![generic("G_USE_FIFO", "boolean", "false")]);
+        input.instances.push(instance(&[]));
+        let v = generic_never_overridden(&input);
+        assert_eq!(v.len(), 1);
+        assert!(v[0].message.contains("gen_fifo"));
+        assert!(v[0].message.contains("always false"));
+    }
+
+    #[test]
+    fn generic_never_overridden_accepts_override_or_no_instances() {
+        let input = input_with_generics(vec![generic("G_USE_FIFO", "boolean", "false")]);
+        assert!(generic_never_overridden(&input).is_empty());
+
+        let mut input = input_with_generics(vec![generic("G_USE_FIFO", "boolean", "false")]);
+        input.instances.push(instance(&[("g_use_fifo", "true")]));
+        assert!(generic_never_overridden(&input).is_empty());
+    }
+
+    #[test]
+    fn unused_generic_counts_ports_declarations_and_pass_through() {
+        let mut input = input_with_generics(vec![
+            generic("G_USE_FIFO", "boolean", "false"),
+            generic("G_WIDTH", "natural", "8"),
+            generic("G_DEPTH", "natural", "16"),
+            generic("G_INIT", "natural", "0"),
+            generic("G_SPARE", "natural", "0"),
+        ]);
+        input.entities[0].ports.push(Port {
+            name: "d".to_string(),
+            r#type: "std_logic_vector(G_WIDTH - 1 downto 0)".to_string(),
+            ..Default::default()
+        });
+        input.signals.push(Signal {
+            name: "count".to_string(),
+            r#type: "unsigned(clog2(g_depth) downto 0)".to_string(),
+            file: "core.vhd".to_string(),
+            ..Default::default()
+        });
+        let mut inst = instance(&[("G_RESET", "G_INIT")]);
+        inst.file = "core.vhd".to_string();
+        inst.in_arch = "rtl".to_string();
+        input.instances.push(inst);
+        // Same name in another entity's architecture doesn't count.
+        input.concurrent_assignments.push(ConcurrentAssignment {
+            target: "y".to_string(),
+            value: "G_SPARE".to_string(),
+            file: "core.vhd".to_string(),
+            in_arch: "other_rtl".to_string(),
+            ..Default::default()
+        });
+
+        let v = unused_generic(&input);
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[0].line, 3);
+        assert_eq!(
+            v[0].message,
+            "Generic 'G_SPARE' of entity 'core' is never referenced in architecture 'rtl'"
+        );
+        assert_eq!(v[0].confidence, Confidence::Medium);
+
+        input.architectures.clear();
+        assert!(unused_generic(&input).is_empty());
+    }
+
+    #[test]
+    fn component_generics_are_compared_with_the_entity() {
+        let mut input = input_with_generics(vec![
+            generic("G_WIDTH", "natural", ""),
+            generic("G_DEPTH", "natural", "16"),
+            generic("G_MODE", "integer range 0 to 3", "0"),
+        ]);
+        let component = |generics: Vec<GenericDecl>| Component {
+            name: "CORE".to_string(),
+            file: "top.vhd".to_string(),
+            line: 5,
+            generics,
+            ..Default::default()
+        };
+        input.components.push(component(vec![
+            generic("G_DEPTH", "positive", "16"),
+            generic("G_MODE", "INTEGER  range 0 to 3", "0"),
+            generic("G_NAME", "string", "\"\""),
+        ]));
+        input
+            .components
+            .push(component(vec![generic("g_width", "natural", "")]));
+
+        let messages: Vec<String> = component_generic_mismatch(&input)
+            .into_iter()
+            .map(|v| v.message)
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "Component 'CORE' does not match entity 'core': generic 'G_DEPTH' has type 'positive' but the entity declares 'natural'",
+                "Component 'CORE' does not match entity 'core': generic 'G_NAME' is not declared by the entity",
+                "Component 'CORE' does not match entity 'core': entity generic 'G_WIDTH' has no default and is missing from the component",
+            ]
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use crate::policy::baseline;
+use crate::policy::result::{Result, Violation};
+use serde::Serialize;
+
+/// One entry of a GitLab Code Quality report (the Code Climate issue subset
+/// GitLab reads for its merge request widget).
+#[derive(Debug, Serialize)]
+pub struct Issue {
+    pub description: String,
+    pub check_name: String,
+    pub fingerprint: String,
+    pub severity: &'static str,
+    pub location: Location,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Location {
+    pub path: String,
+    pub lines: Lines,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Lines {
+    pub begin: usize,
+}
+
+/// Code Quality issues in violation order. Fingerprints are
+/// `baseline::occurrence_fingerprints`, the ids the engine gives violations,
+/// so they survive unrelated edits that move a finding; the occurrence
+/// suffix matters here because GitLab collapses issues that share a
+/// fingerprint.
+pub fn code_quality(result: &Result) -> Vec<Issue> {
+    let fingerprints = baseline::occurrence_fingerprints(&result.violations);
+    result
+        .violations
+        .iter()
+        .zip(fingerprints)
+        .map(|(v, fingerprint)| issue(v, fingerprint))
+        .collect()
+}
+
+pub fn code_quality_json(result: &Result) -> String {
+    serde_json::to_string_pretty(&code_quality(result)).unwrap_or_default()
+}
+
+fn issue(v: &Violation, fingerprint: String) -> Issue {
+    Issue {
+        description: v.message.clone(),
+        check_name: v.rule.clone(),
+        fingerprint,
+        severity: severity(&v.severity),
+        location: Location {
+            path: v.file.trim_start_matches("./").replace('\\', "/"),
+            // GitLab needs a 1-based line; findings without one pin to the top.
+            lines: Lines {
+                begin: v.line.max(1),
+            },
+        },
+    }
+}
+
+fn severity(severity: &str) -> &'static str {
+    match severity {
+        "error" => "major",
+        "warning" => "minor",
+        _ => "info",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn violation(rule: &str, severity: &str, file: &str, line: usize) -> Violation {
+        Violation {
+            rule: rule.to_string(),
+            severity: severity.to_string(),
+            file: file.to_string(),
+            line,
+            message: format!("{} finding", rule),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn maps_violations_to_code_quality_issues() {
+        let result = Result {
+            violations: vec![
+                violation("missing_reset", "error", "./rtl/a.vhd", 4),
+                violation("unused_signal", "warning", "rtl\\b.vhd", 0),
+                violation("magic_number", "info", "rtl/b.vhd", 7),
+            ],
+            ..Default::default()
+        };
+        let json: serde_json::Value = serde_json::from_str(&code_quality_json(&result)).unwrap();
+        let issues = json.as_array().unwrap();
+        assert_eq!(issues.len(), 3);
+        assert_eq!(issues[0]["check_name"], "missing_reset");
+        assert_eq!(issues[0]["description"], "missing_reset finding");
+        assert_eq!(issues[0]["severity"], "major");
+        assert_eq!(issues[0]["location"]["path"], "rtl/a.vhd");
+        assert_eq!(issues[0]["location"]["lines"]["begin"], 4);
+        assert_eq!(issues[1]["severity"], "minor");
+        assert_eq!(issues[1]["location"]["path"], "rtl/b.vhd");
+        assert_eq!(issues[1]["location"]["lines"]["begin"], 1);
+        assert_eq!(issues[2]["severity"], "info");
+    }
+
+    #[test]
+    fn repeated_findings_get_distinct_fingerprints() {
+        let result = Result {
+            violations: vec![
+                violation("magic_number", "info", "top.vhd", 3),
+                violation("magic_number", "info", "top.vhd", 9),
+                violation("magic_number", "info", "other.vhd", 3),
+            ],
+            ..Default::default()
+        };
+        let issues = code_quality(&result);
+        let base = baseline::fingerprint(&result.violations[0]);
+        assert_eq!(issues[0].fingerprint, base);
+        assert_eq!(issues[1].fingerprint, format!("{}-2", base));
+        assert_ne!(issues[2].fingerprint, base);
+        assert!(!issues[2].fingerprint.ends_with("-2"));
+    }
+}
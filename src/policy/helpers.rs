@@ -1,6 +1,27 @@
 use regex::Regex;
 
-use crate::policy::input::{ConcurrentAssignment, Input, Process};
+use crate::policy::elaboration;
+use crate::policy::input::{ClockDecl, Input, Process};
+use crate::policy::registry;
+
+/// Input tables read by these shared helpers; any module may depend on them.
+pub const HELPER_READS: &[&str] = &[
+    "architectures",
+    "constant_decls",
+    "constants",
+    "dependencies",
+    "entities",
+    "enum_literals",
+    "file_count",
+    "files",
+    "generates",
+    "packages",
+    "lint_config",
+    "ports",
+    "shared_variables",
+    "signals",
+    "types",
+];
 
 pub fn is_testbench_name(name: &str) -> bool {
     let lower = name.to_ascii_lowercase();
@@ -22,6 +43,34 @@ pub fn is_clock_name(name: &str) -> bool {
         || lower.ends_with("_clock")
 }
 
+/// The `lint_config.clocks` declaration of clock `name`.
+pub fn declared_clock<'a>(input: &'a Input, name: &str) -> Option<&'a ClockDecl> {
+    input
+        .lint_config
+        .clocks
+        .iter()
+        .find(|clock| clock.name.eq_ignore_ascii_case(name))
+}
+
+/// Whether `name` is a clock: a declared one when the project declares its
+/// clocks, else by naming convention.
+pub fn is_clock(input: &Input, name: &str) -> bool {
+    if input.lint_config.clocks.is_empty() {
+        is_clock_name(name)
+    } else {
+        declared_clock(input, name).is_some()
+    }
+}
+
+/// Lowercase domain of clock `name`: its declared domain, else the clock
+/// itself.
+pub fn clock_domain(input: &Input, name: &str) -> String {
+    match declared_clock(input, name) {
+        Some(clock) if !clock.domain.is_empty() => clock.domain.to_ascii_lowercase(),
+        _ => name.to_ascii_lowercase(),
+    }
+}
+
 pub fn is_reset_name(name: &str) -> bool {
     let lower = name.to_ascii_lowercase();
     if lower.contains("reset") {
@@ -64,6 +113,67 @@ pub fn is_unsigned_type(t: &str) -> bool {
     lower.contains("unsigned")
 }
 
+/// Element count of a literal whose width is fixed by its text: bit-string
+/// literals (`"0101"`, `x"FF"`, VHDL-2008 `8x"F"`) and single-range aggregates
+/// (`(3 downto 0 => '1')`). None for anything that adapts to its target,
+/// such as `(others => '0')`.
+pub fn literal_width(expr: &str) -> Option<usize> {
+    let expr = expr.trim();
+    let bit_string = Regex::new(r#"^([0-9]+)?([uUsS]?[bBoOxX])?"([0-9A-Za-z_-]*)"$"#).unwrap();
+    if let Some(caps) = bit_string.captures(expr) {
+        if let Some(size) = caps.get(1) {
+            return size.as_str().parse().ok();
+        }
+        let digits = caps[3].chars().filter(|c| *c != '_').count();
+        let base = caps
+            .get(2)
+            .and_then(|b| b.as_str().chars().last())
+            .map(|c| c.to_ascii_lowercase());
+        return match base {
+            None => caps[3]
+                .chars()
+                .all(|c| "01uxzwlh-".contains(c.to_ascii_lowercase()))
+                .then_some(digits),
+            Some('b') => Some(digits),
+            Some('o') => Some(digits * 3),
+            Some('x') => Some(digits * 4),
+            _ => None,
+        };
+    }
+    let aggregate =
+        Regex::new(r"(?i)^\(\s*([0-9]+)\s+(downto|to)\s+([0-9]+)\s*=>[^,]*\)$").unwrap();
+    let caps = aggregate.captures(expr)?;
+    let left: usize = caps[1].parse().ok()?;
+    let right: usize = caps[3].parse().ok()?;
+    Some(left.abs_diff(right) + 1)
+}
+
+/// Folds an integer expression such as `C_WIDTH-1` or `2**ADDR_W` over the
+/// constants and generic defaults visible in architecture `scope_arch`
+/// (package constants only when it is unknown); see
+/// `elaboration::architecture_scope`. None when a name doesn't fold or the
+/// text isn't plain integer arithmetic.
+pub fn eval_const_expr(input: &Input, scope_arch: &str, expr: &str) -> Option<i64> {
+    elaboration::eval(expr, &elaboration::architecture_scope(input, scope_arch))
+}
+
+/// Direction ("downto" or "to") of the first range constraint in a type or
+/// slice, e.g. `std_logic_vector(7 downto 0)`. None when unconstrained.
+pub fn range_direction(text: &str) -> Option<&'static str> {
+    let start = text.find('(')?;
+    text[start + 1..]
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .find_map(|token| {
+            if token.eq_ignore_ascii_case("downto") {
+                Some("downto")
+            } else if token.eq_ignore_ascii_case("to") {
+                Some("to")
+            } else {
+                None
+            }
+        })
+}
+
 pub fn is_standard_arch_name(name: &str) -> bool {
     matches!(
         name.to_ascii_lowercase().as_str(),
@@ -120,7 +230,25 @@ pub fn is_unresolved_scalar_type(t: &str) -> bool {
 }
 
 pub fn single_file_mode(input: &Input) -> bool {
-    input.file_count <= 1
+    file_count(input) <= 1
+}
+
+/// `input.file_count`, or the number of distinct files the input mentions when
+/// the producer left it unset.
+pub fn file_count(input: &Input) -> usize {
+    if input.file_count > 0 {
+        return input.file_count;
+    }
+    let files: std::collections::HashSet<&str> = input
+        .files
+        .iter()
+        .map(|f| f.path.as_str())
+        .chain(input.entities.iter().map(|e| e.file.as_str()))
+        .chain(input.architectures.iter().map(|a| a.file.as_str()))
+        .chain(input.packages.iter().map(|p| p.file.as_str()))
+        .filter(|f| !f.is_empty())
+        .collect();
+    files.len()
 }
 
 pub fn entity_exists(input: &Input, name: &str) -> bool {
@@ -406,13 +534,6 @@ pub fn process_in_testbench(input: &Input, proc: &Process) -> bool {
     })
 }
 
-pub fn concurrent_in_testbench(input: &Input, ca: &ConcurrentAssignment) -> bool {
-    input
-        .entities
-        .iter()
-        .any(|entity| entity.file == ca.file && is_testbench_name(&entity.name))
-}
-
 pub fn file_in_testbench(input: &Input, file: &str) -> bool {
     input
         .entities
@@ -420,6 +541,15 @@ pub fn file_in_testbench(input: &Input, file: &str) -> bool {
         .any(|entity| entity.file == file && is_testbench_name(&entity.name))
 }
 
+/// A process's label, or "process at line N" when it has none.
+pub fn process_name(proc: &Process) -> String {
+    if proc.label.is_empty() {
+        format!("process at line {}", proc.line)
+    } else {
+        proc.label.clone()
+    }
+}
+
 pub fn has_all_sensitivity(sens_list: &[String]) -> bool {
     sens_list.iter().any(|s| s.eq_ignore_ascii_case("all"))
 }
@@ -485,22 +615,90 @@ pub fn is_actual_signal(input: &Input, name: &str) -> bool {
     !is_enum_literal(input, name) && !is_constant(input, name) && !is_skip_name(input, name)
 }
 
-pub fn rule_is_disabled(input: &Input, rule: &str) -> bool {
-    if matches!(input.lint_config.rules.get(rule), Some(val) if val == "off") {
-        return true;
+/// Whether `rule` is turned off for a violation in `file`, honouring nested
+/// configs.
+pub fn rule_is_disabled_in(input: &Input, rule: &str, file: &str) -> bool {
+    setting_disables(input, rule, rule_setting(input, rule, file))
+}
+
+/// The severity configured for `rule` in `file`, honouring nested configs.
+pub fn get_rule_severity_in(input: &Input, rule: &str, file: &str) -> Option<String> {
+    rule_setting(input, rule, file).cloned()
+}
+
+fn setting_disables(input: &Input, rule: &str, setting: Option<&String>) -> bool {
+    match setting {
+        Some(value) => value == "off",
+        None => is_optional_rule(rule) && !registry::profile_enables(input, rule),
     }
-    is_optional_rule(rule) && !input.lint_config.rules.contains_key(rule)
 }
 
-pub fn get_rule_severity(input: &Input, rule: &str) -> Option<String> {
-    input.lint_config.rules.get(rule).cloned()
+/// The setting for `rule` of the deepest nested config whose directory holds
+/// `file` and sets it, else the project-wide one.
+fn rule_setting<'a>(input: &'a Input, rule: &str, file: &str) -> Option<&'a String> {
+    let file = file.trim_start_matches("./").replace('\\', "/");
+    input
+        .lint_config
+        .directory_rules
+        .iter()
+        .filter(|dir| {
+            file.strip_prefix(dir.dir.as_str())
+                .is_some_and(|rest| rest.starts_with('/'))
+        })
+        .filter_map(|dir| Some((dir.dir.len(), dir.rules.get(rule)?)))
+        .max_by_key(|(depth, _)| *depth)
+        .map(|(_, setting)| setting)
+        .or_else(|| input.lint_config.rules.get(rule))
 }
 
+/// Whether `file` is classified third-party in the `files` table, by the
+/// config, the project's source sets or `third_party` auto-detection.
 pub fn is_third_party_file(input: &Input, file: &str) -> bool {
     input
-        .third_party_files
+        .files
         .iter()
-        .any(|f| file == f || file.ends_with(f))
+        .any(|f| f.is_third_party && (file == f.path || file.ends_with(&f.path)))
+}
+
+/// Matches a `/`-separated path against a glob: `*` and `?` stay within one
+/// component, `**` spans any number of them.
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<&str> = pattern.split('/').collect();
+    let path: Vec<&str> = path.split('/').collect();
+    match_components(&pattern, &path)
+}
+
+/// True when `file` or its base name matches any of `patterns`.
+pub fn path_matches_any(patterns: &[String], file: &str) -> bool {
+    let file = file.trim_start_matches("./").replace('\\', "/");
+    let base = file.rsplit('/').next().unwrap_or(&file);
+    patterns.iter().any(|pattern| {
+        let pattern = pattern.trim_start_matches("./");
+        glob_match(pattern, &file) || glob_match(pattern, base)
+    })
+}
+
+fn match_components(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| match_components(rest, &path[skip..])),
+        Some((first, rest)) => match path.split_first() {
+            Some((component, path_rest)) => {
+                match_component(first.as_bytes(), component.as_bytes())
+                    && match_components(rest, path_rest)
+            }
+            None => false,
+        },
+    }
+}
+
+fn match_component(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => (0..=text.len()).any(|skip| match_component(rest, &text[skip..])),
+        Some((b'?', rest)) => !text.is_empty() && match_component(rest, &text[1..]),
+        Some((c, rest)) => text.first() == Some(c) && match_component(rest, &text[1..]),
+    }
 }
 
 pub fn is_optional_rule(rule: &str) -> bool {
@@ -534,6 +732,15 @@ pub fn is_optional_rule(rule: &str) -> bool {
             | "state_signal_not_enum"
             | "fsm_missing_default_state"
             | "fsm_unhandled_state"
+            | "fsm_dead_end_state"
+            | "fsm_missing_transition"
+            | "fsm_missing_encoding"
+            | "fsm_state_count_boundary"
+            | "hand_rolled_one_hot"
+            | "psl_assert_without_cover"
+            | "tb_free_running_clock"
+            | "tb_missing_wait_termination"
+            | "tb_no_assertions"
             | "large_combinational_process"
             | "vhdl2008_sensitivity_all"
             | "long_sensitivity_list"
@@ -542,8 +749,10 @@ pub fn is_optional_rule(rule: &str) -> bool {
             | "direct_combinational_loop"
             | "two_stage_combinational_loop"
             | "three_stage_combinational_loop"
+            | "combinational_loop"
             | "potential_combinational_loop"
             | "cross_process_combinational_loop"
+            | "delta_oscillation_risk"
             | "sensitivity_list_superfluous"
             | "sensitivity_list_incomplete"
             | "missing_reset_sensitivity"
@@ -551,6 +760,7 @@ pub fn is_optional_rule(rule: &str) -> bool {
             | "very_wide_register"
             | "mixed_edge_clocking"
             | "async_reset_naming"
+            | "reset_style_inconsistent"
             | "sparse_port_map"
             | "empty_port_map"
             | "instance_name_matches_component"
@@ -566,6 +776,10 @@ pub fn is_optional_rule(rule: &str) -> bool {
             | "short_port_name"
             | "entity_name_with_numbers"
             | "mixed_port_directions"
+            | "port_ordering"
+            | "generic_naming_convention"
+            | "generic_vector_type"
+            | "generic_never_overridden"
             | "bidirectional_port"
             | "unused_signal"
             | "undriven_signal"
@@ -593,6 +807,8 @@ pub fn is_optional_rule(rule: &str) -> bool {
             | "combinational_reset"
             | "unregistered_output"
             | "potential_memory_inference"
+            | "ram_async_read"
+            | "ram_read_during_write"
             | "complex_process"
             | "legacy_packages"
             | "testbench_with_ports"
@@ -606,6 +822,11 @@ pub fn is_optional_rule(rule: &str) -> bool {
             | "enum_case_incomplete"
             | "fsm_no_reset_state"
             | "mixed_signedness"
+            | "mixed_bit_order"
+            | "signal_init_in_rtl"
+            | "tb_uninitialized_signal"
+            | "unused_record_field"
+            | "undriven_record_field"
             | "large_literal_comparison"
             | "magic_number_comparison"
             | "counter_trigger"
@@ -618,6 +839,8 @@ pub fn is_optional_rule(rule: &str) -> bool {
             | "partial_reset_domain"
             | "short_reset_sync"
             | "reset_crosses_domains"
+            | "reset_release_unsynchronized"
+            | "unused_use_clause"
             | "combinational_reset_gen"
             | "potential_latch"
             | "incomplete_case_latch"
@@ -635,6 +858,7 @@ pub fn is_optional_rule(rule: &str) -> bool {
             | "gated_clock_detection"
             | "signal_crosses_clock_domain"
             | "port_width_mismatch"
+            | "port_bit_order_reversed"
             | "input_port_driven"
             | "procedure_param_invalid_mode"
             | "function_param_invalid_mode"
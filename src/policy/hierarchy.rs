@@ -1,13 +1,20 @@
 use regex::Regex;
+use std::collections::HashMap;
 
+use crate::policy::configurations;
+use crate::policy::elaboration::{self, Generics};
 use crate::policy::helpers;
-use crate::policy::input::{Association, Entity, Input, Instance};
+use crate::policy::input::{Association, Component, Entity, Input, Instance, Port};
 use crate::policy::result::Violation;
 
 pub fn violations(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     out.extend(floating_instance_input(input));
     out.extend(port_width_mismatch(input));
+    out.extend(port_bit_order_reversed(input));
+    out.extend(literal_width_mismatch(input));
+    out.extend(port_direction_conflict(input));
+    out.extend(component_entity_mismatch(input));
     out
 }
 
@@ -23,6 +30,20 @@ pub fn optional_violations(input: &Input) -> Vec<Violation> {
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "architectures",
+        "components",
+        "concurrent_assignments",
+        "configurations",
+        "entities",
+        "instances",
+        "ports",
+        "processes",
+        "signals",
+    ]
+}
+
 fn sparse_port_map(input: &Input) -> Vec<Violation> {
     input
         .instances
@@ -38,6 +59,7 @@ fn sparse_port_map(input: &Input) -> Vec<Violation> {
                 inst.name,
                 inst.port_map.len()
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -56,6 +78,7 @@ fn empty_port_map(input: &Input) -> Vec<Violation> {
                 "Instance '{}' has no named port map - using positional mapping or no connections",
                 inst.name
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -80,6 +103,7 @@ fn instance_name_matches_component(input: &Input) -> Vec<Violation> {
                         "Instance name '{}' matches component name - consider a unique instance name",
                         inst.name
                     ),
+                    ..Default::default()
                 })
             } else {
                 None
@@ -88,6 +112,8 @@ fn instance_name_matches_component(input: &Input) -> Vec<Violation> {
         .collect()
 }
 
+/// Counts elaborated instances: one inside a for-generate counts once per
+/// iteration (`elaboration::instance_copies`).
 fn repeated_component_instantiation(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     let mut counts = std::collections::HashMap::new();
@@ -97,25 +123,35 @@ fn repeated_component_instantiation(input: &Input) -> Vec<Violation> {
             continue;
         }
         let key = format!("{}|{}", inst.file, inst.target.to_ascii_lowercase());
-        let count = counts.entry(key.clone()).or_insert(0usize);
-        *count += 1;
+        let (textual, elaborated) = counts.entry(key.clone()).or_insert((0usize, 0usize));
+        *textual += 1;
+        *elaborated += elaboration::instance_copies(input, inst);
         first_instance.entry(key).or_insert(idx);
     }
-    for (key, count) in counts {
-        if count <= 5 {
+    for (key, (textual, elaborated)) in counts {
+        if elaborated <= 5 {
             continue;
         }
         if let Some(first_idx) = first_instance.get(&key) {
             if let Some(inst) = input.instances.get(*first_idx) {
+                let message = if elaborated == textual {
+                    format!(
+                        "Component '{}' instantiated {} times - consider generate statement or hierarchical design",
+                        inst.target, elaborated
+                    )
+                } else {
+                    format!(
+                        "Component '{}' elaborates to {} instances ({} in source) - consider hierarchical design",
+                        inst.target, elaborated, textual
+                    )
+                };
                 out.push(Violation {
                     rule: "repeated_component_instantiation".to_string(),
                     severity: "info".to_string(),
                     file: inst.file.clone(),
                     line: inst.line,
-                    message: format!(
-                        "Component '{}' instantiated {} times - consider generate statement or hierarchical design",
-                        inst.target, count
-                    ),
+                    message,
+                    ..Default::default()
                 });
             }
         }
@@ -123,26 +159,48 @@ fn repeated_component_instantiation(input: &Input) -> Vec<Violation> {
     out
 }
 
+/// Counts the elaborated instances of the architecture and the generates
+/// inside it.
 fn many_instances(input: &Input) -> Vec<Violation> {
     input
         .architectures
         .iter()
         .filter_map(|arch| {
-            let count = input
+            let generate_prefix = format!("{}.", arch.name);
+            let instances: Vec<&Instance> = input
                 .instances
                 .iter()
-                .filter(|inst| inst.in_arch == arch.name && inst.file == arch.file)
-                .count();
+                .filter(|inst| {
+                    inst.file == arch.file
+                        && (inst.in_arch == arch.name
+                            || inst.in_arch.starts_with(&generate_prefix))
+                })
+                .collect();
+            let count: usize = instances
+                .iter()
+                .map(|inst| elaboration::instance_copies(input, inst))
+                .sum();
             if count > 20 {
+                let message = if count == instances.len() {
+                    format!(
+                        "Architecture '{}' has {} instances - consider hierarchical decomposition",
+                        arch.name, count
+                    )
+                } else {
+                    format!(
+                        "Architecture '{}' elaborates to {} instances ({} in source) - consider hierarchical decomposition",
+                        arch.name,
+                        count,
+                        instances.len()
+                    )
+                };
                 Some(Violation {
                     rule: "many_instances".to_string(),
                     severity: "info".to_string(),
                     file: arch.file.clone(),
                     line: arch.line,
-                    message: format!(
-                        "Architecture '{}' has {} instances - consider hierarchical decomposition",
-                        arch.name, count
-                    ),
+                    message,
+                    ..Default::default()
                 })
             } else {
                 None
@@ -165,6 +223,7 @@ fn hardcoded_port_value(input: &Input) -> Vec<Violation> {
                         "Instance '{}' has hardcoded value '{}' on port '{}' - consider using a constant/signal",
                         inst.name, formal, port_name
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -193,6 +252,7 @@ fn open_port_connection(input: &Input) -> Vec<Violation> {
                         "Instance '{}' has 'open' connection on port '{}'",
                         inst.name, port_name
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -200,17 +260,135 @@ fn open_port_connection(input: &Input) -> Vec<Violation> {
     out
 }
 
+/// Component declarations whose port list differs from the entity of the
+/// same name, plus generics both declare with different defaults. Every
+/// difference is listed in one message; extra or missing generics and
+/// generic types are `component_generic_mismatch`'s. Names matching more
+/// than one entity are skipped, as the binding can't be told from the name.
+fn component_entity_mismatch(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for comp in input.components.iter().filter(|comp| !comp.is_instance) {
+        let mut entities = input
+            .entities
+            .iter()
+            .filter(|entity| entity.name.eq_ignore_ascii_case(&comp.name));
+        let (Some(entity), None) = (entities.next(), entities.next()) else {
+            continue;
+        };
+        let diffs = component_diffs(comp, entity);
+        if diffs.is_empty() {
+            continue;
+        }
+        out.push(Violation {
+            rule: "component_entity_mismatch".to_string(),
+            severity: "warning".to_string(),
+            file: comp.file.clone(),
+            line: comp.line,
+            message: format!(
+                "Component '{}' differs from entity '{}': {}",
+                comp.name,
+                entity.name,
+                diffs.join("; ")
+            ),
+            ..Default::default()
+        });
+    }
+    out
+}
+
+/// One entry per differing field, component ports in declaration order
+/// first, then entity ports the component lacks, then generic defaults.
+fn component_diffs(comp: &Component, entity: &Entity) -> Vec<String> {
+    let normalize = |text: &str| {
+        text.split_whitespace()
+            .collect::<String>()
+            .to_ascii_lowercase()
+    };
+    let mode = |port: &Port| match port.direction.to_ascii_lowercase().as_str() {
+        "" => "in".to_string(),
+        direction => direction.to_string(),
+    };
+    let mut diffs = Vec::new();
+    for port in &comp.ports {
+        let Some(declared) = entity
+            .ports
+            .iter()
+            .find(|p| p.name.eq_ignore_ascii_case(&port.name))
+        else {
+            diffs.push(format!("port '{}' is not on the entity", port.name));
+            continue;
+        };
+        if mode(port) != mode(declared) {
+            diffs.push(format!(
+                "port '{}' is {} (entity: {})",
+                port.name,
+                mode(port),
+                mode(declared)
+            ));
+        }
+        if !port.r#type.is_empty()
+            && !declared.r#type.is_empty()
+            && normalize(&port.r#type) != normalize(&declared.r#type)
+        {
+            diffs.push(format!(
+                "port '{}' has type '{}' (entity: '{}')",
+                port.name, port.r#type, declared.r#type
+            ));
+        }
+    }
+    for declared in &entity.ports {
+        if !comp
+            .ports
+            .iter()
+            .any(|p| p.name.eq_ignore_ascii_case(&declared.name))
+        {
+            diffs.push(format!(
+                "entity port '{}' ({} {}) is missing",
+                declared.name,
+                mode(declared),
+                declared.r#type
+            ));
+        }
+    }
+    for generic in &comp.generics {
+        let Some(declared) = entity
+            .generics
+            .iter()
+            .find(|g| g.name.eq_ignore_ascii_case(&generic.name))
+        else {
+            continue;
+        };
+        if normalize(&generic.default) != normalize(&declared.default) {
+            let shown = |default: &str| match default.trim() {
+                "" => "none".to_string(),
+                default => default.to_string(),
+            };
+            diffs.push(format!(
+                "generic '{}' defaults to {} (entity: {})",
+                generic.name,
+                shown(&generic.default),
+                shown(&declared.default)
+            ));
+        }
+    }
+    diffs
+}
+
+/// Instances inside a null-range for-generate are never elaborated and are
+/// skipped; ones repeated by a for-generate say how many copies float.
 fn floating_instance_input(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
-    for inst in &input.instances {
-        if helpers::file_in_testbench(input, &inst.file) {
+    for inst in checked_instances(input) {
+        let copies = elaboration::instance_copies(input, inst);
+        if copies == 0 {
             continue;
         }
-        let target_lower = inst.target.to_ascii_lowercase();
-        for entity in &input.entities {
-            if !target_matches_entity(&target_lower, &entity.name.to_ascii_lowercase()) {
-                continue;
-            }
+        let copies_note = if copies > 1 {
+            format!(" ({} elaborated copies)", copies)
+        } else {
+            String::new()
+        };
+        for entity in bound_entities(input, inst) {
             for port in &entity.ports {
                 if port.direction != "in" {
                     continue;
@@ -230,9 +408,10 @@ fn floating_instance_input(input: &Input) -> Vec<Violation> {
                     file: inst.file.clone(),
                     line: inst.line,
                     message: format!(
-                        "Instance '{}' has unconnected input port '{}' from entity '{}'",
-                        inst.name, port.name, entity.name
+                        "Instance '{}' has unconnected input port '{}' from entity '{}'{}",
+                        inst.name, port.name, entity.name, copies_note
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -240,8 +419,76 @@ fn floating_instance_input(input: &Input) -> Vec<Violation> {
     out
 }
 
-fn target_matches_entity(target: &str, entity_name: &str) -> bool {
-    target == entity_name || target.ends_with(&format!(".{}", entity_name))
+/// Instances the entity-view checks look at. With `strict_binding`, instances
+/// inside architectures that nothing selects are never elaborated and are
+/// skipped.
+fn checked_instances(input: &Input) -> Vec<&Instance> {
+    if !input.lint_config.strict_binding {
+        return input.instances.iter().collect();
+    }
+    let unbound = configurations::unselected_architectures(input);
+    input
+        .instances
+        .iter()
+        .filter(|inst| {
+            !unbound.iter().any(|(_, arch)| {
+                arch.name.eq_ignore_ascii_case(&inst.in_arch) && arch.file == inst.file
+            })
+        })
+        .collect()
+}
+
+/// Entities an instance elaborates to. A configuration binding for the
+/// instance overrides the instantiated name, so component instances are
+/// checked against the entity the configuration actually binds.
+pub fn bound_entities<'a>(input: &'a Input, inst: &Instance) -> Vec<&'a Entity> {
+    let target = configured_target(input, inst).unwrap_or(&inst.target);
+    input
+        .entities
+        .iter()
+        .filter(|entity| target_matches_entity(target, &entity.name))
+        .collect()
+}
+
+/// Entity a configuration binds to `inst`: a binding naming the instance
+/// label wins over `all`/`others`. Bindings apply within the architecture
+/// their block configuration names (the binding scope, else the
+/// configuration's top-level architecture).
+fn configured_target<'a>(input: &'a Input, inst: &Instance) -> Option<&'a str> {
+    let component = inst.target.rsplit('.').next().unwrap_or(&inst.target);
+    let mut fallback = None;
+    for cfg in &input.configurations {
+        for binding in &cfg.bindings {
+            if binding.entity.is_empty() || !binding.component.eq_ignore_ascii_case(component) {
+                continue;
+            }
+            let scope_arch = binding
+                .scope
+                .first()
+                .filter(|arch| !arch.is_empty())
+                .unwrap_or(&cfg.architecture);
+            if !scope_arch.is_empty() && !scope_arch.eq_ignore_ascii_case(&inst.in_arch) {
+                continue;
+            }
+            if binding.instance.eq_ignore_ascii_case(&inst.name) {
+                return Some(&binding.entity);
+            }
+            if binding.instance.eq_ignore_ascii_case("all")
+                || binding.instance.eq_ignore_ascii_case("others")
+            {
+                fallback = Some(binding.entity.as_str());
+            }
+        }
+    }
+    fallback
+}
+
+/// Whether an instantiated `target` (`name` or `lib.name`) names
+/// `entity_name`, ignoring case.
+pub(crate) fn target_matches_entity(target: &str, entity_name: &str) -> bool {
+    let target = target.to_ascii_lowercase();
+    let name = entity_name.to_ascii_lowercase();
+    target == name || target.ends_with(&format!(".{}", name))
 }
 
 fn port_connected_in_instance(inst: &Instance, port_name: &str) -> bool {
@@ -252,14 +499,12 @@ fn port_connected_in_instance(inst: &Instance, port_name: &str) -> bool {
 
 fn port_width_mismatch(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
-    for inst in &input.instances {
-        let target_lower = inst.target.to_ascii_lowercase();
-        for entity in &input.entities {
-            if !target_matches_entity(&target_lower, &entity.name.to_ascii_lowercase()) {
-                continue;
-            }
+    for inst in checked_instances(input) {
+        for entity in bound_entities(input, inst) {
+            let generics = instance_generics(input, inst, entity);
             for port in &entity.ports {
-                if port.width == 0 {
+                let port_width = elaborated_width(port, &generics);
+                if port_width == 0 {
                     continue;
                 }
                 let actual_signal = get_port_connection(inst, entity, &port.name);
@@ -270,7 +515,7 @@ fn port_width_mismatch(input: &Input) -> Vec<Violation> {
                 if signal_width == 0 {
                     continue;
                 }
-                if signal_width != port.width {
+                if signal_width != port_width {
                     out.push(Violation {
                         rule: "port_width_mismatch".to_string(),
                         severity: "error".to_string(),
@@ -278,8 +523,9 @@ fn port_width_mismatch(input: &Input) -> Vec<Violation> {
                         line: inst.line,
                         message: format!(
                             "Width mismatch: signal '{}' ({} bits) connected to port '{}' ({} bits) in instance '{}'",
-                            actual_signal, signal_width, port.name, port.width, inst.name
+                            actual_signal, signal_width, port.name, port_width, inst.name
                         ),
+                        ..Default::default()
                     });
                 }
             }
@@ -288,6 +534,235 @@ fn port_width_mismatch(input: &Input) -> Vec<Violation> {
     out
 }
 
+/// Port maps that fight the port modes: an `out` formal given an expression
+/// (which can only be read), an `out` formal driving an `in` port of the
+/// instantiating entity, and two `out` formals driving the same actual.
+/// `inout` formals are left alone since shared tri-state buses are legal.
+fn port_direction_conflict(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    let mut drivers: HashMap<(String, String, String), (String, String)> = HashMap::new();
+    for inst in checked_instances(input) {
+        for entity in bound_entities(input, inst) {
+            for port in &entity.ports {
+                let direction = port.direction.to_ascii_lowercase();
+                if direction != "out" && direction != "buffer" {
+                    continue;
+                }
+                let actual = get_port_connection(inst, entity, &port.name);
+                let actual = actual.trim();
+                if actual.is_empty() || actual.eq_ignore_ascii_case("open") {
+                    continue;
+                }
+                let conflict = if is_expression_actual(actual) {
+                    Some(format!(
+                        "Output port '{}' of instance '{}' is connected to expression '{}', which it cannot drive",
+                        port.name, inst.name, actual
+                    ))
+                } else if parent_port_mode(input, &inst.in_arch, base_name(actual)).as_deref()
+                    == Some("in")
+                {
+                    Some(format!(
+                        "Output port '{}' of instance '{}' drives '{}', an input port of the enclosing entity",
+                        port.name, inst.name, actual
+                    ))
+                } else {
+                    let key = (
+                        inst.file.clone(),
+                        inst.in_arch.to_ascii_lowercase(),
+                        actual.replace(char::is_whitespace, "").to_ascii_lowercase(),
+                    );
+                    match drivers.get(&key) {
+                        Some((other, other_port)) => Some(format!(
+                            "Output port '{}' of instance '{}' and output port '{}' of instance '{}' both drive '{}'",
+                            port.name, inst.name, other_port, other, actual
+                        )),
+                        None => {
+                            drivers.insert(key, (inst.name.clone(), port.name.clone()));
+                            None
+                        }
+                    }
+                };
+                if let Some(message) = conflict {
+                    out.push(Violation {
+                        rule: "port_direction_conflict".to_string(),
+                        severity: "error".to_string(),
+                        file: inst.file.clone(),
+                        line: inst.line,
+                        message,
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Whether an actual is a literal or has an operator outside its
+/// parentheses, so it is a value rather than a name an output can drive.
+/// Index expressions (`d(N-1 downto 0)`) and conversions (`unsigned(q)`)
+/// are names.
+fn is_expression_actual(actual: &str) -> bool {
+    if Regex::new(r#"^([0-9'"]|[xXbBoO]")"#)
+        .unwrap()
+        .is_match(actual)
+    {
+        return true;
+    }
+    let mut depth = 0usize;
+    let mut top = String::new();
+    for ch in actual.chars() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ if depth == 0 => top.push(ch),
+            _ => {}
+        }
+    }
+    top.contains(['+', '-', '*', '/', '&'])
+        || Regex::new(r"(?i)\b(and|or|xor|nand|nor|xnor|not|mod|rem)\b")
+            .unwrap()
+            .is_match(&top)
+}
+
+/// Mode of `name` when it is a port of the entity `scope_arch` implements.
+fn parent_port_mode(input: &Input, scope_arch: &str, name: &str) -> Option<String> {
+    let entity_name = arch_entity_name(input, scope_arch)?;
+    input
+        .ports
+        .iter()
+        .find(|port| {
+            port.in_entity.eq_ignore_ascii_case(&entity_name)
+                && port.name.eq_ignore_ascii_case(name)
+        })
+        .map(|port| port.direction.to_ascii_lowercase())
+}
+
+/// Values `entity` is elaborated with for `inst`: package constants and its
+/// generic map, evaluated in the instantiating architecture's scope.
+fn instance_generics(input: &Input, inst: &Instance, entity: &Entity) -> Generics {
+    let outer = elaboration::architecture_scope(input, &inst.in_arch);
+    elaboration::entity_scope(input, entity, Some(inst), &outer)
+}
+
+fn elaborated_width(port: &Port, generics: &Generics) -> usize {
+    elaboration::declared_width(&port.r#type, port.width, generics)
+}
+
+/// A `downto` formal connected to a `to` actual (or vice versa) maps bits by
+/// position, so the leftmost bit of one side lands on the leftmost of the other
+/// and the vector is silently reversed.
+fn port_bit_order_reversed(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for inst in checked_instances(input) {
+        for entity in bound_entities(input, inst) {
+            for port in &entity.ports {
+                let Some(formal_dir) = helpers::range_direction(&port.r#type) else {
+                    continue;
+                };
+                let actual = get_port_connection(inst, entity, &port.name);
+                if actual.is_empty() || actual.eq_ignore_ascii_case("open") {
+                    continue;
+                }
+                let Some(actual_dir) = get_actual_direction(input, &actual, &inst.in_arch) else {
+                    continue;
+                };
+                if actual_dir != formal_dir {
+                    out.push(Violation {
+                        rule: "port_bit_order_reversed".to_string(),
+                        severity: "warning".to_string(),
+                        file: inst.file.clone(),
+                        line: inst.line,
+                        message: format!(
+                            "Bit order reversed: '{}' ({}) connected to port '{}' ({}) in instance '{}'",
+                            actual, actual_dir, port.name, formal_dir, inst.name
+                        ),
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Fixed-width literals (bit strings, single-range aggregates) whose element
+/// count differs from the port or signal they drive. Elaboration accepts these
+/// and simulators only fail when the assignment executes.
+fn literal_width_mismatch(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for inst in checked_instances(input) {
+        for entity in bound_entities(input, inst) {
+            let generics = instance_generics(input, inst, entity);
+            for port in &entity.ports {
+                let port_width = elaborated_width(port, &generics);
+                if port_width == 0 {
+                    continue;
+                }
+                let actual = get_port_connection(inst, entity, &port.name);
+                let Some(literal_width) = helpers::literal_width(&actual) else {
+                    continue;
+                };
+                if literal_width != port_width {
+                    out.push(literal_width_violation(
+                        &inst.file,
+                        inst.line,
+                        format!(
+                            "Literal {} ({} bits) connected to port '{}' ({} bits) in instance '{}'",
+                            actual, literal_width, port.name, port_width, inst.name
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+
+    let assignments = input
+        .concurrent_assignments
+        .iter()
+        .map(|ca| {
+            let target = if ca.target_full.is_empty() {
+                &ca.target
+            } else {
+                &ca.target_full
+            };
+            (target, &ca.value, &ca.in_arch, &ca.file, ca.line)
+        })
+        .chain(input.processes.iter().flat_map(|proc| {
+            proc.assignments
+                .iter()
+                .map(move |a| (&a.target, &a.value, &proc.in_arch, &proc.file, a.line))
+        }));
+    for (target, value, scope_arch, file, line) in assignments {
+        let Some(literal_width) = helpers::literal_width(value) else {
+            continue;
+        };
+        let width = get_actual_width(input, target, scope_arch);
+        if width != 0 && literal_width != width {
+            out.push(literal_width_violation(
+                file,
+                line,
+                format!(
+                    "Literal {} ({} bits) assigned to '{}' ({} bits)",
+                    value, literal_width, target, width
+                ),
+            ));
+        }
+    }
+    out
+}
+
+fn literal_width_violation(file: &str, line: usize, message: String) -> Violation {
+    Violation {
+        rule: "literal_width_mismatch".to_string(),
+        severity: "error".to_string(),
+        file: file.to_string(),
+        line,
+        message,
+        ..Default::default()
+    }
+}
+
 fn get_port_connection(inst: &Instance, entity: &Entity, port_name: &str) -> String {
     // Prefer association elements (captures slices/indexing)
     for assoc in &inst.associations {
@@ -335,34 +810,105 @@ fn is_literal_or_expr(s: &str) -> bool {
         || s.to_ascii_lowercase().contains("others")
 }
 
-fn get_actual_width(input: &Input, actual: &str, scope_arch: &str) -> usize {
+/// What the text of an instance actual says about its width. Names and
+/// indexed names depend on the declared width of `base`, which the batch
+/// engine looks up in `get_signal_width` and `vhdl_policyd` maintains
+/// incrementally.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActualWidth {
+    Unknown,
+    Fixed(usize),
+    Declared { base: String, indexed: bool },
+}
+
+pub fn actual_width(actual: &str) -> ActualWidth {
+    actual_width_in(actual, &Generics::new())
+}
+
+/// `actual_width` with slice bounds folded against `generics`.
+fn actual_width_in(actual: &str, generics: &Generics) -> ActualWidth {
     if actual.is_empty() || actual.eq_ignore_ascii_case("open") {
-        return 0;
+        return ActualWidth::Unknown;
     }
     if is_literal_or_expr(actual) {
-        return 0;
+        return ActualWidth::Unknown;
     }
     let base = base_name(actual);
-    let mut base_width = get_signal_width(input, base, scope_arch);
-    if base_width == 0 && base != actual {
-        base_width = get_signal_width(input, actual, scope_arch);
-    }
+    indexed_width(actual, base, generics).unwrap_or_else(|| ActualWidth::Declared {
+        base: base.to_string(),
+        indexed: false,
+    })
+}
 
-    if let Some(width) = indexed_width(actual, base_width) {
-        return width;
+fn get_actual_width(input: &Input, actual: &str, scope_arch: &str) -> usize {
+    let generics = elaboration::architecture_scope(input, scope_arch);
+    match actual_width_in(actual, &generics) {
+        ActualWidth::Unknown => 0,
+        ActualWidth::Fixed(width) => width,
+        ActualWidth::Declared { base, indexed } => {
+            let mut base_width = get_signal_width(input, &base, scope_arch, &generics);
+            if base_width == 0 && base != actual {
+                base_width = get_signal_width(input, actual, scope_arch, &generics);
+            }
+            // Single index: if base width is unknown, avoid guessing.
+            if indexed && base_width > 0 {
+                1
+            } else {
+                base_width
+            }
+        }
     }
+}
 
-    base_width
+fn get_actual_direction(input: &Input, actual: &str, scope_arch: &str) -> Option<&'static str> {
+    if scope_arch.is_empty() || is_literal_or_expr(actual) {
+        return None;
+    }
+    if actual.contains('(') {
+        // Slices carry their own direction; a single index has none.
+        return helpers::range_direction(actual);
+    }
+    let entity_name = arch_entity_name(input, scope_arch);
+    input
+        .signals
+        .iter()
+        .filter(|sig| sig.in_entity.eq_ignore_ascii_case(scope_arch))
+        .filter(|sig| sig.name.eq_ignore_ascii_case(actual))
+        .map(|sig| sig.r#type.as_str())
+        .chain(
+            input
+                .ports
+                .iter()
+                .filter(|port| {
+                    entity_name
+                        .as_deref()
+                        .is_some_and(|name| port.in_entity.eq_ignore_ascii_case(name))
+                })
+                .filter(|port| port.name.eq_ignore_ascii_case(actual))
+                .map(|port| port.r#type.as_str()),
+        )
+        .find_map(helpers::range_direction)
 }
 
-fn get_signal_width(input: &Input, signal_name: &str, scope_arch: &str) -> usize {
+/// Widest declaration of `signal_name` visible in `scope_arch`, sized with
+/// that architecture's `generics`; project-wide when the scope is unknown.
+fn get_signal_width(
+    input: &Input,
+    signal_name: &str,
+    scope_arch: &str,
+    generics: &Generics,
+) -> usize {
     let mut widths = Vec::new();
     if !scope_arch.is_empty() {
         for sig in &input.signals {
             if sig.in_entity.eq_ignore_ascii_case(scope_arch)
                 && sig.name.eq_ignore_ascii_case(signal_name)
             {
-                widths.push(sig.width);
+                widths.push(elaboration::declared_width(
+                    &sig.r#type,
+                    sig.width,
+                    generics,
+                ));
             }
         }
         if let Some(entity_name) = arch_entity_name(input, scope_arch) {
@@ -370,7 +916,7 @@ fn get_signal_width(input: &Input, signal_name: &str, scope_arch: &str) -> usize
                 if port.in_entity.eq_ignore_ascii_case(&entity_name)
                     && port.name.eq_ignore_ascii_case(signal_name)
                 {
-                    widths.push(port.width);
+                    widths.push(elaborated_width(port, generics));
                 }
             }
         }
@@ -409,7 +955,7 @@ fn association_actual(assoc: &Association) -> String {
     assoc.actual_base.clone()
 }
 
-fn base_name(actual: &str) -> &str {
+pub fn base_name(actual: &str) -> &str {
     let mut end = actual.len();
     for (idx, ch) in actual.char_indices() {
         if ch == '(' || ch == '.' || ch == '\'' || ch.is_whitespace() {
@@ -428,7 +974,9 @@ fn arch_entity_name(input: &Input, arch_name: &str) -> Option<String> {
         .map(|arch| arch.entity_name.clone())
 }
 
-fn indexed_width(actual: &str, base_width: usize) -> Option<usize> {
+/// Width of a sliced or indexed actual; `None` when there is no index or its
+/// bounds don't fold against `generics`, so the declared width applies.
+fn indexed_width(actual: &str, base: &str, generics: &Generics) -> Option<ActualWidth> {
     let start = actual.find('(')?;
     let end = actual.rfind(')')?;
     if end <= start {
@@ -436,34 +984,26 @@ fn indexed_width(actual: &str, base_width: usize) -> Option<usize> {
     }
     let inside = actual[start + 1..end].trim();
     if inside.contains(',') {
-        return Some(0);
+        return Some(ActualWidth::Fixed(0));
     }
     let lower = inside.to_ascii_lowercase();
-    if let Some(idx) = lower.find(" downto ") {
-        let (left, right) = inside.split_at(idx);
-        let right = &right[" downto ".len()..];
-        let a = left.trim().parse::<isize>().ok()?;
-        let b = right.trim().parse::<isize>().ok()?;
-        return Some((a - b).abs() as usize + 1);
-    }
-    if let Some(idx) = lower.find(" to ") {
-        let (left, right) = inside.split_at(idx);
-        let right = &right[" to ".len()..];
-        let a = left.trim().parse::<isize>().ok()?;
-        let b = right.trim().parse::<isize>().ok()?;
-        return Some((a - b).abs() as usize + 1);
-    }
-    // Single index: if base width is unknown, avoid guessing.
-    if base_width == 0 {
-        return Some(0);
+    if lower.contains(" downto ") || lower.contains(" to ") {
+        return elaboration::range_width(actual, generics).map(ActualWidth::Fixed);
     }
-    Some(1)
+    Some(ActualWidth::Declared {
+        base: base.to_string(),
+        indexed: true,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::policy::input::{Association, Entity, Input, Instance, Port, Signal};
+    use crate::policy::input::{
+        Architecture, Association, ConcurrentAssignment, Configuration, ConfigurationBinding,
+        ConstantDeclaration, Entity, GenerateStatement, GenericDecl, Input, Instance, Port,
+        Process, SequentialAssignment, Signal,
+    };
 
     #[test]
     fn sparse_port_map_flags() {
@@ -520,6 +1060,139 @@ mod tests {
         assert!(v.is_empty());
     }
 
+    #[test]
+    fn configuration_binding_selects_entity_view() {
+        let mut input = Input::default();
+        for (arch, file, line) in [("rtl", "top.vhd", 10), ("sim", "top.vhd", 40)] {
+            input.architectures.push(Architecture {
+                name: arch.to_string(),
+                entity_name: "top".to_string(),
+                file: file.to_string(),
+                line,
+                ..Default::default()
+            });
+            input.instances.push(Instance {
+                name: "u1".to_string(),
+                target: "fifo_comp".to_string(),
+                file: file.to_string(),
+                line: line + 2,
+                in_arch: arch.to_string(),
+                ..Default::default()
+            });
+        }
+        input.entities.push(Entity {
+            name: "fifo_impl".to_string(),
+            ports: vec![Port {
+                name: "wr_en".to_string(),
+                direction: "in".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        input.entities.push(Entity {
+            name: "top".to_string(),
+            ..Default::default()
+        });
+        input.configurations.push(Configuration {
+            name: "cfg_top".to_string(),
+            entity_name: "top".to_string(),
+            architecture: "rtl".to_string(),
+            bindings: vec![ConfigurationBinding {
+                scope: vec!["rtl".to_string()],
+                instance: "u1".to_string(),
+                component: "fifo_comp".to_string(),
+                entity: "work.fifo_impl".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        let v = floating_instance_input(&input);
+        assert_eq!(v.len(), 1);
+        assert!(v[0].message.contains("'fifo_impl'"));
+
+        input.configurations[0].bindings[0].instance = "all".to_string();
+        input.configurations[0].bindings[0].scope.clear();
+        input.instances[1].target = "work.fifo_impl".to_string();
+        assert_eq!(floating_instance_input(&input).len(), 2);
+        input.lint_config.strict_binding = true;
+        let v = floating_instance_input(&input);
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[0].line, 12);
+    }
+
+    #[test]
+    fn port_direction_conflict_checks_output_formals() {
+        let mut input = Input::default();
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "top".to_string(),
+            file: "a.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        input.ports.push(Port {
+            name: "x_i".to_string(),
+            direction: "in".to_string(),
+            in_entity: "top".to_string(),
+            ..Default::default()
+        });
+        input.entities.push(Entity {
+            name: "child".to_string(),
+            ports: vec![
+                Port {
+                    name: "a".to_string(),
+                    direction: "in".to_string(),
+                    ..Default::default()
+                },
+                Port {
+                    name: "q".to_string(),
+                    direction: "out".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+        for (name, actual, positional) in [
+            ("u1", "x_i", false),
+            ("u2", "s", false),
+            ("u3", "s", true),
+            ("u4", "s1 and s2", false),
+            ("u5", "d(N-1 downto 0)", false),
+        ] {
+            input.instances.push(Instance {
+                name: name.to_string(),
+                target: "work.child".to_string(),
+                file: "a.vhd".to_string(),
+                in_arch: "rtl".to_string(),
+                associations: vec![Association {
+                    kind: "port".to_string(),
+                    formal: if positional {
+                        String::new()
+                    } else {
+                        "q".to_string()
+                    },
+                    actual: actual.to_string(),
+                    is_positional: positional,
+                    position_index: 1,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            });
+        }
+
+        let v = port_direction_conflict(&input);
+        let messages: Vec<_> = v.iter().map(|v| v.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "Output port 'q' of instance 'u1' drives 'x_i', an input port of the enclosing entity",
+                "Output port 'q' of instance 'u3' and output port 'q' of instance 'u2' both drive 's'",
+                "Output port 'q' of instance 'u4' is connected to expression 's1 and s2', which it cannot drive",
+            ]
+        );
+    }
+
     #[test]
     fn port_width_mismatch_ignores_sliced_actual() {
         let mut input = Input::default();
@@ -593,4 +1266,389 @@ mod tests {
         let v = port_width_mismatch(&input);
         assert!(v.is_empty());
     }
+
+    #[test]
+    fn port_width_mismatch_uses_generic_map() {
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "child".to_string(),
+            generics: vec![GenericDecl {
+                name: "WIDTH".to_string(),
+                default: "4".to_string(),
+                ..Default::default()
+            }],
+            ports: vec![Port {
+                name: "d".to_string(),
+                direction: "in".to_string(),
+                r#type: "std_logic_vector(WIDTH-1 downto 0)".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        input.entities.push(Entity {
+            name: "top".to_string(),
+            generics: vec![GenericDecl {
+                name: "BUS_W".to_string(),
+                default: "8".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "top".to_string(),
+            ..Default::default()
+        });
+        input.signals.push(Signal {
+            name: "bus".to_string(),
+            in_entity: "rtl".to_string(),
+            r#type: "std_logic_vector(BUS_W-1 downto 0)".to_string(),
+            ..Default::default()
+        });
+
+        let mut inst = Instance {
+            name: "u1".to_string(),
+            target: "work.child".to_string(),
+            in_arch: "rtl".to_string(),
+            file: "a.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        };
+        inst.associations.push(Association {
+            kind: "port".to_string(),
+            formal: "d".to_string(),
+            actual: "bus".to_string(),
+            ..Default::default()
+        });
+        inst.generic_map
+            .insert("WIDTH".to_string(), "BUS_W".to_string());
+        input.instances.push(inst.clone());
+        assert!(port_width_mismatch(&input).is_empty());
+
+        inst.generic_map
+            .insert("WIDTH".to_string(), "BUS_W / 2".to_string());
+        input.instances = vec![inst.clone()];
+        let v = port_width_mismatch(&input);
+        assert_eq!(v.len(), 1);
+        assert!(v[0].message.contains("(8 bits)"), "{}", v[0].message);
+        assert!(v[0].message.contains("(4 bits)"), "{}", v[0].message);
+
+        inst.associations[0].actual = "bus(BUS_W/2-1 downto 0)".to_string();
+        input.instances = vec![inst];
+        assert!(port_width_mismatch(&input).is_empty());
+    }
+
+    #[test]
+    fn port_width_mismatch_folds_constants() {
+        let mut input = Input::default();
+        input.constant_decls.push(ConstantDeclaration {
+            name: "C_DATA_W".to_string(),
+            value: "C_BYTES * 8".to_string(),
+            in_package: "cfg_pkg".to_string(),
+            ..Default::default()
+        });
+        input.constant_decls.push(ConstantDeclaration {
+            name: "C_BYTES".to_string(),
+            value: "2".to_string(),
+            in_package: "cfg_pkg".to_string(),
+            ..Default::default()
+        });
+        input.constant_decls.push(ConstantDeclaration {
+            name: "C_HALF".to_string(),
+            value: "C_DATA_W / 2".to_string(),
+            file: "top.vhd".to_string(),
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        });
+        input.entities.push(Entity {
+            name: "child".to_string(),
+            ports: vec![Port {
+                name: "d".to_string(),
+                direction: "in".to_string(),
+                r#type: "std_logic_vector(C_DATA_W-1 downto 0)".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "top".to_string(),
+            file: "top.vhd".to_string(),
+            ..Default::default()
+        });
+        input.signals.push(Signal {
+            name: "half".to_string(),
+            in_entity: "rtl".to_string(),
+            r#type: "std_logic_vector(C_HALF-1 downto 0)".to_string(),
+            ..Default::default()
+        });
+        let mut inst = Instance {
+            name: "u1".to_string(),
+            target: "work.child".to_string(),
+            in_arch: "rtl".to_string(),
+            file: "top.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        };
+        inst.associations.push(Association {
+            kind: "port".to_string(),
+            formal: "d".to_string(),
+            actual: "half".to_string(),
+            ..Default::default()
+        });
+        input.instances.push(inst);
+
+        let v = port_width_mismatch(&input);
+        assert_eq!(v.len(), 1);
+        assert!(v[0].message.contains("(8 bits)"), "{}", v[0].message);
+        assert!(v[0].message.contains("(16 bits)"), "{}", v[0].message);
+    }
+
+    fn bit_order_input(signal_type: &str, actual: &str) -> Input {
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "child".to_string(),
+            ports: vec![Port {
+                name: "data_i".to_string(),
+                direction: "in".to_string(),
+                r#type: "std_logic_vector(7 downto 0)".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        input.signals.push(Signal {
+            name: "bus_s".to_string(),
+            r#type: signal_type.to_string(),
+            in_entity: "rtl".to_string(),
+            ..Default::default()
+        });
+        input.instances.push(Instance {
+            name: "u_child".to_string(),
+            target: "work.child".to_string(),
+            file: "top.vhd".to_string(),
+            line: 20,
+            in_arch: "rtl".to_string(),
+            associations: vec![Association {
+                kind: "port".to_string(),
+                formal: "data_i".to_string(),
+                actual: actual.to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        input
+    }
+
+    #[test]
+    fn port_bit_order_reversed_flags_opposite_ranges() {
+        let v = port_bit_order_reversed(&bit_order_input("std_logic_vector(0 to 7)", "bus_s"));
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[0].rule, "port_bit_order_reversed");
+
+        let v = port_bit_order_reversed(&bit_order_input(
+            "std_logic_vector(0 to 15)",
+            "bus_s(0 to 7)",
+        ));
+        assert_eq!(v.len(), 1);
+    }
+
+    #[test]
+    fn port_bit_order_reversed_accepts_matching_ranges() {
+        let v = port_bit_order_reversed(&bit_order_input("std_logic_vector(7 downto 0)", "bus_s"));
+        assert!(v.is_empty());
+        let v = port_bit_order_reversed(&bit_order_input("std_logic_vector(0 to 7)", "bus_s(3)"));
+        assert!(v.is_empty());
+    }
+
+    fn literal_input(signal_type: &str, width: usize) -> Input {
+        let mut input = Input::default();
+        input.signals.push(Signal {
+            name: "bus_s".to_string(),
+            r#type: signal_type.to_string(),
+            in_entity: "rtl".to_string(),
+            width,
+            ..Default::default()
+        });
+        input
+    }
+
+    #[test]
+    fn literal_width_mismatch_checks_assignments() {
+        let mut input = literal_input("std_logic_vector(3 downto 0)", 4);
+        input.concurrent_assignments.push(ConcurrentAssignment {
+            target: "bus_s".to_string(),
+            value: "x\"FF\"".to_string(),
+            in_arch: "rtl".to_string(),
+            file: "a.vhd".to_string(),
+            line: 5,
+            ..Default::default()
+        });
+        input.processes.push(Process {
+            in_arch: "rtl".to_string(),
+            file: "a.vhd".to_string(),
+            assignments: vec![
+                SequentialAssignment {
+                    target: "bus_s(1 downto 0)".to_string(),
+                    value: "(2 downto 0 => '1')".to_string(),
+                    line: 9,
+                    ..Default::default()
+                },
+                SequentialAssignment {
+                    target: "bus_s".to_string(),
+                    value: "\"0101\"".to_string(),
+                    line: 10,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+        let v = literal_width_mismatch(&input);
+        assert_eq!(v.len(), 2);
+        assert!(v[0].message.contains("(8 bits)"));
+        assert_eq!(v[1].line, 9);
+        assert!(v[1]
+            .message
+            .contains("(3 bits) assigned to 'bus_s(1 downto 0)' (2 bits)"));
+    }
+
+    #[test]
+    fn literal_width_mismatch_checks_port_maps() {
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "child".to_string(),
+            ports: vec![Port {
+                name: "cfg_i".to_string(),
+                direction: "in".to_string(),
+                width: 8,
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        let mut inst = Instance {
+            name: "u_child".to_string(),
+            target: "work.child".to_string(),
+            ..Default::default()
+        };
+        for (actual, expected) in [("4x\"A\"", 1), ("(others => '0')", 0), ("8ux\"A\"", 0)] {
+            inst.port_map
+                .insert("cfg_i".to_string(), actual.to_string());
+            input.instances = vec![inst.clone()];
+            assert_eq!(literal_width_mismatch(&input).len(), expected, "{}", actual);
+        }
+    }
+
+    #[test]
+    fn instance_counts_follow_generate_iterations() {
+        let mut input = Input::default();
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "top".to_string(),
+            file: "top.vhd".to_string(),
+            line: 3,
+            ..Default::default()
+        });
+        for (label, count) in [("g_lane", 8), ("g_off", 0)] {
+            input.generates.push(GenerateStatement {
+                label: label.to_string(),
+                kind: "for".to_string(),
+                file: "top.vhd".to_string(),
+                in_arch: "rtl".to_string(),
+                range_dir: "to".to_string(),
+                can_elaborate: true,
+                iteration_count: count,
+                ..Default::default()
+            });
+        }
+        input.entities.push(Entity {
+            name: "lane".to_string(),
+            ports: vec![Port {
+                name: "data_in".to_string(),
+                direction: "in".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        for in_arch in ["rtl.g_lane", "rtl.g_off"] {
+            input.instances.push(Instance {
+                name: "u_lane".to_string(),
+                target: "work.lane".to_string(),
+                file: "top.vhd".to_string(),
+                line: 10,
+                in_arch: in_arch.to_string(),
+                ..Default::default()
+            });
+        }
+
+        let repeated = repeated_component_instantiation(&input);
+        assert_eq!(repeated.len(), 1);
+        assert_eq!(
+            repeated[0].message,
+            "Component 'work.lane' elaborates to 8 instances (2 in source) - consider hierarchical design"
+        );
+        assert!(many_instances(&input).is_empty());
+        input.generates[0].iteration_count = 24;
+        assert!(many_instances(&input)[0]
+            .message
+            .starts_with("Architecture 'rtl' elaborates to 24 instances (2 in source)"));
+
+        let floating = floating_instance_input(&input);
+        assert_eq!(floating.len(), 1);
+        assert!(floating[0]
+            .message
+            .ends_with("'lane' (24 elaborated copies)"));
+    }
+
+    #[test]
+    fn component_entity_mismatch_lists_every_difference() {
+        let port = |name: &str, direction: &str, ty: &str| Port {
+            name: name.to_string(),
+            direction: direction.to_string(),
+            r#type: ty.to_string(),
+            ..Default::default()
+        };
+        let generic = |name: &str, default: &str| GenericDecl {
+            name: name.to_string(),
+            default: default.to_string(),
+            ..Default::default()
+        };
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "fifo".to_string(),
+            ports: vec![
+                port("clk", "in", "std_logic"),
+                port("rst", "in", "std_logic"),
+                port("dout", "out", "std_logic_vector(7 downto 0)"),
+            ],
+            generics: vec![generic("G_DEPTH", "16"), generic("G_NAME", "\"fifo\"")],
+            ..Default::default()
+        });
+        let mut component = Component {
+            name: "FIFO".to_string(),
+            file: "top.vhd".to_string(),
+            line: 7,
+            ports: vec![
+                port("clk", "", "std_logic"),
+                port("dout", "inout", "std_logic_vector(7  DOWNTO 0)"),
+                port("full", "out", "std_logic"),
+            ],
+            generics: vec![generic("g_depth", "8"), generic("G_NAME", "\"fifo\"")],
+            ..Default::default()
+        };
+        input.components.push(component.clone());
+
+        let out = component_entity_mismatch(&input);
+        assert_eq!(out.len(), 1);
+        assert_eq!((out[0].file.as_str(), out[0].line), ("top.vhd", 7));
+        assert_eq!(
+            out[0].message,
+            "Component 'FIFO' differs from entity 'fifo': port 'dout' is inout (entity: out); \
+             port 'full' is not on the entity; entity port 'rst' (in std_logic) is missing; \
+             generic 'g_depth' defaults to 8 (entity: 16)"
+        );
+
+        component.ports = input.entities[0].ports.clone();
+        component.generics = input.entities[0].generics.clone();
+        input.components = vec![component];
+        assert!(component_entity_mismatch(&input).is_empty());
+    }
 }
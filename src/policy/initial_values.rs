@@ -0,0 +1,253 @@
+//! Initial values on signal declarations (`signal cnt : unsigned(7 downto 0)
+//! := (others => '0');`). ASIC synthesis ignores them, so RTL relying on one
+//! simulates differently from the netlist until reset. FPGA flows load
+//! register initial values at configuration, which leaves only those on
+//! combinationally driven signals misleading: they hide the 'U' simulation
+//! would show before the first assignment. `lint_config.target` (`asic` or
+//! `fpga`, the default) picks the policy. Testbenches are the other way round:
+//! a std_logic signal without an initial value starts at 'U', and one only
+//! ever driven from itself (`clk <= not clk after 5 ns`) never leaves it.
+
+use std::collections::HashSet;
+
+use crate::policy::input::{Input, Signal};
+use crate::policy::registry::{Context, FileContexts};
+use crate::policy::result::Violation;
+
+pub fn violations(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    out.extend(signal_init_in_rtl(input));
+    out.extend(tb_uninitialized_signal(input));
+    out
+}
+
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "concurrent_assignments",
+        "entities",
+        "lint_config",
+        "processes",
+        "signals",
+    ]
+}
+
+fn signal_init_in_rtl(input: &Input) -> Vec<Violation> {
+    let contexts = FileContexts::new(input);
+    let asic = input.lint_config.target.eq_ignore_ascii_case("asic");
+    let mut registers = HashSet::new();
+    let mut combinational = HashSet::new();
+    for proc in &input.processes {
+        let set = if proc.clock_signal.is_empty() && !proc.is_sequential {
+            &mut combinational
+        } else {
+            &mut registers
+        };
+        for name in &proc.assigned_signals {
+            set.insert((proc.file.as_str(), name.to_ascii_lowercase()));
+        }
+    }
+    for assign in &input.concurrent_assignments {
+        combinational.insert((assign.file.as_str(), assign.target.to_ascii_lowercase()));
+    }
+
+    let mut out = Vec::new();
+    for sig in &input.signals {
+        if sig.default.is_empty() || contexts.context(&sig.file) != Context::Rtl {
+            continue;
+        }
+        let key = (sig.file.as_str(), sig.name.to_ascii_lowercase());
+        let message = if asic {
+            format!(
+                "Signal '{}' has initial value {} - ASIC synthesis ignores initial values, so simulation and silicon disagree until reset; reset it instead",
+                sig.name, sig.default
+            )
+        } else if combinational.contains(&key) && !registers.contains(&key) {
+            format!(
+                "Signal '{}' has initial value {} but is driven combinationally - the value never reaches hardware and only hides 'U' in simulation until the first assignment",
+                sig.name, sig.default
+            )
+        } else {
+            continue;
+        };
+        out.push(Violation {
+            rule: "signal_init_in_rtl".to_string(),
+            severity: "warning".to_string(),
+            file: sig.file.clone(),
+            line: sig.line,
+            message,
+            ..Default::default()
+        });
+    }
+    out
+}
+
+fn tb_uninitialized_signal(input: &Input) -> Vec<Violation> {
+    let contexts = FileContexts::new(input);
+    let mut out = Vec::new();
+    for sig in &input.signals {
+        if !sig.default.is_empty()
+            || !starts_undefined(sig)
+            || contexts.context(&sig.file) != Context::Tb
+        {
+            continue;
+        }
+        let Some(driver) = self_driver(input, sig) else {
+            continue;
+        };
+        out.push(Violation {
+            rule: "tb_uninitialized_signal".to_string(),
+            severity: "warning".to_string(),
+            file: sig.file.clone(),
+            line: sig.line,
+            message: format!(
+                "Testbench signal '{}' has no initial value and is only driven from itself ('{}') - it starts at 'U' and stays 'U'/'X'; declare it with an initial value",
+                sig.name, driver
+            ),
+            ..Default::default()
+        });
+    }
+    out
+}
+
+/// Nine-valued types, which start at 'U'.
+fn starts_undefined(sig: &Signal) -> bool {
+    let ty = sig.r#type.to_ascii_lowercase();
+    let base = ty.split(['(', ' ']).next().unwrap_or_default();
+    matches!(
+        base,
+        "std_logic"
+            | "std_ulogic"
+            | "std_logic_vector"
+            | "std_ulogic_vector"
+            | "signed"
+            | "unsigned"
+    )
+}
+
+/// The value text of the first assignment to `sig`, when every assignment
+/// to it reads `sig` itself.
+fn self_driver(input: &Input, sig: &Signal) -> Option<String> {
+    let reads_self = |reads: &[String]| reads.iter().any(|r| r.eq_ignore_ascii_case(&sig.name));
+    let mut drivers = Vec::new();
+    for assign in input
+        .concurrent_assignments
+        .iter()
+        .filter(|assign| assign.file == sig.file && assign.target.eq_ignore_ascii_case(&sig.name))
+    {
+        if !reads_self(&assign.read_signals) {
+            return None;
+        }
+        drivers.push(assign.value.clone());
+    }
+    for proc in input.processes.iter().filter(|proc| proc.file == sig.file) {
+        for assign in proc
+            .assignments
+            .iter()
+            .filter(|assign| assign.signal.eq_ignore_ascii_case(&sig.name))
+        {
+            if !reads_self(&proc.read_signals) || !mentions(&assign.value, &sig.name) {
+                return None;
+            }
+            drivers.push(assign.value.clone());
+        }
+    }
+    drivers.into_iter().next()
+}
+
+/// Whether `name` appears as a whole word in `text`.
+fn mentions(text: &str, name: &str) -> bool {
+    text.split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .any(|word| word.eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{ConcurrentAssignment, Entity, Process};
+
+    fn signal(name: &str, ty: &str, default: &str, file: &str) -> Signal {
+        Signal {
+            name: name.to_string(),
+            r#type: ty.to_string(),
+            default: default.to_string(),
+            file: file.to_string(),
+            line: 5,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn initial_values_follow_target_and_context() {
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "alu_tb".to_string(),
+            file: "alu_tb.vhd".to_string(),
+            ..Default::default()
+        });
+        input.signals.push(signal(
+            "cnt",
+            "unsigned(7 downto 0)",
+            "(others => '0')",
+            "alu.vhd",
+        ));
+        input
+            .signals
+            .push(signal("sum", "std_logic", "'0'", "alu.vhd"));
+        input
+            .signals
+            .push(signal("clk", "std_logic", "", "alu_tb.vhd"));
+        input
+            .signals
+            .push(signal("done", "boolean", "", "alu_tb.vhd"));
+        input.processes.push(Process {
+            clock_signal: "clk".to_string(),
+            assigned_signals: vec!["cnt".to_string()],
+            file: "alu.vhd".to_string(),
+            ..Default::default()
+        });
+        let assign = |target: &str, value: &str, file: &str| ConcurrentAssignment {
+            target: target.to_string(),
+            value: value.to_string(),
+            read_signals: vec![target.to_string()],
+            file: file.to_string(),
+            ..Default::default()
+        };
+        input
+            .concurrent_assignments
+            .push(assign("sum", "not sum", "alu.vhd"));
+        input
+            .concurrent_assignments
+            .push(assign("clk", "not clk after 5 ns", "alu_tb.vhd"));
+        input
+            .concurrent_assignments
+            .push(assign("done", "not done", "alu_tb.vhd"));
+
+        let found = |input: &Input| {
+            violations(input)
+                .into_iter()
+                .map(|v| (v.rule, v.file))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            found(&input),
+            [
+                ("signal_init_in_rtl".to_string(), "alu.vhd".to_string()),
+                (
+                    "tb_uninitialized_signal".to_string(),
+                    "alu_tb.vhd".to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            violations(&input)[1].message,
+            "Testbench signal 'clk' has no initial value and is only driven from itself ('not clk after 5 ns') - it starts at 'U' and stays 'U'/'X'; declare it with an initial value"
+        );
+
+        input.lint_config.target = "ASIC".to_string();
+        assert_eq!(found(&input).len(), 3);
+
+        input.signals[2].default = "'0'".to_string();
+        input.lint_config.target.clear();
+        assert_eq!(found(&input).len(), 1);
+    }
+}
@@ -1,11 +1,19 @@
 use std::collections::HashMap;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Input {
     #[serde(default)]
     pub standard: String,
+    /// Lint profile (`--profile`): "tb" swaps synthesis rules for testbench
+    /// checks in testbench files; empty is the default RTL profile.
+    #[serde(default)]
+    pub profile: String,
+    /// Entities dead-code analysis starts from (`--top`); empty turns the
+    /// `dead_code` rules off unless testbenches are roots.
+    #[serde(default)]
+    pub top_entities: Vec<String>,
     #[serde(default)]
     pub file_count: usize,
     #[serde(default)]
@@ -44,6 +52,16 @@ pub struct Input {
     pub verification_tags: Vec<VerificationTag>,
     #[serde(default)]
     pub verification_tag_errors: Vec<VerificationTagError>,
+    /// Inline `--@waive` comments (`policy::waivers`).
+    #[serde(default)]
+    pub waivers: Vec<Waiver>,
+    /// Uses of constructs `policy::standards` gates on `standard`.
+    #[serde(default)]
+    pub language_features: Vec<LanguageFeature>,
+    #[serde(default)]
+    pub psl_directives: Vec<PslDirective>,
+    #[serde(default)]
+    pub assertions: Vec<Assertion>,
     #[serde(default)]
     pub instances: Vec<Instance>,
     #[serde(default)]
@@ -53,6 +71,8 @@ pub struct Input {
     #[serde(default)]
     pub concurrent_assignments: Vec<ConcurrentAssignment>,
     #[serde(default)]
+    pub tristate_assignments: Vec<TristateAssignment>,
+    #[serde(default)]
     pub generates: Vec<GenerateStatement>,
     #[serde(default)]
     pub configurations: Vec<Configuration>,
@@ -67,6 +87,10 @@ pub struct Input {
     #[serde(default)]
     pub constant_decls: Vec<ConstantDeclaration>,
     #[serde(default)]
+    pub mode_views: Vec<ModeView>,
+    #[serde(default)]
+    pub attribute_specs: Vec<AttributeSpec>,
+    #[serde(default)]
     pub enum_literals: Vec<String>,
     #[serde(default)]
     pub constants: Vec<String>,
@@ -88,13 +112,173 @@ pub struct Input {
     pub third_party_files: Vec<String>,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct LintConfig {
     #[serde(default)]
     pub rules: HashMap<String, String>,
+    #[serde(default)]
+    pub only_modules: Vec<String>,
+    #[serde(default)]
+    pub skip_modules: Vec<String>,
+    /// Required generic name prefix for `generic_naming_convention` (default "G_").
+    #[serde(default)]
+    pub generic_prefix: String,
+    /// Regex per identifier class (`signal`, `port_in`, `port_out`,
+    /// `constant`, `generic`, `instance`, `entity`, `type`) that names of
+    /// that class must match in full, for `naming_convention_custom`.
+    #[serde(default)]
+    pub naming_patterns: HashMap<String, String>,
+    /// Port group order for `port_ordering` (clock, reset, in, out, inout).
+    #[serde(default)]
+    pub port_order: Vec<String>,
+    /// Preferred vector range direction for `mixed_bit_order` ("downto" or "to").
+    #[serde(default)]
+    pub bit_order: String,
+    /// Preferred reset style for `reset_style_inconsistent` ("sync" or
+    /// "async"); empty follows the project's majority.
+    #[serde(default)]
+    pub reset_style: String,
+    /// Per-rule design contexts ("rtl", "tb") overriding the registry default.
+    #[serde(default)]
+    pub applies_to: HashMap<String, Vec<String>>,
+    /// Run only the registry's fast rules (`--fast`).
+    #[serde(default)]
+    pub fast: bool,
+    /// Fast mode stops after the module that brings the error count to this
+    /// many; 0 means no limit.
+    #[serde(default)]
+    pub error_budget: usize,
+    /// Worker count for sharding intra-entity modules by entity; 0 or 1 runs
+    /// them unsharded.
+    #[serde(default)]
+    pub shard_workers: usize,
+    /// Threads evaluating rule modules; 0 uses one per available core, 1 runs
+    /// them sequentially.
+    #[serde(default)]
+    pub module_workers: usize,
+    /// Skip hierarchy checks on instances inside architectures that no
+    /// configuration or instantiation selects.
+    #[serde(default)]
+    pub strict_binding: bool,
+    /// Treat testbench entities as dead-code roots alongside `top_entities`.
+    #[serde(default)]
+    pub testbench_roots: bool,
+    /// Cap on the elaborated copies of one instance inside for-generates,
+    /// as the hierarchy rules count them; 0 uses the default of 1024.
+    #[serde(default)]
+    pub generate_limit: usize,
+    /// Levels of combinational logic a path may chain before
+    /// `deep_combinational_path` reports it; 0 uses the default of 8.
+    #[serde(default)]
+    pub max_logic_depth: usize,
+    /// State count above which `fsm_missing_encoding` asks for an explicit
+    /// encoding attribute; 0 uses the default of 8.
+    #[serde(default)]
+    pub fsm_encoding_states: usize,
+    /// Implementation target, `asic` or `fpga` (the default when empty);
+    /// `signal_init_in_rtl` flags every initial value for ASICs and
+    /// `metavalue_comparison` reports errors instead of warnings.
+    #[serde(default)]
+    pub target: String,
+    /// FPGA vendor whose primitives and libraries resolve (`xilinx`,
+    /// `intel`, `lattice`), or `none` to report primitives as
+    /// `vendor_primitive`; see `policy::vendor`.
+    #[serde(default)]
+    pub vendor: String,
+    /// File globs whose violations are dropped, as if the files were never linted.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// Entities, architectures or processes that sample clocks on purpose
+    /// (frequency counters, clock-loss detectors); `clock_used_as_data`
+    /// skips them.
+    #[serde(default)]
+    pub clock_monitors: Vec<String>,
+    /// Port name globs per handshake role ("valid", "ready") used to bind
+    /// ready/valid pairs whose directions don't say which is which; a role
+    /// listed here replaces its built-in patterns.
+    #[serde(default)]
+    pub binding_patterns: HashMap<String, Vec<String>>,
+    /// Minimum confidence (0..1) for a name-based binding; below it the pair
+    /// is reported as `ambiguous_construct`. 0 uses the default of 0.75.
+    #[serde(default)]
+    pub binding_confidence: f64,
+    /// Minimum assertions, PSL directives and verification tags per 100
+    /// lines in an architecture with detected constructs, below which
+    /// `low_assertion_density` fires; 0 uses the default of 1.
+    #[serde(default)]
+    pub assertion_density: f64,
+    /// Drop violations below this confidence ("low", "medium", "high");
+    /// empty keeps everything.
+    #[serde(default)]
+    pub min_confidence: String,
+    /// Accepted violations by `baseline::fingerprint`, with how many of each
+    /// to drop (`--baseline`).
+    #[serde(default)]
+    pub baseline: HashMap<String, usize>,
+    /// Inclusive line ranges per file that a diff changed (`--diff`,
+    /// `--changed-lines-from`, see `policy::diff`). When set, only violations
+    /// on those lines, or file-level ones in changed files, are reported.
+    #[serde(default)]
+    pub changed_lines: Option<HashMap<String, Vec<(usize, usize)>>>,
+    /// Date waiver expiry is checked against (`YYYY-MM-DD`); empty uses the
+    /// system clock.
+    #[serde(default)]
+    pub today: String,
+    /// Project-specific checks written as Rhai scripts (`policy::scripting`).
+    #[serde(default)]
+    pub custom_rules: Vec<CustomRule>,
+    /// Declared primary clocks. When set, they replace the clock naming
+    /// heuristics: clocked processes must use one (`unknown_clock_source`)
+    /// and clocks sharing a domain never cross.
+    #[serde(default)]
+    pub clocks: Vec<ClockDecl>,
+    /// Rule settings of nested config files (`rtl/legacy/.vhdllint.yml`),
+    /// which override `rules` for the files below their directory.
+    #[serde(default)]
+    pub directory_rules: Vec<DirectoryRules>,
+}
+
+/// `[rules]` and `enable` of a config file below the project root.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct DirectoryRules {
+    /// Directory of the config file, `/`-separated and spelled like the
+    /// file paths below it, without a leading `./`.
+    #[serde(default)]
+    pub dir: String,
+    /// Rule name -> severity or "off", as in `rules`.
+    #[serde(default)]
+    pub rules: HashMap<String, String>,
+}
+
+/// A primary clock from the project configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct ClockDecl {
+    /// Clock signal name as processes use it.
+    #[serde(default)]
+    pub name: String,
+    /// Period as written (`"10 ns"`); informational.
+    #[serde(default)]
+    pub period: String,
+    /// Synchronous group; empty puts the clock in a domain of its own.
+    #[serde(default)]
+    pub domain: String,
+}
+
+/// A Rhai script whose `check(input)` function returns violations.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct CustomRule {
+    /// Rule name for the script's violations that don't set their own.
+    #[serde(default)]
+    pub id: String,
+    /// Path to the script.
+    #[serde(default)]
+    pub script: String,
+    /// Severity for violations that don't set their own; empty is "warning".
+    #[serde(default)]
+    pub severity: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Entity {
     #[serde(default)]
     pub name: String,
@@ -102,13 +286,19 @@ pub struct Entity {
     pub file: String,
     #[serde(default)]
     pub line: usize,
+    /// 1-based columns of the name on `line` (end exclusive); 0 when
+    /// unknown.
+    #[serde(default)]
+    pub column: usize,
+    #[serde(default)]
+    pub end_column: usize,
     #[serde(default)]
     pub ports: Vec<Port>,
     #[serde(default)]
     pub generics: Vec<GenericDecl>,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Architecture {
     #[serde(default)]
     pub name: String,
@@ -118,9 +308,15 @@ pub struct Architecture {
     pub file: String,
     #[serde(default)]
     pub line: usize,
+    /// 1-based columns of the name on `line` (end exclusive); 0 when
+    /// unknown.
+    #[serde(default)]
+    pub column: usize,
+    #[serde(default)]
+    pub end_column: usize,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Package {
     #[serde(default)]
     pub name: String,
@@ -128,9 +324,15 @@ pub struct Package {
     pub file: String,
     #[serde(default)]
     pub line: usize,
+    /// 1-based columns of the name on `line` (end exclusive); 0 when
+    /// unknown.
+    #[serde(default)]
+    pub column: usize,
+    #[serde(default)]
+    pub end_column: usize,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Component {
     #[serde(default)]
     pub name: String,
@@ -148,7 +350,7 @@ pub struct Component {
     pub generics: Vec<GenericDecl>,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Signal {
     #[serde(default)]
     pub name: String,
@@ -158,13 +360,22 @@ pub struct Signal {
     pub file: String,
     #[serde(default)]
     pub line: usize,
+    /// 1-based columns of the name on `line` (end exclusive); 0 when
+    /// unknown.
+    #[serde(default)]
+    pub column: usize,
+    #[serde(default)]
+    pub end_column: usize,
     #[serde(default)]
     pub in_entity: String,
     #[serde(default)]
     pub width: usize,
+    /// Initial value expression (`:= ...`), empty when there is none.
+    #[serde(default)]
+    pub default: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Port {
     #[serde(default)]
     pub name: String,
@@ -176,13 +387,26 @@ pub struct Port {
     pub default: String,
     #[serde(default)]
     pub line: usize,
+    /// 1-based columns of the name on `line` (end exclusive); 0 when
+    /// unknown.
+    #[serde(default)]
+    pub column: usize,
+    #[serde(default)]
+    pub end_column: usize,
     #[serde(default)]
     pub in_entity: String,
     #[serde(default)]
     pub width: usize,
+    /// Trailing `--` comment on the declaration line, without the dashes.
+    #[serde(default)]
+    pub comment: String,
+    /// Mode view of a VHDL-2019 `view` port, whose direction is "view":
+    /// element directions come from the view, not the port.
+    #[serde(default)]
+    pub view: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct GenericDecl {
     #[serde(default)]
     pub name: String,
@@ -202,7 +426,7 @@ pub struct GenericDecl {
     pub in_component: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct UseClause {
     #[serde(default)]
     pub items: Vec<String>,
@@ -212,7 +436,7 @@ pub struct UseClause {
     pub line: usize,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct LibraryClause {
     #[serde(default)]
     pub libraries: Vec<String>,
@@ -222,7 +446,7 @@ pub struct LibraryClause {
     pub line: usize,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ContextClause {
     #[serde(default)]
     pub name: String,
@@ -232,7 +456,7 @@ pub struct ContextClause {
     pub line: usize,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Dependency {
     #[serde(default)]
     pub source: String,
@@ -246,7 +470,7 @@ pub struct Dependency {
     pub resolved: bool,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Symbol {
     #[serde(default)]
     pub name: String,
@@ -258,7 +482,7 @@ pub struct Symbol {
     pub line: usize,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct FileInfo {
     #[serde(default)]
     pub path: String,
@@ -266,9 +490,13 @@ pub struct FileInfo {
     pub library: String,
     #[serde(default)]
     pub is_third_party: bool,
+    /// Standard the file is analyzed as when it differs from
+    /// `Input.standard` (a project source set's `standard`).
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub standard: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Scope {
     #[serde(default)]
     pub name: String,
@@ -284,7 +512,7 @@ pub struct Scope {
     pub path: Vec<String>,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SymbolDef {
     #[serde(default)]
     pub name: String,
@@ -298,7 +526,7 @@ pub struct SymbolDef {
     pub scope: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct NameUse {
     #[serde(default)]
     pub name: String,
@@ -314,7 +542,7 @@ pub struct NameUse {
     pub context: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct VerificationBlock {
     #[serde(default)]
     pub label: String,
@@ -328,7 +556,7 @@ pub struct VerificationBlock {
     pub in_arch: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct VerificationTag {
     #[serde(default)]
     pub id: String,
@@ -346,7 +574,7 @@ pub struct VerificationTag {
     pub in_arch: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct VerificationTagError {
     #[serde(default)]
     pub file: String,
@@ -360,12 +588,92 @@ pub struct VerificationTagError {
     pub in_arch: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+/// An inline `--@waive rule=<rules> [expires=YYYY-MM-DD]` comment. It
+/// waives the rules' violations on `target_line`: the comment's own line when
+/// it trails code, otherwise the next line of code.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct Waiver {
+    #[serde(default)]
+    pub rules: Vec<String>,
+    /// Empty when the waiver never expires.
+    #[serde(default)]
+    pub expires: String,
+    #[serde(default)]
+    pub file: String,
+    #[serde(default)]
+    pub line: usize,
+    #[serde(default)]
+    pub target_line: usize,
+    #[serde(default)]
+    pub raw: String,
+}
+
+/// One use of a version-dependent construct; `feature` is one of the
+/// `policy::standards` constants (`block_comment`, `matching_case`).
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct LanguageFeature {
+    #[serde(default)]
+    pub feature: String,
+    #[serde(default)]
+    pub file: String,
+    #[serde(default)]
+    pub line: usize,
+}
+
+/// A concurrent PSL `assert`/`assume`/`cover`/`restrict` directive. Labels
+/// spelling a check id with `_` for `.` (`fsm_legal_state:`) map the
+/// directive onto the verification check registry.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PslDirective {
+    /// "assert", "assume", "cover" or "restrict".
+    #[serde(default)]
+    pub kind: String,
+    #[serde(default)]
+    pub label: String,
+    /// Signals the property reads.
+    #[serde(default)]
+    pub signals: Vec<String>,
+    #[serde(default)]
+    pub file: String,
+    #[serde(default)]
+    pub line: usize,
+    #[serde(default)]
+    pub in_arch: String,
+}
+
+/// A plain VHDL `assert` or `report` statement, concurrent or inside a
+/// process. PSL assertions are `psl_directives` instead.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Assertion {
+    /// "assert" or "report".
+    #[serde(default)]
+    pub kind: String,
+    /// Lowercase severity clause ("note", "warning", "error", "failure");
+    /// empty when the statement has none.
+    #[serde(default)]
+    pub severity: String,
+    #[serde(default)]
+    pub file: String,
+    #[serde(default)]
+    pub line: usize,
+    #[serde(default)]
+    pub in_arch: String,
+    #[serde(default)]
+    pub in_process: String,
+    /// Line of the enclosing process; 0 for concurrent assertions.
+    #[serde(default)]
+    pub process_line: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Instance {
     #[serde(default)]
     pub name: String,
     #[serde(default)]
     pub target: String,
+    /// Explicit architecture from `entity work.e(arch)`; empty when unqualified.
+    #[serde(default)]
+    pub target_arch: String,
     #[serde(default)]
     pub port_map: HashMap<String, String>,
     #[serde(default)]
@@ -376,11 +684,17 @@ pub struct Instance {
     pub file: String,
     #[serde(default)]
     pub line: usize,
+    /// 1-based columns of the label on `line` (end exclusive); 0 when
+    /// unknown.
+    #[serde(default)]
+    pub column: usize,
+    #[serde(default)]
+    pub end_column: usize,
     #[serde(default)]
     pub in_arch: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Association {
     #[serde(default)]
     pub kind: String,
@@ -402,7 +716,7 @@ pub struct Association {
     pub position_index: usize,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct CaseStatement {
     #[serde(default)]
     pub expression: String,
@@ -420,14 +734,36 @@ pub struct CaseStatement {
     pub in_arch: String,
     #[serde(default)]
     pub is_complete: bool,
+    /// Alternatives in source order.
+    #[serde(default)]
+    pub arms: Vec<CaseArm>,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CaseArm {
+    /// Choices of the alternative; `others` for the catch-all.
+    #[serde(default)]
+    pub choices: Vec<String>,
+    #[serde(default)]
+    pub line: usize,
+    /// Path element (`case@<byte>`) the alternative adds to the `branch` of
+    /// the assignments inside it.
+    #[serde(default)]
+    pub branch: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ConcurrentAssignment {
     #[serde(default)]
     pub target: String,
+    /// Full target text including slices/fields (e.g. `bus(3 downto 0)`).
+    #[serde(default)]
+    pub target_full: String,
     #[serde(default)]
     pub read_signals: Vec<String>,
+    /// RHS text of plain simple assignments; empty otherwise.
+    #[serde(default)]
+    pub value: String,
     #[serde(default)]
     pub file: String,
     #[serde(default)]
@@ -442,7 +778,27 @@ pub struct ConcurrentAssignment {
     pub generate_label: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+/// A signal assignment, concurrent or inside a process, whose value contains
+/// a 'Z' literal: a tri-state driver.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TristateAssignment {
+    #[serde(default)]
+    pub target: String,
+    /// RHS text.
+    #[serde(default)]
+    pub value: String,
+    #[serde(default)]
+    pub file: String,
+    #[serde(default)]
+    pub line: usize,
+    #[serde(default)]
+    pub in_arch: String,
+    /// Label of the containing process; empty for concurrent assignments.
+    #[serde(default)]
+    pub in_process: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Comparison {
     #[serde(default)]
     pub left_operand: String,
@@ -468,7 +824,7 @@ pub struct Comparison {
     pub in_process: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ArithmeticOp {
     #[serde(default)]
     pub operator: String,
@@ -490,7 +846,7 @@ pub struct ArithmeticOp {
     pub in_arch: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SignalDep {
     #[serde(default)]
     pub source: String,
@@ -508,7 +864,7 @@ pub struct SignalDep {
     pub in_arch: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct CDCCrossing {
     #[serde(default)]
     pub signal: String,
@@ -534,7 +890,7 @@ pub struct CDCCrossing {
     pub in_arch: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SignalUsage {
     #[serde(default)]
     pub signal: String,
@@ -554,7 +910,7 @@ pub struct SignalUsage {
     pub line: usize,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Process {
     #[serde(default)]
     pub label: String,
@@ -586,15 +942,74 @@ pub struct Process {
     pub function_calls: Vec<FunctionCall>,
     #[serde(default)]
     pub wait_statements: Vec<WaitStatement>,
+    /// Signal assignments in source order.
+    #[serde(default)]
+    pub assignments: Vec<SequentialAssignment>,
+    /// If statements in source order, outer before nested.
+    #[serde(default)]
+    pub if_statements: Vec<IfStatement>,
     #[serde(default)]
     pub file: String,
     #[serde(default)]
     pub line: usize,
+    /// 1-based columns of the label on `line` (end exclusive); 0 when
+    /// unknown.
+    #[serde(default)]
+    pub column: usize,
+    #[serde(default)]
+    pub end_column: usize,
     #[serde(default)]
     pub in_arch: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SequentialAssignment {
+    #[serde(default)]
+    pub target: String,
+    #[serde(default)]
+    pub signal: String,
+    /// RHS text of plain assignments; empty for waveforms with delays.
+    #[serde(default)]
+    pub value: String,
+    #[serde(default)]
+    pub line: usize,
+    /// Enclosing branch path; "" is the process body. A prefix of another
+    /// assignment's branch executes whenever that assignment does.
+    #[serde(default)]
+    pub branch: String,
+    /// Wait statements executed before this assignment.
+    #[serde(default)]
+    pub segment: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct IfStatement {
+    /// Branch path the statement sits in, as in `SequentialAssignment::branch`.
+    #[serde(default)]
+    pub branch: String,
+    #[serde(default)]
+    pub line: usize,
+    /// Branches in source order; the last one is the `else` when `has_else`.
+    #[serde(default)]
+    pub arms: Vec<IfArm>,
+    #[serde(default)]
+    pub has_else: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct IfArm {
+    #[serde(default)]
+    pub line: usize,
+    /// Path element (`if@<byte>.<index>`) the branch adds to the `branch` of
+    /// the assignments inside it.
+    #[serde(default)]
+    pub branch: String,
+    /// Condition text; empty for the `else` branch.
+    #[serde(default)]
+    pub condition: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct VariableDecl {
     #[serde(default)]
     pub name: String,
@@ -604,17 +1019,21 @@ pub struct VariableDecl {
     pub line: usize,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ProcedureCall {
     #[serde(default)]
     pub name: String,
+    /// Dotted name as written (`std.env.stop`); `name` may hold only its
+    /// first segment.
+    #[serde(default)]
+    pub full_name: String,
     #[serde(default)]
     pub line: usize,
     #[serde(default)]
     pub in_process: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct FunctionCall {
     #[serde(default)]
     pub name: String,
@@ -624,15 +1043,23 @@ pub struct FunctionCall {
     pub in_process: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct WaitStatement {
+    /// Signals after `on`, the `until` condition and the `for` timeout; all
+    /// empty for a bare `wait;`.
+    #[serde(default)]
+    pub on_signals: Vec<String>,
+    #[serde(default)]
+    pub until_expr: String,
+    #[serde(default)]
+    pub for_expr: String,
     #[serde(default)]
     pub line: usize,
     #[serde(default)]
     pub in_process: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct GenerateStatement {
     #[serde(default)]
     pub label: String,
@@ -668,19 +1095,42 @@ pub struct GenerateStatement {
     pub file_scope: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Configuration {
     #[serde(default)]
     pub name: String,
     #[serde(default)]
     pub entity_name: String,
+    /// Architecture named by the top-level block configuration.
+    #[serde(default)]
+    pub architecture: String,
+    #[serde(default)]
+    pub bindings: Vec<ConfigurationBinding>,
     #[serde(default)]
     pub file: String,
     #[serde(default)]
     pub line: usize,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ConfigurationBinding {
+    /// Block configuration nesting the binding sits in, architecture first
+    /// (e.g. `["rtl", "gen(0)"]`).
+    #[serde(default)]
+    pub scope: Vec<String>,
+    #[serde(default)]
+    pub instance: String,
+    #[serde(default)]
+    pub component: String,
+    #[serde(default)]
+    pub entity: String,
+    #[serde(default)]
+    pub architecture: String,
+    #[serde(default)]
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct TypeDeclaration {
     #[serde(default)]
     pub name: String,
@@ -700,11 +1150,14 @@ pub struct TypeDeclaration {
     pub fields: Vec<RecordField>,
     #[serde(default)]
     pub element_type: String,
+    /// First index constraint of an array type, e.g. `0 to DEPTH-1`.
+    #[serde(default)]
+    pub index_range: String,
     #[serde(default)]
     pub unconstrained: bool,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct RecordField {
     #[serde(default)]
     pub name: String,
@@ -712,7 +1165,7 @@ pub struct RecordField {
     pub r#type: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SubtypeDeclaration {
     #[serde(default)]
     pub name: String,
@@ -730,7 +1183,7 @@ pub struct SubtypeDeclaration {
     pub in_package: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct FunctionDeclaration {
     #[serde(default)]
     pub name: String,
@@ -750,9 +1203,12 @@ pub struct FunctionDeclaration {
     pub in_arch: String,
     #[serde(default)]
     pub in_package: String,
+    /// Names read in the body that are neither parameters nor locals.
+    #[serde(default)]
+    pub read_signals: Vec<String>,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ProcedureDeclaration {
     #[serde(default)]
     pub name: String,
@@ -768,9 +1224,12 @@ pub struct ProcedureDeclaration {
     pub in_arch: String,
     #[serde(default)]
     pub in_package: String,
+    /// Signal assignment targets in the body.
+    #[serde(default)]
+    pub assigned_signals: Vec<String>,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SubprogramParameter {
     #[serde(default)]
     pub name: String,
@@ -782,7 +1241,7 @@ pub struct SubprogramParameter {
     pub line: usize,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ConstantDeclaration {
     #[serde(default)]
     pub name: String,
@@ -799,3 +1258,61 @@ pub struct ConstantDeclaration {
     #[serde(default)]
     pub in_arch: String,
 }
+
+/// VHDL-2019 mode view declaration (`view name of record_type is ... end
+/// view;`): per-element modes for ports declared with that view.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ModeView {
+    #[serde(default)]
+    pub name: String,
+    /// Record (or array of record) type the view applies to.
+    #[serde(default)]
+    pub r#type: String,
+    #[serde(default)]
+    pub elements: Vec<ModeViewElement>,
+    #[serde(default)]
+    pub file: String,
+    #[serde(default)]
+    pub line: usize,
+    #[serde(default)]
+    pub in_package: String,
+    #[serde(default)]
+    pub in_arch: String,
+}
+
+/// Attribute specification (`attribute fsm_encoding of state : signal is
+/// "one_hot";`).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AttributeSpec {
+    #[serde(default)]
+    pub name: String,
+    /// Named items, or `others` / `all`.
+    #[serde(default)]
+    pub targets: Vec<String>,
+    /// Entity class: `signal`, `type`, `entity`, ...
+    #[serde(default)]
+    pub class: String,
+    /// Value expression text, quotes included.
+    #[serde(default)]
+    pub value: String,
+    #[serde(default)]
+    pub file: String,
+    #[serde(default)]
+    pub line: usize,
+    #[serde(default)]
+    pub in_package: String,
+    #[serde(default)]
+    pub in_arch: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ModeViewElement {
+    #[serde(default)]
+    pub name: String,
+    /// "in", "out", "inout", "buffer" or "view" for a nested view.
+    #[serde(default)]
+    pub direction: String,
+    /// Nested mode view when `direction` is "view".
+    #[serde(default)]
+    pub view: String,
+}
@@ -1,14 +1,20 @@
 use crate::policy::helpers::valid_instance_prefix;
-use crate::policy::input::Input;
+use crate::policy::input::{Entity, Input, Instance};
 use crate::policy::result::Violation;
 
 pub fn violations(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     out.extend(positional_mapping(input));
     out.extend(instance_naming_convention(input));
+    out.extend(mixed_association_style(input));
+    out.extend(positional_count_mismatch(input));
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &["entities", "instances"]
+}
+
 pub fn positional_mapping(input: &Input) -> Vec<Violation> {
     input
         .instances
@@ -23,6 +29,7 @@ pub fn positional_mapping(input: &Input) -> Vec<Violation> {
                 "Instance '{}' uses positional port mapping - use named mapping for safety",
                 inst.name
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -41,13 +48,94 @@ pub fn instance_naming_convention(input: &Input) -> Vec<Violation> {
                 "Instance '{}' should use a standard prefix (u_, i_, or inst_)",
                 inst.name
             ),
+            ..Default::default()
         })
         .collect()
 }
 
+/// Mixing positional and named associations in one map is legal (positional
+/// first) but makes it easy to shift every positional actual by one port.
+pub fn mixed_association_style(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for inst in &input.instances {
+        for kind in ["generic", "port"] {
+            let mut assocs = inst.associations.iter().filter(|a| a.kind == kind);
+            let positional = assocs.clone().filter(|a| a.is_positional).count();
+            if positional == 0 || !assocs.any(|a| !a.is_positional) {
+                continue;
+            }
+            out.push(Violation {
+                rule: "mixed_association_style".to_string(),
+                severity: "warning".to_string(),
+                file: inst.file.clone(),
+                line: inst.line,
+                message: format!(
+                    "Instance '{}' mixes {} positional and named {} associations - use named association throughout",
+                    inst.name, positional, kind
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+/// Fully positional port maps whose actual count differs from the target
+/// entity's port count: extra actuals don't elaborate, missing ones silently
+/// leave trailing ports unconnected.
+pub fn positional_count_mismatch(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for inst in &input.instances {
+        let ports: Vec<_> = inst
+            .associations
+            .iter()
+            .filter(|a| a.kind == "port")
+            .collect();
+        if ports.is_empty() || ports.iter().any(|a| !a.is_positional) {
+            continue;
+        }
+        let Some(entity) = target_entity(input, inst) else {
+            continue;
+        };
+        let expected = entity.ports.len();
+        if ports.len() == expected {
+            continue;
+        }
+        let severity = if ports.len() > expected {
+            "error"
+        } else {
+            "warning"
+        };
+        out.push(Violation {
+            rule: "positional_count_mismatch".to_string(),
+            severity: severity.to_string(),
+            file: inst.file.clone(),
+            line: inst.line,
+            message: format!(
+                "Instance '{}' has {} positional port actual(s) but entity '{}' has {} port(s)",
+                inst.name,
+                ports.len(),
+                entity.name,
+                expected
+            ),
+            ..Default::default()
+        });
+    }
+    out
+}
+
+fn target_entity<'a>(input: &'a Input, inst: &Instance) -> Option<&'a Entity> {
+    let target = inst.target.to_ascii_lowercase();
+    input.entities.iter().find(|entity| {
+        let name = entity.name.to_ascii_lowercase();
+        target == name || target.ends_with(&format!(".{}", name))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::policy::input::{Association, Port};
 
     fn input_with_instance(name: &str, port_map_len: usize) -> Input {
         let mut input = Input::default();
@@ -115,4 +203,68 @@ mod tests {
         let violations = instance_naming_convention(&input);
         assert!(violations.is_empty());
     }
+
+    fn assoc(kind: &str, formal: &str, position_index: usize) -> Association {
+        Association {
+            kind: kind.to_string(),
+            formal: formal.to_string(),
+            actual: format!("a{}", position_index),
+            is_positional: formal.is_empty(),
+            position_index,
+            ..Default::default()
+        }
+    }
+
+    fn input_with_associations(associations: Vec<Association>, port_count: usize) -> Input {
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "child".to_string(),
+            ports: (0..port_count)
+                .map(|idx| Port {
+                    name: format!("p{}", idx),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        });
+        input.instances.push(Instance {
+            name: "u_child".to_string(),
+            target: "work.child".to_string(),
+            associations,
+            file: "top.vhd".to_string(),
+            line: 20,
+            ..Default::default()
+        });
+        input
+    }
+
+    #[test]
+    fn mixed_association_style_flags_per_kind() {
+        let input = input_with_associations(
+            vec![
+                assoc("generic", "", 0),
+                assoc("generic", "", 1),
+                assoc("port", "", 0),
+                assoc("port", "p1", 1),
+            ],
+            2,
+        );
+        let violations = mixed_association_style(&input);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("named port"));
+    }
+
+    #[test]
+    fn positional_count_mismatch_compares_with_entity_ports() {
+        let input = input_with_associations(vec![assoc("port", "", 0), assoc("port", "", 1)], 3);
+        let violations = positional_count_mismatch(&input);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, "warning");
+
+        let input = input_with_associations(vec![assoc("port", "", 0), assoc("port", "", 1)], 1);
+        assert_eq!(positional_count_mismatch(&input)[0].severity, "error");
+
+        let input = input_with_associations(vec![assoc("port", "", 0), assoc("port", "", 1)], 2);
+        assert!(positional_count_mismatch(&input).is_empty());
+    }
 }
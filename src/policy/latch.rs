@@ -1,6 +1,6 @@
 use crate::policy::helpers;
-use crate::policy::input::Input;
-use crate::policy::result::Violation;
+use crate::policy::input::{CaseStatement, Input};
+use crate::policy::result::{Fix, FixEdit, Violation};
 
 pub fn violations(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
@@ -19,6 +19,10 @@ pub fn optional_violations(input: &Input) -> Vec<Violation> {
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &["case_statements", "concurrent_assignments", "processes", "signals", "types"]
+}
+
 fn incomplete_case_latch(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     for cs in &input.case_statements {
@@ -35,6 +39,8 @@ fn incomplete_case_latch(input: &Input) -> Vec<Violation> {
                     "Case statement on '{}' missing 'when others =>' - may infer latch",
                     cs.expression
                 ),
+                fixes: vec![others_fix(cs)],
+                ..Default::default()
             });
             continue;
         }
@@ -52,12 +58,24 @@ fn incomplete_case_latch(input: &Input) -> Vec<Violation> {
                     "Case statement on '{}' in combinational process '{}' missing 'when others =>' - will infer latch",
                     cs.expression, proc.label
                 ),
+                fixes: vec![others_fix(cs)],
+                ..Default::default()
             });
         }
     }
     out
 }
 
+fn others_fix(cs: &CaseStatement) -> Fix {
+    Fix {
+        file: cs.file.clone(),
+        line: cs.line,
+        edit: FixEdit::CaseAlternative {
+            text: "when others => null;".to_string(),
+        },
+    }
+}
+
 fn enum_case_incomplete(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     for cs in &input.case_statements {
@@ -105,6 +123,7 @@ fn enum_case_incomplete(input: &Input) -> Vec<Violation> {
                     "Case statement on enum '{}' missing values {:?} in combinational process - will infer latch",
                     cs.expression, missing
                 ),
+                ..Default::default()
             });
         }
     }
@@ -140,6 +159,7 @@ fn combinational_incomplete_assignment(input: &Input) -> Vec<Violation> {
                         "Signal '{}' in combinational process '{}' is read as well as written - verify all code paths assign it to avoid latch",
                         assigned, proc.label
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -165,6 +185,7 @@ fn conditional_assignment_check(input: &Input) -> Vec<Violation> {
                 "Conditional assignment to '{}' - verify all conditions have an 'else' clause to avoid latch inference",
                 ca.target
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -183,6 +204,7 @@ fn selected_assignment_check(input: &Input) -> Vec<Violation> {
                 "Selected assignment to '{}' - verify 'when others' is present to avoid latch inference",
                 ca.target
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -210,6 +232,7 @@ fn many_signals_no_default(input: &Input) -> Vec<Violation> {
                 proc.label,
                 proc.assigned_signals.len()
             ),
+            ..Default::default()
         });
     }
     out
@@ -232,6 +255,7 @@ fn fsm_no_reset(input: &Input) -> Vec<Violation> {
                         "State signal '{}' in process '{}' has no reset - initial state undefined",
                         assigned, proc.label
                     ),
+                    ..Default::default()
                 });
             }
         }
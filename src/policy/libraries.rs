@@ -0,0 +1,102 @@
+//! Project library model: every file in `input.files` belongs to one library
+//! (`work` when unset), and library names in selected names resolve against
+//! it. `work` always means the library of the file the name appears in, so
+//! `work.pkg.f` in a file of library `dsp` refers to `dsp.pkg.f`.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::policy::input::Input;
+use crate::policy::vendor;
+
+/// Libraries every tool provides without files in the project.
+const PREDEFINED_LIBRARIES: &[&str] = &["std", "ieee"];
+
+pub struct LibraryMap {
+    file_libraries: HashMap<String, String>,
+    libraries: HashSet<String>,
+    /// Libraries of the configured `vendor`, known without project files.
+    vendor_libraries: &'static [String],
+}
+
+impl LibraryMap {
+    pub fn new(input: &Input) -> Self {
+        let mut file_libraries = HashMap::new();
+        let mut libraries = HashSet::new();
+        for file in &input.files {
+            let lib = if file.library.is_empty() {
+                "work".to_string()
+            } else {
+                file.library.to_ascii_lowercase()
+            };
+            libraries.insert(lib.clone());
+            file_libraries.insert(file.path.clone(), lib);
+        }
+        Self {
+            file_libraries,
+            libraries,
+            vendor_libraries: vendor::libraries(input),
+        }
+    }
+
+    /// Library `file` is compiled into, lowercase.
+    pub fn library_of(&self, file: &str) -> String {
+        self.file_libraries
+            .get(file)
+            .cloned()
+            .unwrap_or_else(|| "work".to_string())
+    }
+
+    /// Library a name written in `file` refers to: `work` is the file's own.
+    pub fn resolve(&self, file: &str, library: &str) -> String {
+        let lib = library.trim().to_ascii_lowercase();
+        if lib == "work" {
+            self.library_of(file)
+        } else {
+            lib
+        }
+    }
+
+    /// Whether any project file is mapped to `library` (resolved name).
+    pub fn has_files(&self, library: &str) -> bool {
+        self.libraries.contains(library) || (self.libraries.is_empty() && library == "work")
+    }
+
+    /// Whether `library` (resolved name) can be referenced: it has files, is
+    /// predefined or comes with the configured vendor's tools.
+    pub fn is_known(&self, library: &str) -> bool {
+        self.has_files(library)
+            || PREDEFINED_LIBRARIES.contains(&library)
+            || self.vendor_libraries.iter().any(|lib| lib == library)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::FileInfo;
+
+    #[test]
+    fn work_resolves_to_the_referencing_files_library() {
+        let mut input = Input::default();
+        for (path, library) in [("dsp/fir.vhd", "DSP"), ("top.vhd", "")] {
+            input.files.push(FileInfo {
+                path: path.to_string(),
+                library: library.to_string(),
+                ..Default::default()
+            });
+        }
+        let map = LibraryMap::new(&input);
+        assert_eq!(map.library_of("dsp/fir.vhd"), "dsp");
+        assert_eq!(map.library_of("top.vhd"), "work");
+        assert_eq!(map.resolve("dsp/fir.vhd", "WORK"), "dsp");
+        assert_eq!(map.resolve("top.vhd", "dsp"), "dsp");
+        assert!(map.has_files("dsp") && map.has_files("work"));
+        assert!(map.is_known("ieee") && !map.has_files("ieee"));
+        assert!(!map.is_known("unisim"));
+        input.lint_config.vendor = "Xilinx".to_string();
+        assert!(LibraryMap::new(&input).is_known("unisim"));
+
+        let empty = LibraryMap::new(&Input::default());
+        assert!(empty.has_files("work") && !empty.has_files("dsp"));
+    }
+}
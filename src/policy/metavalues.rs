@@ -0,0 +1,141 @@
+//! Comparisons against metavalues. `std_ulogic` has nine values but hardware
+//! only two, so synthesis folds `a = 'X'` (or `'U'`, `'W'`, `'Z'`) to false
+//! and `/=` to true: the check only ever exists in simulation. `std_match`
+//! has the same blind spot, since only `'-'` is a don't care there; a literal
+//! with `'X'` never matches. The opposite mistake is a `'-'` in an `=`
+//! literal, which compares against the character and not a don't care.
+//! Violations are warnings for `lint_config.target = "fpga"` (the default)
+//! and errors for `asic`, where X-pessimism differences between RTL and
+//! gate-level simulation are costly to debug.
+
+use crate::policy::input::{Comparison, Input};
+use crate::policy::result::Violation;
+
+/// Values synthesis can't produce or test for.
+const METAVALUES: &[char] = &['U', 'X', 'W', 'Z'];
+
+pub fn violations(input: &Input) -> Vec<Violation> {
+    let severity = if input.lint_config.target.eq_ignore_ascii_case("asic") {
+        "error"
+    } else {
+        "warning"
+    };
+    let mut out = Vec::new();
+    for cmp in input.comparisons.iter().filter(|cmp| cmp.is_literal) {
+        let Some(message) = metavalue_message(cmp) else {
+            continue;
+        };
+        out.push(Violation {
+            rule: "metavalue_comparison".to_string(),
+            severity: severity.to_string(),
+            file: cmp.file.clone(),
+            line: cmp.line,
+            message,
+            ..Default::default()
+        });
+    }
+    out
+}
+
+pub fn reads() -> &'static [&'static str] {
+    &["comparisons", "lint_config"]
+}
+
+fn metavalue_message(cmp: &Comparison) -> Option<String> {
+    let digits = literal_digits(&cmp.literal_value)?;
+    let metavalue = digits.chars().find(|c| METAVALUES.contains(c));
+    let operator = cmp.operator.trim().to_ascii_lowercase();
+    match (operator.as_str(), metavalue) {
+        ("std_match", Some(value)) => Some(format!(
+            "std_match({}, {}) never matches: '{}' only equals itself in simulation and std_match treats only '-' as don't care - use '-'",
+            cmp.left_operand, cmp.literal_value, value
+        )),
+        ("=" | "/=" | "?=" | "?/=", Some(value)) => Some(format!(
+            "'{} {} {}' compares against metavalue '{}' - synthesis folds it to a constant, so the check only exists in simulation; use is_x() in a testbench or assertion instead",
+            cmp.left_operand, cmp.operator, cmp.literal_value, value
+        )),
+        ("=" | "/=", None) if digits.contains('-') => Some(format!(
+            "'{} {} {}' compares against '-' as a character, not a don't care - use std_match() or '?{}'",
+            cmp.left_operand,
+            cmp.operator,
+            cmp.literal_value,
+            cmp.operator.trim()
+        )),
+        _ => None,
+    }
+}
+
+/// The characters of a character, string or bit string literal, uppercased
+/// for bit strings with a base (whose digits are case-insensitive). `None`
+/// for numbers and anything else.
+fn literal_digits(literal: &str) -> Option<String> {
+    let literal = literal.trim();
+    if let Some(inner) = literal
+        .strip_prefix('\'')
+        .and_then(|rest| rest.strip_suffix('\''))
+    {
+        return (inner.chars().count() == 1).then(|| inner.to_string());
+    }
+    let start = literal.find('"')?;
+    let end = literal.rfind('"')?;
+    if end <= start {
+        return None;
+    }
+    let inner = &literal[start + 1..end];
+    if start == 0 {
+        Some(inner.to_string())
+    } else {
+        Some(inner.to_ascii_uppercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compare(operator: &str, literal: &str) -> Comparison {
+        Comparison {
+            left_operand: "opcode".to_string(),
+            operator: operator.to_string(),
+            right_operand: literal.to_string(),
+            is_literal: true,
+            literal_value: literal.to_string(),
+            file: "dec.vhd".to_string(),
+            line: 14,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn flags_metavalues_and_literal_dont_cares() {
+        let mut input = Input::default();
+        for (operator, literal) in [
+            ("=", "'X'"),
+            ("std_match", "\"10X1\""),
+            ("=", "\"10-1\""),
+            ("?=", "\"10-1\""),
+            ("std_match", "\"10-1\""),
+            ("=", "x\"F0\""),
+            ("=", "\"10x1\""),
+            ("/=", "b\"z0\""),
+        ] {
+            input.comparisons.push(compare(operator, literal));
+        }
+
+        let out = violations(&input);
+        assert_eq!(out.len(), 4);
+        assert!(out.iter().all(|v| v.severity == "warning"));
+        assert_eq!(
+            out[1].message,
+            "std_match(opcode, \"10X1\") never matches: 'X' only equals itself in simulation and std_match treats only '-' as don't care - use '-'"
+        );
+        assert_eq!(
+            out[2].message,
+            "'opcode = \"10-1\"' compares against '-' as a character, not a don't care - use std_match() or '?='"
+        );
+        assert!(out[3].message.contains("metavalue 'Z'"));
+
+        input.lint_config.target = "asic".to_string();
+        assert!(violations(&input).iter().all(|v| v.severity == "error"));
+    }
+}
@@ -0,0 +1,317 @@
+//! Design metrics: per-architecture size and complexity numbers (processes,
+//! signals, instance fan-out, combinational depth, cyclomatic-like
+//! complexity), rolled up per file. Reported as `Result.metrics` and, with
+//! `vhdl_policy --emit-metrics`, as CSV for trending over time.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::policy::helpers;
+use crate::policy::input::{Architecture, Input};
+use crate::policy::result::{ArchitectureMetrics, FileMetrics, Metrics};
+use crate::policy::timing;
+
+/// Architectures in file and line order, files in path order. Third-party
+/// files are left out.
+pub fn compute(input: &Input) -> Metrics {
+    let mut architectures: Vec<ArchitectureMetrics> = input
+        .architectures
+        .iter()
+        .filter(|arch| !helpers::is_third_party_file(input, &arch.file))
+        .map(|arch| architecture_metrics(input, arch))
+        .collect();
+    architectures.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+
+    let mut files: BTreeMap<&str, FileMetrics> = BTreeMap::new();
+    for entity in &input.entities {
+        if helpers::is_third_party_file(input, &entity.file) {
+            continue;
+        }
+        file_entry(&mut files, &entity.file).entities += 1;
+    }
+    for arch in &architectures {
+        let file = file_entry(&mut files, &arch.file);
+        file.architectures += 1;
+        file.processes += arch.processes;
+        file.signals += arch.signals;
+        file.instances += arch.instances;
+        file.max_comb_depth = file.max_comb_depth.max(arch.max_comb_depth);
+        file.complexity += arch.complexity;
+    }
+    Metrics {
+        files: files.into_values().collect(),
+        architectures,
+    }
+}
+
+/// Metrics as CSV: one `file` row per file, then one `architecture` row per
+/// architecture. File rows leave `entity`, `architecture` and `line` empty.
+pub fn csv(metrics: &Metrics) -> String {
+    let mut out = String::from(
+        "kind,file,entity,architecture,line,entities,architectures,processes,signals,\
+         instances,max_comb_depth,complexity\n",
+    );
+    for file in &metrics.files {
+        out.push_str(&format!(
+            "file,{},,,,{},{},{},{},{},{},{}\n",
+            csv_field(&file.file),
+            file.entities,
+            file.architectures,
+            file.processes,
+            file.signals,
+            file.instances,
+            file.max_comb_depth,
+            file.complexity
+        ));
+    }
+    for arch in &metrics.architectures {
+        out.push_str(&format!(
+            "architecture,{},{},{},{},,,{},{},{},{},{}\n",
+            csv_field(&arch.file),
+            csv_field(&arch.entity),
+            csv_field(&arch.architecture),
+            arch.line,
+            arch.processes,
+            arch.signals,
+            arch.instances,
+            arch.max_comb_depth,
+            arch.complexity
+        ));
+    }
+    out
+}
+
+fn file_entry<'a, 'b>(
+    files: &'b mut BTreeMap<&'a str, FileMetrics>,
+    file: &'a str,
+) -> &'b mut FileMetrics {
+    files.entry(file).or_insert_with(|| FileMetrics {
+        file: file.to_string(),
+        ..Default::default()
+    })
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn architecture_metrics(input: &Input, arch: &Architecture) -> ArchitectureMetrics {
+    let in_arch =
+        |file: &str, name: &str| file == arch.file && name.eq_ignore_ascii_case(&arch.name);
+    let processes: Vec<_> = input
+        .processes
+        .iter()
+        .filter(|proc| in_arch(&proc.file, &proc.in_arch))
+        .collect();
+
+    let mut decisions = 0;
+    for proc in &processes {
+        decisions += proc
+            .if_statements
+            .iter()
+            .map(|stmt| stmt.arms.len().max(1))
+            .sum::<usize>();
+    }
+    decisions += input
+        .case_statements
+        .iter()
+        .filter(|cs| in_arch(&cs.file, &cs.in_arch))
+        .map(|cs| {
+            let alternatives = if cs.arms.is_empty() {
+                cs.choices.len() + usize::from(cs.has_others)
+            } else {
+                cs.arms.len()
+            };
+            alternatives.saturating_sub(1)
+        })
+        .sum::<usize>();
+    decisions += input
+        .concurrent_assignments
+        .iter()
+        .filter(|ca| in_arch(&ca.file, &ca.in_arch))
+        .filter(|ca| matches!(ca.kind.as_str(), "conditional" | "selected"))
+        .count();
+
+    ArchitectureMetrics {
+        entity: arch.entity_name.clone(),
+        architecture: arch.name.clone(),
+        file: arch.file.clone(),
+        line: arch.line,
+        processes: processes.len(),
+        signals: input
+            .signals
+            .iter()
+            .filter(|sig| in_arch(&sig.file, &sig.in_entity))
+            .count(),
+        instances: input
+            .instances
+            .iter()
+            .filter(|inst| in_arch(&inst.file, &inst.in_arch))
+            .count(),
+        max_comb_depth: comb_depth(input, arch),
+        complexity: 1 + decisions,
+    }
+}
+
+/// Longest path, in edges, through the architecture's combinational signal
+/// dependencies. Feedback loops are cut where the search re-enters a signal
+/// still on its path.
+fn comb_depth(input: &Input, arch: &Architecture) -> usize {
+    let mut edges: HashMap<String, Vec<(String, usize)>> = HashMap::new();
+    for dep in &input.signal_deps {
+        if dep.is_sequential
+            || dep.file != arch.file
+            || !dep.in_arch.eq_ignore_ascii_case(&arch.name)
+            || dep.source.eq_ignore_ascii_case(&dep.target)
+        {
+            continue;
+        }
+        edges
+            .entry(dep.source.to_ascii_lowercase())
+            .or_default()
+            .push((dep.target.to_ascii_lowercase(), dep.line));
+    }
+    let mut chains = HashMap::new();
+    let mut starts: Vec<&String> = edges.keys().collect();
+    starts.sort();
+    starts
+        .into_iter()
+        .map(|start| {
+            let steps = |signal: &str| {
+                edges
+                    .get(signal)
+                    .into_iter()
+                    .flatten()
+                    .map(|(target, line)| (target.as_str(), *line))
+            };
+            timing::longest_chain(start, steps, &mut chains)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{
+        CaseStatement, ConcurrentAssignment, Entity, IfArm, IfStatement, Instance, Process, Signal,
+        SignalDep,
+    };
+
+    fn dep(source: &str, target: &str, is_sequential: bool) -> SignalDep {
+        SignalDep {
+            source: source.to_string(),
+            target: target.to_string(),
+            file: "top.vhd".to_string(),
+            is_sequential,
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn design() -> Input {
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "top".to_string(),
+            file: "top.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "top".to_string(),
+            file: "top.vhd".to_string(),
+            line: 10,
+            ..Default::default()
+        });
+        for name in ["a", "b", "q"] {
+            input.signals.push(Signal {
+                name: name.to_string(),
+                file: "top.vhd".to_string(),
+                in_entity: "rtl".to_string(),
+                ..Default::default()
+            });
+        }
+        input.processes.push(Process {
+            label: "comb_p".to_string(),
+            is_combinational: true,
+            if_statements: vec![IfStatement {
+                arms: vec![IfArm::default(), IfArm::default()],
+                has_else: true,
+                ..Default::default()
+            }],
+            file: "top.vhd".to_string(),
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        });
+        input.case_statements.push(CaseStatement {
+            choices: vec!["IDLE".to_string(), "RUN".to_string()],
+            has_others: true,
+            file: "top.vhd".to_string(),
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        });
+        input.concurrent_assignments.push(ConcurrentAssignment {
+            target: "y".to_string(),
+            kind: "conditional".to_string(),
+            file: "top.vhd".to_string(),
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        });
+        input.instances.push(Instance {
+            name: "u_sub".to_string(),
+            target: "work.sub".to_string(),
+            file: "top.vhd".to_string(),
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        });
+        input.signal_deps = vec![
+            dep("d", "a", false),
+            dep("a", "b", false),
+            dep("b", "a", false),
+            dep("b", "y", false),
+            dep("y", "q", true),
+            dep("q", "z", false),
+        ];
+        input
+    }
+
+    #[test]
+    fn architecture_metrics_count_size_and_decisions() {
+        let metrics = compute(&design());
+        let arch = &metrics.architectures[0];
+        assert_eq!((arch.processes, arch.signals, arch.instances), (1, 3, 1));
+        // d -> a -> b -> y; the register q starts a new path.
+        assert_eq!(arch.max_comb_depth, 3);
+        // 1 + if/elsif + 2 extra case alternatives + conditional assignment.
+        assert_eq!(arch.complexity, 6);
+
+        assert_eq!(metrics.files.len(), 1);
+        let file = &metrics.files[0];
+        assert_eq!((file.entities, file.architectures), (1, 1));
+        assert_eq!(file.complexity, 6);
+    }
+
+    #[test]
+    fn comb_depth_follows_long_chains() {
+        let mut input = design();
+        input.signal_deps = (0..50_000)
+            .map(|n| dep(&format!("s{n}"), &format!("s{}", n + 1), false))
+            .collect();
+        assert_eq!(compute(&input).architectures[0].max_comb_depth, 50_000);
+    }
+
+    #[test]
+    fn csv_has_file_and_architecture_rows() {
+        let mut input = design();
+        input.architectures[0].file = "a,b.vhd".to_string();
+        let text = csv(&compute(&input));
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines[0].starts_with("kind,file,entity,architecture,line"));
+        assert!(lines.contains(&"file,top.vhd,,,,1,0,0,0,0,0,0"));
+        assert!(lines.contains(&"architecture,\"a,b.vhd\",top,rtl,10,,,0,0,0,0,1"));
+    }
+}
@@ -1,29 +1,73 @@
+pub mod baseline;
+pub mod bus_protocols;
 pub mod cdc;
+pub mod checkstyle;
+pub mod clock_domains;
 pub mod clocks_resets;
 pub mod combinational;
+pub mod compile_order;
+pub mod config;
 pub mod configurations;
 pub mod core;
+pub mod daemon;
+pub mod dataflow;
+pub mod dead_code;
+pub mod diff;
+pub mod dot;
+pub mod duplicates;
+pub mod elaboration;
 pub mod engine;
+pub mod fixes;
 pub mod fsm;
+pub mod generics;
+pub mod gitlab;
 pub mod helpers;
 pub mod hierarchy;
+pub mod initial_values;
 pub mod input;
 pub mod instances;
 pub mod latch;
+pub mod libraries;
+pub mod metavalues;
+pub mod metrics;
 pub mod naming;
 pub mod ports;
 pub mod power;
 pub mod processes;
+pub mod project;
 pub mod quality;
+pub mod ranges;
 pub mod rdc;
+pub mod registry;
+pub mod report;
+pub mod reset_domains;
 pub mod result;
+pub mod result_diff;
+pub mod rule;
+pub mod rule_docs;
+pub mod sarif;
+pub mod scope;
+pub mod scripting;
 pub mod security;
 pub mod sensitivity;
 pub mod sequential;
+pub mod shard;
 pub mod signals;
+pub mod source_map;
+pub mod standards;
 pub mod style;
 pub mod subprograms;
 pub mod synthesis;
 pub mod testbench;
+pub mod third_party;
+pub mod timing;
 pub mod types;
+pub mod use_clauses;
+pub mod vendor;
 pub mod verification;
+pub mod waivers;
+pub mod width_arith;
+
+pub use engine::evaluate;
+pub use input::Input;
+pub use result::{Result, Violation};
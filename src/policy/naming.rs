@@ -1,9 +1,25 @@
+use regex::Regex;
+use std::collections::HashMap;
+
 use crate::policy::helpers::{is_clock_name, is_reset_name};
 use crate::policy::input::{Input, Port};
 use crate::policy::result::Violation;
 
-pub fn violations(_input: &Input) -> Vec<Violation> {
-    Vec::new()
+/// Identifier classes `lint_config.naming_patterns` can constrain, with the
+/// word messages use for them.
+pub const NAMING_CLASSES: &[(&str, &str)] = &[
+    ("signal", "Signal"),
+    ("port_in", "Input port"),
+    ("port_out", "Output port"),
+    ("constant", "Constant"),
+    ("generic", "Generic"),
+    ("instance", "Instance"),
+    ("entity", "Entity"),
+    ("type", "Type"),
+];
+
+pub fn violations(input: &Input) -> Vec<Violation> {
+    naming_convention_custom(input)
 }
 
 pub fn optional_violations(input: &Input) -> Vec<Violation> {
@@ -15,6 +31,131 @@ pub fn optional_violations(input: &Input) -> Vec<Violation> {
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "constant_decls",
+        "entities",
+        "instances",
+        "ports",
+        "signals",
+        "types",
+    ]
+}
+
+/// Compiles naming patterns, anchored so they must match the whole name.
+/// Errors name the offending class: unknown classes and invalid regexes.
+pub fn compile_naming_patterns(
+    patterns: &HashMap<String, String>,
+) -> Result<Vec<(&'static str, String, Regex)>, String> {
+    let mut out = Vec::new();
+    for (class, pattern) in patterns {
+        let Some((known, _)) = NAMING_CLASSES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(class))
+        else {
+            let classes: Vec<&str> = NAMING_CLASSES.iter().map(|(name, _)| *name).collect();
+            return Err(format!(
+                "unknown naming class '{}' (expected one of: {})",
+                class,
+                classes.join(", ")
+            ));
+        };
+        let regex = Regex::new(&format!("^(?:{})$", pattern))
+            .map_err(|e| format!("invalid naming pattern for '{}': {}", class, e))?;
+        out.push((*known, pattern.clone(), regex));
+    }
+    out.sort_by_key(|(class, _, _)| NAMING_CLASSES.iter().position(|(name, _)| name == class));
+    Ok(out)
+}
+
+/// Names that don't match the project's pattern for their class. Patterns
+/// that fail to compile were rejected when the config was loaded and are
+/// skipped here.
+fn naming_convention_custom(input: &Input) -> Vec<Violation> {
+    let Ok(patterns) = compile_naming_patterns(&input.lint_config.naming_patterns) else {
+        return Vec::new();
+    };
+    let mut out = Vec::new();
+    for (class, pattern, regex) in &patterns {
+        let label = NAMING_CLASSES
+            .iter()
+            .find(|(name, _)| name == class)
+            .map_or("Name", |(_, label)| *label);
+        for (name, file, line) in names_of_class(input, class) {
+            if regex.is_match(name) {
+                continue;
+            }
+            out.push(Violation {
+                rule: "naming_convention_custom".to_string(),
+                severity: "info".to_string(),
+                file,
+                line,
+                message: format!(
+                    "{} '{}' doesn't match the project's {} naming pattern '{}'",
+                    label, name, class, pattern
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+/// Declared names of one identifier class with their file and line.
+fn names_of_class<'a>(input: &'a Input, class: &str) -> Vec<(&'a str, String, usize)> {
+    let ports = |direction: &'static str| {
+        input
+            .ports
+            .iter()
+            .filter(move |port| port.direction.eq_ignore_ascii_case(direction))
+            .map(|port| {
+                let file = entity_file(input, port).unwrap_or_default();
+                (port.name.as_str(), file, port.line)
+            })
+            .collect()
+    };
+    match class {
+        "signal" => input
+            .signals
+            .iter()
+            .map(|sig| (sig.name.as_str(), sig.file.clone(), sig.line))
+            .collect(),
+        "port_in" => ports("in"),
+        "port_out" => ports("out"),
+        "constant" => input
+            .constant_decls
+            .iter()
+            .map(|decl| (decl.name.as_str(), decl.file.clone(), decl.line))
+            .collect(),
+        "generic" => input
+            .entities
+            .iter()
+            .flat_map(|entity| {
+                entity
+                    .generics
+                    .iter()
+                    .map(|generic| (generic.name.as_str(), entity.file.clone(), generic.line))
+            })
+            .collect(),
+        "instance" => input
+            .instances
+            .iter()
+            .map(|inst| (inst.name.as_str(), inst.file.clone(), inst.line))
+            .collect(),
+        "entity" => input
+            .entities
+            .iter()
+            .map(|entity| (entity.name.as_str(), entity.file.clone(), entity.line))
+            .collect(),
+        "type" => input
+            .types
+            .iter()
+            .map(|decl| (decl.name.as_str(), decl.file.clone(), decl.line))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
 fn entity_naming(input: &Input) -> Vec<Violation> {
     input
         .entities
@@ -26,6 +167,7 @@ fn entity_naming(input: &Input) -> Vec<Violation> {
             file: entity.file.clone(),
             line: entity.line,
             message: format!("Entity '{}' should use lowercase naming", entity.name),
+            ..Default::default()
         })
         .collect()
 }
@@ -44,6 +186,7 @@ fn signal_input_naming(input: &Input) -> Vec<Violation> {
             file: entity_file(input, port).unwrap_or_default(),
             line: port.line,
             message: format!("Input port '{}' should end with '_i' suffix", port.name),
+            ..Default::default()
         })
         .collect()
 }
@@ -60,6 +203,7 @@ fn signal_output_naming(input: &Input) -> Vec<Violation> {
             file: entity_file(input, port).unwrap_or_default(),
             line: port.line,
             message: format!("Output port '{}' should end with '_o' suffix", port.name),
+            ..Default::default()
         })
         .collect()
 }
@@ -82,6 +226,7 @@ fn active_low_naming(input: &Input) -> Vec<Violation> {
                 "Active-low signal '{}' should end with '_n' suffix",
                 sig.name
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -102,7 +247,7 @@ fn entity_file(input: &Input, port: &Port) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::policy::input::{Entity, Input, Port, Signal};
+    use crate::policy::input::{Entity, Input, Instance, Port, Signal};
 
     #[test]
     fn entity_naming_flags_uppercase() {
@@ -173,4 +318,56 @@ mod tests {
         assert_eq!(violations.len(), 1);
         assert_eq!(violations[0].rule, "active_low_naming");
     }
+
+    #[test]
+    fn naming_convention_custom_checks_configured_classes() {
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "Core".to_string(),
+            file: "a.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        input.ports.push(Port {
+            name: "data".to_string(),
+            direction: "in".to_string(),
+            in_entity: "core".to_string(),
+            line: 2,
+            ..Default::default()
+        });
+        input.instances.push(Instance {
+            name: "u_fifo".to_string(),
+            file: "a.vhd".to_string(),
+            line: 9,
+            ..Default::default()
+        });
+        let patterns = &mut input.lint_config.naming_patterns;
+        patterns.insert("port_in".to_string(), "[a-z_]+_i".to_string());
+        patterns.insert("instance".to_string(), "u_[a-z_]+".to_string());
+        patterns.insert("Entity".to_string(), "[a-z_]+".to_string());
+
+        let violations = naming_convention_custom(&input);
+        let messages: Vec<&str> = violations.iter().map(|v| v.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "Input port 'data' doesn't match the project's port_in naming pattern '[a-z_]+_i'",
+                "Entity 'Core' doesn't match the project's entity naming pattern '[a-z_]+'",
+            ]
+        );
+        assert_eq!(violations[0].file, "a.vhd");
+    }
+
+    #[test]
+    fn compile_naming_patterns_rejects_bad_config() {
+        let mut patterns = HashMap::new();
+        patterns.insert("signal".to_string(), "[a-z".to_string());
+        let err = compile_naming_patterns(&patterns).unwrap_err();
+        assert!(err.starts_with("invalid naming pattern for 'signal'"));
+
+        patterns.clear();
+        patterns.insert("variable".to_string(), ".*".to_string());
+        let err = compile_naming_patterns(&patterns).unwrap_err();
+        assert!(err.starts_with("unknown naming class 'variable'"));
+    }
 }
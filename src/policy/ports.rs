@@ -1,6 +1,7 @@
 use crate::policy::helpers::{is_clock_name, is_reset_name};
-use crate::policy::input::{Input, Instance};
-use crate::policy::result::Violation;
+use crate::policy::input::{Entity, Input, Instance, Port};
+use crate::policy::result::{Fix, FixEdit, Violation};
+use crate::policy::standards;
 
 pub fn violations(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
@@ -8,15 +9,30 @@ pub fn violations(input: &Input) -> Vec<Violation> {
     out.extend(output_port_read(input));
     out.extend(inout_as_output(input));
     out.extend(inout_as_input(input));
+    out.extend(mode_view_requires_vhdl2019(input));
     out
 }
 
 pub fn optional_violations(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     out.extend(unused_input_port(input));
+    out.extend(port_ordering(input));
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "architectures",
+        "concurrent_assignments",
+        "entities",
+        "instances",
+        "mode_views",
+        "ports",
+        "processes",
+        "standard",
+    ]
+}
+
 fn unused_input_port(input: &Input) -> Vec<Violation> {
     input
         .ports
@@ -32,6 +48,7 @@ fn unused_input_port(input: &Input) -> Vec<Violation> {
             file: entity_file(input, &port.in_entity).unwrap_or_default(),
             line: port.line,
             message: format!("Input port '{}' is never read", port.name),
+            ..Default::default()
         })
         .collect()
 }
@@ -52,12 +69,13 @@ fn undriven_output_port(input: &Input) -> Vec<Violation> {
                 "Output port '{}' is never assigned (floating output)",
                 port.name
             ),
+            ..Default::default()
         })
         .collect()
 }
 
 fn output_port_read(input: &Input) -> Vec<Violation> {
-    if !is_legacy_standard(input) {
+    if standards::supports(input, standards::OUTPUT_PORT_READ) {
         return Vec::new();
     }
     input
@@ -75,6 +93,7 @@ fn output_port_read(input: &Input) -> Vec<Violation> {
                 "Output port '{}' is read internally (use buffer or internal signal for VHDL-93 compatibility)",
                 port.name
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -96,6 +115,7 @@ fn inout_as_output(input: &Input) -> Vec<Violation> {
                 "Inout port '{}' is only written, never read - consider 'out' direction",
                 port.name
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -117,10 +137,192 @@ fn inout_as_input(input: &Input) -> Vec<Violation> {
                 "Inout port '{}' is only read, never written - consider 'in' direction",
                 port.name
             ),
+            ..Default::default()
         })
         .collect()
 }
 
+/// Mode views and the ports using them are VHDL-2019; tools analyzing an
+/// older `standard` reject both. Direction-based port rules skip view ports
+/// (direction "view") since their element modes come from the view.
+fn mode_view_requires_vhdl2019(input: &Input) -> Vec<Violation> {
+    if standards::supports(input, standards::MODE_VIEW) {
+        return Vec::new();
+    }
+    let violation = |file: String, line: usize, what: String| Violation {
+        rule: "mode_view_requires_vhdl2019".to_string(),
+        severity: "error".to_string(),
+        file,
+        line,
+        message: format!(
+            "{} requires VHDL-2019 (standard is {})",
+            what, input.standard
+        ),
+        ..Default::default()
+    };
+    let mut out: Vec<Violation> = input
+        .mode_views
+        .iter()
+        .map(|view| {
+            violation(
+                view.file.clone(),
+                view.line,
+                format!("Mode view '{}'", view.name),
+            )
+        })
+        .collect();
+    out.extend(
+        input
+            .ports
+            .iter()
+            .filter(|port| port.direction == "view")
+            .map(|port| {
+                violation(
+                    entity_file(input, &port.in_entity).unwrap_or_default(),
+                    port.line,
+                    format!("Port '{}' uses mode view '{}', which", port.name, port.view),
+                )
+            }),
+    );
+    out
+}
+
+/// Port groups in declaration order unless `lint_config.port_order` overrides
+/// them. Groups missing from the order (e.g. inout) are unconstrained.
+const DEFAULT_PORT_ORDER: &[&str] = &["clock", "reset", "in", "out"];
+
+fn port_group(port: &Port) -> &'static str {
+    let direction = port.direction.to_ascii_lowercase();
+    match direction.as_str() {
+        "in" if is_clock_name(&port.name) => "clock",
+        "in" if is_reset_name(&port.name) => "reset",
+        "in" => "in",
+        "out" | "buffer" => "out",
+        "inout" => "inout",
+        _ => "other",
+    }
+}
+
+fn port_order(input: &Input) -> Vec<String> {
+    if input.lint_config.port_order.is_empty() {
+        DEFAULT_PORT_ORDER.iter().map(|g| g.to_string()).collect()
+    } else {
+        input
+            .lint_config
+            .port_order
+            .iter()
+            .map(|g| g.trim().to_ascii_lowercase())
+            .collect()
+    }
+}
+
+fn port_ordering(input: &Input) -> Vec<Violation> {
+    let order = port_order(input);
+    let rank = |port: &Port| order.iter().position(|g| g == port_group(port));
+    let mut out = Vec::new();
+    for entity in &input.entities {
+        let mut latest: Option<(usize, &Port)> = None;
+        for port in &entity.ports {
+            let Some(port_rank) = rank(port) else {
+                continue;
+            };
+            match latest {
+                Some((latest_rank, prev)) if port_rank < latest_rank => {
+                    out.push(Violation {
+                        rule: "port_ordering".to_string(),
+                        severity: "info".to_string(),
+                        file: entity.file.clone(),
+                        line: port.line,
+                        message: format!(
+                            "Port '{}' ({}) of entity '{}' is declared after '{}' ({}); expected order: {}; {}",
+                            port.name,
+                            port_group(port),
+                            entity.name,
+                            prev.name,
+                            port_group(prev),
+                            order.join(", "),
+                            reorder_hint(input, entity, &rank)
+                        ),
+                        fixes: reorder_fix(input, entity, &rank).into_iter().collect(),
+                        ..Default::default()
+                    });
+                    break;
+                }
+                _ => latest = Some((port_rank, port)),
+            }
+        }
+    }
+    out
+}
+
+/// Reordering an entity's ports is only safe when no instance binds them
+/// positionally.
+fn reorder_hint(input: &Input, entity: &Entity, rank: &dyn Fn(&Port) -> Option<usize>) -> String {
+    if let Some(inst) = positional_instance_of(input, &entity.name) {
+        return format!(
+            "not safe to reorder: instance '{}' at {}:{} maps ports positionally",
+            inst.name, inst.file, inst.line
+        );
+    }
+    format!(
+        "safe to reorder: {}",
+        sorted_port_names(entity, rank).join(", ")
+    )
+}
+
+/// Rewrites the port clause into the sorted order, unless an instance maps the
+/// ports positionally.
+fn reorder_fix(
+    input: &Input,
+    entity: &Entity,
+    rank: &dyn Fn(&Port) -> Option<usize>,
+) -> Option<Fix> {
+    if positional_instance_of(input, &entity.name).is_some() {
+        return None;
+    }
+    Some(Fix {
+        file: entity.file.clone(),
+        line: entity.line,
+        edit: FixEdit::PortOrder {
+            ports: sorted_port_names(entity, rank),
+        },
+    })
+}
+
+/// Stable sort by group rank; unconstrained ports stay after the port they follow.
+fn sorted_port_names(entity: &Entity, rank: &dyn Fn(&Port) -> Option<usize>) -> Vec<String> {
+    let mut current = 0;
+    let mut ranked: Vec<(usize, &str)> = entity
+        .ports
+        .iter()
+        .map(|port| {
+            if let Some(r) = rank(port) {
+                current = r;
+            }
+            (current, port.name.as_str())
+        })
+        .collect();
+    ranked.sort_by_key(|(r, _)| *r);
+    ranked
+        .into_iter()
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+fn positional_instance_of<'a>(input: &'a Input, entity_name: &str) -> Option<&'a Instance> {
+    let name = entity_name.to_ascii_lowercase();
+    input.instances.iter().find(|inst| {
+        let target = inst.target.to_ascii_lowercase();
+        let matches = target == name || target.ends_with(&format!(".{}", name));
+        let positional = inst
+            .associations
+            .iter()
+            .any(|assoc| assoc.kind == "port" && assoc.is_positional)
+            || (inst.port_map.is_empty() && inst.associations.is_empty());
+        matches && positional
+    })
+}
+
 fn port_is_read(input: &Input, port_name: &str) -> bool {
     let port_lower = port_name.to_ascii_lowercase();
     input.processes.iter().any(|proc| {
@@ -178,14 +380,12 @@ fn entity_file(input: &Input, entity_name: &str) -> Option<String> {
         .map(|entity| entity.file.clone())
 }
 
-fn is_legacy_standard(input: &Input) -> bool {
-    matches!(input.standard.as_str(), "1993" | "2002")
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::policy::input::{Architecture, ConcurrentAssignment, Entity, Port, Process};
+    use crate::policy::input::{
+        Architecture, ConcurrentAssignment, Entity, ModeView, Port, Process,
+    };
 
     fn base_input() -> Input {
         Input {
@@ -206,9 +406,93 @@ mod tests {
             entity_name: name.to_string(),
             file: "a.vhd".to_string(),
             line: 2,
+            ..Default::default()
         });
     }
 
+    fn entity_with_ports(ports: &[(&str, &str)]) -> Entity {
+        Entity {
+            name: "core".to_string(),
+            file: "a.vhd".to_string(),
+            line: 1,
+            ports: ports
+                .iter()
+                .enumerate()
+                .map(|(idx, (name, dir))| Port {
+                    name: name.to_string(),
+                    direction: dir.to_string(),
+                    in_entity: "core".to_string(),
+                    line: idx + 3,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn port_ordering_reports_first_out_of_order_port() {
+        let mut input = base_input();
+        input.entities.push(entity_with_ports(&[
+            ("data_i", "in"),
+            ("clk", "in"),
+            ("data_o", "out"),
+            ("rst", "in"),
+        ]));
+        let violations = port_ordering(&input);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].rule, "port_ordering");
+        assert_eq!(violations[0].line, 4);
+        assert!(violations[0].message.contains("'clk' (clock)"));
+        assert!(violations[0]
+            .message
+            .contains("safe to reorder: clk, rst, data_i, data_o"));
+        assert_eq!(
+            violations[0].fixes[0].edit,
+            FixEdit::PortOrder {
+                ports: ["clk", "rst", "data_i", "data_o"]
+                    .map(String::from)
+                    .to_vec()
+            }
+        );
+    }
+
+    #[test]
+    fn port_ordering_accepts_conventional_order() {
+        let mut input = base_input();
+        input.entities.push(entity_with_ports(&[
+            ("clk", "in"),
+            ("rst_n", "in"),
+            ("data_i", "in"),
+            ("bus_io", "inout"),
+            ("data_o", "out"),
+        ]));
+        assert!(port_ordering(&input).is_empty());
+    }
+
+    #[test]
+    fn port_ordering_uses_configured_order_and_positional_guard() {
+        let mut input = base_input();
+        input.lint_config.port_order = vec!["out".to_string(), "in".to_string()];
+        input
+            .entities
+            .push(entity_with_ports(&[("data_i", "in"), ("data_o", "out")]));
+        input.instances.push(Instance {
+            name: "u_core".to_string(),
+            target: "work.core".to_string(),
+            file: "top.vhd".to_string(),
+            line: 12,
+            ..Default::default()
+        });
+        let violations = port_ordering(&input);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("expected order: out, in"));
+        assert!(violations[0]
+            .message
+            .contains("not safe to reorder: instance 'u_core' at top.vhd:12"));
+        assert!(violations[0].fixes.is_empty());
+    }
+
     #[test]
     fn unused_input_port_warns() {
         let mut input = base_input();
@@ -319,4 +603,48 @@ mod tests {
         let violations = unused_input_port(&input);
         assert!(violations.is_empty());
     }
+
+    #[test]
+    fn mode_views_require_vhdl2019() {
+        let mut input = base_input();
+        add_entity_arch(&mut input, "core");
+        input.mode_views.push(ModeView {
+            name: "master_v".to_string(),
+            r#type: "bus_rec".to_string(),
+            file: "bus_pkg.vhd".to_string(),
+            line: 9,
+            ..Default::default()
+        });
+        input.ports.push(Port {
+            name: "bus".to_string(),
+            direction: "view".to_string(),
+            view: "master_v".to_string(),
+            in_entity: "core".to_string(),
+            line: 4,
+            ..Default::default()
+        });
+        input.concurrent_assignments.push(ConcurrentAssignment {
+            target: "bus".to_string(),
+            read_signals: vec!["bus".to_string()],
+            ..Default::default()
+        });
+
+        let violations = mode_view_requires_vhdl2019(&input);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].file, "bus_pkg.vhd");
+        assert_eq!(
+            violations[1].message,
+            "Port 'bus' uses mode view 'master_v', which requires VHDL-2019 (standard is 1993)"
+        );
+        assert_eq!(violations[1].file, "a.vhd");
+        // Direction rules leave view ports to the view's element modes.
+        assert!(super::violations(&input)
+            .iter()
+            .all(|v| v.rule == "mode_view_requires_vhdl2019"));
+
+        input.standard = "2019".to_string();
+        assert!(mode_view_requires_vhdl2019(&input).is_empty());
+        input.standard.clear();
+        assert!(mode_view_requires_vhdl2019(&input).is_empty());
+    }
 }
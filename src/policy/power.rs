@@ -18,6 +18,10 @@ pub fn optional_violations(input: &Input) -> Vec<Violation> {
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &["arithmetic_ops", "processes", "signals"]
+}
+
 fn unguarded_multiplication(input: &Input) -> Vec<Violation> {
     input
         .arithmetic_ops
@@ -29,6 +33,7 @@ fn unguarded_multiplication(input: &Input) -> Vec<Violation> {
             file: op.file.clone(),
             line: op.line,
             message: "Multiplier without operand isolation - runs every cycle even when unused. Guard with enable signal.".to_string(),
+            ..Default::default()
         })
         .collect()
 }
@@ -47,6 +52,7 @@ fn unguarded_division(input: &Input) -> Vec<Violation> {
                 "Division/modulo operator '{}' without operand isolation - VERY expensive, runs every cycle!",
                 op.operator
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -68,6 +74,7 @@ fn unguarded_exponent(input: &Input) -> Vec<Violation> {
             message:
                 "Exponentiation '**' without operand isolation - implement with proper enable gating"
                     .to_string(),
+            ..Default::default()
         })
         .collect()
 }
@@ -90,6 +97,7 @@ fn power_hotspot(input: &Input) -> Vec<Violation> {
                     "Process '{}' contains {} expensive operations - power hotspot, consider operand isolation",
                     proc.label, count
                 ),
+                ..Default::default()
             });
         }
     }
@@ -118,6 +126,7 @@ fn combinational_multiplier(input: &Input) -> Vec<Violation> {
                     line: op.line,
                     message: "Multiplier in combinational process - active continuously, consider clocked implementation with enable"
                         .to_string(),
+                    ..Default::default()
                 });
             }
         }
@@ -140,6 +149,7 @@ fn weak_guard(input: &Input) -> Vec<Violation> {
                 "Expensive operation guarded by '{}' - verify this actually gates operand toggling",
                 op.guard_signal
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -173,6 +183,7 @@ fn dsp_candidate_no_control(input: &Input) -> Vec<Violation> {
                         "Wide signal '{}' multiplication - likely DSP block, add clock enable for power savings",
                         operand
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -204,6 +215,7 @@ fn clock_gating_opportunity(input: &Input) -> Vec<Violation> {
                         proc.assigned_signals.len(),
                         read
                     ),
+                    ..Default::default()
                 });
             }
         }
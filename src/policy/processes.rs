@@ -1,13 +1,18 @@
-use crate::policy::input::Input;
+use crate::policy::input::{Input, SequentialAssignment};
 use crate::policy::result::Violation;
 
 pub fn violations(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     out.extend(complex_process(input));
     out.extend(comb_process_no_default(input));
+    out.extend(duplicate_signal_assignment(input));
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &["case_statements", "processes"]
+}
+
 fn complex_process(input: &Input) -> Vec<Violation> {
     input
         .processes
@@ -23,6 +28,7 @@ fn complex_process(input: &Input) -> Vec<Violation> {
                 proc.label,
                 proc.assigned_signals.len()
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -50,16 +56,59 @@ fn comb_process_no_default(input: &Input) -> Vec<Violation> {
                     "Combinational process '{}' has incomplete case statement - may infer latch",
                     proc.label
                 ),
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+/// An assignment is dead when a later write to the same target, with no wait in
+/// between, executes whenever it does: same branch or an enclosing one. The
+/// default-then-override idiom puts the later write in a nested branch and is
+/// not flagged.
+fn duplicate_signal_assignment(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for proc in &input.processes {
+        for (idx, earlier) in proc.assignments.iter().enumerate() {
+            let Some(later) = proc.assignments[idx + 1..]
+                .iter()
+                .find(|later| overwrites(later, earlier))
+            else {
+                continue;
+            };
+            out.push(Violation {
+                rule: "duplicate_signal_assignment".to_string(),
+                severity: "warning".to_string(),
+                file: proc.file.clone(),
+                line: earlier.line,
+                message: format!(
+                    "Assignment to '{}' in process '{}' is overwritten by the assignment at line {} (last write wins)",
+                    earlier.target, proc.label, later.line
+                ),
+                ..Default::default()
             });
         }
     }
     out
 }
 
+fn overwrites(later: &SequentialAssignment, earlier: &SequentialAssignment) -> bool {
+    later.segment == earlier.segment
+        && later.target.eq_ignore_ascii_case(&earlier.target)
+        && branch_encloses(&later.branch, &earlier.branch)
+}
+
+fn branch_encloses(outer: &str, inner: &str) -> bool {
+    inner
+        .strip_prefix(outer)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::policy::input::{CaseStatement, Input, Process};
+    use crate::policy::input::{CaseStatement, Input, Process, SequentialAssignment};
 
     #[test]
     fn complex_process_flags_many_assigns() {
@@ -96,4 +145,55 @@ mod tests {
         assert_eq!(violations.len(), 1);
         assert_eq!(violations[0].rule, "comb_process_no_default");
     }
+
+    fn assign(target: &str, line: usize, branch: &str, segment: usize) -> SequentialAssignment {
+        SequentialAssignment {
+            target: target.to_string(),
+            signal: target.to_string(),
+            line,
+            branch: branch.to_string(),
+            segment,
+            ..Default::default()
+        }
+    }
+
+    fn input_with_assignments(assignments: Vec<SequentialAssignment>) -> Input {
+        let mut input = Input::default();
+        input.processes.push(Process {
+            label: "p1".to_string(),
+            assignments,
+            file: "a.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        input
+    }
+
+    #[test]
+    fn duplicate_signal_assignment_flags_dead_write() {
+        let input = input_with_assignments(vec![
+            assign("q", 3, "/if@10.0", 0),
+            assign("Q", 4, "/if@10.0", 0),
+            assign("q", 6, "/if@10.1/case@40", 0),
+            assign("q", 8, "", 0),
+        ]);
+        let violations = duplicate_signal_assignment(&input);
+        assert_eq!(violations.len(), 3);
+        assert!(violations[0].message.contains("line 4"));
+        assert!(violations[1].message.contains("line 8"));
+    }
+
+    #[test]
+    fn duplicate_signal_assignment_allows_default_then_override() {
+        let input = input_with_assignments(vec![
+            assign("q", 3, "", 0),
+            assign("q", 5, "/if@10.0", 0),
+            assign("clk", 7, "", 0),
+            assign("clk", 9, "", 1),
+            assign("r(0)", 10, "", 1),
+            assign("r(1)", 11, "", 1),
+            assign("q", 12, "/if@1", 0),
+        ]);
+        assert!(duplicate_signal_assignment(&input).is_empty());
+    }
 }
@@ -0,0 +1,350 @@
+//! Project manifests (`vhdl_project.toml`): the files making up a design as
+//! source sets, the library each set compiles into, the standard it is
+//! analyzed as, the top-level entities and the third-party directories.
+//! `vhdl-compiler --lint --project vhdl_project.toml` lints the manifest's
+//! files and fills `Input.files`, `third_party_files`, `standard` and
+//! `top_entities` from it.
+//!
+//! ```toml
+//! standard = "2008"
+//! top = ["soc_top"]
+//! third_party = ["ip/vendor"]
+//!
+//! [[sources]]
+//! files = ["rtl/**/*.vhd"]
+//! exclude = ["rtl/legacy/**"]
+//!
+//! [[sources]]
+//! library = "legacy"
+//! files = ["rtl/legacy"]
+//! standard = "1993"
+//! ```
+//!
+//! Paths are relative to the manifest's directory. A pattern without `*` or
+//! `?` naming a directory covers every VHDL file beneath it. A file listed by
+//! several source sets belongs to the first.
+
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::extract::discover;
+use crate::policy::helpers;
+use crate::policy::input::Input;
+
+/// Standards a manifest may name.
+const STANDARDS: &[&str] = &["1987", "1993", "2000", "2002", "2008", "2019"];
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectManifest {
+    /// Standard for source sets that don't set one; empty keeps the
+    /// extractor's default (2008).
+    pub standard: String,
+    /// Top-level entities the `dead_code` rules measure reachability from.
+    pub top: Vec<String>,
+    /// Directories or globs of third-party code.
+    pub third_party: Vec<String>,
+    pub sources: Vec<SourceSet>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SourceSet {
+    /// Library the files compile into; empty means `work`.
+    pub library: String,
+    /// Globs, files or directories.
+    pub files: Vec<String>,
+    pub exclude: Vec<String>,
+    /// Overrides the manifest's `standard` for these files.
+    pub standard: String,
+    /// Marks every file of the set as third-party.
+    pub third_party: bool,
+}
+
+/// One file of a project with the settings of the source set listing it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectFile {
+    pub path: String,
+    pub library: String,
+    pub standard: String,
+    pub third_party: bool,
+}
+
+/// A manifest with its source sets expanded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Project {
+    pub manifest: ProjectManifest,
+    /// In source set order, then path order within a set.
+    pub files: Vec<ProjectFile>,
+}
+
+/// Reads a manifest and expands its source sets against the manifest's
+/// directory. A source set matching no file is an error, so a typo in a path
+/// doesn't silently shrink the design.
+pub fn open(path: &Path) -> Result<Project, String> {
+    let text =
+        fs::read_to_string(path).map_err(|e| format!("reading '{}': {}", path.display(), e))?;
+    let manifest = parse(&text).map_err(|e| format!("parsing '{}': {}", path.display(), e))?;
+    let root = path.parent().unwrap_or(Path::new(""));
+    let files = manifest.expand(root)?;
+    Ok(Project { manifest, files })
+}
+
+pub fn parse(text: &str) -> Result<ProjectManifest, String> {
+    let manifest: ProjectManifest = toml::from_str(text).map_err(|e| e.to_string())?;
+    manifest.validate()
+}
+
+impl ProjectManifest {
+    /// Rejects manifests without source sets, sets without files and unknown
+    /// standards.
+    fn validate(self) -> Result<Self, String> {
+        if self.sources.is_empty() {
+            return Err("a project needs at least one [[sources]] entry".to_string());
+        }
+        if let Some(idx) = self.sources.iter().position(|set| set.files.is_empty()) {
+            return Err(format!("[[sources]] entry {} lists no files", idx + 1));
+        }
+        let standards =
+            std::iter::once(&self.standard).chain(self.sources.iter().map(|set| &set.standard));
+        for standard in standards {
+            if !standard.is_empty() && !STANDARDS.contains(&standard.as_str()) {
+                return Err(format!(
+                    "unknown standard '{}' (expected one of {})",
+                    standard,
+                    STANDARDS.join(", ")
+                ));
+            }
+        }
+        Ok(self)
+    }
+
+    /// The files of every source set under `root`.
+    pub fn expand(&self, root: &Path) -> Result<Vec<ProjectFile>, String> {
+        let third_party = directory_patterns(&self.third_party);
+        let mut seen = HashSet::new();
+        let mut files = Vec::new();
+        for (idx, set) in self.sources.iter().enumerate() {
+            let include = directory_patterns(&set.files);
+            let exclude = directory_patterns(&set.exclude);
+            let mut found = Vec::new();
+            for pattern in &include {
+                let base = root.join(literal_prefix(pattern));
+                if base.is_file() {
+                    found.push(pattern.clone());
+                    continue;
+                }
+                let walk = if base.as_os_str().is_empty() {
+                    PathBuf::from(".")
+                } else {
+                    base
+                };
+                let listed = discover::vhdl_files(&walk)
+                    .map_err(|e| format!("reading '{}': {}", walk.display(), e))?;
+                found.extend(
+                    listed
+                        .iter()
+                        .filter_map(|path| path.strip_prefix(root).ok())
+                        .map(|path| {
+                            let path = path.to_string_lossy().replace('\\', "/");
+                            path.trim_start_matches("./").to_string()
+                        })
+                        .filter(|path| helpers::glob_match(pattern, path)),
+                );
+            }
+            found.retain(|path| {
+                !exclude
+                    .iter()
+                    .any(|pattern| helpers::glob_match(pattern, path))
+            });
+            if found.is_empty() {
+                return Err(format!(
+                    "[[sources]] entry {} ({}) matches no VHDL files",
+                    idx + 1,
+                    set.files.join(", ")
+                ));
+            }
+            found.sort();
+            for relative in found {
+                if !seen.insert(relative.clone()) {
+                    continue;
+                }
+                files.push(ProjectFile {
+                    path: root.join(&relative).to_string_lossy().to_string(),
+                    library: if set.library.is_empty() {
+                        "work".to_string()
+                    } else {
+                        set.library.to_ascii_lowercase()
+                    },
+                    standard: if set.standard.is_empty() {
+                        self.standard.clone()
+                    } else {
+                        set.standard.clone()
+                    },
+                    third_party: set.third_party
+                        || third_party
+                            .iter()
+                            .any(|pattern| helpers::glob_match(pattern, &relative)),
+                });
+            }
+        }
+        Ok(files)
+    }
+}
+
+impl Project {
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.files
+            .iter()
+            .map(|file| PathBuf::from(&file.path))
+            .collect()
+    }
+
+    /// Sets each project file's library, standard and third-party flag in
+    /// `input.files`, and the manifest's standard and top entities on the
+    /// input. Files the manifest doesn't list are left alone.
+    pub fn apply(&self, input: &mut Input) {
+        if !self.manifest.standard.is_empty() {
+            input.standard = self.manifest.standard.clone();
+        }
+        if !self.manifest.top.is_empty() {
+            input.top_entities = self.manifest.top.clone();
+        }
+        for info in &mut input.files {
+            let Some(file) = self.files.iter().find(|file| file.path == info.path) else {
+                continue;
+            };
+            info.library = file.library.clone();
+            info.standard = if file.standard == input.standard {
+                String::new()
+            } else {
+                file.standard.clone()
+            };
+            info.is_third_party |= file.third_party;
+            if file.third_party && !input.third_party_files.contains(&file.path) {
+                input.third_party_files.push(file.path.clone());
+            }
+        }
+    }
+}
+
+/// Trims `./` and a trailing `/`, and turns a wildcard-free pattern that
+/// isn't a VHDL file into `<dir>/**` so a directory covers the files beneath
+/// it.
+fn directory_patterns(patterns: &[String]) -> Vec<String> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+            if pattern.contains(['*', '?']) || discover::is_vhdl_file(Path::new(pattern)) {
+                pattern.to_string()
+            } else {
+                format!("{}/**", pattern)
+            }
+        })
+        .collect()
+}
+
+/// The leading components of `pattern` without wildcards.
+fn literal_prefix(pattern: &str) -> String {
+    pattern
+        .split('/')
+        .take_while(|part| !part.contains(['*', '?']))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::FileInfo;
+
+    const MANIFEST: &str = r#"
+standard = "2008"
+top = ["soc_top"]
+third_party = ["ip/vendor"]
+
+[[sources]]
+files = ["rtl/**/*.vhd", "ip"]
+exclude = ["rtl/legacy/**"]
+
+[[sources]]
+library = "Legacy"
+files = ["rtl/legacy"]
+standard = "1993"
+"#;
+
+    #[test]
+    fn source_sets_map_files_to_libraries_and_standards() {
+        let dir = std::env::temp_dir().join(format!("vhdl_project_{}", std::process::id()));
+        for file in [
+            "rtl/top.vhd",
+            "rtl/core/alu.vhd",
+            "rtl/legacy/uart.vhd",
+            "rtl/notes.txt",
+            "ip/vendor/fifo.vhdl",
+        ] {
+            let path = dir.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, "").unwrap();
+        }
+        let manifest = dir.join("vhdl_project.toml");
+        fs::write(&manifest, MANIFEST).unwrap();
+
+        let project = open(&manifest).unwrap();
+        let root = dir.to_string_lossy().to_string();
+        let summary: Vec<_> = project
+            .files
+            .iter()
+            .map(|file| {
+                (
+                    file.path.strip_prefix(&root).unwrap().to_string(),
+                    file.library.as_str(),
+                    file.standard.as_str(),
+                    file.third_party,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("/ip/vendor/fifo.vhdl".to_string(), "work", "2008", true),
+                ("/rtl/core/alu.vhd".to_string(), "work", "2008", false),
+                ("/rtl/top.vhd".to_string(), "work", "2008", false),
+                ("/rtl/legacy/uart.vhd".to_string(), "legacy", "1993", false),
+            ]
+        );
+
+        let mut input = Input {
+            standard: "2008".to_string(),
+            files: project
+                .files
+                .iter()
+                .map(|file| FileInfo {
+                    path: file.path.clone(),
+                    library: "work".to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        project.apply(&mut input);
+        assert_eq!(input.top_entities, ["soc_top"]);
+        assert_eq!(input.third_party_files, [project.files[0].path.clone()]);
+        assert_eq!(input.files[3].library, "legacy");
+        assert_eq!(input.files[3].standard, "1993");
+        assert!(input.files[2].standard.is_empty());
+
+        fs::write(&manifest, "[[sources]]\nfiles = [\"hdl\"]").unwrap();
+        assert!(open(&manifest)
+            .unwrap_err()
+            .contains("matches no VHDL files"));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(parse("standard = \"2008\"").is_err());
+        let err = parse("[[sources]]\nfiles = [\"a.vhd\"]\nstandard = \"08\"").unwrap_err();
+        assert!(err.contains("unknown standard '08'"));
+    }
+}
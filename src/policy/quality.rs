@@ -1,7 +1,10 @@
 use regex::Regex;
 
-use crate::policy::input::{Input, Port};
-use crate::policy::result::Violation;
+use crate::policy::elaboration::{self, Generics};
+use crate::policy::helpers;
+use crate::policy::input::{GenerateStatement, Input, Port};
+use crate::policy::result::{Fix, FixEdit, Violation};
+use crate::policy::standards;
 
 pub fn violations(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
@@ -32,6 +35,21 @@ pub fn optional_violations(input: &Input) -> Vec<Violation> {
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "architectures",
+        "concurrent_assignments",
+        "entities",
+        "generates",
+        "instances",
+        "packages",
+        "ports",
+        "processes",
+        "signals",
+        "standard",
+    ]
+}
+
 fn very_long_file(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     let mut files: Vec<&str> = input.entities.iter().map(|e| e.file.as_str()).collect();
@@ -55,6 +73,7 @@ fn very_long_file(input: &Input) -> Vec<Violation> {
                     "File contains {} design units - consider splitting into separate files",
                     total
                 ),
+                ..Default::default()
             });
         }
     }
@@ -81,6 +100,7 @@ fn large_package(input: &Input) -> Vec<Violation> {
                         "Package '{}' is very large ({} items) - consider splitting",
                         pkg.name, count
                     ),
+                    ..Default::default()
                 })
             } else {
                 None
@@ -104,6 +124,7 @@ fn short_signal_name(input: &Input) -> Vec<Violation> {
                 "Signal '{}' has very short name - consider a more descriptive name",
                 sig.name
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -123,6 +144,7 @@ fn long_signal_name(input: &Input) -> Vec<Violation> {
                 sig.name,
                 sig.name.chars().count()
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -142,6 +164,7 @@ fn short_port_name(input: &Input) -> Vec<Violation> {
                 "Port '{}' has very short name - consider a more descriptive name",
                 port.name
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -162,6 +185,7 @@ fn entity_name_with_numbers(input: &Input) -> Vec<Violation> {
                 "Entity '{}' contains numbers - consider a more descriptive name",
                 entity.name
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -184,6 +208,7 @@ fn mixed_port_directions(input: &Input) -> Vec<Violation> {
                         "Entity '{}' has mixed port directions - consider grouping inputs and outputs together",
                         entity.name
                     ),
+                    ..Default::default()
                 })
             } else {
                 None
@@ -206,6 +231,7 @@ fn bidirectional_port(input: &Input) -> Vec<Violation> {
                 "Port '{}' is bidirectional (inout) - consider separate in/out ports unless truly needed",
                 port.name
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -224,6 +250,20 @@ fn buffer_port(input: &Input) -> Vec<Violation> {
                 "Port '{}' uses deprecated 'buffer' direction - use 'out' with internal signal instead",
                 port.name
             ),
+            // Only safe where an architecture may read its own `out` ports.
+            fixes: if standards::supports(input, standards::OUTPUT_PORT_READ) {
+                vec![Fix {
+                    file: entity_file(input, port).unwrap_or_default(),
+                    line: port.line,
+                    edit: FixEdit::Replace {
+                        find: "buffer".to_string(),
+                        replacement: "out".to_string(),
+                    },
+                }]
+            } else {
+                Vec::new()
+            },
+            ..Default::default()
         })
         .collect()
 }
@@ -280,6 +320,7 @@ fn trivial_architecture(input: &Input) -> Vec<Violation> {
                     "Architecture '{}' has no processes, concurrent statements, or instances",
                     arch.name
                 ),
+                ..Default::default()
             });
         }
     }
@@ -310,6 +351,7 @@ fn file_entity_mismatch(input: &Input) -> Vec<Violation> {
                         "Entity '{}' is in file '{}' - consider renaming file to '{}.vhd'",
                         entity.name, filename, entity.name
                     ),
+                    ..Default::default()
                 })
             } else {
                 None
@@ -330,10 +372,25 @@ fn unlabeled_generate(input: &Input) -> Vec<Violation> {
             line: gen.line,
             message: "Generate block without label - labels are required for generate blocks"
                 .to_string(),
+            fixes: generate_label_fix(gen).into_iter().collect(),
+            ..Default::default()
         })
         .collect()
 }
 
+/// Labels the generate statement in front of its `for`/`if`/`case` keyword,
+/// naming it after its line so the label is unique within the file.
+fn generate_label_fix(gen: &GenerateStatement) -> Option<Fix> {
+    matches!(gen.kind.as_str(), "for" | "if" | "case").then(|| Fix {
+        file: gen.file.clone(),
+        line: gen.line,
+        edit: FixEdit::InsertBefore {
+            anchor: gen.kind.clone(),
+            text: format!("gen_{}: ", gen.line),
+        },
+    })
+}
+
 fn many_signals(input: &Input) -> Vec<Violation> {
     input
         .entities
@@ -354,6 +411,7 @@ fn many_signals(input: &Input) -> Vec<Violation> {
                         "Entity '{}' has {} signals - consider refactoring into sub-modules",
                         entity.name, signals
                     ),
+                    ..Default::default()
                 })
             } else {
                 None
@@ -378,6 +436,7 @@ fn deep_generate_nesting(input: &Input) -> Vec<Violation> {
                         "Generate block '{}' is deeply nested ({} levels) - consider flattening",
                         gen.label, dots
                     ),
+                    ..Default::default()
                 })
             } else {
                 None
@@ -386,23 +445,19 @@ fn deep_generate_nesting(input: &Input) -> Vec<Violation> {
         .collect()
 }
 
+/// Vector signals sized with bare numbers. A range folds without any scope
+/// only when its bounds are literal arithmetic (`11 downto 0`,
+/// `3*8-1 downto 0`); bounds that name a constant or generic are not magic,
+/// whatever width they resolve to.
 fn magic_width_number(input: &Input) -> Vec<Violation> {
-    let re = Regex::new(r"\(\s*([0-9]+)\s+downto\s+([0-9]+)\s*\)").unwrap();
     input
         .signals
         .iter()
         .filter_map(|sig| {
-            let width = if sig.width > 0 {
-                sig.width as i32
+            let width = if helpers::range_direction(&sig.r#type).is_some() {
+                elaboration::range_width(&sig.r#type, &Generics::new())?
             } else {
-                let lower = sig.r#type.to_ascii_lowercase();
-                if let Some(caps) = re.captures(&lower) {
-                    let high: i32 = caps.get(1)?.as_str().parse().ok()?;
-                    let low: i32 = caps.get(2)?.as_str().parse().ok()?;
-                    high - low + 1
-                } else {
-                    0
-                }
+                sig.width
             };
             if width > 8 && !matches!(width, 16 | 32 | 64 | 128) {
                 return Some(Violation {
@@ -414,6 +469,7 @@ fn magic_width_number(input: &Input) -> Vec<Violation> {
                         "Signal '{}' has magic width {} - consider using a constant",
                         sig.name, width
                     ),
+                    ..Default::default()
                 });
             }
             None
@@ -441,6 +497,7 @@ fn duplicate_signal_in_entity(input: &Input) -> Vec<Violation> {
                     "Signal '{}' declared multiple times in same scope (first at line {})",
                     sig.name, first_line
                 ),
+                ..Default::default()
             });
         } else {
             seen.insert(key, sig.line);
@@ -470,6 +527,7 @@ fn duplicate_port_in_entity(input: &Input) -> Vec<Violation> {
                     "Port '{}' declared multiple times in same entity (first at line {})",
                     port.name, first_line
                 ),
+                ..Default::default()
             });
         } else {
             seen.insert(key, port.line);
@@ -493,6 +551,7 @@ fn duplicate_entity_in_file(input: &Input) -> Vec<Violation> {
                     "Entity '{}' declared multiple times in same file (first at line {})",
                     entity.name, first_line
                 ),
+                ..Default::default()
             });
         } else {
             seen.insert(key, entity.line);
@@ -518,6 +577,7 @@ fn hardcoded_generic(input: &Input) -> Vec<Violation> {
                                 "Instance '{}' has hardcoded generic value '{}' - consider using a constant or generic",
                                 inst.name, value
                             ),
+                            ..Default::default()
                         });
                     }
                 }
@@ -578,6 +638,30 @@ mod tests {
     use super::*;
     use crate::policy::input::{Entity, GenerateStatement, Input, Port, Signal};
 
+    #[test]
+    fn magic_width_number_skips_named_bounds() {
+        let mut input = Input::default();
+        for (name, r#type, width) in [
+            ("raw", "std_logic_vector(11 downto 0)", 12),
+            ("folded", "std_logic_vector(3*4-1 downto 0)", 0),
+            ("named", "std_logic_vector(C_W-1 downto 0)", 12),
+            ("word", "word_t", 24),
+            ("byte", "std_logic_vector(7 downto 0)", 8),
+        ] {
+            input.signals.push(Signal {
+                name: name.to_string(),
+                r#type: r#type.to_string(),
+                width,
+                ..Default::default()
+            });
+        }
+        let flagged: Vec<String> = magic_width_number(&input)
+            .iter()
+            .map(|v| v.message.split('\'').nth(1).unwrap_or("").to_string())
+            .collect();
+        assert_eq!(flagged, ["raw", "folded", "word"]);
+    }
+
     #[test]
     fn very_long_file_flags() {
         let mut input = Input::default();
@@ -675,6 +759,11 @@ mod tests {
         let violations = buffer_port(&input);
         assert_eq!(violations.len(), 1);
         assert_eq!(violations[0].rule, "buffer_port");
+        assert_eq!(violations[0].fixes.len(), 1);
+
+        // Before VHDL-2008 the architecture can't read an `out` port.
+        input.standard = "1993".to_string();
+        assert!(buffer_port(&input)[0].fixes.is_empty());
     }
 
     #[test]
@@ -0,0 +1,132 @@
+//! Column ranges of violations, so editors can underline the identifier a
+//! finding is about rather than the whole line.
+//!
+//! Like the scope, the range is recovered from the facts after the rules ran:
+//! declarations, instances and processes record the columns of their name,
+//! and a violation takes the range of the fact on its line whose name it
+//! quotes (`'name'`). A line with a single located fact only gives its range
+//! to messages naming it, so a finding about an expression on a signal's
+//! declaration line keeps the whole line.
+
+use crate::policy::input::Input;
+use crate::policy::result::Violation;
+use std::collections::HashMap;
+
+/// A name with its start and end columns.
+type Located<'a> = (&'a str, usize, usize);
+
+pub struct Ranges<'a> {
+    /// Located names by file and line.
+    names: HashMap<(&'a str, usize), Vec<Located<'a>>>,
+}
+
+impl<'a> Ranges<'a> {
+    pub fn new(input: &'a Input) -> Self {
+        let mut located: Vec<(&str, usize, &str, usize, usize)> = Vec::new();
+        for e in &input.entities {
+            located.push((&e.file, e.line, &e.name, e.column, e.end_column));
+        }
+        for a in &input.architectures {
+            located.push((&a.file, a.line, &a.name, a.column, a.end_column));
+        }
+        for p in &input.packages {
+            located.push((&p.file, p.line, &p.name, p.column, p.end_column));
+        }
+        for s in &input.signals {
+            located.push((&s.file, s.line, &s.name, s.column, s.end_column));
+        }
+        for e in &input.entities {
+            for p in &e.ports {
+                located.push((&e.file, p.line, &p.name, p.column, p.end_column));
+            }
+        }
+        for i in &input.instances {
+            located.push((&i.file, i.line, &i.name, i.column, i.end_column));
+        }
+        for p in &input.processes {
+            located.push((&p.file, p.line, &p.label, p.column, p.end_column));
+        }
+
+        let mut names: HashMap<(&str, usize), Vec<Located>> = HashMap::new();
+        for (file, line, name, column, end_column) in located {
+            if column > 0 && end_column > column && !name.is_empty() {
+                names
+                    .entry((file, line))
+                    .or_default()
+                    .push((name, column, end_column));
+            }
+        }
+        Ranges { names }
+    }
+
+    /// Start and end columns of the name `message` quotes among the facts on
+    /// `line` of `file`.
+    pub fn at(&self, file: &str, line: usize, message: &str) -> Option<(usize, usize)> {
+        let candidates = self.names.get(&(file, line))?;
+        let message = message.to_ascii_lowercase();
+        candidates
+            .iter()
+            .find(|(name, _, _)| message.contains(&format!("'{}'", name.to_ascii_lowercase())))
+            .map(|&(_, column, end_column)| (column, end_column))
+    }
+
+    /// Sets the column range of `v` unless the rule already did.
+    pub fn annotate(&self, v: &mut Violation) {
+        if v.column > 0 || v.line == 0 {
+            return;
+        }
+        if let Some((column, end_column)) = self.at(&v.file, v.line, &v.message) {
+            v.column = column;
+            v.end_line = v.line;
+            v.end_column = end_column;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{Instance, Signal};
+
+    #[test]
+    fn locates_the_quoted_name() {
+        let mut input = Input::default();
+        for (name, column) in [("a", 12), ("ab", 15)] {
+            input.signals.push(Signal {
+                name: name.to_string(),
+                file: "top.vhd".to_string(),
+                line: 7,
+                column,
+                end_column: column + name.len(),
+                ..Default::default()
+            });
+        }
+        input.instances.push(Instance {
+            name: "u_core".to_string(),
+            file: "top.vhd".to_string(),
+            line: 20,
+            ..Default::default()
+        });
+        let ranges = Ranges::new(&input);
+
+        let mut v = Violation {
+            file: "top.vhd".to_string(),
+            line: 7,
+            message: "Signal 'AB' is never read".to_string(),
+            ..Default::default()
+        };
+        ranges.annotate(&mut v);
+        assert_eq!((v.column, v.end_line, v.end_column), (15, 7, 17));
+
+        v.column = 0;
+        v.message = "Signal ab is never read".to_string();
+        ranges.annotate(&mut v);
+        assert_eq!(v.column, 0);
+
+        // Instances the extractor couldn't locate keep the whole line.
+        v.line = 20;
+        v.message = "Instance 'u_core' has open ports".to_string();
+        ranges.annotate(&mut v);
+        assert_eq!(v.column, 0);
+    }
+}
@@ -1,10 +1,15 @@
+use std::collections::HashSet;
+
 use crate::policy::helpers;
 use crate::policy::input::Input;
-use crate::policy::result::Violation;
+use crate::policy::reset_domains;
+use crate::policy::result::{Confidence, ResetDomainMap, Violation};
 
 pub fn violations(input: &Input) -> Vec<Violation> {
+    let map = reset_domains::infer(input);
     let mut out = Vec::new();
-    out.extend(reset_crosses_domains(input));
+    out.extend(reset_crosses_domains(input, &map));
+    out.extend(reset_domain_crossing_unsynchronized(&map));
     out
 }
 
@@ -14,9 +19,21 @@ pub fn optional_violations(input: &Input) -> Vec<Violation> {
     out.extend(async_reset_unsynchronized(input));
     out.extend(partial_reset_domain(input));
     out.extend(short_reset_sync(input));
+    out.extend(reset_release_unsynchronized(&reset_domains::infer(input)));
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "architectures",
+        "concurrent_assignments",
+        "configurations",
+        "instances",
+        "ports",
+        "processes",
+    ]
+}
+
 fn async_reset_unsynchronized(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     for proc in &input.processes {
@@ -39,6 +56,7 @@ fn async_reset_unsynchronized(input: &Input) -> Vec<Violation> {
                 "Async reset '{}' used directly in process '{}' - needs synchronization to '{}' clock domain",
                 proc.reset_signal, proc.label, proc.clock_signal
             ),
+            ..Default::default()
         });
     }
     out
@@ -72,12 +90,97 @@ fn is_sync_name(assigned: &str, reset_sig: &str) -> bool {
     lower_assigned.contains("sync") && helpers::is_reset_name(assigned)
 }
 
-fn reset_crosses_domains(input: &Input) -> Vec<Violation> {
+/// Async resets whose traced source is a top-level port, combinational
+/// logic or an undriven signal: nothing re-times their release to the
+/// domain's clock.
+fn reset_release_unsynchronized(map: &ResetDomainMap) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for domain in map.domains.iter().filter(|domain| domain.is_async) {
+        let Some(source) = reset_domains::source_of(map, domain) else {
+            continue;
+        };
+        let (origin, confidence) = match source.kind.as_str() {
+            // A port may be synchronized by a parent outside the linted files.
+            "port" => ("top-level input port", Confidence::Medium),
+            "combinational" => ("combinational logic driving", Confidence::High),
+            "signal" => ("signal", Confidence::Medium),
+            _ => continue,
+        };
+        out.push(Violation {
+            rule: "reset_release_unsynchronized".to_string(),
+            severity: "warning".to_string(),
+            file: domain.file.clone(),
+            line: domain.line,
+            message: format!(
+                "Async reset '{}' of clock domain '{}' comes from {} '{}' without a reset synchronizer - its release is not timed to '{}'",
+                domain.reset, domain.clock, origin, source.root, domain.clock
+            ),
+            confidence,
+            ..Default::default()
+        });
+    }
+    out
+}
+
+/// Resets synchronized to (or generated by a register in) one clock domain
+/// that also reset registers of another clock in the same architecture.
+fn reset_domain_crossing_unsynchronized(map: &ResetDomainMap) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for domain in &map.domains {
+        let Some(source) = reset_domains::source_of(map, domain) else {
+            continue;
+        };
+        let origin = match source.kind.as_str() {
+            "synchronizer" => "synchronized to",
+            "register" => "generated in",
+            _ => continue,
+        };
+        if source.through_ports || source.clock.eq_ignore_ascii_case(&domain.clock) {
+            continue;
+        }
+        out.push(Violation {
+            rule: "reset_domain_crossing_unsynchronized".to_string(),
+            severity: "warning".to_string(),
+            file: domain.file.clone(),
+            line: domain.line,
+            message: format!(
+                "Reset '{}' is {} clock domain '{}' but resets registers clocked by '{}' - re-synchronize it in the '{}' domain",
+                domain.reset, origin, source.clock, domain.clock, domain.clock
+            ),
+            ..Default::default()
+        });
+    }
+    out
+}
+
+/// Resets used by several clocks. Resets traced to a synchronizer or register
+/// are left to `reset_domain_crossing_unsynchronized`, which knows which
+/// domain they belong to.
+fn reset_crosses_domains(input: &Input, map: &ResetDomainMap) -> Vec<Violation> {
+    let retimed: HashSet<(&str, String, String)> = map
+        .sources
+        .iter()
+        .filter(|source| matches!(source.kind.as_str(), "synchronizer" | "register"))
+        .map(|source| {
+            (
+                source.file.as_str(),
+                source.in_arch.to_ascii_lowercase(),
+                source.signal.to_ascii_lowercase(),
+            )
+        })
+        .collect();
     let mut out = Vec::new();
     for proc1 in &input.processes {
         if !proc1.has_reset || proc1.reset_signal.is_empty() {
             continue;
         }
+        if retimed.contains(&(
+            proc1.file.as_str(),
+            proc1.in_arch.to_ascii_lowercase(),
+            proc1.reset_signal.to_ascii_lowercase(),
+        )) {
+            continue;
+        }
         for proc2 in &input.processes {
             if !proc2.has_reset || proc2.reset_signal != proc1.reset_signal {
                 continue;
@@ -100,6 +203,7 @@ fn reset_crosses_domains(input: &Input) -> Vec<Violation> {
                     "Reset '{}' used in multiple clock domains ('{}' and '{}') - each domain needs synchronized reset",
                     proc1.reset_signal, proc1.clock_signal, proc2.clock_signal
                 ),
+                ..Default::default()
             });
         }
     }
@@ -134,6 +238,7 @@ fn partial_reset_domain(input: &Input) -> Vec<Violation> {
                     "Process '{}' in clock domain '{}' has no reset, but other processes in same domain do - potential state inconsistency",
                     proc2.label, proc2.clock_signal
                 ),
+                ..Default::default()
             });
         }
     }
@@ -163,6 +268,7 @@ fn combinational_reset_gen(input: &Input) -> Vec<Violation> {
                     "Reset signal '{}' generated by combinational logic - prone to glitches",
                     reset_sig
                 ),
+                ..Default::default()
             });
         }
     }
@@ -194,6 +300,7 @@ fn short_reset_sync(input: &Input) -> Vec<Violation> {
                     "Reset synchronizer '{}' appears to be single-stage - use 2+ stages for metastability",
                     assigned
                 ),
+                ..Default::default()
             });
         }
     }
@@ -219,7 +326,7 @@ fn has_second_stage(signals: &[String], first_stage: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::policy::input::Process;
+    use crate::policy::input::{Process, SequentialAssignment};
 
     #[test]
     fn reset_crosses_domains_flags() {
@@ -242,8 +349,55 @@ mod tests {
             line: 2,
             ..Default::default()
         });
-        let v = reset_crosses_domains(&input);
+        let v = reset_crosses_domains(&input, &reset_domains::infer(&input));
         assert_eq!(v.len(), 1);
         assert_eq!(v[0].rule, "reset_crosses_domains");
     }
+
+    #[test]
+    fn reset_synchronized_in_one_domain_flags_other_clock() {
+        let assign = |target: &str, value: &str| SequentialAssignment {
+            target: target.to_string(),
+            value: value.to_string(),
+            ..Default::default()
+        };
+        let mut input = Input::default();
+        input.processes.push(Process {
+            label: "sync_p".to_string(),
+            is_sequential: true,
+            has_reset: true,
+            reset_signal: "rst_n".to_string(),
+            reset_async: true,
+            clock_signal: "clk_a".to_string(),
+            assigned_signals: vec!["rst_meta".to_string(), "rst_a_n".to_string()],
+            assignments: vec![
+                assign("rst_meta", "'0'"),
+                assign("rst_a_n", "'0'"),
+                assign("rst_meta", "'1'"),
+                assign("rst_a_n", "rst_meta"),
+            ],
+            file: "a.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        for (label, clock, line) in [("p_a", "clk_a", 10), ("p_b", "clk_b", 20)] {
+            input.processes.push(Process {
+                label: label.to_string(),
+                is_sequential: true,
+                has_reset: true,
+                reset_signal: "rst_a_n".to_string(),
+                reset_async: true,
+                clock_signal: clock.to_string(),
+                file: "a.vhd".to_string(),
+                line,
+                ..Default::default()
+            });
+        }
+        let map = reset_domains::infer(&input);
+        let v = reset_domain_crossing_unsynchronized(&map);
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[0].line, 20);
+        assert!(v[0].message.contains("'clk_a'"));
+        assert!(reset_crosses_domains(&input, &map).is_empty());
+    }
 }
@@ -0,0 +1,395 @@
+use crate::policy::helpers::is_testbench_name;
+use crate::policy::input::Input;
+use crate::policy::rule_docs;
+use std::collections::HashSet;
+
+/// Design context a file belongs to. A file is testbench code when it declares
+/// a testbench-named entity; everything else is RTL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Context {
+    Rtl,
+    Tb,
+}
+
+impl Context {
+    pub fn name(self) -> &'static str {
+        match self {
+            Context::Rtl => "rtl",
+            Context::Tb => "tb",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Context> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "rtl" => Some(Context::Rtl),
+            "tb" | "testbench" => Some(Context::Tb),
+            _ => None,
+        }
+    }
+}
+
+pub struct RuleInfo {
+    pub name: &'static str,
+    pub applies_to: &'static [Context],
+    /// Rank in `--fast` mode (lower runs first); `None` keeps the rule out of it.
+    pub fast: Option<FastRank>,
+}
+
+/// Fast-mode metadata: cheap, high-signal rules and the module producing them,
+/// so fast mode only runs the modules it needs.
+pub struct FastRank {
+    pub priority: u8,
+    pub module: &'static str,
+}
+
+const RTL: &[Context] = &[Context::Rtl];
+const TB: &[Context] = &[Context::Tb];
+const ALL: &[Context] = &[Context::Rtl, Context::Tb];
+
+/// Optional rules the `tb` profile switches on.
+pub const TB_PROFILE_RULES: &[&str] = &[
+    "tb_free_running_clock",
+    "tb_missing_wait_termination",
+    "tb_no_assertions",
+    "tb_uninitialized_signal",
+];
+
+/// Rule categories (modules, as `rule_docs` lists them) the `tb` profile
+/// keeps out of testbench files: hardware-inference and synthesis checks.
+const SYNTHESIS_CATEGORIES: &[&str] = &[
+    "cdc",
+    "clocks_resets",
+    "combinational",
+    "latch",
+    "power",
+    "rdc",
+    "sequential",
+    "synthesis",
+];
+
+/// Rules with non-default metadata: scoped away from the default (RTL and
+/// testbench) or ranked for `--fast`. Scoping here is by file; rules that need
+/// finer granularity (e.g. per architecture) keep their own checks.
+pub const RULES: &[RuleInfo] = &[
+    RuleInfo {
+        name: "architecture_has_entity",
+        applies_to: ALL,
+        fast: Some(FastRank {
+            priority: 1,
+            module: "core",
+        }),
+    },
+    RuleInfo {
+        name: "clock_used_as_data",
+        applies_to: RTL,
+        fast: None,
+    },
+    RuleInfo {
+        name: "direct_combinational_loop",
+        applies_to: RTL,
+        fast: None,
+    },
+    RuleInfo {
+        name: "floating_instance_input",
+        applies_to: RTL,
+        fast: None,
+    },
+    RuleInfo {
+        name: "gated_clock_detection",
+        applies_to: RTL,
+        fast: None,
+    },
+    RuleInfo {
+        name: "input_port_driven",
+        applies_to: RTL,
+        fast: None,
+    },
+    RuleInfo {
+        name: "internal_tristate",
+        applies_to: RTL,
+        fast: None,
+    },
+    RuleInfo {
+        name: "metavalue_comparison",
+        applies_to: RTL,
+        fast: None,
+    },
+    RuleInfo {
+        name: "multi_driven_signal",
+        applies_to: ALL,
+        fast: Some(FastRank {
+            priority: 2,
+            module: "signals",
+        }),
+    },
+    RuleInfo {
+        name: "multi_trigger_process",
+        applies_to: RTL,
+        fast: None,
+    },
+    RuleInfo {
+        name: "potential_latch",
+        applies_to: RTL,
+        fast: None,
+    },
+    RuleInfo {
+        name: "signal_init_in_rtl",
+        applies_to: RTL,
+        fast: None,
+    },
+    RuleInfo {
+        name: "tb_free_running_clock",
+        applies_to: TB,
+        fast: None,
+    },
+    RuleInfo {
+        name: "tb_missing_wait_termination",
+        applies_to: TB,
+        fast: None,
+    },
+    RuleInfo {
+        name: "tb_no_assertions",
+        applies_to: TB,
+        fast: None,
+    },
+    RuleInfo {
+        name: "tb_uninitialized_signal",
+        applies_to: TB,
+        fast: None,
+    },
+    RuleInfo {
+        name: "undriven_output_port",
+        applies_to: ALL,
+        fast: Some(FastRank {
+            priority: 3,
+            module: "ports",
+        }),
+    },
+    RuleInfo {
+        name: "undriven_signal",
+        applies_to: ALL,
+        fast: Some(FastRank {
+            priority: 2,
+            module: "signals",
+        }),
+    },
+    RuleInfo {
+        name: "unresolved_dependency",
+        applies_to: ALL,
+        fast: Some(FastRank {
+            priority: 1,
+            module: "core",
+        }),
+    },
+    RuleInfo {
+        name: "unused_signal",
+        applies_to: RTL,
+        fast: None,
+    },
+];
+
+pub fn default_applies_to(rule: &str) -> &'static [Context] {
+    RULES
+        .iter()
+        .find(|info| info.name == rule)
+        .map(|info| info.applies_to)
+        .unwrap_or(ALL)
+}
+
+pub fn is_fast_rule(rule: &str) -> bool {
+    RULES
+        .iter()
+        .any(|info| info.name == rule && info.fast.is_some())
+}
+
+/// Modules producing fast rules, in priority order (each listed once).
+pub fn fast_modules() -> Vec<&'static str> {
+    let mut ranked: Vec<&FastRank> = RULES.iter().filter_map(|info| info.fast.as_ref()).collect();
+    ranked.sort_by_key(|rank| rank.priority);
+    let mut modules = Vec::new();
+    for rank in ranked {
+        if !modules.contains(&rank.module) {
+            modules.push(rank.module);
+        }
+    }
+    modules
+}
+
+pub fn is_tb_profile(input: &Input) -> bool {
+    input.profile.eq_ignore_ascii_case("tb")
+}
+
+/// Whether the input's profile switches on the optional `rule`.
+pub fn profile_enables(input: &Input, rule: &str) -> bool {
+    is_tb_profile(input) && TB_PROFILE_RULES.contains(&rule)
+}
+
+fn is_synthesis_rule(rule: &str) -> bool {
+    rule_docs::lookup(rule).is_some_and(|doc| SYNTHESIS_CATEGORIES.contains(&doc.category.as_str()))
+}
+
+/// Contexts a rule runs in, honoring `lint_config.applies_to` overrides.
+/// Unrecognized context names in an override are ignored. The `tb` profile
+/// scopes synthesis rules to RTL files.
+pub fn applies_to(input: &Input, rule: &str) -> Vec<Context> {
+    match input.lint_config.applies_to.get(rule) {
+        Some(values) => values.iter().filter_map(|v| Context::parse(v)).collect(),
+        None if is_tb_profile(input) && is_synthesis_rule(rule) => RTL.to_vec(),
+        None => default_applies_to(rule).to_vec(),
+    }
+}
+
+/// Classifies files by context once so per-violation lookups stay cheap.
+pub struct FileContexts {
+    testbench_files: HashSet<String>,
+}
+
+impl FileContexts {
+    pub fn new(input: &Input) -> Self {
+        let testbench_files = input
+            .entities
+            .iter()
+            .filter(|entity| is_testbench_name(&entity.name))
+            .map(|entity| entity.file.clone())
+            .collect();
+        Self { testbench_files }
+    }
+
+    pub fn context(&self, file: &str) -> Context {
+        if self.testbench_files.contains(file) {
+            Context::Tb
+        } else {
+            Context::Rtl
+        }
+    }
+}
+
+pub fn rule_applies(input: &Input, contexts: &FileContexts, rule: &str, file: &str) -> bool {
+    applies_to(input, rule).contains(&contexts.context(file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::Entity;
+
+    fn input_with_tb() -> Input {
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "top_tb".to_string(),
+            file: "top_tb.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        input.entities.push(Entity {
+            name: "top".to_string(),
+            file: "top.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        input
+    }
+
+    #[test]
+    fn rtl_only_rules_skip_testbench_files() {
+        let input = input_with_tb();
+        let contexts = FileContexts::new(&input);
+        assert!(rule_applies(
+            &input,
+            &contexts,
+            "potential_latch",
+            "top.vhd"
+        ));
+        assert!(!rule_applies(
+            &input,
+            &contexts,
+            "potential_latch",
+            "top_tb.vhd"
+        ));
+        assert!(rule_applies(
+            &input,
+            &contexts,
+            "multi_driven_signal",
+            "top_tb.vhd"
+        ));
+    }
+
+    #[test]
+    fn config_overrides_applies_to() {
+        let mut input = input_with_tb();
+        input.lint_config.applies_to.insert(
+            "potential_latch".to_string(),
+            vec!["rtl".to_string(), "tb".to_string()],
+        );
+        input
+            .lint_config
+            .applies_to
+            .insert("multi_driven_signal".to_string(), vec!["rtl".to_string()]);
+        let contexts = FileContexts::new(&input);
+        assert!(rule_applies(
+            &input,
+            &contexts,
+            "potential_latch",
+            "top_tb.vhd"
+        ));
+        assert!(!rule_applies(
+            &input,
+            &contexts,
+            "multi_driven_signal",
+            "top_tb.vhd"
+        ));
+        assert!(rule_applies(
+            &input,
+            &contexts,
+            "multi_driven_signal",
+            "top.vhd"
+        ));
+    }
+
+    #[test]
+    fn tb_profile_swaps_synthesis_rules_for_testbench_checks() {
+        let mut input = input_with_tb();
+        let contexts = FileContexts::new(&input);
+        for rule in ["unregistered_output", "incomplete_case_latch"] {
+            assert!(rule_applies(&input, &contexts, rule, "top_tb.vhd"));
+        }
+        assert!(!profile_enables(&input, "tb_no_assertions"));
+
+        input.profile = "tb".to_string();
+        for rule in ["unregistered_output", "incomplete_case_latch"] {
+            assert!(!rule_applies(&input, &contexts, rule, "top_tb.vhd"));
+            assert!(rule_applies(&input, &contexts, rule, "top.vhd"));
+        }
+        assert!(rule_applies(
+            &input,
+            &contexts,
+            "multi_driven_signal",
+            "top_tb.vhd"
+        ));
+        assert!(profile_enables(&input, "tb_no_assertions"));
+        assert!(!rule_applies(
+            &input,
+            &contexts,
+            "tb_no_assertions",
+            "top.vhd"
+        ));
+
+        input
+            .lint_config
+            .applies_to
+            .insert("unregistered_output".to_string(), vec!["tb".to_string()]);
+        assert!(rule_applies(
+            &input,
+            &contexts,
+            "unregistered_output",
+            "top_tb.vhd"
+        ));
+    }
+
+    #[test]
+    fn fast_modules_follow_priority() {
+        assert_eq!(fast_modules(), vec!["core", "signals", "ports"]);
+        assert!(is_fast_rule("multi_driven_signal"));
+        assert!(!is_fast_rule("potential_latch"));
+    }
+}
@@ -0,0 +1,346 @@
+use crate::policy::result::{Result, SeverityCounts, Summary};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// Weight of each finding when computing the health score. Info findings are
+/// advisory and do not lower the score.
+const ERROR_WEIGHT: usize = 5;
+const WARNING_WEIGHT: usize = 1;
+
+/// Rules listed under "Top rules" in the markdown summary.
+const TOP_RULES: usize = 5;
+
+/// Badge in the shields.io endpoint format
+/// (<https://shields.io/badges/endpoint-badge>), also rendered as SVG.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct Badge {
+    #[serde(rename = "schemaVersion")]
+    pub schema_version: u8,
+    pub label: String,
+    pub message: String,
+    pub color: String,
+}
+
+/// Health score in 0..=100: 100 for a clean run, decaying with the weighted
+/// finding density per linted file so large projects aren't pinned at zero.
+pub fn health_score(summary: &Summary, file_count: usize) -> u8 {
+    let weighted = summary.errors * ERROR_WEIGHT + summary.warnings * WARNING_WEIGHT;
+    let files = file_count.max(1) as f64;
+    let score = 100.0 / (1.0 + weighted as f64 / files);
+    score.round() as u8
+}
+
+pub fn badge(summary: &Summary, file_count: usize) -> Badge {
+    let score = health_score(summary, file_count);
+    Badge {
+        schema_version: 1,
+        label: "vhdl-lint".to_string(),
+        message: format!(
+            "{} · {} · {}%",
+            plural(summary.errors, "error"),
+            plural(summary.warnings, "warning"),
+            score
+        ),
+        color: badge_color(summary, score).to_string(),
+    }
+}
+
+pub fn badge_json(badge: &Badge) -> String {
+    serde_json::to_string_pretty(badge).unwrap_or_default()
+}
+
+/// Flat two-part badge matching the shields.io look.
+pub fn badge_svg(badge: &Badge) -> String {
+    let label_width = text_width(&badge.label);
+    let message_width = text_width(&badge.message);
+    let width = label_width + message_width;
+    let label = escape_xml(&badge.label);
+    let message = escape_xml(&badge.message);
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="20" role="img" aria-label="{label}: {message}">
+<title>{label}: {message}</title>
+<linearGradient id="s" x2="0" y2="100%"><stop offset="0" stop-color="#bbb" stop-opacity=".1"/><stop offset="1" stop-opacity=".1"/></linearGradient>
+<clipPath id="r"><rect width="{width}" height="20" rx="3" fill="#fff"/></clipPath>
+<g clip-path="url(#r)"><rect width="{label_width}" height="20" fill="#555"/><rect x="{label_width}" width="{message_width}" height="20" fill="{color}"/><rect width="{width}" height="20" fill="url(#s)"/></g>
+<g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">
+<text x="{label_x}" y="14">{label}</text>
+<text x="{message_x}" y="14">{message}</text>
+</g>
+</svg>
+"##,
+        color = color_hex(&badge.color),
+        label_x = label_width / 2,
+        message_x = label_width + message_width / 2,
+    )
+}
+
+/// Standalone HTML report of the summary with the directory and library
+/// rollups, heaviest debt (errors weighted as in the health score) first.
+pub fn summary_html(summary: &Summary) -> String {
+    let mut html = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>vhdl-lint summary</title>\n\
+         <style>body{font-family:sans-serif}table{border-collapse:collapse;margin-bottom:1.5em}\
+         th,td{border:1px solid #ccc;padding:4px 10px;text-align:right}\
+         th:first-child,td:first-child{text-align:left}</style>\n</head>\n<body>\n",
+    );
+    html.push_str(&format!(
+        "<h1>vhdl-lint summary</h1>\n<p>{} · {} · {}</p>\n",
+        plural(summary.errors, "error"),
+        plural(summary.warnings, "warning"),
+        plural(summary.info, "info finding"),
+    ));
+    html.push_str(&rollup_table("Directory", &summary.by_directory));
+    html.push_str(&rollup_table("Library", &summary.by_library));
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// Pull-request comment (`--format markdown`): severity counts, the rules
+/// that fired most and a table of the first `limit` violations, errors
+/// first. With a `link_template` such as
+/// `https://github.com/org/repo/blob/main/{file}#L{line}` each location
+/// links to the repository browser.
+pub fn summary_markdown(result: &Result, limit: usize, link_template: &str) -> String {
+    let summary = &result.summary;
+    if summary.total_violations == 0 {
+        return "### vhdl-lint: no violations\n".to_string();
+    }
+    let mut md = format!(
+        "### vhdl-lint: {} · {} · {}\n\n",
+        plural(summary.errors, "error"),
+        plural(summary.warnings, "warning"),
+        plural(summary.info, "info finding"),
+    );
+
+    let mut by_rule: HashMap<&str, usize> = HashMap::new();
+    for v in &result.violations {
+        *by_rule.entry(v.rule.as_str()).or_default() += 1;
+    }
+    let mut rules: Vec<(&str, usize)> = by_rule.into_iter().collect();
+    rules.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    let top: Vec<String> = rules
+        .iter()
+        .take(TOP_RULES)
+        .map(|(rule, count)| format!("`{}` ({})", rule, count))
+        .collect();
+    md.push_str(&format!("**Top rules:** {}\n\n", top.join(", ")));
+
+    let mut ordered: Vec<_> = result.violations.iter().collect();
+    ordered.sort_by_key(|v| (severity_rank(&v.severity), &v.file, v.line));
+    md.push_str("| Severity | Location | Rule | Message |\n|---|---|---|---|\n");
+    for v in ordered.iter().take(limit) {
+        let location = format!("`{}:{}`", v.file, v.line);
+        let location = if link_template.is_empty() {
+            location
+        } else {
+            let url = link_template
+                .replace("{file}", &v.file)
+                .replace("{line}", &v.line.to_string());
+            format!("[{}]({})", location, url)
+        };
+        md.push_str(&format!(
+            "| {} | {} | `{}` | {} |\n",
+            v.severity,
+            location,
+            v.rule,
+            v.message.replace('|', "\\|").replace('\n', " ")
+        ));
+    }
+    if ordered.len() > limit {
+        md.push_str(&format!("\n_…and {} more._\n", ordered.len() - limit));
+    }
+    md
+}
+
+fn severity_rank(severity: &str) -> u8 {
+    match severity {
+        "error" => 0,
+        "warning" => 1,
+        _ => 2,
+    }
+}
+
+fn rollup_table(heading: &str, rollup: &BTreeMap<String, SeverityCounts>) -> String {
+    let mut rows: Vec<(&String, &SeverityCounts)> = rollup.iter().collect();
+    rows.sort_by_key(|(_, counts)| {
+        std::cmp::Reverse(counts.errors * ERROR_WEIGHT + counts.warnings * WARNING_WEIGHT)
+    });
+    let mut table = format!(
+        "<h2>By {}</h2>\n<table>\n<tr><th>{}</th><th>Total</th><th>Errors</th>\
+         <th>Warnings</th><th>Info</th></tr>\n",
+        heading.to_ascii_lowercase(),
+        heading
+    );
+    for (name, counts) in rows {
+        table.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            escape_xml(name),
+            counts.total_violations,
+            counts.errors,
+            counts.warnings,
+            counts.info
+        ));
+    }
+    table.push_str("</table>\n");
+    table
+}
+
+fn badge_color(summary: &Summary, score: u8) -> &'static str {
+    if summary.errors > 0 && score >= 75 {
+        return "yellow";
+    }
+    match score {
+        90..=100 => "brightgreen",
+        75..=89 => "green",
+        50..=74 => "yellow",
+        25..=49 => "orange",
+        _ => "red",
+    }
+}
+
+fn color_hex(color: &str) -> &'static str {
+    match color {
+        "brightgreen" => "#4c1",
+        "green" => "#97ca00",
+        "yellow" => "#dfb317",
+        "orange" => "#fe7d37",
+        _ => "#e05d44",
+    }
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
+
+/// Approximate Verdana 11px advance (~7px per character) plus padding.
+fn text_width(text: &str) -> usize {
+    text.chars().count() * 7 + 10
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::result::Violation;
+
+    fn summary(errors: usize, warnings: usize, info: usize) -> Summary {
+        Summary {
+            total_violations: errors + warnings + info,
+            errors,
+            warnings,
+            info,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn clean_run_scores_full_health() {
+        let badge = badge(&summary(0, 0, 3), 4);
+        assert_eq!(health_score(&summary(0, 0, 3), 4), 100);
+        assert_eq!(badge.message, "0 errors · 0 warnings · 100%");
+        assert_eq!(badge.color, "brightgreen");
+    }
+
+    #[test]
+    fn score_scales_with_finding_density() {
+        assert_eq!(health_score(&summary(0, 2, 0), 10), 83);
+        assert_eq!(health_score(&summary(0, 2, 0), 100), 98);
+        assert_eq!(health_score(&summary(2, 0, 0), 0), 9);
+    }
+
+    #[test]
+    fn errors_cap_badge_color() {
+        let badge = badge(&summary(1, 0, 0), 100);
+        assert_eq!(badge.message, "1 error · 0 warnings · 95%");
+        assert_eq!(badge.color, "yellow");
+    }
+
+    #[test]
+    fn markdown_lists_errors_first_with_links() {
+        let violation = |rule: &str, severity: &str, line| Violation {
+            rule: rule.to_string(),
+            severity: severity.to_string(),
+            file: "rtl/top.vhd".to_string(),
+            line,
+            message: format!("{} | here", rule),
+            ..Default::default()
+        };
+        let result = Result {
+            violations: vec![
+                violation("unused_signal", "warning", 3),
+                violation("unused_signal", "warning", 9),
+                violation("latch_inferred", "error", 20),
+            ],
+            summary: summary(1, 2, 0),
+            ..Default::default()
+        };
+        let md = summary_markdown(&result, 2, "https://example.com/blob/main/{file}#L{line}");
+        assert!(md.starts_with("### vhdl-lint: 1 error · 2 warnings · 0 info findings\n"));
+        assert!(md.contains("**Top rules:** `unused_signal` (2), `latch_inferred` (1)\n"));
+        let rows: Vec<&str> = md
+            .lines()
+            .filter(|l| l.starts_with("| ") && !l.starts_with("| Severity"))
+            .collect();
+        assert_eq!(
+            rows[0],
+            "| error | [`rtl/top.vhd:20`](https://example.com/blob/main/rtl/top.vhd#L20) | `latch_inferred` | latch_inferred \\| here |"
+        );
+        assert_eq!(rows.len(), 2);
+        assert!(md.ends_with("_…and 1 more._\n"));
+
+        assert_eq!(
+            summary_markdown(&Result::default(), 20, ""),
+            "### vhdl-lint: no violations\n"
+        );
+    }
+
+    #[test]
+    fn badge_serializes_as_shields_endpoint() {
+        let json = badge_json(&badge(&summary(0, 1, 0), 1));
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["schemaVersion"], 1);
+        assert_eq!(value["label"], "vhdl-lint");
+        assert_eq!(value["message"], "0 errors · 1 warning · 50%");
+    }
+
+    #[test]
+    fn svg_contains_escaped_text() {
+        let svg = badge_svg(&Badge {
+            schema_version: 1,
+            label: "lint".to_string(),
+            message: "a<b".to_string(),
+            color: "red".to_string(),
+        });
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("a&lt;b"));
+        assert!(svg.contains("#e05d44"));
+    }
+    #[test]
+    fn html_rollups_list_heaviest_area_first() {
+        let mut s = summary(3, 1, 0);
+        let counts = |errors, warnings| SeverityCounts {
+            total_violations: errors + warnings,
+            errors,
+            warnings,
+            info: 0,
+        };
+        s.by_directory.insert("a_clean".to_string(), counts(0, 1));
+        s.by_directory.insert("z_<dma>".to_string(), counts(3, 0));
+        s.by_library.insert("work".to_string(), counts(3, 1));
+        let html = summary_html(&s);
+        assert!(html.find("z_&lt;dma&gt;").unwrap() < html.find("a_clean").unwrap());
+        assert!(html.contains("<h2>By library</h2>"));
+        assert!(html.contains("<td>work</td><td>4</td><td>3</td><td>1</td><td>0</td>"));
+    }
+}
@@ -0,0 +1,603 @@
+//! Reset-domain inference. A clocked process with a reset puts the registers
+//! it assigns in the domain of that reset and its clock (per architecture).
+//! Each domain's reset is traced to its source through plain concurrent
+//! copies (`rst <= x`, `rst <= not x`) and, for input ports, up through the
+//! instances connecting them: a reset synchronizer, another register,
+//! combinational logic, or a top-level port.
+//!
+//! A reset synchronizer is a chain of plain register copies in one clocked
+//! process whose first stage either loads constants while the raw reset is
+//! asserted asynchronously (async assert, sync deassert) or samples the raw
+//! reset as data. Processes that do nothing else are reported as
+//! synchronizers only, not as members of the raw reset's domain.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::policy::helpers;
+use crate::policy::hierarchy;
+use crate::policy::input::{Input, Process};
+use crate::policy::result::{ResetDomain, ResetDomainMap, ResetSource, ResetSynchronizer};
+
+/// Copies, aliases and port hops followed before a trace gives up.
+const MAX_TRACE: usize = 16;
+
+/// Domains in order of their first process, sources in domain order.
+pub fn infer(input: &Input) -> ResetDomainMap {
+    let clocked: Vec<&Process> = input
+        .processes
+        .iter()
+        .filter(|proc| proc.is_sequential && !proc.clock_signal.is_empty())
+        .filter(|proc| !helpers::process_in_testbench(input, proc))
+        .collect();
+
+    let reset_names: HashSet<String> = clocked
+        .iter()
+        .filter(|proc| proc.has_reset && !proc.reset_signal.is_empty())
+        .map(|proc| proc.reset_signal.to_ascii_lowercase())
+        .collect();
+    let mut synchronizers = Vec::new();
+    let mut sync_procs = HashSet::new();
+    for (idx, proc) in clocked.iter().enumerate() {
+        if let Some((sync, dedicated)) = synchronizer(input, proc, &reset_names) {
+            synchronizers.push(sync);
+            if dedicated {
+                sync_procs.insert(idx);
+            }
+        }
+    }
+
+    let mut domains: Vec<ResetDomain> = Vec::new();
+    let mut domain_ids: HashMap<(String, String, String, String, bool), usize> = HashMap::new();
+    for (idx, proc) in clocked.iter().enumerate() {
+        if !proc.has_reset || proc.reset_signal.is_empty() || sync_procs.contains(&idx) {
+            continue;
+        }
+        let is_async =
+            proc.reset_async || helpers::signal_in_list(&proc.reset_signal, &proc.sensitivity_list);
+        let key = (
+            proc.file.clone(),
+            proc.in_arch.to_ascii_lowercase(),
+            proc.reset_signal.to_ascii_lowercase(),
+            proc.clock_signal.to_ascii_lowercase(),
+            is_async,
+        );
+        let id = *domain_ids.entry(key).or_insert_with(|| {
+            domains.push(ResetDomain {
+                reset: proc.reset_signal.clone(),
+                clock: proc.clock_signal.clone(),
+                is_async,
+                in_arch: proc.in_arch.clone(),
+                file: proc.file.clone(),
+                line: proc.line,
+                processes: Vec::new(),
+                registers: Vec::new(),
+            });
+            domains.len() - 1
+        });
+        let domain = &mut domains[id];
+        domain.processes.push(helpers::process_name(proc));
+        for sig in &proc.assigned_signals {
+            if helpers::is_actual_signal(input, sig)
+                && !domain.registers.iter().any(|r| r.eq_ignore_ascii_case(sig))
+            {
+                domain.registers.push(sig.clone());
+            }
+        }
+    }
+
+    let tracer = Tracer {
+        input,
+        clocked: &clocked,
+        synchronizers: &synchronizers,
+    };
+    let mut sources = Vec::new();
+    let mut seen = HashSet::new();
+    for domain in &domains {
+        let key = (
+            domain.file.clone(),
+            domain.in_arch.to_ascii_lowercase(),
+            domain.reset.to_ascii_lowercase(),
+        );
+        if seen.insert(key) {
+            sources.push(tracer.source(&domain.file, &domain.in_arch, &domain.reset));
+        }
+    }
+
+    ResetDomainMap {
+        domains,
+        sources,
+        synchronizers,
+    }
+}
+
+/// The traced source of `domain`'s reset in `map`.
+pub fn source_of<'a>(map: &'a ResetDomainMap, domain: &ResetDomain) -> Option<&'a ResetSource> {
+    map.sources.iter().find(|source| {
+        source.file == domain.file
+            && source.in_arch.eq_ignore_ascii_case(&domain.in_arch)
+            && source.signal.eq_ignore_ascii_case(&domain.reset)
+    })
+}
+
+/// The longest plain-copy chain in `proc` that starts at a reset
+/// synchronizer's first stage, if there is one, with whether the chain is
+/// all the process assigns.
+fn synchronizer(
+    input: &Input,
+    proc: &Process,
+    reset_names: &HashSet<String>,
+) -> Option<(ResetSynchronizer, bool)> {
+    let mut values: Vec<(String, Vec<&str>)> = Vec::new();
+    for assign in &proc.assignments {
+        let target = assign.target.to_ascii_lowercase();
+        let value = assign.value.trim();
+        match values.iter_mut().find(|(t, _)| *t == target) {
+            Some((_, vals)) => vals.push(value),
+            None => values.push((target, vec![value])),
+        }
+    }
+    let assigned: HashSet<&str> = values.iter().map(|(t, _)| t.as_str()).collect();
+    let is_reset = |name: &str| {
+        reset_names.contains(&name.to_ascii_lowercase()) || helpers::is_reset_name(name)
+    };
+    let async_reset = proc.has_reset
+        && !proc.reset_signal.is_empty()
+        && (proc.reset_async
+            || helpers::signal_in_list(&proc.reset_signal, &proc.sensitivity_list));
+
+    let mut best: Option<(ResetSynchronizer, bool)> = None;
+    for (head, vals) in &values {
+        if vals.iter().any(|v| v.is_empty()) {
+            continue;
+        }
+        let sources: HashSet<String> = vals
+            .iter()
+            .filter(|v| !is_literal(v))
+            .map(|v| v.to_ascii_lowercase())
+            .collect();
+        let literals: HashSet<&str> = vals.iter().copied().filter(|v| is_literal(v)).collect();
+        let (raw, async_assert) = if sources.is_empty() && literals.len() > 1 && async_reset {
+            (proc.reset_signal.clone(), true)
+        } else if sources.len() == 1 {
+            let raw = sources.iter().next().expect("one source");
+            if !is_identifier(raw) || assigned.contains(raw.as_str()) || !is_reset(raw) {
+                continue;
+            }
+            let raw = proc
+                .read_signals
+                .iter()
+                .find(|sig| sig.eq_ignore_ascii_case(raw))
+                .cloned()
+                .unwrap_or_else(|| raw.clone());
+            // Sampling the raw reset that also clears the chain is still
+            // async assert, sync deassert.
+            let async_assert = async_reset && raw.eq_ignore_ascii_case(&proc.reset_signal);
+            (raw, async_assert)
+        } else {
+            continue;
+        };
+
+        let mut output = head.clone();
+        let mut stages = 1;
+        let mut visited = HashSet::from([head.clone()]);
+        while stages < MAX_TRACE {
+            let next = values.iter().find(|(target, vals)| {
+                !visited.contains(target)
+                    && vals.iter().any(|v| v.eq_ignore_ascii_case(&output))
+                    && vals
+                        .iter()
+                        .all(|v| is_literal(v) || v.eq_ignore_ascii_case(&output))
+            });
+            let Some((next, _)) = next else {
+                break;
+            };
+            visited.insert(next.clone());
+            output = next.clone();
+            stages += 1;
+        }
+        // An async-assert chain must end in something used as a reset, or
+        // any register loading constants would qualify.
+        if sources.is_empty()
+            && !is_reset(&output)
+            && !drives_reset(input, proc, &output, reset_names)
+        {
+            continue;
+        }
+        if best.as_ref().is_some_and(|(b, _)| b.stages >= stages) {
+            continue;
+        }
+        let output = proc
+            .assigned_signals
+            .iter()
+            .find(|sig| sig.eq_ignore_ascii_case(&output))
+            .cloned()
+            .unwrap_or(output);
+        let dedicated = values.iter().all(|(target, _)| visited.contains(target));
+        best = Some((
+            ResetSynchronizer {
+                input: raw,
+                output,
+                clock: proc.clock_signal.clone(),
+                stages,
+                async_assert,
+                process: helpers::process_name(proc),
+                in_arch: proc.in_arch.clone(),
+                file: proc.file.clone(),
+                line: proc.line,
+            },
+            dedicated,
+        ));
+    }
+    best
+}
+
+/// Whether `signal` feeds, directly or through one concurrent copy, the
+/// reset of some process.
+fn drives_reset(
+    input: &Input,
+    proc: &Process,
+    signal: &str,
+    reset_names: &HashSet<String>,
+) -> bool {
+    let lower = signal.to_ascii_lowercase();
+    reset_names.contains(&lower)
+        || input.concurrent_assignments.iter().any(|ca| {
+            ca.file == proc.file
+                && ca.in_arch.eq_ignore_ascii_case(&proc.in_arch)
+                && reset_names.contains(&ca.target.to_ascii_lowercase())
+                && alias_of(&ca.value).is_some_and(|src| src.eq_ignore_ascii_case(signal))
+        })
+}
+
+struct Tracer<'a> {
+    input: &'a Input,
+    clocked: &'a [&'a Process],
+    synchronizers: &'a [ResetSynchronizer],
+}
+
+impl Tracer<'_> {
+    fn source(&self, file: &str, in_arch: &str, reset: &str) -> ResetSource {
+        let mut source = ResetSource {
+            signal: reset.to_string(),
+            in_arch: in_arch.to_string(),
+            file: file.to_string(),
+            kind: "signal".to_string(),
+            root: reset.to_string(),
+            root_file: file.to_string(),
+            root_line: 0,
+            clock: String::new(),
+            through_ports: false,
+        };
+        self.trace(file, in_arch, reset, 0, &mut source);
+        source
+    }
+
+    fn trace(&self, file: &str, in_arch: &str, name: &str, depth: usize, out: &mut ResetSource) {
+        out.root = name.to_string();
+        out.root_file = file.to_string();
+        if depth >= MAX_TRACE {
+            return;
+        }
+        let in_scope = |f: &str, arch: &str| f == file && arch.eq_ignore_ascii_case(in_arch);
+
+        if let Some(ca) = self
+            .input
+            .concurrent_assignments
+            .iter()
+            .find(|ca| in_scope(&ca.file, &ca.in_arch) && ca.target.eq_ignore_ascii_case(name))
+        {
+            out.root_line = ca.line;
+            match alias_of(&ca.value) {
+                Some(src) => self.trace(file, in_arch, src, depth + 1, out),
+                None => out.kind = "combinational".to_string(),
+            }
+            return;
+        }
+        if let Some(sync) = self
+            .synchronizers
+            .iter()
+            .find(|s| in_scope(&s.file, &s.in_arch) && s.output.eq_ignore_ascii_case(name))
+        {
+            out.kind = "synchronizer".to_string();
+            out.clock = sync.clock.clone();
+            out.root_line = sync.line;
+            return;
+        }
+        if let Some(proc) = self.clocked.iter().find(|proc| {
+            in_scope(&proc.file, &proc.in_arch)
+                && proc
+                    .assigned_signals
+                    .iter()
+                    .any(|sig| sig.eq_ignore_ascii_case(name))
+        }) {
+            out.kind = "register".to_string();
+            out.clock = proc.clock_signal.clone();
+            out.root_line = proc.line;
+            return;
+        }
+        let Some(entity) = self
+            .input
+            .architectures
+            .iter()
+            .find(|arch| in_scope(&arch.file, &arch.name))
+            .map(|arch| arch.entity_name.as_str())
+        else {
+            return;
+        };
+        let Some(port) = self.input.ports.iter().find(|port| {
+            port.in_entity.eq_ignore_ascii_case(entity)
+                && port.name.eq_ignore_ascii_case(name)
+                && port.direction.eq_ignore_ascii_case("in")
+        }) else {
+            out.root_line = self
+                .input
+                .signals
+                .iter()
+                .find(|sig| {
+                    sig.file == file
+                        && sig.in_entity.eq_ignore_ascii_case(in_arch)
+                        && sig.name.eq_ignore_ascii_case(name)
+                })
+                .map_or(0, |sig| sig.line);
+            return;
+        };
+
+        // An input port: follow the instances that connect it. With several,
+        // the first unsafe connection wins so one bad parent is not hidden.
+        let mut traced: Option<ResetSource> = None;
+        for inst in &self.input.instances {
+            if !hierarchy::bound_entities(self.input, inst)
+                .iter()
+                .any(|e| e.name.eq_ignore_ascii_case(entity))
+            {
+                continue;
+            }
+            let Some(actual) = inst
+                .port_map
+                .iter()
+                .find(|(formal, _)| formal.eq_ignore_ascii_case(name))
+                .map(|(_, actual)| actual.trim())
+            else {
+                continue;
+            };
+            let mut parent = out.clone();
+            parent.through_ports = true;
+            match alias_of(actual) {
+                Some(src) => self.trace(&inst.file, &inst.in_arch, src, depth + 1, &mut parent),
+                None => {
+                    parent.kind = "combinational".to_string();
+                    parent.root = actual.to_string();
+                    parent.root_file = inst.file.clone();
+                    parent.root_line = inst.line;
+                }
+            }
+            let unsafe_source = matches!(parent.kind.as_str(), "port" | "combinational" | "signal");
+            if traced.is_none() || unsafe_source {
+                traced = Some(parent);
+            }
+            if unsafe_source {
+                break;
+            }
+        }
+        match traced {
+            Some(parent) => *out = parent,
+            None => {
+                out.kind = "port".to_string();
+                out.root_line = port.line;
+            }
+        }
+    }
+}
+
+/// Source of a plain copy or inversion (`x`, `not x`, `(not x)`).
+fn alias_of(value: &str) -> Option<&str> {
+    let mut value = value.trim();
+    while let Some(inner) = value.strip_prefix('(').and_then(|v| v.strip_suffix(')')) {
+        value = inner.trim();
+    }
+    if value
+        .get(..4)
+        .is_some_and(|kw| kw.eq_ignore_ascii_case("not "))
+    {
+        value = value[4..].trim();
+        while let Some(inner) = value.strip_prefix('(').and_then(|v| v.strip_suffix(')')) {
+            value = inner.trim();
+        }
+    }
+    is_identifier(value).then_some(value)
+}
+
+fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn is_literal(value: &str) -> bool {
+    value.len() == 3 && value.starts_with('\'') && value.ends_with('\'')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{
+        Architecture, ConcurrentAssignment, Entity, Instance, Port, SequentialAssignment,
+    };
+
+    fn clocked(
+        label: &str,
+        clock: &str,
+        reset: &str,
+        line: usize,
+        assigns: &[(&str, &str)],
+    ) -> Process {
+        Process {
+            label: label.to_string(),
+            is_sequential: true,
+            clock_signal: clock.to_string(),
+            has_reset: !reset.is_empty(),
+            reset_signal: reset.to_string(),
+            reset_async: !reset.is_empty(),
+            assigned_signals: assigns.iter().map(|(t, _)| t.to_string()).collect(),
+            read_signals: assigns.iter().map(|(_, v)| v.to_string()).collect(),
+            assignments: assigns
+                .iter()
+                .enumerate()
+                .map(|(i, (target, value))| SequentialAssignment {
+                    target: target.to_string(),
+                    value: value.to_string(),
+                    line: line + i + 1,
+                    ..Default::default()
+                })
+                .collect(),
+            file: "top.vhd".to_string(),
+            line,
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn design() -> Input {
+        let mut input = Input::default();
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "top".to_string(),
+            file: "top.vhd".to_string(),
+            line: 10,
+            ..Default::default()
+        });
+        for name in ["clk_a", "clk_b", "rst_n"] {
+            input.ports.push(Port {
+                name: name.to_string(),
+                direction: "in".to_string(),
+                in_entity: "top".to_string(),
+                line: 3,
+                ..Default::default()
+            });
+        }
+        input
+    }
+
+    #[test]
+    fn finds_async_assert_synchronizer_and_traces_aliases() {
+        let mut input = design();
+        input.processes.push(clocked(
+            "p_sync",
+            "clk_a",
+            "rst_n",
+            20,
+            &[("r1", "'0'"), ("r2", "'0'"), ("r1", "'1'"), ("r2", "r1")],
+        ));
+        input.concurrent_assignments.push(ConcurrentAssignment {
+            target: "rst_a".to_string(),
+            value: "not r2".to_string(),
+            file: "top.vhd".to_string(),
+            line: 30,
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        });
+        input
+            .processes
+            .push(clocked("p_a", "clk_a", "rst_a", 40, &[("q", "d")]));
+        input
+            .processes
+            .push(clocked("p_b", "clk_b", "rst_a", 50, &[("q_b", "d")]));
+
+        let map = infer(&input);
+        assert_eq!(map.synchronizers.len(), 1);
+        let sync = &map.synchronizers[0];
+        assert_eq!((sync.input.as_str(), sync.output.as_str()), ("rst_n", "r2"));
+        assert_eq!(sync.stages, 2);
+        assert!(sync.async_assert);
+
+        assert_eq!(map.domains.len(), 2);
+        assert_eq!(map.domains[0].registers, ["q"]);
+        assert_eq!(map.sources.len(), 1);
+        let source = &map.sources[0];
+        assert_eq!(source.kind, "synchronizer");
+        assert_eq!(source.root, "r2");
+        assert_eq!(source.clock, "clk_a");
+        assert!(!source.through_ports);
+    }
+
+    #[test]
+    fn classifies_ports_and_combinational_resets() {
+        let mut input = design();
+        input
+            .processes
+            .push(clocked("p_port", "clk_a", "rst_n", 20, &[("q", "d")]));
+        input.concurrent_assignments.push(ConcurrentAssignment {
+            target: "rst_comb".to_string(),
+            value: String::new(),
+            read_signals: vec!["rst_n".to_string(), "lock".to_string()],
+            file: "top.vhd".to_string(),
+            line: 30,
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        });
+        input
+            .processes
+            .push(clocked("p_comb", "clk_a", "rst_comb", 40, &[("q2", "d")]));
+
+        let map = infer(&input);
+        assert!(map.synchronizers.is_empty());
+        let kinds: Vec<&str> = map.sources.iter().map(|s| s.kind.as_str()).collect();
+        assert_eq!(kinds, ["port", "combinational"]);
+        assert_eq!(map.sources[0].root_line, 3);
+        assert_eq!(map.sources[1].root_line, 30);
+    }
+
+    #[test]
+    fn follows_ports_up_to_the_parent_synchronizer() {
+        let mut input = design();
+        input.entities.push(Entity {
+            name: "leaf".to_string(),
+            file: "leaf.vhd".to_string(),
+            ..Default::default()
+        });
+        input.architectures.push(Architecture {
+            name: "beh".to_string(),
+            entity_name: "leaf".to_string(),
+            file: "leaf.vhd".to_string(),
+            line: 5,
+            ..Default::default()
+        });
+        input.ports.push(Port {
+            name: "rst".to_string(),
+            direction: "in".to_string(),
+            in_entity: "leaf".to_string(),
+            ..Default::default()
+        });
+        let mut leaf = clocked("p_leaf", "clk", "rst", 8, &[("q", "d")]);
+        leaf.file = "leaf.vhd".to_string();
+        leaf.in_arch = "beh".to_string();
+        input.processes.push(leaf);
+        input.processes.push(clocked(
+            "p_sync",
+            "clk_a",
+            "",
+            20,
+            &[("rst_meta", "rst_n"), ("rst_sync", "rst_meta")],
+        ));
+        input.instances.push(Instance {
+            name: "u_leaf".to_string(),
+            target: "work.leaf".to_string(),
+            port_map: [("rst".to_string(), "rst_sync".to_string())].into(),
+            file: "top.vhd".to_string(),
+            line: 30,
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        });
+
+        let map = infer(&input);
+        assert_eq!(map.synchronizers.len(), 1);
+        assert!(!map.synchronizers[0].async_assert);
+        assert_eq!(map.synchronizers[0].output, "rst_sync");
+        let source = &map.sources[0];
+        assert_eq!(source.signal, "rst");
+        assert_eq!(source.kind, "synchronizer");
+        assert_eq!(source.root, "rst_sync");
+        assert_eq!(source.root_file, "top.vhd");
+        assert!(source.through_ports);
+    }
+}
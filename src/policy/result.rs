@@ -1,13 +1,106 @@
+use crate::policy::input::CDCCrossing;
+use crate::policy::rule_docs::RuleDoc;
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
 pub struct Violation {
     pub rule: String,
     pub severity: String,
     pub file: String,
     pub line: usize,
+    /// 1-based column range (end exclusive) of the name the violation is
+    /// about, when the facts locate it; 0 otherwise (see `policy::ranges`).
+    #[serde(skip_serializing_if = "is_zero")]
+    pub column: usize,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub end_line: usize,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub end_column: usize,
     pub message: String,
+    /// Hierarchical path of the code the violation points at,
+    /// `entity:arch[:generate...][:process]`; empty outside architectures
+    /// (see `policy::scope`).
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub scope: String,
+    /// How sure the rule is. Heuristic rules (name-based clock/reset
+    /// detection, memory inference) report less than `high`, raising it when
+    /// the design confirms the guess.
+    pub confidence: Confidence,
+    /// Edits that resolve the violation, for rules that can compute a safe
+    /// fix. `--fix` applies them (see `policy::fixes`).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fixes: Vec<Fix>,
+    /// The source lines with carets under the range, when output asks for
+    /// snippets (`--with-snippets`, see `policy::source_map`).
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub snippet: String,
+    /// Stable id of the violation within its result (see
+    /// `baseline::occurrence_fingerprints`), set by `engine::evaluate`.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub fingerprint: String,
+}
+
+fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Confidence {
+    Low,
+    Medium,
+    #[default]
+    High,
+}
+
+impl Confidence {
+    pub fn name(self) -> &'static str {
+        match self {
+            Confidence::Low => "low",
+            Confidence::Medium => "medium",
+            Confidence::High => "high",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Confidence> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "low" => Some(Confidence::Low),
+            "medium" => Some(Confidence::Medium),
+            "high" => Some(Confidence::High),
+            _ => None,
+        }
+    }
+}
+
+/// A text edit attached to a violation. Facts carry lines but not columns,
+/// so the range is anchored to text on `line` (1-based) and resolved against
+/// the source when the fix is applied; a fix whose anchor is gone is skipped.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct Fix {
+    pub file: String,
+    pub line: usize,
+    #[serde(flatten)]
+    pub edit: FixEdit,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FixEdit {
+    /// Replace the first whole-word `find` on the line.
+    Replace { find: String, replacement: String },
+    /// Insert `text` before the first whole-word `anchor` on the line.
+    InsertBefore { anchor: String, text: String },
+    /// Insert `text` as the last alternative of the case statement opening on
+    /// the line, right before its `end case`.
+    CaseAlternative { text: String },
+    /// Replace the sensitivity list of the process opening on the line with
+    /// `text`, a complete parenthesized list, or add it after `process` when
+    /// the process has none.
+    SensitivityList { text: String },
+    /// Reorder the declarations in the port clause of the entity declared on
+    /// the line so its ports come in `ports` order.
+    PortOrder { ports: Vec<String> },
 }
 
 #[derive(Debug, Clone, Serialize, Default)]
@@ -16,6 +109,33 @@ pub struct Summary {
     pub errors: usize,
     pub warnings: usize,
     pub info: usize,
+    /// Rollup by the directory of each violation's file ("." for bare names).
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub by_directory: BTreeMap<String, SeverityCounts>,
+    /// Rollup by the VHDL library of each violation's file.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub by_library: BTreeMap<String, SeverityCounts>,
+}
+
+/// Violation counts for one directory or library.
+#[derive(Debug, Clone, Serialize, Default, PartialEq, Eq)]
+pub struct SeverityCounts {
+    pub total_violations: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    pub info: usize,
+}
+
+impl SeverityCounts {
+    pub fn add(&mut self, severity: &str) {
+        self.total_violations += 1;
+        match severity {
+            "error" => self.errors += 1,
+            "warning" => self.warnings += 1,
+            "info" => self.info += 1,
+            _ => {}
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
@@ -26,7 +146,7 @@ pub struct VerificationAnchor {
     pub exists: bool,
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct MissingCheckTask {
     pub file: String,
     pub scope: String,
@@ -34,15 +154,332 @@ pub struct MissingCheckTask {
     pub missing_ids: Vec<String>,
     pub bindings: HashMap<String, String>,
     pub notes: Vec<String>,
+    /// Set when the bindings were inferred from port names rather than
+    /// port directions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+/// Missing checks of one architecture as insertable tags, the
+/// `--emit-tasks` output: where to put them and the text to put there.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct FixTaskGroup {
+    pub file: String,
+    pub scope: String,
+    pub anchor: VerificationAnchor,
+    pub tasks: Vec<FixTask>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct FixTask {
+    /// `<scope>/<check>[/<binding>=<signal>...]`; the same finding keeps the
+    /// same id across runs.
+    pub id: String,
+    pub check: String,
+    /// Tag text from the registry template with the bindings filled in.
+    pub tag: String,
+    pub bindings: BTreeMap<String, String>,
+    /// Template placeholders no binding filled (e.g. `bound`), left in `tag`
+    /// as `{name}` for the caller to supply.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unfilled: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct AmbiguousConstruct {
     pub kind: String,
     pub scope: String,
     pub file: String,
     pub line: usize,
     pub candidates: HashMap<String, Vec<String>>,
+    /// Confidence of `best_guess`, below `lint_config.binding_confidence`.
+    pub confidence: f64,
+    /// Highest-scoring role binding from port names and comments.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub best_guess: HashMap<String, String>,
+}
+
+/// A construct verification checks bind to, with the signals filling each
+/// role.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DetectedConstruct {
+    pub kind: String,
+    pub scope: String,
+    pub file: String,
+    pub line: usize,
+    pub bindings: BTreeMap<String, String>,
+    /// Set when the bindings were inferred from port names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+}
+
+/// Every construct detection found, bound or not, in file and line order.
+#[derive(Debug, Clone, Serialize, Default, PartialEq)]
+pub struct ConstructInventory {
+    pub constructs: Vec<DetectedConstruct>,
+    pub ambiguous_constructs: Vec<AmbiguousConstruct>,
+}
+
+/// Assertions and verification checks written in one architecture.
+#[derive(Debug, Clone, Serialize, Default, PartialEq)]
+pub struct AssertionDensity {
+    pub entity: String,
+    pub architecture: String,
+    pub file: String,
+    pub line: usize,
+    /// Source lines, up to the next design unit in the file or, for the
+    /// last one, to the last statement found in it.
+    pub lines: usize,
+    /// Plain VHDL `assert` statements.
+    pub assertions: usize,
+    /// PSL `assert`/`assume`/`cover` directives.
+    pub psl_directives: usize,
+    /// Valid verification tags scoped to the architecture.
+    pub tags: usize,
+    /// Assertions, directives and tags per 100 lines.
+    pub per_100_lines: f64,
+}
+
+/// Required checks of every construct of one kind, and how many are present.
+#[derive(Debug, Clone, Serialize, Default, PartialEq)]
+pub struct KindCoverage {
+    pub kind: String,
+    pub constructs: usize,
+    pub required: usize,
+    pub satisfied: usize,
+}
+
+/// The checks one construct requires, split into present and missing.
+#[derive(Debug, Clone, Serialize, Default, PartialEq)]
+pub struct ConstructCoverage {
+    pub kind: String,
+    pub scope: String,
+    pub file: String,
+    pub line: usize,
+    pub satisfied: Vec<String>,
+    pub missing: Vec<String>,
+}
+
+/// Assertion density per architecture and check coverage per construct and
+/// construct kind (`Result.verification_coverage`).
+#[derive(Debug, Clone, Serialize, Default, PartialEq)]
+pub struct VerificationCoverage {
+    pub architectures: Vec<AssertionDensity>,
+    pub kinds: Vec<KindCoverage>,
+    pub constructs: Vec<ConstructCoverage>,
+}
+
+impl VerificationCoverage {
+    pub fn is_empty(&self) -> bool {
+        self.architectures.is_empty() && self.constructs.is_empty()
+    }
+}
+
+/// Registers and clocked processes driven by one clock in one architecture,
+/// or by the clocks of one declared domain (`lint_config.clocks`).
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ClockDomain {
+    pub clock: String,
+    /// Declared domain of `clock`, if any.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub domain: String,
+    pub in_arch: String,
+    pub file: String,
+    /// Line of the first process in the domain.
+    pub line: usize,
+    pub processes: Vec<String>,
+    pub registers: Vec<String>,
+}
+
+/// Inferred clock domains and the registers crossing between them.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct ClockDomainMap {
+    pub domains: Vec<ClockDomain>,
+    pub crossings: Vec<CDCCrossing>,
+}
+
+impl ClockDomainMap {
+    pub fn is_empty(&self) -> bool {
+        self.domains.is_empty()
+    }
+}
+
+/// Where a reset used by a reset domain comes from, traced through plain
+/// concurrent copies and up through the instances driving input ports.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ResetSource {
+    /// The reset as the domain's processes name it.
+    pub signal: String,
+    pub in_arch: String,
+    pub file: String,
+    /// "synchronizer", "register", "combinational", "port" (a top-level
+    /// input) or "signal" (no driver found).
+    pub kind: String,
+    /// Signal the trace ended at, and where it is driven or declared.
+    pub root: String,
+    pub root_file: String,
+    pub root_line: usize,
+    /// Clock of the synchronizer or register driving the reset.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub clock: String,
+    /// Set when the trace left the architecture through an input port, so
+    /// `clock` is named in another architecture.
+    pub through_ports: bool,
+}
+
+/// A chain of registers re-timing a raw reset into one clock domain.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ResetSynchronizer {
+    /// Raw reset the first stage is reset by (async assert) or samples.
+    pub input: String,
+    /// Last register of the chain: the synchronized reset.
+    pub output: String,
+    pub clock: String,
+    pub stages: usize,
+    /// Asserts asynchronously and releases on the clock, rather than
+    /// sampling the raw reset as data.
+    pub async_assert: bool,
+    pub process: String,
+    pub in_arch: String,
+    pub file: String,
+    pub line: usize,
+}
+
+/// Registers one reset initializes within one clock domain of one
+/// architecture.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ResetDomain {
+    pub reset: String,
+    pub clock: String,
+    pub is_async: bool,
+    pub in_arch: String,
+    pub file: String,
+    /// Line of the first process in the domain.
+    pub line: usize,
+    pub processes: Vec<String>,
+    pub registers: Vec<String>,
+}
+
+/// Inferred reset tree: the reset domains, the source each of their resets
+/// traces back to, and the reset synchronizers found on the way.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct ResetDomainMap {
+    pub domains: Vec<ResetDomain>,
+    pub sources: Vec<ResetSource>,
+    pub synchronizers: Vec<ResetSynchronizer>,
+}
+
+impl ResetDomainMap {
+    pub fn is_empty(&self) -> bool {
+        self.domains.is_empty() && self.synchronizers.is_empty()
+    }
+}
+
+/// A state machine extracted from an enumerated type and the case
+/// statements over its signals, with the analysis `fsm` rules report on.
+#[derive(Debug, Clone, Serialize, Default, PartialEq, Eq)]
+pub struct FsmGraph {
+    pub type_name: String,
+    pub signals: Vec<String>,
+    pub file: String,
+    /// Line of the first case statement over the state signals.
+    pub line: usize,
+    pub in_arch: String,
+    pub states: Vec<String>,
+    /// The leftmost literal plus every state assigned outside the case
+    /// alternatives (reset, aborts).
+    pub initial_states: Vec<String>,
+    pub transitions: Vec<FsmTransition>,
+    pub unreachable_states: Vec<String>,
+    pub dead_end_states: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct FsmTransition {
+    pub from: String,
+    pub to: String,
+    /// Line of the assignment making the transition.
+    pub line: usize,
+}
+
+/// Size and complexity of one architecture.
+#[derive(Debug, Clone, Serialize, Default, PartialEq, Eq)]
+pub struct ArchitectureMetrics {
+    pub entity: String,
+    pub architecture: String,
+    pub file: String,
+    pub line: usize,
+    pub processes: usize,
+    pub signals: usize,
+    /// Component and entity instantiations: the architecture's fan-out in
+    /// the hierarchy.
+    pub instances: usize,
+    /// Longest chain of combinational `signal_deps` (signal-to-signal hops
+    /// not broken by a register).
+    pub max_comb_depth: usize,
+    /// Cyclomatic-like complexity: 1 plus every if/elsif condition, case
+    /// alternative after the first and conditional or selected concurrent
+    /// assignment.
+    pub complexity: usize,
+}
+
+/// Architecture metrics rolled up per file.
+#[derive(Debug, Clone, Serialize, Default, PartialEq, Eq)]
+pub struct FileMetrics {
+    pub file: String,
+    pub entities: usize,
+    pub architectures: usize,
+    pub processes: usize,
+    pub signals: usize,
+    pub instances: usize,
+    /// Deepest architecture in the file.
+    pub max_comb_depth: usize,
+    /// Sum over the file's architectures.
+    pub complexity: usize,
+}
+
+/// Trendable design-size numbers, independent of the violations.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct Metrics {
+    pub files: Vec<FileMetrics>,
+    pub architectures: Vec<ArchitectureMetrics>,
+}
+
+impl Metrics {
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+/// Violations a waiver or the baseline kept out of `violations`.
+#[derive(Debug, Clone, Serialize, Default, PartialEq, Eq)]
+pub struct Suppressions {
+    pub total: usize,
+    /// Dropped by an unexpired `--@waive` comment.
+    pub waived: usize,
+    /// Dropped by `--baseline`.
+    pub baselined: usize,
+    pub by_rule: BTreeMap<String, usize>,
+    pub by_file: BTreeMap<String, usize>,
+}
+
+impl Suppressions {
+    pub fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+
+    pub fn add(&mut self, v: &Violation, waived: bool) {
+        self.total += 1;
+        if waived {
+            self.waived += 1;
+        } else {
+            self.baselined += 1;
+        }
+        *self.by_rule.entry(v.rule.clone()).or_default() += 1;
+        *self.by_file.entry(v.file.clone()).or_default() += 1;
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Default)]
@@ -53,4 +490,19 @@ pub struct Result {
     pub missing_checks: Vec<MissingCheckTask>,
     #[serde(default)]
     pub ambiguous_constructs: Vec<AmbiguousConstruct>,
+    #[serde(skip_serializing_if = "ClockDomainMap::is_empty")]
+    pub clock_domains: ClockDomainMap,
+    #[serde(skip_serializing_if = "ResetDomainMap::is_empty")]
+    pub reset_domains: ResetDomainMap,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fsm_graphs: Vec<FsmGraph>,
+    #[serde(skip_serializing_if = "Metrics::is_empty")]
+    pub metrics: Metrics,
+    #[serde(skip_serializing_if = "VerificationCoverage::is_empty")]
+    pub verification_coverage: VerificationCoverage,
+    #[serde(skip_serializing_if = "Suppressions::is_empty")]
+    pub suppressions: Suppressions,
+    /// Documentation of each rule in `violations`, for UIs describing them.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub rule_docs: BTreeMap<String, RuleDoc>,
 }
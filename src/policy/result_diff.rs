@@ -0,0 +1,223 @@
+//! Comparison of two lint results (`vhdl_policy diff old.json new.json`):
+//! which violations a change introduced, which it fixed and which persist.
+//!
+//! Violations match on the baseline fingerprint (rule, file and message with
+//! quoted line numbers left out), so code moving within a file doesn't turn
+//! a persisting finding into a fixed one plus a new one. Matching counts
+//! duplicates: two identical findings before and three after is one new.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::policy::baseline;
+use crate::policy::result::Violation;
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct ResultDiff {
+    pub new: Vec<Violation>,
+    pub fixed: Vec<Violation>,
+    pub persisting: Vec<Violation>,
+}
+
+/// The fields of a violation both result formats (the policy engine's and
+/// `vhdl-lint --json`) share.
+#[derive(Deserialize)]
+struct ReportedViolation {
+    #[serde(default)]
+    rule: String,
+    #[serde(default)]
+    severity: String,
+    #[serde(default)]
+    file: String,
+    #[serde(default)]
+    line: usize,
+    #[serde(default)]
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct ReportedResult {
+    #[serde(default)]
+    violations: Vec<ReportedViolation>,
+}
+
+/// Violations of a result JSON document.
+pub fn read_violations(json: &str) -> Result<Vec<Violation>, String> {
+    let result: ReportedResult = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    Ok(result
+        .violations
+        .into_iter()
+        .map(|v| Violation {
+            rule: v.rule,
+            severity: v.severity,
+            file: v.file,
+            line: v.line,
+            message: v.message,
+            ..Default::default()
+        })
+        .collect())
+}
+
+/// Splits `old` and `new` into new, fixed and persisting violations, each
+/// ordered by file, line and rule. Persisting ones are reported as they
+/// appear in `new`.
+pub fn compare(old: &[Violation], new: &[Violation]) -> ResultDiff {
+    let mut unmatched: HashMap<String, Vec<&Violation>> = HashMap::new();
+    for v in old {
+        unmatched
+            .entry(baseline::fingerprint(v))
+            .or_default()
+            .push(v);
+    }
+    for group in unmatched.values_mut() {
+        // Pop from the end so the earliest old instance matches first.
+        group.sort_by_key(|v| std::cmp::Reverse(v.line));
+    }
+    let mut diff = ResultDiff::default();
+    let mut ordered: Vec<&Violation> = new.iter().collect();
+    ordered.sort_by_key(|v| v.line);
+    for v in ordered {
+        match unmatched
+            .get_mut(&baseline::fingerprint(v))
+            .and_then(Vec::pop)
+        {
+            Some(_) => diff.persisting.push(v.clone()),
+            None => diff.new.push(v.clone()),
+        }
+    }
+    diff.fixed = unmatched.into_values().flatten().cloned().collect();
+    for list in [&mut diff.new, &mut diff.fixed, &mut diff.persisting] {
+        list.sort_by(|a, b| (&a.file, a.line, &a.rule).cmp(&(&b.file, b.line, &b.rule)));
+    }
+    diff
+}
+
+/// Plain-text listing, one violation per line under each heading.
+pub fn text(diff: &ResultDiff) -> String {
+    let mut out = String::new();
+    for (heading, list) in sections(diff) {
+        if list.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("{} ({}):\n", heading, list.len()));
+        for v in list {
+            out.push_str(&format!(
+                "  {:<7} {}:{}  {}: {}\n",
+                v.severity, v.file, v.line, v.rule, v.message
+            ));
+        }
+    }
+    out.push_str(&totals(diff));
+    out.push('\n');
+    out
+}
+
+/// Markdown summary for a PR comment: tables of the new and fixed
+/// violations, with the persisting ones folded away.
+pub fn markdown(diff: &ResultDiff) -> String {
+    let mut out = format!("### vhdl-lint: {}\n", totals(diff));
+    for (heading, list) in sections(diff) {
+        if list.is_empty() {
+            continue;
+        }
+        let title = format!("{} ({})", heading, list.len());
+        if heading == "Persisting" {
+            out.push_str(&format!(
+                "\n<details><summary>{}</summary>\n\n{}\n</details>\n",
+                title,
+                table(list)
+            ));
+        } else {
+            out.push_str(&format!("\n#### {}\n\n{}", title, table(list)));
+        }
+    }
+    out
+}
+
+fn sections(diff: &ResultDiff) -> [(&'static str, &[Violation]); 3] {
+    [
+        ("New", &diff.new),
+        ("Fixed", &diff.fixed),
+        ("Persisting", &diff.persisting),
+    ]
+}
+
+fn totals(diff: &ResultDiff) -> String {
+    format!(
+        "{} new, {} fixed, {} persisting",
+        diff.new.len(),
+        diff.fixed.len(),
+        diff.persisting.len()
+    )
+}
+
+fn table(list: &[Violation]) -> String {
+    let mut out = String::from("| Severity | Location | Rule | Message |\n|---|---|---|---|\n");
+    for v in list {
+        out.push_str(&format!(
+            "| {} | `{}:{}` | `{}` | {} |\n",
+            v.severity,
+            v.file,
+            v.line,
+            v.rule,
+            v.message.replace('|', "\\|").replace('\n', " ")
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn violation(rule: &str, line: usize, message: &str) -> Violation {
+        Violation {
+            rule: rule.to_string(),
+            severity: "warning".to_string(),
+            file: "a.vhd".to_string(),
+            line,
+            message: message.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn matches_moved_and_duplicate_findings() {
+        let old = [
+            violation(
+                "unused_signal",
+                10,
+                "Signal 'x' is unused (declared at line 10)",
+            ),
+            violation("latch_inferred", 20, "Latch on 'q'"),
+            violation("magic_number", 30, "Literal 42"),
+        ];
+        let new = [
+            violation(
+                "unused_signal",
+                14,
+                "Signal 'x' is unused (declared at line 14)",
+            ),
+            violation("magic_number", 31, "Literal 42"),
+            violation("magic_number", 40, "Literal 42"),
+        ];
+        let diff = compare(&old, &new);
+        let lines = |list: &[Violation]| list.iter().map(|v| v.line).collect::<Vec<_>>();
+        assert_eq!(lines(&diff.new), [40]);
+        assert_eq!(lines(&diff.fixed), [20]);
+        assert_eq!(lines(&diff.persisting), [14, 31]);
+
+        let md = markdown(&diff);
+        assert!(md.starts_with("### vhdl-lint: 1 new, 1 fixed, 2 persisting\n"));
+        assert!(md.contains("#### New (1)\n\n| Severity |"));
+        assert!(md.contains("| warning | `a.vhd:40` | `magic_number` | Literal 42 |"));
+        assert!(md.contains("<details><summary>Persisting (2)</summary>"));
+
+        let parsed = read_violations(
+            r#"{"violations":[{"rule":"r","severity":"error","file":"b.vhd","line":3,"message":"m","confidence":"low"}],"summary":{}}"#,
+        )
+        .unwrap();
+        assert_eq!(parsed[0].file, "b.vhd");
+        assert_eq!(parsed[0].line, 3);
+    }
+}
@@ -0,0 +1,147 @@
+//! The `Rule` trait `engine::evaluate` runs. The built-in rule modules are
+//! registered in `engine`'s module table; with the `plugins` feature,
+//! downstream crates add their own at build time with `register_rule!`:
+//!
+//! ```ignore
+//! struct NoLatchNames;
+//!
+//! impl Rule for NoLatchNames {
+//!     fn id(&self) -> &'static str { "acme_no_latch_names" }
+//!     fn category(&self) -> &'static str { "acme" }
+//!     fn check(&self, input: &Input) -> Vec<Violation> { ... }
+//! }
+//!
+//! vhdl_compiler::register_rule!(NoLatchNames);
+//! ```
+//!
+//! Registered rules run after the built-in ones, ordered by id, and their
+//! violations go through the same config, waiver and baseline filtering.
+
+use crate::policy::input::Input;
+use crate::policy::result::Violation;
+
+/// `Rule::reads` entry standing for every Input table.
+pub const ALL_TABLES: &str = "*";
+
+pub trait Rule: Sync {
+    /// Unique name; `--only`/`--skip` select the rule by it.
+    fn id(&self) -> &'static str;
+
+    /// Family the rule belongs to; selecting it with `--only`/`--skip`
+    /// covers every rule in the family.
+    fn category(&self) -> &'static str;
+
+    fn check(&self, input: &Input) -> Vec<Violation>;
+
+    /// Input tables `check` reads on top of `helpers::HELPER_READS`, so the
+    /// daemon's module cache can skip it while they are unchanged. The
+    /// default, `ALL_TABLES`, re-runs it on any change.
+    fn reads(&self) -> &'static [&'static str] {
+        &[ALL_TABLES]
+    }
+}
+
+/// A rule submitted with `register_rule!`.
+#[cfg(feature = "plugins")]
+pub struct Plugin(pub &'static dyn Rule);
+
+#[cfg(feature = "plugins")]
+inventory::collect!(Plugin);
+
+#[cfg(feature = "plugins")]
+#[doc(hidden)]
+pub use inventory;
+
+/// Registers a `Rule` value with the engine. Needs the `plugins` feature.
+#[cfg(feature = "plugins")]
+#[macro_export]
+macro_rules! register_rule {
+    ($rule:expr) => {
+        $crate::policy::rule::inventory::submit! {
+            $crate::policy::rule::Plugin(&$rule)
+        }
+    };
+}
+
+/// Rules registered with `register_rule!`, ordered by id; empty without the
+/// `plugins` feature.
+pub fn plugins() -> Vec<&'static dyn Rule> {
+    #[cfg(feature = "plugins")]
+    {
+        let mut rules: Vec<&'static dyn Rule> = inventory::iter::<Plugin>
+            .into_iter()
+            .map(|plugin| plugin.0)
+            .collect();
+        rules.sort_by_key(|rule| rule.id());
+        rules
+    }
+    #[cfg(not(feature = "plugins"))]
+    {
+        Vec::new()
+    }
+}
+
+#[cfg(all(test, feature = "plugins"))]
+mod tests {
+    use super::*;
+    use crate::policy::engine;
+    use crate::policy::input::Entity;
+
+    struct EntityCount;
+
+    impl Rule for EntityCount {
+        fn id(&self) -> &'static str {
+            "plugin_entity"
+        }
+
+        fn category(&self) -> &'static str {
+            "plugin"
+        }
+
+        fn check(&self, input: &Input) -> Vec<Violation> {
+            // Only the probe entity, so other tests' full evaluations don't
+            // pick up this rule.
+            input
+                .entities
+                .iter()
+                .filter(|entity| entity.name == "plugin_probe")
+                .map(|entity| Violation {
+                    rule: "plugin_entity".to_string(),
+                    severity: "info".to_string(),
+                    file: entity.file.clone(),
+                    line: entity.line,
+                    message: format!("Entity '{}'", entity.name),
+                    ..Default::default()
+                })
+                .collect()
+        }
+
+        fn reads(&self) -> &'static [&'static str] {
+            &["entities"]
+        }
+    }
+
+    crate::register_rule!(EntityCount);
+
+    #[test]
+    fn registered_rules_run_after_the_built_in_ones() {
+        assert_eq!(
+            engine::rules().last().map(|rule| rule.id()),
+            Some("plugin_entity")
+        );
+        assert!(engine::is_known_module("plugin"));
+
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "plugin_probe".to_string(),
+            file: "probe.vhd".to_string(),
+            line: 3,
+            ..Default::default()
+        });
+        input.lint_config.only_modules.push("plugin".to_string());
+        let result = engine::evaluate(&input);
+        let rules: Vec<&str> = result.violations.iter().map(|v| v.rule.as_str()).collect();
+        assert_eq!(rules, ["plugin_entity"]);
+        assert_eq!(result.violations[0].line, 3);
+    }
+}
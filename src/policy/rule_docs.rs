@@ -0,0 +1,139 @@
+//! Per-rule documentation: category, default severity, rationale and a
+//! bad/good VHDL example for every rule id, kept in `rule_docs.toml`.
+//! `vhdl_policy --explain <rule>` prints an entry; JSON and SARIF output
+//! embed the entries of the rules that fired so UIs can show descriptions.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::OnceLock;
+
+use crate::policy::helpers;
+use crate::policy::registry;
+use crate::policy::result::Violation;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct RuleDoc {
+    pub id: String,
+    /// Module reporting the rule, as `--only`/`--skip` name it.
+    pub category: String,
+    /// Severity before `lint_config` overrides. A few rules escalate
+    /// individual findings (e.g. `positional_count_mismatch`).
+    pub severity: String,
+    pub summary: String,
+    pub rationale: String,
+    pub bad: String,
+    pub good: String,
+}
+
+#[derive(Deserialize)]
+struct DocFile {
+    rule: Vec<RuleDoc>,
+}
+
+/// Every documented rule, sorted by id.
+pub fn all() -> &'static [RuleDoc] {
+    static DOCS: OnceLock<Vec<RuleDoc>> = OnceLock::new();
+    DOCS.get_or_init(|| {
+        let file: DocFile = toml::from_str(include_str!("rule_docs.toml"))
+            .unwrap_or_else(|err| panic!("failed to parse rule documentation: {}", err));
+        let mut docs = file.rule;
+        docs.sort_by(|a, b| a.id.cmp(&b.id));
+        docs
+    })
+}
+
+pub fn lookup(rule: &str) -> Option<&'static RuleDoc> {
+    let rule = rule.trim().to_ascii_lowercase();
+    all()
+        .binary_search_by(|doc| doc.id.as_str().cmp(&rule))
+        .ok()
+        .map(|idx| &all()[idx])
+}
+
+/// Documentation of the rules in `violations`, keyed by rule id.
+pub fn for_violations(violations: &[Violation]) -> BTreeMap<String, RuleDoc> {
+    violations
+        .iter()
+        .filter_map(|v| lookup(&v.rule))
+        .map(|doc| (doc.id.clone(), doc.clone()))
+        .collect()
+}
+
+/// Plain-text rendering for `--explain`.
+pub fn explain(doc: &RuleDoc) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{}: {}", doc.id, doc.summary);
+    let _ = writeln!(out);
+    let _ = writeln!(out, "  category: {}", doc.category);
+    let _ = writeln!(out, "  severity: {}", doc.severity);
+    if registry::TB_PROFILE_RULES.contains(&doc.id.as_str()) {
+        let _ = writeln!(
+            out,
+            "  optional: off unless enabled in the rule config or by --profile tb"
+        );
+    } else if helpers::is_optional_rule(&doc.id) {
+        let _ = writeln!(out, "  optional: off unless enabled in the rule config");
+    }
+    let _ = writeln!(out);
+    let _ = writeln!(out, "{}", doc.rationale.trim());
+    for (label, example) in [("Bad:", &doc.bad), ("Good:", &doc.good)] {
+        let _ = writeln!(out);
+        let _ = writeln!(out, "{}", label);
+        for line in example.trim_end().lines() {
+            let _ = writeln!(out, "    {}", line);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn every_rule_is_documented_once() {
+        let mut seen = HashSet::new();
+        for doc in all() {
+            assert!(seen.insert(doc.id.as_str()), "{} documented twice", doc.id);
+            assert!(
+                matches!(doc.severity.as_str(), "error" | "warning" | "info"),
+                "{} has severity '{}'",
+                doc.id,
+                doc.severity
+            );
+            assert!(
+                !doc.summary.is_empty() && !doc.bad.is_empty() && !doc.good.is_empty(),
+                "{} is incomplete",
+                doc.id
+            );
+        }
+        let manifest: BTreeMap<String, serde_json::Value> =
+            serde_json::from_str(include_str!("../../testdata/policy_rules/manifest.json"))
+                .unwrap();
+        for rule in manifest.keys() {
+            assert!(lookup(rule).is_some(), "{} has no documentation", rule);
+        }
+    }
+
+    #[test]
+    fn explain_renders_examples() {
+        let doc = lookup("Sensitivity_List_Incomplete").unwrap();
+        assert_eq!(doc.category, "sensitivity");
+        let text = explain(doc);
+        assert!(text.starts_with("sensitivity_list_incomplete: "));
+        assert!(text.contains("\nBad:\n    process(a) begin y <= a and b; end process;\n"));
+        assert!(lookup("no_such_rule").is_none());
+
+        let violations: Vec<Violation> = ["unused_signal", "custom_rule"]
+            .into_iter()
+            .map(|rule| Violation {
+                rule: rule.to_string(),
+                ..Default::default()
+            })
+            .collect();
+        let docs = for_violations(&violations);
+        assert_eq!(docs.keys().collect::<Vec<_>>(), ["unused_signal"]);
+    }
+}
@@ -0,0 +1,400 @@
+use crate::policy::result::{Result, Violation};
+use crate::policy::rule_docs::{self, RuleDoc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const TOOL_NAME: &str = "vhdl-lint";
+
+/// SARIF 2.1.0 log with a single run, the shape GitHub code scanning accepts.
+#[derive(Debug, Serialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    pub schema: &'static str,
+    pub version: &'static str,
+    pub runs: Vec<Run>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Run {
+    pub tool: Tool,
+    pub results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Tool {
+    pub driver: Driver,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Driver {
+    pub name: &'static str,
+    pub version: &'static str,
+    pub rules: Vec<RuleDescriptor>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleDescriptor {
+    pub id: String,
+    pub name: String,
+    pub short_description: Message,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_description: Option<Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help: Option<Help>,
+    pub default_configuration: Configuration,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<RuleProperties>,
+}
+
+/// Rule rationale with its bad/good example, the form GitHub code scanning
+/// shows in its rule help pane.
+#[derive(Debug, Serialize)]
+pub struct Help {
+    pub text: String,
+    pub markdown: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RuleProperties {
+    pub category: String,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Configuration {
+    pub level: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Message {
+    pub text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    pub rule_id: String,
+    pub rule_index: usize,
+    pub level: &'static str,
+    pub message: Message,
+    pub locations: Vec<Location>,
+    pub properties: ResultProperties,
+}
+
+/// Property bag carrying fields SARIF has no slot for.
+#[derive(Debug, Serialize)]
+pub struct ResultProperties {
+    pub confidence: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    pub physical_location: PhysicalLocation,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub logical_locations: Vec<LogicalLocation>,
+}
+
+/// The violation's `scope`, as a colon-separated hierarchical name.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogicalLocation {
+    pub fully_qualified_name: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhysicalLocation {
+    pub artifact_location: ArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactLocation {
+    pub uri: String,
+    pub uri_base_id: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Region {
+    pub start_line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_column: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<usize>,
+}
+
+/// SARIF lines and columns are 1-based with an exclusive end column, like
+/// the violation's; 0 means the finding has no line (or column).
+fn region(v: &Violation) -> Option<Region> {
+    let located = v.column > 0;
+    (v.line > 0).then(|| Region {
+        start_line: v.line,
+        start_column: located.then_some(v.column),
+        end_line: located.then_some(v.end_line.max(v.line)),
+        end_column: (located && v.end_column > 0).then_some(v.end_column),
+    })
+}
+
+/// One descriptor per rule that fired, in rule-name order; its default level
+/// is the most severe level the rule was reported at. Documented rules (see
+/// `policy::rule_docs`) carry their description, help and category.
+pub fn sarif(result: &Result) -> SarifLog {
+    let mut levels: BTreeMap<&str, &'static str> = BTreeMap::new();
+    for v in &result.violations {
+        let level = level(&v.severity);
+        let entry = levels.entry(&v.rule).or_insert(level);
+        if rank(level) > rank(entry) {
+            *entry = level;
+        }
+    }
+    let index: BTreeMap<&str, usize> = levels
+        .keys()
+        .enumerate()
+        .map(|(i, rule)| (*rule, i))
+        .collect();
+    let rules = levels
+        .iter()
+        .map(|(rule, level)| {
+            let doc = rule_docs::lookup(rule);
+            RuleDescriptor {
+                id: rule.to_string(),
+                name: pascal_case(rule),
+                short_description: Message {
+                    text: doc.map_or_else(|| describe(rule), |doc| doc.summary.clone()),
+                },
+                full_description: doc.map(|doc| Message {
+                    text: doc.rationale.clone(),
+                }),
+                help: doc.map(help),
+                default_configuration: Configuration { level },
+                properties: doc.map(|doc| RuleProperties {
+                    category: doc.category.clone(),
+                    tags: vec![doc.category.clone()],
+                }),
+            }
+        })
+        .collect();
+    let results = result
+        .violations
+        .iter()
+        .map(|v| sarif_result(v, index[v.rule.as_str()]))
+        .collect();
+    SarifLog {
+        schema: SARIF_SCHEMA,
+        version: SARIF_VERSION,
+        runs: vec![Run {
+            tool: Tool {
+                driver: Driver {
+                    name: TOOL_NAME,
+                    version: env!("CARGO_PKG_VERSION"),
+                    rules,
+                },
+            },
+            results,
+        }],
+    }
+}
+
+pub fn sarif_json(result: &Result) -> String {
+    serde_json::to_string_pretty(&sarif(result)).unwrap_or_default()
+}
+
+fn sarif_result(v: &Violation, rule_index: usize) -> SarifResult {
+    SarifResult {
+        rule_id: v.rule.clone(),
+        rule_index,
+        level: level(&v.severity),
+        message: Message {
+            text: v.message.clone(),
+        },
+        locations: vec![Location {
+            physical_location: PhysicalLocation {
+                artifact_location: ArtifactLocation {
+                    uri: v.file.trim_start_matches("./").replace('\\', "/"),
+                    uri_base_id: "%SRCROOT%",
+                },
+                region: region(v),
+            },
+            logical_locations: (!v.scope.is_empty())
+                .then(|| LogicalLocation {
+                    fully_qualified_name: v.scope.clone(),
+                })
+                .into_iter()
+                .collect(),
+        }],
+        properties: ResultProperties {
+            confidence: v.confidence.name(),
+        },
+    }
+}
+
+fn level(severity: &str) -> &'static str {
+    match severity {
+        "error" => "error",
+        "warning" => "warning",
+        _ => "note",
+    }
+}
+
+fn rank(level: &str) -> u8 {
+    match level {
+        "error" => 2,
+        "warning" => 1,
+        _ => 0,
+    }
+}
+
+fn pascal_case(rule: &str) -> String {
+    rule.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn help(doc: &RuleDoc) -> Help {
+    Help {
+        text: rule_docs::explain(doc),
+        markdown: format!(
+            "{}\n\n**Bad:**\n\n```vhdl\n{}```\n\n**Good:**\n\n```vhdl\n{}```\n",
+            doc.rationale.trim(),
+            doc.bad,
+            doc.good
+        ),
+    }
+}
+
+/// `sensitivity_list_incomplete` -> "Sensitivity list incomplete".
+fn describe(rule: &str) -> String {
+    let text = rule.replace('_', " ");
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn violation(rule: &str, severity: &str, file: &str, line: usize) -> Violation {
+        Violation {
+            rule: rule.to_string(),
+            severity: severity.to_string(),
+            file: file.to_string(),
+            line,
+            message: format!("{} finding", rule),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rules_become_descriptors_with_most_severe_level() {
+        let result = Result {
+            violations: vec![
+                violation("unused_signal", "info", "./rtl/a.vhd", 4),
+                violation("missing_reset", "warning", "rtl/b.vhd", 9),
+                violation("unused_signal", "error", "rtl/b.vhd", 0),
+            ],
+            ..Default::default()
+        };
+        let log = sarif(&result);
+        let run = &log.runs[0];
+        let ids: Vec<&str> = run
+            .tool
+            .driver
+            .rules
+            .iter()
+            .map(|r| r.id.as_str())
+            .collect();
+        assert_eq!(ids, ["missing_reset", "unused_signal"]);
+        assert_eq!(run.tool.driver.rules[1].name, "UnusedSignal");
+        assert_eq!(
+            run.tool.driver.rules[1].default_configuration.level,
+            "error"
+        );
+
+        assert_eq!(run.results[0].level, "note");
+        assert_eq!(run.results[0].rule_index, 1);
+        let location = &run.results[0].locations[0].physical_location;
+        assert_eq!(location.artifact_location.uri, "rtl/a.vhd");
+        assert_eq!(location.region.as_ref().unwrap().start_line, 4);
+        assert!(location.region.as_ref().unwrap().start_column.is_none());
+        assert!(run.results[2].locations[0]
+            .physical_location
+            .region
+            .is_none());
+    }
+
+    #[test]
+    fn serializes_sarif_field_names() {
+        let mut scoped = violation("missing_reset", "warning", "top.vhd", 3);
+        scoped.scope = "top:rtl:sync".to_string();
+        scoped.column = 9;
+        scoped.end_line = 3;
+        scoped.end_column = 13;
+        let result = Result {
+            violations: vec![scoped],
+            ..Default::default()
+        };
+        let json: serde_json::Value = serde_json::from_str(&sarif_json(&result)).unwrap();
+        assert_eq!(json["version"], "2.1.0");
+        assert_eq!(json["$schema"], SARIF_SCHEMA);
+        let result = &json["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "missing_reset");
+        assert_eq!(result["properties"]["confidence"], "high");
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["region"]["startLine"],
+            3
+        );
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["region"]["startColumn"],
+            9
+        );
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["region"]["endColumn"],
+            13
+        );
+        assert_eq!(
+            result["locations"][0]["logicalLocations"][0]["fullyQualifiedName"],
+            "top:rtl:sync"
+        );
+        let rule = &json["runs"][0]["tool"]["driver"]["rules"][0];
+        assert_eq!(
+            rule["shortDescription"]["text"],
+            "Sequential process has no reset."
+        );
+        assert_eq!(rule["properties"]["category"], "clocks_resets");
+        assert!(rule["help"]["markdown"]
+            .as_str()
+            .unwrap()
+            .contains("```vhdl\nprocess(clk) begin"));
+    }
+
+    #[test]
+    fn undocumented_rules_fall_back_to_their_name() {
+        let result = Result {
+            violations: vec![violation("custom_check", "warning", "top.vhd", 3)],
+            ..Default::default()
+        };
+        let json: serde_json::Value = serde_json::from_str(&sarif_json(&result)).unwrap();
+        let rule = &json["runs"][0]["tool"]["driver"]["rules"][0];
+        assert_eq!(rule["shortDescription"]["text"], "Custom check");
+        assert!(rule.get("help").is_none());
+        assert!(rule.get("properties").is_none());
+    }
+}
@@ -0,0 +1,265 @@
+//! Hierarchical scope of a violation: `entity:arch[:generate...][:process]`,
+//! so findings in big designs can be found in a hierarchy browser.
+//!
+//! Rules report only file and line, so the scope is recovered from the facts
+//! afterwards instead of being threaded through every module. Facts carry no
+//! end lines: a process spans from its header to its last inner fact, and a
+//! line outside any process or scoped fact belongs to the closest design unit
+//! header above it, which only yields a scope when that unit is an
+//! architecture.
+
+use crate::policy::input::{Architecture, Input, Process};
+use crate::policy::result::Violation;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+pub struct Scopes<'a> {
+    files: HashMap<&'a str, FileScopes<'a>>,
+}
+
+#[derive(Default)]
+struct FileScopes<'a> {
+    architectures: Vec<&'a Architecture>,
+    /// Entity, package and architecture header lines; `Some` for
+    /// architectures.
+    units: Vec<(usize, Option<&'a Architecture>)>,
+    /// Processes with the last line of their body.
+    processes: Vec<(&'a Process, usize)>,
+    /// `in_arch` path of the scoped facts starting on a line.
+    lines: HashMap<usize, Cow<'a, str>>,
+}
+
+impl<'a> Scopes<'a> {
+    pub fn new(input: &'a Input) -> Self {
+        let mut files: HashMap<&str, FileScopes> = HashMap::new();
+        for arch in &input.architectures {
+            let file = files.entry(&arch.file).or_default();
+            file.architectures.push(arch);
+            file.units.push((arch.line, Some(arch)));
+        }
+        for (file, line) in input
+            .entities
+            .iter()
+            .map(|e| (&e.file, e.line))
+            .chain(input.packages.iter().map(|p| (&p.file, p.line)))
+        {
+            files.entry(file).or_default().units.push((line, None));
+        }
+        for proc in &input.processes {
+            files
+                .entry(&proc.file)
+                .or_default()
+                .processes
+                .push((proc, process_end(proc)));
+        }
+
+        let mut scoped: Vec<(&str, usize, Cow<str>)> = Vec::new();
+        for gen in &input.generates {
+            let path = if gen.label.is_empty() {
+                Cow::Borrowed(gen.in_arch.as_str())
+            } else {
+                Cow::Owned(format!("{}.{}", gen.in_arch, gen.label))
+            };
+            scoped.push((&gen.file, gen.line, path));
+        }
+        for s in &input.signals {
+            scoped.push((&s.file, s.line, Cow::Borrowed(&s.in_entity)));
+        }
+        for i in &input.instances {
+            scoped.push((&i.file, i.line, Cow::Borrowed(&i.in_arch)));
+        }
+        for c in &input.concurrent_assignments {
+            scoped.push((&c.file, c.line, Cow::Borrowed(&c.in_arch)));
+        }
+        for c in &input.case_statements {
+            scoped.push((&c.file, c.line, Cow::Borrowed(&c.in_arch)));
+        }
+        for a in &input.assertions {
+            scoped.push((&a.file, a.line, Cow::Borrowed(&a.in_arch)));
+        }
+        for p in &input.psl_directives {
+            scoped.push((&p.file, p.line, Cow::Borrowed(&p.in_arch)));
+        }
+        for c in &input.cdc_crossings {
+            scoped.push((&c.file, c.line, Cow::Borrowed(&c.in_arch)));
+        }
+        for (file, line, path) in scoped {
+            if !path.is_empty() {
+                let lines = &mut files.entry(file).or_default().lines;
+                lines.entry(line).or_insert(path);
+            }
+        }
+
+        for file in files.values_mut() {
+            file.units.sort_by_key(|(line, _)| *line);
+        }
+        Scopes { files }
+    }
+
+    /// Scope of the code at `line` of `file`, or `None` outside architectures.
+    pub fn at(&self, file: &str, line: usize) -> Option<String> {
+        let scopes = self.files.get(file)?;
+        let innermost = scopes
+            .processes
+            .iter()
+            .filter(|(proc, end)| proc.line <= line && line <= *end)
+            .max_by_key(|(proc, _)| proc.line);
+        if let Some((proc, _)) = innermost {
+            return scopes.path(&proc.in_arch, &proc.label, line);
+        }
+        if let Some(path) = scopes.lines.get(&line) {
+            return scopes.path(path, "", line);
+        }
+        let (_, unit) = scopes
+            .units
+            .iter()
+            .rev()
+            .find(|(start, _)| *start <= line)?;
+        unit.map(|arch| format!("{}:{}", arch.entity_name, arch.name))
+    }
+
+    /// Sets `v.scope` unless the rule already did.
+    pub fn annotate(&self, v: &mut Violation) {
+        if v.scope.is_empty() {
+            if let Some(scope) = self.at(&v.file, v.line) {
+                v.scope = scope;
+            }
+        }
+    }
+}
+
+impl FileScopes<'_> {
+    /// Formats an `in_arch` path (`arch[.generate...]`), plus `process` when
+    /// not empty. `None` when the path's first segment names no architecture
+    /// in the file, as in an entity's statement part.
+    fn path(&self, in_arch: &str, process: &str, line: usize) -> Option<String> {
+        let mut segments = in_arch.split('.').filter(|s| !s.is_empty());
+        let name = segments.next()?;
+        // Closest architecture of that name above the line; files may hold
+        // several `rtl`s for different entities.
+        let named = || {
+            self.architectures
+                .iter()
+                .filter(|arch| arch.name.eq_ignore_ascii_case(name))
+        };
+        let arch = named()
+            .filter(|arch| arch.line <= line)
+            .max_by_key(|arch| arch.line)
+            .or_else(|| named().next())?;
+        let mut scope = vec![arch.entity_name.as_str(), arch.name.as_str()];
+        scope.extend(segments);
+        if !process.is_empty() {
+            scope.push(process);
+        }
+        Some(scope.join(":"))
+    }
+}
+
+/// Last line of `proc` any of its inner facts is on.
+fn process_end(proc: &Process) -> usize {
+    let inner = proc
+        .assignments
+        .iter()
+        .map(|a| a.line)
+        .chain(proc.if_statements.iter().map(|i| i.line))
+        .chain(proc.wait_statements.iter().map(|w| w.line))
+        .chain(proc.variables.iter().map(|v| v.line))
+        .chain(proc.procedure_calls.iter().map(|c| c.line))
+        .chain(proc.function_calls.iter().map(|c| c.line));
+    inner.max().unwrap_or(proc.line).max(proc.line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{
+        ConcurrentAssignment, Entity, GenerateStatement, SequentialAssignment, Signal,
+    };
+
+    fn input() -> Input {
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "top".to_string(),
+            file: "top.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "top".to_string(),
+            file: "top.vhd".to_string(),
+            line: 5,
+            ..Default::default()
+        });
+        input.signals.push(Signal {
+            name: "s".to_string(),
+            file: "top.vhd".to_string(),
+            line: 6,
+            in_entity: "rtl".to_string(),
+            ..Default::default()
+        });
+        input.generates.push(GenerateStatement {
+            label: "gen_lanes".to_string(),
+            file: "top.vhd".to_string(),
+            line: 8,
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        });
+        input.processes.push(Process {
+            label: "lane".to_string(),
+            file: "top.vhd".to_string(),
+            line: 9,
+            in_arch: "rtl.gen_lanes".to_string(),
+            assignments: vec![SequentialAssignment {
+                line: 12,
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        input.concurrent_assignments.push(ConcurrentAssignment {
+            file: "top.vhd".to_string(),
+            line: 15,
+            in_arch: "rtl.gen_lanes".to_string(),
+            ..Default::default()
+        });
+        input
+    }
+
+    #[test]
+    fn lines_resolve_to_their_innermost_scope() {
+        let input = input();
+        let scopes = Scopes::new(&input);
+        assert_eq!(scopes.at("top.vhd", 2), None);
+        assert_eq!(scopes.at("top.vhd", 6).as_deref(), Some("top:rtl"));
+        assert_eq!(
+            scopes.at("top.vhd", 8).as_deref(),
+            Some("top:rtl:gen_lanes")
+        );
+        assert_eq!(
+            scopes.at("top.vhd", 11).as_deref(),
+            Some("top:rtl:gen_lanes:lane")
+        );
+        assert_eq!(
+            scopes.at("top.vhd", 15).as_deref(),
+            Some("top:rtl:gen_lanes")
+        );
+        assert_eq!(scopes.at("top.vhd", 20).as_deref(), Some("top:rtl"));
+        assert_eq!(scopes.at("other.vhd", 6), None);
+    }
+
+    #[test]
+    fn annotate_keeps_scopes_rules_set() {
+        let input = input();
+        let scopes = Scopes::new(&input);
+        let mut v = Violation {
+            file: "top.vhd".to_string(),
+            line: 11,
+            ..Default::default()
+        };
+        scopes.annotate(&mut v);
+        assert_eq!(v.scope, "top:rtl:gen_lanes:lane");
+        v.scope = "custom".to_string();
+        scopes.annotate(&mut v);
+        assert_eq!(v.scope, "custom");
+    }
+}
@@ -0,0 +1,250 @@
+//! Project-specific checks written in Rhai. Each `lint_config.custom_rules`
+//! entry names a script defining `fn check(input)`: it receives the whole
+//! `Input` as an object map and returns an array of maps with `file`, `line`
+//! and `message`, plus optional `rule` and `severity` (defaulting to the
+//! entry's `id` and `severity`). For example:
+//!
+//! ```rhai
+//! fn check(input) {
+//!     input.signals
+//!         .filter(|s| s.name.starts_with("latch_"))
+//!         .map(|s| #{ file: s.file, line: s.line, message: `'${s.name}' looks like a latch` })
+//! }
+//! ```
+//!
+//! A script that can't be read, compiled or run, that runs past
+//! `MAX_OPERATIONS`, or whose result isn't such an array, is reported as
+//! `custom_rule_error` instead of being skipped. So is every configured
+//! script when the crate is built without the `scripting` feature.
+
+use crate::policy::input::{CustomRule, Input};
+use crate::policy::result::Violation;
+use crate::policy::rule;
+
+pub fn violations(input: &Input) -> Vec<Violation> {
+    let rules = &input.lint_config.custom_rules;
+    if rules.is_empty() {
+        return Vec::new();
+    }
+    let outcomes = run_all(input, rules);
+    rules
+        .iter()
+        .zip(outcomes)
+        .flat_map(|(custom, outcome)| match outcome {
+            Ok(violations) => violations,
+            Err(message) => vec![error_violation(custom, message)],
+        })
+        .collect()
+}
+
+/// Scripts see every table.
+pub fn reads() -> &'static [&'static str] {
+    &[rule::ALL_TABLES]
+}
+
+fn error_violation(custom: &CustomRule, message: String) -> Violation {
+    Violation {
+        rule: "custom_rule_error".to_string(),
+        severity: "error".to_string(),
+        file: custom.script.clone(),
+        line: 0,
+        message: format!("Custom rule '{}': {}", custom.id, message),
+        ..Default::default()
+    }
+}
+
+/// Rhai operations one `check` call may run before it is stopped, so a
+/// runaway script fails its rule instead of hanging the lint.
+#[cfg(feature = "scripting")]
+const MAX_OPERATIONS: u64 = 50_000_000;
+
+#[cfg(feature = "scripting")]
+fn engine(max_operations: u64) -> rhai::Engine {
+    let mut engine = rhai::Engine::new();
+    // Rhai's release-build nesting limits; debug builds default lower.
+    engine.set_max_expr_depths(64, 32);
+    engine.set_max_operations(max_operations);
+    engine
+}
+
+#[cfg(feature = "scripting")]
+fn run_all(input: &Input, rules: &[CustomRule]) -> Vec<Result<Vec<Violation>, String>> {
+    let engine = engine(MAX_OPERATIONS);
+    let value = match rhai::serde::to_dynamic(input) {
+        Ok(value) => value,
+        Err(e) => {
+            let message = format!("serializing the input: {}", e);
+            return rules.iter().map(|_| Err(message.clone())).collect();
+        }
+    };
+    rules
+        .iter()
+        .map(|custom| run(&engine, &value, custom))
+        .collect()
+}
+
+#[cfg(not(feature = "scripting"))]
+fn run_all(_input: &Input, rules: &[CustomRule]) -> Vec<Result<Vec<Violation>, String>> {
+    let message = "this build has no script support (enable the `scripting` feature)";
+    rules.iter().map(|_| Err(message.to_string())).collect()
+}
+
+/// One violation as a script returns it.
+#[cfg(feature = "scripting")]
+#[derive(serde::Deserialize)]
+struct Reported {
+    #[serde(default)]
+    rule: String,
+    #[serde(default)]
+    severity: String,
+    #[serde(default)]
+    file: String,
+    #[serde(default)]
+    line: usize,
+    message: String,
+}
+
+#[cfg(feature = "scripting")]
+fn run(
+    engine: &rhai::Engine,
+    input: &rhai::Dynamic,
+    custom: &CustomRule,
+) -> Result<Vec<Violation>, String> {
+    let text = std::fs::read_to_string(&custom.script)
+        .map_err(|e| format!("reading '{}': {}", custom.script, e))?;
+    let ast = engine.compile(&text).map_err(|e| e.to_string())?;
+    let reported: rhai::Array = engine
+        .call_fn(&mut rhai::Scope::new(), &ast, "check", (input.clone(),))
+        .map_err(|e| e.to_string())?;
+    let default_severity = match custom.severity.as_str() {
+        "" => "warning".to_string(),
+        severity => severity.to_ascii_lowercase(),
+    };
+    reported
+        .iter()
+        .enumerate()
+        .map(|(idx, item)| {
+            let item: Reported = rhai::serde::from_dynamic(item)
+                .map_err(|e| format!("violation {} of check(): {}", idx, e))?;
+            Ok(Violation {
+                rule: if item.rule.is_empty() {
+                    custom.id.clone()
+                } else {
+                    item.rule
+                },
+                severity: if item.severity.is_empty() {
+                    default_severity.clone()
+                } else {
+                    item.severity.to_ascii_lowercase()
+                },
+                file: item.file,
+                line: item.line,
+                message: item.message,
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom_rule(script: &str) -> CustomRule {
+        CustomRule {
+            id: "no_latch_names".to_string(),
+            script: script.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn unreadable_scripts_are_reported() {
+        let mut input = Input::default();
+        input
+            .lint_config
+            .custom_rules
+            .push(custom_rule("/nonexistent/no_latch_names.rhai"));
+        let v = violations(&input);
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[0].rule, "custom_rule_error");
+        assert_eq!(v[0].file, "/nonexistent/no_latch_names.rhai");
+        assert!(v[0].message.starts_with("Custom rule 'no_latch_names': "));
+    }
+
+    #[cfg(feature = "scripting")]
+    #[test]
+    fn scripts_report_violations_from_the_input() {
+        use crate::policy::input::Signal;
+
+        let dir = std::env::temp_dir().join(format!("vhdl_scripting_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let script = dir.join("no_latch_names.rhai");
+        std::fs::write(
+            &script,
+            r#"
+fn check(input) {
+    let out = [];
+    for s in input.signals {
+        if s.name.starts_with("latch_") {
+            out.push(#{ file: s.file, line: s.line, message: `'${s.name}' looks like a latch` });
+        }
+    }
+    out.push(#{ rule: "always", severity: "INFO", message: "ran" });
+    out
+}
+"#,
+        )
+        .unwrap();
+        let broken = dir.join("broken.rhai");
+        std::fs::write(&broken, "fn check(input) { [1] }").unwrap();
+
+        let mut input = Input::default();
+        for (name, line) in [("latch_q", 4), ("data", 5)] {
+            input.signals.push(Signal {
+                name: name.to_string(),
+                file: "top.vhd".to_string(),
+                line,
+                ..Default::default()
+            });
+        }
+        input
+            .lint_config
+            .custom_rules
+            .push(custom_rule(&script.to_string_lossy()));
+        input
+            .lint_config
+            .custom_rules
+            .push(custom_rule(&broken.to_string_lossy()));
+        let v = violations(&input);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(v.len(), 3);
+        assert_eq!(
+            (v[0].rule.as_str(), v[0].severity.as_str(), v[0].line),
+            ("no_latch_names", "warning", 4)
+        );
+        assert_eq!(v[0].message, "'latch_q' looks like a latch");
+        assert_eq!(
+            (v[1].rule.as_str(), v[1].severity.as_str()),
+            ("always", "info")
+        );
+        assert_eq!(v[2].rule, "custom_rule_error");
+        assert!(v[2].message.contains("violation 0 of check()"));
+    }
+
+    #[cfg(feature = "scripting")]
+    #[test]
+    fn runaway_scripts_are_stopped() {
+        let dir = std::env::temp_dir().join(format!("vhdl_runaway_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let script = dir.join("spin.rhai");
+        std::fs::write(&script, "fn check(input) { loop {} }").unwrap();
+        let custom = custom_rule(&script.to_string_lossy());
+        let result = run(&engine(10_000), &rhai::Dynamic::UNIT, &custom);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let message = result.unwrap_err();
+        assert!(message.contains("Too many operations"), "{}", message);
+    }
+}
@@ -18,6 +18,10 @@ pub fn optional_violations(input: &Input) -> Vec<Violation> {
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &["comparisons", "ports", "processes"]
+}
+
 fn large_literal_comparison(input: &Input) -> Vec<Violation> {
     input
         .comparisons
@@ -32,6 +36,7 @@ fn large_literal_comparison(input: &Input) -> Vec<Violation> {
                 "Suspicious comparison: '{}' {} literal '{}' ({} bits) - potential trojan trigger",
                 comp.left_operand, comp.operator, comp.literal_value, comp.literal_bits
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -50,6 +55,7 @@ fn magic_number_comparison(input: &Input) -> Vec<Violation> {
                 "CRITICAL: Comparison against known magic number '{}' - HIGH PROBABILITY TROJAN TRIGGER",
                 comp.literal_value
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -80,6 +86,7 @@ fn trigger_drives_output(input: &Input) -> Vec<Violation> {
                     "ALERT: Literal comparison '{}' = '{}' drives output port '{}' - classic trojan pattern",
                     comp.left_operand, comp.literal_value, comp.result_drives
                 ),
+                ..Default::default()
             });
         }
     }
@@ -101,6 +108,7 @@ fn counter_trigger(input: &Input) -> Vec<Violation> {
                 "Counter '{}' compared against large literal '{}' - potential time bomb trigger",
                 comp.left_operand, comp.literal_value
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -119,6 +127,7 @@ fn inverted_trigger(input: &Input) -> Vec<Violation> {
                 "Inverted comparison '/=' against large literal '{}' - could hide trojan by inverting trigger logic",
                 comp.literal_value
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -126,9 +135,7 @@ fn inverted_trigger(input: &Input) -> Vec<Violation> {
 fn multi_trigger_process(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     for proc in &input.processes {
-        if helpers::process_in_testbench(input, proc)
-            || helpers::file_in_testbench(input, &proc.file)
-        {
+        if helpers::process_in_testbench(input, proc) {
             continue;
         }
         let count = input
@@ -148,6 +155,7 @@ fn multi_trigger_process(input: &Input) -> Vec<Violation> {
                     "Process '{}' contains {} large literal comparisons - suspicious concentration of potential triggers",
                     proc.label, count
                 ),
+                ..Default::default()
             });
         }
     }
@@ -234,6 +242,7 @@ mod tests {
             entity_name: "tb_top".to_string(),
             file: "tb.vhd".to_string(),
             line: 1,
+            ..Default::default()
         });
         input.processes.push(Process {
             label: "p1".to_string(),
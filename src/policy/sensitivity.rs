@@ -1,21 +1,40 @@
 use crate::policy::helpers;
 use crate::policy::input::Input;
-use crate::policy::result::Violation;
+use crate::policy::result::{Fix, FixEdit, Violation};
 use crate::policy::signals;
 
 pub fn violations(input: &Input) -> Vec<Violation> {
-    sensitivity_list_incomplete(input)
+    let mut out = sensitivity_list_incomplete(input);
+    out.extend(sensitivity_list_contains_output(input));
+    out
 }
 
 pub fn optional_violations(input: &Input) -> Vec<Violation> {
     sensitivity_list_superfluous(input)
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "constants",
+        "enum_literals",
+        "functions",
+        "ports",
+        "procedures",
+        "processes",
+        "signals",
+        "subtypes",
+        "types",
+    ]
+}
+
 fn skip_sensitivity(input: &Input, proc_index: usize) -> bool {
     let proc = &input.processes[proc_index];
     helpers::single_file_mode(input) && helpers::sensitivity_list_has_clock(&proc.sensitivity_list)
 }
 
+/// One violation per signal a combinational process reads without listing
+/// it. Each carries the same fix: the process's full corrected sensitivity
+/// list, ready to paste.
 fn sensitivity_list_incomplete(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     for (idx, proc) in input.processes.iter().enumerate() {
@@ -34,6 +53,7 @@ fn sensitivity_list_incomplete(input: &Input) -> Vec<Violation> {
         if helpers::process_in_testbench(input, proc) {
             continue;
         }
+        let mut missing: Vec<&String> = Vec::new();
         for read_sig in &proc.read_signals {
             if !signals::is_declared_identifier(input, read_sig) {
                 continue;
@@ -47,6 +67,33 @@ fn sensitivity_list_incomplete(input: &Input) -> Vec<Violation> {
             if helpers::sig_in_sensitivity(read_sig, &proc.sensitivity_list) {
                 continue;
             }
+            if missing.iter().any(|m| m.eq_ignore_ascii_case(read_sig)) {
+                continue;
+            }
+            missing.push(read_sig);
+        }
+        if missing.is_empty() {
+            continue;
+        }
+        let list: Vec<&String> = proc
+            .sensitivity_list
+            .iter()
+            .chain(missing.iter().copied())
+            .collect();
+        let fix = Fix {
+            file: proc.file.clone(),
+            line: proc.line,
+            edit: FixEdit::SensitivityList {
+                text: format!(
+                    "({})",
+                    list.iter()
+                        .map(|s| s.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            },
+        };
+        for read_sig in missing {
             out.push(Violation {
                 rule: "sensitivity_list_incomplete".to_string(),
                 severity: "error".to_string(),
@@ -56,12 +103,60 @@ fn sensitivity_list_incomplete(input: &Input) -> Vec<Violation> {
                     "Signal '{}' read in combinational process '{}' but missing from sensitivity list",
                     read_sig, proc.label
                 ),
+                fixes: vec![fix.clone()],
+                ..Default::default()
             });
         }
     }
     out
 }
 
+/// Outputs of a combinational process listed in its own sensitivity list
+/// re-trigger the process on every update. Signals the process also reads are
+/// left to `combinational_feedback`.
+fn sensitivity_list_contains_output(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for proc in &input.processes {
+        if !proc.is_combinational {
+            continue;
+        }
+        if helpers::has_all_sensitivity(&proc.sensitivity_list) {
+            continue;
+        }
+        if helpers::process_in_testbench(input, proc) {
+            continue;
+        }
+        let outputs: Vec<&String> = proc
+            .sensitivity_list
+            .iter()
+            .filter(|sens| {
+                proc.assigned_signals
+                    .iter()
+                    .any(|assigned| assigned.eq_ignore_ascii_case(sens))
+            })
+            .filter(|sens| !helpers::sig_in_reads(sens, &proc.read_signals))
+            .filter(|sens| helpers::is_actual_signal(input, sens))
+            .collect();
+        if outputs.is_empty() {
+            continue;
+        }
+        let names: Vec<String> = outputs.iter().map(|s| format!("'{}'", s)).collect();
+        out.push(Violation {
+            rule: "sensitivity_list_contains_output".to_string(),
+            severity: "warning".to_string(),
+            file: proc.file.clone(),
+            line: proc.line,
+            message: format!(
+                "Combinational process '{}' has its own outputs in the sensitivity list: {} (re-triggers on every update)",
+                proc.label,
+                names.join(", ")
+            ),
+            ..Default::default()
+        });
+    }
+    out
+}
+
 fn sensitivity_list_superfluous(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     for (idx, proc) in input.processes.iter().enumerate() {
@@ -87,6 +182,7 @@ fn sensitivity_list_superfluous(input: &Input) -> Vec<Violation> {
                     "Signal '{}' in sensitivity list but never read in process '{}'",
                     sens_sig, proc.label
                 ),
+                ..Default::default()
             });
         }
     }
@@ -120,6 +216,77 @@ mod tests {
         assert_eq!(v[0].rule, "sensitivity_list_incomplete");
     }
 
+    #[test]
+    fn sensitivity_list_incomplete_suggests_the_full_list() {
+        let mut input = Input::default();
+        input.processes.push(Process {
+            label: "comb".to_string(),
+            is_combinational: true,
+            read_signals: vec![
+                "a".to_string(),
+                "sel".to_string(),
+                "IDLE".to_string(),
+                "WIDTH".to_string(),
+                "B".to_string(),
+                "b".to_string(),
+            ],
+            assigned_signals: vec!["y".to_string()],
+            sensitivity_list: vec!["a".to_string()],
+            file: "a.vhd".to_string(),
+            line: 7,
+            ..Default::default()
+        });
+        for name in ["a", "b", "sel", "y"] {
+            input.signals.push(crate::policy::input::Signal {
+                name: name.to_string(),
+                ..Default::default()
+            });
+        }
+        input.enum_literals.push("IDLE".to_string());
+        input.constants.push("WIDTH".to_string());
+        let v = sensitivity_list_incomplete(&input);
+        assert_eq!(v.len(), 2);
+        let expected = Fix {
+            file: "a.vhd".to_string(),
+            line: 7,
+            edit: FixEdit::SensitivityList {
+                text: "(a, sel, B)".to_string(),
+            },
+        };
+        assert!(v.iter().all(|v| v.fixes == [expected.clone()]));
+    }
+
+    #[test]
+    fn sensitivity_list_contains_output_lists_signals() {
+        let mut input = Input::default();
+        input.processes.push(Process {
+            label: "p1".to_string(),
+            is_combinational: true,
+            read_signals: vec!["a".to_string(), "c".to_string()],
+            assigned_signals: vec!["b".to_string(), "c".to_string(), "d".to_string()],
+            sensitivity_list: vec![
+                "a".to_string(),
+                "b".to_string(),
+                "c".to_string(),
+                "d".to_string(),
+            ],
+            file: "a.vhd".to_string(),
+            line: 4,
+            ..Default::default()
+        });
+        for name in ["a", "b", "c", "d"] {
+            input.signals.push(crate::policy::input::Signal {
+                name: name.to_string(),
+                ..Default::default()
+            });
+        }
+        let v = sensitivity_list_contains_output(&input);
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[0].rule, "sensitivity_list_contains_output");
+        assert!(v[0].message.contains("'b', 'd'"));
+        assert!(!v[0].message.contains("'c'"));
+    }
+
     #[test]
     fn sensitivity_list_superfluous_flags() {
         let mut input = Input::default();
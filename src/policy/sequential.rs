@@ -1,11 +1,14 @@
 use crate::policy::helpers;
-use crate::policy::input::Input;
+use crate::policy::input::{Input, Process};
 use crate::policy::result::Violation;
+use serde::Serialize;
+use std::collections::BTreeMap;
 
 pub fn violations(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     out.extend(missing_clock_sensitivity(input));
     out.extend(signal_in_seq_and_comb(input));
+    out.extend(mixed_reset_style(input));
     out
 }
 
@@ -15,9 +18,14 @@ pub fn optional_violations(input: &Input) -> Vec<Violation> {
     out.extend(very_wide_register(input));
     out.extend(mixed_edge_clocking(input));
     out.extend(async_reset_naming(input));
+    out.extend(reset_style_inconsistent(input));
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &["architectures", "processes"]
+}
+
 fn missing_clock_sensitivity(input: &Input) -> Vec<Violation> {
     input
         .processes
@@ -41,6 +49,7 @@ fn missing_clock_sensitivity(input: &Input) -> Vec<Violation> {
                 "Sequential process '{}' uses clock '{}' but it's not in sensitivity list",
                 proc.label, proc.clock_signal
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -69,6 +78,7 @@ fn missing_reset_sensitivity(input: &Input) -> Vec<Violation> {
                 "Process '{}' uses reset '{}' but it's not in sensitivity list (sync reset?)",
                 proc.label, proc.reset_signal
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -89,6 +99,7 @@ fn very_wide_register(input: &Input) -> Vec<Violation> {
                 proc.label,
                 proc.assigned_signals.len()
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -121,6 +132,7 @@ fn mixed_edge_clocking(input: &Input) -> Vec<Violation> {
                     "Processes '{}' ({} edge) and '{}' ({} edge) use same clock '{}' with different edges",
                     proc1.label, proc1.clock_edge, proc2.label, proc2.clock_edge, proc1.clock_signal
                 ),
+                ..Default::default()
             });
         }
     }
@@ -154,6 +166,7 @@ fn signal_in_seq_and_comb(input: &Input) -> Vec<Violation> {
                             "Signal '{}' assigned in both sequential process '{}' and combinational process '{}'",
                             assigned_seq, proc_seq.label, proc_comb.label
                         ),
+                        ..Default::default()
                     });
                 }
             }
@@ -179,10 +192,150 @@ fn async_reset_naming(input: &Input) -> Vec<Violation> {
                 "Reset signal '{}' doesn't follow active-low naming convention (*_n, *n)",
                 proc.reset_signal
             ),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Registers reset through the same signal in one architecture should agree
+/// on sync vs async reset; the processes in the minority style (the later
+/// ones on a tie) are flagged.
+fn mixed_reset_style(input: &Input) -> Vec<Violation> {
+    let mut groups: BTreeMap<(&str, String, String), Vec<&Process>> = BTreeMap::new();
+    for proc in reset_processes(input) {
+        let key = (
+            proc.file.as_str(),
+            helpers::base_arch_name(&proc.in_arch).to_ascii_lowercase(),
+            proc.reset_signal.to_ascii_lowercase(),
+        );
+        groups.entry(key).or_default().push(proc);
+    }
+    let mut out = Vec::new();
+    for procs in groups.values_mut() {
+        procs.sort_by_key(|proc| proc.line);
+        let asyncs = procs.iter().filter(|proc| proc.reset_async).count();
+        let syncs = procs.len() - asyncs;
+        if asyncs == 0 || syncs == 0 {
+            continue;
+        }
+        let majority_async = match asyncs.cmp(&syncs) {
+            std::cmp::Ordering::Equal => procs[0].reset_async,
+            order => order.is_gt(),
+        };
+        let Some(reference) = procs.iter().find(|p| p.reset_async == majority_async) else {
+            continue;
+        };
+        for proc in procs.iter().filter(|p| p.reset_async != majority_async) {
+            out.push(Violation {
+                rule: "mixed_reset_style".to_string(),
+                severity: "warning".to_string(),
+                file: proc.file.clone(),
+                line: proc.line,
+                message: format!(
+                    "Process '{}' uses {} reset on '{}' while '{}' uses {} reset on the same signal",
+                    proc.label,
+                    reset_style(proc),
+                    proc.reset_signal,
+                    reference.label,
+                    reset_style(reference)
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+/// Processes whose reset style differs from `lint_config.reset_style`, or from
+/// the project's majority style when no preference is configured.
+fn reset_style_inconsistent(input: &Input) -> Vec<Violation> {
+    let preferred = match input.lint_config.reset_style.to_ascii_lowercase().as_str() {
+        "sync" | "synchronous" => Some(false),
+        "async" | "asynchronous" => Some(true),
+        _ => {
+            let asyncs = reset_processes(input).filter(|p| p.reset_async).count();
+            let syncs = reset_processes(input).count() - asyncs;
+            match asyncs.cmp(&syncs) {
+                std::cmp::Ordering::Equal => None,
+                order => Some(order.is_gt()),
+            }
+        }
+    };
+    let Some(preferred_async) = preferred else {
+        return Vec::new();
+    };
+    let expected = if preferred_async { "async" } else { "sync" };
+    reset_processes(input)
+        .filter(|proc| proc.reset_async != preferred_async)
+        .map(|proc| Violation {
+            rule: "reset_style_inconsistent".to_string(),
+            severity: "info".to_string(),
+            file: proc.file.clone(),
+            line: proc.line,
+            message: format!(
+                "Process '{}' uses {} reset on '{}' but the project style is {} reset",
+                proc.label,
+                reset_style(proc),
+                proc.reset_signal,
+                expected
+            ),
+            ..Default::default()
         })
         .collect()
 }
 
+/// Sync/async reset process counts for one entity.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct ResetStyleCount {
+    pub entity: String,
+    pub file: String,
+    pub sync: usize,
+    #[serde(rename = "async")]
+    pub async_: usize,
+}
+
+/// Per-entity reset style counts, by entity name. Processes in architectures
+/// without a known entity are counted under the architecture name.
+pub fn reset_style_counts(input: &Input) -> Vec<ResetStyleCount> {
+    let mut counts: BTreeMap<(String, String), ResetStyleCount> = BTreeMap::new();
+    for proc in reset_processes(input) {
+        let arch = helpers::base_arch_name(&proc.in_arch);
+        let entity = input
+            .architectures
+            .iter()
+            .find(|a| a.file == proc.file && a.name.eq_ignore_ascii_case(&arch))
+            .map_or(arch.clone(), |a| a.entity_name.clone());
+        let entry = counts
+            .entry((entity.to_ascii_lowercase(), proc.file.clone()))
+            .or_insert_with(|| ResetStyleCount {
+                entity,
+                file: proc.file.clone(),
+                ..Default::default()
+            });
+        if proc.reset_async {
+            entry.async_ += 1;
+        } else {
+            entry.sync += 1;
+        }
+    }
+    counts.into_values().collect()
+}
+
+fn reset_processes(input: &Input) -> impl Iterator<Item = &Process> {
+    input
+        .processes
+        .iter()
+        .filter(|proc| proc.is_sequential && proc.has_reset && !proc.reset_signal.is_empty())
+}
+
+fn reset_style(proc: &Process) -> &'static str {
+    if proc.reset_async {
+        "async"
+    } else {
+        "sync"
+    }
+}
+
 fn is_active_low_reset_name(name: &str) -> bool {
     let lower = name.to_ascii_lowercase();
     lower.ends_with("_n") || (lower.ends_with('n') && helpers::is_reset_name(name))
@@ -191,7 +344,7 @@ fn is_active_low_reset_name(name: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::policy::input::Process;
+    use crate::policy::input::{Architecture, Process};
 
     #[test]
     fn missing_clock_sensitivity_flags() {
@@ -250,4 +403,73 @@ mod tests {
         assert_eq!(v.len(), 1);
         assert_eq!(v[0].rule, "async_reset_naming");
     }
+
+    fn reset_proc(label: &str, line: usize, reset_async: bool) -> Process {
+        Process {
+            label: label.to_string(),
+            is_sequential: true,
+            has_reset: true,
+            reset_signal: "rst_n".to_string(),
+            reset_async,
+            in_arch: "rtl".to_string(),
+            file: "a.vhd".to_string(),
+            line,
+            ..Default::default()
+        }
+    }
+
+    fn reset_style_input() -> Input {
+        Input {
+            architectures: vec![Architecture {
+                name: "rtl".to_string(),
+                entity_name: "core".to_string(),
+                file: "a.vhd".to_string(),
+                line: 2,
+                ..Default::default()
+            }],
+            processes: vec![
+                reset_proc("p_a", 10, true),
+                reset_proc("p_b", 20, false),
+                reset_proc("p_c", 30, true),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn mixed_reset_style_flags_minority_process() {
+        let input = reset_style_input();
+        let v = mixed_reset_style(&input);
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[0].line, 20);
+        assert!(v[0].message.contains("'p_a' uses async reset"));
+    }
+
+    #[test]
+    fn reset_style_inconsistent_follows_preference() {
+        let mut input = reset_style_input();
+        let lines = |input: &Input| -> Vec<usize> {
+            reset_style_inconsistent(input)
+                .iter()
+                .map(|v| v.line)
+                .collect()
+        };
+        assert_eq!(lines(&input), [20]);
+        input.lint_config.reset_style = "sync".to_string();
+        assert_eq!(lines(&input), [10, 30]);
+    }
+
+    #[test]
+    fn reset_style_counts_per_entity() {
+        let counts = reset_style_counts(&reset_style_input());
+        assert_eq!(
+            counts,
+            [ResetStyleCount {
+                entity: "core".to_string(),
+                file: "a.vhd".to_string(),
+                sync: 1,
+                async_: 2,
+            }]
+        );
+    }
 }
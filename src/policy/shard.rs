@@ -0,0 +1,226 @@
+use crate::policy::input::Input;
+use std::collections::{HashMap, HashSet};
+
+/// Splits `input` into at most `count` shards for intra-entity rule modules.
+///
+/// Only design-unit tables (entities, architectures, ports, signals,
+/// processes, case statements, concurrent assignments, instances) are
+/// partitioned. Everything else (packages, types, functions, dependencies,
+/// config) is copied into every shard so name resolution still sees the whole
+/// project; signals declared outside architecture files (package signals) are
+/// copied too. Files are grouped so an entity and all of its architectures land in
+/// the same shard, and groups are spread largest-first over the least loaded
+/// shard.
+pub fn split(input: &Input, count: usize) -> Vec<Input> {
+    let groups = file_groups(input);
+    let count = count.min(groups.len()).max(1);
+
+    let weights = file_weights(input);
+    let mut sized: Vec<(usize, Vec<String>)> = groups
+        .into_iter()
+        .map(|files| {
+            let weight = files
+                .iter()
+                .map(|f| weights.get(f).copied().unwrap_or(1))
+                .sum();
+            (weight, files)
+        })
+        .collect();
+    sized.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+
+    let mut loads = vec![0usize; count];
+    let mut shard_of_file: HashMap<String, usize> = HashMap::new();
+    for (weight, files) in sized {
+        let (target, _) = loads
+            .iter()
+            .enumerate()
+            .min_by_key(|(idx, load)| (**load, *idx))
+            .unwrap_or((0, &0));
+        loads[target] += weight;
+        for file in files {
+            shard_of_file.insert(file, target);
+        }
+    }
+
+    let mut base = input.clone();
+    base.entities.clear();
+    base.architectures.clear();
+    base.ports.clear();
+    base.signals.clear();
+    base.processes.clear();
+    base.case_statements.clear();
+    base.concurrent_assignments.clear();
+    base.instances.clear();
+    let mut shards = vec![base; count];
+    let shard = |file: &str| shard_of_file.get(file).copied().unwrap_or(0);
+
+    let mut entity_shard: HashMap<String, usize> = HashMap::new();
+    for entity in &input.entities {
+        let idx = shard(&entity.file);
+        entity_shard.insert(entity.name.to_ascii_lowercase(), idx);
+        shards[idx].entities.push(entity.clone());
+    }
+    for port in &input.ports {
+        match entity_shard.get(&port.in_entity.to_ascii_lowercase()) {
+            Some(&idx) => shards[idx].ports.push(port.clone()),
+            None => shards.iter_mut().for_each(|s| s.ports.push(port.clone())),
+        }
+    }
+    for arch in &input.architectures {
+        shards[shard(&arch.file)].architectures.push(arch.clone());
+    }
+    let arch_files: HashSet<&str> = input
+        .architectures
+        .iter()
+        .map(|a| a.file.as_str())
+        .collect();
+    for sig in &input.signals {
+        if arch_files.contains(sig.file.as_str()) {
+            shards[shard(&sig.file)].signals.push(sig.clone());
+        } else {
+            shards.iter_mut().for_each(|s| s.signals.push(sig.clone()));
+        }
+    }
+    for proc in &input.processes {
+        shards[shard(&proc.file)].processes.push(proc.clone());
+    }
+    for cs in &input.case_statements {
+        shards[shard(&cs.file)].case_statements.push(cs.clone());
+    }
+    for ca in &input.concurrent_assignments {
+        shards[shard(&ca.file)]
+            .concurrent_assignments
+            .push(ca.clone());
+    }
+    for inst in &input.instances {
+        shards[shard(&inst.file)].instances.push(inst.clone());
+    }
+    shards
+}
+
+/// Groups of files that must share a shard: a file declaring an entity and
+/// the files holding its architectures.
+fn file_groups(input: &Input) -> Vec<Vec<String>> {
+    let mut files: Vec<&str> = input
+        .entities
+        .iter()
+        .map(|e| e.file.as_str())
+        .chain(input.architectures.iter().map(|a| a.file.as_str()))
+        .chain(input.signals.iter().map(|s| s.file.as_str()))
+        .chain(input.processes.iter().map(|p| p.file.as_str()))
+        .chain(input.case_statements.iter().map(|c| c.file.as_str()))
+        .chain(input.concurrent_assignments.iter().map(|c| c.file.as_str()))
+        .chain(input.instances.iter().map(|i| i.file.as_str()))
+        .collect();
+    files.sort_unstable();
+    files.dedup();
+    let index: HashMap<&str, usize> = files.iter().enumerate().map(|(i, f)| (*f, i)).collect();
+
+    let mut parent: Vec<usize> = (0..files.len()).collect();
+    fn find(parent: &mut [usize], mut x: usize) -> usize {
+        while parent[x] != x {
+            parent[x] = parent[parent[x]];
+            x = parent[x];
+        }
+        x
+    }
+    for arch in &input.architectures {
+        for entity in &input.entities {
+            if !entity.name.eq_ignore_ascii_case(&arch.entity_name) {
+                continue;
+            }
+            let a = find(&mut parent, index[arch.file.as_str()]);
+            let b = find(&mut parent, index[entity.file.as_str()]);
+            parent[a.max(b)] = a.min(b);
+        }
+    }
+
+    let mut groups: HashMap<usize, Vec<String>> = HashMap::new();
+    for (idx, file) in files.iter().enumerate() {
+        let root = find(&mut parent, idx);
+        groups.entry(root).or_default().push(file.to_string());
+    }
+    let mut out: Vec<Vec<String>> = groups.into_values().collect();
+    out.sort();
+    out
+}
+
+/// Rough per-file cost: the rows intra-entity modules iterate over.
+fn file_weights(input: &Input) -> HashMap<String, usize> {
+    let mut weights: HashMap<String, usize> = HashMap::new();
+    let files = input
+        .signals
+        .iter()
+        .map(|s| &s.file)
+        .chain(input.processes.iter().map(|p| &p.file))
+        .chain(input.concurrent_assignments.iter().map(|c| &c.file));
+    for file in files {
+        *weights.entry(file.clone()).or_insert(1) += 1;
+    }
+    weights
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{Architecture, Entity, Port, Process};
+
+    fn unit(input: &mut Input, name: &str, entity_file: &str, arch_file: &str) {
+        input.entities.push(Entity {
+            name: name.to_string(),
+            file: entity_file.to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        input.ports.push(Port {
+            name: format!("{}_i", name),
+            in_entity: name.to_string(),
+            ..Default::default()
+        });
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: name.to_string(),
+            file: arch_file.to_string(),
+            line: 10,
+            ..Default::default()
+        });
+        input.processes.push(Process {
+            label: format!("p_{}", name),
+            file: arch_file.to_string(),
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn split_keeps_entity_and_architectures_together() {
+        let mut input = Input::default();
+        unit(&mut input, "a", "a.vhd", "a_rtl.vhd");
+        unit(&mut input, "b", "b.vhd", "b.vhd");
+        unit(&mut input, "c", "c.vhd", "c.vhd");
+        input.enum_literals.push("IDLE".to_string());
+
+        let shards = split(&input, 2);
+        assert_eq!(shards.len(), 2);
+        for shard in &shards {
+            assert_eq!(shard.enum_literals, vec!["IDLE".to_string()]);
+            for entity in &shard.entities {
+                assert!(shard
+                    .processes
+                    .iter()
+                    .any(|p| p.label == format!("p_{}", entity.name)));
+                assert!(shard.ports.iter().any(|p| p.in_entity == entity.name));
+            }
+        }
+        let total: usize = shards.iter().map(|s| s.entities.len()).sum();
+        assert_eq!(total, 3);
+    }
+
+    #[test]
+    fn split_never_exceeds_group_count() {
+        let mut input = Input::default();
+        unit(&mut input, "a", "a.vhd", "a.vhd");
+        assert_eq!(split(&input, 8).len(), 1);
+        assert_eq!(split(&Input::default(), 4).len(), 1);
+    }
+}
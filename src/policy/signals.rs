@@ -1,6 +1,6 @@
-use regex::Regex;
 use std::collections::HashSet;
 
+use crate::policy::elaboration;
 use crate::policy::helpers;
 use crate::policy::input::{Architecture, Input, Process, Signal};
 use crate::policy::result::Violation;
@@ -23,6 +23,26 @@ pub fn optional_violations(input: &Input) -> Vec<Violation> {
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "architectures",
+        "components",
+        "concurrent_assignments",
+        "constants",
+        "entities",
+        "enum_literals",
+        "functions",
+        "ports",
+        "procedures",
+        "processes",
+        "shared_variables",
+        "signal_usages",
+        "signals",
+        "subtypes",
+        "types",
+    ]
+}
+
 pub fn is_declared_identifier(input: &Input, name: &str) -> bool {
     input
         .signals
@@ -116,7 +136,6 @@ fn unused_signal(input: &Input, usage: &SignalUsageIndex) -> Vec<Violation> {
     input
         .signals
         .iter()
-        .filter(|sig| !helpers::file_in_testbench(input, &sig.file))
         .filter(|sig| !usage.has_used(&sig.name))
         .map(|sig| Violation {
             rule: "unused_signal".to_string(),
@@ -124,6 +143,7 @@ fn unused_signal(input: &Input, usage: &SignalUsageIndex) -> Vec<Violation> {
             file: sig.file.clone(),
             line: sig.line,
             message: format!("Signal '{}' is declared but never used", sig.name),
+            ..Default::default()
         })
         .collect()
 }
@@ -143,6 +163,7 @@ fn undriven_signal(input: &Input, usage: &SignalUsageIndex) -> Vec<Violation> {
                 "Signal '{}' is read but never assigned (undriven)",
                 sig.name
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -249,6 +270,7 @@ fn multi_driven_signal(input: &Input) -> Vec<Violation> {
                         "Signal '{}' is assigned in {} places (review for multi-driver)",
                         sig.name, drivers
                     ),
+                    ..Default::default()
                 })
             } else {
                 None
@@ -277,6 +299,7 @@ fn undeclared_signal_usage(input: &Input, usage: &SignalUsageIndex) -> Vec<Viola
                         "Signal '{}' is read but not declared in this design unit",
                         name
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -297,6 +320,7 @@ fn undeclared_signal_usage(input: &Input, usage: &SignalUsageIndex) -> Vec<Viola
                         "Signal '{}' is assigned but not declared in this design unit",
                         name
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -319,6 +343,7 @@ fn undeclared_signal_usage(input: &Input, usage: &SignalUsageIndex) -> Vec<Viola
                         "Signal '{}' is read but not declared in this design unit",
                         name
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -338,6 +363,7 @@ fn undeclared_signal_usage(input: &Input, usage: &SignalUsageIndex) -> Vec<Viola
                     "Signal '{}' is assigned but not declared in this design unit",
                     ca.target
                 ),
+                ..Default::default()
             });
         }
     }
@@ -357,9 +383,6 @@ fn input_port_driven(input: &Input) -> Vec<Violation> {
                 .iter()
                 .any(|sig| sig.eq_ignore_ascii_case(&port.name))
             {
-                if helpers::file_in_testbench(input, &proc.file) {
-                    continue;
-                }
                 if let Some(arch) = input
                     .architectures
                     .iter()
@@ -375,6 +398,7 @@ fn input_port_driven(input: &Input) -> Vec<Violation> {
                                 "Input port '{}' is assigned in process '{}' (illegal driver)",
                                 port.name, proc.label
                             ),
+                            ..Default::default()
                         });
                     }
                 }
@@ -382,9 +406,6 @@ fn input_port_driven(input: &Input) -> Vec<Violation> {
         }
         for ca in &input.concurrent_assignments {
             if ca.target.eq_ignore_ascii_case(&port.name) {
-                if helpers::file_in_testbench(input, &ca.file) {
-                    continue;
-                }
                 if let Some(arch) = input
                     .architectures
                     .iter()
@@ -400,6 +421,7 @@ fn input_port_driven(input: &Input) -> Vec<Violation> {
                                 "Input port '{}' is driven by concurrent assignment (illegal driver)",
                                 port.name
                             ),
+                            ..Default::default()
                         });
                     }
                 }
@@ -492,8 +514,8 @@ fn wide_signal(input: &Input) -> Vec<Violation> {
     input
         .signals
         .iter()
-        .filter_map(|sig| {
-            let width = extract_vector_width(&sig.r#type);
+        .zip(elaboration::signal_range_widths(input))
+        .filter_map(|(sig, width)| {
             if width > 128 {
                 Some(Violation {
                     rule: "wide_signal".to_string(),
@@ -504,6 +526,7 @@ fn wide_signal(input: &Input) -> Vec<Violation> {
                         "Signal '{}' is {} bits wide - consider if this width is necessary",
                         sig.name, width
                     ),
+                    ..Default::default()
                 })
             } else {
                 None
@@ -512,23 +535,6 @@ fn wide_signal(input: &Input) -> Vec<Violation> {
         .collect()
 }
 
-fn extract_vector_width(type_str: &str) -> usize {
-    let lower = type_str.to_ascii_lowercase();
-    let re_downto = Regex::new(r"\(([0-9]+) downto 0\)").unwrap();
-    let re_to = Regex::new(r"\(0 to ([0-9]+)\)").unwrap();
-    if let Some(caps) = re_downto.captures(&lower) {
-        if let Ok(val) = caps.get(1).unwrap().as_str().parse::<usize>() {
-            return val + 1;
-        }
-    }
-    if let Some(caps) = re_to.captures(&lower) {
-        if let Ok(val) = caps.get(1).unwrap().as_str().parse::<usize>() {
-            return val + 1;
-        }
-    }
-    0
-}
-
 fn duplicate_signal_name(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     for (i, sig1) in input.signals.iter().enumerate() {
@@ -551,6 +557,7 @@ fn duplicate_signal_name(input: &Input) -> Vec<Violation> {
                     "Signal '{}' also exists in entity '{}' - verify intentional",
                     sig1.name, sig2.in_entity
                 ),
+                ..Default::default()
             });
         }
     }
@@ -613,6 +620,7 @@ mod tests {
             entity_name: "ent".to_string(),
             file: "a.vhd".to_string(),
             line: 2,
+            ..Default::default()
         });
         input.signals.push(Signal {
             name: "sig".to_string(),
@@ -653,6 +661,7 @@ mod tests {
             entity_name: "ent".to_string(),
             file: "a.vhd".to_string(),
             line: 2,
+            ..Default::default()
         });
         input.signals.push(Signal {
             name: "bus".to_string(),
@@ -702,6 +711,7 @@ mod tests {
             entity_name: "ent".to_string(),
             file: "a.vhd".to_string(),
             line: 1,
+            ..Default::default()
         });
         input.ports.push(Port {
             name: "in_sig".to_string(),
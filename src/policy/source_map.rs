@@ -0,0 +1,176 @@
+//! Source text of the linted files by path, so output (and any policy module
+//! that needs more than the facts) can look up the code at a file and line.
+//! `--with-snippets` uses it to attach the offending lines, with carets under
+//! the violation's column range, to each violation.
+
+use crate::policy::result::Violation;
+use std::collections::HashMap;
+
+/// Lines of a multi-line range shown before the snippet is cut short.
+const MAX_SNIPPET_LINES: usize = 3;
+
+#[derive(Debug, Default)]
+pub struct SourceMap {
+    files: HashMap<String, Vec<String>>,
+}
+
+impl SourceMap {
+    /// A map of already read sources, keyed by the path violations report.
+    pub fn new<'a>(sources: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        let mut map = SourceMap::default();
+        for (path, text) in sources {
+            map.insert(path, text);
+        }
+        map
+    }
+
+    /// Reads every file `violations` point at from disk; unreadable files are
+    /// left out and their violations get no snippet.
+    pub fn read(violations: &[Violation]) -> Self {
+        let mut map = SourceMap::default();
+        for v in violations {
+            if !map.files.contains_key(&v.file) {
+                if let Ok(text) = std::fs::read_to_string(&v.file) {
+                    map.insert(&v.file, &text);
+                }
+            }
+        }
+        map
+    }
+
+    pub fn insert(&mut self, path: &str, text: &str) {
+        let lines = text.lines().map(str::to_string).collect();
+        self.files.insert(path.to_string(), lines);
+    }
+
+    /// 1-based `line` of `file`, without its line break.
+    pub fn line(&self, file: &str, line: usize) -> Option<&str> {
+        let lines = self.files.get(file)?;
+        lines.get(line.checked_sub(1)?).map(String::as_str)
+    }
+
+    /// The lines `v` covers with carets under its column range, or under the
+    /// code of the line when the violation has no columns:
+    ///
+    /// ```text
+    ///   12 |   signal unused_q : std_logic;
+    ///      |          ^^^^^^^^
+    /// ```
+    pub fn snippet(&self, v: &Violation) -> Option<String> {
+        let first = self.line(&v.file, v.line)?;
+        let last_line = if v.column > 0 {
+            v.end_line.max(v.line)
+        } else {
+            v.line
+        };
+        let shown = (last_line - v.line + 1).min(MAX_SNIPPET_LINES);
+        let gutter = (v.line + shown - 1).to_string().len();
+        let mut out = Vec::new();
+        for (offset, line) in (v.line..v.line + shown).enumerate() {
+            let text = if offset == 0 {
+                first
+            } else {
+                match self.line(&v.file, line) {
+                    Some(text) => text,
+                    None => break,
+                }
+            };
+            let code = code_start(text);
+            let start = if offset == 0 && v.column > 0 {
+                v.column - 1
+            } else {
+                code
+            };
+            let end = if line == last_line && v.column > 0 && v.end_column > 0 {
+                v.end_column - 1
+            } else {
+                text.trim_end().len()
+            };
+            out.push(format!("{:>gutter$} | {}", line, text));
+            out.push(format!("{:>gutter$} | {}", "", carets(text, start, end)));
+        }
+        if last_line - v.line + 1 > shown {
+            out.push(format!("{:>gutter$} | ...", ""));
+        }
+        Some(out.join("\n"))
+    }
+
+    /// Sets `snippet` on every violation whose line is known.
+    pub fn attach_snippets(&self, violations: &mut [Violation]) {
+        for v in violations {
+            if let Some(snippet) = self.snippet(v) {
+                v.snippet = snippet;
+            }
+        }
+    }
+}
+
+/// Byte offset of the first non-whitespace character of `text`.
+fn code_start(text: &str) -> usize {
+    text.len() - text.trim_start().len()
+}
+
+/// Carets under bytes `start..end` of `text` (at least one), indented with the
+/// same tabs and spaces so they line up however tabs are rendered.
+fn carets(text: &str, start: usize, end: usize) -> String {
+    let start = start.min(text.len());
+    let indent: String = text
+        .char_indices()
+        .take_while(|(i, _)| *i < start)
+        .map(|(_, c)| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    let width = text
+        .get(start..end.clamp(start, text.len()))
+        .map(|covered| covered.chars().count())
+        .unwrap_or(0)
+        .max(1);
+    format!("{}{}", indent, "^".repeat(width))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "architecture rtl of top is\n\tsignal unused_q : std_logic;\nbegin\n";
+
+    fn violation(line: usize) -> Violation {
+        Violation {
+            file: "top.vhd".to_string(),
+            line,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn snippets_underline_the_range_or_the_code() {
+        let map = SourceMap::new([("top.vhd", TEXT)]);
+        let mut v = violation(2);
+        assert_eq!(
+            map.snippet(&v).unwrap(),
+            "2 | \tsignal unused_q : std_logic;\n  | \t^^^^^^^^^^^^^^^^^^^^^^^^^^^^"
+        );
+
+        v.column = 9;
+        v.end_line = 2;
+        v.end_column = 17;
+        assert_eq!(
+            map.snippet(&v).unwrap(),
+            "2 | \tsignal unused_q : std_logic;\n  | \t       ^^^^^^^^"
+        );
+
+        v.line = 1;
+        v.column = 14;
+        v.end_line = 3;
+        v.end_column = 6;
+        let lines: Vec<String> = map.snippet(&v).unwrap().lines().map(String::from).collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[1], "  |              ^^^^^^^^^^^^^");
+        assert_eq!(lines[5], "  | ^^^^^");
+
+        assert!(map.snippet(&violation(9)).is_none());
+        let mut violations = vec![violation(3), violation(0)];
+        map.attach_snippets(&mut violations);
+        assert_eq!(violations[0].snippet, "3 | begin\n  | ^^^^^");
+        assert!(violations[1].snippet.is_empty());
+    }
+}
@@ -0,0 +1,210 @@
+//! Which VHDL standard each version-dependent construct needs. Rules consult
+//! the matrix through `supports` instead of matching `Input.standard` strings;
+//! `vhdl2008_feature_in_93_mode` reports 2008 constructs in code analyzed as
+//! an older standard, and `recommendation_applies` silences rules that
+//! suggest a construct the standard lacks. An empty or unknown `standard`
+//! supports everything.
+
+use crate::policy::helpers;
+use crate::policy::input::Input;
+use crate::policy::result::Violation;
+
+/// `process (all)`.
+pub const SENSITIVITY_ALL: &str = "sensitivity_all";
+/// `/* ... */`.
+pub const BLOCK_COMMENT: &str = "block_comment";
+/// `case? ... end case?`.
+pub const MATCHING_CASE: &str = "matching_case";
+/// An architecture reading its own `out` ports.
+pub const OUTPUT_PORT_READ: &str = "output_port_read";
+/// `view` declarations and mode-view ports.
+pub const MODE_VIEW: &str = "mode_view";
+
+/// Each construct with the first standard that has it and how messages name
+/// it.
+const CAPABILITIES: &[(&str, u32, &str)] = &[
+    (SENSITIVITY_ALL, 2008, "'all' sensitivity list"),
+    (BLOCK_COMMENT, 2008, "Block comment"),
+    (MATCHING_CASE, 2008, "Matching case statement (case?)"),
+    (OUTPUT_PORT_READ, 2008, "Reading an out port"),
+    (MODE_VIEW, 2019, "Mode view"),
+];
+
+/// Rules recommending a construct, silenced when the standard lacks it.
+const RECOMMENDATIONS: &[(&str, &str)] = &[
+    ("long_sensitivity_list", SENSITIVITY_ALL),
+    // Superseded by vhdl2008_feature_in_93_mode there.
+    ("vhdl2008_sensitivity_all", SENSITIVITY_ALL),
+];
+
+pub fn violations(input: &Input) -> Vec<Violation> {
+    vhdl2008_feature_in_93_mode(input)
+}
+
+pub fn reads() -> &'static [&'static str] {
+    &["files", "language_features", "processes", "standard"]
+}
+
+/// Year of a standard, or `None` when it is empty or unknown.
+fn year(standard: &str) -> Option<u32> {
+    match standard {
+        "1987" => Some(1987),
+        "1993" => Some(1993),
+        "2000" => Some(2000),
+        "2002" => Some(2002),
+        "2008" => Some(2008),
+        "2019" => Some(2019),
+        _ => None,
+    }
+}
+
+fn capability(feature: &str) -> Option<(u32, &'static str)> {
+    CAPABILITIES
+        .iter()
+        .find(|(name, _, _)| *name == feature)
+        .map(|(_, since, label)| (*since, *label))
+}
+
+/// Whether the configured standard has `feature`.
+pub fn supports(input: &Input, feature: &str) -> bool {
+    supported_by(&input.standard, feature)
+}
+
+/// Whether the standard `file` is analyzed as has `feature`.
+pub fn supports_in(input: &Input, file: &str, feature: &str) -> bool {
+    supported_by(standard_of(input, file), feature)
+}
+
+/// The file's own standard (from a project source set), or `input.standard`.
+pub fn standard_of<'a>(input: &'a Input, file: &str) -> &'a str {
+    input
+        .files
+        .iter()
+        .find(|info| info.path == file && !info.standard.is_empty())
+        .map_or(&input.standard, |info| &info.standard)
+}
+
+fn supported_by(standard: &str, feature: &str) -> bool {
+    match (year(standard), capability(feature)) {
+        (Some(year), Some((since, _))) => year >= since,
+        _ => true,
+    }
+}
+
+/// False for rules recommending a construct the configured standard lacks.
+pub fn recommendation_applies(input: &Input, rule: &str) -> bool {
+    RECOMMENDATIONS
+        .iter()
+        .filter(|(name, _)| *name == rule)
+        .all(|(_, feature)| supports(input, feature))
+}
+
+fn vhdl2008_feature_in_93_mode(input: &Input) -> Vec<Violation> {
+    let mut uses: Vec<(&str, &str, usize, String)> = Vec::new();
+    for proc in &input.processes {
+        if helpers::has_all_sensitivity(&proc.sensitivity_list) {
+            uses.push((
+                SENSITIVITY_ALL,
+                &proc.file,
+                proc.line,
+                format!(" in process '{}'", proc.label),
+            ));
+        }
+    }
+    for feature in &input.language_features {
+        uses.push((&feature.feature, &feature.file, feature.line, String::new()));
+    }
+    uses.into_iter()
+        .filter(|(feature, file, ..)| !supports_in(input, file, feature))
+        .filter_map(|(feature, file, line, context)| {
+            let (since, label) = capability(feature)?;
+            (since == 2008).then(|| Violation {
+                rule: "vhdl2008_feature_in_93_mode".to_string(),
+                severity: "error".to_string(),
+                file: file.to_string(),
+                line,
+                message: format!(
+                    "{}{} requires VHDL-2008 (standard is {})",
+                    label,
+                    context,
+                    standard_of(input, file)
+                ),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{FileInfo, LanguageFeature, Process};
+
+    fn input(standard: &str) -> Input {
+        let mut input = Input {
+            standard: standard.to_string(),
+            ..Default::default()
+        };
+        input.processes.push(Process {
+            label: "comb".to_string(),
+            sensitivity_list: vec!["all".to_string()],
+            file: "a.vhd".to_string(),
+            line: 4,
+            ..Default::default()
+        });
+        for (feature, line) in [(BLOCK_COMMENT, 1), (MATCHING_CASE, 7)] {
+            input.language_features.push(LanguageFeature {
+                feature: feature.to_string(),
+                file: "a.vhd".to_string(),
+                line,
+            });
+        }
+        input
+    }
+
+    #[test]
+    fn supports_follows_the_matrix() {
+        assert!(!supports(&input("1993"), SENSITIVITY_ALL));
+        assert!(supports(&input("2008"), SENSITIVITY_ALL));
+        assert!(!supports(&input("2008"), MODE_VIEW));
+        assert!(supports(&input(""), MODE_VIEW));
+        assert!(supports(&input("1993"), "no_such_feature"));
+    }
+
+    #[test]
+    fn vhdl2008_features_are_errors_before_2008() {
+        let v = vhdl2008_feature_in_93_mode(&input("1993"));
+        let messages: Vec<&str> = v.iter().map(|v| v.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "'all' sensitivity list in process 'comb' requires VHDL-2008 (standard is 1993)",
+                "Block comment requires VHDL-2008 (standard is 1993)",
+                "Matching case statement (case?) requires VHDL-2008 (standard is 1993)",
+            ]
+        );
+        assert!(vhdl2008_feature_in_93_mode(&input("2008")).is_empty());
+        assert!(vhdl2008_feature_in_93_mode(&input("")).is_empty());
+
+        let mut legacy = input("2008");
+        legacy.files.push(FileInfo {
+            path: "a.vhd".to_string(),
+            standard: "1993".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(vhdl2008_feature_in_93_mode(&legacy).len(), 3);
+    }
+
+    #[test]
+    fn recommendations_need_the_construct() {
+        assert!(!recommendation_applies(
+            &input("1993"),
+            "long_sensitivity_list"
+        ));
+        assert!(recommendation_applies(
+            &input("2008"),
+            "long_sensitivity_list"
+        ));
+        assert!(recommendation_applies(&input("1993"), "unused_signal"));
+    }
+}
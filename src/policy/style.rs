@@ -18,6 +18,18 @@ pub fn optional_violations(input: &Input) -> Vec<Violation> {
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "architectures",
+        "concurrent_assignments",
+        "dependencies",
+        "entities",
+        "instances",
+        "processes",
+        "signals",
+    ]
+}
+
 fn large_entity(input: &Input) -> Vec<Violation> {
     input
         .entities
@@ -33,6 +45,7 @@ fn large_entity(input: &Input) -> Vec<Violation> {
                 entity.name,
                 entity.ports.len()
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -51,6 +64,7 @@ fn process_label_missing(input: &Input) -> Vec<Violation> {
                 "Process at line {} has no label - add 'label: process' for debugging",
                 proc.line
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -74,6 +88,7 @@ fn multiple_entities_per_file(input: &Input) -> Vec<Violation> {
                         "File contains {} entities - consider one entity per file",
                         entities.len()
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -101,6 +116,7 @@ fn legacy_packages(input: &Input) -> Vec<Violation> {
                 file: dep.source.clone(),
                 line: dep.line,
                 message: msg.to_string(),
+                ..Default::default()
             });
         }
     }
@@ -121,6 +137,7 @@ fn architecture_naming_convention(input: &Input) -> Vec<Violation> {
                 "Architecture '{}' uses non-standard name - consider rtl, behavioral, or structural",
                 arch.name
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -162,6 +179,7 @@ fn empty_architecture(input: &Input) -> Vec<Violation> {
                     "Architecture '{}' is empty (no signals, instances, or processes)",
                     arch.name
                 ),
+                ..Default::default()
             });
         }
     }
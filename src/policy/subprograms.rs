@@ -1,5 +1,6 @@
 use crate::policy::helpers;
-use crate::policy::input::Input;
+use crate::policy::input::{Input, SubprogramParameter};
+use crate::policy::libraries::LibraryMap;
 use crate::policy::result::Violation;
 use std::collections::{HashMap, HashSet};
 
@@ -9,9 +10,25 @@ pub fn violations(input: &Input) -> Vec<Violation> {
     out.extend(procedure_param_invalid_mode(input));
     out.extend(unresolved_qualified_function_call(input));
     out.extend(unresolved_qualified_procedure_call(input));
+    out.extend(procedure_assigns_non_parameter_signal(input));
+    out.extend(function_reads_non_parameter_signal(input));
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "architectures",
+        "files",
+        "functions",
+        "name_uses",
+        "ports",
+        "procedures",
+        "processes",
+        "signals",
+        "symbol_defs",
+    ]
+}
+
 fn function_param_invalid_mode(input: &Input) -> Vec<Violation> {
     let mut violations = Vec::new();
     for func in &input.functions {
@@ -34,6 +51,7 @@ fn function_param_invalid_mode(input: &Input) -> Vec<Violation> {
                     "Function '{}' parameter '{}' has invalid mode '{}' (only 'in' allowed)",
                     func.name, param.name, param.direction
                 ),
+                ..Default::default()
             });
         }
     }
@@ -58,6 +76,7 @@ fn procedure_param_invalid_mode(input: &Input) -> Vec<Violation> {
                         "Procedure '{}' parameter '{}' has invalid mode '{}'",
                         proc_decl.name, param.name, param.direction
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -77,6 +96,7 @@ fn unresolved_qualified_function_call(input: &Input) -> Vec<Violation> {
                 "Function call '{}' has no matching function in package '{}'",
                 entry.name, entry.package
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -93,16 +113,118 @@ fn unresolved_qualified_procedure_call(input: &Input) -> Vec<Violation> {
                 "Procedure call '{}' has no matching procedure in package '{}'",
                 entry.name, entry.package
             ),
+            ..Default::default()
         })
         .collect()
 }
 
+/// Procedures called from a process that drive signals other than their
+/// `out`/`inout` parameters. The calling process becomes an extra, hidden
+/// driver of those signals.
+fn procedure_assigns_non_parameter_signal(input: &Input) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for proc_decl in &input.procedures {
+        if !proc_decl.has_body || proc_decl.assigned_signals.is_empty() {
+            continue;
+        }
+        let caller = input.processes.iter().find(|proc| {
+            proc.procedure_calls.iter().any(|call| {
+                let name = call.name.rsplit('.').next().unwrap_or(&call.name);
+                name.eq_ignore_ascii_case(&proc_decl.name)
+            })
+        });
+        let Some(caller) = caller else {
+            continue;
+        };
+        for sig in &proc_decl.assigned_signals {
+            if is_parameter(&proc_decl.parameters, sig) {
+                continue;
+            }
+            let process = if caller.label.is_empty() {
+                format!("process at line {}", caller.line)
+            } else {
+                format!("process '{}'", caller.label)
+            };
+            violations.push(Violation {
+                rule: "procedure_assigns_non_parameter_signal".to_string(),
+                severity: "warning".to_string(),
+                file: proc_decl.file.clone(),
+                line: proc_decl.line,
+                message: format!(
+                    "Procedure '{}' (called from {}) assigns signal '{}' directly; pass it as an out/inout parameter",
+                    proc_decl.name, process, sig
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    violations
+}
+
+/// Architecture-local functions that read architecture signals or entity
+/// ports without receiving them as parameters.
+fn function_reads_non_parameter_signal(input: &Input) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for func in &input.functions {
+        if !func.has_body || func.in_arch.is_empty() || func.read_signals.is_empty() {
+            continue;
+        }
+        let entity = input
+            .architectures
+            .iter()
+            .find(|arch| arch.file == func.file && arch.name.eq_ignore_ascii_case(&func.in_arch))
+            .map(|arch| arch.entity_name.as_str())
+            .unwrap_or("");
+        for name in &func.read_signals {
+            if is_parameter(&func.parameters, name) {
+                continue;
+            }
+            let is_signal = input.signals.iter().any(|sig| {
+                sig.file == func.file
+                    && sig.in_entity.eq_ignore_ascii_case(&func.in_arch)
+                    && sig.name.eq_ignore_ascii_case(name)
+            });
+            let is_port = !entity.is_empty()
+                && input.ports.iter().any(|port| {
+                    port.in_entity.eq_ignore_ascii_case(entity)
+                        && port.name.eq_ignore_ascii_case(name)
+                });
+            if !is_signal && !is_port {
+                continue;
+            }
+            violations.push(Violation {
+                rule: "function_reads_non_parameter_signal".to_string(),
+                severity: "warning".to_string(),
+                file: func.file.clone(),
+                line: func.line,
+                message: format!(
+                    "Function '{}' reads {} '{}' that is not passed as a parameter",
+                    func.name,
+                    if is_signal { "signal" } else { "port" },
+                    name
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    violations
+}
+
+fn is_parameter(params: &[SubprogramParameter], name: &str) -> bool {
+    params.iter().any(|p| p.name.eq_ignore_ascii_case(name))
+}
+
+/// Qualified calls (`pkg.f`, `lib.pkg.f`) with no matching subprogram in
+/// the package. A library prefix restricts the lookup to that library's
+/// packages (`work` being the calling file's library); calls into packages
+/// or libraries the project doesn't contain are left alone.
 fn unresolved_qualified_call_entries(
     input: &Input,
     use_kind: &str,
     def_kind: &str,
 ) -> Vec<UnresolvedCall> {
-    let mut package_scopes: HashMap<String, Vec<String>> = HashMap::new();
+    let libraries = LibraryMap::new(input);
+    let mut package_scopes: HashMap<String, Vec<(String, String)>> = HashMap::new();
     let mut defs: HashSet<(String, String)> = HashSet::new();
 
     for def in &input.symbol_defs {
@@ -110,7 +232,10 @@ fn unresolved_qualified_call_entries(
             package_scopes
                 .entry(def.name.to_ascii_lowercase())
                 .or_default()
-                .push(def.scope.to_ascii_lowercase());
+                .push((
+                    libraries.library_of(&def.file),
+                    def.scope.to_ascii_lowercase(),
+                ));
         } else if def.kind.eq_ignore_ascii_case(def_kind) {
             defs.insert((
                 def.scope.to_ascii_lowercase(),
@@ -131,7 +256,7 @@ fn unresolved_qualified_call_entries(
         if helpers::is_third_party_file(input, &use_.file) {
             continue;
         }
-        let (pkg, name) = match parse_qualified_name(&use_.name) {
+        let (library, pkg, name) = match parse_qualified_name(&use_.name) {
             Some(parts) => parts,
             None => continue,
         };
@@ -139,18 +264,25 @@ fn unresolved_qualified_call_entries(
             Some(scopes) => scopes,
             None => continue, // Avoid false positives for external/standard packages
         };
-        let name_key = name.to_ascii_lowercase();
-        let mut found = false;
-        for scope in pkg_scopes {
-            if defs.contains(&(scope.to_ascii_lowercase(), name_key.clone())) {
-                found = true;
-                break;
-            }
+        let library = library.map(|lib| libraries.resolve(&use_.file, &lib));
+        if library
+            .as_ref()
+            .is_some_and(|lib| !libraries.has_files(lib))
+        {
+            continue;
         }
+        let name_key = name.to_ascii_lowercase();
+        let found = pkg_scopes
+            .iter()
+            .filter(|(lib, _)| library.as_ref().is_none_or(|wanted| lib == wanted))
+            .any(|(_, scope)| defs.contains(&(scope.clone(), name_key.clone())));
         if !found {
             missing.push(UnresolvedCall {
                 name: use_.name.clone(),
-                package: pkg,
+                package: match library {
+                    Some(lib) => format!("{}.{}", lib, pkg),
+                    None => pkg,
+                },
                 file: use_.file.clone(),
                 line: use_.line,
             });
@@ -160,7 +292,9 @@ fn unresolved_qualified_call_entries(
     missing
 }
 
-fn parse_qualified_name(name: &str) -> Option<(String, String)> {
+/// Splits `[lib.]pkg.name` into its lowercase library (if written),
+/// package and subprogram name.
+fn parse_qualified_name(name: &str) -> Option<(Option<String>, String, String)> {
     let parts: Vec<&str> = name.split('.').map(str::trim).filter(|p| !p.is_empty()).collect();
     if parts.len() < 2 {
         return None;
@@ -170,7 +304,8 @@ fn parse_qualified_name(name: &str) -> Option<(String, String)> {
     if func.is_empty() || pkg.is_empty() {
         return None;
     }
-    Some((pkg.to_ascii_lowercase(), func.to_ascii_lowercase()))
+    let library = (parts.len() > 2).then(|| parts[parts.len() - 3].to_ascii_lowercase());
+    Some((library, pkg.to_ascii_lowercase(), func.to_ascii_lowercase()))
 }
 
 struct UnresolvedCall {
@@ -183,7 +318,10 @@ struct UnresolvedCall {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::policy::input::{FunctionDeclaration, Input, NameUse, ProcedureDeclaration, SymbolDef, SubprogramParameter};
+    use crate::policy::input::{
+        Architecture, FileInfo, FunctionDeclaration, Input, NameUse, ProcedureCall,
+        ProcedureDeclaration, Process, Signal, SubprogramParameter, SymbolDef,
+    };
 
     fn param(name: &str, direction: &str) -> SubprogramParameter {
         SubprogramParameter {
@@ -273,6 +411,65 @@ mod tests {
         assert_eq!(violations[0].rule, "unresolved_qualified_function_call");
     }
 
+    #[test]
+    fn qualified_calls_resolve_through_their_library() {
+        let mut input = Input::default();
+        for (path, library) in [
+            ("dsp/pkg.vhd", "dsp"),
+            ("dsp/fir.vhd", "dsp"),
+            ("top.vhd", ""),
+        ] {
+            input.files.push(FileInfo {
+                path: path.to_string(),
+                library: library.to_string(),
+                ..Default::default()
+            });
+        }
+        for (name, kind) in [("pkg", "package"), ("good_fn", "function")] {
+            input.symbol_defs.push(SymbolDef {
+                name: name.to_string(),
+                kind: kind.to_string(),
+                file: "dsp/pkg.vhd".to_string(),
+                scope: "file:dsp/pkg.vhd::package:pkg".to_string(),
+                line: 1,
+            });
+        }
+        let calls = [
+            ("dsp.pkg.good_fn", "top.vhd"),
+            ("work.pkg.good_fn", "dsp/fir.vhd"),
+            ("work.pkg.good_fn", "top.vhd"),
+            ("vendor.pkg.good_fn", "top.vhd"),
+            ("dsp.pkg.bad_fn", "dsp/fir.vhd"),
+        ];
+        for (line, (name, file)) in calls.into_iter().enumerate() {
+            input.name_uses.push(NameUse {
+                name: name.to_string(),
+                kind: "function_call".to_string(),
+                file: file.to_string(),
+                line: line + 1,
+                ..Default::default()
+            });
+        }
+        let violations = unresolved_qualified_function_call(&input);
+        let found: Vec<(usize, &str)> = violations
+            .iter()
+            .map(|v| (v.line, v.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (
+                    3,
+                    "Function call 'work.pkg.good_fn' has no matching function in package 'work.pkg'"
+                ),
+                (
+                    5,
+                    "Function call 'dsp.pkg.bad_fn' has no matching function in package 'dsp.pkg'"
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn unresolved_qualified_function_call_allows_defined() {
         let mut input = Input::default();
@@ -339,4 +536,67 @@ mod tests {
         let violations = unresolved_qualified_function_call(&input);
         assert!(violations.is_empty());
     }
+    #[test]
+    fn procedure_assigns_non_parameter_signal_requires_process_caller() {
+        let mut input = Input::default();
+        input.procedures.push(ProcedureDeclaration {
+            name: "drive_busy".to_string(),
+            file: "a.vhd".to_string(),
+            line: 5,
+            has_body: true,
+            parameters: vec![param("dst", "out")],
+            assigned_signals: vec!["dst".to_string(), "busy".to_string()],
+            ..Default::default()
+        });
+        assert!(procedure_assigns_non_parameter_signal(&input).is_empty());
+
+        input.processes.push(Process {
+            label: "p_main".to_string(),
+            file: "a.vhd".to_string(),
+            procedure_calls: vec![ProcedureCall {
+                name: "drive_busy".to_string(),
+                line: 20,
+                in_process: "p_main".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        let violations = procedure_assigns_non_parameter_signal(&input);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("'busy'"));
+    }
+
+    #[test]
+    fn function_reads_non_parameter_signal_flags_arch_signals() {
+        let mut input = Input::default();
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "top".to_string(),
+            file: "a.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        input.signals.push(Signal {
+            name: "mode".to_string(),
+            file: "a.vhd".to_string(),
+            in_entity: "rtl".to_string(),
+            ..Default::default()
+        });
+        input.functions.push(FunctionDeclaration {
+            name: "scaled".to_string(),
+            file: "a.vhd".to_string(),
+            line: 4,
+            has_body: true,
+            in_arch: "rtl".to_string(),
+            parameters: vec![param("x", "in")],
+            read_signals: vec!["mode".to_string(), "WIDTH".to_string()],
+            ..Default::default()
+        });
+        let violations = function_reads_non_parameter_signal(&input);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("signal 'mode'"));
+
+        input.functions[0].parameters.push(param("mode", "in"));
+        assert!(function_reads_non_parameter_signal(&input).is_empty());
+    }
 }
@@ -1,13 +1,16 @@
-use regex::Regex;
-
+use crate::policy::elaboration;
 use crate::policy::helpers;
-use crate::policy::input::Input;
-use crate::policy::result::Violation;
+use crate::policy::hierarchy;
+use crate::policy::input::{Input, Signal};
+use crate::policy::registry::{Context, FileContexts};
+use crate::policy::result::{Confidence, Violation};
+use regex::Regex;
 use std::collections::HashSet;
 
 pub fn violations(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     out.extend(signal_crosses_clock_domain(input));
+    out.extend(internal_tristate(input));
     out
 }
 
@@ -19,10 +22,29 @@ pub fn optional_violations(input: &Input) -> Vec<Violation> {
     out.extend(critical_signal_no_reset(input));
     out.extend(combinational_reset(input));
     out.extend(potential_memory_inference(input));
+    out.extend(ram_async_read(input));
+    out.extend(ram_read_during_write(input));
     out.extend(unregistered_output(input));
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "architectures",
+        "concurrent_assignments",
+        "configurations",
+        "entities",
+        "files",
+        "instances",
+        "ports",
+        "processes",
+        "signals",
+        "top_entities",
+        "tristate_assignments",
+        "types",
+    ]
+}
+
 fn multiple_clock_domains(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     for arch in &input.architectures {
@@ -43,6 +65,7 @@ fn multiple_clock_domains(input: &Input) -> Vec<Violation> {
                     "Architecture '{}' uses multiple clocks {:?} - ensure proper CDC synchronization",
                     arch.name, clock_list
                 ),
+                ..Default::default()
             });
         }
     }
@@ -76,6 +99,7 @@ fn signal_crosses_clock_domain(input: &Input) -> Vec<Violation> {
                             "Signal '{}' written in '{}' domain, read in '{}' domain - needs synchronizer",
                             assigned, proc1.clock_signal, proc2.clock_signal
                         ),
+                        ..Default::default()
                     });
                 }
             }
@@ -88,8 +112,8 @@ fn very_wide_bus(input: &Input) -> Vec<Violation> {
     input
         .signals
         .iter()
-        .filter_map(|sig| {
-            let width = extract_width(&sig.r#type);
+        .zip(elaboration::signal_range_widths(input))
+        .filter_map(|(sig, width)| {
             if width > 64 {
                 Some(Violation {
                     rule: "very_wide_bus".to_string(),
@@ -100,6 +124,7 @@ fn very_wide_bus(input: &Input) -> Vec<Violation> {
                         "Signal '{}' is {} bits wide - consider pipelining for timing closure",
                         sig.name, width
                     ),
+                    ..Default::default()
                 })
             } else {
                 None
@@ -108,23 +133,6 @@ fn very_wide_bus(input: &Input) -> Vec<Violation> {
         .collect()
 }
 
-fn extract_width(type_str: &str) -> usize {
-    let lower = type_str.to_ascii_lowercase();
-    let re_downto = Regex::new(r"\(([0-9]+) downto 0\)").unwrap();
-    let re_to = Regex::new(r"\(0 to ([0-9]+)\)").unwrap();
-    if let Some(caps) = re_downto.captures(&lower) {
-        if let Ok(val) = caps.get(1).unwrap().as_str().parse::<usize>() {
-            return val + 1;
-        }
-    }
-    if let Some(caps) = re_to.captures(&lower) {
-        if let Ok(val) = caps.get(1).unwrap().as_str().parse::<usize>() {
-            return val + 1;
-        }
-    }
-    0
-}
-
 fn critical_signal_no_reset(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     for proc in input
@@ -143,6 +151,7 @@ fn critical_signal_no_reset(input: &Input) -> Vec<Violation> {
                         "Critical signal '{}' in process '{}' has no reset initialization",
                         assigned, proc.label
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -166,7 +175,6 @@ fn gated_clock_detection(input: &Input) -> Vec<Violation> {
     for ca in &input.concurrent_assignments {
         if helpers::is_clock_name(&ca.target)
             && clock_signals.contains(&ca.target.to_ascii_lowercase())
-            && !helpers::concurrent_in_testbench(input, ca)
         {
             out.push(Violation {
                 rule: "gated_clock_detection".to_string(),
@@ -177,6 +185,7 @@ fn gated_clock_detection(input: &Input) -> Vec<Violation> {
                     "Clock signal '{}' assigned in concurrent statement - potential gated clock (use clock enable instead)",
                     ca.target
                 ),
+                ..Default::default()
             });
         }
     }
@@ -196,6 +205,7 @@ fn gated_clock_detection(input: &Input) -> Vec<Violation> {
                             "Clock signal '{}' assigned in combinational process - potential gated clock",
                             assigned
                         ),
+                        ..Default::default()
                     });
                 }
             }
@@ -229,11 +239,18 @@ fn combinational_reset(input: &Input) -> Vec<Violation> {
                 "Reset signal '{}' generated combinationally - consider dedicated reset controller",
                 ca.target
             ),
+            confidence: if drives_reset(input, &ca.file, &ca.target) {
+                Confidence::High
+            } else {
+                Confidence::Medium
+            },
+            ..Default::default()
         })
         .collect()
 }
 
 fn potential_memory_inference(input: &Input) -> Vec<Violation> {
+    let memories = memories(input);
     input
         .signals
         .iter()
@@ -243,14 +260,48 @@ fn potential_memory_inference(input: &Input) -> Vec<Violation> {
             severity: "info".to_string(),
             file: sig.file.clone(),
             line: sig.line,
-            message: format!(
-                "Signal '{}' with type '{}' may infer memory block - verify synthesis results",
-                sig.name, sig.r#type
-            ),
+            message: match memories.iter().find(|mem| std::ptr::eq(mem.signal, sig)) {
+                Some(mem) => format!(
+                    "Signal '{}' with type '{}' may infer memory block ({}) - verify synthesis results",
+                    sig.name,
+                    sig.r#type,
+                    mem.style()
+                ),
+                None => format!(
+                    "Signal '{}' with type '{}' may infer memory block - verify synthesis results",
+                    sig.name, sig.r#type
+                ),
+            },
+            confidence: if written_on_clock(input, &sig.file, &sig.name) {
+                Confidence::Medium
+            } else {
+                Confidence::Low
+            },
+            ..Default::default()
         })
         .collect()
 }
 
+fn drives_reset(input: &Input, file: &str, signal: &str) -> bool {
+    input
+        .processes
+        .iter()
+        .any(|proc| proc.file == file && proc.reset_signal.eq_ignore_ascii_case(signal))
+}
+
+/// Memories are written from a clocked process; an array only assigned
+/// combinationally is more likely a lookup table or plain wiring.
+fn written_on_clock(input: &Input, file: &str, signal: &str) -> bool {
+    input.processes.iter().any(|proc| {
+        proc.file == file
+            && proc.is_sequential
+            && proc
+                .assigned_signals
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(signal))
+    })
+}
+
 fn is_array_type(t: &str) -> bool {
     if t.to_ascii_lowercase().contains("array") {
         return true;
@@ -273,6 +324,217 @@ fn is_array_type(t: &str) -> bool {
     false
 }
 
+/// One indexed access `mem(address)` to a memory.
+struct MemoryAccess<'a> {
+    /// Index expression with whitespace collapsed.
+    address: String,
+    /// Clock of the enclosing clocked process; empty for combinational
+    /// processes and concurrent assignments.
+    clock: &'a str,
+    line: usize,
+}
+
+impl MemoryAccess<'_> {
+    fn is_sync(&self) -> bool {
+        !self.clock.is_empty()
+    }
+}
+
+/// An array signal written only element-wise from clocked processes: the
+/// shape synthesis maps onto a RAM.
+struct Memory<'a> {
+    signal: &'a Signal,
+    writes: Vec<MemoryAccess<'a>>,
+    reads: Vec<MemoryAccess<'a>>,
+}
+
+impl Memory<'_> {
+    /// Clock of the first write.
+    fn write_clock(&self) -> &str {
+        self.writes[0].clock
+    }
+
+    /// Port count from the distinct addresses, and whether any read is
+    /// unregistered, e.g. "dual-port RAM, async read".
+    fn style(&self) -> String {
+        let addresses: HashSet<String> = self
+            .writes
+            .iter()
+            .chain(&self.reads)
+            .map(|access| access.address.to_ascii_lowercase())
+            .collect();
+        let ports = match addresses.len() {
+            1 => "single-port",
+            2 => "dual-port",
+            _ => "multi-port",
+        };
+        let read = if self.reads.is_empty() {
+            "write-only"
+        } else if self.reads.iter().any(|r| !r.is_sync()) {
+            "async read"
+        } else {
+            "sync read"
+        };
+        format!("{} RAM, {}", ports, read)
+    }
+}
+
+/// Array signals written through an index from clocked processes, with the
+/// reads of their elements. A whole-array or combinational write disqualifies
+/// the signal: it is then wiring or a register file, not a RAM.
+fn memories(input: &Input) -> Vec<Memory<'_>> {
+    let array_types: HashSet<String> = input
+        .types
+        .iter()
+        .filter(|td| td.kind.eq_ignore_ascii_case("array"))
+        .map(|td| td.name.to_ascii_lowercase())
+        .collect();
+    let mut out = Vec::new();
+    'signals: for sig in &input.signals {
+        if !is_array_type(&sig.r#type)
+            && !array_types.contains(&helpers::base_type_name(&sig.r#type))
+        {
+            continue;
+        }
+        if input
+            .concurrent_assignments
+            .iter()
+            .any(|ca| ca.file == sig.file && ca.target.eq_ignore_ascii_case(&sig.name))
+        {
+            continue;
+        }
+        let mut writes = Vec::new();
+        let mut reads = Vec::new();
+        for proc in input.processes.iter().filter(|proc| proc.file == sig.file) {
+            let clock = if proc.is_sequential {
+                proc.clock_signal.as_str()
+            } else {
+                ""
+            };
+            for a in &proc.assignments {
+                if a.signal.eq_ignore_ascii_case(&sig.name) {
+                    match indexes(&a.target, &sig.name).into_iter().next() {
+                        Some(address) if !clock.is_empty() => writes.push(MemoryAccess {
+                            address,
+                            clock,
+                            line: a.line,
+                        }),
+                        _ => continue 'signals,
+                    }
+                }
+                for address in indexes(&a.value, &sig.name) {
+                    reads.push(MemoryAccess {
+                        address,
+                        clock,
+                        line: a.line,
+                    });
+                }
+            }
+        }
+        if writes.is_empty() {
+            continue;
+        }
+        for ca in input
+            .concurrent_assignments
+            .iter()
+            .filter(|ca| ca.file == sig.file)
+        {
+            for address in indexes(&ca.value, &sig.name) {
+                reads.push(MemoryAccess {
+                    address,
+                    clock: "",
+                    line: ca.line,
+                });
+            }
+        }
+        out.push(Memory {
+            signal: sig,
+            writes,
+            reads,
+        });
+    }
+    out
+}
+
+/// Index expressions of the `name(...)` occurrences in `text`.
+fn indexes(text: &str, name: &str) -> Vec<String> {
+    let re = Regex::new(&format!(r"(?i)(^|[^\w.'])({})\s*\(", regex::escape(name))).unwrap();
+    let mut out = Vec::new();
+    for caps in re.captures_iter(text) {
+        let open = caps.get(0).map_or(0, |m| m.end());
+        let mut depth = 1;
+        for (i, ch) in text[open..].char_indices() {
+            match ch {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                let address = text[open..open + i].split_whitespace().collect::<Vec<_>>();
+                out.push(address.join(" "));
+                break;
+            }
+        }
+    }
+    out
+}
+
+/// Block RAMs register their read port; a combinational read maps the
+/// memory onto LUTs or flip-flops instead.
+fn ram_async_read(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for mem in memories(input) {
+        for read in mem.reads.iter().filter(|r| !r.is_sync()) {
+            out.push(Violation {
+                rule: "ram_async_read".to_string(),
+                severity: "warning".to_string(),
+                file: mem.signal.file.clone(),
+                line: read.line,
+                message: format!(
+                    "Memory '{}' ({}, written on '{}') is read asynchronously at address '{}' - register the read to infer block RAM",
+                    mem.signal.name,
+                    mem.style(),
+                    mem.write_clock(),
+                    read.address
+                ),
+                confidence: Confidence::Medium,
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+/// A registered read on another clock than the write can hit the address
+/// being written; what it returns then depends on the RAM primitive.
+fn ram_read_during_write(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for mem in memories(input) {
+        for read in mem.reads.iter().filter(|r| r.is_sync()) {
+            let Some(write) = mem
+                .writes
+                .iter()
+                .find(|w| !w.clock.eq_ignore_ascii_case(read.clock))
+            else {
+                continue;
+            };
+            out.push(Violation {
+                rule: "ram_read_during_write".to_string(),
+                severity: "warning".to_string(),
+                file: mem.signal.file.clone(),
+                line: read.line,
+                message: format!(
+                    "Memory '{}' is written on '{}' at '{}' and read on '{}' at '{}' - a read of the address being written returns undefined data",
+                    mem.signal.name, write.clock, write.address, read.clock, read.address
+                ),
+                confidence: Confidence::Medium,
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
 fn unregistered_output(input: &Input) -> Vec<Violation> {
     let mut out = Vec::new();
     for port in input.ports.iter().filter(|p| p.direction == "out") {
@@ -295,6 +557,7 @@ fn unregistered_output(input: &Input) -> Vec<Violation> {
                 "Output port '{}' is driven by combinational logic - consider registering for timing closure",
                 port.name
             ),
+            ..Default::default()
         });
     }
     out
@@ -321,6 +584,55 @@ fn output_is_driven(input: &Input, port_name: &str) -> bool {
         .any(|ca| ca.target.eq_ignore_ascii_case(port_name))
 }
 
+/// 'Z' drivers below the top level. Most FPGA fabrics have no internal
+/// tri-state buffers, so synthesis either errors out or rewrites them into
+/// multiplexers that no longer behave like the simulated bus. An entity is
+/// top-level when `top_entities` lists it or, without that list, when no RTL
+/// architecture instantiates it (testbenches instantiating it don't count).
+fn internal_tristate(input: &Input) -> Vec<Violation> {
+    let contexts = FileContexts::new(input);
+    let instantiated: HashSet<String> = input
+        .instances
+        .iter()
+        .filter(|inst| contexts.context(&inst.file) == Context::Rtl)
+        .flat_map(|inst| hierarchy::bound_entities(input, inst))
+        .map(|entity| entity.name.to_ascii_lowercase())
+        .collect();
+    let is_top = |entity: &str| {
+        if input.top_entities.is_empty() {
+            return !instantiated.contains(&entity.to_ascii_lowercase());
+        }
+        input.top_entities.iter().any(|top| {
+            let name = top.rsplit('.').next().unwrap_or(top).trim();
+            name.eq_ignore_ascii_case(entity)
+        })
+    };
+
+    let mut out = Vec::new();
+    for assign in &input.tristate_assignments {
+        let Some(arch) = input.architectures.iter().find(|arch| {
+            arch.file == assign.file && arch.name.eq_ignore_ascii_case(&assign.in_arch)
+        }) else {
+            continue;
+        };
+        if is_top(&arch.entity_name) {
+            continue;
+        }
+        out.push(Violation {
+            rule: "internal_tristate".to_string(),
+            severity: "error".to_string(),
+            file: assign.file.clone(),
+            line: assign.line,
+            message: format!(
+                "'{} <= {}' drives 'Z' inside '{}', which is not the top-level entity - FPGA flows only support tri-state at I/O pads; pass an output enable up and place the tri-state buffer at the top level",
+                assign.target, assign.value, arch.entity_name
+            ),
+            ..Default::default()
+        });
+    }
+    out
+}
+
 fn get_entity_file(input: &Input, entity_name: &str) -> String {
     input
         .entities
@@ -333,7 +645,52 @@ fn get_entity_file(input: &Input, entity_name: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::policy::input::{Input, Signal};
+    use crate::policy::input::{
+        Architecture, ConcurrentAssignment, Entity, GenericDecl, Input, Instance, Process,
+        SequentialAssignment, Signal, TristateAssignment, TypeDeclaration,
+    };
+
+    fn assign(target: &str, value: &str, line: usize) -> SequentialAssignment {
+        SequentialAssignment {
+            target: target.to_string(),
+            signal: target.split('(').next().unwrap_or("").to_string(),
+            value: value.to_string(),
+            line,
+            ..Default::default()
+        }
+    }
+
+    fn clocked(clock: &str, assignments: Vec<SequentialAssignment>) -> Process {
+        Process {
+            is_sequential: true,
+            clock_signal: clock.to_string(),
+            assignments,
+            file: "ram.vhd".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// `ram : ram_t` with a named array type, written on `wclk` at `waddr`.
+    fn ram_input() -> Input {
+        let mut input = Input::default();
+        input.types.push(TypeDeclaration {
+            name: "ram_t".to_string(),
+            kind: "array".to_string(),
+            ..Default::default()
+        });
+        input.signals.push(Signal {
+            name: "ram".to_string(),
+            r#type: "ram_t".to_string(),
+            file: "ram.vhd".to_string(),
+            line: 3,
+            ..Default::default()
+        });
+        input.processes.push(clocked(
+            "wclk",
+            vec![assign("ram(to_integer(waddr))", "din", 10)],
+        ));
+        input
+    }
 
     #[test]
     fn very_wide_bus_flags() {
@@ -349,4 +706,145 @@ mod tests {
         assert_eq!(v.len(), 1);
         assert_eq!(v[0].rule, "very_wide_bus");
     }
+
+    #[test]
+    fn very_wide_bus_sizes_generic_ranges() {
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "top".to_string(),
+            generics: vec![GenericDecl {
+                name: "WIDTH".to_string(),
+                default: "512".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "top".to_string(),
+            ..Default::default()
+        });
+        input.signals.push(Signal {
+            name: "wide".to_string(),
+            in_entity: "rtl".to_string(),
+            r#type: "std_logic_vector(WIDTH-1 downto 0)".to_string(),
+            ..Default::default()
+        });
+        input.signals.push(Signal {
+            name: "narrow".to_string(),
+            in_entity: "rtl".to_string(),
+            r#type: "std_logic_vector(WIDTH/8-1 downto 0)".to_string(),
+            ..Default::default()
+        });
+        let v = very_wide_bus(&input);
+        assert_eq!(v.len(), 1);
+        assert!(v[0].message.contains("'wide' is 512 bits"));
+    }
+
+    #[test]
+    fn memories_classify_ports_and_read_style() {
+        let mut input = ram_input();
+        input.processes.push(clocked(
+            "wclk",
+            vec![assign("dout", "ram(to_integer( raddr ))", 20)],
+        ));
+        let found = memories(&input);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].style(), "dual-port RAM, sync read");
+        assert_eq!(found[0].reads[0].address, "to_integer( raddr )");
+        assert!(ram_async_read(&input).is_empty());
+        assert!(ram_read_during_write(&input).is_empty());
+
+        // A whole-array write makes it plain storage, not a RAM.
+        input.processes.push(clocked(
+            "wclk",
+            vec![assign("ram", "(others => x\"00\")", 30)],
+        ));
+        assert!(memories(&input).is_empty());
+    }
+
+    #[test]
+    fn ram_async_read_binds_memory_clock_and_address() {
+        let mut input = ram_input();
+        input.concurrent_assignments.push(ConcurrentAssignment {
+            target: "dout".to_string(),
+            value: "ram(to_integer(waddr))".to_string(),
+            file: "ram.vhd".to_string(),
+            line: 25,
+            ..Default::default()
+        });
+        let v = ram_async_read(&input);
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[0].line, 25);
+        assert_eq!(
+            v[0].message,
+            "Memory 'ram' (single-port RAM, async read, written on 'wclk') is read asynchronously at address 'to_integer(waddr)' - register the read to infer block RAM"
+        );
+    }
+
+    #[test]
+    fn ram_read_during_write_needs_another_clock() {
+        let mut input = ram_input();
+        input.processes.push(clocked(
+            "rclk",
+            vec![assign("dout", "ram(to_integer(raddr))", 20)],
+        ));
+        let v = ram_read_during_write(&input);
+        assert_eq!(v.len(), 1);
+        assert_eq!(
+            v[0].message,
+            "Memory 'ram' is written on 'wclk' at 'to_integer(waddr)' and read on 'rclk' at 'to_integer(raddr)' - a read of the address being written returns undefined data"
+        );
+    }
+
+    #[test]
+    fn internal_tristate_skips_the_top_level() {
+        let mut input = Input::default();
+        for (entity, file) in [
+            ("soc", "soc.vhd"),
+            ("bus_if", "bus_if.vhd"),
+            ("soc_tb", "soc_tb.vhd"),
+        ] {
+            input.entities.push(Entity {
+                name: entity.to_string(),
+                file: file.to_string(),
+                ..Default::default()
+            });
+            input.architectures.push(Architecture {
+                name: "rtl".to_string(),
+                entity_name: entity.to_string(),
+                file: file.to_string(),
+                ..Default::default()
+            });
+        }
+        for (target, file, arch) in [("bus_if", "soc.vhd", "rtl"), ("soc", "soc_tb.vhd", "rtl")] {
+            input.instances.push(Instance {
+                target: format!("work.{}", target),
+                file: file.to_string(),
+                in_arch: arch.to_string(),
+                ..Default::default()
+            });
+        }
+        for (target, file) in [("pad", "soc.vhd"), ("data", "bus_if.vhd")] {
+            input.tristate_assignments.push(TristateAssignment {
+                target: target.to_string(),
+                value: "(others => 'Z')".to_string(),
+                file: file.to_string(),
+                line: 12,
+                in_arch: "RTL".to_string(),
+                ..Default::default()
+            });
+        }
+
+        let v = internal_tristate(&input);
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[0].file, "bus_if.vhd");
+        assert_eq!(
+            v[0].message,
+            "'data <= (others => 'Z')' drives 'Z' inside 'bus_if', which is not the top-level entity - FPGA flows only support tri-state at I/O pads; pass an output enable up and place the tri-state buffer at the top level"
+        );
+
+        input.top_entities = vec!["work.soc".to_string(), "bus_if".to_string()];
+        assert!(internal_tristate(&input).is_empty());
+    }
 }
@@ -1,5 +1,6 @@
-use crate::policy::helpers::is_testbench_name;
-use crate::policy::input::Input;
+use crate::policy::helpers::{is_clock_name, is_testbench_name, process_name};
+use crate::policy::input::{Architecture, Input, Process};
+use crate::policy::registry::{Context, FileContexts};
 use crate::policy::result::Violation;
 
 pub fn violations(input: &Input) -> Vec<Violation> {
@@ -11,9 +12,23 @@ pub fn optional_violations(input: &Input) -> Vec<Violation> {
     out.extend(testbench_with_ports(input));
     out.extend(mismatched_tb_architecture(input));
     out.extend(tb_with_synth_arch(input));
+    out.extend(tb_missing_wait_termination(input));
+    out.extend(tb_no_assertions(input));
+    out.extend(tb_free_running_clock(input));
     out
 }
 
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "architectures",
+        "assertions",
+        "concurrent_assignments",
+        "entities",
+        "processes",
+        "psl_directives",
+    ]
+}
+
 fn testbench_with_ports(input: &Input) -> Vec<Violation> {
     input
         .entities
@@ -30,6 +45,7 @@ fn testbench_with_ports(input: &Input) -> Vec<Violation> {
                 entity.name,
                 entity.ports.len()
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -50,6 +66,7 @@ fn entity_no_ports_not_tb(input: &Input) -> Vec<Violation> {
                 "Entity '{}' has no ports but doesn't look like a testbench",
                 entity.name
             ),
+            ..Default::default()
         })
         .collect()
 }
@@ -75,6 +92,7 @@ fn mismatched_tb_architecture(input: &Input) -> Vec<Violation> {
                         "Architecture '{}' has testbench name but entity '{}' doesn't",
                         arch.name, arch.entity_name
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -103,6 +121,7 @@ fn tb_with_synth_arch(input: &Input) -> Vec<Violation> {
                         "Testbench entity '{}' has synthesis-style architecture name '{}'",
                         entity.name, arch.name
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -110,6 +129,181 @@ fn tb_with_synth_arch(input: &Input) -> Vec<Violation> {
     out
 }
 
+/// Stimulus processes (timed waits, driving signals) that never reach a bare
+/// `wait;` or end the simulation, so their stimulus replays forever.
+fn tb_missing_wait_termination(input: &Input) -> Vec<Violation> {
+    let contexts = FileContexts::new(input);
+    input
+        .processes
+        .iter()
+        .filter(|proc| contexts.context(&proc.file) == Context::Tb)
+        .filter(|proc| proc.sensitivity_list.is_empty() && !proc.assigned_signals.is_empty())
+        .filter(|proc| proc.wait_statements.iter().any(|w| !w.for_expr.is_empty()))
+        .filter(|proc| !is_clock_generator(proc) && !has_bare_wait(proc))
+        .filter(|proc| !ends_simulation(input, proc))
+        .map(|proc| Violation {
+            rule: "tb_missing_wait_termination".to_string(),
+            severity: "warning".to_string(),
+            file: proc.file.clone(),
+            line: proc.line,
+            message: format!(
+                "Testbench stimulus {} never reaches a final 'wait;' or stops the simulation - its stimulus replays every time the process loops",
+                process_name(proc)
+            ),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Testbench architectures that check nothing: no assert, failing report,
+/// PSL assertion or check procedure (VUnit `check_*`, OSVVM `AffirmIf`).
+fn tb_no_assertions(input: &Input) -> Vec<Violation> {
+    let contexts = FileContexts::new(input);
+    input
+        .architectures
+        .iter()
+        .filter(|arch| contexts.context(&arch.file) == Context::Tb)
+        .filter(|arch| is_testbench_name(&arch.entity_name))
+        .filter(|arch| {
+            let checks = input.assertions.iter().any(|a| {
+                in_arch(arch, &a.file, &a.in_arch)
+                    && (a.kind == "assert" || matches!(a.severity.as_str(), "error" | "failure"))
+            });
+            let psl = input
+                .psl_directives
+                .iter()
+                .any(|d| d.kind == "assert" && in_arch(arch, &d.file, &d.in_arch));
+            let calls = arch_processes(input, arch).any(|proc| {
+                proc.procedure_calls.iter().any(|call| {
+                    let name = call_name(&call.name, &call.full_name);
+                    ["check", "assert", "affirm", "alert", "require"]
+                        .iter()
+                        .any(|prefix| name.starts_with(prefix))
+                })
+            });
+            !checks && !psl && !calls
+        })
+        .map(|arch| Violation {
+            rule: "tb_no_assertions".to_string(),
+            severity: "warning".to_string(),
+            file: arch.file.clone(),
+            line: arch.line,
+            message: format!(
+                "Testbench '{}' (architecture '{}') has no assertions - it can run to completion without checking anything",
+                arch.entity_name, arch.name
+            ),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Clocks that toggle unconditionally in a testbench nothing stops: without
+/// an enable or `std.env.stop`, the simulation only ends at a time limit.
+fn tb_free_running_clock(input: &Input) -> Vec<Violation> {
+    let contexts = FileContexts::new(input);
+    let mut out = Vec::new();
+    for arch in input
+        .architectures
+        .iter()
+        .filter(|arch| contexts.context(&arch.file) == Context::Tb)
+    {
+        let concurrent_stop = input.assertions.iter().any(|a| {
+            a.process_line == 0 && a.severity == "failure" && in_arch(arch, &a.file, &a.in_arch)
+        });
+        if concurrent_stop || arch_processes(input, arch).any(|proc| ends_simulation(input, proc)) {
+            continue;
+        }
+        let mut clocks: Vec<(String, usize)> = input
+            .concurrent_assignments
+            .iter()
+            .filter(|ca| in_arch(arch, &ca.file, &ca.in_arch) && is_clock_name(&ca.target))
+            .filter(|ca| {
+                ca.read_signals
+                    .iter()
+                    .all(|read| read.eq_ignore_ascii_case(&ca.target))
+            })
+            .map(|ca| (ca.target.clone(), ca.line))
+            .collect();
+        for proc in arch_processes(input, arch) {
+            let gated = proc.read_signals.iter().any(|read| !is_clock_name(read));
+            if is_clock_generator(proc) && !gated && !has_bare_wait(proc) {
+                for clock in &proc.assigned_signals {
+                    clocks.push((clock.clone(), proc.line));
+                }
+            }
+        }
+        for (clock, line) in clocks {
+            out.push(Violation {
+                rule: "tb_free_running_clock".to_string(),
+                severity: "warning".to_string(),
+                file: arch.file.clone(),
+                line,
+                message: format!(
+                    "Clock '{}' in testbench architecture '{}' runs forever and nothing stops the simulation - gate it with an enable or call std.env.stop",
+                    clock, arch.name
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+fn in_arch(arch: &Architecture, file: &str, name: &str) -> bool {
+    arch.file == file && arch.name.eq_ignore_ascii_case(name)
+}
+
+fn arch_processes<'a>(
+    input: &'a Input,
+    arch: &'a Architecture,
+) -> impl Iterator<Item = &'a Process> + 'a {
+    input
+        .processes
+        .iter()
+        .filter(move |proc| in_arch(arch, &proc.file, &proc.in_arch))
+}
+
+/// A wait-driven process assigning nothing but clocks.
+fn is_clock_generator(proc: &Process) -> bool {
+    proc.sensitivity_list.is_empty()
+        && !proc.wait_statements.is_empty()
+        && !proc.assigned_signals.is_empty()
+        && proc.assigned_signals.iter().all(|sig| is_clock_name(sig))
+}
+
+fn has_bare_wait(proc: &Process) -> bool {
+    proc.wait_statements
+        .iter()
+        .any(|w| w.on_signals.is_empty() && w.until_expr.is_empty() && w.for_expr.is_empty())
+}
+
+/// Calls `std.env.stop`/`finish` or fails an assertion with severity failure.
+fn ends_simulation(input: &Input, proc: &Process) -> bool {
+    proc.procedure_calls.iter().any(|call| {
+        matches!(
+            call_name(&call.name, &call.full_name).as_str(),
+            "stop" | "finish"
+        )
+    }) || input
+        .assertions
+        .iter()
+        .any(|a| a.file == proc.file && a.process_line == proc.line && a.severity == "failure")
+}
+
+/// Lowercase last segment of a (possibly dotted) procedure name.
+fn call_name(name: &str, full_name: &str) -> String {
+    let name = if full_name.is_empty() {
+        name
+    } else {
+        full_name
+    };
+    name.rsplit('.')
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_ascii_lowercase()
+}
+
 fn is_testbench_arch_name(name: &str) -> bool {
     matches!(
         name.to_ascii_lowercase().as_str(),
@@ -132,7 +326,9 @@ fn is_package_name(name: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::policy::input::{Architecture, Entity, Input, Port};
+    use crate::policy::input::{
+        Architecture, Assertion, ConcurrentAssignment, Entity, Input, Port, WaitStatement,
+    };
 
     #[test]
     fn testbench_with_ports_flags() {
@@ -177,6 +373,7 @@ mod tests {
             entity_name: "core".to_string(),
             file: "a.vhd".to_string(),
             line: 3,
+            ..Default::default()
         });
         let violations = mismatched_tb_architecture(&input);
         assert_eq!(violations.len(), 1);
@@ -197,9 +394,90 @@ mod tests {
             entity_name: "core_tb".to_string(),
             file: "a.vhd".to_string(),
             line: 3,
+            ..Default::default()
         });
         let violations = tb_with_synth_arch(&input);
         assert_eq!(violations.len(), 1);
         assert_eq!(violations[0].rule, "tb_with_synth_arch");
     }
+
+    fn looping_tb() -> Input {
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "loop_tb".to_string(),
+            file: "loop_tb.vhd".to_string(),
+            line: 4,
+            ..Default::default()
+        });
+        input.architectures.push(Architecture {
+            name: "sim".to_string(),
+            entity_name: "loop_tb".to_string(),
+            file: "loop_tb.vhd".to_string(),
+            line: 7,
+            ..Default::default()
+        });
+        input.concurrent_assignments.push(ConcurrentAssignment {
+            target: "clk".to_string(),
+            read_signals: vec!["clk".to_string()],
+            file: "loop_tb.vhd".to_string(),
+            line: 11,
+            in_arch: "sim".to_string(),
+            ..Default::default()
+        });
+        let timed = |line| WaitStatement {
+            for_expr: "20 ns".to_string(),
+            line,
+            in_process: "stim".to_string(),
+            ..Default::default()
+        };
+        input.processes.push(Process {
+            label: "stim".to_string(),
+            assigned_signals: vec!["rst".to_string()],
+            wait_statements: vec![timed(15), timed(17)],
+            file: "loop_tb.vhd".to_string(),
+            line: 13,
+            in_arch: "sim".to_string(),
+            ..Default::default()
+        });
+        input
+    }
+
+    #[test]
+    fn looping_testbench_flags_profile_rules() {
+        let mut input = looping_tb();
+        assert_eq!(tb_missing_wait_termination(&input).len(), 1);
+        let no_checks = tb_no_assertions(&input);
+        assert_eq!(no_checks.len(), 1);
+        assert!(no_checks[0]
+            .message
+            .contains("'loop_tb' (architecture 'sim')"));
+        let clocks = tb_free_running_clock(&input);
+        assert_eq!(clocks.len(), 1);
+        assert_eq!(clocks[0].line, 11);
+
+        input.processes[0].wait_statements.push(WaitStatement {
+            line: 18,
+            ..Default::default()
+        });
+        input.assertions.push(Assertion {
+            kind: "report".to_string(),
+            severity: "failure".to_string(),
+            file: "loop_tb.vhd".to_string(),
+            line: 19,
+            in_arch: "sim".to_string(),
+            in_process: "stim".to_string(),
+            process_line: 13,
+        });
+        assert!(tb_missing_wait_termination(&input).is_empty());
+        assert!(tb_no_assertions(&input).is_empty());
+        assert!(tb_free_running_clock(&input).is_empty());
+
+        // Not a testbench file: none of the profile rules look at it.
+        let mut rtl = looping_tb();
+        rtl.entities[0].name = "looper".to_string();
+        rtl.architectures[0].entity_name = "looper".to_string();
+        assert!(tb_missing_wait_termination(&rtl).is_empty());
+        assert!(tb_no_assertions(&rtl).is_empty());
+        assert!(tb_free_running_clock(&rtl).is_empty());
+    }
 }
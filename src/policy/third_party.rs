@@ -0,0 +1,75 @@
+//! Recognizes vendor code the project didn't write, so it is classified as
+//! third-party (`FileInfo.is_third_party`) without being listed in the
+//! config's `third_party` globs: files under a `vendor/` or `ip/` directory,
+//! and files whose leading comment block is an FPGA vendor's copyright
+//! header (Xilinx, Altera/Intel, Lattice, Microsemi/Microchip).
+
+use crate::policy::helpers;
+
+/// Paths classified as third-party whatever the config says.
+pub const VENDOR_GLOBS: &[&str] = &["**/vendor/**", "**/ip/**"];
+
+/// Copyright holders of vendor-generated primitives and IP cores.
+const VENDORS: &[&str] = &[
+    "xilinx",
+    "advanced micro devices",
+    "altera",
+    "intel corporation",
+    "lattice semiconductor",
+    "microsemi",
+    "microchip technology",
+];
+
+/// Whether `path` or the header of `text` marks the file as vendor code.
+pub fn is_vendor_file(path: &str, text: &str) -> bool {
+    is_vendor_path(path) || has_vendor_header(text)
+}
+
+pub fn is_vendor_path(path: &str) -> bool {
+    let path = path.trim_start_matches("./").replace('\\', "/");
+    VENDOR_GLOBS
+        .iter()
+        .any(|glob| helpers::glob_match(glob, &path))
+}
+
+/// Whether a comment line of the file's leading comment block is a vendor's
+/// copyright notice. A design that merely targets a vendor's device names it
+/// without claiming its copyright, so it isn't matched.
+pub fn has_vendor_header(text: &str) -> bool {
+    text.lines()
+        .map(str::trim)
+        .take_while(|line| line.is_empty() || line.starts_with("--"))
+        .map(str::to_ascii_lowercase)
+        .any(|line| {
+            (line.contains("copyright") || line.contains("(c)"))
+                && VENDORS.iter().any(|vendor| line.contains(vendor))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_vendor_directories_and_copyright_headers() {
+        assert!(is_vendor_path("./vendor/axi/axi_fifo.vhd"));
+        assert!(is_vendor_path("rtl/ip/fifo_gen/fifo_gen.vhd"));
+        assert!(!is_vendor_path("rtl/ipsec/core.vhd"));
+        assert!(!is_vendor_path("ip.vhd"));
+
+        let xilinx =
+            "\n-- (c) Copyright 1995-2019 Xilinx, Inc. All rights reserved.\n--\nlibrary ieee;\n";
+        assert!(has_vendor_header(xilinx));
+        assert!(has_vendor_header(
+            "-- Copyright (C) 2018  Intel Corporation. All rights reserved.\n"
+        ));
+        assert!(!has_vendor_header(
+            "-- Target: Xilinx Artix-7\n-- Copyright 2024 Acme\nentity top is end;\n"
+        ));
+        // Only the leading comment block counts as the header.
+        assert!(!has_vendor_header(
+            "library ieee;\n-- (c) Copyright Xilinx, Inc.\n"
+        ));
+        assert!(is_vendor_file("top.vhd", xilinx));
+    }
+}
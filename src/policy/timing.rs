@@ -0,0 +1,290 @@
+//! Combinational depth estimates from `signal_deps`. Every combinational
+//! assignment (a concurrent statement or one inside a combinational process)
+//! counts as a level of logic, and a signal's depth is the longest chain of
+//! them since a register, input port or constant. Paths deeper than
+//! `lint_config.max_logic_depth` are reported with their members so it's
+//! clear where a pipeline stage would split them.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use crate::policy::input::Input;
+use crate::policy::result::Violation;
+
+const DEFAULT_MAX_LOGIC_DEPTH: usize = 8;
+
+/// The longest combinational chain ending at one signal, source first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CombPath {
+    pub signals: Vec<String>,
+    pub file: String,
+    /// Line of the assignment driving the last signal.
+    pub line: usize,
+}
+
+impl CombPath {
+    /// Levels of logic on the path.
+    pub fn depth(&self) -> usize {
+        self.signals.len().saturating_sub(1)
+    }
+}
+
+pub fn violations(input: &Input) -> Vec<Violation> {
+    let limit = max_logic_depth(input);
+    let mut out = Vec::new();
+    for arch in &input.architectures {
+        for path in paths(input, &arch.file, &arch.name) {
+            if path.depth() <= limit {
+                continue;
+            }
+            let end = path.signals.last().cloned().unwrap_or_default();
+            let split = &path.signals[path.signals.len() / 2];
+            out.push(Violation {
+                rule: "deep_combinational_path".to_string(),
+                severity: "warning".to_string(),
+                file: path.file.clone(),
+                line: path.line,
+                message: format!(
+                    "Combinational path to '{}' is {} levels deep (limit {}): {} - consider registering '{}' to split it",
+                    end,
+                    path.depth(),
+                    limit,
+                    path.signals.join(" -> "),
+                    split
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+pub fn reads() -> &'static [&'static str] {
+    &["architectures", "signal_deps"]
+}
+
+fn max_logic_depth(input: &Input) -> usize {
+    match input.lint_config.max_logic_depth {
+        0 => DEFAULT_MAX_LOGIC_DEPTH,
+        depth => depth,
+    }
+}
+
+/// The deepest path to every endpoint of an architecture: each signal driven
+/// combinationally that doesn't itself feed further combinational logic.
+/// Signals are lowercase; endpoints are in name order. Edges closing a
+/// combinational loop are ignored, as `combinational_loop` reports those.
+pub fn paths(input: &Input, file: &str, arch: &str) -> Vec<CombPath> {
+    let mut drivers: BTreeMap<String, BTreeMap<String, usize>> = BTreeMap::new();
+    let mut sources = BTreeSet::new();
+    for dep in input.signal_deps.iter().filter(|dep| {
+        !dep.is_sequential && dep.file == file && dep.in_arch.eq_ignore_ascii_case(arch)
+    }) {
+        let source = dep.source.to_ascii_lowercase();
+        let target = dep.target.to_ascii_lowercase();
+        if source == target {
+            continue;
+        }
+        sources.insert(source.clone());
+        let line = drivers
+            .entry(target)
+            .or_default()
+            .entry(source)
+            .or_insert(dep.line);
+        *line = (*line).min(dep.line);
+    }
+
+    let mut levels = HashMap::new();
+    drivers
+        .keys()
+        .filter(|target| !sources.contains(*target))
+        .map(|end| {
+            longest_chain(
+                end,
+                |signal| {
+                    drivers
+                        .get(signal)
+                        .into_iter()
+                        .flatten()
+                        .map(|(source, line)| (source.as_str(), *line))
+                },
+                &mut levels,
+            );
+            let mut signals = vec![end.clone()];
+            let mut line = 0;
+            let mut current = end.clone();
+            while let Some(Chain {
+                from: Some((source, at)),
+                ..
+            }) = levels.get(&current)
+            {
+                if line == 0 {
+                    line = *at;
+                }
+                signals.push(source.clone());
+                current = source.clone();
+            }
+            signals.reverse();
+            CombPath {
+                signals,
+                file: file.to_string(),
+                line,
+            }
+        })
+        .collect()
+}
+
+/// The longest chain of steps from a signal, and the first step (with its
+/// line) it goes through.
+pub(crate) struct Chain {
+    pub depth: usize,
+    pub from: Option<(String, usize)>,
+}
+
+/// Longest chain of `steps` from `start`, memoizing every signal it passes
+/// in `chains`. A step back onto the chain being searched is skipped, which
+/// cuts feedback loops; among equally long chains the first step wins. Uses
+/// an explicit work stack, so long dependency chains can't overflow the call
+/// stack.
+pub(crate) fn longest_chain<'a, I>(
+    start: &str,
+    steps: impl Fn(&str) -> I,
+    chains: &mut HashMap<String, Chain>,
+) -> usize
+where
+    I: Iterator<Item = (&'a str, usize)>,
+{
+    if let Some(known) = chains.get(start) {
+        return known.depth;
+    }
+    /// A signal being searched, its remaining steps, the best chain so far
+    /// and the step whose chain is being searched above it.
+    struct Frame<I> {
+        signal: String,
+        steps: I,
+        best: Chain,
+        pending: Option<(String, usize)>,
+    }
+    let frame = |signal: &str| Frame {
+        signal: signal.to_string(),
+        steps: steps(signal),
+        best: Chain {
+            depth: 0,
+            from: None,
+        },
+        pending: None,
+    };
+    let offer = |best: &mut Chain, depth: usize, step: (String, usize)| {
+        if depth > best.depth {
+            *best = Chain {
+                depth,
+                from: Some(step),
+            };
+        }
+    };
+
+    let mut on_path = HashSet::from([start.to_string()]);
+    let mut stack = vec![frame(start)];
+    while let Some(top) = stack.last_mut() {
+        if let Some((next, line)) = top.steps.next() {
+            if on_path.contains(next) {
+                continue;
+            }
+            if let Some(known) = chains.get(next) {
+                let depth = known.depth + 1;
+                offer(&mut top.best, depth, (next.to_string(), line));
+                continue;
+            }
+            top.pending = Some((next.to_string(), line));
+            on_path.insert(next.to_string());
+            stack.push(frame(next));
+            continue;
+        }
+        let done = stack.pop().expect("stack has a top frame");
+        on_path.remove(&done.signal);
+        let depth = done.best.depth;
+        chains.insert(done.signal, done.best);
+        match stack.last_mut() {
+            Some(parent) => {
+                let step = parent
+                    .pending
+                    .take()
+                    .expect("child frames have a pending step");
+                offer(&mut parent.best, depth + 1, step);
+            }
+            None => return depth,
+        }
+    }
+    unreachable!("the start frame returns when popped")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{Architecture, SignalDep};
+
+    fn dep(source: &str, target: &str, sequential: bool, line: usize) -> SignalDep {
+        SignalDep {
+            source: source.to_string(),
+            target: target.to_string(),
+            is_sequential: sequential,
+            file: "alu.vhd".to_string(),
+            line,
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn reports_paths_deeper_than_the_limit() {
+        let mut input = Input::default();
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "alu".to_string(),
+            file: "alu.vhd".to_string(),
+            ..Default::default()
+        });
+        // a -> s1 -> s2 -> s3 -> s4, with a shortcut a -> s3 and a register
+        // on s4; s2 -> s1 closes a loop that must not be followed.
+        input.signal_deps.push(dep("a", "s1", false, 10));
+        input.signal_deps.push(dep("s1", "s2", false, 11));
+        input.signal_deps.push(dep("S2", "s3", false, 12));
+        input.signal_deps.push(dep("a", "s3", false, 12));
+        input.signal_deps.push(dep("s3", "s4", false, 13));
+        input.signal_deps.push(dep("s4", "q", true, 20));
+        input.signal_deps.push(dep("b", "y", false, 14));
+
+        let found = paths(&input, "alu.vhd", "RTL");
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].signals, ["a", "s1", "s2", "s3", "s4"]);
+        assert_eq!(found[0].depth(), 4);
+        assert_eq!(found[0].line, 13);
+        assert_eq!(found[1].signals, ["b", "y"]);
+
+        assert!(violations(&input).is_empty());
+        input.lint_config.max_logic_depth = 3;
+        let out = violations(&input);
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0].line, 13);
+        assert_eq!(
+            out[0].message,
+            "Combinational path to 's4' is 4 levels deep (limit 3): a -> s1 -> s2 -> s3 -> s4 - consider registering 's2' to split it"
+        );
+
+        input.signal_deps.push(dep("s2", "s1", false, 15));
+        assert_eq!(paths(&input, "alu.vhd", "rtl")[0].depth(), 4);
+    }
+
+    #[test]
+    fn deep_chains_do_not_overflow_the_stack() {
+        let mut input = Input::default();
+        for n in 0..50_000 {
+            input
+                .signal_deps
+                .push(dep(&format!("s{n}"), &format!("s{}", n + 1), false, n));
+        }
+        let found = paths(&input, "alu.vhd", "rtl");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].depth(), 50_000);
+        assert_eq!(found[0].signals[0], "s0");
+    }
+}
@@ -1,13 +1,39 @@
-use crate::policy::helpers::{is_signed_type, is_unsigned_type};
-use crate::policy::input::Input;
-use crate::policy::result::Violation;
+use crate::policy::helpers::{base_type_name, is_signed_type, is_unsigned_type, range_direction};
+use crate::policy::hierarchy;
+use crate::policy::input::{Entity, Input, Port, TypeDeclaration};
+use crate::policy::result::{Confidence, Violation};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
-pub fn violations(_input: &Input) -> Vec<Violation> {
-    Vec::new()
+pub fn violations(input: &Input) -> Vec<Violation> {
+    port_type_not_visible(input)
 }
 
 pub fn optional_violations(input: &Input) -> Vec<Violation> {
-    mixed_signedness(input)
+    let mut out = Vec::new();
+    out.extend(mixed_signedness(input));
+    out.extend(mixed_bit_order(input));
+    out.extend(record_field_usage(input));
+    out
+}
+
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "architectures",
+        "case_statements",
+        "concurrent_assignments",
+        "constant_decls",
+        "dependencies",
+        "entities",
+        "functions",
+        "generates",
+        "instances",
+        "ports",
+        "procedures",
+        "processes",
+        "signals",
+        "subtypes",
+        "types",
+    ]
 }
 
 fn mixed_signedness(input: &Input) -> Vec<Violation> {
@@ -30,6 +56,7 @@ fn mixed_signedness(input: &Input) -> Vec<Violation> {
                         "Architecture uses both signed ('{}') and unsigned ('{}') types - ensure proper conversions",
                         s1.name, s2.name
                     ),
+                    ..Default::default()
                 });
             }
         }
@@ -37,10 +64,707 @@ fn mixed_signedness(input: &Input) -> Vec<Violation> {
     violations
 }
 
+struct RangedDecl<'a> {
+    name: &'a str,
+    file: &'a str,
+    line: usize,
+    direction: &'static str,
+}
+
+/// Vector ports and signals grouped by design unit (entity plus its
+/// architectures), keyed by lowercase entity name.
+fn ranged_decls_by_unit(input: &Input) -> BTreeMap<String, Vec<RangedDecl<'_>>> {
+    let mut units: BTreeMap<String, Vec<RangedDecl>> = BTreeMap::new();
+    for entity in &input.entities {
+        for port in &entity.ports {
+            if let Some(direction) = range_direction(&port.r#type) {
+                units
+                    .entry(entity.name.to_ascii_lowercase())
+                    .or_default()
+                    .push(RangedDecl {
+                        name: &port.name,
+                        file: &entity.file,
+                        line: port.line,
+                        direction,
+                    });
+            }
+        }
+    }
+    for sig in &input.signals {
+        let Some(direction) = range_direction(&sig.r#type) else {
+            continue;
+        };
+        // Signals record their architecture; resolve it to the entity in the same file.
+        let unit = input
+            .architectures
+            .iter()
+            .find(|arch| arch.name.eq_ignore_ascii_case(&sig.in_entity) && arch.file == sig.file)
+            .map(|arch| arch.entity_name.as_str())
+            .unwrap_or(&sig.in_entity);
+        units
+            .entry(unit.to_ascii_lowercase())
+            .or_default()
+            .push(RangedDecl {
+                name: &sig.name,
+                file: &sig.file,
+                line: sig.line,
+                direction,
+            });
+    }
+    units
+}
+
+/// Flags vectors whose range direction disagrees with the rest of their design
+/// unit, or with `lint_config.bit_order` when a project preference is set.
+fn mixed_bit_order(input: &Input) -> Vec<Violation> {
+    let preferred = preferred_bit_order(input);
+    let mut out = Vec::new();
+    for (unit, decls) in ranged_decls_by_unit(input) {
+        let downto = decls.iter().filter(|d| d.direction == "downto").count();
+        let to = decls.len() - downto;
+        let expected = match preferred {
+            Some(direction) => direction,
+            None if downto == 0 || to == 0 => continue,
+            None if to > downto => "to",
+            None => "downto",
+        };
+        for decl in decls.iter().filter(|d| d.direction != expected) {
+            let reason = if preferred.is_some() {
+                format!("project prefers '{}'", expected)
+            } else {
+                format!("design unit '{}' otherwise uses '{}'", unit, expected)
+            };
+            out.push(Violation {
+                rule: "mixed_bit_order".to_string(),
+                severity: "info".to_string(),
+                file: decl.file.to_string(),
+                line: decl.line,
+                message: format!(
+                    "Vector '{}' uses a '{}' range but {}",
+                    decl.name, decl.direction, reason
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+fn preferred_bit_order(input: &Input) -> Option<&'static str> {
+    let configured = input.lint_config.bit_order.trim();
+    if configured.eq_ignore_ascii_case("downto") {
+        Some("downto")
+    } else if configured.eq_ignore_ascii_case("to") {
+        Some("to")
+    } else {
+        None
+    }
+}
+
+/// Where a port's type name is declared: (architecture, package) per
+/// declaration, empty strings for entity-level declarations.
+fn type_scopes<'a>(input: &'a Input, name: &str) -> Vec<(&'a str, &'a str)> {
+    let types = input
+        .types
+        .iter()
+        .filter(|t| t.name.eq_ignore_ascii_case(name))
+        .map(|t| (t.in_arch.as_str(), t.in_package.as_str()));
+    let subtypes = input
+        .subtypes
+        .iter()
+        .filter(|t| t.name.eq_ignore_ascii_case(name))
+        .map(|t| (t.in_arch.as_str(), t.in_package.as_str()));
+    types.chain(subtypes).collect()
+}
+
+/// True when a `use` clause in `file` names `package` (e.g. `work.pkg.all`).
+fn file_uses_package(input: &Input, file: &str, package: &str) -> bool {
+    input
+        .dependencies
+        .iter()
+        .filter(|dep| dep.kind == "use" && dep.source == file)
+        .any(|dep| {
+            dep.target
+                .split('.')
+                .any(|part| part.trim().eq_ignore_ascii_case(package))
+        })
+}
+
+fn file_has_context_reference(input: &Input, file: &str) -> bool {
+    input
+        .dependencies
+        .iter()
+        .any(|dep| dep.kind == "context" && dep.source == file)
+}
+
+/// Port types must be visible wherever the entity is elaborated: a type
+/// declared only inside an architecture, or in a package the entity's file
+/// never `use`s, makes the entity fail to analyze.
+fn port_type_not_visible(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for entity in &input.entities {
+        for port in &entity.ports {
+            if let Some(violation) = invisible_port_type(input, entity, port) {
+                out.push(violation);
+            }
+        }
+    }
+    out
+}
+
+fn invisible_port_type(input: &Input, entity: &Entity, port: &Port) -> Option<Violation> {
+    let head = port.r#type.split('(').next().unwrap_or("").trim();
+    // Selected names (`pkg.t`, `work.pkg.t`) carry their own visibility.
+    if head.contains('.') {
+        return None;
+    }
+    let name = base_type_name(&port.r#type);
+    let scopes = type_scopes(input, &name);
+    if scopes.is_empty() {
+        return None;
+    }
+    let visible = scopes.iter().any(|(arch, package)| {
+        (arch.is_empty() && package.is_empty())
+            || (!package.is_empty() && file_uses_package(input, &entity.file, package))
+    });
+    if visible {
+        return None;
+    }
+    let (severity, reason) = match scopes.iter().find(|(_, package)| !package.is_empty()) {
+        Some(_) if file_has_context_reference(input, &entity.file) => return None,
+        Some((_, package)) => (
+            "warning",
+            format!("package '{}', which this file does not use", package),
+        ),
+        None => (
+            "error",
+            format!("architecture '{}', not visible to the entity", scopes[0].0),
+        ),
+    };
+    Some(Violation {
+        rule: "port_type_not_visible".to_string(),
+        severity: severity.to_string(),
+        file: entity.file.clone(),
+        line: port.line,
+        message: format!(
+            "Port '{}' of entity '{}' has type '{}' declared in {}",
+            port.name, entity.name, name, reason
+        ),
+        ..Default::default()
+    })
+}
+
+/// How a record field is used across the design.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FieldUse {
+    pub read: bool,
+    pub written: bool,
+    /// Where the field is first read by name, for the report.
+    pub read_at: Option<(String, usize)>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Access {
+    Read,
+    Write,
+    Both,
+}
+
+/// Record types reached from objects (signals, ports, constants, variables
+/// and subprogram parameters) and how their fields are read and written.
+/// Fields are tracked through selected names (`bus.valid`, `lanes(i).data`,
+/// nested records) in assignment targets and values, if and case
+/// expressions, generate conditions and port maps; using a whole record reads or writes
+/// every field. Entity input ports count as written, output ports as read.
+pub struct RecordUsage<'a> {
+    records: HashMap<String, &'a TypeDeclaration>,
+    /// Array types to their element type, subtypes to their base, lowercase.
+    aliases: HashMap<String, String>,
+    /// Object name to the types it is declared with, lowercase.
+    objects: HashMap<String, Vec<String>>,
+    pub fields: BTreeMap<String, BTreeMap<String, FieldUse>>,
+    /// Records held by subprogram parameters, whose uses inside subprogram
+    /// bodies aren't recorded.
+    pub opaque: BTreeSet<String>,
+    /// Records held by variables, whose assignments aren't recorded.
+    pub variable_held: BTreeSet<String>,
+}
+
+impl<'a> RecordUsage<'a> {
+    pub fn analyze(input: &'a Input) -> Self {
+        let mut usage = RecordUsage {
+            records: input
+                .types
+                .iter()
+                .filter(|decl| decl.kind == "record")
+                .map(|decl| (decl.name.to_ascii_lowercase(), decl))
+                .collect(),
+            aliases: input
+                .types
+                .iter()
+                .filter(|decl| !decl.element_type.is_empty())
+                .map(|decl| {
+                    (
+                        decl.name.to_ascii_lowercase(),
+                        base_type_name(&decl.element_type),
+                    )
+                })
+                .chain(input.subtypes.iter().map(|decl| {
+                    (
+                        decl.name.to_ascii_lowercase(),
+                        base_type_name(&decl.base_type),
+                    )
+                }))
+                .collect(),
+            objects: HashMap::new(),
+            fields: BTreeMap::new(),
+            opaque: BTreeSet::new(),
+            variable_held: BTreeSet::new(),
+        };
+        for decl in usage.records.values() {
+            let fields = decl
+                .fields
+                .iter()
+                .map(|field| (field.name.to_ascii_lowercase(), FieldUse::default()))
+                .collect();
+            usage.fields.insert(decl.name.to_ascii_lowercase(), fields);
+        }
+        usage.collect_objects(input);
+        usage.collect_uses(input);
+        usage
+    }
+
+    fn collect_objects(&mut self, input: &Input) {
+        let mut declare = |name: &str, ty: &str| {
+            self.objects
+                .entry(name.to_ascii_lowercase())
+                .or_default()
+                .push(ty.to_string());
+        };
+        for sig in &input.signals {
+            declare(&sig.name, &sig.r#type);
+        }
+        let ports = input
+            .entities
+            .iter()
+            .flat_map(|entity| &entity.ports)
+            .chain(&input.ports);
+        for port in ports.clone() {
+            declare(&port.name, &port.r#type);
+        }
+        for decl in &input.constant_decls {
+            declare(&decl.name, &decl.r#type);
+        }
+        let mut variables = Vec::new();
+        for var in input.processes.iter().flat_map(|proc| &proc.variables) {
+            declare(&var.name, &var.r#type);
+            variables.push(var.r#type.as_str());
+        }
+        let mut parameters = Vec::new();
+        let params = input
+            .functions
+            .iter()
+            .flat_map(|func| &func.parameters)
+            .chain(input.procedures.iter().flat_map(|proc| &proc.parameters));
+        for param in params {
+            declare(&param.name, &param.r#type);
+            parameters.push(param.r#type.as_str());
+        }
+        for ty in variables {
+            let held = self.contained_records(ty);
+            self.variable_held.extend(held);
+        }
+        for ty in parameters {
+            let held = self.contained_records(ty);
+            self.opaque.extend(held);
+        }
+
+        for port in ports {
+            let access = match port.direction.to_ascii_lowercase().as_str() {
+                "out" => Access::Read,
+                "inout" | "buffer" => Access::Both,
+                _ => Access::Write,
+            };
+            self.whole(&port.r#type, access, 0);
+        }
+        for decl in &input.constant_decls {
+            self.whole(&decl.r#type, Access::Write, 0);
+        }
+    }
+
+    fn collect_uses(&mut self, input: &Input) {
+        for proc in &input.processes {
+            for assign in &proc.assignments {
+                self.text(&assign.target, Access::Write, &proc.file, assign.line);
+                self.text(&assign.value, Access::Read, &proc.file, assign.line);
+            }
+            for wait in &proc.wait_statements {
+                self.text(&wait.until_expr, Access::Read, &proc.file, wait.line);
+            }
+            for arm in proc.if_statements.iter().flat_map(|stmt| &stmt.arms) {
+                self.text(&arm.condition, Access::Read, &proc.file, arm.line);
+            }
+        }
+        for assign in &input.concurrent_assignments {
+            self.text(
+                &assign.target_full,
+                Access::Write,
+                &assign.file,
+                assign.line,
+            );
+            if assign.value.is_empty() {
+                // Conditional and selected assignments keep no value text;
+                // everything they read counts as read in full.
+                for name in &assign.read_signals {
+                    self.text(name, Access::Read, &assign.file, assign.line);
+                }
+            } else {
+                self.text(&assign.value, Access::Read, &assign.file, assign.line);
+            }
+        }
+        for case in &input.case_statements {
+            self.text(&case.expression, Access::Read, &case.file, case.line);
+        }
+        for generate in &input.generates {
+            self.text(
+                &generate.condition,
+                Access::Read,
+                &generate.file,
+                generate.line,
+            );
+        }
+        for decl in &input.constant_decls {
+            self.text(&decl.value, Access::Read, &decl.file, decl.line);
+        }
+        for inst in &input.instances {
+            let entities = hierarchy::bound_entities(input, inst);
+            let direction = |formal: &str| {
+                entities
+                    .iter()
+                    .flat_map(|entity| &entity.ports)
+                    .find(|port| !formal.is_empty() && port.name.eq_ignore_ascii_case(formal))
+                    .map(|port| port.direction.to_ascii_lowercase())
+            };
+            let mut connections: Vec<(String, String)> = inst
+                .associations
+                .iter()
+                .filter(|assoc| assoc.kind == "port")
+                .map(|assoc| {
+                    let actual = if assoc.actual_full.is_empty() {
+                        &assoc.actual
+                    } else {
+                        &assoc.actual_full
+                    };
+                    (assoc.formal.clone(), actual.clone())
+                })
+                .collect();
+            if connections.is_empty() {
+                connections = inst
+                    .port_map
+                    .iter()
+                    .map(|(formal, actual)| (formal.clone(), actual.clone()))
+                    .collect();
+            }
+            for (formal, actual) in connections {
+                let access = match direction(&formal).as_deref() {
+                    Some("in") => Access::Read,
+                    Some("out") => Access::Write,
+                    _ => Access::Both,
+                };
+                self.text(&actual, access, &inst.file, inst.line);
+            }
+        }
+    }
+
+    /// Record types a type holds: itself, array elements and nested fields.
+    fn contained_records(&self, ty: &str) -> Vec<String> {
+        let mut out = Vec::new();
+        let mut pending = vec![base_type_name(ty)];
+        while let Some(name) = pending.pop() {
+            let Some(record) = self.record(&name) else {
+                continue;
+            };
+            let key = record.name.to_ascii_lowercase();
+            if out.contains(&key) {
+                continue;
+            }
+            pending.extend(record.fields.iter().map(|f| base_type_name(&f.r#type)));
+            out.push(key);
+        }
+        out
+    }
+
+    /// The record `name` is, or is an array or subtype of.
+    fn record(&self, name: &str) -> Option<&'a TypeDeclaration> {
+        let mut name = name.to_string();
+        for _ in 0..8 {
+            if let Some(record) = self.records.get(&name) {
+                return Some(record);
+            }
+            name = self.aliases.get(&name)?.clone();
+        }
+        None
+    }
+
+    /// Marks every field of a record type (and of records nested in it).
+    fn whole(&mut self, ty: &str, access: Access, depth: usize) {
+        let Some(record) = self.record(&base_type_name(ty)) else {
+            return;
+        };
+        if depth > 8 {
+            return;
+        }
+        let key = record.name.to_ascii_lowercase();
+        for field in &record.fields {
+            self.mark(&key, &field.name.to_ascii_lowercase(), access, None);
+            self.whole(&field.r#type, access, depth + 1);
+        }
+    }
+
+    fn mark(&mut self, record: &str, field: &str, access: Access, at: Option<(&str, usize)>) {
+        let Some(usage) = self
+            .fields
+            .get_mut(record)
+            .and_then(|fields| fields.get_mut(field))
+        else {
+            return;
+        };
+        if access != Access::Write {
+            usage.read = true;
+            if usage.read_at.is_none() {
+                usage.read_at = at.map(|(file, line)| (file.to_string(), line));
+            }
+        }
+        if access != Access::Read {
+            usage.written = true;
+        }
+    }
+
+    /// Follows every object name in `text` through its selectors.
+    fn text(&mut self, text: &str, access: Access, file: &str, line: usize) {
+        for (root, steps) in selected_names(text) {
+            let Some(types) = self.objects.get(&root).cloned() else {
+                continue;
+            };
+            for ty in types {
+                self.path(&ty, &steps, access, file, line);
+            }
+        }
+    }
+
+    fn path(&mut self, ty: &str, steps: &[Step], access: Access, file: &str, line: usize) {
+        let mut current = base_type_name(ty);
+        for step in steps {
+            match step {
+                Step::Index => match self.aliases.get(&current) {
+                    Some(element) => current = element.clone(),
+                    None => return,
+                },
+                Step::Field(name) => {
+                    let Some(record) = self.record(&current) else {
+                        return;
+                    };
+                    let Some(field) = record
+                        .fields
+                        .iter()
+                        .find(|field| field.name.eq_ignore_ascii_case(name))
+                    else {
+                        return;
+                    };
+                    let key = record.name.to_ascii_lowercase();
+                    self.mark(&key, name, access, Some((file, line)));
+                    current = base_type_name(&field.r#type);
+                }
+            }
+        }
+        self.whole(&current, access, 0);
+    }
+}
+
+enum Step {
+    Index,
+    /// Lowercase field name.
+    Field(String),
+}
+
+/// Names in an expression with the index and selector steps after them:
+/// `lanes(i).data(3)` is `lanes` with index, field `data`, index. Names
+/// after a `.`, before `=>` (aggregate choices, formals) or starting a bit
+/// string literal are skipped; index expressions are scanned too.
+fn selected_names(text: &str) -> Vec<(String, Vec<Step>)> {
+    let chars: Vec<char> = text.chars().collect();
+    let ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let skip_spaces = |mut idx: usize| {
+        while idx < chars.len() && chars[idx].is_whitespace() {
+            idx += 1;
+        }
+        idx
+    };
+    let mut out = Vec::new();
+    let mut idx = 0;
+    while idx < chars.len() {
+        let c = chars[idx];
+        if c == '"' {
+            idx += 1;
+            while idx < chars.len() && chars[idx] != '"' {
+                idx += 1;
+            }
+            idx += 1;
+            continue;
+        }
+        if c == '\'' && idx + 2 < chars.len() && chars[idx + 2] == '\'' {
+            idx += 3;
+            continue;
+        }
+        if !c.is_ascii_alphabetic() || (idx > 0 && ident(chars[idx - 1])) {
+            idx += 1;
+            continue;
+        }
+        let start = idx;
+        while idx < chars.len() && ident(chars[idx]) {
+            idx += 1;
+        }
+        let name: String = chars[start..idx]
+            .iter()
+            .collect::<String>()
+            .to_ascii_lowercase();
+        let before = chars[..start].iter().rev().find(|c| !c.is_whitespace());
+        let after = skip_spaces(idx);
+        let choice = chars.get(after) == Some(&'=') && chars.get(after + 1) == Some(&'>');
+        if before == Some(&'.') || before == Some(&'\'') || choice || chars.get(idx) == Some(&'"') {
+            continue;
+        }
+        let mut steps = Vec::new();
+        let mut pos = idx;
+        loop {
+            let next = skip_spaces(pos);
+            match chars.get(next) {
+                Some('(') => {
+                    let mut depth = 0;
+                    let mut end = next;
+                    while end < chars.len() {
+                        match chars[end] {
+                            '(' => depth += 1,
+                            ')' => {
+                                depth -= 1;
+                                if depth == 0 {
+                                    break;
+                                }
+                            }
+                            _ => {}
+                        }
+                        end += 1;
+                    }
+                    steps.push(Step::Index);
+                    pos = end + 1;
+                }
+                Some('.') => {
+                    let field_start = skip_spaces(next + 1);
+                    let mut field_end = field_start;
+                    while field_end < chars.len() && ident(chars[field_end]) {
+                        field_end += 1;
+                    }
+                    if field_end == field_start {
+                        break;
+                    }
+                    steps.push(Step::Field(
+                        chars[field_start..field_end]
+                            .iter()
+                            .collect::<String>()
+                            .to_ascii_lowercase(),
+                    ));
+                    pos = field_end;
+                }
+                _ => break,
+            }
+        }
+        out.push((name, steps));
+    }
+    out
+}
+
+/// `unused_record_field`: fields of records the design holds that nothing
+/// reads. `undriven_record_field`: fields read by name that nothing writes.
+/// Records held by subprogram parameters are skipped, and so are records
+/// held by variables for the undriven check, since those uses aren't in the
+/// facts.
+fn record_field_usage(input: &Input) -> Vec<Violation> {
+    let usage = RecordUsage::analyze(input);
+    let held: HashSet<String> = usage
+        .objects
+        .values()
+        .flatten()
+        .flat_map(|ty| usage.contained_records(ty))
+        .collect();
+    let mut out = Vec::new();
+    for (key, fields) in &usage.fields {
+        if !held.contains(key) || usage.opaque.contains(key) {
+            continue;
+        }
+        let decl = usage.records[key];
+        let name_of = |field: &str| {
+            decl.fields
+                .iter()
+                .find(|f| f.name.eq_ignore_ascii_case(field))
+                .map_or(field.to_string(), |f| f.name.clone())
+        };
+        let unread: Vec<String> = fields
+            .iter()
+            .filter(|(_, use_)| !use_.read)
+            .map(|(field, _)| format!("'{}'", name_of(field)))
+            .collect();
+        if !unread.is_empty() {
+            out.push(Violation {
+                rule: "unused_record_field".to_string(),
+                severity: "info".to_string(),
+                file: decl.file.clone(),
+                line: decl.line,
+                message: format!(
+                    "Record '{}' has field(s) nothing reads: {}",
+                    decl.name,
+                    unread.join(", ")
+                ),
+                confidence: Confidence::Medium,
+                ..Default::default()
+            });
+        }
+        if usage.variable_held.contains(key) {
+            continue;
+        }
+        let undriven: Vec<String> = fields
+            .iter()
+            .filter(|(_, use_)| use_.read && !use_.written)
+            .map(|(field, use_)| match &use_.read_at {
+                Some((file, line)) => format!("'{}' (read at {}:{})", name_of(field), file, line),
+                None => format!("'{}'", name_of(field)),
+            })
+            .collect();
+        if !undriven.is_empty() {
+            out.push(Violation {
+                rule: "undriven_record_field".to_string(),
+                severity: "info".to_string(),
+                file: decl.file.clone(),
+                line: decl.line,
+                message: format!(
+                    "Record '{}' has field(s) read but never written: {}",
+                    decl.name,
+                    undriven.join(", ")
+                ),
+                confidence: Confidence::Medium,
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::policy::input::{Input, Signal};
+    use crate::policy::input::{
+        Architecture, Entity, IfArm, IfStatement, Input, Port, Process, RecordField,
+        SequentialAssignment, Signal, TypeDeclaration,
+    };
 
     #[test]
     fn mixed_signedness_flags_pair() {
@@ -88,4 +812,218 @@ mod tests {
         let violations = optional_violations(&input);
         assert!(violations.is_empty());
     }
+
+    fn input_with_bit_orders(port_type: &str, signal_types: &[&str]) -> Input {
+        let mut input = Input::default();
+        input.entities.push(Entity {
+            name: "core".to_string(),
+            file: "core.vhd".to_string(),
+            line: 1,
+            ports: vec![Port {
+                name: "data_i".to_string(),
+                direction: "in".to_string(),
+                r#type: port_type.to_string(),
+                line: 3,
+                in_entity: "core".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "core".to_string(),
+            file: "core.vhd".to_string(),
+            line: 10,
+            ..Default::default()
+        });
+        for (idx, ty) in signal_types.iter().enumerate() {
+            input.signals.push(Signal {
+                name: format!("s{}", idx),
+                r#type: ty.to_string(),
+                file: "core.vhd".to_string(),
+                line: 11 + idx,
+                in_entity: "rtl".to_string(),
+                ..Default::default()
+            });
+        }
+        input
+    }
+
+    #[test]
+    fn mixed_bit_order_flags_minority_direction() {
+        let input = input_with_bit_orders(
+            "std_logic_vector(7 downto 0)",
+            &[
+                "std_logic_vector(0 to 7)",
+                "unsigned(3 downto 0)",
+                "std_logic",
+            ],
+        );
+        let violations = mixed_bit_order(&input);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 11);
+        assert!(violations[0].message.contains("'s0'"));
+    }
+
+    #[test]
+    fn mixed_bit_order_honors_project_preference() {
+        let mut input =
+            input_with_bit_orders("std_logic_vector(7 downto 0)", &["unsigned(3 downto 0)"]);
+        assert!(mixed_bit_order(&input).is_empty());
+
+        input.lint_config.bit_order = "to".to_string();
+        let violations = mixed_bit_order(&input);
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].message.contains("project prefers 'to'"));
+    }
+
+    #[test]
+    fn port_type_not_visible_flags_local_and_unused_package_types() {
+        use crate::policy::input::{Dependency, TypeDeclaration};
+        let mut input = Input::default();
+        let port = |name: &str, ty: &str, line| Port {
+            name: name.to_string(),
+            direction: "in".to_string(),
+            r#type: ty.to_string(),
+            line,
+            in_entity: "core".to_string(),
+            ..Default::default()
+        };
+        input.entities.push(Entity {
+            name: "core".to_string(),
+            file: "core.vhd".to_string(),
+            line: 1,
+            ports: vec![
+                port("a", "state_t", 3),
+                port("b", "word_t", 4),
+                port("c", "byte_t", 5),
+                port("d", "std_logic", 6),
+            ],
+            ..Default::default()
+        });
+        for (name, arch, package) in [
+            ("state_t", "rtl", ""),
+            ("word_t", "", "bus_pkg"),
+            ("byte_t", "", "core_pkg"),
+        ] {
+            input.types.push(TypeDeclaration {
+                name: name.to_string(),
+                kind: "enum".to_string(),
+                file: "core.vhd".to_string(),
+                in_arch: arch.to_string(),
+                in_package: package.to_string(),
+                ..Default::default()
+            });
+        }
+        input.dependencies.push(Dependency {
+            source: "core.vhd".to_string(),
+            target: "work.core_pkg".to_string(),
+            kind: "use".to_string(),
+            ..Default::default()
+        });
+
+        let violations = port_type_not_visible(&input);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].severity, "error");
+        assert!(violations[0].message.contains("architecture 'rtl'"));
+        assert_eq!(violations[1].severity, "warning");
+        assert!(violations[1].message.contains("package 'bus_pkg'"));
+    }
+
+    #[test]
+    fn record_fields_unread_and_undriven() {
+        let field = |name: &str, ty: &str| RecordField {
+            name: name.to_string(),
+            r#type: ty.to_string(),
+        };
+        let mut input = Input::default();
+        input.types.push(TypeDeclaration {
+            name: "t_bus".to_string(),
+            kind: "record".to_string(),
+            file: "pkg.vhd".to_string(),
+            line: 4,
+            fields: vec![
+                field("valid", "std_logic"),
+                field("data", "std_logic_vector(7 downto 0)"),
+                field("err", "std_logic"),
+                field("spare", "std_logic"),
+            ],
+            ..Default::default()
+        });
+        input.types.push(TypeDeclaration {
+            name: "t_lanes".to_string(),
+            kind: "array".to_string(),
+            element_type: "t_bus".to_string(),
+            ..Default::default()
+        });
+        for (name, ty) in [("lanes", "t_lanes"), ("q", "std_logic_vector(7 downto 0)")] {
+            input.signals.push(Signal {
+                name: name.to_string(),
+                r#type: ty.to_string(),
+                ..Default::default()
+            });
+        }
+        let assign = |target: &str, value: &str| SequentialAssignment {
+            target: target.to_string(),
+            value: value.to_string(),
+            line: 20,
+            ..Default::default()
+        };
+        input.processes.push(Process {
+            file: "core.vhd".to_string(),
+            assignments: vec![
+                assign("lanes(0).valid", "'1'"),
+                assign("lanes(0).data", "x\"00\""),
+                assign("q", "lanes(1).data"),
+            ],
+            if_statements: vec![IfStatement {
+                arms: vec![IfArm {
+                    line: 18,
+                    condition: "lanes(i).valid = '1' and lanes(i).ERR = '0'".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        let usage = RecordUsage::analyze(&input);
+        let bus = &usage.fields["t_bus"];
+        assert!(bus["valid"].read && bus["valid"].written);
+        assert!(bus["err"].read && !bus["err"].written);
+        assert!(!bus["spare"].read && !bus["spare"].written);
+        let out: Vec<_> = optional_violations(&input)
+            .into_iter()
+            .map(|v| (v.rule, v.line, v.message))
+            .collect();
+        assert_eq!(
+            out,
+            [
+                (
+                    "unused_record_field".to_string(),
+                    4,
+                    "Record 't_bus' has field(s) nothing reads: 'spare'".to_string()
+                ),
+                (
+                    "undriven_record_field".to_string(),
+                    4,
+                    "Record 't_bus' has field(s) read but never written: 'err' (read at core.vhd:18)"
+                        .to_string()
+                ),
+            ]
+        );
+
+        // A record passed to a subprogram may be used in its body.
+        input
+            .functions
+            .push(crate::policy::input::FunctionDeclaration {
+                parameters: vec![crate::policy::input::SubprogramParameter {
+                    name: "b".to_string(),
+                    r#type: "t_bus".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            });
+        assert!(optional_violations(&input).is_empty());
+    }
 }
@@ -0,0 +1,724 @@
+//! Use-clause hygiene: imports nothing in the file refers to, imports made
+//! twice in one design unit's context, and package-qualified names the file
+//! never made visible.
+//!
+//! Package contents come from `symbol_defs` (everything declared in a
+//! package's scope) plus the enum literals of its types; packages with no
+//! known contents, such as `ieee` ones, are never reported unused. A file's
+//! references are the names in `name_uses` and the identifiers in its
+//! declarations, expressions and maps, the same text dead-code analysis reads.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::policy::helpers;
+use crate::policy::input::{Input, Package, UseClause};
+use crate::policy::libraries::LibraryMap;
+use crate::policy::result::{Confidence, Violation};
+
+pub fn violations(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    out.extend(duplicate_use_clause(input));
+    out.extend(missing_use_clause(input));
+    out
+}
+
+pub fn optional_violations(input: &Input) -> Vec<Violation> {
+    unused_use_clause(input)
+}
+
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "architectures",
+        "case_statements",
+        "components",
+        "concurrent_assignments",
+        "configurations",
+        "constant_decls",
+        "functions",
+        "instances",
+        "library_clauses",
+        "name_uses",
+        "procedures",
+        "processes",
+        "subtypes",
+        "symbol_defs",
+        "use_clauses",
+    ]
+}
+
+/// `use lib.pkg.all` / `use lib.pkg.item` where nothing the package declares
+/// (or `item`) is referenced in the file.
+fn unused_use_clause(input: &Input) -> Vec<Violation> {
+    let libraries = LibraryMap::new(input);
+    let exports = package_exports(input);
+    let mut refs_by_file: HashMap<&str, HashSet<String>> = HashMap::new();
+    let mut out = Vec::new();
+    for clause in &input.use_clauses {
+        if helpers::is_third_party_file(input, &clause.file) {
+            continue;
+        }
+        for item in &clause.items {
+            let parts = item_parts(item);
+            if parts.len() != 3 {
+                continue;
+            }
+            let library = libraries.resolve(&clause.file, &parts[0]);
+            let Some(pkg) = find_package(input, &libraries, &library, &parts[1]) else {
+                continue;
+            };
+            let Some(names) = exports.get(&(pkg.file.as_str(), pkg.name.to_ascii_lowercase()))
+            else {
+                continue;
+            };
+            let refs = refs_by_file.entry(clause.file.as_str()).or_insert_with(|| {
+                references(input, &clause.file)
+                    .into_iter()
+                    .flat_map(|(word, _)| {
+                        word.split('.')
+                            .map(str::to_ascii_lowercase)
+                            .collect::<Vec<_>>()
+                    })
+                    .collect()
+            });
+            let used = if parts[2] == "all" {
+                names.iter().any(|name| refs.contains(name))
+            } else {
+                refs.contains(&parts[2])
+            };
+            if used {
+                continue;
+            }
+            out.push(Violation {
+                rule: "unused_use_clause".to_string(),
+                severity: "info".to_string(),
+                file: clause.file.clone(),
+                line: clause.line,
+                message: format!(
+                    "Use clause '{}' is unused - nothing it makes visible is referenced in this file",
+                    item.trim()
+                ),
+                confidence: Confidence::Medium,
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+/// The same item imported twice into one design unit's context, or an item
+/// a `.all` of the same package already covers. A secondary unit sees the
+/// context of its primary unit when both are in the file.
+fn duplicate_use_clause(input: &Input) -> Vec<Violation> {
+    let mut out = Vec::new();
+    let mut files: Vec<&str> = input
+        .use_clauses
+        .iter()
+        .map(|clause| clause.file.as_str())
+        .collect();
+    files.sort_unstable();
+    files.dedup();
+    for file in files {
+        if helpers::is_third_party_file(input, file) {
+            continue;
+        }
+        let units = design_units(input, file);
+        let mut clauses: Vec<&UseClause> = input
+            .use_clauses
+            .iter()
+            .filter(|clause| clause.file == file)
+            .collect();
+        clauses.sort_by_key(|clause| clause.line);
+        // Items seen per unit index, with the line that imported them.
+        let mut seen: HashMap<usize, HashMap<String, usize>> = HashMap::new();
+        for clause in clauses {
+            let unit = units.context_of(clause.line);
+            let primary = units.primary_of(unit);
+            for item in &clause.items {
+                let parts = item_parts(item);
+                if parts.len() < 2 {
+                    continue;
+                }
+                let key = parts.join(".");
+                let all = format!("{}.{}.all", parts[0], parts[1]);
+                let earlier = [Some(unit), primary]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|idx| seen.get(&idx))
+                    .find_map(|items| {
+                        items
+                            .get(&key)
+                            .map(|line| (key.as_str(), *line))
+                            .or_else(|| {
+                                (parts.len() == 3 && parts[2] != "all")
+                                    .then(|| items.get(&all).map(|line| (all.as_str(), *line)))
+                                    .flatten()
+                            })
+                    });
+                if let Some((covering, line)) = earlier {
+                    let message = if covering == key {
+                        format!(
+                            "Use clause '{}' repeats the one at line {}",
+                            item.trim(),
+                            line
+                        )
+                    } else {
+                        format!(
+                            "Use clause '{}' is already covered by '{}' at line {}",
+                            item.trim(),
+                            covering,
+                            line
+                        )
+                    };
+                    out.push(Violation {
+                        rule: "duplicate_use_clause".to_string(),
+                        severity: "warning".to_string(),
+                        file: clause.file.clone(),
+                        line: clause.line,
+                        message,
+                        ..Default::default()
+                    });
+                    continue;
+                }
+                seen.entry(unit).or_default().insert(key, clause.line);
+            }
+        }
+    }
+    out
+}
+
+/// Package-qualified names without the clause that makes them legal:
+/// `pkg.name` needs a use clause naming `pkg` (or `lib.all`),
+/// `lib.pkg.name` a library clause for `lib` (`work` and `std` are implicit).
+fn missing_use_clause(input: &Input) -> Vec<Violation> {
+    let libraries = LibraryMap::new(input);
+    let mut files: Vec<&str> = input.files.iter().map(|f| f.path.as_str()).collect();
+    files.extend(input.use_clauses.iter().map(|clause| clause.file.as_str()));
+    files.extend(input.name_uses.iter().map(|use_| use_.file.as_str()));
+    files.sort_unstable();
+    files.dedup();
+    let mut out = Vec::new();
+    for file in files {
+        if file.is_empty() || helpers::is_third_party_file(input, file) {
+            continue;
+        }
+        let units = design_units(input, file);
+        let used: Vec<(usize, Vec<String>)> = input
+            .use_clauses
+            .iter()
+            .filter(|clause| clause.file == file)
+            .flat_map(|clause| {
+                clause
+                    .items
+                    .iter()
+                    .map(|item| (clause.line, item_parts(item)))
+            })
+            .collect();
+        let declared_libraries: Vec<(usize, String)> = input
+            .library_clauses
+            .iter()
+            .filter(|clause| clause.file == file)
+            .flat_map(|clause| {
+                clause
+                    .libraries
+                    .iter()
+                    .map(|lib| (clause.line, lib.to_ascii_lowercase()))
+            })
+            .collect();
+        let local = local_names(input, file);
+        let mut reported: HashSet<String> = HashSet::new();
+        for (word, line) in references(input, file) {
+            let parts: Vec<String> = word
+                .split('.')
+                .map(|part| part.to_ascii_lowercase())
+                .collect();
+            let message = match parts.len() {
+                0 | 1 => continue,
+                2 => {
+                    let pkg = &parts[0];
+                    if local.contains(pkg)
+                        || input
+                            .packages
+                            .iter()
+                            .any(|p| p.file == file && p.name.eq_ignore_ascii_case(pkg))
+                    {
+                        continue;
+                    }
+                    let Some(decl) = input
+                        .packages
+                        .iter()
+                        .find(|p| p.name.eq_ignore_ascii_case(pkg))
+                    else {
+                        continue;
+                    };
+                    let library = libraries.library_of(&decl.file);
+                    let visible = used.iter().any(|(clause_line, item)| {
+                        units.sees(*clause_line, line)
+                            && (item.get(1) == Some(pkg)
+                                || (item.len() == 2
+                                    && item[1] == "all"
+                                    && libraries.resolve(file, &item[0]) == library))
+                    });
+                    if visible {
+                        continue;
+                    }
+                    format!(
+                        "'{}' refers to package '{}' without a use clause for it - add 'use {}.{};'",
+                        word, decl.name, library, decl.name
+                    )
+                }
+                n => {
+                    let lib = &parts[n - 3];
+                    if n > 3 || matches!(lib.as_str(), "work" | "std") || local.contains(lib) {
+                        continue;
+                    }
+                    let declared = declared_libraries
+                        .iter()
+                        .any(|(clause_line, name)| name == lib && units.sees(*clause_line, line));
+                    if !libraries.is_known(lib) || declared {
+                        continue;
+                    }
+                    let is_package = lib == "ieee"
+                        || find_package(input, &libraries, lib, &parts[n - 2]).is_some();
+                    if !is_package {
+                        continue;
+                    }
+                    format!(
+                        "'{}' names library '{}' without a library clause - add 'library {};'",
+                        word, lib, lib
+                    )
+                }
+            };
+            if !reported.insert(word.to_ascii_lowercase()) {
+                continue;
+            }
+            out.push(Violation {
+                rule: "missing_use_clause".to_string(),
+                severity: "warning".to_string(),
+                file: file.to_string(),
+                line,
+                message,
+                ..Default::default()
+            });
+        }
+    }
+    out.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+    out
+}
+
+/// A design unit of one file: entities, packages and configurations are
+/// primary; an architecture whose entity is in the same file names it.
+struct Unit {
+    line: usize,
+    name: String,
+    primary: Option<String>,
+}
+
+/// The design units of one file, by line.
+struct Units(Vec<Unit>);
+
+impl Units {
+    /// The unit whose context clause a clause on `line` belongs to: the
+    /// first unit after it (one past the end for trailing clauses).
+    fn context_of(&self, line: usize) -> usize {
+        self.0.partition_point(|u| u.line <= line)
+    }
+
+    /// The primary unit whose context the unit at `idx` also sees.
+    fn primary_of(&self, idx: usize) -> Option<usize> {
+        let name = self.0.get(idx)?.primary.as_ref()?;
+        self.0
+            .iter()
+            .position(|u| u.primary.is_none() && u.name == *name)
+    }
+
+    /// Whether a context clause on `clause_line` is visible on `line`.
+    /// Lines before the first unit see every clause.
+    fn sees(&self, clause_line: usize, line: usize) -> bool {
+        let Some(unit) = self.context_of(line).checked_sub(1) else {
+            return true;
+        };
+        let context = self.context_of(clause_line);
+        context == unit || self.primary_of(unit) == Some(context)
+    }
+}
+
+fn design_units(input: &Input, file: &str) -> Units {
+    let mut units: Vec<Unit> = Vec::new();
+    let primary = |name: &str, line: usize| Unit {
+        line,
+        name: name.to_ascii_lowercase(),
+        primary: None,
+    };
+    units.extend(
+        input
+            .entities
+            .iter()
+            .filter(|e| e.file == file)
+            .map(|e| primary(&e.name, e.line)),
+    );
+    units.extend(
+        input
+            .packages
+            .iter()
+            .filter(|p| p.file == file)
+            .map(|p| primary(&p.name, p.line)),
+    );
+    units.extend(
+        input
+            .configurations
+            .iter()
+            .filter(|c| c.file == file)
+            .map(|c| primary(&c.name, c.line)),
+    );
+    let local_entities: HashSet<String> = units.iter().map(|u| u.name.clone()).collect();
+    units.extend(
+        input
+            .architectures
+            .iter()
+            .filter(|a| a.file == file)
+            .map(|a| {
+                let entity = a.entity_name.to_ascii_lowercase();
+                Unit {
+                    line: a.line,
+                    name: a.name.to_ascii_lowercase(),
+                    primary: local_entities.contains(&entity).then_some(entity),
+                }
+            }),
+    );
+    units.sort_by_key(|u| u.line);
+    Units(units)
+}
+
+/// Names declared in the package, by (file, lowercase package name).
+fn package_exports(input: &Input) -> HashMap<(&str, String), HashSet<String>> {
+    let mut exports: HashMap<(&str, String), HashSet<String>> = HashMap::new();
+    for def in &input.symbol_defs {
+        if def.kind == "package" {
+            continue;
+        }
+        let Some((_, pkg)) = def.scope.rsplit_once("::package:") else {
+            continue;
+        };
+        exports
+            .entry((def.file.as_str(), pkg.to_ascii_lowercase()))
+            .or_default()
+            .insert(def.name.to_ascii_lowercase());
+    }
+    for decl in input.types.iter().filter(|t| !t.in_package.is_empty()) {
+        if let Some(names) =
+            exports.get_mut(&(decl.file.as_str(), decl.in_package.to_ascii_lowercase()))
+        {
+            names.extend(
+                decl.enum_literals
+                    .iter()
+                    .map(|lit| lit.to_ascii_lowercase()),
+            );
+        }
+    }
+    exports
+}
+
+fn find_package<'a>(
+    input: &'a Input,
+    libraries: &LibraryMap,
+    library: &str,
+    name: &str,
+) -> Option<&'a Package> {
+    input.packages.iter().find(|pkg| {
+        pkg.name.eq_ignore_ascii_case(name) && libraries.library_of(&pkg.file) == library
+    })
+}
+
+/// `ieee . numeric_std . all` -> ["ieee", "numeric_std", "all"], lowercase.
+fn item_parts(item: &str) -> Vec<String> {
+    item.split('.')
+        .map(|part| part.trim().to_ascii_lowercase())
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+/// Objects declared in `file` whose names could prefix a selected name
+/// (`rec.field`), so those aren't taken for packages or libraries.
+fn local_names(input: &Input, file: &str) -> HashSet<String> {
+    let mut names: HashSet<String> = HashSet::new();
+    names.extend(
+        input
+            .signals
+            .iter()
+            .filter(|sig| sig.file == file)
+            .map(|sig| sig.name.to_ascii_lowercase()),
+    );
+    for entity in input.entities.iter().filter(|e| e.file == file) {
+        names.extend(entity.ports.iter().map(|p| p.name.to_ascii_lowercase()));
+        names.extend(entity.generics.iter().map(|g| g.name.to_ascii_lowercase()));
+    }
+    names.extend(
+        input
+            .constant_decls
+            .iter()
+            .filter(|decl| decl.file == file)
+            .map(|decl| decl.name.to_ascii_lowercase()),
+    );
+    for proc in input.processes.iter().filter(|proc| proc.file == file) {
+        names.extend(proc.variables.iter().map(|v| v.name.to_ascii_lowercase()));
+    }
+    names
+}
+
+/// Identifier words (dots kept, so `pkg.c_width` stays whole) referenced in
+/// `file`, with the line they appear on.
+fn references(input: &Input, file: &str) -> Vec<(String, usize)> {
+    let mut refs: Vec<(String, usize)> = Vec::new();
+    let mut words = |text: &str, line: usize| {
+        refs.extend(
+            text.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+                .map(|word| word.trim_matches('.'))
+                .filter(|word| word.starts_with(|c: char| c.is_ascii_alphabetic()))
+                .map(|word| (word.to_string(), line)),
+        );
+    };
+    for use_ in input.name_uses.iter().filter(|use_| use_.file == file) {
+        words(&use_.name, use_.line);
+    }
+    for entity in input.entities.iter().filter(|e| e.file == file) {
+        for port in &entity.ports {
+            words(&port.r#type, port.line);
+            words(&port.default, port.line);
+        }
+        for generic in &entity.generics {
+            words(&generic.r#type, generic.line);
+            words(&generic.default, generic.line);
+        }
+    }
+    for comp in input.components.iter().filter(|c| c.file == file) {
+        if comp.is_instance {
+            words(&comp.entity_ref, comp.line);
+            words(&comp.name, comp.line);
+        }
+        for port in &comp.ports {
+            words(&port.r#type, port.line);
+        }
+    }
+    for sig in input.signals.iter().filter(|sig| sig.file == file) {
+        words(&sig.r#type, sig.line);
+    }
+    for decl in input.constant_decls.iter().filter(|d| d.file == file) {
+        words(&decl.r#type, decl.line);
+        words(&decl.value, decl.line);
+    }
+    for decl in input.types.iter().filter(|t| t.file == file) {
+        words(&decl.element_type, decl.line);
+        for field in &decl.fields {
+            words(&field.r#type, decl.line);
+        }
+    }
+    for decl in input.subtypes.iter().filter(|s| s.file == file) {
+        words(&decl.base_type, decl.line);
+        words(&decl.constraint, decl.line);
+    }
+    for func in input.functions.iter().filter(|f| f.file == file) {
+        words(&func.return_type, func.line);
+        for param in &func.parameters {
+            words(&param.r#type, param.line.max(func.line));
+        }
+    }
+    for proc_decl in input.procedures.iter().filter(|p| p.file == file) {
+        for param in &proc_decl.parameters {
+            words(&param.r#type, param.line.max(proc_decl.line));
+        }
+    }
+    for proc in input.processes.iter().filter(|proc| proc.file == file) {
+        for name in &proc.read_signals {
+            words(name, proc.line);
+        }
+        for call in &proc.function_calls {
+            words(&call.name, call.line);
+        }
+        for call in &proc.procedure_calls {
+            let name = if call.full_name.is_empty() {
+                &call.name
+            } else {
+                &call.full_name
+            };
+            words(name, call.line);
+        }
+        for var in &proc.variables {
+            words(&var.r#type, proc.line);
+        }
+        for assign in &proc.assignments {
+            words(&assign.value, assign.line);
+        }
+    }
+    for assign in input
+        .concurrent_assignments
+        .iter()
+        .filter(|a| a.file == file)
+    {
+        for name in &assign.read_signals {
+            words(name, assign.line);
+        }
+        words(&assign.value, assign.line);
+    }
+    for cs in input.case_statements.iter().filter(|cs| cs.file == file) {
+        words(&cs.expression, cs.line);
+        for choice in &cs.choices {
+            words(choice, cs.line);
+        }
+    }
+    for inst in input.instances.iter().filter(|inst| inst.file == file) {
+        words(&inst.target, inst.line);
+        for actual in inst.port_map.values().chain(inst.generic_map.values()) {
+            words(actual, inst.line);
+        }
+    }
+    refs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{
+        Architecture, ConstantDeclaration, Entity, LibraryClause, NameUse, Signal, SymbolDef,
+    };
+
+    fn design() -> Input {
+        let mut input = Input::default();
+        input.packages.push(Package {
+            name: "util_pkg".to_string(),
+            file: "pkg.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        for name in ["word_t", "C_WIDTH", "clog2"] {
+            input.symbol_defs.push(SymbolDef {
+                name: name.to_string(),
+                kind: "constant".to_string(),
+                file: "pkg.vhd".to_string(),
+                line: 2,
+                scope: "file:pkg.vhd::package:util_pkg".to_string(),
+            });
+        }
+        input.entities.push(Entity {
+            name: "top".to_string(),
+            file: "top.vhd".to_string(),
+            line: 5,
+            ..Default::default()
+        });
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "top".to_string(),
+            file: "top.vhd".to_string(),
+            line: 12,
+            ..Default::default()
+        });
+        input
+    }
+
+    fn use_clause(item: &str, line: usize) -> UseClause {
+        UseClause {
+            items: vec![item.to_string()],
+            file: "top.vhd".to_string(),
+            line,
+        }
+    }
+
+    #[test]
+    fn unused_use_clause_checks_package_contents() {
+        let mut input = design();
+        input.use_clauses = vec![
+            use_clause("ieee.std_logic_1164.all", 2),
+            use_clause("work.util_pkg.all", 3),
+            use_clause("work.util_pkg.clog2", 4),
+        ];
+        input.signals.push(Signal {
+            name: "data".to_string(),
+            r#type: "std_logic_vector(C_WIDTH-1 downto 0)".to_string(),
+            file: "top.vhd".to_string(),
+            line: 13,
+            in_entity: "rtl".to_string(),
+            ..Default::default()
+        });
+        let v = unused_use_clause(&input);
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[0].line, 4);
+        assert!(v[0].message.contains("work.util_pkg.clog2"));
+
+        input.signals[0].r#type = "std_logic".to_string();
+        let lines: Vec<usize> = unused_use_clause(&input).iter().map(|v| v.line).collect();
+        assert_eq!(lines, [3, 4]);
+    }
+
+    #[test]
+    fn duplicate_use_clause_is_per_design_unit() {
+        let mut input = design();
+        input.entities.push(Entity {
+            name: "other".to_string(),
+            file: "top.vhd".to_string(),
+            line: 30,
+            ..Default::default()
+        });
+        input.use_clauses = vec![
+            use_clause("ieee.std_logic_1164.all", 2),
+            use_clause("IEEE.std_logic_1164.all", 3),
+            use_clause("work.util_pkg.all", 4),
+            // Architecture of `top` inherits the entity's context.
+            use_clause("work.util_pkg.C_WIDTH", 10),
+            // A new primary unit starts a fresh context.
+            use_clause("ieee.std_logic_1164.all", 28),
+        ];
+        let v = duplicate_use_clause(&input);
+        let lines: Vec<usize> = v.iter().map(|v| v.line).collect();
+        assert_eq!(lines, [3, 10]);
+        assert!(v[0].message.contains("line 2"));
+        assert!(v[1].message.contains("covered by 'work.util_pkg.all'"));
+    }
+
+    #[test]
+    fn missing_use_clause_needs_package_or_library_visibility() {
+        let mut input = design();
+        input.packages.push(Package {
+            name: "dsp_pkg".to_string(),
+            file: "dsp/pkg.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        input.files = ["pkg.vhd", "top.vhd", "dsp/pkg.vhd"]
+            .iter()
+            .map(|path| crate::policy::input::FileInfo {
+                path: path.to_string(),
+                library: if path.starts_with("dsp/") { "dsp" } else { "" }.to_string(),
+                ..Default::default()
+            })
+            .collect();
+        input.name_uses.push(NameUse {
+            name: "util_pkg.clog2".to_string(),
+            kind: "function_call".to_string(),
+            file: "top.vhd".to_string(),
+            line: 14,
+            ..Default::default()
+        });
+        input.constant_decls.push(ConstantDeclaration {
+            name: "C_TAPS".to_string(),
+            value: "dsp.dsp_pkg.C_TAPS + work.util_pkg.C_WIDTH".to_string(),
+            file: "top.vhd".to_string(),
+            line: 13,
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        });
+        let lines: Vec<usize> = missing_use_clause(&input).iter().map(|v| v.line).collect();
+        assert_eq!(lines, [13, 14]);
+
+        input.use_clauses.push(use_clause("work.util_pkg", 3));
+        input.library_clauses.push(LibraryClause {
+            libraries: vec!["DSP".to_string()],
+            file: "top.vhd".to_string(),
+            line: 2,
+        });
+        assert!(missing_use_clause(&input).is_empty());
+
+        // A library clause only reaches the design unit that follows it.
+        input.library_clauses[0].line = 40;
+        let lines: Vec<usize> = missing_use_clause(&input).iter().map(|v| v.line).collect();
+        assert_eq!(lines, [13]);
+    }
+}
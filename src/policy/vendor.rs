@@ -0,0 +1,233 @@
+//! FPGA vendor primitives: a built-in database of the design elements each
+//! vendor's simulation libraries provide (`vendor_primitives/<vendor>.toml`),
+//! so instantiating them doesn't read as a missing entity.
+//!
+//! `lint_config.vendor` names the vendor the design targets (`xilinx`,
+//! `intel`, `lattice`, or an alias such as `altera`). Instantiations of that
+//! vendor's primitives resolve for `unresolved_dependency` and
+//! `component_resolved`, and its libraries (`unisim`, `altera_mf`, ...) are
+//! known to `unknown_library`. `vendor = "none"` declares the design
+//! vendor-neutral: a primitive of any vendor is reported by
+//! `vendor_primitive` instead of as unresolved. When unset, primitives are
+//! unresolved like any other missing entity.
+
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use crate::policy::helpers;
+use crate::policy::input::Input;
+use crate::policy::result::Violation;
+
+/// `lint_config.vendor` value for a design that must stay vendor-neutral.
+pub const NONE: &str = "none";
+
+pub struct Vendor {
+    pub name: String,
+    pub aliases: Vec<String>,
+    /// Library names, lowercase.
+    pub libraries: Vec<String>,
+    /// Primitive names, lowercase.
+    primitives: HashSet<String>,
+}
+
+#[derive(Deserialize)]
+struct VendorFile {
+    vendor: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+    libraries: Vec<String>,
+    primitives: Vec<String>,
+}
+
+const DATA_FILES: &[(&str, &str)] = &[
+    ("xilinx", include_str!("vendor_primitives/xilinx.toml")),
+    ("intel", include_str!("vendor_primitives/intel.toml")),
+    ("lattice", include_str!("vendor_primitives/lattice.toml")),
+];
+
+/// Every vendor in the database.
+pub fn all() -> &'static [Vendor] {
+    static VENDORS: OnceLock<Vec<Vendor>> = OnceLock::new();
+    VENDORS.get_or_init(|| {
+        DATA_FILES
+            .iter()
+            .map(|(name, text)| {
+                let file: VendorFile = toml::from_str(text).unwrap_or_else(|err| {
+                    panic!("failed to parse {} primitive database: {}", name, err)
+                });
+                let lower = |names: Vec<String>| -> Vec<String> {
+                    names.iter().map(|name| name.to_ascii_lowercase()).collect()
+                };
+                Vendor {
+                    name: file.vendor,
+                    aliases: lower(file.aliases),
+                    libraries: lower(file.libraries),
+                    primitives: lower(file.primitives).into_iter().collect(),
+                }
+            })
+            .collect()
+    })
+}
+
+/// The vendor `name` (or one of its aliases) refers to.
+pub fn lookup(name: &str) -> Option<&'static Vendor> {
+    let name = name.trim().to_ascii_lowercase();
+    all()
+        .iter()
+        .find(|vendor| vendor.name == name || vendor.aliases.contains(&name))
+}
+
+/// Whether `name` is a valid `vendor` setting: a known vendor or `none`.
+pub fn is_valid_setting(name: &str) -> bool {
+    name.eq_ignore_ascii_case(NONE) || lookup(name).is_some()
+}
+
+impl Vendor {
+    /// Whether `target` (an instantiated name, possibly library-qualified)
+    /// is one of this vendor's primitives.
+    pub fn has_primitive(&self, target: &str) -> bool {
+        let base = target.rsplit('.').next().unwrap_or(target);
+        self.primitives.contains(&base.trim().to_ascii_lowercase())
+    }
+}
+
+/// Vendors whose databases list `target`, in database order.
+pub fn vendors_of(target: &str) -> Vec<&'static Vendor> {
+    all()
+        .iter()
+        .filter(|vendor| vendor.has_primitive(target))
+        .collect()
+}
+
+/// Whether an instantiation of `target` is resolved by the configured
+/// vendor: a primitive of that vendor, or of any vendor under `none` (where
+/// `vendor_primitive` reports it instead).
+pub fn resolves(input: &Input, target: &str) -> bool {
+    let setting = input.lint_config.vendor.trim();
+    if setting.eq_ignore_ascii_case(NONE) {
+        return !vendors_of(target).is_empty();
+    }
+    lookup(setting).is_some_and(|vendor| vendor.has_primitive(target))
+}
+
+/// Libraries the configured vendor's tools provide, lowercase.
+pub fn libraries(input: &Input) -> &'static [String] {
+    lookup(&input.lint_config.vendor)
+        .map(|vendor| vendor.libraries.as_slice())
+        .unwrap_or(&[])
+}
+
+pub fn violations(input: &Input) -> Vec<Violation> {
+    if !input.lint_config.vendor.trim().eq_ignore_ascii_case(NONE) {
+        return Vec::new();
+    }
+    let mut out = Vec::new();
+    for dep in &input.dependencies {
+        if dep.kind != "instantiation" || defined_in_project(input, &dep.target) {
+            continue;
+        }
+        let vendors = vendors_of(&dep.target);
+        if vendors.is_empty() {
+            continue;
+        }
+        let names: Vec<&str> = vendors.iter().map(|vendor| vendor.name.as_str()).collect();
+        out.push(Violation {
+            rule: "vendor_primitive".to_string(),
+            severity: "warning".to_string(),
+            file: dep.source.clone(),
+            line: dep.line,
+            message: format!(
+                "'{}' is a {} primitive in a design configured vendor-neutral (vendor = \"none\") - infer it from RTL or isolate it in a technology-specific architecture",
+                dep.target,
+                names.join("/")
+            ),
+            ..Default::default()
+        });
+    }
+    out
+}
+
+pub fn reads() -> &'static [&'static str] {
+    &["components", "dependencies", "entities", "lint_config"]
+}
+
+/// Whether the project declares an entity or component named like `target`,
+/// which then shadows the vendor primitive. Component declarations in
+/// third-party code don't count: a vendored `vcomponents` package doesn't
+/// make the primitive portable.
+fn defined_in_project(input: &Input, target: &str) -> bool {
+    let base = target.rsplit('.').next().unwrap_or(target);
+    input
+        .entities
+        .iter()
+        .any(|entity| entity.name.eq_ignore_ascii_case(base))
+        || input
+            .components
+            .iter()
+            .filter(|comp| !comp.is_instance && !helpers::is_third_party_file(input, &comp.file))
+            .any(|comp| comp.name.eq_ignore_ascii_case(base))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{Dependency, Entity};
+
+    fn instantiation(target: &str, line: usize) -> Dependency {
+        Dependency {
+            source: "top.vhd".to_string(),
+            target: target.to_string(),
+            kind: "instantiation".to_string(),
+            line,
+            resolved: false,
+        }
+    }
+
+    #[test]
+    fn database_resolves_primitives_of_the_configured_vendor() {
+        assert_eq!(lookup("Altera").unwrap().name, "intel");
+        assert!(is_valid_setting("XILINX") && is_valid_setting("none"));
+        assert!(!is_valid_setting("microsemi"));
+        for vendor in all() {
+            assert!(!vendor.primitives.is_empty() && !vendor.libraries.is_empty());
+        }
+
+        let mut input = Input::default();
+        assert!(!resolves(&input, "BUFG"));
+        input.lint_config.vendor = "xilinx".to_string();
+        assert!(resolves(&input, "bufg"));
+        assert!(resolves(&input, "unisim.vcomponents.IBUFDS"));
+        assert!(!resolves(&input, "altsyncram"));
+        assert!(libraries(&input).contains(&"unisim".to_string()));
+        input.lint_config.vendor = "intel".to_string();
+        assert!(resolves(&input, "altsyncram"));
+    }
+
+    #[test]
+    fn vendor_neutral_designs_report_primitives() {
+        let mut input = Input::default();
+        input.dependencies = vec![
+            instantiation("BUFG", 10),
+            instantiation("work.fifo", 12),
+            instantiation("LUT4", 14),
+            instantiation("dff", 16),
+        ];
+        input.entities.push(Entity {
+            name: "dff".to_string(),
+            file: "dff.vhd".to_string(),
+            ..Default::default()
+        });
+        assert!(violations(&input).is_empty());
+
+        input.lint_config.vendor = "None".to_string();
+        let violations = violations(&input);
+        let lines: Vec<usize> = violations.iter().map(|v| v.line).collect();
+        assert_eq!(lines, [10, 14]);
+        assert!(violations[0]
+            .message
+            .contains("'BUFG' is a xilinx primitive"));
+        assert!(violations[1].message.contains("xilinx/lattice primitive"));
+        assert!(resolves(&input, "BUFG"));
+    }
+}
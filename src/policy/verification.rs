@@ -1,10 +1,21 @@
+use crate::policy::bus_protocols;
+use crate::policy::elaboration;
+use crate::policy::fixes::{FileFix, TextEdit};
 use crate::policy::helpers;
-use crate::policy::input::{Input, Process, VerificationTag, VerificationTagError};
-use crate::policy::result::{AmbiguousConstruct, MissingCheckTask, VerificationAnchor, Violation};
+use crate::policy::input::{
+    Architecture, Comparison, Input, Process, PslDirective, VerificationTag, VerificationTagError,
+};
+use crate::policy::result::{
+    AmbiguousConstruct, AssertionDensity, ConstructCoverage, ConstructInventory, DetectedConstruct,
+    FixTask, FixTaskGroup, KindCoverage, MissingCheckTask, VerificationAnchor,
+    VerificationCoverage, Violation,
+};
+use regex::Regex;
 use serde::Deserialize;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::env;
 use std::fs;
+use std::path::Path;
 
 #[derive(Debug, Clone, Deserialize)]
 struct CheckEntry {
@@ -19,6 +30,13 @@ struct CheckEntry {
     severity: String,
     #[serde(default)]
     requires_bound: bool,
+    /// Tag text with `{scope}` and `{<binding>}` placeholders.
+    #[serde(default)]
+    template: String,
+    /// PSL property for `generate_checks`, with the tag's placeholders plus
+    /// the ones `derived_values` reads off the design.
+    #[serde(default)]
+    psl: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -27,15 +45,24 @@ enum ConstructKind {
     Counter,
     ReadyValid,
     Fifo,
+    /// A `bus_protocols` interface, named by its protocol.
+    Bus(&'static str),
+    /// Found by a declarative detector, requiring the detector's checks.
+    Custom {
+        name: String,
+        checks: Vec<String>,
+    },
 }
 
 impl ConstructKind {
-    fn label(&self) -> &'static str {
+    fn label(&self) -> &str {
         match self {
             ConstructKind::Fsm => "fsm",
             ConstructKind::Counter => "counter",
             ConstructKind::ReadyValid => "ready_valid",
             ConstructKind::Fifo => "fifo",
+            ConstructKind::Bus(protocol) => protocol,
+            ConstructKind::Custom { name, .. } => name,
         }
     }
 }
@@ -47,6 +74,9 @@ struct Construct {
     file: String,
     line: usize,
     bindings: HashMap<String, String>,
+    /// Confidence of bindings inferred from port names; `None` when the
+    /// structure alone fixed them.
+    confidence: Option<f64>,
 }
 
 struct DetectionReport {
@@ -58,29 +88,52 @@ pub struct VerificationAnalysis {
     pub violations: Vec<Violation>,
     pub missing_checks: Vec<MissingCheckTask>,
     pub ambiguous_constructs: Vec<AmbiguousConstruct>,
+    pub coverage: VerificationCoverage,
 }
 
 pub fn analyze(input: &Input) -> VerificationAnalysis {
     let registry = registry_by_id();
     let tags_by_scope = tags_by_scope(input, &registry);
+    let psl_checks = psl_checks_by_scope(input, &registry);
     let detection = detect_constructs(input);
     let mut violations = Vec::new();
     violations.extend(invalid_tag_violations(input, &registry));
     violations.extend(missing_liveness_bound(input, &registry));
     violations.extend(missing_cover_companion(input, &registry, &tags_by_scope));
+    violations.extend(psl_check_kind_mismatch(input, &registry));
+    violations.extend(psl_assert_without_cover(input, &tags_by_scope));
     violations.extend(missing_verification_block(input, &detection.constructs));
     violations.extend(missing_check_violations(
         input,
         &detection.constructs,
         &tags_by_scope,
+        &psl_checks,
         &registry,
     ));
+    violations.extend(counter_wrap_without_check(input, &detection.constructs));
+    violations.extend(fifo_pointer_width_mismatch(input, &detection.constructs));
+    violations.extend(fifo_flag_incomplete(input, &detection.constructs));
+    violations.extend(fifo_pointer_not_gray(input, &detection.constructs));
     violations.extend(ambiguous_construct_warnings(&detection.ambiguous));
 
+    let coverage = coverage(
+        input,
+        &detection.constructs,
+        &tags_by_scope,
+        &psl_checks,
+        &registry,
+    );
+    violations.extend(low_assertion_density(
+        input,
+        &detection.constructs,
+        &coverage.architectures,
+    ));
+
     let missing_checks = missing_check_tasks(
         input,
         &detection.constructs,
         &tags_by_scope,
+        &psl_checks,
         &registry,
     );
 
@@ -88,6 +141,32 @@ pub fn analyze(input: &Input) -> VerificationAnalysis {
         violations,
         missing_checks,
         ambiguous_constructs: detection.ambiguous,
+        coverage,
+    }
+}
+
+/// Runs construct detection alone: the FSMs, counters, FIFOs and ready/valid
+/// interfaces `analyze` binds checks to, without evaluating any check.
+pub fn constructs(input: &Input) -> ConstructInventory {
+    let detection = detect_constructs(input);
+    let mut constructs: Vec<DetectedConstruct> = detection
+        .constructs
+        .into_iter()
+        .map(|c| DetectedConstruct {
+            kind: c.kind.label().to_string(),
+            scope: format!("arch:{}", c.in_arch.to_ascii_lowercase()),
+            file: c.file,
+            line: c.line,
+            bindings: c.bindings.into_iter().collect(),
+            confidence: c.confidence,
+        })
+        .collect();
+    constructs.sort_by(|a, b| (&a.file, a.line, &a.kind).cmp(&(&b.file, b.line, &b.kind)));
+    let mut ambiguous_constructs = detection.ambiguous;
+    ambiguous_constructs.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+    ConstructInventory {
+        constructs,
+        ambiguous_constructs,
     }
 }
 
@@ -183,6 +262,7 @@ fn missing_liveness_bound(
                     "Verification tag '{}' requires an explicit bound (add bound=)",
                     tag.id
                 ),
+                ..Default::default()
             });
         }
     }
@@ -223,7 +303,16 @@ fn missing_cover_companion(
                 })
             })
             .unwrap_or(false);
-        if !has_cover {
+        let psl_prefix = prefix.replace('.', "_");
+        let has_psl_cover = input.psl_directives.iter().any(|directive| {
+            directive.kind.eq_ignore_ascii_case("cover")
+                && directive
+                    .label
+                    .to_ascii_lowercase()
+                    .starts_with(&psl_prefix)
+                && psl_scope_keys(input, directive).contains(&scope_key)
+        });
+        if !has_cover && !has_psl_cover {
             out.push(Violation {
                 rule: "missing_cover_companion".to_string(),
                 severity: "warning".to_string(),
@@ -233,8 +322,94 @@ fn missing_cover_companion(
                     "Verification tag '{}' requires a cover companion in {}",
                     tag.id, scope_key
                 ),
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+fn psl_check_kind_mismatch(
+    input: &Input,
+    registry: &HashMap<String, CheckEntry>,
+) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for directive in &input.psl_directives {
+        let entry = match psl_check_entry(directive, registry) {
+            Some(entry) => entry,
+            None => continue,
+        };
+        if psl_kind_fits(entry, &directive.kind) {
+            continue;
+        }
+        let expected = if entry.id.starts_with("cover.") {
+            "a cover directive"
+        } else {
+            "an assert or assume directive"
+        };
+        out.push(Violation {
+            rule: "psl_check_kind_mismatch".to_string(),
+            severity: "warning".to_string(),
+            file: directive.file.clone(),
+            line: directive.line,
+            message: format!(
+                "PSL {} '{}' is labelled as check '{}', which needs {}",
+                directive.kind.to_ascii_lowercase(),
+                directive.label,
+                entry.id,
+                expected
+            ),
+            ..Default::default()
+        });
+    }
+    out
+}
+
+/// Architectures asserting PSL properties without any cover, PSL or tag, so
+/// an assertion that passes vacuously goes unnoticed.
+fn psl_assert_without_cover(
+    input: &Input,
+    tags_by_scope: &HashMap<String, Vec<&VerificationTag>>,
+) -> Vec<Violation> {
+    let mut out = Vec::new();
+    for arch in &input.architectures {
+        let in_arch = |directive: &&PslDirective| {
+            directive.file == arch.file && directive.in_arch.eq_ignore_ascii_case(&arch.name)
+        };
+        let asserts = input
+            .psl_directives
+            .iter()
+            .filter(in_arch)
+            .filter(|directive| directive.kind.eq_ignore_ascii_case("assert"))
+            .count();
+        if asserts == 0 {
+            continue;
+        }
+        let has_psl_cover = input
+            .psl_directives
+            .iter()
+            .filter(in_arch)
+            .any(|directive| directive.kind.eq_ignore_ascii_case("cover"));
+        let has_cover_tag = tags_by_scope
+            .get(&format!("arch:{}", arch.name.to_ascii_lowercase()))
+            .is_some_and(|tags| {
+                tags.iter()
+                    .any(|tag| tag.id.to_ascii_lowercase().starts_with("cover."))
             });
+        if has_psl_cover || has_cover_tag {
+            continue;
         }
+        out.push(Violation {
+            rule: "psl_assert_without_cover".to_string(),
+            severity: "info".to_string(),
+            file: arch.file.clone(),
+            line: arch.line,
+            message: format!(
+                "Architecture '{}' has {} PSL assertion(s) but no cover directive",
+                arch.name, asserts
+            ),
+            ..Default::default()
+        });
     }
     out
 }
@@ -267,6 +442,7 @@ fn missing_verification_block(input: &Input, constructs: &[Construct]) -> Vec<Vi
                 "Architecture '{}' has detectable constructs but no verification block",
                 arch.name
             ),
+            ..Default::default()
         });
     }
     out
@@ -276,6 +452,7 @@ fn missing_check_violations(
     input: &Input,
     constructs: &[Construct],
     tags_by_scope: &HashMap<String, Vec<&VerificationTag>>,
+    psl_checks: &HashMap<String, HashSet<String>>,
     registry: &HashMap<String, CheckEntry>,
 ) -> Vec<Violation> {
     let mut out = Vec::new();
@@ -283,15 +460,7 @@ fn missing_check_violations(
 
     for construct in constructs {
         let scope_key = format!("arch:{}", construct.in_arch.to_ascii_lowercase());
-        let tags = tags_by_scope.get(&scope_key);
-        let tag_ids: HashSet<String> = tags
-            .map(|list| {
-                list.iter()
-                    .filter(|tag| tag_is_valid(tag, registry))
-                    .map(|tag| tag.id.to_ascii_lowercase())
-                    .collect()
-            })
-            .unwrap_or_default();
+        let tag_ids = satisfied_checks(&scope_key, tags_by_scope, psl_checks, registry);
         for check_id in required_checks_for_construct(&construct.kind) {
             let check_id_lower = check_id.to_ascii_lowercase();
             if tag_ids.contains(&check_id_lower) {
@@ -310,6 +479,7 @@ fn missing_check_violations(
                 file: construct.file.clone(),
                 line: construct.line,
                 message: msg,
+                ..Default::default()
             });
         }
     }
@@ -320,21 +490,14 @@ fn missing_check_tasks(
     input: &Input,
     constructs: &[Construct],
     tags_by_scope: &HashMap<String, Vec<&VerificationTag>>,
+    psl_checks: &HashMap<String, HashSet<String>>,
     registry: &HashMap<String, CheckEntry>,
 ) -> Vec<MissingCheckTask> {
     let mut tasks = Vec::new();
     let mut seen = HashSet::new();
     for construct in constructs {
         let scope_key = format!("arch:{}", construct.in_arch.to_ascii_lowercase());
-        let tags = tags_by_scope.get(&scope_key);
-        let tag_ids: HashSet<String> = tags
-            .map(|list| {
-                list.iter()
-                    .filter(|tag| tag_is_valid(tag, registry))
-                    .map(|tag| tag.id.to_ascii_lowercase())
-                    .collect()
-            })
-            .unwrap_or_default();
+        let tag_ids = satisfied_checks(&scope_key, tags_by_scope, psl_checks, registry);
         let mut missing_ids = Vec::new();
         for check_id in required_checks_for_construct(&construct.kind) {
             if tag_ids.contains(&check_id.to_ascii_lowercase()) {
@@ -363,11 +526,544 @@ fn missing_check_tasks(
             missing_ids,
             bindings: construct.bindings.clone(),
             notes,
+            confidence: construct.confidence,
         });
     }
     tasks
 }
 
+const DEFAULT_ASSERTION_DENSITY: f64 = 1.0;
+
+/// Assertion density of every first-party architecture, and the present and
+/// missing checks of every construct, rolled up per construct kind.
+fn coverage(
+    input: &Input,
+    constructs: &[Construct],
+    tags_by_scope: &HashMap<String, Vec<&VerificationTag>>,
+    psl_checks: &HashMap<String, HashSet<String>>,
+    registry: &HashMap<String, CheckEntry>,
+) -> VerificationCoverage {
+    let mut architectures: Vec<AssertionDensity> = input
+        .architectures
+        .iter()
+        .filter(|arch| !helpers::is_third_party_file(input, &arch.file))
+        .map(|arch| assertion_density(input, arch, tags_by_scope))
+        .collect();
+    architectures.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+
+    let mut seen = HashSet::new();
+    let mut covered = Vec::new();
+    for construct in constructs
+        .iter()
+        .filter(|c| !helpers::is_third_party_file(input, &c.file))
+    {
+        let scope = format!("arch:{}", construct.in_arch.to_ascii_lowercase());
+        if !seen.insert(format!(
+            "{}:{}:{}",
+            scope,
+            construct.kind.label(),
+            format_bindings(&construct.bindings)
+        )) {
+            continue;
+        }
+        let present = satisfied_checks(&scope, tags_by_scope, psl_checks, registry);
+        let (satisfied, missing): (Vec<String>, Vec<String>) =
+            required_checks_for_construct(&construct.kind)
+                .into_iter()
+                .map(str::to_string)
+                .partition(|id| present.contains(&id.to_ascii_lowercase()));
+        covered.push(ConstructCoverage {
+            kind: construct.kind.label().to_string(),
+            scope,
+            file: construct.file.clone(),
+            line: construct.line,
+            satisfied,
+            missing,
+        });
+    }
+    covered.sort_by(|a, b| (&a.file, a.line, &a.kind).cmp(&(&b.file, b.line, &b.kind)));
+
+    let mut kinds: BTreeMap<&str, KindCoverage> = BTreeMap::new();
+    for construct in &covered {
+        let kind = kinds
+            .entry(&construct.kind)
+            .or_insert_with(|| KindCoverage {
+                kind: construct.kind.clone(),
+                ..Default::default()
+            });
+        kind.constructs += 1;
+        kind.required += construct.satisfied.len() + construct.missing.len();
+        kind.satisfied += construct.satisfied.len();
+    }
+    VerificationCoverage {
+        architectures,
+        kinds: kinds.into_values().collect(),
+        constructs: covered,
+    }
+}
+
+fn assertion_density(
+    input: &Input,
+    arch: &Architecture,
+    tags_by_scope: &HashMap<String, Vec<&VerificationTag>>,
+) -> AssertionDensity {
+    let in_arch =
+        |file: &str, name: &str| file == arch.file && name.eq_ignore_ascii_case(&arch.name);
+    let assertions = input
+        .assertions
+        .iter()
+        .filter(|a| a.kind == "assert" && in_arch(&a.file, &a.in_arch))
+        .count();
+    let psl_directives = input
+        .psl_directives
+        .iter()
+        .filter(|d| d.kind != "restrict" && in_arch(&d.file, &d.in_arch))
+        .count();
+    // Tags may sit in a companion file, so they count by scope alone.
+    let tags = tags_by_scope
+        .get(&format!("arch:{}", arch.name.to_ascii_lowercase()))
+        .map_or(0, Vec::len);
+    let lines = architecture_lines(input, arch);
+    AssertionDensity {
+        entity: arch.entity_name.clone(),
+        architecture: arch.name.clone(),
+        file: arch.file.clone(),
+        line: arch.line,
+        lines,
+        assertions,
+        psl_directives,
+        tags,
+        per_100_lines: (assertions + psl_directives + tags) as f64 * 100.0 / lines as f64,
+    }
+}
+
+/// Lines from `architecture` up to the next entity, architecture or package
+/// in the file, or through the last statement found in the architecture
+/// (plus its `end`) when it is the file's last unit.
+fn architecture_lines(input: &Input, arch: &Architecture) -> usize {
+    let next_unit = input
+        .entities
+        .iter()
+        .map(|e| (&e.file, e.line))
+        .chain(input.architectures.iter().map(|a| (&a.file, a.line)))
+        .chain(input.packages.iter().map(|p| (&p.file, p.line)))
+        .filter(|(file, line)| **file == arch.file && *line > arch.line)
+        .map(|(_, line)| line)
+        .min();
+    let end = match next_unit {
+        Some(line) => line - 1,
+        None => {
+            let in_arch =
+                |file: &str, name: &str| file == arch.file && name.eq_ignore_ascii_case(&arch.name);
+            let last = input
+                .processes
+                .iter()
+                .filter(|p| in_arch(&p.file, &p.in_arch))
+                .map(|p| p.line)
+                .chain(
+                    input
+                        .concurrent_assignments
+                        .iter()
+                        .filter(|ca| in_arch(&ca.file, &ca.in_arch))
+                        .map(|ca| ca.line),
+                )
+                .chain(
+                    input
+                        .signal_deps
+                        .iter()
+                        .filter(|dep| in_arch(&dep.file, &dep.in_arch))
+                        .map(|dep| dep.line),
+                )
+                .chain(
+                    input
+                        .instances
+                        .iter()
+                        .filter(|inst| in_arch(&inst.file, &inst.in_arch))
+                        .map(|inst| inst.line),
+                )
+                .chain(
+                    input
+                        .assertions
+                        .iter()
+                        .filter(|a| in_arch(&a.file, &a.in_arch))
+                        .map(|a| a.line),
+                )
+                .chain(
+                    input
+                        .psl_directives
+                        .iter()
+                        .filter(|d| in_arch(&d.file, &d.in_arch))
+                        .map(|d| d.line),
+                )
+                .max()
+                .unwrap_or(arch.line);
+            last + 1
+        }
+    };
+    (end + 1).saturating_sub(arch.line).max(1)
+}
+
+fn assertion_density_threshold(input: &Input) -> f64 {
+    if input.lint_config.assertion_density > 0.0 {
+        input.lint_config.assertion_density
+    } else {
+        DEFAULT_ASSERTION_DENSITY
+    }
+}
+
+/// Architectures holding a detected construct whose assertions, PSL
+/// directives and tags fall below `lint_config.assertion_density` per 100
+/// lines.
+fn low_assertion_density(
+    input: &Input,
+    constructs: &[Construct],
+    densities: &[AssertionDensity],
+) -> Vec<Violation> {
+    let threshold = assertion_density_threshold(input);
+    densities
+        .iter()
+        .filter(|d| d.per_100_lines < threshold)
+        .filter_map(|d| {
+            let mut kinds: Vec<&str> = constructs
+                .iter()
+                .filter(|c| c.file == d.file && c.in_arch.eq_ignore_ascii_case(&d.architecture))
+                .map(|c| c.kind.label())
+                .collect();
+            if kinds.is_empty() {
+                return None;
+            }
+            kinds.sort_unstable();
+            kinds.dedup();
+            Some(Violation {
+                rule: "low_assertion_density".to_string(),
+                severity: "info".to_string(),
+                file: d.file.clone(),
+                line: d.line,
+                message: format!(
+                    "Architecture '{}' of '{}' has {:.2} assertions per 100 lines ({} in {} lines), below {:.2}; its {} constructs need checks",
+                    d.architecture,
+                    d.entity,
+                    d.per_100_lines,
+                    d.assertions + d.psl_directives + d.tags,
+                    d.lines,
+                    threshold,
+                    kinds.join(", ")
+                ),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+/// Groups missing-check tasks by architecture and renders each missing
+/// check as the tag that would satisfy it, ordered by file, scope and id.
+pub fn fix_tasks(tasks: &[MissingCheckTask]) -> Vec<FixTaskGroup> {
+    let registry = registry_by_id();
+    let mut groups: BTreeMap<(String, String), FixTaskGroup> = BTreeMap::new();
+    for task in tasks {
+        let group = groups
+            .entry((task.file.clone(), task.scope.clone()))
+            .or_insert_with(|| FixTaskGroup {
+                file: task.file.clone(),
+                scope: task.scope.clone(),
+                anchor: task.anchor.clone(),
+                tasks: Vec::new(),
+            });
+        let bindings: BTreeMap<String, String> = task
+            .bindings
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        for check in &task.missing_ids {
+            let entry = registry.get(&check.to_ascii_lowercase());
+            let (tag, unfilled) = render_tag(entry, check, &task.scope, &bindings);
+            let id = std::iter::once(format!("{}/{}", task.scope, check))
+                .chain(bindings.iter().map(|(k, v)| format!("{}={}", k, v)))
+                .collect::<Vec<_>>()
+                .join("/");
+            if group.tasks.iter().any(|t| t.id == id) {
+                continue;
+            }
+            group.tasks.push(FixTask {
+                id,
+                check: check.clone(),
+                tag,
+                bindings: bindings.clone(),
+                unfilled,
+                confidence: task.confidence,
+            });
+        }
+    }
+    groups
+        .into_values()
+        .map(|mut group| {
+            group.tasks.sort_by(|a, b| a.id.cmp(&b.id));
+            group
+        })
+        .collect()
+}
+
+/// Fills the registry template of `check` (or a plain `--@check` line naming
+/// its required bindings when the entry has none), returning the tag and the
+/// placeholders left unfilled.
+fn render_tag(
+    entry: Option<&CheckEntry>,
+    check: &str,
+    scope: &str,
+    bindings: &BTreeMap<String, String>,
+) -> (String, Vec<String>) {
+    let template = match entry {
+        Some(entry) if !entry.template.is_empty() => entry.template.clone(),
+        _ => {
+            let names = entry.map_or(Vec::new(), |e| e.required_bindings.clone());
+            std::iter::once(format!("--@check id={} scope={{scope}}", check))
+                .chain(names.iter().map(|name| format!("{}={{{}}}", name, name)))
+                .collect::<Vec<_>>()
+                .join(" ")
+        }
+    };
+    fill_template(&template, scope, bindings)
+}
+
+/// Replaces `{scope}` and each `{name}` with a value from `values`, returning
+/// the text and the placeholders left in it.
+fn fill_template(
+    template: &str,
+    scope: &str,
+    values: &BTreeMap<String, String>,
+) -> (String, Vec<String>) {
+    let mut unfilled = Vec::new();
+    let text = Regex::new(r"\{([A-Za-z_][A-Za-z0-9_]*)\}")
+        .unwrap()
+        .replace_all(template, |caps: &regex::Captures| {
+            let name = &caps[1];
+            if name == "scope" {
+                return scope.to_string();
+            }
+            match values.get(name) {
+                Some(value) => value.clone(),
+                None => {
+                    if !unfilled.iter().any(|u| u == name) {
+                        unfilled.push(name.to_string());
+                    }
+                    caps[0].to_string()
+                }
+            }
+        })
+        .into_owned();
+    (text, unfilled)
+}
+
+/// Skeleton code for the missing checks: per check its `--@check` tag and a
+/// PSL directive from the registry's `psl` template, labelled after the
+/// check id so it satisfies the check. Architectures with a verification
+/// block get them appended to the block; the others get a PSL vunit bound to
+/// the architecture in a `<file>_verification.vhd` companion. Directives
+/// whose template the design doesn't fill (an unknown clock, a `{bound}`)
+/// are written commented out under a TODO. `source` returns a file's text;
+/// blocks in files it can't read are skipped.
+pub fn generate_checks(
+    input: &Input,
+    tasks: &[MissingCheckTask],
+    source: impl Fn(&str) -> Option<String>,
+) -> Vec<FileFix> {
+    let registry = registry_by_id();
+    let mut files: BTreeMap<String, FileFix> = BTreeMap::new();
+    for group in fix_tasks(tasks) {
+        let arch = group.scope.strip_prefix("arch:").unwrap_or(&group.scope);
+        let mut lines = Vec::new();
+        let mut labels: HashMap<String, usize> = HashMap::new();
+        for task in &group.tasks {
+            lines.push(task.tag.clone());
+            let Some(entry) = registry.get(&task.check.to_ascii_lowercase()) else {
+                continue;
+            };
+            if entry.psl.is_empty() {
+                continue;
+            }
+            let mut values = task.bindings.clone();
+            values.extend(derived_values(input, arch, &task.bindings));
+            let mut label = task.check.replace('.', "_");
+            let seen = labels.entry(label.clone()).or_default();
+            *seen += 1;
+            if *seen > 1 {
+                label = format!("{}_{}", label, seen);
+            }
+            let template = format!("{} : {} @{{clock}};", label, entry.psl);
+            let (directive, unfilled) = fill_template(&template, &group.scope, &values);
+            if unfilled.is_empty() {
+                lines.push(directive);
+            } else {
+                lines.push(format!("-- TODO: fill in {}", unfilled.join(", ")));
+                lines.push(format!("-- {}", directive));
+            }
+        }
+
+        let (path, offset, text) = if group.anchor.exists {
+            let Some(original) = files
+                .get(&group.file)
+                .map(|fix| fix.original.clone())
+                .or_else(|| source(&group.file))
+            else {
+                continue;
+            };
+            let offset = line_offset(&original, group.anchor.line_end);
+            let indent: String = original[offset..]
+                .chars()
+                .take_while(|c| *c == ' ' || *c == '\t')
+                .collect();
+            let text = lines
+                .iter()
+                .map(|line| format!("{}  {}\n", indent, line))
+                .collect::<String>();
+            (group.file.clone(), Some(offset), text)
+        } else {
+            let entity = input
+                .architectures
+                .iter()
+                .find(|a| a.name.eq_ignore_ascii_case(arch) && a.file == group.file)
+                .map_or(String::new(), |a| a.entity_name.clone());
+            let mut text = format!("vunit {0}_verification({1}({0})) {{\n", arch, entity);
+            for line in &lines {
+                text.push_str(&format!("  {}\n", line));
+            }
+            text.push_str("}\n");
+            (companion_path(&group.file), None, text)
+        };
+        let fix = files.entry(path.clone()).or_insert_with(|| FileFix {
+            original: source(&path).unwrap_or_default(),
+            file: path,
+            edits: Vec::new(),
+        });
+        let (start, text) = match offset {
+            Some(offset) => (offset, text),
+            None if fix.original.is_empty() && fix.edits.is_empty() => (0, text),
+            None => (fix.original.len(), format!("\n{}", text)),
+        };
+        fix.edits.push(TextEdit {
+            start,
+            end: start,
+            replacement: text,
+        });
+    }
+    files
+        .into_values()
+        .map(|mut fix| {
+            fix.edits.sort_by_key(|e| e.start);
+            fix
+        })
+        .collect()
+}
+
+/// `rtl/fifo.vhd` -> `rtl/fifo_verification.vhd`.
+fn companion_path(file: &str) -> String {
+    let path = Path::new(file);
+    let stem = path
+        .file_stem()
+        .map_or(String::new(), |s| s.to_string_lossy().into_owned());
+    path.with_file_name(format!("{}_verification.vhd", stem))
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Byte offset where 1-based `line` starts.
+fn line_offset(text: &str, line: usize) -> usize {
+    text.split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum()
+}
+
+/// Template values read off the design for a construct bound in `arch`: the
+/// `clock` edge and `reset_active` condition of the clocked process driving
+/// it, and per binding `<role>_legal` (its enum literals as a disjunction)
+/// and `<role>_reset` (the value reset assigns it).
+fn derived_values(
+    input: &Input,
+    arch: &str,
+    bindings: &BTreeMap<String, String>,
+) -> BTreeMap<String, String> {
+    let mut values = BTreeMap::new();
+    let clocked: Vec<&Process> = input
+        .processes
+        .iter()
+        .filter(|p| p.in_arch.eq_ignore_ascii_case(arch) && !p.clock_signal.is_empty())
+        .collect();
+    let drives = |p: &&Process| {
+        bindings.values().any(|sig| {
+            p.assigned_signals
+                .iter()
+                .any(|a| a.eq_ignore_ascii_case(sig))
+        })
+    };
+    if let Some(proc) = clocked
+        .iter()
+        .find(|p| drives(p))
+        .or_else(|| clocked.first())
+    {
+        let edge = if proc.clock_edge == "falling" {
+            "falling_edge"
+        } else {
+            "rising_edge"
+        };
+        values.insert(
+            "clock".to_string(),
+            format!("{}({})", edge, proc.clock_signal),
+        );
+        if proc.has_reset && !proc.reset_signal.is_empty() {
+            let reset = proc.reset_signal.to_ascii_lowercase();
+            let level = if reset.ends_with("_n")
+                || reset.ends_with("rstn")
+                || reset.ends_with("resetn")
+                || reset.starts_with("nrst")
+                || reset.starts_with("nreset")
+            {
+                '0'
+            } else {
+                '1'
+            };
+            values.insert(
+                "reset_active".to_string(),
+                format!("{} = '{}'", proc.reset_signal, level),
+            );
+        }
+    }
+    for (role, sig) in bindings {
+        let literals = input
+            .signals
+            .iter()
+            .find(|s| s.in_entity.eq_ignore_ascii_case(arch) && s.name.eq_ignore_ascii_case(sig))
+            .and_then(|s| {
+                input
+                    .types
+                    .iter()
+                    .find(|t| t.name.eq_ignore_ascii_case(s.r#type.trim()))
+            })
+            .map(|t| t.enum_literals.clone())
+            .unwrap_or_default();
+        if !literals.is_empty() {
+            values.insert(
+                format!("{}_legal", role),
+                literals
+                    .iter()
+                    .map(|lit| format!("{} = {}", sig, lit))
+                    .collect::<Vec<_>>()
+                    .join(" or "),
+            );
+        }
+        let reset_value = clocked
+            .iter()
+            .filter(|p| p.has_reset)
+            .flat_map(|p| &p.assignments)
+            .filter(|a| a.signal.eq_ignore_ascii_case(sig) && !a.value.is_empty())
+            .min_by_key(|a| a.line);
+        if let Some(assign) = reset_value {
+            values.insert(format!("{}_reset", role), assign.value.trim().to_string());
+        }
+    }
+    values
+}
+
 fn missing_check_details(
     input: &Input,
     construct: &Construct,
@@ -437,11 +1133,13 @@ fn ambiguous_construct_warnings(ambiguous: &[AmbiguousConstruct]) -> Vec<Violati
             file: amb.file.clone(),
             line: amb.line,
             message: format!(
-                "Ambiguous {} construct in {} (candidates: {})",
+                "Ambiguous {} construct in {} (candidates: {}; name confidence {:.2})",
                 amb.kind,
                 amb.scope,
-                parts.join("; ")
+                parts.join("; "),
+                amb.confidence
             ),
+            ..Default::default()
         });
     }
     out
@@ -461,6 +1159,7 @@ fn tag_error_violation(err: &VerificationTagError) -> Violation {
         file: err.file.clone(),
         line: err.line,
         message: format!("Malformed verification tag: {}", err.message),
+        ..Default::default()
     }
 }
 
@@ -471,6 +1170,7 @@ fn tag_violation(tag: &VerificationTag, message: String) -> Violation {
         file: tag.file.clone(),
         line: tag.line,
         message,
+        ..Default::default()
     }
 }
 
@@ -527,6 +1227,79 @@ fn tags_by_scope<'a>(
     map
 }
 
+/// Registry ids satisfied in each scope by PSL directives whose label spells
+/// the id (`fsm_legal_state:` for `fsm.legal_state`) and whose kind fits it.
+fn psl_checks_by_scope(
+    input: &Input,
+    registry: &HashMap<String, CheckEntry>,
+) -> HashMap<String, HashSet<String>> {
+    let mut map: HashMap<String, HashSet<String>> = HashMap::new();
+    for directive in &input.psl_directives {
+        let entry = match psl_check_entry(directive, registry) {
+            Some(entry) if psl_kind_fits(entry, &directive.kind) => entry,
+            _ => continue,
+        };
+        for scope_key in psl_scope_keys(input, directive) {
+            map.entry(scope_key).or_default().insert(entry.id.clone());
+        }
+    }
+    map
+}
+
+fn psl_check_entry<'a>(
+    directive: &PslDirective,
+    registry: &'a HashMap<String, CheckEntry>,
+) -> Option<&'a CheckEntry> {
+    let label = directive.label.trim().to_ascii_lowercase();
+    if label.is_empty() {
+        return None;
+    }
+    registry
+        .values()
+        .find(|entry| entry.id.replace('.', "_") == label)
+}
+
+fn psl_kind_fits(entry: &CheckEntry, kind: &str) -> bool {
+    let kind = kind.to_ascii_lowercase();
+    if entry.id.starts_with("cover.") {
+        kind == "cover"
+    } else {
+        kind == "assert" || kind == "assume"
+    }
+}
+
+/// `arch:` and `entity:` scope keys of the architecture holding `directive`.
+fn psl_scope_keys(input: &Input, directive: &PslDirective) -> Vec<String> {
+    let arch = directive.in_arch.to_ascii_lowercase();
+    let mut keys = vec![format!("arch:{}", arch)];
+    if let Some(entity) = arch_entity_map(input).get(&arch) {
+        keys.push(format!("entity:{}", entity));
+    }
+    keys
+}
+
+/// Check ids satisfied in `scope_key` by valid tags or PSL directives.
+fn satisfied_checks(
+    scope_key: &str,
+    tags_by_scope: &HashMap<String, Vec<&VerificationTag>>,
+    psl_checks: &HashMap<String, HashSet<String>>,
+    registry: &HashMap<String, CheckEntry>,
+) -> HashSet<String> {
+    let mut ids: HashSet<String> = tags_by_scope
+        .get(scope_key)
+        .map(|list| {
+            list.iter()
+                .filter(|tag| tag_is_valid(tag, registry))
+                .map(|tag| tag.id.to_ascii_lowercase())
+                .collect()
+        })
+        .unwrap_or_default();
+    if let Some(psl) = psl_checks.get(scope_key) {
+        ids.extend(psl.iter().cloned());
+    }
+    ids
+}
+
 fn tag_scope_key(input: &Input, tag: &VerificationTag) -> Option<String> {
     let (scope_type, scope_name) = parse_scope(&tag.scope)?;
     match scope_type.as_str() {
@@ -644,6 +1417,10 @@ fn detect_constructs(input: &Input) -> DetectionReport {
     constructs.extend(rv_constructs);
     ambiguous.extend(rv_ambiguous);
     constructs.extend(detect_fifo_constructs(input));
+    constructs.extend(detect_bus_constructs(input));
+    let (custom_constructs, custom_ambiguous) = detect_custom_constructs(input, &load_detectors());
+    constructs.extend(custom_constructs);
+    ambiguous.extend(custom_ambiguous);
 
     let mut seen = HashSet::new();
     constructs.retain(|c| {
@@ -667,40 +1444,271 @@ fn detect_constructs(input: &Input) -> DetectionReport {
     }
 }
 
-fn detect_fsm_constructs(input: &Input) -> Vec<Construct> {
-    let enum_types = enum_type_names(input);
+/// Bus interfaces of each architecture's entity, anchored at the
+/// architecture like the other constructs.
+fn detect_bus_constructs(input: &Input) -> Vec<Construct> {
     let mut constructs = Vec::new();
-
-    for cs in &input.case_statements {
-        if !is_fsm_case(input, cs, &enum_types) {
-            continue;
+    for iface in bus_protocols::interfaces(input) {
+        for arch in input
+            .architectures
+            .iter()
+            .filter(|arch| arch.entity_name.eq_ignore_ascii_case(&iface.entity))
+        {
+            constructs.push(Construct {
+                kind: ConstructKind::Bus(iface.protocol),
+                in_arch: arch.name.clone(),
+                file: arch.file.clone(),
+                line: arch.line,
+                bindings: iface.bindings.clone().into_iter().collect(),
+                confidence: None,
+            });
         }
-        let state = cs.expression.trim().to_string();
-        let mut bindings = HashMap::new();
-        bindings.insert("state".to_string(), state);
-        constructs.push(Construct {
-            kind: ConstructKind::Fsm,
-            in_arch: cs.in_arch.clone(),
-            file: cs.file.clone(),
-            line: cs.line,
-            bindings,
-        });
     }
     constructs
 }
 
-fn is_fsm_case(
-    input: &Input,
-    case_stmt: &crate::policy::input::CaseStatement,
-    enum_types: &HashSet<String>,
-) -> bool {
-    let expr = case_stmt.expression.trim();
-    if !is_simple_identifier(expr) {
-        return false;
-    }
-    if !signal_is_enum(input, expr, enum_types) {
-        return false;
-    }
+/// A construct described in data rather than code: each role binds to the
+/// one port or signal of an architecture matching its pattern, optionally
+/// tied together by a process reading and assigning them. Loaded from
+/// `construct_detectors.json`, or from `VHDL_CONSTRUCT_DETECTORS` (JSON, or
+/// TOML with `[[detector]]` tables when the path ends in `.toml`).
+#[derive(Debug, Clone, Deserialize)]
+struct DetectorSpec {
+    kind: String,
+    #[serde(default)]
+    roles: BTreeMap<String, RolePattern>,
+    #[serde(default)]
+    process: Option<ProcessPattern>,
+    /// Registry check ids the construct requires.
+    #[serde(default)]
+    checks: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct RolePattern {
+    /// Name globs, matched case-insensitively.
+    names: Vec<String>,
+    /// "port" (the default), "signal" or "any".
+    #[serde(default)]
+    source: String,
+    /// Port mode the role needs; set, it only matches ports.
+    #[serde(default)]
+    direction: String,
+    #[serde(default)]
+    single_bit: Option<bool>,
+}
+
+/// Roles a single process of the architecture must read and assign.
+#[derive(Debug, Clone, Deserialize)]
+struct ProcessPattern {
+    #[serde(default)]
+    clocked: Option<bool>,
+    #[serde(default)]
+    reads: Vec<String>,
+    #[serde(default)]
+    assigns: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct DetectorFile {
+    #[serde(default)]
+    detector: Vec<DetectorSpec>,
+}
+
+fn load_detectors() -> Vec<DetectorSpec> {
+    let Ok(path) = env::var("VHDL_CONSTRUCT_DETECTORS") else {
+        return serde_json::from_str(include_str!("construct_detectors.json"))
+            .unwrap_or_else(|err| panic!("failed to parse construct detectors: {}", err));
+    };
+    let payload = fs::read_to_string(&path).unwrap_or_else(|err| {
+        panic!("failed to read VHDL_CONSTRUCT_DETECTORS {}: {}", path, err)
+    });
+    parse_detectors(&payload, path.to_ascii_lowercase().ends_with(".toml"))
+        .unwrap_or_else(|err| panic!("failed to parse construct detectors {}: {}", path, err))
+}
+
+fn parse_detectors(payload: &str, is_toml: bool) -> Result<Vec<DetectorSpec>, String> {
+    if is_toml {
+        toml::from_str::<DetectorFile>(payload)
+            .map(|file| file.detector)
+            .map_err(|e| e.to_string())
+    } else {
+        serde_json::from_str(payload).map_err(|e| e.to_string())
+    }
+}
+
+/// Runs the declarative detectors over every architecture. A role matching
+/// several names makes the construct ambiguous rather than guessed.
+fn detect_custom_constructs(
+    input: &Input,
+    detectors: &[DetectorSpec],
+) -> (Vec<Construct>, Vec<AmbiguousConstruct>) {
+    let mut constructs = Vec::new();
+    let mut ambiguous = Vec::new();
+    for detector in detectors.iter().filter(|d| !d.roles.is_empty()) {
+        for arch in &input.architectures {
+            let candidates: BTreeMap<&String, Vec<String>> = detector
+                .roles
+                .iter()
+                .map(|(role, pattern)| (role, role_candidates(input, arch, pattern)))
+                .collect();
+            if candidates.values().any(Vec::is_empty) {
+                continue;
+            }
+            let scope = format!("arch:{}", arch.name.to_ascii_lowercase());
+            if candidates.values().any(|names| names.len() > 1) {
+                ambiguous.push(AmbiguousConstruct {
+                    kind: detector.kind.clone(),
+                    scope,
+                    file: arch.file.clone(),
+                    line: arch.line,
+                    candidates: candidates
+                        .into_iter()
+                        .map(|(role, names)| (role.clone(), names))
+                        .collect(),
+                    confidence: 0.0,
+                    best_guess: HashMap::new(),
+                });
+                continue;
+            }
+            let bindings: HashMap<String, String> = candidates
+                .into_iter()
+                .map(|(role, mut names)| (role.clone(), names.remove(0)))
+                .collect();
+            let distinct: HashSet<String> =
+                bindings.values().map(|n| n.to_ascii_lowercase()).collect();
+            if distinct.len() != bindings.len() {
+                continue;
+            }
+            let line = match &detector.process {
+                Some(pattern) => match matching_process(input, &arch.name, pattern, &bindings) {
+                    Some(proc) => proc.line,
+                    None => continue,
+                },
+                None => arch.line,
+            };
+            constructs.push(Construct {
+                kind: ConstructKind::Custom {
+                    name: detector.kind.clone(),
+                    checks: detector.checks.clone(),
+                },
+                in_arch: arch.name.clone(),
+                file: arch.file.clone(),
+                line,
+                bindings,
+                confidence: None,
+            });
+        }
+    }
+    (constructs, ambiguous)
+}
+
+/// Ports of `arch`'s entity and signals of `arch` matching `pattern`, sorted.
+fn role_candidates(input: &Input, arch: &Architecture, pattern: &RolePattern) -> Vec<String> {
+    let globs: Vec<String> = pattern
+        .names
+        .iter()
+        .map(|g| g.to_ascii_lowercase())
+        .collect();
+    let name_matches = |name: &str| {
+        let name = name.to_ascii_lowercase();
+        globs.iter().any(|g| helpers::glob_match(g, &name))
+    };
+    let width_matches = |ty: &str| {
+        pattern
+            .single_bit
+            .is_none_or(|single| helpers::is_single_bit_type(ty) == single)
+    };
+    let source = pattern.source.to_ascii_lowercase();
+    let mut names = Vec::new();
+    if source != "signal" {
+        names.extend(
+            input
+                .ports
+                .iter()
+                .filter(|p| p.in_entity.eq_ignore_ascii_case(&arch.entity_name))
+                .filter(|p| {
+                    pattern.direction.is_empty()
+                        || p.direction.eq_ignore_ascii_case(&pattern.direction)
+                })
+                .filter(|p| name_matches(&p.name) && width_matches(&p.r#type))
+                .map(|p| p.name.clone()),
+        );
+    }
+    if (source == "signal" || source == "any") && pattern.direction.is_empty() {
+        names.extend(
+            input
+                .signals
+                .iter()
+                .filter(|s| s.in_entity.eq_ignore_ascii_case(&arch.name))
+                .filter(|s| name_matches(&s.name) && width_matches(&s.r#type))
+                .map(|s| s.name.clone()),
+        );
+    }
+    names.sort();
+    names.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    names
+}
+
+fn matching_process<'a>(
+    input: &'a Input,
+    arch: &str,
+    pattern: &ProcessPattern,
+    bindings: &HashMap<String, String>,
+) -> Option<&'a Process> {
+    let bound = |roles: &[String]| -> Option<Vec<&String>> {
+        roles.iter().map(|role| bindings.get(role)).collect()
+    };
+    let (reads, assigns) = (bound(&pattern.reads)?, bound(&pattern.assigns)?);
+    input.processes.iter().find(|proc| {
+        proc.in_arch.eq_ignore_ascii_case(arch)
+            && pattern
+                .clocked
+                .is_none_or(|clocked| proc.clock_signal.is_empty() != clocked)
+            && reads
+                .iter()
+                .all(|sig| signal_in_list(sig, &proc.read_signals))
+            && assigns
+                .iter()
+                .all(|sig| signal_in_list(sig, &proc.assigned_signals))
+    })
+}
+
+fn detect_fsm_constructs(input: &Input) -> Vec<Construct> {
+    let enum_types = enum_type_names(input);
+    let mut constructs = Vec::new();
+
+    for cs in &input.case_statements {
+        if !is_fsm_case(input, cs, &enum_types) {
+            continue;
+        }
+        let state = cs.expression.trim().to_string();
+        let mut bindings = HashMap::new();
+        bindings.insert("state".to_string(), state);
+        constructs.push(Construct {
+            kind: ConstructKind::Fsm,
+            in_arch: cs.in_arch.clone(),
+            file: cs.file.clone(),
+            line: cs.line,
+            bindings,
+            confidence: None,
+        });
+    }
+    constructs
+}
+
+fn is_fsm_case(
+    input: &Input,
+    case_stmt: &crate::policy::input::CaseStatement,
+    enum_types: &HashSet<String>,
+) -> bool {
+    let expr = case_stmt.expression.trim();
+    if !is_simple_identifier(expr) {
+        return false;
+    }
+    if !signal_is_enum(input, expr, enum_types) {
+        return false;
+    }
     for process in process_candidates(input, case_stmt) {
         if !process.is_sequential {
             continue;
@@ -755,18 +1763,191 @@ fn detect_counter_constructs(input: &Input) -> Vec<Construct> {
             }
             let mut bindings = HashMap::new();
             bindings.insert("counter".to_string(), signal.clone());
+            if let Some(max) = counter_profile(input, process, signal).and_then(|p| p.max) {
+                bindings.insert("max".to_string(), max.to_string());
+            }
             constructs.push(Construct {
                 kind: ConstructKind::Counter,
                 in_arch: process.in_arch.clone(),
                 file: process.file.clone(),
                 line: process.line,
                 bindings,
+                confidence: None,
             });
         }
     }
     constructs
 }
 
+/// What a counter's declaration, updates and comparisons say about its range.
+struct CounterProfile<'a> {
+    /// Largest value the declared type holds.
+    capacity: Option<i64>,
+    /// Largest value the counter takes: the terminal count it wraps at when
+    /// its process compares it and reloads it, else `capacity`.
+    max: Option<i64>,
+    /// Comparisons in the architecture that need a value above `capacity`
+    /// to change outcome, with that value.
+    unreachable: Vec<(&'a Comparison, i64)>,
+}
+
+/// None when a comparison's threshold overflows `i64`, like the elaboration
+/// evaluator giving up on an expression it can't fold.
+fn counter_profile<'a>(
+    input: &'a Input,
+    process: &Process,
+    signal: &str,
+) -> Option<CounterProfile<'a>> {
+    let arch = helpers::base_arch_name(&process.in_arch);
+    let generics = elaboration::architecture_scope(input, &arch);
+    let capacity = input
+        .signals
+        .iter()
+        .filter(|sig| sig.name.eq_ignore_ascii_case(signal))
+        .max_by_key(|sig| sig.in_entity.eq_ignore_ascii_case(&arch))
+        .and_then(|sig| type_capacity(&sig.r#type, &generics));
+
+    let step = Regex::new(&format!(r"(?i)^\(?\s*{}\s*([+-])", regex::escape(signal))).unwrap();
+    let mut counts_up = false;
+    let mut loads = Vec::new();
+    for assign in process
+        .assignments
+        .iter()
+        .filter(|a| a.signal.eq_ignore_ascii_case(signal))
+    {
+        match step.captures(assign.value.trim()) {
+            Some(caps) => counts_up |= &caps[1] == "+",
+            None => {
+                if let Some(value) = counter_literal(&assign.value, &generics) {
+                    loads.push(value);
+                }
+            }
+        }
+    }
+    // The reset value is one load; a counter that wraps has another.
+    let wraps = loads.len() > usize::from(process.has_reset);
+
+    let mut unreachable = Vec::new();
+    let mut terminal = None;
+    for cmp in input.comparisons.iter().filter(|cmp| {
+        cmp.left_operand.trim().eq_ignore_ascii_case(signal)
+            && helpers::base_arch_name(&cmp.in_arch).eq_ignore_ascii_case(&arch)
+    }) {
+        let operand = if cmp.is_literal {
+            &cmp.literal_value
+        } else {
+            &cmp.right_operand
+        };
+        let Some(value) = counter_literal(operand, &generics) else {
+            continue;
+        };
+        let threshold = match cmp.operator.trim() {
+            "=" | "/=" | ">=" | "<" => value,
+            ">" | "<=" => value.checked_add(1)?,
+            _ => continue,
+        };
+        if capacity.is_some_and(|cap| threshold > cap) {
+            unreachable.push((cmp, threshold));
+        } else if cmp.in_process == process.label {
+            terminal = terminal.max(Some(threshold));
+        }
+    }
+
+    let max = match (wraps, counts_up) {
+        (true, true) => terminal.or(capacity),
+        (true, false) => loads.iter().copied().max().or(capacity),
+        (false, _) => capacity,
+    };
+    Some(CounterProfile {
+        capacity,
+        max,
+        unreachable,
+    })
+}
+
+/// Largest value of `unsigned(N downto 0)`, `signed(...)` or an integer
+/// subtype with a `range` constraint; None for unconstrained integers.
+fn type_capacity(type_str: &str, generics: &elaboration::Generics) -> Option<i64> {
+    if helpers::is_unsigned_type(type_str) || helpers::is_signed_type(type_str) {
+        let width = elaboration::range_width(type_str, generics)?;
+        let bits = if helpers::is_signed_type(type_str) {
+            width.checked_sub(1)?
+        } else {
+            width
+        };
+        return (bits < 63).then(|| (1i64 << bits) - 1);
+    }
+    let range = Regex::new(r"(?i)\brange\s+(.+?)\s+(to|downto)\s+(.+)$").unwrap();
+    let caps = range.captures(type_str.trim())?;
+    let left = elaboration::eval(&caps[1], generics)?;
+    let right = elaboration::eval(&caps[3], generics)?;
+    Some(left.max(right))
+}
+
+/// Value of a counter operand: an integer expression over constants and
+/// generics, a bit-string literal (`"1010"`, `x"1F"`) or `(others => '0')`.
+fn counter_literal(text: &str, generics: &elaboration::Generics) -> Option<i64> {
+    let text = text.trim();
+    let zero = Regex::new(r"(?i)^\(\s*others\s*=>\s*'0'\s*\)$").unwrap();
+    if zero.is_match(text) {
+        return Some(0);
+    }
+    let bit_string = Regex::new(r#"^([uU]?[bBoOxX])?"([0-9A-Fa-f_]+)"$"#).unwrap();
+    if let Some(caps) = bit_string.captures(text) {
+        let radix = match caps.get(1).and_then(|b| b.as_str().chars().last()) {
+            None | Some('b' | 'B') => 2,
+            Some('o' | 'O') => 8,
+            _ => 16,
+        };
+        return i64::from_str_radix(&caps[2].replace('_', ""), radix).ok();
+    }
+    elaboration::eval(text, generics)
+}
+
+/// `counter_wrap_without_check`: a counter compared against a value its
+/// width can't hold. The comparison never changes outcome, so the wrap or
+/// terminal count it was meant to enforce never happens.
+fn counter_wrap_without_check(input: &Input, constructs: &[Construct]) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for construct in constructs
+        .iter()
+        .filter(|c| c.kind == ConstructKind::Counter)
+    {
+        let Some(process) = input.processes.iter().find(|p| {
+            p.file == construct.file && p.line == construct.line && p.in_arch == construct.in_arch
+        }) else {
+            continue;
+        };
+        let signal = &construct.bindings["counter"];
+        let Some(profile) = counter_profile(input, process, signal) else {
+            continue;
+        };
+        let Some(capacity) = profile.capacity else {
+            continue;
+        };
+        for (cmp, threshold) in profile.unreachable {
+            violations.push(Violation {
+                rule: "counter_wrap_without_check".to_string(),
+                severity: "warning".to_string(),
+                file: cmp.file.clone(),
+                line: cmp.line,
+                message: format!(
+                    "Counter '{}' holds at most {} but '{} {} {}' needs it to reach {}; it wraps at {} instead",
+                    signal,
+                    capacity,
+                    cmp.left_operand,
+                    cmp.operator,
+                    if cmp.is_literal { &cmp.literal_value } else { &cmp.right_operand },
+                    threshold,
+                    capacity
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    violations
+}
+
 fn signal_is_numeric(input: &Input, signal: &str) -> bool {
     let sig = match input
         .signals
@@ -786,6 +1967,7 @@ fn detect_ready_valid_constructs(
     input: &Input,
 ) -> (Vec<Construct>, Vec<AmbiguousConstruct>) {
     let port_map = port_info_map(input);
+    let threshold = binding_threshold(input);
     let mut constructs = Vec::new();
     let mut ambiguous = Vec::new();
     for ca in &input.concurrent_assignments {
@@ -803,21 +1985,33 @@ fn detect_ready_valid_constructs(
         if !pa.single_bit || !pb.single_bit {
             continue;
         }
-        let (valid, ready) = match (pa.direction.as_str(), pb.direction.as_str()) {
-            ("out", "in") | ("buffer", "in") => (a.clone(), b.clone()),
-            ("in", "out") | ("in", "buffer") => (b.clone(), a.clone()),
+        let (valid, ready, confidence) = match (pa.direction.as_str(), pb.direction.as_str()) {
+            ("out", "in") | ("buffer", "in") => (a.clone(), b.clone(), None),
+            ("in", "out") | ("in", "buffer") => (b.clone(), a.clone(), None),
             _ => {
-                let mut candidates = HashMap::new();
-                candidates.insert("valid".to_string(), vec![a.clone(), b.clone()]);
-                candidates.insert("ready".to_string(), vec![a.clone(), b.clone()]);
-                ambiguous.push(AmbiguousConstruct {
-                    kind: "ready_valid".to_string(),
-                    scope: format!("arch:{}", ca.in_arch.to_ascii_lowercase()),
-                    file: ca.file.clone(),
-                    line: ca.line,
-                    candidates,
-                });
-                continue;
+                let guess = bind_by_names(input, pa, pb, &a, &b);
+                if guess.confidence >= threshold {
+                    (guess.valid, guess.ready, Some(guess.confidence))
+                } else {
+                    let mut candidates = HashMap::new();
+                    candidates.insert("valid".to_string(), vec![a.clone(), b.clone()]);
+                    candidates.insert("ready".to_string(), vec![a.clone(), b.clone()]);
+                    let mut best_guess = HashMap::new();
+                    if guess.confidence > 0.0 {
+                        best_guess.insert("valid".to_string(), guess.valid);
+                        best_guess.insert("ready".to_string(), guess.ready);
+                    }
+                    ambiguous.push(AmbiguousConstruct {
+                        kind: "ready_valid".to_string(),
+                        scope: format!("arch:{}", ca.in_arch.to_ascii_lowercase()),
+                        file: ca.file.clone(),
+                        line: ca.line,
+                        candidates,
+                        confidence: guess.confidence,
+                        best_guess,
+                    });
+                    continue;
+                }
             }
         };
         let mut bindings = HashMap::new();
@@ -829,11 +2023,84 @@ fn detect_ready_valid_constructs(
             file: ca.file.clone(),
             line: ca.line,
             bindings,
+            confidence,
         });
     }
     (constructs, ambiguous)
 }
 
+/// Port name globs suggesting each ready/valid role, used when both ports of
+/// a handshake point the same way.
+const DEFAULT_BINDING_PATTERNS: &[(&str, &[&str])] = &[
+    ("valid", &["*valid", "*_vld", "*_req"]),
+    ("ready", &["*ready", "*_rdy", "*_ack"]),
+];
+/// Evidence for a role from a port name matching one of its patterns.
+const NAME_WEIGHT: f64 = 0.8;
+/// Evidence for a role from a word in the port's trailing comment.
+const COMMENT_WEIGHT: f64 = 0.4;
+const DEFAULT_BINDING_CONFIDENCE: f64 = 0.75;
+
+struct NameBinding {
+    valid: String,
+    ready: String,
+    confidence: f64,
+}
+
+fn binding_threshold(input: &Input) -> f64 {
+    match input.lint_config.binding_confidence {
+        c if c > 0.0 => c,
+        _ => DEFAULT_BINDING_CONFIDENCE,
+    }
+}
+
+fn binding_patterns(input: &Input, role: &str) -> Vec<String> {
+    if let Some(patterns) = input.lint_config.binding_patterns.get(role) {
+        return patterns.iter().map(|p| p.to_ascii_lowercase()).collect();
+    }
+    DEFAULT_BINDING_PATTERNS
+        .iter()
+        .filter(|(name, _)| *name == role)
+        .flat_map(|(_, patterns)| patterns.iter().map(|p| p.to_string()))
+        .collect()
+}
+
+/// Picks the likelier of the two ways to bind `a` and `b` to valid/ready.
+/// Each port scores up to 1.0 per role; confidence is half the margin
+/// between the two assignments, so it only reaches 1.0 when each port fully
+/// matches its own role and not the other.
+fn bind_by_names(input: &Input, pa: &PortInfo, pb: &PortInfo, a: &str, b: &str) -> NameBinding {
+    let valid = binding_patterns(input, "valid");
+    let ready = binding_patterns(input, "ready");
+    let (a_valid, a_ready) = (role_evidence(a, pa, &valid), role_evidence(a, pa, &ready));
+    let (b_valid, b_ready) = (role_evidence(b, pb, &valid), role_evidence(b, pb, &ready));
+    let straight = a_valid + b_ready;
+    let swapped = b_valid + a_ready;
+    let confidence = ((straight - swapped).abs() * 50.0).round() / 100.0;
+    let (valid, ready) = if straight >= swapped { (a, b) } else { (b, a) };
+    NameBinding {
+        valid: valid.to_string(),
+        ready: ready.to_string(),
+        confidence,
+    }
+}
+
+fn role_evidence(name: &str, port: &PortInfo, patterns: &[String]) -> f64 {
+    let matches = |word: &str| patterns.iter().any(|p| helpers::glob_match(p, word));
+    let mut score = 0.0;
+    if matches(&name.to_ascii_lowercase()) {
+        score += NAME_WEIGHT;
+    }
+    if port
+        .comment
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .any(|word| !word.is_empty() && matches(word))
+    {
+        score += COMMENT_WEIGHT;
+    }
+    f64::min(score, 1.0)
+}
+
 fn detect_fifo_constructs(input: &Input) -> Vec<Construct> {
     let port_map = port_info_map(input);
     let array_signals = array_signals_by_arch(input);
@@ -860,18 +2127,345 @@ fn detect_fifo_constructs(input: &Input) -> Vec<Construct> {
             bindings.insert("rd_en".to_string(), rd_en);
             bindings.insert("full".to_string(), full);
             bindings.insert("empty".to_string(), empty);
+            let shape = fifo_shape(input, &arch, &mem_name, &write_procs);
+            if let Some(ptr) = shape.wr_ptr {
+                bindings.insert("wr_ptr".to_string(), ptr);
+            }
+            if let Some(ptr) = shape.rd_ptr {
+                bindings.insert("rd_ptr".to_string(), ptr);
+            }
+            if let Some(depth) = shape.depth {
+                bindings.insert("depth".to_string(), depth.to_string());
+            }
             constructs.push(Construct {
                 kind: ConstructKind::Fifo,
                 in_arch: arch.clone(),
                 file: mem.1.clone(),
                 line: mem.2,
                 bindings,
+                confidence: None,
             });
         }
     }
     constructs
 }
 
+/// Pointers and depth of a FIFO memory: the signals indexing its writes and
+/// its reads, and the element count of its array type.
+struct FifoShape {
+    depth: Option<i64>,
+    wr_ptr: Option<String>,
+    rd_ptr: Option<String>,
+}
+
+fn fifo_shape(input: &Input, arch: &str, memory: &str, write_procs: &HashSet<String>) -> FifoShape {
+    let index = Regex::new(&format!(
+        r"(?i)\b{}\s*\(\s*(?:(?:to_integer|unsigned|signed)\s*\(\s*)*([a-z][a-z0-9_]*)",
+        regex::escape(memory)
+    ))
+    .unwrap();
+    let pointer = |text: &str| index.captures(text).map(|caps| caps[1].to_string());
+    let in_arch = |name: &str| helpers::base_arch_name(name).eq_ignore_ascii_case(arch);
+    let procs: Vec<&Process> = input
+        .processes
+        .iter()
+        .filter(|p| in_arch(&p.in_arch))
+        .collect();
+    let wr_ptr = procs
+        .iter()
+        .filter(|p| write_procs.contains(&p.label))
+        .flat_map(|p| &p.assignments)
+        .filter(|a| a.signal.eq_ignore_ascii_case(memory))
+        .find_map(|a| pointer(&a.target));
+    // A read through the write pointer is a single-port memory, not a FIFO.
+    let rd_ptr = procs
+        .iter()
+        .flat_map(|p| &p.assignments)
+        .map(|a| a.value.as_str())
+        .chain(
+            input
+                .concurrent_assignments
+                .iter()
+                .filter(|ca| in_arch(&ca.in_arch))
+                .map(|ca| ca.value.as_str()),
+        )
+        .filter_map(pointer)
+        .find(|ptr| {
+            wr_ptr
+                .as_ref()
+                .is_none_or(|wr| !wr.eq_ignore_ascii_case(ptr))
+        });
+    FifoShape {
+        depth: memory_depth(input, arch, memory),
+        wr_ptr,
+        rd_ptr,
+    }
+}
+
+/// Element count of `memory`'s array type: the index range of an inline
+/// `array (0 to 11) of ...` or constrained `mem_t(0 to 11)`, else of the
+/// named type's declaration.
+fn memory_depth(input: &Input, arch: &str, memory: &str) -> Option<i64> {
+    let generics = elaboration::architecture_scope(input, arch);
+    let sig = input
+        .signals
+        .iter()
+        .find(|s| s.in_entity.eq_ignore_ascii_case(arch) && s.name.eq_ignore_ascii_case(memory))?;
+    let width = elaboration::range_width(&sig.r#type, &generics).or_else(|| {
+        let base = helpers::base_type_name(&sig.r#type);
+        input
+            .types
+            .iter()
+            .filter(|t| t.name.eq_ignore_ascii_case(&base) && !t.index_range.is_empty())
+            .find_map(|t| elaboration::range_width(&format!("({})", t.index_range), &generics))
+    })?;
+    i64::try_from(width).ok()
+}
+
+/// Declared type of signal `name` in `arch`.
+fn signal_type<'a>(input: &'a Input, arch: &str, name: &str) -> Option<&'a str> {
+    input
+        .signals
+        .iter()
+        .find(|s| s.in_entity.eq_ignore_ascii_case(arch) && s.name.eq_ignore_ascii_case(name))
+        .map(|s| s.r#type.as_str())
+}
+
+/// Clocked process in `arch` that updates `signal` from its own value.
+fn updating_process<'a>(input: &'a Input, arch: &str, signal: &str) -> Option<&'a Process> {
+    input.processes.iter().find(|p| {
+        p.is_sequential
+            && helpers::base_arch_name(&p.in_arch).eq_ignore_ascii_case(arch)
+            && signal_in_list(signal, &p.assigned_signals)
+            && signal_in_list(signal, &p.read_signals)
+    })
+}
+
+/// `fifo_pointer_width_mismatch`: a FIFO pointer that can't address every
+/// entry (depth 12 with a 3-bit pointer), or that runs past the last entry
+/// of a non-power-of-two memory because it wraps at its natural width.
+fn fifo_pointer_width_mismatch(input: &Input, constructs: &[Construct]) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for construct in constructs.iter().filter(|c| c.kind == ConstructKind::Fifo) {
+        let Some(depth) = construct
+            .bindings
+            .get("depth")
+            .and_then(|d| d.parse::<i64>().ok())
+        else {
+            continue;
+        };
+        let arch = &construct.in_arch;
+        for role in ["wr_ptr", "rd_ptr"] {
+            let Some(ptr) = construct.bindings.get(role) else {
+                continue;
+            };
+            let generics = elaboration::architecture_scope(input, arch);
+            let Some(capacity) =
+                signal_type(input, arch, ptr).and_then(|t| type_capacity(t, &generics))
+            else {
+                continue;
+            };
+            let message = if capacity + 1 < depth {
+                format!(
+                    "FIFO pointer '{}' holds at most {} but the memory has {} entries; entries {} to {} are never addressed",
+                    ptr,
+                    capacity,
+                    depth,
+                    capacity + 1,
+                    depth - 1
+                )
+            } else if (depth as u64).is_power_of_two() {
+                continue;
+            } else {
+                let wraps_at = updating_process(input, arch, ptr)
+                    .and_then(|p| counter_profile(input, p, ptr))
+                    .and_then(|profile| profile.max);
+                match wraps_at {
+                    Some(max) if max >= depth => format!(
+                        "FIFO pointer '{}' wraps at {} but the memory has {} entries; it must wrap at {}",
+                        ptr,
+                        max,
+                        depth,
+                        depth - 1
+                    ),
+                    _ => continue,
+                }
+            };
+            let line = input
+                .signals
+                .iter()
+                .find(|s| {
+                    s.in_entity.eq_ignore_ascii_case(arch) && s.name.eq_ignore_ascii_case(ptr)
+                })
+                .map_or(construct.line, |s| s.line);
+            violations.push(Violation {
+                rule: "fifo_pointer_width_mismatch".to_string(),
+                severity: "error".to_string(),
+                file: construct.file.clone(),
+                line,
+                message,
+                ..Default::default()
+            });
+        }
+    }
+    violations
+}
+
+/// `fifo_flag_incomplete`: a full or empty flag whose logic reads neither
+/// both pointers nor both enables (a fill count), so it can't track
+/// occupancy.
+fn fifo_flag_incomplete(input: &Input, constructs: &[Construct]) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for construct in constructs.iter().filter(|c| c.kind == ConstructKind::Fifo) {
+        let sources = |flag: &str| fan_in(input, &construct.in_arch, flag);
+        let covers = |found: &HashSet<String>, a: &str, b: &str| match (
+            construct.bindings.get(a),
+            construct.bindings.get(b),
+        ) {
+            (Some(a), Some(b)) => {
+                found.contains(&a.to_ascii_lowercase()) && found.contains(&b.to_ascii_lowercase())
+            }
+            _ => false,
+        };
+        for role in ["full", "empty"] {
+            let flag = &construct.bindings[role];
+            let found = sources(flag);
+            if covers(&found, "wr_ptr", "rd_ptr") || covers(&found, "wr_en", "rd_en") {
+                continue;
+            }
+            let line = input
+                .processes
+                .iter()
+                .filter(|p| {
+                    helpers::base_arch_name(&p.in_arch).eq_ignore_ascii_case(&construct.in_arch)
+                })
+                .flat_map(|p| &p.assignments)
+                .filter(|a| a.signal.eq_ignore_ascii_case(flag))
+                .map(|a| a.line)
+                .max()
+                .unwrap_or(construct.line);
+            violations.push(Violation {
+                rule: "fifo_flag_incomplete".to_string(),
+                severity: "warning".to_string(),
+                file: construct.file.clone(),
+                line,
+                message: format!(
+                    "FIFO {} flag '{}' depends on neither both pointers nor both enables; it can't track occupancy",
+                    role, flag
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    violations
+}
+
+/// Lowercase signals `signal` depends on in `arch`, through any number of
+/// assignments.
+fn fan_in(input: &Input, arch: &str, signal: &str) -> HashSet<String> {
+    let mut sources: HashMap<String, Vec<String>> = HashMap::new();
+    for dep in input
+        .signal_deps
+        .iter()
+        .filter(|dep| helpers::base_arch_name(&dep.in_arch).eq_ignore_ascii_case(arch))
+    {
+        sources
+            .entry(dep.target.to_ascii_lowercase())
+            .or_default()
+            .push(dep.source.to_ascii_lowercase());
+    }
+    let mut seen = HashSet::new();
+    let mut pending = vec![signal.to_ascii_lowercase()];
+    while let Some(next) = pending.pop() {
+        for source in sources.get(&next).into_iter().flatten() {
+            if seen.insert(source.clone()) {
+                pending.push(source.clone());
+            }
+        }
+    }
+    seen
+}
+
+/// `fifo_pointer_not_gray`: a FIFO whose pointers are updated on different
+/// clocks, with a pointer that is never Gray-coded. A binary pointer
+/// changes several bits at once and can be sampled mid-transition.
+fn fifo_pointer_not_gray(input: &Input, constructs: &[Construct]) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for construct in constructs.iter().filter(|c| c.kind == ConstructKind::Fifo) {
+        let arch = &construct.in_arch;
+        let (Some(wr_ptr), Some(rd_ptr)) = (
+            construct.bindings.get("wr_ptr"),
+            construct.bindings.get("rd_ptr"),
+        ) else {
+            continue;
+        };
+        let clock = |ptr: &str| {
+            updating_process(input, arch, ptr)
+                .map(|p| p.clock_signal.clone())
+                .filter(|clk| !clk.is_empty())
+        };
+        let (Some(wr_clk), Some(rd_clk)) = (clock(wr_ptr), clock(rd_ptr)) else {
+            continue;
+        };
+        if helpers::clock_domain(input, &wr_clk) == helpers::clock_domain(input, &rd_clk) {
+            continue;
+        }
+        for (ptr, from, to) in [(wr_ptr, &wr_clk, &rd_clk), (rd_ptr, &rd_clk, &wr_clk)] {
+            if is_gray_coded(input, arch, ptr) {
+                continue;
+            }
+            let line = input
+                .signals
+                .iter()
+                .find(|s| {
+                    s.in_entity.eq_ignore_ascii_case(arch) && s.name.eq_ignore_ascii_case(ptr)
+                })
+                .map_or(construct.line, |s| s.line);
+            violations.push(Violation {
+                rule: "fifo_pointer_not_gray".to_string(),
+                severity: "error".to_string(),
+                file: construct.file.clone(),
+                line,
+                message: format!(
+                    "FIFO pointer '{}' crosses from {} to {} without Gray coding",
+                    ptr, from, to
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    violations
+}
+
+/// Whether `ptr` is Gray-coded itself (by name) or converted somewhere in
+/// `arch`: an assignment reading it through `xor` or a `*gray*` function.
+fn is_gray_coded(input: &Input, arch: &str, ptr: &str) -> bool {
+    let gray_name = |name: &str| {
+        let lower = name.to_ascii_lowercase();
+        lower.contains("gray") || lower.contains("grey")
+    };
+    if gray_name(ptr) {
+        return true;
+    }
+    let reads = Regex::new(&format!(r"(?i)\b{}\b", regex::escape(ptr))).unwrap();
+    let in_arch = |name: &str| helpers::base_arch_name(name).eq_ignore_ascii_case(arch);
+    input
+        .processes
+        .iter()
+        .filter(|p| in_arch(&p.in_arch))
+        .flat_map(|p| &p.assignments)
+        .map(|a| a.value.as_str())
+        .chain(
+            input
+                .concurrent_assignments
+                .iter()
+                .filter(|ca| in_arch(&ca.in_arch))
+                .map(|ca| ca.value.as_str()),
+        )
+        .filter(|value| reads.is_match(value))
+        .any(|value| value.to_ascii_lowercase().contains("xor") || gray_name(value))
+}
+
 fn processes_writing_signal(input: &Input, signal: &str, arch: &str) -> HashSet<String> {
     input
         .signal_deps
@@ -970,6 +2564,8 @@ fn array_type_names(input: &Input) -> HashSet<String> {
 struct PortInfo {
     direction: String,
     single_bit: bool,
+    /// Lowercased trailing comment.
+    comment: String,
 }
 
 fn port_info_map(input: &Input) -> HashMap<String, PortInfo> {
@@ -980,6 +2576,7 @@ fn port_info_map(input: &Input) -> HashMap<String, PortInfo> {
             PortInfo {
                 direction: port.direction.to_ascii_lowercase(),
                 single_bit: helpers::is_single_bit_type(&port.r#type),
+                comment: port.comment.to_ascii_lowercase(),
             },
         );
     }
@@ -1019,8 +2616,8 @@ fn is_simple_identifier(expr: &str) -> bool {
             .all(|c| c.is_ascii_alphanumeric() || c == '_')
 }
 
-fn required_checks_for_construct(kind: &ConstructKind) -> &'static [&'static str] {
-    match kind {
+fn required_checks_for_construct(kind: &ConstructKind) -> Vec<&str> {
+    let builtin: &[&str] = match kind {
         ConstructKind::Fsm => &[
             "fsm.legal_state",
             "fsm.reset_known",
@@ -1033,5 +2630,680 @@ fn required_checks_for_construct(kind: &ConstructKind) -> &'static [&'static str
             "cover.fifo.activity",
         ],
         ConstructKind::Counter => &["ctr.range", "ctr.step_rule", "cover.ctr.moved"],
+        ConstructKind::Bus(name) => bus_protocols::protocol(name).map_or(&[], |p| p.checks),
+        ConstructKind::Custom { checks, .. } => {
+            return checks.iter().map(String::as_str).collect();
+        }
+    };
+    builtin.to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{
+        Architecture, Assertion, ConcurrentAssignment, Entity, Port, SequentialAssignment, Signal,
+        SignalDep, TypeDeclaration,
+    };
+
+    fn handshake(ports: &[(&str, &str)]) -> Input {
+        Input {
+            ports: ports
+                .iter()
+                .map(|(name, comment)| Port {
+                    name: name.to_string(),
+                    direction: "in".to_string(),
+                    r#type: "std_logic".to_string(),
+                    comment: comment.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            concurrent_assignments: vec![ConcurrentAssignment {
+                target: "xfer".to_string(),
+                read_signals: ports.iter().map(|(name, _)| name.to_string()).collect(),
+                file: "rv.vhd".to_string(),
+                line: 12,
+                in_arch: "rtl".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn fix_tasks_render_registry_templates_per_architecture() {
+        fn task(file: &str, ids: &[&str], bindings: &[(&str, &str)]) -> MissingCheckTask {
+            MissingCheckTask {
+                file: file.to_string(),
+                scope: "arch:rtl".to_string(),
+                anchor: VerificationAnchor {
+                    label: "architecture".to_string(),
+                    line_start: 5,
+                    line_end: 5,
+                    exists: false,
+                },
+                missing_ids: ids.iter().map(|id| id.to_string()).collect(),
+                bindings: bindings
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+                notes: Vec::new(),
+                confidence: None,
+            }
+        }
+        let groups = fix_tasks(&[
+            task(
+                "b.vhd",
+                &["rv.eventual_progress_bounded"],
+                &[("valid", "v"), ("ready", "r")],
+            ),
+            task("a.vhd", &["fsm.legal_state"], &[("state", "st")]),
+            task("a.vhd", &["fsm.legal_state"], &[("state", "st")]),
+        ]);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].file, "a.vhd");
+        assert_eq!(groups[0].tasks.len(), 1);
+        assert_eq!(groups[0].tasks[0].id, "arch:rtl/fsm.legal_state/state=st");
+        assert_eq!(
+            groups[0].tasks[0].tag,
+            "--@check id=fsm.legal_state scope=arch:rtl state=st"
+        );
+        let liveness = &groups[1].tasks[0];
+        assert_eq!(
+            liveness.tag,
+            "--@check id=rv.eventual_progress_bounded scope=arch:rtl valid=v ready=r bound={bound}"
+        );
+        assert_eq!(liveness.unfilled, ["bound"]);
+    }
+
+    #[test]
+    fn generate_checks_fills_the_block_or_a_companion_vunit() {
+        let source = "architecture rtl of top is\n\
+                      begin\n\
+                      \x20 verification : block\n\
+                      \x20 begin\n\
+                      \x20 end block verification;\n\
+                      end architecture;\n";
+        let mut input = Input {
+            architectures: vec![Architecture {
+                name: "rtl".to_string(),
+                entity_name: "top".to_string(),
+                file: "top.vhd".to_string(),
+                line: 1,
+                ..Default::default()
+            }],
+            signals: vec![Signal {
+                name: "mode".to_string(),
+                r#type: "mode_t".to_string(),
+                in_entity: "rtl".to_string(),
+                ..Default::default()
+            }],
+            types: vec![TypeDeclaration {
+                name: "mode_t".to_string(),
+                enum_literals: vec!["S0".to_string(), "S1".to_string()],
+                ..Default::default()
+            }],
+            processes: vec![Process {
+                clock_signal: "clk".to_string(),
+                clock_edge: "rising".to_string(),
+                has_reset: true,
+                reset_signal: "rst_n".to_string(),
+                assigned_signals: vec!["mode".to_string()],
+                assignments: vec![SequentialAssignment {
+                    signal: "mode".to_string(),
+                    value: "S0".to_string(),
+                    line: 7,
+                    ..Default::default()
+                }],
+                in_arch: "rtl".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let task = MissingCheckTask {
+            file: "top.vhd".to_string(),
+            scope: "arch:rtl".to_string(),
+            anchor: VerificationAnchor {
+                label: "verification".to_string(),
+                line_start: 3,
+                line_end: 5,
+                exists: true,
+            },
+            missing_ids: vec!["fsm.legal_state".to_string(), "fsm.reset_known".to_string()],
+            bindings: [("state".to_string(), "mode".to_string())].into(),
+            notes: Vec::new(),
+            confidence: None,
+        };
+        let read = |file: &str| (file == "top.vhd").then(|| source.to_string());
+        let fixes = generate_checks(&input, std::slice::from_ref(&task), read);
+        assert_eq!(fixes.len(), 1);
+        let fixed = fixes[0].fixed();
+        assert!(fixed.contains(
+            "    --@check id=fsm.legal_state scope=arch:rtl state=mode\n\
+             \x20   fsm_legal_state : assert always (mode = S0 or mode = S1) @rising_edge(clk);\n"
+        ));
+        assert!(fixed.contains(
+            "    fsm_reset_known : assert always (rst_n = '0' -> next (mode = S0)) @rising_edge(clk);\n\
+             \x20 end block verification;"
+        ));
+
+        input.processes.clear();
+        let task = MissingCheckTask {
+            anchor: VerificationAnchor {
+                exists: false,
+                ..task.anchor
+            },
+            missing_ids: vec!["fsm.legal_state".to_string()],
+            ..task
+        };
+        let fixes = generate_checks(&input, &[task], read);
+        assert_eq!(fixes[0].file, "top_verification.vhd");
+        assert_eq!(
+            fixes[0].fixed(),
+            "vunit rtl_verification(top(rtl)) {\n\
+             \x20 --@check id=fsm.legal_state scope=arch:rtl state=mode\n\
+             \x20 -- TODO: fill in clock\n\
+             \x20 -- fsm_legal_state : assert always (mode = S0 or mode = S1) @{clock};\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn declarative_detectors_bind_roles_and_require_their_checks() {
+        let detectors = parse_detectors(
+            r#"
+            [[detector]]
+            kind = "apb"
+            checks = ["apb.setup_before_access"]
+            process = { clocked = true, reads = ["psel", "penable"] }
+
+            [detector.roles]
+            psel = { names = ["*psel"], direction = "in", single_bit = true }
+            penable = { names = ["*penable"], direction = "in" }
+            "#,
+            true,
+        )
+        .unwrap();
+        let port = |name: &str| Port {
+            name: name.to_string(),
+            direction: "in".to_string(),
+            r#type: "std_logic".to_string(),
+            in_entity: "apb_slave".to_string(),
+            ..Default::default()
+        };
+        let mut input = Input {
+            architectures: vec![Architecture {
+                name: "rtl".to_string(),
+                entity_name: "apb_slave".to_string(),
+                file: "apb.vhd".to_string(),
+                line: 10,
+                ..Default::default()
+            }],
+            ports: vec![port("s_psel"), port("s_penable")],
+            processes: vec![Process {
+                clock_signal: "pclk".to_string(),
+                read_signals: vec!["s_psel".to_string(), "s_penable".to_string()],
+                in_arch: "rtl".to_string(),
+                line: 14,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let (found, ambiguous) = detect_custom_constructs(&input, &detectors);
+        assert!(ambiguous.is_empty());
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].kind.label(), "apb");
+        assert_eq!(found[0].line, 14);
+        assert_eq!(found[0].bindings["psel"], "s_psel");
+        assert_eq!(
+            required_checks_for_construct(&found[0].kind),
+            ["apb.setup_before_access"]
+        );
+
+        input.ports.push(port("m_psel"));
+        let (found, ambiguous) = detect_custom_constructs(&input, &detectors);
+        assert!(found.is_empty());
+        assert_eq!(ambiguous[0].candidates["psel"], ["m_psel", "s_psel"]);
+    }
+
+    #[test]
+    fn counters_bind_their_terminal_count_and_flag_unreachable_compares() {
+        let assign = |value: &str, line| SequentialAssignment {
+            signal: "idx".to_string(),
+            value: value.to_string(),
+            line,
+            ..Default::default()
+        };
+        let compare = |value: &str, line, in_process: &str| Comparison {
+            left_operand: "idx".to_string(),
+            operator: "=".to_string(),
+            is_literal: true,
+            literal_value: value.to_string(),
+            file: "cnt.vhd".to_string(),
+            line,
+            in_arch: "rtl".to_string(),
+            in_process: in_process.to_string(),
+            ..Default::default()
+        };
+        let mut input = Input {
+            signals: vec![Signal {
+                name: "idx".to_string(),
+                r#type: "unsigned(3 downto 0)".to_string(),
+                in_entity: "rtl".to_string(),
+                ..Default::default()
+            }],
+            processes: vec![Process {
+                label: "p_cnt".to_string(),
+                is_sequential: true,
+                has_reset: true,
+                assigned_signals: vec!["idx".to_string()],
+                read_signals: vec!["idx".to_string()],
+                assignments: vec![
+                    assign("(others => '0')", 12),
+                    assign("(others => '0')", 15),
+                    assign("idx + 1", 17),
+                ],
+                file: "cnt.vhd".to_string(),
+                line: 10,
+                in_arch: "rtl".to_string(),
+                ..Default::default()
+            }],
+            comparisons: vec![compare("9", 14, "p_cnt"), compare("x\"14\"", 22, "")],
+            ..Default::default()
+        };
+
+        let constructs = detect_counter_constructs(&input);
+        assert_eq!(constructs[0].bindings["max"], "9");
+
+        let violations = counter_wrap_without_check(&input, &constructs);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 22);
+        assert!(violations[0].message.contains("holds at most 15"));
+        assert!(violations[0].message.contains("reach 20"));
+
+        // `idx > i64::MAX` has no threshold to compare against.
+        input.comparisons.push(Comparison {
+            operator: ">".to_string(),
+            ..compare("x\"7FFFFFFFFFFFFFFF\"", 24, "")
+        });
+        assert!(counter_wrap_without_check(&input, &constructs).is_empty());
+    }
+
+    #[test]
+    fn fifo_pointers_are_checked_against_depth_flags_and_clocks() {
+        let signal = |name: &str, ty: &str, line| Signal {
+            name: name.to_string(),
+            r#type: ty.to_string(),
+            file: "fifo.vhd".to_string(),
+            line,
+            in_entity: "rtl".to_string(),
+            ..Default::default()
+        };
+        let assign = |target: &str, value: &str, line| SequentialAssignment {
+            target: target.to_string(),
+            signal: target.split('(').next().unwrap().to_string(),
+            value: value.to_string(),
+            line,
+            ..Default::default()
+        };
+        let process = |label: &str, clock: &str, assignments: Vec<SequentialAssignment>| {
+            let names =
+                |a: &Vec<SequentialAssignment>| a.iter().map(|a| a.signal.clone()).collect();
+            Process {
+                label: label.to_string(),
+                is_sequential: true,
+                clock_signal: clock.to_string(),
+                assigned_signals: names(&assignments),
+                read_signals: names(&assignments),
+                assignments,
+                in_arch: "rtl".to_string(),
+                ..Default::default()
+            }
+        };
+        let mut input = Input {
+            signals: vec![
+                signal("mem", "mem_t", 20),
+                signal("wptr", "unsigned(2 downto 0)", 21),
+                signal("rptr", "unsigned(3 downto 0)", 22),
+            ],
+            types: vec![TypeDeclaration {
+                name: "mem_t".to_string(),
+                kind: "array".to_string(),
+                index_range: "0 to 11".to_string(),
+                ..Default::default()
+            }],
+            processes: vec![
+                process(
+                    "write_p",
+                    "wclk",
+                    vec![
+                        assign("mem(to_integer(wptr))", "din", 30),
+                        assign("wptr", "wptr + 1", 31),
+                        assign("full", "'1'", 32),
+                    ],
+                ),
+                process(
+                    "read_p",
+                    "rclk",
+                    vec![
+                        assign("dout", "mem(to_integer(rptr))", 40),
+                        assign("rptr", "rptr + 1", 41),
+                        assign("empty", "'0'", 42),
+                    ],
+                ),
+            ],
+            ..Default::default()
+        };
+        for source in ["wptr", "rptr"] {
+            input.signal_deps.push(SignalDep {
+                source: source.to_string(),
+                target: "full".to_string(),
+                in_arch: "rtl".to_string(),
+                ..Default::default()
+            });
+        }
+
+        let shape = fifo_shape(
+            &input,
+            "rtl",
+            "mem",
+            &HashSet::from(["write_p".to_string()]),
+        );
+        assert_eq!(shape.wr_ptr.as_deref(), Some("wptr"));
+        assert_eq!(shape.rd_ptr.as_deref(), Some("rptr"));
+        assert_eq!(shape.depth, Some(12));
+
+        let mut bindings: HashMap<String, String> = [
+            ("wr_en", "wr_go"),
+            ("rd_en", "rd_go"),
+            ("full", "full"),
+            ("empty", "empty"),
+            ("wr_ptr", "wptr"),
+            ("rd_ptr", "rptr"),
+        ]
+        .iter()
+        .map(|(role, sig)| (role.to_string(), sig.to_string()))
+        .collect();
+        bindings.insert("depth".to_string(), "12".to_string());
+        let constructs = [Construct {
+            kind: ConstructKind::Fifo,
+            in_arch: "rtl".to_string(),
+            file: "fifo.vhd".to_string(),
+            line: 20,
+            bindings,
+            confidence: None,
+        }];
+
+        let width: Vec<_> = fifo_pointer_width_mismatch(&input, &constructs)
+            .into_iter()
+            .map(|v| (v.line, v.message))
+            .collect();
+        assert_eq!(width.len(), 2);
+        assert_eq!(width[0].0, 21);
+        assert!(width[0].1.contains("entries 8 to 11 are never addressed"));
+        assert_eq!(width[1].0, 22);
+        assert!(width[1].1.contains("wraps at 15"));
+
+        let flags = fifo_flag_incomplete(&input, &constructs);
+        assert_eq!(flags.len(), 1);
+        assert_eq!(flags[0].line, 42);
+
+        assert_eq!(fifo_pointer_not_gray(&input, &constructs).len(), 2);
+        input.processes[0].assignments.push(assign(
+            "wptr_gray",
+            "wptr xor shift_right(wptr, 1)",
+            33,
+        ));
+        let gray = fifo_pointer_not_gray(&input, &constructs);
+        assert_eq!(gray.len(), 1);
+        assert!(gray[0].message.contains("'rptr' crosses from rclk to wclk"));
+    }
+
+    #[test]
+    fn bus_interfaces_require_their_protocol_checks() {
+        let mut input = Input {
+            architectures: vec![Architecture {
+                name: "rtl".to_string(),
+                entity_name: "sink".to_string(),
+                file: "sink.vhd".to_string(),
+                line: 8,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        for (name, direction) in [("s_tvalid", "in"), ("s_tready", "out"), ("s_tdata", "in")] {
+            input.ports.push(Port {
+                name: name.to_string(),
+                direction: direction.to_string(),
+                in_entity: "sink".to_string(),
+                ..Default::default()
+            });
+        }
+        let analysis = analyze(&input);
+        let task = &analysis.missing_checks[0];
+        assert_eq!(task.bindings["tvalid"], "s_tvalid");
+        assert_eq!(
+            task.missing_ids,
+            [
+                "axis.stable_while_stalled",
+                "axis.valid_known",
+                "cover.axis.transfer"
+            ]
+        );
+    }
+
+    #[test]
+    fn coverage_maps_checks_and_assertion_density() {
+        let mut input = Input {
+            architectures: vec![Architecture {
+                name: "rtl".to_string(),
+                entity_name: "sink".to_string(),
+                file: "sink.vhd".to_string(),
+                line: 8,
+                ..Default::default()
+            }],
+            entities: vec![Entity {
+                name: "next_unit".to_string(),
+                file: "sink.vhd".to_string(),
+                line: 58,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        for (name, direction) in [("s_tvalid", "in"), ("s_tready", "out"), ("s_tdata", "in")] {
+            input.ports.push(Port {
+                name: name.to_string(),
+                direction: direction.to_string(),
+                in_entity: "sink".to_string(),
+                ..Default::default()
+            });
+        }
+        input.assertions.push(Assertion {
+            kind: "assert".to_string(),
+            file: "sink.vhd".to_string(),
+            line: 30,
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        });
+        input.verification_tags.push(VerificationTag {
+            id: "axis.valid_known".to_string(),
+            scope: "arch:rtl".to_string(),
+            bindings: [("tvalid".to_string(), "s_tvalid".to_string())].into(),
+            file: "sink.vhd".to_string(),
+            line: 40,
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        });
+
+        let analysis = analyze(&input);
+        let coverage = &analysis.coverage;
+        let density = &coverage.architectures[0];
+        assert_eq!(
+            (density.lines, density.assertions, density.tags),
+            (50, 1, 1)
+        );
+        assert_eq!(density.per_100_lines, 4.0);
+        assert_eq!(coverage.constructs[0].kind, "axi_stream");
+        assert_eq!(coverage.constructs[0].satisfied, ["axis.valid_known"]);
+        assert_eq!(
+            coverage.constructs[0].missing,
+            ["axis.stable_while_stalled", "cover.axis.transfer"]
+        );
+        let kind = &coverage.kinds[0];
+        assert_eq!((kind.constructs, kind.required, kind.satisfied), (1, 3, 1));
+        assert!(!analysis
+            .violations
+            .iter()
+            .any(|v| v.rule == "low_assertion_density"));
+
+        input.lint_config.assertion_density = 5.0;
+        let low: Vec<_> = analyze(&input)
+            .violations
+            .into_iter()
+            .filter(|v| v.rule == "low_assertion_density")
+            .collect();
+        assert_eq!(low.len(), 1);
+        assert_eq!(low[0].line, 8);
+        assert!(low[0]
+            .message
+            .contains("4.00 assertions per 100 lines (2 in 50 lines), below 5.00"));
+    }
+
+    #[test]
+    fn ready_valid_names_resolve_same_direction_ports() {
+        let input = handshake(&[("up_rdy", ""), ("up_valid", "")]);
+        let (constructs, ambiguous) = detect_ready_valid_constructs(&input);
+        assert!(ambiguous.is_empty());
+        assert_eq!(constructs.len(), 1);
+        assert_eq!(constructs[0].bindings["valid"], "up_valid");
+        assert_eq!(constructs[0].bindings["ready"], "up_rdy");
+        assert_eq!(constructs[0].confidence, Some(0.8));
+    }
+
+    #[test]
+    fn weak_name_evidence_stays_ambiguous_with_score() {
+        let mut input = handshake(&[("a_in", ""), ("b_in", "sink ready")]);
+        let (constructs, ambiguous) = detect_ready_valid_constructs(&input);
+        assert!(constructs.is_empty());
+        assert_eq!(ambiguous.len(), 1);
+        assert_eq!(ambiguous[0].confidence, 0.2);
+        assert_eq!(ambiguous[0].best_guess["ready"], "b_in");
+        let warnings = ambiguous_construct_warnings(&ambiguous);
+        assert!(warnings[0].message.ends_with("name confidence 0.20)"));
+
+        input.lint_config.binding_confidence = 0.2;
+        let (constructs, _) = detect_ready_valid_constructs(&input);
+        assert_eq!(constructs[0].bindings["valid"], "a_in");
+
+        let mut input = handshake(&[("a_in", ""), ("b_in", "")]);
+        input
+            .lint_config
+            .binding_patterns
+            .insert("ready".to_string(), vec!["b_*".to_string()]);
+        input.lint_config.binding_confidence = 0.4;
+        let (constructs, _) = detect_ready_valid_constructs(&input);
+        assert_eq!(constructs[0].bindings["ready"], "b_in");
+    }
+
+    #[test]
+    fn constructs_lists_detections_in_source_order() {
+        let mut input = handshake(&[("up_valid", ""), ("up_ready", "")]);
+        input.concurrent_assignments.push(ConcurrentAssignment {
+            read_signals: vec!["a_in".to_string(), "up_valid".to_string()],
+            line: 4,
+            ..input.concurrent_assignments[0].clone()
+        });
+        input.ports.push(Port {
+            name: "a_in".to_string(),
+            direction: "in".to_string(),
+            r#type: "std_logic".to_string(),
+            ..Default::default()
+        });
+        let inventory = constructs(&input);
+        assert_eq!(inventory.constructs.len(), 1);
+        let rv = &inventory.constructs[0];
+        assert_eq!(
+            (rv.kind.as_str(), rv.scope.as_str(), rv.line),
+            ("ready_valid", "arch:rtl", 12)
+        );
+        assert_eq!(
+            serde_json::to_value(&rv.bindings).unwrap(),
+            serde_json::json!({"ready": "up_ready", "valid": "up_valid"})
+        );
+        assert_eq!(inventory.ambiguous_constructs.len(), 1);
+        assert_eq!(inventory.ambiguous_constructs[0].line, 4);
+    }
+
+    #[test]
+    fn psl_directives_satisfy_registry_checks() {
+        let psl = |kind: &str, label: &str, line: usize| PslDirective {
+            kind: kind.to_string(),
+            label: label.to_string(),
+            file: "rv.vhd".to_string(),
+            line,
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        };
+        let mut input = handshake(&[("up_valid", ""), ("up_ready", "")]);
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "rv".to_string(),
+            file: "rv.vhd".to_string(),
+            line: 8,
+            ..Default::default()
+        });
+        input.verification_tags.push(VerificationTag {
+            id: "rv.stable_while_stalled".to_string(),
+            scope: "arch:rtl".to_string(),
+            bindings: [("valid", "up_valid"), ("ready", "up_ready")]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            file: "rv.vhd".to_string(),
+            line: 20,
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        });
+        input
+            .psl_directives
+            .push(psl("assert", "rv_eventual_progress_bounded", 22));
+        input
+            .lint_config
+            .rules
+            .insert("psl_assert_without_cover".to_string(), "info".to_string());
+
+        let rules = |input: &Input| -> Vec<String> {
+            analyze(input)
+                .violations
+                .into_iter()
+                .map(|v| format!("{}:{}", v.rule, v.message))
+                .collect()
+        };
+        let before = rules(&input);
+        assert!(before
+            .iter()
+            .any(|v| v.starts_with("missing_cover_companion:")));
+        assert!(before
+            .iter()
+            .any(|v| v.starts_with("psl_assert_without_cover:")));
+        assert!(before.iter().any(|v| v.contains("'cover.rv.handshake'")));
+
+        input
+            .psl_directives
+            .push(psl("cover", "cover_rv_handshake", 24));
+        input
+            .psl_directives
+            .push(psl("cover", "rv_stable_while_stalled", 26));
+        let after = rules(&input);
+        assert!(!after
+            .iter()
+            .any(|v| v.starts_with("missing_cover_companion:")
+                || v.starts_with("psl_assert_without_cover:")
+                || v.contains("'cover.rv.handshake'")));
+        assert!(after.contains(
+            &"psl_check_kind_mismatch:PSL cover 'rv_stable_while_stalled' is labelled as check 'rv.stable_while_stalled', which needs an assert or assume directive"
+                .to_string()
+        ));
     }
 }
@@ -0,0 +1,247 @@
+//! Inline waivers. A `--@waive rule=<rule>[,<rule>...] [expires=YYYY-MM-DD]`
+//! comment drops those rules' violations on the line it trails, or on the
+//! next line of code when it stands alone; other `key=value` tokens and free
+//! text (a reason) are ignored. `engine::filter_violations` applies unexpired
+//! waivers and counts what they drop in `Result.suppressions`. Past its
+//! `expires` date a waiver stops applying and is itself reported as
+//! `expired_waiver`, so temporary exceptions can't quietly become permanent.
+
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::policy::input::{Input, Waiver};
+use crate::policy::result::Violation;
+
+pub const TAG: &str = "--@waive";
+
+pub fn violations(input: &Input) -> Vec<Violation> {
+    expired_waiver(input)
+}
+
+pub fn reads() -> &'static [&'static str] {
+    &["waivers"]
+}
+
+/// The waivers in one file's text, with their target lines resolved.
+pub fn scan(text: &str, file: &str) -> Vec<Waiver> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut out = Vec::new();
+    for (idx, line) in lines.iter().enumerate() {
+        let Some(pos) = line.find(TAG) else {
+            continue;
+        };
+        let Some(mut waiver) = parse(&line[pos..]) else {
+            continue;
+        };
+        let before = line[..pos].trim();
+        waiver.target_line = if !before.is_empty() && !before.starts_with("--") {
+            idx + 1
+        } else {
+            lines[idx + 1..]
+                .iter()
+                .position(|next| {
+                    let next = next.trim();
+                    !next.is_empty() && !next.starts_with("--")
+                })
+                .map_or(0, |offset| idx + offset + 2)
+        };
+        waiver.file = file.to_string();
+        waiver.line = idx + 1;
+        waiver.raw = line.trim().to_string();
+        out.push(waiver);
+    }
+    out
+}
+
+/// Parses a waiver comment starting at `--@waive`. `None` when it names no
+/// rule.
+pub fn parse(comment: &str) -> Option<Waiver> {
+    let rest = comment.trim().strip_prefix(TAG)?;
+    let mut waiver = Waiver::default();
+    for token in rest.split_whitespace() {
+        let Some((key, value)) = token.split_once('=') else {
+            continue;
+        };
+        match key.to_ascii_lowercase().as_str() {
+            "rule" | "rules" => waiver.rules.extend(
+                value
+                    .split(',')
+                    .map(|rule| rule.trim().to_ascii_lowercase())
+                    .filter(|rule| !rule.is_empty()),
+            ),
+            "expires" => waiver.expires = value.to_string(),
+            _ => {}
+        }
+    }
+    (!waiver.rules.is_empty()).then_some(waiver)
+}
+
+/// Unexpired waivers, by file and target line.
+pub struct Active<'a> {
+    by_line: HashMap<(&'a str, usize), Vec<&'a str>>,
+}
+
+impl<'a> Active<'a> {
+    pub fn new(input: &'a Input) -> Self {
+        let today = today(input);
+        let mut by_line: HashMap<(&str, usize), Vec<&str>> = HashMap::new();
+        for waiver in &input.waivers {
+            if waiver.target_line == 0 || is_expired(waiver, &today) {
+                continue;
+            }
+            by_line
+                .entry((waiver.file.as_str(), waiver.target_line))
+                .or_default()
+                .extend(waiver.rules.iter().map(String::as_str));
+        }
+        Active { by_line }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_line.is_empty()
+    }
+
+    pub fn waives(&self, v: &Violation) -> bool {
+        self.by_line
+            .get(&(v.file.as_str(), v.line))
+            .is_some_and(|rules| rules.iter().any(|rule| *rule == v.rule))
+    }
+}
+
+/// `lint_config.today`, or the current UTC date, as `YYYY-MM-DD`.
+pub fn today(input: &Input) -> String {
+    if !input.lint_config.today.is_empty() {
+        return input.lint_config.today.clone();
+    }
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Whether `today` is past the waiver's `expires` date. A malformed date
+/// counts as expired, so a typo can't make a waiver permanent.
+pub fn is_expired(waiver: &Waiver, today: &str) -> bool {
+    if waiver.expires.is_empty() {
+        return false;
+    }
+    match (parse_date(&waiver.expires), parse_date(today)) {
+        (Some(expires), Some(today)) => today > expires,
+        _ => true,
+    }
+}
+
+fn expired_waiver(input: &Input) -> Vec<Violation> {
+    let today = today(input);
+    input
+        .waivers
+        .iter()
+        .filter(|waiver| is_expired(waiver, &today))
+        .map(|waiver| {
+            let rules = waiver.rules.join(", ");
+            let message = if parse_date(&waiver.expires).is_some() {
+                format!(
+                    "Waiver for '{}' expired on {} - fix the violation or renew the waiver",
+                    rules, waiver.expires
+                )
+            } else {
+                format!(
+                    "Waiver for '{}' has an invalid expires date '{}' (expected YYYY-MM-DD)",
+                    rules, waiver.expires
+                )
+            };
+            Violation {
+                rule: "expired_waiver".to_string(),
+                severity: "warning".to_string(),
+                file: waiver.file.clone(),
+                line: waiver.line,
+                message,
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn parse_date(text: &str) -> Option<(i64, u32, u32)> {
+    let mut parts = text.trim().splitn(3, '-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: u32 = parts.next()?.parse().ok()?;
+    let day: u32 = parts.next()?.parse().ok()?;
+    ((1..=12).contains(&month) && (1..=31).contains(&day)).then_some((year, month, day))
+}
+
+/// Proleptic Gregorian date of a day count since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "\
+architecture rtl of top is
+  --@waive rule=unused_signal expires=2026-06-30 reason=spare
+  -- spare for the next revision
+  signal spare : std_logic;
+  signal dbg : std_logic;  --@waive rules=unused_signal,wide_signal
+  --@waive expires=2026-06-30
+begin
+end rtl;
+";
+
+    #[test]
+    fn scan_resolves_target_lines() {
+        let waivers = scan(TEXT, "top.vhd");
+        assert_eq!(waivers.len(), 2);
+        assert_eq!(waivers[0].rules, ["unused_signal"]);
+        assert_eq!(waivers[0].expires, "2026-06-30");
+        assert_eq!((waivers[0].line, waivers[0].target_line), (2, 4));
+        assert_eq!(waivers[1].rules, ["unused_signal", "wide_signal"]);
+        assert_eq!((waivers[1].line, waivers[1].target_line), (5, 5));
+    }
+
+    #[test]
+    fn expired_waivers_stop_applying_and_are_reported() {
+        let mut input = Input {
+            waivers: scan(TEXT, "top.vhd"),
+            ..Default::default()
+        };
+        let unused = Violation {
+            rule: "unused_signal".to_string(),
+            file: "top.vhd".to_string(),
+            line: 4,
+            ..Default::default()
+        };
+        input.lint_config.today = "2026-06-30".to_string();
+        assert!(Active::new(&input).waives(&unused));
+        assert!(expired_waiver(&input).is_empty());
+
+        input.lint_config.today = "2026-07-01".to_string();
+        assert!(!Active::new(&input).waives(&unused));
+        let v = expired_waiver(&input);
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[0].line, 2);
+        assert!(v[0].message.contains("expired on 2026-06-30"));
+
+        input.waivers[0].expires = "June".to_string();
+        assert!(expired_waiver(&input)[0].message.contains("invalid"));
+    }
+
+    #[test]
+    fn civil_dates_from_day_counts() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+    }
+}
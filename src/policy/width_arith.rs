@@ -0,0 +1,330 @@
+//! Width checks on the arithmetic in processes: sums that lose their carry,
+//! products assigned without `resize`, and operands mixing signed and
+//! unsigned. Widths come from the folded ranges of the operands' and
+//! target's declarations (signals of the architecture and ports of its
+//! entity); anything that doesn't fold is skipped.
+
+use crate::policy::elaboration::{self, Generics};
+use crate::policy::helpers::{is_signed_type, is_unsigned_type};
+use crate::policy::input::{ArithmeticOp, Input};
+use crate::policy::result::Violation;
+use std::collections::HashMap;
+
+pub fn violations(input: &Input) -> Vec<Violation> {
+    let mut decls = Decls::new(input);
+    let mut out = Vec::new();
+    for op in &input.arithmetic_ops {
+        out.extend(arith_overflow(input, &mut decls, op));
+        out.extend(multiplication_without_resize(input, &mut decls, op));
+        out.extend(arith_mixed_signedness(&mut decls, op));
+    }
+    out
+}
+
+pub fn reads() -> &'static [&'static str] {
+    &[
+        "architectures",
+        "arithmetic_ops",
+        "entities",
+        "ports",
+        "processes",
+        "signals",
+    ]
+}
+
+/// A signal or port as seen from an architecture.
+#[derive(Clone, Copy)]
+struct Decl<'a> {
+    r#type: &'a str,
+    /// Folded range width, `None` for scalars and unfoldable ranges.
+    width: Option<usize>,
+}
+
+/// Declarations by architecture, resolved on first use.
+struct Decls<'a> {
+    input: &'a Input,
+    scopes: HashMap<String, (Generics, HashMap<String, &'a str>)>,
+}
+
+impl<'a> Decls<'a> {
+    fn new(input: &'a Input) -> Self {
+        Decls {
+            input,
+            scopes: HashMap::new(),
+        }
+    }
+
+    /// `name` declared in the architecture of `in_arch` (`arch[.generate...]`).
+    fn get(&mut self, in_arch: &str, name: &str) -> Option<Decl<'a>> {
+        let arch = in_arch.split('.').next().unwrap_or("").to_ascii_lowercase();
+        let input = self.input;
+        let (generics, types) = self
+            .scopes
+            .entry(arch)
+            .or_insert_with_key(|arch| scope(input, arch));
+        let r#type = *types.get(&name.to_ascii_lowercase())?;
+        Some(Decl {
+            r#type,
+            width: elaboration::range_width(r#type, generics),
+        })
+    }
+}
+
+/// Constants visible in `arch` and the types of its signals and its entity's
+/// ports, keyed by lowercase name.
+fn scope<'a>(input: &'a Input, arch: &str) -> (Generics, HashMap<String, &'a str>) {
+    let mut types = HashMap::new();
+    let entity = input
+        .architectures
+        .iter()
+        .find(|a| a.name.eq_ignore_ascii_case(arch))
+        .map(|a| a.entity_name.as_str());
+    if let Some(entity) = entity {
+        for port in &input.ports {
+            if port.in_entity.eq_ignore_ascii_case(entity) {
+                types.insert(port.name.to_ascii_lowercase(), port.r#type.as_str());
+            }
+        }
+    }
+    for sig in &input.signals {
+        if sig.in_entity.eq_ignore_ascii_case(arch) {
+            types.insert(sig.name.to_ascii_lowercase(), sig.r#type.as_str());
+        }
+    }
+    (elaboration::architecture_scope(input, arch), types)
+}
+
+/// Value of the assignment to `op.result` the operation is part of; empty
+/// when it isn't a plain assignment.
+fn assigned_value<'a>(input: &'a Input, op: &ArithmeticOp) -> &'a str {
+    input
+        .processes
+        .iter()
+        .filter(|proc| proc.file == op.file && proc.label == op.in_process)
+        .flat_map(|proc| &proc.assignments)
+        .filter(|a| a.line <= op.line && a.signal.eq_ignore_ascii_case(&op.result))
+        .max_by_key(|a| a.line)
+        .map_or("", |a| a.value.as_str())
+}
+
+fn resizes(value: &str) -> bool {
+    value.to_ascii_lowercase().contains("resize")
+}
+
+/// Widths of the two operands and the target, when all three fold.
+fn widths(decls: &mut Decls, op: &ArithmeticOp) -> Option<(usize, usize, usize)> {
+    let [a, b] = op.operands.as_slice() else {
+        return None;
+    };
+    if op.result.is_empty() {
+        return None;
+    }
+    Some((
+        decls.get(&op.in_arch, a)?.width?,
+        decls.get(&op.in_arch, b)?.width?,
+        decls.get(&op.in_arch, &op.result)?.width?,
+    ))
+}
+
+/// `sum <= a + b` with `sum` no wider than the widest operand: numeric_std
+/// returns that width, so the carry (or borrow) is dropped. Widening an
+/// operand (`resize`, `'0' & a`) keeps it.
+fn arith_overflow(input: &Input, decls: &mut Decls, op: &ArithmeticOp) -> Option<Violation> {
+    if op.operator != "+" && op.operator != "-" {
+        return None;
+    }
+    let (wa, wb, target) = widths(decls, op)?;
+    let needed = wa.max(wb) + 1;
+    let value = assigned_value(input, op);
+    if target >= needed || resizes(value) || value.contains('&') {
+        return None;
+    }
+    Some(Violation {
+        rule: "arith_overflow".to_string(),
+        severity: "warning".to_string(),
+        file: op.file.clone(),
+        line: op.line,
+        message: format!(
+            "'{}' {} '{}' needs {} bits but '{}' is {} bits - the carry is dropped; resize an operand to keep it",
+            op.operands[0], op.operator, op.operands[1], needed, op.result, target
+        ),
+        ..Default::default()
+    })
+}
+
+/// `p <= a * b` where `p` isn't `a'length + b'length` bits: numeric_std's
+/// product has exactly that width, so the assignment fails or truncates
+/// unless `resize` picks the bits.
+fn multiplication_without_resize(
+    input: &Input,
+    decls: &mut Decls,
+    op: &ArithmeticOp,
+) -> Option<Violation> {
+    if op.operator != "*" {
+        return None;
+    }
+    let (wa, wb, target) = widths(decls, op)?;
+    if target == wa + wb || resizes(assigned_value(input, op)) {
+        return None;
+    }
+    Some(Violation {
+        rule: "multiplication_without_resize".to_string(),
+        severity: "warning".to_string(),
+        file: op.file.clone(),
+        line: op.line,
+        message: format!(
+            "Product of '{}' ({} bits) and '{}' ({} bits) is {} bits but '{}' is {} bits - resize the product explicitly",
+            op.operands[0],
+            wa,
+            op.operands[1],
+            wb,
+            wa + wb,
+            op.result,
+            target
+        ),
+        ..Default::default()
+    })
+}
+
+fn arith_mixed_signedness(decls: &mut Decls, op: &ArithmeticOp) -> Option<Violation> {
+    let [a, b] = op.operands.as_slice() else {
+        return None;
+    };
+    let (ta, tb) = (
+        decls.get(&op.in_arch, a)?.r#type,
+        decls.get(&op.in_arch, b)?.r#type,
+    );
+    let mixed = (is_signed_type(ta) && is_unsigned_type(tb))
+        || (is_unsigned_type(ta) && is_signed_type(tb));
+    mixed.then(|| Violation {
+        rule: "arith_mixed_signedness".to_string(),
+        severity: "warning".to_string(),
+        file: op.file.clone(),
+        line: op.line,
+        message: format!(
+            "'{}' {} '{}' mixes {} and {} operands - convert one explicitly",
+            a,
+            op.operator,
+            b,
+            if is_signed_type(ta) {
+                "signed"
+            } else {
+                "unsigned"
+            },
+            if is_signed_type(ta) {
+                "unsigned"
+            } else {
+                "signed"
+            }
+        ),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::input::{Architecture, Port, Process, SequentialAssignment, Signal};
+
+    fn input(decls: &[(&str, &str)], op: &str, operands: [&str; 2], value: &str) -> Input {
+        let mut input = Input::default();
+        input.architectures.push(Architecture {
+            name: "rtl".to_string(),
+            entity_name: "top".to_string(),
+            file: "a.vhd".to_string(),
+            line: 1,
+            ..Default::default()
+        });
+        input.ports.push(Port {
+            name: "a".to_string(),
+            r#type: "unsigned(7 downto 0)".to_string(),
+            in_entity: "top".to_string(),
+            ..Default::default()
+        });
+        for (name, r#type) in decls {
+            input.signals.push(Signal {
+                name: name.to_string(),
+                r#type: r#type.to_string(),
+                in_entity: "rtl".to_string(),
+                file: "a.vhd".to_string(),
+                ..Default::default()
+            });
+        }
+        input.processes.push(Process {
+            label: "p".to_string(),
+            file: "a.vhd".to_string(),
+            assignments: vec![SequentialAssignment {
+                target: "y".to_string(),
+                signal: "y".to_string(),
+                value: value.to_string(),
+                line: 10,
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        input.arithmetic_ops.push(ArithmeticOp {
+            operator: op.to_string(),
+            operands: operands.iter().map(|o| o.to_string()).collect(),
+            result: "y".to_string(),
+            file: "a.vhd".to_string(),
+            line: 10,
+            in_process: "p".to_string(),
+            in_arch: "rtl".to_string(),
+            ..Default::default()
+        });
+        input
+    }
+
+    fn rules(input: &Input) -> Vec<(String, String)> {
+        violations(input)
+            .into_iter()
+            .map(|v| (v.rule, v.message))
+            .collect()
+    }
+
+    #[test]
+    fn sums_into_the_operand_width_drop_the_carry() {
+        let decls = [("b", "unsigned(7 downto 0)"), ("y", "unsigned(7 downto 0)")];
+        assert_eq!(
+            rules(&input(&decls, "+", ["a", "b"], "a + b")),
+            [(
+                "arith_overflow".to_string(),
+                "'a' + 'b' needs 9 bits but 'y' is 8 bits - the carry is dropped; resize an operand to keep it".to_string()
+            )]
+        );
+        assert!(rules(&input(&decls, "+", ["a", "b"], "resize(a, 8) + b")).is_empty());
+
+        let wide = [("b", "unsigned(7 downto 0)"), ("y", "unsigned(8 downto 0)")];
+        assert!(rules(&input(&wide, "+", ["a", "b"], "('0' & a) + b")).is_empty());
+    }
+
+    #[test]
+    fn products_need_the_full_width_or_a_resize() {
+        let decls = [
+            ("b", "unsigned(WIDTH-1 downto 0)"),
+            ("y", "unsigned(7 downto 0)"),
+        ];
+        // WIDTH doesn't fold: nothing to compare.
+        assert!(rules(&input(&decls, "*", ["a", "b"], "a * b")).is_empty());
+
+        let decls = [("b", "unsigned(3 downto 0)"), ("y", "unsigned(7 downto 0)")];
+        let v = rules(&input(&decls, "*", ["a", "b"], "a * b"));
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[0].0, "multiplication_without_resize");
+        assert!(v[0].1.contains("is 12 bits but 'y' is 8 bits"));
+        assert!(rules(&input(&decls, "*", ["a", "b"], "resize(a * b, 8)")).is_empty());
+    }
+
+    #[test]
+    fn mixed_signedness_names_both_operands() {
+        let decls = [("b", "signed(8 downto 0)"), ("y", "signed(9 downto 0)")];
+        let v = rules(&input(&decls, "-", ["a", "b"], "a - b"));
+        assert_eq!(
+            v,
+            [(
+                "arith_mixed_signedness".to_string(),
+                "'a' - 'b' mixes unsigned and signed operands - convert one explicitly".to_string()
+            )]
+        );
+    }
+}
@@ -10,3 +10,10 @@ package misc_2019 is
   attribute foo : integer;
   attribute foo of g1 : group is 1;
 end package;
+
+entity view_user is
+  port (
+    clk  : in bit;
+    link : view v1 of rec_t
+  );
+end entity;
@@ -0,0 +1,40 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity assoc_child is
+  port (
+    a_i : in std_logic;
+    b_i : in std_logic;
+    y_o : out std_logic
+  );
+end entity assoc_child;
+
+architecture rtl of assoc_child is
+begin
+  y_o <= a_i and b_i;
+end architecture rtl;
+
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity association_rules is
+  port (
+    a_i  : in std_logic;
+    b_i  : in std_logic;
+    y0_o : out std_logic;
+    y1_o : out std_logic
+  );
+end entity association_rules;
+
+architecture rtl of association_rules is
+begin
+  -- Positional then named in the same port map
+  u_mixed : entity work.assoc_child
+    port map (a_i, b_i, y_o => y0_o);
+
+  -- Two positional actuals for three ports
+  u_short : entity work.assoc_child
+    port map (a_i, b_i);
+
+  y1_o <= a_i;
+end architecture rtl;
@@ -0,0 +1,36 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity bit_order_child is
+  port (
+    data_i : in std_logic_vector(7 downto 0);
+    data_o : out std_logic_vector(7 downto 0)
+  );
+end entity bit_order_child;
+
+architecture rtl of bit_order_child is
+begin
+  data_o <= data_i;
+end architecture rtl;
+
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity bit_order_rules is
+  port (
+    data_i : in std_logic_vector(7 downto 0);
+    data_o : out std_logic_vector(7 downto 0)
+  );
+end entity bit_order_rules;
+
+architecture rtl of bit_order_rules is
+  signal bus_s : std_logic_vector(0 to 7);
+begin
+  bus_s <= data_i;
+
+  u_child : entity work.bit_order_child
+    port map (
+      data_i => bus_s,
+      data_o => data_o
+    );
+end architecture rtl;
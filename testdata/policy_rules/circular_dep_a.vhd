@@ -0,0 +1,7 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use work.cycle_b_pkg.all;
+
+package cycle_a_pkg is
+  constant A_WIDTH : integer := B_WIDTH * 2;
+end cycle_a_pkg;
@@ -0,0 +1,22 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+package cycle_b_pkg is
+  constant B_WIDTH : integer := 4;
+end cycle_b_pkg;
+
+library ieee;
+use ieee.std_logic_1164.all;
+use work.cycle_a_pkg.all;
+
+entity cycle_user is
+  port (
+    a : in std_logic_vector(A_WIDTH - 1 downto 0);
+    y : out std_logic_vector(A_WIDTH - 1 downto 0)
+  );
+end cycle_user;
+
+architecture rtl of cycle_user is
+begin
+  y <= a;
+end rtl;
@@ -0,0 +1,31 @@
+-- fixture clock: clk_core domain core
+-- fixture clock: clk_core_div2 domain core
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity clean_clock_decl_rules is
+  port (
+    clk_core      : in  std_logic;
+    clk_core_div2 : in  std_logic;
+    d             : in  std_logic;
+    q             : out std_logic
+  );
+end entity clean_clock_decl_rules;
+
+architecture rtl of clean_clock_decl_rules is
+  signal stage : std_logic;
+begin
+  fast_p : process(clk_core)
+  begin
+    if rising_edge(clk_core) then
+      stage <= d;
+    end if;
+  end process fast_p;
+
+  slow_p : process(clk_core_div2)
+  begin
+    if rising_edge(clk_core_div2) then
+      q <= stage;
+    end if;
+  end process slow_p;
+end architecture rtl;
@@ -12,3 +12,34 @@ end architecture rtl;
 
 configuration cfg_clean of clean_configurations_rules is
 end configuration cfg_clean;
+
+architecture sim of clean_configurations_rules is
+begin
+  data_o <= not data_i;
+end architecture sim;
+
+entity clean_configurations_top is
+  port (
+    data_i : in bit;
+    data_o : out bit
+  );
+end entity clean_configurations_top;
+
+architecture rtl of clean_configurations_top is
+  signal data_rtl : bit;
+  signal data_sim : bit;
+begin
+  u_rtl : entity work.clean_configurations_rules(rtl)
+    port map (
+      data_i => data_i,
+      data_o => data_rtl
+    );
+
+  u_sim : entity work.clean_configurations_rules(sim)
+    port map (
+      data_i => data_i,
+      data_o => data_sim
+    );
+
+  data_o <= data_rtl and data_sim;
+end architecture rtl;
@@ -0,0 +1,66 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity clean_generics_leaf is
+  generic (
+    G_WIDTH    : natural := 8;
+    G_USE_PIPE : boolean := false
+  );
+  port (
+    clk    : in std_logic;
+    data_i : in std_logic_vector(G_WIDTH - 1 downto 0);
+    data_o : out std_logic_vector(G_WIDTH - 1 downto 0)
+  );
+end entity clean_generics_leaf;
+
+architecture rtl of clean_generics_leaf is
+begin
+  gen_pipe : if G_USE_PIPE generate
+    process(clk)
+    begin
+      if rising_edge(clk) then
+        data_o <= data_i;
+      end if;
+    end process;
+  end generate gen_pipe;
+
+  gen_comb : if not G_USE_PIPE generate
+    data_o <= data_i;
+  end generate gen_comb;
+end architecture rtl;
+
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity clean_generics_rules is
+  port (
+    clk    : in std_logic;
+    data_i : in std_logic_vector(7 downto 0);
+    data_o : out std_logic_vector(7 downto 0)
+  );
+end entity clean_generics_rules;
+
+architecture rtl of clean_generics_rules is
+  component clean_generics_leaf is
+    generic (
+      G_WIDTH    : natural := 8;
+      G_USE_PIPE : boolean := false
+    );
+    port (
+      clk    : in std_logic;
+      data_i : in std_logic_vector(G_WIDTH - 1 downto 0);
+      data_o : out std_logic_vector(G_WIDTH - 1 downto 0)
+    );
+  end component clean_generics_leaf;
+begin
+  u_leaf : entity work.clean_generics_leaf
+    generic map (
+      G_WIDTH    => 8,
+      G_USE_PIPE => true
+    )
+    port map (
+      clk    => clk,
+      data_i => data_i,
+      data_o => data_o
+    );
+end architecture rtl;
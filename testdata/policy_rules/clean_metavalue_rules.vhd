@@ -0,0 +1,29 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity clean_metavalue_rules is
+  port (
+    clk_i    : in  std_logic;
+    opcode_i : in  std_logic_vector(3 downto 0);
+    load_o   : out std_logic;
+    store_o  : out std_logic
+  );
+end entity clean_metavalue_rules;
+
+architecture rtl of clean_metavalue_rules is
+begin
+  decode_p : process(clk_i)
+  begin
+    if rising_edge(clk_i) then
+      load_o  <= '0';
+      store_o <= '0';
+      if std_match(opcode_i, "10-1") then
+        load_o <= '1';
+      end if;
+      if opcode_i = x"A" then
+        store_o <= '1';
+      end if;
+    end if;
+  end process decode_p;
+end architecture rtl;
@@ -0,0 +1,39 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity clean_rdc_rules is
+  port (
+    clk_i : in  std_logic;
+    rst_n : in  std_logic;
+    d     : in  std_logic;
+    q     : out std_logic
+  );
+end clean_rdc_rules;
+
+architecture rtl of clean_rdc_rules is
+  signal rst_meta_n : std_logic;
+  signal rst_sync_n : std_logic;
+  signal q_r        : std_logic;
+begin
+  rst_sync_p: process(clk_i, rst_n)
+  begin
+    if rst_n = '0' then
+      rst_meta_n <= '0';
+      rst_sync_n <= '0';
+    elsif rising_edge(clk_i) then
+      rst_meta_n <= '1';
+      rst_sync_n <= rst_meta_n;
+    end if;
+  end process;
+
+  data_p: process(clk_i, rst_sync_n)
+  begin
+    if rst_sync_n = '0' then
+      q_r <= '0';
+    elsif rising_edge(clk_i) then
+      q_r <= d;
+    end if;
+  end process;
+
+  q <= q_r;
+end rtl;
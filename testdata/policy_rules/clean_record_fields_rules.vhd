@@ -0,0 +1,31 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity clean_record_fields_rules is
+  port (
+    clk    : in  std_logic;
+    data_i : in  std_logic_vector(7 downto 0);
+    data_o : out std_logic_vector(7 downto 0)
+  );
+end entity clean_record_fields_rules;
+
+architecture rtl of clean_record_fields_rules is
+  type t_bus is record
+    valid : std_logic;
+    data  : std_logic_vector(7 downto 0);
+  end record;
+
+  signal bus_s : t_bus;
+begin
+  bus_s.valid <= '1';
+  bus_s.data  <= data_i;
+
+  process (clk)
+  begin
+    if rising_edge(clk) then
+      if bus_s.valid = '1' then
+        data_o <= bus_s.data;
+      end if;
+    end if;
+  end process;
+end architecture rtl;
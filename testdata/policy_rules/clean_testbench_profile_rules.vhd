@@ -0,0 +1,22 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity checked_tb is
+end checked_tb;
+
+architecture sim of checked_tb is
+  signal clk     : std_logic := '0';
+  signal running : std_logic := '1';
+  signal q       : std_logic := '0';
+begin
+  clk <= not clk after 5 ns when running = '1' else '0';
+
+  stim : process
+  begin
+    q <= '1';
+    wait for 20 ns;
+    assert q = '1' report "q stuck" severity error;
+    running <= '0';
+    wait;
+  end process;
+end sim;
@@ -0,0 +1,34 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity clean_timing_rules is
+  port (
+    clk : in  std_logic;
+    a   : in  std_logic;
+    b   : in  std_logic;
+    y   : out std_logic
+  );
+end entity clean_timing_rules;
+
+architecture rtl of clean_timing_rules is
+  signal s1, s2, s3, s4, s4_q, s5, s6, s7, s8, s9 : std_logic;
+begin
+  s1 <= a and b;
+  s2 <= s1 xor a;
+  s3 <= s2 or b;
+  s4 <= not s3;
+
+  stage_p : process(clk)
+  begin
+    if rising_edge(clk) then
+      s4_q <= s4;
+    end if;
+  end process stage_p;
+
+  s5 <= s4_q and a;
+  s6 <= s5 xor b;
+  s7 <= s6 or a;
+  s8 <= not s7;
+  s9 <= s8 and b;
+  y  <= s9 xor a;
+end architecture rtl;
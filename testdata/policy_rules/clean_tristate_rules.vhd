@@ -0,0 +1,43 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity clean_tristate_bus_if is
+  port (
+    oe_i   : in  std_logic;
+    data_i : in  std_logic_vector(7 downto 0);
+    oe_o   : out std_logic;
+    data_o : out std_logic_vector(7 downto 0)
+  );
+end entity clean_tristate_bus_if;
+
+architecture rtl of clean_tristate_bus_if is
+begin
+  oe_o   <= oe_i;
+  data_o <= data_i;
+end architecture rtl;
+
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity clean_tristate_rules is
+  port (
+    oe_i   : in    std_logic;
+    data_i : in    std_logic_vector(7 downto 0);
+    pad_io : inout std_logic_vector(7 downto 0)
+  );
+end entity clean_tristate_rules;
+
+architecture rtl of clean_tristate_rules is
+  signal oe_s   : std_logic;
+  signal data_s : std_logic_vector(7 downto 0);
+begin
+  bus_if_u : entity work.clean_tristate_bus_if
+    port map (
+      oe_i   => oe_i,
+      data_i => data_i,
+      oe_o   => oe_s,
+      data_o => data_s
+    );
+
+  pad_io <= data_s when oe_s = '1' else (others => 'Z');
+end architecture rtl;
@@ -2,8 +2,18 @@ library ieee;
 use ieee.std_logic_1164.all;
 use ieee.numeric_std.all;
 
+package clean_types_pkg is
+  type mode_t is (MODE_IDLE, MODE_RUN);
+end package clean_types_pkg;
+
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use work.clean_types_pkg.all;
+
 entity clean_types_rules is
   port (
+    mode_i : in mode_t;
     data_i : in unsigned(3 downto 0);
     data_o : out unsigned(3 downto 0)
   );
@@ -12,6 +22,6 @@ end entity clean_types_rules;
 architecture rtl of clean_types_rules is
   signal data_s : unsigned(3 downto 0);
 begin
-  data_s <= data_i;
+  data_s <= data_i when mode_i = MODE_RUN else (others => '0');
   data_o <= data_s;
 end architecture rtl;
@@ -0,0 +1,32 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+package clean_use_clause_pkg is
+  constant C_WIDTH : natural := 8;
+end package clean_use_clause_pkg;
+
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use work.clean_use_clause_pkg.all;
+
+entity clean_use_clause_rules is
+  port (
+    clk_i : in  std_logic;
+    d     : in  std_logic_vector(C_WIDTH - 1 downto 0);
+    q     : out std_logic_vector(C_WIDTH - 1 downto 0)
+  );
+end clean_use_clause_rules;
+
+architecture rtl of clean_use_clause_rules is
+  signal q_r : unsigned(C_WIDTH - 1 downto 0);
+begin
+  reg_p: process(clk_i)
+  begin
+    if rising_edge(clk_i) then
+      q_r <= unsigned(d) + 1;
+    end if;
+  end process;
+
+  q <= std_logic_vector(q_r);
+end rtl;
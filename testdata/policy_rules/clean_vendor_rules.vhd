@@ -0,0 +1,31 @@
+-- fixture vendor: xilinx
+library ieee;
+use ieee.std_logic_1164.all;
+
+library unisim;
+use unisim.vcomponents.all;
+
+entity clean_vendor_rules is
+  port (
+    clk_i : in  std_logic;
+    d_i   : in  std_logic;
+    q_o   : out std_logic
+  );
+end entity clean_vendor_rules;
+
+architecture rtl of clean_vendor_rules is
+  signal clk : std_logic;
+begin
+  u_bufg : BUFG
+    port map (
+      I => clk_i,
+      O => clk
+    );
+
+  q_p : process(clk)
+  begin
+    if rising_edge(clk) then
+      q_o <= d_i;
+    end if;
+  end process q_p;
+end architecture rtl;
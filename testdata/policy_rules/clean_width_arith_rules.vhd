@@ -0,0 +1,28 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity clean_width_arith_rules is
+  port (
+    clk   : in  std_logic;
+    a     : in  unsigned(7 downto 0);
+    b     : in  unsigned(7 downto 0);
+    c     : in  unsigned(3 downto 0);
+    s     : in  signed(7 downto 0);
+    sum_o : out unsigned(8 downto 0);
+    prd_o : out unsigned(11 downto 0);
+    mix_o : out signed(8 downto 0)
+  );
+end entity clean_width_arith_rules;
+
+architecture rtl of clean_width_arith_rules is
+begin
+  arith_p : process(clk)
+  begin
+    if rising_edge(clk) then
+      sum_o <= resize(a, 9) + b;
+      prd_o <= a * c;
+      mix_o <= resize(s, 9) + signed(resize(a, 9));
+    end if;
+  end process arith_p;
+end architecture rtl;
@@ -0,0 +1,31 @@
+-- fixture clock: sys_clk domain sys
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity clock_decl_rules is
+  port (
+    sys_clk : in  std_logic;
+    aux_clk : in  std_logic;
+    d       : in  std_logic;
+    q_sys   : out std_logic;
+    q_aux   : out std_logic
+  );
+end entity clock_decl_rules;
+
+architecture rtl of clock_decl_rules is
+begin
+  sys_p : process(sys_clk)
+  begin
+    if rising_edge(sys_clk) then
+      q_sys <= d;
+    end if;
+  end process sys_p;
+
+  -- unknown_clock_source: aux_clk is not a declared clock
+  aux_p : process(aux_clk)
+  begin
+    if rising_edge(aux_clk) then
+      q_aux <= d;
+    end if;
+  end process aux_p;
+end architecture rtl;
@@ -10,7 +10,8 @@ entity clocks_resets_rules is
     data_in : in std_logic;
     q1      : out std_logic;
     q2      : out std_logic;
-    q3      : out std_logic
+    q3      : out std_logic;
+    q4      : out std_logic
   );
 end clocks_resets_rules;
 
@@ -38,4 +39,6 @@ begin
       q3 <= data_in;
     end if;
   end process;
+
+  q4 <= clk_aux and data_in;
 end rtl;
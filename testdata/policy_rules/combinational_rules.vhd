@@ -1,3 +1,4 @@
+-- fixture standard: 2008 (long_sensitivity_list recommends process(all))
 library ieee;
 use ieee.std_logic_1164.all;
 
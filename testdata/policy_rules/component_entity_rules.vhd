@@ -0,0 +1,46 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity mismatch_leaf is
+  generic (
+    G_DEPTH : natural := 16
+  );
+  port (
+    clk  : in std_logic;
+    rst  : in std_logic;
+    dout : out std_logic
+  );
+end entity mismatch_leaf;
+
+architecture rtl of mismatch_leaf is
+begin
+  dout <= rst when G_DEPTH > 0 else clk;
+end architecture rtl;
+
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity component_entity_rules is
+  port (
+    clk  : in std_logic;
+    dout : out std_logic
+  );
+end entity component_entity_rules;
+
+architecture rtl of component_entity_rules is
+  component mismatch_leaf is
+    generic (
+      G_DEPTH : natural := 8
+    );
+    port (
+      clk  : in std_logic;
+      dout : inout std_logic
+    );
+  end component mismatch_leaf;
+begin
+  u_leaf : mismatch_leaf
+    port map (
+      clk  => clk,
+      dout => dout
+    );
+end architecture rtl;
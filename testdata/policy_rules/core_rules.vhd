@@ -18,7 +18,9 @@ entity core_with_inst is
   port (
     clk : in std_logic;
     a   : in std_logic;
-    y   : out std_logic
+    b   : in std_logic;
+    y   : out std_logic;
+    z   : out std_logic
   );
 end core_with_inst;
 
@@ -37,4 +39,13 @@ begin
       when '1' => y <= '1';
     end case;
   end process;
+
+  comb_if: process(a, b)
+  begin
+    if a = '1' then
+      z <= b;
+    elsif b = '1' then
+      null;
+    end if;
+  end process;
 end rtl;
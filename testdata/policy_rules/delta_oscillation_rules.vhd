@@ -0,0 +1,20 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity delta_oscillation_rules is
+end delta_oscillation_rules;
+
+architecture rtl of delta_oscillation_rules is
+  signal req : std_logic;
+  signal ack : std_logic;
+begin
+  p_req: process(ack)
+  begin
+    req <= not ack;
+  end process;
+
+  p_ack: process(req)
+  begin
+    ack <= req;
+  end process;
+end rtl;
@@ -0,0 +1,29 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity duplicate_assignment_rules is
+  port (
+    clk_i  : in std_logic;
+    en_i   : in std_logic;
+    data_i : in std_logic;
+    data_o : out std_logic
+  );
+end entity duplicate_assignment_rules;
+
+architecture rtl of duplicate_assignment_rules is
+  signal data_r : std_logic;
+begin
+  seq_p : process(clk_i)
+  begin
+    if rising_edge(clk_i) then
+      -- Default then override: not flagged
+      data_r <= '0';
+      if en_i = '1' then
+        data_r <= data_i;
+      end if;
+      -- Dead write: overwritten below in the same branch
+      data_o <= data_r;
+      data_o <= not data_r;
+    end if;
+  end process seq_p;
+end architecture rtl;
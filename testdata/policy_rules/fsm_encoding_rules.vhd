@@ -0,0 +1,58 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity fsm_encoding_rules is
+  port (
+    clk_i  : in  std_logic;
+    rst_i  : in  std_logic;
+    go_i   : in  std_logic;
+    busy_o : out std_logic
+  );
+end entity fsm_encoding_rules;
+
+architecture rtl of fsm_encoding_rules is
+  type seq_state_t is (Q_IDLE, Q_ARM, Q_LOAD, Q_SHIFT, Q_WAIT, Q_SAMPLE, Q_CHECK, Q_STORE, Q_DONE);
+  signal seq_state : seq_state_t;
+
+  constant PH_DRAIN : std_logic_vector(3 downto 0) := "1000";
+  signal phase : std_logic_vector(3 downto 0);
+begin
+  seq_p : process(clk_i)
+  begin
+    if rising_edge(clk_i) then
+      if rst_i = '1' then
+        seq_state <= Q_IDLE;
+      else
+        case seq_state is
+          when Q_IDLE   => if go_i = '1' then seq_state <= Q_ARM; end if;
+          when Q_ARM    => seq_state <= Q_LOAD;
+          when Q_LOAD   => seq_state <= Q_SHIFT;
+          when Q_SHIFT  => seq_state <= Q_WAIT;
+          when Q_WAIT   => seq_state <= Q_SAMPLE;
+          when Q_SAMPLE => seq_state <= Q_CHECK;
+          when Q_CHECK  => seq_state <= Q_STORE;
+          when Q_STORE  => seq_state <= Q_DONE;
+          when Q_DONE   => seq_state <= Q_IDLE;
+        end case;
+      end if;
+    end if;
+  end process seq_p;
+
+  phase_p : process(clk_i)
+  begin
+    if rising_edge(clk_i) then
+      if rst_i = '1' then
+        phase <= "0001";
+      else
+        case phase is
+          when "0001" => phase <= "0010";
+          when "0010" => phase <= "0100";
+          when "0100" => phase <= PH_DRAIN;
+          when others => phase <= "0001";
+        end case;
+      end if;
+    end if;
+  end process phase_p;
+
+  busy_o <= '0' when seq_state = Q_IDLE else '1';
+end architecture rtl;
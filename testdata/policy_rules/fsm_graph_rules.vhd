@@ -0,0 +1,45 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity fsm_graph_rules is
+  port (
+    clk_i   : in  std_logic;
+    rst_i   : in  std_logic;
+    start_i : in  std_logic;
+    abort_i : in  std_logic;
+    busy_o  : out std_logic
+  );
+end entity fsm_graph_rules;
+
+architecture rtl of fsm_graph_rules is
+  type ctrl_state_t is (C_IDLE, C_RUN, C_DONE, C_FLUSH, C_ORPHAN);
+  signal ctrl_state : ctrl_state_t;
+begin
+  ctrl_p : process(clk_i)
+  begin
+    if rising_edge(clk_i) then
+      if rst_i = '1' then
+        ctrl_state <= C_IDLE;
+      else
+        case ctrl_state is
+          when C_IDLE =>
+            if start_i = '1' then
+              ctrl_state <= C_RUN;
+            end if;
+          when C_RUN =>
+            if abort_i = '1' then
+              ctrl_state <= C_FLUSH;
+            else
+              ctrl_state <= C_DONE;
+            end if;
+          when C_DONE =>
+            null;
+          when C_ORPHAN =>
+            ctrl_state <= C_IDLE;
+        end case;
+      end if;
+    end if;
+  end process ctrl_p;
+
+  busy_o <= '1' when ctrl_state = C_RUN else '0';
+end architecture rtl;
@@ -0,0 +1,66 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity generic_map_leaf is
+  generic (
+    G_WIDTH : natural;
+    G_NAME  : string := "leaf"
+  );
+  port (
+    data_i : in  std_logic_vector(G_WIDTH - 1 downto 0);
+    data_o : out std_logic_vector(G_WIDTH - 1 downto 0)
+  );
+end entity generic_map_leaf;
+
+architecture rtl of generic_map_leaf is
+begin
+  data_o <= data_i;
+end architecture rtl;
+
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity generic_map_rules is
+  port (
+    a_i : in  std_logic_vector(7 downto 0);
+    a_o : out std_logic_vector(7 downto 0);
+    b_i : in  std_logic_vector(7 downto 0);
+    b_o : out std_logic_vector(7 downto 0);
+    c_i : in  std_logic_vector(7 downto 0);
+    c_o : out std_logic_vector(7 downto 0)
+  );
+end entity generic_map_rules;
+
+architecture rtl of generic_map_rules is
+begin
+  -- generic_type_mismatch: string literal for a natural generic
+  u_string : entity work.generic_map_leaf
+    generic map (
+      G_WIDTH => "8"
+    )
+    port map (
+      data_i => a_i,
+      data_o => a_o
+    );
+
+  -- missing_generic_binding: G_WIDTH has no default
+  u_missing : entity work.generic_map_leaf
+    generic map (
+      G_NAME => "b"
+    )
+    port map (
+      data_i => b_i,
+      data_o => b_o
+    );
+
+  -- unknown_generic: G_DEPTH isn't declared
+  u_unknown : entity work.generic_map_leaf
+    generic map (
+      G_WIDTH => 8,
+      G_DEPTH => 4
+    )
+    port map (
+      data_i => c_i,
+      data_o => c_o
+    );
+end architecture rtl;
@@ -0,0 +1,69 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity generics_leaf is
+  generic (
+    WIDTH      : integer := 8;
+    G_MASK     : std_logic_vector(7 downto 0) := x"FF";
+    G_USE_PIPE : boolean := false
+  );
+  port (
+    clk    : in std_logic;
+    data_i : in std_logic_vector(7 downto 0);
+    data_o : out std_logic_vector(7 downto 0)
+  );
+end entity generics_leaf;
+
+architecture rtl of generics_leaf is
+begin
+  gen_pipe : if G_USE_PIPE generate
+    process(clk)
+    begin
+      if rising_edge(clk) then
+        data_o <= data_i and G_MASK;
+      end if;
+    end process;
+  end generate gen_pipe;
+
+  gen_comb : if not G_USE_PIPE generate
+    data_o <= data_i and G_MASK;
+  end generate gen_comb;
+end architecture rtl;
+
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity generics_rules is
+  port (
+    clk    : in std_logic;
+    data_i : in std_logic_vector(7 downto 0);
+    data_o : out std_logic_vector(7 downto 0)
+  );
+end entity generics_rules;
+
+architecture rtl of generics_rules is
+  component generics_leaf is
+    generic (
+      WIDTH      : natural := 8;
+      G_MASK     : std_logic_vector(7 downto 0) := x"FF";
+      G_USE_PIPE : boolean := false;
+      G_DEPTH    : natural := 4
+    );
+    port (
+      clk    : in std_logic;
+      data_i : in std_logic_vector(7 downto 0);
+      data_o : out std_logic_vector(7 downto 0)
+    );
+  end component generics_leaf;
+begin
+  u_leaf : entity work.generics_leaf
+    generic map (
+      WIDTH  => 8,
+      G_MASK => x"0F"
+    )
+    port map (
+      clk    => clk,
+      data_i => data_i,
+      data_o => data_o
+    );
+end architecture rtl;
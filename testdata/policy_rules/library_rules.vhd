@@ -0,0 +1,16 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+library missing_lib;
+
+entity library_user is
+  port (
+    a : in  std_logic;
+    y : out std_logic
+  );
+end library_user;
+
+architecture rtl of library_user is
+begin
+  y <= a;
+end rtl;
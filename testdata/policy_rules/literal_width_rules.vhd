@@ -0,0 +1,46 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity literal_width_child is
+  port (
+    cfg_i  : in std_logic_vector(7 downto 0);
+    data_o : out std_logic_vector(7 downto 0)
+  );
+end entity literal_width_child;
+
+architecture rtl of literal_width_child is
+begin
+  data_o <= cfg_i;
+end architecture rtl;
+
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity literal_width_rules is
+  port (
+    clk_i  : in std_logic;
+    data_o : out std_logic_vector(7 downto 0)
+  );
+end entity literal_width_rules;
+
+architecture rtl of literal_width_rules is
+  signal nibble_s : std_logic_vector(3 downto 0);
+  signal mask_r   : std_logic_vector(3 downto 0);
+begin
+  -- Five bits into a four-bit signal
+  nibble_s <= "00011";
+
+  seq_p : process(clk_i)
+  begin
+    if rising_edge(clk_i) then
+      mask_r <= (7 downto 0 => '1');
+    end if;
+  end process seq_p;
+
+  -- Twelve bits into an eight-bit port
+  u_child : entity work.literal_width_child
+    port map (
+      cfg_i  => x"ABC",
+      data_o => data_o
+    );
+end architecture rtl;
@@ -0,0 +1,22 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity long_combinational_loop_rules is
+  port (
+    en_i : in bit
+  );
+end long_combinational_loop_rules;
+
+architecture rtl of long_combinational_loop_rules is
+  signal s0 : bit;
+  signal s1 : bit;
+  signal s2 : bit;
+  signal s3 : bit;
+  signal s4 : bit;
+begin
+  s1 <= s0 and en_i;
+  s2 <= not s1;
+  s3 <= s2 or en_i;
+  s4 <= s3;
+  s0 <= s4 xor en_i;
+end rtl;
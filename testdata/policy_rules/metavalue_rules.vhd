@@ -0,0 +1,34 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity metavalue_rules is
+  port (
+    clk_i    : in  std_logic;
+    opcode_i : in  std_logic_vector(3 downto 0);
+    load_o   : out std_logic;
+    store_o  : out std_logic;
+    bad_o    : out std_logic
+  );
+end entity metavalue_rules;
+
+architecture rtl of metavalue_rules is
+begin
+  decode_p : process(clk_i)
+  begin
+    if rising_edge(clk_i) then
+      load_o  <= '0';
+      store_o <= '0';
+      bad_o   <= '0';
+      if std_match(opcode_i, "10X1") then
+        load_o <= '1';
+      end if;
+      if opcode_i = "01-0" then
+        store_o <= '1';
+      end if;
+      if opcode_i(0) = 'X' then
+        bad_o <= '1';
+      end if;
+    end if;
+  end process decode_p;
+end architecture rtl;
@@ -0,0 +1,36 @@
+-- VHDL-2019 mode views (policy fixtures run with standard 1993)
+library ieee;
+use ieee.std_logic_1164.all;
+
+package mode_view_pkg is
+  type link_rec is record
+    req : std_logic;
+    ack : std_logic;
+  end record;
+
+  view link_master of link_rec is
+    req : out;
+    ack : in;
+  end view;
+end package;
+
+library ieee;
+use ieee.std_logic_1164.all;
+use work.mode_view_pkg.all;
+
+entity mode_view_rules is
+  port (
+    clk  : in std_logic;
+    link : view link_master of link_rec
+  );
+end entity;
+
+architecture rtl of mode_view_rules is
+begin
+  p_req: process(clk)
+  begin
+    if rising_edge(clk) then
+      link.req <= not link.ack;
+    end if;
+  end process;
+end architecture;
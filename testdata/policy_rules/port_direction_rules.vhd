@@ -0,0 +1,48 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity dir_child is
+  port (
+    a_i : in  std_logic;
+    y_o : out std_logic
+  );
+end entity dir_child;
+
+architecture rtl of dir_child is
+begin
+  y_o <= not a_i;
+end architecture rtl;
+
+entity port_direction_rules is
+  port (
+    a_i : in  std_logic;
+    b_i : in  std_logic;
+    y_o : out std_logic
+  );
+end entity port_direction_rules;
+
+architecture rtl of port_direction_rules is
+  signal y_s : std_logic;
+begin
+  -- port_direction_conflict: child output drives an input of this entity
+  u_drive_in : entity work.dir_child
+    port map (
+      a_i => a_i,
+      y_o => b_i
+    );
+
+  -- port_direction_conflict: two child outputs drive the same signal
+  u_first : entity work.dir_child
+    port map (
+      a_i => a_i,
+      y_o => y_s
+    );
+
+  u_second : entity work.dir_child
+    port map (
+      a_i => b_i,
+      y_o => y_s
+    );
+
+  y_o <= y_s;
+end architecture rtl;
@@ -0,0 +1,25 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity port_order_rules is
+  port (
+    data_i : in std_logic;
+    clk    : in std_logic;
+    rst    : in std_logic;
+    data_o : out std_logic
+  );
+end entity port_order_rules;
+
+architecture rtl of port_order_rules is
+begin
+  process(clk)
+  begin
+    if rising_edge(clk) then
+      if rst = '1' then
+        data_o <= '0';
+      else
+        data_o <= data_i;
+      end if;
+    end if;
+  end process;
+end architecture rtl;
@@ -0,0 +1,26 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+package visibility_pkg is
+  type mode_t is (MODE_IDLE, MODE_RUN);
+end package visibility_pkg;
+
+library ieee;
+use ieee.std_logic_1164.all;
+
+-- Neither port type is visible here: state_t lives in the architecture and
+-- visibility_pkg is never used.
+entity port_type_visibility_rules is
+  port (
+    clk_i   : in  std_logic;
+    state_i : in  state_t;
+    mode_i  : in  mode_t;
+    busy_o  : out std_logic
+  );
+end entity port_type_visibility_rules;
+
+architecture rtl of port_type_visibility_rules is
+  type state_t is (S_IDLE, S_BUSY);
+begin
+  busy_o <= '1' when state_i = S_BUSY or mode_i = MODE_RUN else '0';
+end architecture rtl;
@@ -0,0 +1,51 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity ram_rules is
+  port (
+    wclk   : in  std_logic;
+    rclk   : in  std_logic;
+    we     : in  std_logic;
+    waddr  : in  unsigned(3 downto 0);
+    raddr  : in  unsigned(3 downto 0);
+    din    : in  std_logic_vector(7 downto 0);
+    dout_a : out std_logic_vector(7 downto 0);
+    dout_s : out std_logic_vector(7 downto 0)
+  );
+end entity ram_rules;
+
+architecture rtl of ram_rules is
+  type ram_t is array (0 to 15) of std_logic_vector(7 downto 0);
+  signal lut_ram : ram_t;
+  signal dp_ram  : ram_t;
+begin
+  lut_write_p : process(wclk)
+  begin
+    if rising_edge(wclk) then
+      if we = '1' then
+        lut_ram(to_integer(waddr)) <= din;
+      end if;
+    end if;
+  end process lut_write_p;
+
+  -- ram_async_read: unregistered read port
+  dout_a <= lut_ram(to_integer(raddr));
+
+  dp_write_p : process(wclk)
+  begin
+    if rising_edge(wclk) then
+      if we = '1' then
+        dp_ram(to_integer(waddr)) <= din;
+      end if;
+    end if;
+  end process dp_write_p;
+
+  -- ram_read_during_write: read port on another clock
+  dp_read_p : process(rclk)
+  begin
+    if rising_edge(rclk) then
+      dout_s <= dp_ram(to_integer(raddr));
+    end if;
+  end process dp_read_p;
+end architecture rtl;
@@ -16,6 +16,9 @@ architecture rtl of rdc_rules is
   signal q1    : std_logic;
   signal q2    : std_logic;
   signal q3    : std_logic;
+  signal q4    : std_logic;
+  signal rst_a_meta : std_logic;
+  signal rst_a_sync : std_logic;
 begin
   rst_n <= a and b;
 
@@ -50,4 +53,24 @@ begin
       rst_sync <= rst_n;
     end if;
   end process;
+
+  rst_a_sync_p: process(clk_a, rst_n)
+  begin
+    if rst_n = '0' then
+      rst_a_meta <= '0';
+      rst_a_sync <= '0';
+    elsif rising_edge(clk_a) then
+      rst_a_meta <= '1';
+      rst_a_sync <= rst_a_meta;
+    end if;
+  end process;
+
+  proc_b_sync: process(clk_b, rst_a_sync)
+  begin
+    if rst_a_sync = '0' then
+      q4 <= '0';
+    elsif rising_edge(clk_b) then
+      q4 <= b;
+    end if;
+  end process;
 end rtl;
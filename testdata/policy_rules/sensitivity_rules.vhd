@@ -12,6 +12,7 @@ end sensitivity_rules;
 architecture rtl of sensitivity_rules is
   signal s_out1 : std_logic;
   signal s_out2 : std_logic;
+  signal s_out3 : std_logic;
 begin
   p_incomplete: process(a)
   begin
@@ -22,4 +23,9 @@ begin
   begin
     s_out2 <= a or b;
   end process;
+
+  p_own_output: process(c, s_out3)
+  begin
+    s_out3 <= not c;
+  end process;
 end rtl;
@@ -31,6 +31,8 @@ architecture rtl of sequential_rules is
   signal wide13 : std_logic;
   signal wide14 : std_logic;
   signal wide15 : std_logic;
+  signal sync_reg_a : std_logic;
+  signal sync_reg_b : std_logic;
 begin
   seq_proc: process(data)
   begin
@@ -53,6 +55,28 @@ begin
     end if;
   end process;
 
+  p_sync_reset_a: process(clk)
+  begin
+    if rising_edge(clk) then
+      if rst = '1' then
+        sync_reg_a <= '0';
+      else
+        sync_reg_a <= data;
+      end if;
+    end if;
+  end process;
+
+  p_sync_reset_b: process(clk)
+  begin
+    if rising_edge(clk) then
+      if rst = '1' then
+        sync_reg_b <= '0';
+      else
+        sync_reg_b <= data;
+      end if;
+    end if;
+  end process;
+
   p_rise: process(clk)
   begin
     if rising_edge(clk) then
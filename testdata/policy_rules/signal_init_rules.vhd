@@ -0,0 +1,30 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity signal_init_rules is
+  port (
+    clk_i : in  std_logic;
+    a_i   : in  std_logic;
+    b_i   : in  std_logic;
+    y_o   : out std_logic;
+    q_o   : out unsigned(7 downto 0)
+  );
+end entity signal_init_rules;
+
+architecture rtl of signal_init_rules is
+  signal both_s : std_logic := '0';
+  signal cnt_r  : unsigned(7 downto 0) := (others => '0');
+begin
+  both_s <= a_i and b_i;
+  y_o    <= both_s;
+
+  count_p : process(clk_i)
+  begin
+    if rising_edge(clk_i) then
+      cnt_r <= cnt_r + 1;
+    end if;
+  end process count_p;
+
+  q_o <= cnt_r;
+end architecture rtl;
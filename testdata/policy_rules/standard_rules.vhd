@@ -0,0 +1,22 @@
+-- VHDL-2008 constructs (policy fixtures run with standard 1993)
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity standard_rules is
+  port (
+    sel : in std_logic_vector(1 downto 0);
+    y   : out std_logic
+  );
+end standard_rules;
+
+architecture rtl of standard_rules is
+  /* block comments are VHDL-2008 */
+begin
+  decode: process(all)
+  begin
+    case? sel is
+      when "1-" => y <= '1';
+      when others => y <= '0';
+    end case?;
+  end process;
+end rtl;
@@ -0,0 +1,37 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity subprogram_side_effects_rules is
+  port (
+    clk    : in  std_logic;
+    data_i : in  std_logic;
+    data_o : out std_logic
+  );
+end entity subprogram_side_effects_rules;
+
+architecture rtl of subprogram_side_effects_rules is
+  signal enable : std_logic;
+  signal busy   : std_logic;
+
+  -- Reads 'enable' without taking it as a parameter
+  function gated(x : std_logic) return std_logic is
+  begin
+    return x and enable;
+  end function gated;
+
+  -- Drives 'busy' behind the caller's back
+  procedure mark_busy(signal dst : out std_logic) is
+  begin
+    dst  <= '1';
+    busy <= '1';
+  end procedure mark_busy;
+begin
+  enable <= data_i;
+
+  p_main : process (clk)
+  begin
+    if rising_edge(clk) then
+      mark_busy(data_o);
+    end if;
+  end process p_main;
+end architecture rtl;
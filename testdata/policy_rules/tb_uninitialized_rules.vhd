@@ -0,0 +1,20 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity uninit_clock_tb is
+end entity uninit_clock_tb;
+
+architecture sim of uninit_clock_tb is
+  signal clk  : std_logic;
+  signal done : boolean := false;
+begin
+  clk <= not clk after 5 ns;
+
+  stim : process
+  begin
+    wait for 100 ns;
+    assert clk = '0' or clk = '1' report "clock never started" severity error;
+    done <= true;
+    wait;
+  end process;
+end architecture sim;
@@ -0,0 +1,20 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity loop_tb is
+end loop_tb;
+
+architecture sim of loop_tb is
+  signal clk : std_logic := '0';
+  signal rst : std_logic := '1';
+begin
+  clk <= not clk after 5 ns;
+
+  stim : process
+  begin
+    rst <= '1';
+    wait for 20 ns;
+    rst <= '0';
+    wait for 100 ns;
+  end process;
+end sim;
@@ -0,0 +1,36 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity tristate_bus_if is
+  port (
+    oe_i   : in  std_logic;
+    data_i : in  std_logic_vector(7 downto 0);
+    bus_o  : out std_logic_vector(7 downto 0)
+  );
+end entity tristate_bus_if;
+
+architecture rtl of tristate_bus_if is
+begin
+  bus_o <= data_i when oe_i = '1' else (others => 'Z');
+end architecture rtl;
+
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity tristate_rules is
+  port (
+    oe_i   : in  std_logic;
+    data_i : in  std_logic_vector(7 downto 0);
+    pad_o  : out std_logic_vector(7 downto 0)
+  );
+end entity tristate_rules;
+
+architecture rtl of tristate_rules is
+begin
+  bus_if_u : entity work.tristate_bus_if
+    port map (
+      oe_i   => oe_i,
+      data_i => data_i,
+      bus_o  => pad_o
+    );
+end architecture rtl;
@@ -0,0 +1,32 @@
+entity unused_arch_core is
+  port (
+    data_i : in bit;
+    data_o : out bit
+  );
+end entity unused_arch_core;
+
+architecture rtl of unused_arch_core is
+begin
+  data_o <= data_i;
+end architecture rtl;
+
+architecture sim of unused_arch_core is
+begin
+  data_o <= not data_i;
+end architecture sim;
+
+entity unused_architecture_rules is
+  port (
+    data_i : in bit;
+    data_o : out bit
+  );
+end entity unused_architecture_rules;
+
+architecture rtl of unused_architecture_rules is
+begin
+  u_core : entity work.unused_arch_core(rtl)
+    port map (
+      data_i => data_i,
+      data_o => data_o
+    );
+end architecture rtl;
@@ -0,0 +1,44 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+package use_clause_pkg is
+  constant C_WIDTH : natural := 8;
+  function clog2(n : natural) return natural;
+end package use_clause_pkg;
+
+package body use_clause_pkg is
+  function clog2(n : natural) return natural is
+    variable r : natural := 0;
+  begin
+    while (2 ** r) < n loop
+      r := r + 1;
+    end loop;
+    return r;
+  end function;
+end package body use_clause_pkg;
+
+use ieee.std_logic_1164.all;
+use ieee.std_logic_1164.all;
+use work.use_clause_pkg.all;
+
+entity use_clause_rules is
+  port (
+    clk_i : in  std_logic;
+    d     : in  std_logic;
+    q     : out std_logic
+  );
+end use_clause_rules;
+
+architecture rtl of use_clause_rules is
+  constant C_HALF : real := ieee.math_real.floor(2.5);
+  signal q_r : std_logic;
+begin
+  reg_p: process(clk_i)
+  begin
+    if rising_edge(clk_i) then
+      q_r <= d;
+    end if;
+  end process;
+
+  q <= q_r;
+end rtl;
@@ -0,0 +1,37 @@
+-- fixture vendor: none
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity vendor_rules is
+  port (
+    clk_p_i : in  std_logic;
+    clk_n_i : in  std_logic;
+    d_i     : in  std_logic;
+    q_o     : out std_logic
+  );
+end entity vendor_rules;
+
+architecture rtl of vendor_rules is
+  signal clk_ibuf : std_logic;
+  signal clk      : std_logic;
+begin
+  u_ibufds : IBUFDS
+    port map (
+      I  => clk_p_i,
+      IB => clk_n_i,
+      O  => clk_ibuf
+    );
+
+  u_bufg : BUFG
+    port map (
+      I => clk_ibuf,
+      O => clk
+    );
+
+  q_p : process(clk)
+  begin
+    if rising_edge(clk) then
+      q_o <= d_i;
+    end if;
+  end process q_p;
+end architecture rtl;
@@ -0,0 +1,25 @@
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity waiver_rules is
+  port (
+    clk_i : in  std_logic;
+    d     : in  std_logic;
+    q     : out std_logic
+  );
+end waiver_rules;
+
+architecture rtl of waiver_rules is
+  --@waive rule=unused_signal expires=2020-01-31 reason=spare
+  signal spare : std_logic;
+  signal q_r   : std_logic;
+begin
+  reg_p: process(clk_i)
+  begin
+    if rising_edge(clk_i) then
+      q_r <= d;
+    end if;
+  end process;
+
+  q <= q_r;
+end rtl;
@@ -0,0 +1,31 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity width_arith_rules is
+  port (
+    clk   : in  std_logic;
+    a     : in  unsigned(7 downto 0);
+    b     : in  unsigned(7 downto 0);
+    c     : in  unsigned(3 downto 0);
+    s     : in  signed(7 downto 0);
+    sum_o : out unsigned(7 downto 0);
+    prd_o : out unsigned(7 downto 0);
+    mix_o : out signed(8 downto 0)
+  );
+end entity width_arith_rules;
+
+architecture rtl of width_arith_rules is
+begin
+  arith_p : process(clk)
+  begin
+    if rising_edge(clk) then
+      -- arith_overflow: the carry of a + b is dropped
+      sum_o <= a + b;
+      -- multiplication_without_resize: 12-bit product into 8 bits
+      prd_o <= a * c;
+      -- arith_mixed_signedness: signed and unsigned operands
+      mix_o <= s + a;
+    end if;
+  end process arith_p;
+end architecture rtl;
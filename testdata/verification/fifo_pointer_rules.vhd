@@ -0,0 +1,54 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity fifo_pointer_rules is
+  port (
+    wclk       : in std_logic;
+    rclk       : in std_logic;
+    rst        : in std_logic;
+    wr_go      : in std_logic;
+    rd_go      : in std_logic;
+    full_flag  : out std_logic;
+    empty_flag : out std_logic;
+    data_in    : in std_logic_vector(7 downto 0);
+    data_out   : out std_logic_vector(7 downto 0)
+  );
+end entity;
+
+architecture rtl of fifo_pointer_rules is
+  -- 12 entries: a 3-bit write pointer can't reach them all, and a 4-bit
+  -- read pointer runs past the end. The pointers are binary across clocks.
+  type mem_t is array (0 to 11) of std_logic_vector(7 downto 0);
+  signal mem  : mem_t;
+  signal wptr : unsigned(2 downto 0);
+  signal rptr : unsigned(3 downto 0);
+begin
+  write_p : process(wclk, rst)
+  begin
+    if rst = '1' then
+      wptr <= (others => '0');
+      full_flag <= '0';
+    elsif rising_edge(wclk) then
+      if wr_go = '1' then
+        mem(to_integer(wptr)) <= data_in;
+        wptr <= wptr + 1;
+      end if;
+      full_flag <= '0';
+    end if;
+  end process;
+
+  read_p : process(rclk, rst)
+  begin
+    if rst = '1' then
+      rptr <= (others => '0');
+      empty_flag <= '1';
+    elsif rising_edge(rclk) then
+      if rd_go = '1' then
+        data_out <= mem(to_integer(rptr));
+        rptr <= rptr + 1;
+      end if;
+      empty_flag <= '0';
+    end if;
+  end process;
+end architecture;
@@ -0,0 +1,16 @@
+entity named_ready_valid is
+  port (
+    up_valid : in std_logic; -- source has data
+    up_rdy   : in std_logic  -- sink can accept
+  );
+end entity;
+
+architecture rtl of named_ready_valid is
+  signal xfer : std_logic;
+begin
+  verification : block
+  begin
+  end block verification;
+
+  xfer <= up_valid and up_rdy;
+end architecture;
@@ -0,0 +1,17 @@
+entity psl_cover_companion is
+end entity;
+
+architecture rtl of psl_cover_companion is
+  signal clk   : std_logic;
+  signal v_sig : std_logic;
+  signal r_sig : std_logic;
+begin
+  verification : block
+  begin
+    --@check id=rv.stable_while_stalled scope=arch:rtl valid=v_sig ready=r_sig
+  end block verification;
+
+  default clock is rising_edge(clk);
+  cover_rv_handshake : cover {v_sig and r_sig};
+  rv_stable_while_stalled : cover {v_sig and not r_sig};
+end architecture;
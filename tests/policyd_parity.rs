@@ -0,0 +1,735 @@
+//! Parity harness between the batch policy engine (`engine::evaluate`) and the
+//! incremental `vhdl_policyd` daemon.
+//!
+//! Each scenario is described once as a `Project`, lowered to both an `Input`
+//! for the batch engine and fact tables for the daemon, and the violation sets
+//! for the rules the daemon implements must match exactly. Deltas are replayed
+//! against the daemon while the batch engine re-evaluates the full state, so
+//! incremental maintenance is checked as well as the initial snapshot.
+//!
+//! When a rule is ported to the daemon, add it to `DAEMON_RULES` (with its
+//! default severity) and to `daemon::RULES`, and extend `Project` with the
+//! tables it reads.
+//! Configurations and `strict_binding` are not modeled by the daemon, so the
+//! scenarios bind instances by target name only.
+//!
+//! The daemon is always run as a release build (built on first use, or taken
+//! from `VHDL_POLICYD`): differential-dataflow 0.12's merge batcher trips the
+//! standard library's debug-build `get_unchecked` precondition check, so a
+//! debug `vhdl_policyd` aborts on its first batch.
+
+use std::collections::{BTreeSet, HashMap};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::sync::OnceLock;
+
+use serde_json::{json, Value};
+use vhdl_compiler::policy::engine;
+use vhdl_compiler::policy::hierarchy::base_name;
+use vhdl_compiler::policy::input::{
+    Architecture, Dependency, Entity, Input, Instance, Port, Process, Signal, SignalUsage,
+};
+
+/// Rules implemented by `vhdl_policyd`, with the severity it reports them at.
+const DAEMON_RULES: &[(&str, &str)] = &[
+    ("entity_has_ports", "warning"),
+    ("architecture_has_entity", "error"),
+    ("entity_without_arch", "warning"),
+    ("unresolved_dependency", "error"),
+    ("unused_signal", "warning"),
+    ("undriven_output_port", "error"),
+    ("floating_instance_input", "error"),
+    ("port_width_mismatch", "error"),
+];
+
+/// (rule, severity, file, line, message)
+type Key = (String, String, String, usize, String);
+
+/// (name, target, architecture, file, line, port map)
+type InstanceDecl = (String, String, String, String, usize, Vec<(String, String)>);
+
+/// Derives the next project state from the previous one.
+type Step = fn(&Project) -> Project;
+
+#[derive(Clone, Default)]
+struct Project {
+    entities: Vec<(String, String, usize, Vec<String>)>,
+    architectures: Vec<(String, String, String, usize)>,
+    dependencies: Vec<(String, String, String, usize)>,
+    symbols: Vec<String>,
+    /// (entity, name, direction, width, default); declared at the entity's location.
+    ports: Vec<(String, String, String, usize, String)>,
+    /// (name, architecture, file, line, width)
+    signals: Vec<(String, String, String, usize, usize)>,
+    instances: Vec<InstanceDecl>,
+    /// (name, "read" | "write")
+    uses: Vec<(String, String)>,
+}
+
+impl Project {
+    fn entity(mut self, name: &str, file: &str, line: usize, ports: &[&str]) -> Self {
+        self.entities.push((
+            name.to_string(),
+            file.to_string(),
+            line,
+            ports.iter().map(|p| p.to_string()).collect(),
+        ));
+        self
+    }
+
+    fn architecture(mut self, name: &str, entity: &str, file: &str, line: usize) -> Self {
+        self.architectures
+            .push((name.to_string(), entity.to_string(), file.to_string(), line));
+        self
+    }
+
+    fn dependency(mut self, file: &str, target: &str, kind: &str, line: usize) -> Self {
+        self.dependencies
+            .push((file.to_string(), target.to_string(), kind.to_string(), line));
+        self
+    }
+
+    fn symbol(mut self, name: &str) -> Self {
+        self.symbols.push(name.to_string());
+        self
+    }
+
+    fn port(
+        mut self,
+        entity: &str,
+        name: &str,
+        direction: &str,
+        width: usize,
+        default: &str,
+    ) -> Self {
+        self.ports.push((
+            entity.to_string(),
+            name.to_string(),
+            direction.to_string(),
+            width,
+            default.to_string(),
+        ));
+        self
+    }
+
+    fn signal(mut self, name: &str, arch: &str, file: &str, line: usize, width: usize) -> Self {
+        self.signals.push((
+            name.to_string(),
+            arch.to_string(),
+            file.to_string(),
+            line,
+            width,
+        ));
+        self
+    }
+
+    fn instance(
+        mut self,
+        name: &str,
+        target: &str,
+        arch: &str,
+        file: &str,
+        line: usize,
+        port_map: &[(&str, &str)],
+    ) -> Self {
+        self.instances.push((
+            name.to_string(),
+            target.to_string(),
+            arch.to_string(),
+            file.to_string(),
+            line,
+            port_map
+                .iter()
+                .map(|(formal, actual)| (formal.to_string(), actual.to_string()))
+                .collect(),
+        ));
+        self
+    }
+
+    fn uses(mut self, name: &str, kind: &str) -> Self {
+        self.uses.push((name.to_string(), kind.to_string()));
+        self
+    }
+
+    /// Every port with the file and line of the entity declaring it, including
+    /// the plain input ports given to `entity`.
+    fn all_ports(&self) -> Vec<(String, String, usize, Port)> {
+        let mut out = Vec::new();
+        for (entity, file, line, names) in &self.entities {
+            for name in names {
+                let port = Port {
+                    name: name.clone(),
+                    direction: "in".to_string(),
+                    in_entity: entity.clone(),
+                    line: *line,
+                    ..Default::default()
+                };
+                out.push((entity.clone(), file.clone(), *line, port));
+            }
+            for (_, name, direction, width, default) in self.ports.iter().filter(|p| p.0 == *entity)
+            {
+                let port = Port {
+                    name: name.clone(),
+                    direction: direction.clone(),
+                    default: default.clone(),
+                    width: *width,
+                    in_entity: entity.clone(),
+                    line: *line,
+                    ..Default::default()
+                };
+                out.push((entity.clone(), file.clone(), *line, port));
+            }
+        }
+        out
+    }
+
+    /// Batch engine view. Dependency resolution mirrors the daemon: a target is
+    /// resolved when it appears in the symbol table. Port map actuals are
+    /// recorded as port-map signal usages, as the extractor does.
+    fn input(&self) -> Input {
+        let mut input = Input::default();
+        let ports = self.all_ports();
+        for (name, file, line, _) in &self.entities {
+            input.entities.push(Entity {
+                name: name.clone(),
+                file: file.clone(),
+                line: *line,
+                ports: ports
+                    .iter()
+                    .filter(|(entity, entity_file, ..)| entity == name && entity_file == file)
+                    .map(|(.., port)| port.clone())
+                    .collect(),
+                ..Default::default()
+            });
+        }
+        input.ports = ports.into_iter().map(|(.., port)| port).collect();
+        for (name, arch, file, line, width) in &self.signals {
+            input.signals.push(Signal {
+                name: name.clone(),
+                in_entity: arch.clone(),
+                file: file.clone(),
+                line: *line,
+                width: *width,
+                ..Default::default()
+            });
+        }
+        for (name, target, arch, file, line, port_map) in &self.instances {
+            input.instances.push(Instance {
+                name: name.clone(),
+                target: target.clone(),
+                in_arch: arch.clone(),
+                file: file.clone(),
+                line: *line,
+                port_map: port_map.iter().cloned().collect::<HashMap<_, _>>(),
+                ..Default::default()
+            });
+            for (_, actual) in port_map {
+                input.signal_usages.push(SignalUsage {
+                    signal: base_name(actual).to_string(),
+                    in_port_map: true,
+                    instance_name: name.clone(),
+                    line: *line,
+                    ..Default::default()
+                });
+            }
+        }
+        if !self.uses.is_empty() {
+            let names = |kind: &str| {
+                self.uses
+                    .iter()
+                    .filter(|(_, k)| k == kind)
+                    .map(|(name, _)| name.clone())
+                    .collect()
+            };
+            input.processes.push(Process {
+                read_signals: names("read"),
+                assigned_signals: names("write"),
+                ..Default::default()
+            });
+        }
+        for (name, entity, file, line) in &self.architectures {
+            input.architectures.push(Architecture {
+                name: name.clone(),
+                entity_name: entity.clone(),
+                file: file.clone(),
+                line: *line,
+                ..Default::default()
+            });
+        }
+        for (file, target, kind, line) in &self.dependencies {
+            input.dependencies.push(Dependency {
+                source: file.clone(),
+                target: target.clone(),
+                kind: kind.clone(),
+                line: *line,
+                resolved: self.symbols.contains(target),
+            });
+        }
+        for (rule, severity) in DAEMON_RULES {
+            input
+                .lint_config
+                .rules
+                .insert(rule.to_string(), severity.to_string());
+        }
+        input
+    }
+
+    /// Daemon view, one JSON array per fact table.
+    fn tables(&self) -> Tables {
+        let mut tables = Tables::default();
+        for (name, file, line, _) in &self.entities {
+            tables
+                .entities
+                .push(json!({"name": name, "file": file, "line": line}));
+        }
+        for (entity, file, line, port) in self.all_ports() {
+            tables.ports.push(json!({
+                "entity": entity,
+                "name": port.name,
+                "direction": port.direction,
+                "default": port.default,
+                "width": port.width,
+                "file": file,
+                "line": line,
+            }));
+        }
+        for (name, arch, file, line, width) in &self.signals {
+            tables.signals.push(json!({
+                "name": name,
+                "scope": arch,
+                "width": width,
+                "file": file,
+                "line": line,
+            }));
+        }
+        for (name, target, arch, file, line, port_map) in &self.instances {
+            tables.instances.push(json!({
+                "name": name,
+                "target": target,
+                "in_arch": arch,
+                "file": file,
+                "line": line,
+            }));
+            for (formal, actual) in port_map {
+                tables.port_maps.push(json!({
+                    "instance": name,
+                    "formal": formal,
+                    "actual": actual,
+                    "file": file,
+                    "line": line,
+                }));
+            }
+        }
+        for (name, kind) in &self.uses {
+            tables
+                .signal_uses
+                .push(json!({"name": name, "kind": kind, "file": "uses.vhd", "line": 1}));
+        }
+        for (name, entity, file, line) in &self.architectures {
+            tables
+                .architectures
+                .push(json!({"name": name, "entity_name": entity, "file": file, "line": line}));
+        }
+        for (file, target, kind, line) in &self.dependencies {
+            tables
+                .dependencies
+                .push(json!({"file": file, "target": target, "kind": kind, "line": line}));
+        }
+        for name in &self.symbols {
+            tables.symbols.push(json!({ "name": name }));
+        }
+        tables
+    }
+}
+
+#[derive(Default)]
+struct Tables {
+    entities: Vec<Value>,
+    architectures: Vec<Value>,
+    ports: Vec<Value>,
+    dependencies: Vec<Value>,
+    symbols: Vec<Value>,
+    signals: Vec<Value>,
+    instances: Vec<Value>,
+    signal_uses: Vec<Value>,
+    port_maps: Vec<Value>,
+}
+
+impl Tables {
+    fn to_json(&self) -> Value {
+        json!({
+            "entities": self.entities,
+            "architectures": self.architectures,
+            "ports": self.ports,
+            "dependencies": self.dependencies,
+            "symbols": self.symbols,
+            "signals": self.signals,
+            "instances": self.instances,
+            "signal_uses": self.signal_uses,
+            "port_maps": self.port_maps,
+        })
+    }
+
+    /// Rows present in `self` but not in `other`, table by table.
+    fn minus(&self, other: &Tables) -> Tables {
+        fn diff(a: &[Value], b: &[Value]) -> Vec<Value> {
+            a.iter().filter(|row| !b.contains(row)).cloned().collect()
+        }
+        Tables {
+            entities: diff(&self.entities, &other.entities),
+            architectures: diff(&self.architectures, &other.architectures),
+            ports: diff(&self.ports, &other.ports),
+            dependencies: diff(&self.dependencies, &other.dependencies),
+            symbols: diff(&self.symbols, &other.symbols),
+            signals: diff(&self.signals, &other.signals),
+            instances: diff(&self.instances, &other.instances),
+            signal_uses: diff(&self.signal_uses, &other.signal_uses),
+            port_maps: diff(&self.port_maps, &other.port_maps),
+        }
+    }
+}
+
+struct Daemon {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+/// Release `vhdl_policyd`: `VHDL_POLICYD` if set, else built once per run.
+fn daemon_binary() -> &'static Path {
+    static BINARY: OnceLock<PathBuf> = OnceLock::new();
+    BINARY.get_or_init(|| {
+        if let Some(path) = std::env::var_os("VHDL_POLICYD") {
+            return PathBuf::from(path);
+        }
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let target_dir = std::env::var_os("CARGO_TARGET_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| manifest_dir.join("target"));
+        let status = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+            .args(["build", "--release", "--quiet", "--bin", "vhdl_policyd"])
+            .arg("--manifest-path")
+            .arg(manifest_dir.join("Cargo.toml"))
+            .arg("--target-dir")
+            .arg(&target_dir)
+            .status()
+            .expect("run cargo build for vhdl_policyd");
+        assert!(status.success(), "building release vhdl_policyd failed");
+        target_dir
+            .join("release")
+            .join(format!("vhdl_policyd{}", std::env::consts::EXE_SUFFIX))
+    })
+}
+
+impl Daemon {
+    fn spawn() -> Daemon {
+        let mut child = Command::new(daemon_binary())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("spawn vhdl_policyd");
+        let stdin = child.stdin.take().expect("daemon stdin");
+        let stdout = BufReader::new(child.stdout.take().expect("daemon stdout"));
+        Daemon {
+            child,
+            stdin,
+            stdout,
+        }
+    }
+
+    fn send(&mut self, cmd: Value) -> BTreeSet<Key> {
+        writeln!(self.stdin, "{}", cmd).expect("write command");
+        self.stdin.flush().expect("flush command");
+        let mut line = String::new();
+        let read = self.stdout.read_line(&mut line).expect("read response");
+        if read == 0 {
+            self.crashed();
+        }
+        let response: Value = serde_json::from_str(&line)
+            .unwrap_or_else(|e| panic!("daemon answered with invalid JSON ({}): {}", e, line));
+        assert_eq!(response["kind"], "snapshot", "daemon error: {}", line);
+        response["violations"]
+            .as_array()
+            .expect("violations array")
+            .iter()
+            .map(|v| {
+                (
+                    v["rule"].as_str().unwrap_or_default().to_string(),
+                    v["severity"].as_str().unwrap_or_default().to_string(),
+                    v["file"].as_str().unwrap_or_default().to_string(),
+                    v["line"].as_u64().unwrap_or_default() as usize,
+                    v["message"].as_str().unwrap_or_default().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    /// Fails the test with the daemon's exit status and stderr after it
+    /// closed stdout without answering.
+    fn crashed(&mut self) -> ! {
+        let status = self.child.wait().expect("wait for vhdl_policyd");
+        let mut stderr = String::new();
+        if let Some(mut pipe) = self.child.stderr.take() {
+            let _ = pipe.read_to_string(&mut stderr);
+        }
+        panic!(
+            "vhdl_policyd exited ({}) without answering; stderr:\n{}",
+            status, stderr
+        );
+    }
+
+    fn init(&mut self, project: &Project) -> BTreeSet<Key> {
+        self.send(json!({"kind": "init", "tables": project.tables().to_json()}))
+    }
+
+    fn delta(&mut self, prev: &Project, next: &Project) -> BTreeSet<Key> {
+        let (prev, next) = (prev.tables(), next.tables());
+        self.send(json!({
+            "kind": "delta",
+            "added": next.minus(&prev).to_json(),
+            "removed": prev.minus(&next).to_json(),
+        }))
+    }
+}
+
+impl Drop for Daemon {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+fn batch(project: &Project) -> BTreeSet<Key> {
+    engine::evaluate(&project.input())
+        .violations
+        .into_iter()
+        .filter(|v| DAEMON_RULES.iter().any(|(rule, _)| *rule == v.rule))
+        .map(|v| (v.rule, v.severity, v.file, v.line, v.message))
+        .collect()
+}
+
+fn assert_parity(step: &str, daemon: &BTreeSet<Key>, project: &Project) {
+    let batch = batch(project);
+    assert_eq!(
+        daemon,
+        &batch,
+        "{}: daemon and batch engine disagree\nonly daemon: {:?}\nonly batch: {:?}",
+        step,
+        daemon.difference(&batch).collect::<Vec<_>>(),
+        batch.difference(daemon).collect::<Vec<_>>(),
+    );
+}
+
+fn sample_project() -> Project {
+    Project::default()
+        .entity("top", "top.vhd", 1, &["clk", "rst"])
+        .architecture("rtl", "top", "top.vhd", 10)
+        .entity("no_ports", "no_ports.vhd", 1, &[])
+        .architecture("rtl", "no_ports", "no_ports.vhd", 5)
+        .entity("lonely", "lonely.vhd", 1, &["a"])
+        .architecture("rtl", "missing", "orphan.vhd", 3)
+        .entity("top_tb", "top_tb.vhd", 1, &[])
+        .architecture("sim", "top_tb", "top_tb.vhd", 4)
+        .dependency("top.vhd", "work.child", "instantiation", 20)
+        .dependency("top.vhd", "work.lonely", "instantiation", 22)
+        .dependency("top.vhd", "ieee.std_logic_1164", "use", 2)
+        .symbol("work.lonely")
+}
+
+#[test]
+fn init_snapshot_matches_batch() {
+    let project = sample_project();
+    let mut daemon = Daemon::spawn();
+    let got = daemon.init(&project);
+    assert!(!got.is_empty(), "sample project should produce violations");
+    assert_parity("init", &got, &project);
+}
+
+#[test]
+fn empty_tables_match_batch() {
+    let project = Project::default();
+    let mut daemon = Daemon::spawn();
+    let got = daemon.init(&project);
+    assert!(got.is_empty());
+    assert_parity("empty", &got, &project);
+}
+
+#[test]
+fn identifiers_match_case_insensitively() {
+    let project = Project::default()
+        .entity("Counter", "counter.vhd", 1, &["clk"])
+        .architecture("rtl", "COUNTER", "counter.vhd", 8);
+    let mut daemon = Daemon::spawn();
+    let got = daemon.init(&project);
+    assert!(got.is_empty(), "unexpected violations: {:?}", got);
+    assert_parity("mixed case", &got, &project);
+}
+
+#[test]
+fn duplicate_rows_do_not_skew_results() {
+    let project = Project::default()
+        .entity("dual", "dual.vhd", 1, &["a", "b", "c"])
+        .architecture("rtl", "dual", "dual.vhd", 10)
+        .architecture("beh", "dual", "dual_beh.vhd", 1)
+        .entity("bare", "bare.vhd", 1, &[]);
+    let mut daemon = Daemon::spawn();
+    let got = daemon.init(&project);
+    assert_parity("duplicates", &got, &project);
+}
+
+#[test]
+fn deltas_stay_in_parity() {
+    // Each step builds on the state left by the previous one.
+    let steps: &[(&str, Step)] = &[
+        ("add missing entity", |p| {
+            p.clone().entity("missing", "missing.vhd", 1, &["x"])
+        }),
+        ("resolve child", |p| p.clone().symbol("work.child")),
+        ("add architecture for lonely", |p| {
+            p.clone().architecture("rtl", "lonely", "lonely.vhd", 6)
+        }),
+        ("give no_ports a port", |p| {
+            let mut next = p.clone();
+            for entity in next.entities.iter_mut().filter(|e| e.0 == "no_ports") {
+                entity.3.push("clk".to_string());
+            }
+            next
+        }),
+        ("remove top", |p| {
+            let mut next = p.clone();
+            next.entities.retain(|e| e.0 != "top");
+            next
+        }),
+        ("restore sample", |_| sample_project()),
+    ];
+
+    let mut daemon = Daemon::spawn();
+    let mut prev = sample_project();
+    assert_parity("init", &daemon.init(&prev), &prev);
+    for (step, apply) in steps {
+        let next = apply(&prev);
+        let got = daemon.delta(&prev, &next);
+        assert_parity(step, &got, &next);
+        prev = next;
+    }
+
+    // A snapshot with no changes reports the same state.
+    let got = daemon.send(json!({"kind": "snapshot"}));
+    assert_parity("snapshot", &got, &prev);
+}
+
+/// A two-level design exercising the signal, port and hierarchy rules: `top`
+/// instantiates `core` twice and a testbench instantiates it once more.
+fn hierarchy_project() -> Project {
+    Project::default()
+        .entity("core", "core.vhd", 1, &["clk"])
+        .port("core", "din", "in", 8, "")
+        .port("core", "en", "in", 0, "")
+        .port("core", "mode", "in", 2, "\"00\"")
+        .port("core", "dout", "out", 8, "")
+        .port("core", "valid", "out", 0, "")
+        .architecture("rtl", "core", "core.vhd", 10)
+        .uses("dout", "write")
+        .entity("top", "top.vhd", 1, &["clk"])
+        .port("top", "result", "out", 4, "")
+        .architecture("struct", "top", "top.vhd", 10)
+        .signal("bus", "struct", "top.vhd", 12, 8)
+        .signal("narrow", "struct", "top.vhd", 13, 4)
+        .signal("spare", "struct", "top.vhd", 14, 1)
+        .instance(
+            "u_core",
+            "work.core",
+            "struct",
+            "top.vhd",
+            20,
+            &[("clk", "clk"), ("din", "narrow"), ("dout", "result")],
+        )
+        .instance(
+            "u_core2",
+            "work.CORE",
+            "struct",
+            "top.vhd",
+            30,
+            &[
+                ("DIN", "bus(7 downto 0)"),
+                ("en", "bus(0)"),
+                ("mode", "bus(3 downto 0)"),
+                ("dout", "open"),
+            ],
+        )
+        .entity("core_tb", "core_tb.vhd", 1, &[])
+        .architecture("sim", "core_tb", "core_tb.vhd", 5)
+        .signal("stim", "sim", "core_tb.vhd", 6, 4)
+        .signal("idle", "sim", "core_tb.vhd", 7, 1)
+        .instance("dut", "core", "sim", "core_tb.vhd", 12, &[("din", "stim")])
+}
+
+#[test]
+fn hierarchy_rules_match_batch() {
+    let project = hierarchy_project();
+    let mut daemon = Daemon::spawn();
+    let got = daemon.init(&project);
+    let rules: BTreeSet<&str> = got.iter().map(|v| v.0.as_str()).collect();
+    for rule in [
+        "unused_signal",
+        "undriven_output_port",
+        "floating_instance_input",
+        "port_width_mismatch",
+    ] {
+        assert!(rules.contains(rule), "expected {} in {:?}", rule, got);
+    }
+    assert_parity("init", &got, &project);
+}
+
+#[test]
+fn hierarchy_deltas_stay_in_parity() {
+    fn instance_mut<'a>(project: &'a mut Project, name: &str) -> &'a mut Vec<(String, String)> {
+        &mut project
+            .instances
+            .iter_mut()
+            .find(|inst| inst.0 == name)
+            .expect("instance exists")
+            .5
+    }
+
+    let steps: &[(&str, Step)] = &[
+        ("connect enable", |p| {
+            let mut next = p.clone();
+            instance_mut(&mut next, "u_core").push(("en".to_string(), "bus(1)".to_string()));
+            next
+        }),
+        ("widen narrow", |p| {
+            let mut next = p.clone();
+            for sig in next.signals.iter_mut().filter(|s| s.0 == "narrow") {
+                sig.4 = 8;
+            }
+            next
+        }),
+        ("drive valid", |p| p.clone().uses("valid", "write")),
+        ("read spare", |p| p.clone().uses("SPARE", "read")),
+        ("disconnect result", |p| {
+            let mut next = p.clone();
+            instance_mut(&mut next, "u_core").retain(|(formal, _)| formal != "dout");
+            next
+        }),
+        ("remove core", |p| {
+            let mut next = p.clone();
+            next.entities.retain(|e| e.0 != "core");
+            next.ports.retain(|port| port.0 != "core");
+            next
+        }),
+        ("restore", |_| hierarchy_project()),
+    ];
+
+    let mut daemon = Daemon::spawn();
+    let mut prev = hierarchy_project();
+    assert_parity("init", &daemon.init(&prev), &prev);
+    for (step, apply) in steps {
+        let next = apply(&prev);
+        let got = daemon.delta(&prev, &next);
+        assert_parity(step, &got, &next);
+        prev = next;
+    }
+}
@@ -1023,9 +1023,26 @@ module.exports = grammar({
           $.anonymous_type_indication
         ), $.parameter_type)),
         optional(seq(':=', field('default', $.default_value)))  // default value
+      ),
+      // VHDL-2019: mode view port, e.g. bus : view master_view of bus_rec
+      seq(
+        field('names', $.identifier_list),
+        ':',
+        field('view', $.mode_view_indication)
       )
     ),
 
+    // VHDL-2019: mode view indication
+    // view name [of type] | view (name) of array_type
+    mode_view_indication: $ => seq(
+      $._kw_view,
+      choice(
+        field('name', $._name),
+        seq('(', field('name', $._name), ')')
+      ),
+      optional(seq($._kw_of, field('type', $._type_mark)))
+    ),
+
     // VHDL-2019: anonymous type indication in port list
     // Example: A : type is private; B : type is <>
     anonymous_type_indication: $ => seq(
@@ -1635,20 +1652,25 @@ module.exports = grammar({
 
     view_declaration: $ => seq(
       $._kw_view,
-      $.identifier,
+      field('name', $.identifier),
       $._kw_of,
-      $._name,
+      field('type', $._name),
       $._kw_is,
       repeat1($.view_element),
       $._kw_end,
       $._kw_view,
+      optional($.identifier),
       ';'
     ),
 
+    // Element modes: a, b : in;  or  sub : view sub_view;
     view_element: $ => seq(
-      $.identifier,
+      field('names', $.identifier_list),
       ':',
-      $.port_direction,
+      choice(
+        field('direction', $.port_direction),
+        field('view', $.mode_view_indication)
+      ),
       ';'
     ),
 